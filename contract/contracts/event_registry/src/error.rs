@@ -28,6 +28,39 @@ pub enum EventRegistryError {
     EventCancelled = 22,
     EventAlreadyCancelled = 23,
     InvalidGracePeriodEnd = 24,
+    InvalidTierVisibilityWindow = 25,
+    InvalidTokenPrice = 26,
+    InvalidFlashSale = 27,
+    ProposalNotFound = 28,
+    ProposalAlreadyExecuted = 29,
+    InvalidDepositAmount = 30,
+    InvalidRecoveryConfig = 31,
+    InvalidPolicyFlags = 32,
+    InvalidBatchSize = 33,
+    AdminAlreadyExists = 34,
+    AdminNotFound = 35,
+    CannotRemoveLastAdmin = 36,
+    InvalidThreshold = 37,
+    AlreadyApproved = 38,
+    ProposalExpired = 39,
+    InsufficientApprovals = 40,
+    EventAlreadyImported = 41,
+    InvalidImportedEvent = 42,
+    InvalidScannerWindow = 43,
+    InvalidSeatMap = 44,
+    SeatNotFound = 45,
+    SeatUnavailable = 46,
+    InvalidDutchAuction = 47,
+    InvalidRefundPolicy = 48,
+    EventNotEnded = 49,
+    ActionTimelocked = 50,
+    InvalidStorageVersion = 51,
+    InvalidResaleRoyaltyBps = 52,
+    InvalidStartTime = 53,
+    EventNotPostponed = 54,
+    InvalidTimeRange = 55,
+    EventIdUnavailable = 56,
+    InvalidFlashSaleDiscount = 57,
 }
 
 impl core::fmt::Display for EventRegistryError {
@@ -91,6 +124,117 @@ impl core::fmt::Display for EventRegistryError {
             EventRegistryError::InvalidGracePeriodEnd => {
                 write!(f, "Grace period end timestamp must be in the future")
             }
+            EventRegistryError::InvalidTierVisibilityWindow => {
+                write!(f, "Tier visibility window must end after it starts")
+            }
+            EventRegistryError::InvalidTokenPrice => {
+                write!(f, "Token-specific tier prices must be greater than zero")
+            }
+            EventRegistryError::InvalidFlashSale => {
+                write!(
+                    f,
+                    "Flash sale window must end after it starts and in the future"
+                )
+            }
+            EventRegistryError::ProposalNotFound => write!(f, "Proposal not found"),
+            EventRegistryError::ProposalAlreadyExecuted => {
+                write!(f, "Proposal has already been executed")
+            }
+            EventRegistryError::InvalidDepositAmount => {
+                write!(f, "Deposit amount cannot be negative")
+            }
+            EventRegistryError::InvalidRecoveryConfig => {
+                write!(
+                    f,
+                    "Recovery address must differ from the organizer and timeout must be positive"
+                )
+            }
+            EventRegistryError::InvalidPolicyFlags => {
+                write!(
+                    f,
+                    "Policy flags are missing flags required by platform policy"
+                )
+            }
+            EventRegistryError::InvalidBatchSize => {
+                write!(
+                    f,
+                    "Batch must contain between 1 and the maximum number of items"
+                )
+            }
+            EventRegistryError::AdminAlreadyExists => write!(f, "Address is already an admin"),
+            EventRegistryError::AdminNotFound => write!(f, "Address is not an admin"),
+            EventRegistryError::CannotRemoveLastAdmin => {
+                write!(f, "The last remaining admin cannot be removed")
+            }
+            EventRegistryError::InvalidThreshold => {
+                write!(f, "Threshold must be between 1 and the number of admins")
+            }
+            EventRegistryError::AlreadyApproved => {
+                write!(f, "Admin has already approved this proposal")
+            }
+            EventRegistryError::ProposalExpired => write!(f, "Proposal has expired"),
+            EventRegistryError::InsufficientApprovals => {
+                write!(f, "Proposal does not have enough admin approvals")
+            }
+            EventRegistryError::EventAlreadyImported => {
+                write!(f, "Event has already been imported")
+            }
+            EventRegistryError::InvalidImportedEvent => {
+                write!(f, "Imported event violates supply or status invariants")
+            }
+            EventRegistryError::InvalidScannerWindow => {
+                write!(f, "Scanner validity window ends before it starts")
+            }
+            EventRegistryError::InvalidSeatMap => {
+                write!(f, "Seat map must list unique seats matching the tier limit")
+            }
+            EventRegistryError::SeatNotFound => {
+                write!(f, "Seat is not part of the tier's seat map or not reserved")
+            }
+            EventRegistryError::SeatUnavailable => write!(f, "Seat is already reserved"),
+            EventRegistryError::InvalidDutchAuction => {
+                write!(
+                    f,
+                    "Dutch auction must fall to a positive floor over a window"
+                )
+            }
+            EventRegistryError::InvalidRefundPolicy => {
+                write!(
+                    f,
+                    "Refund policy is malformed or the event already has sales"
+                )
+            }
+            EventRegistryError::EventNotEnded => {
+                write!(f, "Event has no end time or it has not passed yet")
+            }
+            EventRegistryError::ActionTimelocked => {
+                write!(f, "Action must be queued and wait out the timelock delay")
+            }
+            EventRegistryError::InvalidStorageVersion => {
+                write!(f, "Storage is not at the version being migrated from")
+            }
+            EventRegistryError::InvalidResaleRoyaltyBps => {
+                write!(f, "Resale royalty must be between 0 and 10000 basis points")
+            }
+            EventRegistryError::InvalidStartTime => {
+                write!(f, "New start time must be in the future")
+            }
+            EventRegistryError::EventNotPostponed => write!(f, "Event is not postponed"),
+            EventRegistryError::InvalidTimeRange => {
+                write!(f, "Time range is reversed or spans too many days")
+            }
+            EventRegistryError::EventIdUnavailable => {
+                write!(
+                    f,
+                    "Event id is reserved for another registry in the payment contract"
+                )
+            }
+            EventRegistryError::InvalidFlashSaleDiscount => {
+                write!(
+                    f,
+                    "Flash sale discount must be between 1 and 9999 basis points"
+                )
+            }
         }
     }
 }
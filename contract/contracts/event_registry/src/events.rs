@@ -1,5 +1,8 @@
-use soroban_sdk::{contracttype, Address, String};
+use crate::types::{AdminAction, EventVisibility, Milestone, OrganizerAction, RefundPolicy};
+use soroban_sdk::{contracttype, Address, String, Vec};
 
+/// First topic of every event the registry publishes. Events about a single
+/// event carry its event_id as the second topic.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AgoraEvent {
@@ -10,15 +13,55 @@ pub enum AgoraEvent {
     ContractInitialized,
     ContractUpgraded,
     MetadataUpdated,
+    MetadataBatchUpdated,
     InventoryIncremented,
     InventoryDecremented,
     OrganizerBlacklisted,
     OrganizerRemovedFromBlacklist,
     EventsSuspended,
     GlobalPromoUpdated,
+    PolicyRequirementUpdated,
     EventPostponed,
     ScannerAuthorized,
     GoalMet,
+    EventVisibilityUpdated,
+    FlashSaleScheduled,
+    OrganizerActionProposed,
+    ActionProposerUpdated,
+    OrganizerActionExecuted,
+    AdminActionProposed,
+    AdminActionApproved,
+    AdminActionExecuted,
+    EventImported,
+    EventsBatchRegistered,
+    SeatMapUpdated,
+    SeatReserved,
+    SeatReleased,
+    RefundPolicyUpdated,
+    EventEndTimeUpdated,
+    EventCompleted,
+    ActionQueued,
+    ActionExecuted,
+    ActionCancelled,
+    PlatformWalletProposed,
+    PlatformWalletUpdated,
+    AdminTransferStarted,
+    AdminTransferred,
+    EventFeeOverridden,
+    OrganizerRegistered,
+    OrganizerVerified,
+    BundleRegistered,
+    BundleSold,
+    MilestonePlanProposed,
+    MilestonePlanUpdated,
+    InventoryHeld,
+    InventoryHoldReleased,
+    StorageMigrated,
+    BuyerLimitUpdated,
+    EventStartTimeUpdated,
+    TransferLockUpdated,
+    EventRescheduled,
+    CancellationNoticeFailed,
 }
 
 #[contracttype]
@@ -29,6 +72,16 @@ pub struct EventCancelledEvent {
     pub timestamp: u64,
 }
 
+/// Emitted when the payment contract could not lock a cancelled event's
+/// escrow; the cancellation stands and the notice can be retried off-chain.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CancellationNoticeFailedEvent {
+    pub event_id: String,
+    pub ticket_payment: Address,
+    pub timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EventRegisteredEvent {
@@ -69,6 +122,15 @@ pub struct RegistryUpgradedEvent {
     pub timestamp: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageMigratedEvent {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub admin_address: Address,
+    pub timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct MetadataUpdatedEvent {
@@ -78,6 +140,25 @@ pub struct MetadataUpdatedEvent {
     pub timestamp: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataBatchUpdatedEvent {
+    pub organizer_address: Address,
+    pub updated_count: u32,
+    pub unchanged_count: u32,
+    pub failed_event_ids: Vec<String>,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventsBatchRegisteredEvent {
+    pub organizer_address: Address,
+    pub event_ids: Vec<String>,
+    pub count: u32,
+    pub timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct InventoryIncrementedEvent {
@@ -193,6 +274,8 @@ pub struct ScannerAuthorizedEvent {
     pub event_id: String,
     pub scanner: Address,
     pub authorized_by: Address,
+    pub valid_from: u64,
+    pub valid_until: u64,
     pub timestamp: u64,
 }
 #[contracttype]
@@ -203,3 +286,327 @@ pub struct GoalMetEvent {
     pub current_supply: i128,
     pub timestamp: u64,
 }
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventVisibilityUpdatedEvent {
+    pub event_id: String,
+    pub visibility: EventVisibility,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuyerLimitUpdatedEvent {
+    pub event_id: String,
+    pub max_tickets_per_buyer: u32,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FlashSaleScheduledEvent {
+    pub event_id: String,
+    pub tier_id: String,
+    pub discount_bps: u32,
+    pub start: u64,
+    pub end: u64,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionProposerUpdatedEvent {
+    pub event_id: String,
+    pub proposer: Address,
+    pub allowed: bool,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrganizerActionProposedEvent {
+    pub proposal_id: u64,
+    pub event_id: String,
+    pub proposer: Address,
+    pub action: OrganizerAction,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrganizerActionExecutedEvent {
+    pub proposal_id: u64,
+    pub event_id: String,
+    pub executed_by: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PolicyRequirementUpdatedEvent {
+    pub trigger_flag: u32,
+    pub required_flags: u32,
+    pub admin_address: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminActionProposedEvent {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub action: AdminAction,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminActionApprovedEvent {
+    pub proposal_id: u64,
+    pub approver: Address,
+    pub approvals: u32,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminActionExecutedEvent {
+    pub proposal_id: u64,
+    pub executed_by: Address,
+    pub action: AdminAction,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventImportedEvent {
+    pub event_id: String,
+    pub organizer_address: Address,
+    pub source_contract: Address,
+    pub source_ledger: u32,
+    pub imported_by: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeatMapUpdatedEvent {
+    pub event_id: String,
+    pub tier_id: String,
+    pub seat_count: u32,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeatReservedEvent {
+    pub event_id: String,
+    pub tier_id: String,
+    pub seat_id: String,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeatReleasedEvent {
+    pub event_id: String,
+    pub tier_id: String,
+    pub seat_id: String,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundPolicyUpdatedEvent {
+    pub event_id: String,
+    pub policy: RefundPolicy,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventEndTimeUpdatedEvent {
+    pub event_id: String,
+    pub end_time: u64,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventRescheduledEvent {
+    pub event_id: String,
+    pub previous_start_time: u64,
+    pub new_start_time: u64,
+    pub refund_deadline: u64,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventStartTimeUpdatedEvent {
+    pub event_id: String,
+    pub start_time: u64,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferLockUpdatedEvent {
+    pub event_id: String,
+    pub transfer_lock_before_secs: u64,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventCompletedEvent {
+    pub event_id: String,
+    pub completed_by: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionQueuedEvent {
+    pub action_id: u64,
+    pub action: AdminAction,
+    pub eta: u64,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionExecutedEvent {
+    pub action_id: u64,
+    pub action: AdminAction,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionCancelledEvent {
+    pub action_id: u64,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformWalletProposedEvent {
+    pub current_wallet: Address,
+    pub proposed_wallet: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformWalletUpdatedEvent {
+    pub old_wallet: Address,
+    pub new_wallet: Address,
+    pub timestamp: u64,
+}
+
+/// Emitted when an admin transfer is started, or with `pending_admin` of None
+/// when the pending transfer is cancelled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferStartedEvent {
+    pub admin: Address,
+    pub pending_admin: Option<Address>,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferredEvent {
+    pub old_admin: Address,
+    pub new_admin: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventFeeOverriddenEvent {
+    pub event_id: String,
+    pub fee_bps: u32,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrganizerRegisteredEvent {
+    pub organizer: Address,
+    pub profile_cid: String,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrganizerVerifiedEvent {
+    pub organizer: Address,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BundleRegisteredEvent {
+    pub bundle_id: String,
+    pub organizer: Address,
+    pub event_ids: Vec<String>,
+    pub price: i128,
+    pub max_supply: u32,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BundleSoldEvent {
+    pub bundle_id: String,
+    pub sold: u32,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestonePlanProposedEvent {
+    pub event_id: String,
+    pub organizer: Address,
+    pub plan: Vec<Milestone>,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestonePlanUpdatedEvent {
+    pub event_id: String,
+    pub admin: Address,
+    pub plan: Vec<Milestone>,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InventoryHeldEvent {
+    pub hold_id: String,
+    pub event_id: String,
+    pub tier_id: String,
+    pub quantity: u32,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InventoryHoldReleasedEvent {
+    pub hold_id: String,
+    pub event_id: String,
+    pub tier_id: String,
+    pub quantity: u32,
+    pub timestamp: u64,
+}
@@ -1,24 +1,84 @@
 #![no_std]
 
 use crate::events::{
-    AgoraEvent, EventCancelledEvent, EventPostponedEvent, EventRegisteredEvent,
-    EventStatusUpdatedEvent, EventsSuspendedEvent, FeeUpdatedEvent, GlobalPromoUpdatedEvent,
-    GoalMetEvent, InitializationEvent, InventoryIncrementedEvent, MetadataUpdatedEvent,
-    OrganizerBlacklistedEvent, OrganizerRemovedFromBlacklistEvent, RegistryUpgradedEvent,
-    ScannerAuthorizedEvent,
+    ActionCancelledEvent, ActionExecutedEvent, ActionProposerUpdatedEvent, ActionQueuedEvent,
+    AdminActionApprovedEvent, AdminActionExecutedEvent, AdminActionProposedEvent,
+    AdminTransferStartedEvent, AdminTransferredEvent, AgoraEvent, BundleRegisteredEvent,
+    BundleSoldEvent, BuyerLimitUpdatedEvent, CancellationNoticeFailedEvent, EventCancelledEvent,
+    EventCompletedEvent, EventEndTimeUpdatedEvent, EventFeeOverriddenEvent, EventImportedEvent,
+    EventPostponedEvent, EventRegisteredEvent, EventRescheduledEvent, EventStartTimeUpdatedEvent,
+    EventStatusUpdatedEvent, EventVisibilityUpdatedEvent, EventsBatchRegisteredEvent,
+    EventsSuspendedEvent, FeeUpdatedEvent, FlashSaleScheduledEvent, GlobalPromoUpdatedEvent,
+    GoalMetEvent, InitializationEvent, InventoryHeldEvent, InventoryHoldReleasedEvent,
+    InventoryIncrementedEvent, MetadataBatchUpdatedEvent, MetadataUpdatedEvent,
+    MilestonePlanProposedEvent, MilestonePlanUpdatedEvent, OrganizerActionExecutedEvent,
+    OrganizerActionProposedEvent, OrganizerBlacklistedEvent, OrganizerRegisteredEvent,
+    OrganizerRemovedFromBlacklistEvent, OrganizerVerifiedEvent, PlatformWalletProposedEvent,
+    PlatformWalletUpdatedEvent, PolicyRequirementUpdatedEvent, RefundPolicyUpdatedEvent,
+    RegistryUpgradedEvent, ScannerAuthorizedEvent, SeatMapUpdatedEvent, SeatReleasedEvent,
+    SeatReservedEvent, StorageMigratedEvent, TransferLockUpdatedEvent,
 };
 use crate::types::{
-    BlacklistAuditEntry, EventInfo, EventRegistrationArgs, EventStatus, MultiSigConfig, PaymentInfo,
+    AdminAction, BlacklistAuditEntry, Bundle, ContractMetadata, EventInfo, EventRegistrationArgs,
+    EventSchedule, EventStatus, EventVisibility, FlashSale, ImportProvenance, InventoryHold,
+    Milestone, MultiSigConfig, OrganizerAction, OrganizerProfile, OrganizerProposal, PaymentInfo,
+    PromoMode, Proposal, QueuedAction, RecoveryConfig, RefundPolicy, ScannerWindow, SeatMap,
+    TimelockConfig, DEFAULT_PROPOSAL_EXPIRY_LEDGERS, LEDGER_SECONDS, MAX_BUNDLE_EVENTS,
+};
+use agora_money::{is_valid_bps, MAX_BPS};
+use agora_pagination::PageCursor;
+use soroban_sdk::{
+    contract, contractimpl, contractmeta, xdr::ScErrorType, Address, BytesN, Env, Executable, Map,
+    String, Vec,
 };
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
 
 pub mod error;
 pub mod events;
 pub mod storage;
 pub mod types;
 
+pub use agora_interface::registry::MAX_REFUND_STEPS;
+pub use agora_pagination::MAX_PAGE_SIZE;
+
 use crate::error::EventRegistryError;
 
+/// Interface of the linked ticket_payment contract that the registry calls into.
+pub use agora_interface::payment as ticket_payment;
+
+/// Revision of the public interface exposed via `get_contract_metadata`.
+pub const INTERFACE_REVISION: u32 = 2;
+
+/// Maximum number of events accepted by `register_events_batch`.
+pub const MAX_REGISTRATION_BATCH: u32 = 20;
+
+/// Maximum number of seats in a single tier's seat map.
+pub const MAX_SEATS_PER_TIER: u32 = 500;
+
+/// Maximum number of items accepted by `update_metadata_batch`.
+pub const MAX_METADATA_BATCH: u32 = 25;
+
+/// Most creation-time buckets (days) a `get_events_created_between` range may span.
+pub const MAX_CREATED_RANGE_BUCKETS: u64 = 366;
+
+/// Layout version of the records this build writes. Deployments that predate
+/// versioning are at version 1; `migrate` brings them up to this one.
+/// Version 3 added early-bird and USD pricing to ticket tiers, version 4 the
+/// per-buyer ticket limit of events, version 5 their resale royalty and
+/// version 6 their start time and transfer lock window.
+pub const STORAGE_VERSION: u32 = 6;
+
+contractmeta!(key = "name", val = "agora-event-registry");
+contractmeta!(key = "version", val = env!("CARGO_PKG_VERSION"));
+contractmeta!(key = "interface_revision", val = "2");
+contractmeta!(key = "counterpart", val = "agora-ticket-payment");
+
+/// Registry of events, tiers and inventory.
+///
+/// Organizer entrypoints authorize solely through `organizer_address.require_auth()`
+/// and never inspect the invoker, so an organizer may be a regular account, a
+/// custom account contract, or a governance contract calling in directly. Such
+/// contracts can stage actions with `propose_organizer_action` and apply them with
+/// `execute_organizer_action` once their own approval process completes.
 #[contract]
 pub struct EventRegistry;
 
@@ -51,7 +111,7 @@ impl EventRegistry {
             platform_fee_percent
         };
 
-        if initial_fee > 10000 {
+        if !is_valid_bps(initial_fee) {
             return Err(EventRegistryError::InvalidFeePercent);
         }
 
@@ -67,6 +127,7 @@ impl EventRegistry {
         storage::set_multisig_config(&env, &multisig_config);
         storage::set_platform_wallet(&env, &platform_wallet);
         storage::set_platform_fee(&env, initial_fee);
+        storage::set_storage_version(&env, STORAGE_VERSION);
         storage::set_initialized(&env, true);
 
         env.events().publish(
@@ -96,75 +157,46 @@ impl EventRegistry {
         }
         args.organizer_address.require_auth();
 
-        // Check if organizer is blacklisted
-        if storage::is_blacklisted(&env, &args.organizer_address) {
-            return Err(EventRegistryError::OrganizerBlacklisted);
-        }
-
-        validate_metadata_cid(&env, &args.metadata_cid)?;
+        store_new_event(&env, &args)
+    }
 
-        if storage::event_exists(&env, args.event_id.clone()) {
-            return Err(EventRegistryError::EventAlreadyExists);
+    /// Registers up to `MAX_REGISTRATION_BATCH` events for one organizer in a
+    /// single transaction. Every event is validated as in `register_event`; if
+    /// any fails, none are registered. Emits `EventRegistered` per event plus
+    /// one `EventsBatchRegistered` summary. Returns the number registered.
+    pub fn register_events_batch(
+        env: Env,
+        events: Vec<EventRegistrationArgs>,
+    ) -> Result<u32, EventRegistryError> {
+        if !storage::is_initialized(&env) {
+            return Err(EventRegistryError::NotInitialized);
         }
-
-        // Validate tier limits don't exceed max_supply
-        if args.max_supply > 0 {
-            let mut total_tier_limit: i128 = 0;
-            for tier in args.tiers.values() {
-                total_tier_limit = total_tier_limit
-                    .checked_add(tier.tier_limit)
-                    .ok_or(EventRegistryError::SupplyOverflow)?;
-            }
-            if total_tier_limit > args.max_supply {
-                return Err(EventRegistryError::TierLimitExceedsMaxSupply);
-            }
+        if events.is_empty() || events.len() > MAX_REGISTRATION_BATCH {
+            return Err(EventRegistryError::InvalidBatchSize);
         }
+        let organizer_address = events.get_unchecked(0).organizer_address;
+        organizer_address.require_auth();
 
-        // Validate resale cap if provided
-        if let Some(cap) = args.resale_cap_bps {
-            if cap > 10000 {
-                return Err(EventRegistryError::InvalidResaleCapBps);
+        let mut event_ids = Vec::new(&env);
+        for args in events.iter() {
+            if args.organizer_address != organizer_address {
+                return Err(EventRegistryError::Unauthorized);
             }
+            store_new_event(&env, &args)?;
+            event_ids.push_back(args.event_id);
         }
 
-        let platform_fee_percent = storage::get_platform_fee(&env);
-
-        let event_info = EventInfo {
-            event_id: args.event_id.clone(),
-            organizer_address: args.organizer_address.clone(),
-            payment_address: args.payment_address.clone(),
-            platform_fee_percent,
-            is_active: true,
-            status: EventStatus::Active,
-            created_at: env.ledger().timestamp(),
-            metadata_cid: args.metadata_cid.clone(),
-            max_supply: args.max_supply,
-            current_supply: 0,
-            milestone_plan: args.milestone_plan.clone(),
-            tiers: args.tiers.clone(),
-            refund_deadline: args.refund_deadline,
-            restocking_fee: args.restocking_fee,
-            resale_cap_bps: args.resale_cap_bps,
-            is_postponed: false,
-            grace_period_end: 0,
-            min_sales_target: args.min_sales_target.unwrap_or(0),
-            target_deadline: args.target_deadline.unwrap_or(0),
-            goal_met: false,
-        };
-
-        storage::store_event(&env, event_info);
-
         env.events().publish(
-            (AgoraEvent::EventRegistered,),
-            EventRegisteredEvent {
-                event_id: args.event_id.clone(),
-                organizer_address: args.organizer_address.clone(),
-                payment_address: args.payment_address.clone(),
+            (AgoraEvent::EventsBatchRegistered,),
+            EventsBatchRegisteredEvent {
+                organizer_address,
+                count: event_ids.len(),
+                event_ids,
                 timestamp: env.ledger().timestamp(),
             },
         );
 
-        Ok(())
+        Ok(events.len())
     }
 
     /// Get event payment information including tiered pricing
@@ -172,15 +204,28 @@ impl EventRegistry {
         env: Env,
         event_id: String,
     ) -> Result<PaymentInfo, EventRegistryError> {
-        match storage::get_event(&env, event_id) {
+        match storage::get_event(&env, event_id.clone()) {
             Some(event_info) => {
-                if !event_info.is_active {
+                if !event_info.is_active || event_info.visibility == EventVisibility::Draft {
                     return Err(EventRegistryError::EventInactive);
                 }
+                // Only expose tiers whose visibility window is currently open
+                let now = env.ledger().timestamp();
+                let mut tiers = Map::new(&env);
+                for (tier_id, tier) in event_info.tiers.iter() {
+                    if tier.is_visible_at(now) {
+                        tiers.set(tier_id, tier);
+                    }
+                }
+                // A negotiated per-event rate takes precedence over the registered fee
+                let platform_fee_percent = storage::get_event_fee_override(&env, event_id.clone())
+                    .unwrap_or(event_info.platform_fee_percent);
                 Ok(PaymentInfo {
                     payment_address: event_info.payment_address,
-                    platform_fee_percent: event_info.platform_fee_percent,
-                    tiers: event_info.tiers,
+                    platform_fee_percent,
+                    tiers,
+                    policy_flags: storage::get_policy_flags(&env, event_id),
+                    resale_royalty_bps: event_info.resale_royalty_bps,
                 })
             }
             None => Err(EventRegistryError::EventNotFound),
@@ -198,8 +243,10 @@ impl EventRegistry {
                 // Verify organizer signature
                 event_info.organizer_address.require_auth();
 
-                if matches!(event_info.status, EventStatus::Cancelled) {
-                    return Err(EventRegistryError::EventCancelled);
+                match event_info.status {
+                    EventStatus::Cancelled => return Err(EventRegistryError::EventCancelled),
+                    EventStatus::Completed => return Err(EventRegistryError::EventInactive),
+                    _ => {}
                 }
 
                 // Skip storage/event writes when status is unchanged.
@@ -213,7 +260,7 @@ impl EventRegistry {
 
                 // Emit status update event using contract event type
                 env.events().publish(
-                    (AgoraEvent::EventStatusUpdated,),
+                    (AgoraEvent::EventStatusUpdated, event_id.clone()),
                     EventStatusUpdatedEvent {
                         event_id,
                         is_active,
@@ -235,8 +282,12 @@ impl EventRegistry {
                 // Verify organizer signature
                 event_info.organizer_address.require_auth();
 
-                if matches!(event_info.status, EventStatus::Cancelled) {
-                    return Err(EventRegistryError::EventAlreadyCancelled);
+                match event_info.status {
+                    EventStatus::Cancelled => {
+                        return Err(EventRegistryError::EventAlreadyCancelled)
+                    }
+                    EventStatus::Completed => return Err(EventRegistryError::EventInactive),
+                    _ => {}
                 }
 
                 // Update status to Cancelled and deactivate
@@ -244,9 +295,28 @@ impl EventRegistry {
                 event_info.is_active = false;
                 storage::update_event(&env, event_info.clone());
 
+                // Let the payment contract lock the event's escrow for refunds.
+                // A failing counterpart must not block the cancellation itself,
+                // but the failure is reported so the pool can be opened later.
+                if let Some(ticket_payment_addr) = storage::get_ticket_payment_contract(&env) {
+                    let notice =
+                        ticket_payment::TicketPaymentClient::new(&env, &ticket_payment_addr)
+                            .try_on_event_cancelled(&event_id);
+                    if !matches!(notice, Ok(Ok(()))) {
+                        env.events().publish(
+                            (AgoraEvent::CancellationNoticeFailed, event_id.clone()),
+                            CancellationNoticeFailedEvent {
+                                event_id: event_id.clone(),
+                                ticket_payment: ticket_payment_addr,
+                                timestamp: env.ledger().timestamp(),
+                            },
+                        );
+                    }
+                }
+
                 // Emit cancellation event
                 env.events().publish(
-                    (AgoraEvent::EventCancelled,),
+                    (AgoraEvent::EventCancelled, event_id.clone()),
                     EventCancelledEvent {
                         event_id,
                         cancelled_by: event_info.organizer_address,
@@ -260,6 +330,107 @@ impl EventRegistry {
         }
     }
 
+    /// Update the listing visibility of an event (only by organizer).
+    /// Draft events cannot be purchased; Unlisted events sell normally but are
+    /// excluded from `list_events`.
+    pub fn set_event_visibility(
+        env: Env,
+        event_id: String,
+        visibility: EventVisibility,
+    ) -> Result<(), EventRegistryError> {
+        let mut event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+
+        event_info.organizer_address.require_auth();
+
+        if matches!(event_info.status, EventStatus::Cancelled) {
+            return Err(EventRegistryError::EventCancelled);
+        }
+
+        // Skip storage/event writes when visibility is unchanged.
+        if event_info.visibility == visibility {
+            return Ok(());
+        }
+
+        event_info.visibility = visibility.clone();
+        storage::update_event(&env, event_info.clone());
+
+        env.events().publish(
+            (AgoraEvent::EventVisibilityUpdated, event_id.clone()),
+            EventVisibilityUpdatedEvent {
+                event_id,
+                visibility,
+                updated_by: event_info.organizer_address,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Sets the most tickets a single buyer may hold for an event, enforced at
+    /// purchase by the payment contract; 0 removes the limit. Only by organizer.
+    pub fn set_max_tickets_per_buyer(
+        env: Env,
+        event_id: String,
+        max_tickets: u32,
+    ) -> Result<(), EventRegistryError> {
+        let mut event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        event_info.organizer_address.require_auth();
+
+        if matches!(event_info.status, EventStatus::Cancelled) {
+            return Err(EventRegistryError::EventCancelled);
+        }
+
+        event_info.max_tickets_per_buyer = max_tickets;
+        storage::update_event(&env, event_info.clone());
+
+        env.events().publish(
+            (AgoraEvent::BuyerLimitUpdated, event_id.clone()),
+            BuyerLimitUpdatedEvent {
+                event_id,
+                max_tickets_per_buyer: max_tickets,
+                updated_by: event_info.organizer_address,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Sets how many seconds before the event's start time its tickets stop
+    /// changing hands, enforced by the payment contract unless the organizer
+    /// approves a transfer; 0 removes the lock. Only by organizer.
+    pub fn set_transfer_lock_before_secs(
+        env: Env,
+        event_id: String,
+        lock_before_secs: u64,
+    ) -> Result<(), EventRegistryError> {
+        let mut event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        event_info.organizer_address.require_auth();
+
+        if matches!(event_info.status, EventStatus::Cancelled) {
+            return Err(EventRegistryError::EventCancelled);
+        }
+
+        event_info.transfer_lock_before_secs = lock_before_secs;
+        storage::update_event(&env, event_info.clone());
+
+        env.events().publish(
+            (AgoraEvent::TransferLockUpdated, event_id.clone()),
+            TransferLockUpdatedEvent {
+                event_id,
+                transfer_lock_before_secs: lock_before_secs,
+                updated_by: event_info.organizer_address,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
     /// Update the decentralized metadata CID for an event (only by organizer)
     pub fn update_metadata(
         env: Env,
@@ -285,7 +456,7 @@ impl EventRegistry {
 
                 // Emit metadata update event
                 env.events().publish(
-                    (AgoraEvent::MetadataUpdated,),
+                    (AgoraEvent::MetadataUpdated, event_id.clone()),
                     MetadataUpdatedEvent {
                         event_id,
                         new_metadata_cid,
@@ -300,6 +471,62 @@ impl EventRegistry {
         }
     }
 
+    /// Updates the metadata CID of several of the organizer's events in one call.
+    ///
+    /// Items are validated individually: unknown events, events owned by another
+    /// organizer and invalid CIDs are skipped and reported in a single
+    /// `MetadataBatchUpdated` event rather than failing the whole batch.
+    /// Returns the number of events whose metadata changed.
+    pub fn update_metadata_batch(
+        env: Env,
+        organizer_address: Address,
+        updates: Vec<(String, String)>,
+    ) -> Result<u32, EventRegistryError> {
+        organizer_address.require_auth();
+
+        if updates.is_empty() || updates.len() > MAX_METADATA_BATCH {
+            return Err(EventRegistryError::InvalidBatchSize);
+        }
+
+        let mut updated_count = 0u32;
+        let mut unchanged_count = 0u32;
+        let mut failed_event_ids = Vec::new(&env);
+        for (event_id, new_metadata_cid) in updates.iter() {
+            let mut event_info = match storage::get_event(&env, event_id.clone()) {
+                Some(event_info) if event_info.organizer_address == organizer_address => event_info,
+                _ => {
+                    failed_event_ids.push_back(event_id);
+                    continue;
+                }
+            };
+            if validate_metadata_cid(&env, &new_metadata_cid).is_err() {
+                failed_event_ids.push_back(event_id);
+                continue;
+            }
+            if event_info.metadata_cid == new_metadata_cid {
+                unchanged_count += 1;
+                continue;
+            }
+
+            event_info.metadata_cid = new_metadata_cid;
+            storage::update_event(&env, event_info);
+            updated_count += 1;
+        }
+
+        env.events().publish(
+            (AgoraEvent::MetadataBatchUpdated,),
+            MetadataBatchUpdatedEvent {
+                organizer_address,
+                updated_count,
+                unchanged_count,
+                failed_event_ids,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(updated_count)
+    }
+
     /// Stores or updates an event (legacy function for backward compatibility).
     pub fn store_event(env: Env, event_info: EventInfo) {
         // Require authorization to ensure only the organizer can store/update their event directly
@@ -307,6 +534,76 @@ impl EventRegistry {
         storage::store_event(&env, event_info);
     }
 
+    /// Imports a fully-specified event exported from a legacy registry deployment.
+    /// Only callable by the administrator. Unlike `store_event`, the record is
+    /// validated against the same invariants `register_event` and inventory
+    /// updates maintain, and each event_id can only be imported once.
+    ///
+    /// # Arguments
+    /// * `event_info` - The event as it existed on the legacy deployment.
+    /// * `source_contract` - Address of the legacy registry.
+    /// * `source_ledger` - Ledger sequence the legacy state was read at.
+    pub fn import_event(
+        env: Env,
+        event_info: EventInfo,
+        source_contract: Address,
+        source_ledger: u32,
+    ) -> Result<(), EventRegistryError> {
+        let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
+        admin.require_auth();
+
+        let event_id = event_info.event_id.clone();
+        if storage::get_import_provenance(&env, event_id.clone()).is_some() {
+            return Err(EventRegistryError::EventAlreadyImported);
+        }
+        if storage::event_exists(&env, event_id.clone()) {
+            return Err(EventRegistryError::EventAlreadyExists);
+        }
+        if source_contract == env.current_contract_address() {
+            return Err(EventRegistryError::InvalidAddress);
+        }
+        if source_ledger > env.ledger().sequence() {
+            return Err(EventRegistryError::InvalidImportedEvent);
+        }
+        if storage::is_blacklisted(&env, &event_info.organizer_address) {
+            return Err(EventRegistryError::OrganizerBlacklisted);
+        }
+        validate_metadata_cid(&env, &event_info.metadata_cid)?;
+        validate_imported_event(&event_info)?;
+
+        let organizer_address = event_info.organizer_address.clone();
+        storage::store_event(&env, event_info);
+        storage::set_import_provenance(
+            &env,
+            event_id.clone(),
+            &ImportProvenance {
+                source_contract: source_contract.clone(),
+                source_ledger,
+                imported_by: admin.clone(),
+                imported_at: env.ledger().timestamp(),
+            },
+        );
+
+        env.events().publish(
+            (AgoraEvent::EventImported, event_id.clone()),
+            EventImportedEvent {
+                event_id,
+                organizer_address,
+                source_contract,
+                source_ledger,
+                imported_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Returns where an imported event came from, or None for native events.
+    pub fn get_import_provenance(env: Env, event_id: String) -> Option<ImportProvenance> {
+        storage::get_import_provenance(&env, event_id)
+    }
+
     /// Retrieves an event by its ID.
     pub fn get_event(env: Env, event_id: String) -> Option<EventInfo> {
         storage::get_event(&env, event_id)
@@ -317,34 +614,184 @@ impl EventRegistry {
         storage::event_exists(&env, event_id)
     }
 
+    /// Extends the storage TTL of an event and its per-event configuration so
+    /// long-lived events are not archived between sales. Callable by anyone.
+    pub fn bump_event(env: Env, event_id: String) -> Result<(), EventRegistryError> {
+        if !storage::extend_event_ttl(&env, event_id) {
+            return Err(EventRegistryError::EventNotFound);
+        }
+        Ok(())
+    }
+
+    /// Retrieves publicly listed event IDs from the first `MAX_PAGE_SIZE`
+    /// entries of the global index only; events registered after those are
+    /// not returned. Use `list_events_paginated` to read the whole index.
+    #[deprecated(note = "truncated to the first index page; use list_events_paginated")]
+    pub fn list_events(env: Env) -> Vec<String> {
+        Self::list_events_paginated(env, 0, MAX_PAGE_SIZE)
+    }
+
+    /// Retrieves publicly listed event IDs from `limit` positions of the global
+    /// index starting at `offset` (registration order). Unlisted and Draft events
+    /// are skipped, so a page may be shorter than `limit`; advance `offset` by
+    /// `limit` until it reaches `get_event_count`.
+    pub fn list_events_paginated(env: Env, offset: u32, limit: u32) -> Vec<String> {
+        let mut listed = Vec::new(&env);
+        for event_id in storage::get_events_page(&env, PageCursor::new(offset, limit)).iter() {
+            if let Some(event_info) = storage::get_event(&env, event_id.clone()) {
+                if event_info.visibility == EventVisibility::Public {
+                    listed.push_back(event_id);
+                }
+            }
+        }
+        listed
+    }
+
+    /// Returns the number of entries in the global event index.
+    pub fn get_event_count(env: Env) -> u32 {
+        storage::get_global_event_count(&env)
+    }
+
     /// Retrieves all event IDs for an organizer.
     pub fn get_organizer_events(env: Env, organizer: Address) -> Vec<String> {
         storage::get_organizer_events(&env, &organizer)
     }
 
-    /// Updates the platform fee percentage. Only callable by the administrator.
-    pub fn set_platform_fee(env: Env, new_fee_percent: u32) -> Result<(), EventRegistryError> {
+    /// Retrieves up to `limit` of an organizer's event IDs starting at `offset`.
+    pub fn get_organizer_events_paginated(
+        env: Env,
+        organizer: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<String> {
+        storage::get_organizer_events_page(&env, &organizer, PageCursor::new(offset, limit))
+    }
+
+    /// Returns how many events an organizer has registered.
+    pub fn get_organizer_event_count(env: Env, organizer: Address) -> u32 {
+        storage::get_organizer_event_count(&env, &organizer)
+    }
+
+    /// Retrieves up to `limit` IDs of the events currently in `status`, starting
+    /// at `offset`. Unlisted and Draft events are included. The index is not
+    /// ordered: an event leaving a status is replaced by that status's last entry.
+    pub fn get_events_by_status(
+        env: Env,
+        status: EventStatus,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<String> {
+        storage::get_status_events_page(&env, &status, PageCursor::new(offset, limit))
+    }
+
+    /// Returns how many events are currently in `status`.
+    pub fn get_status_event_count(env: Env, status: EventStatus) -> u32 {
+        storage::get_status_event_count(&env, &status)
+    }
+
+    /// Retrieves up to `limit` IDs of the events created between `from` and `to`
+    /// (inclusive), in creation order, skipping the first `offset` matches.
+    /// Unlisted and Draft events are included.
+    ///
+    /// # Errors
+    /// `InvalidTimeRange` if `from` is after `to` or the range spans more than
+    /// `MAX_CREATED_RANGE_BUCKETS` days.
+    pub fn get_events_created_between(
+        env: Env,
+        from: u64,
+        to: u64,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<String>, EventRegistryError> {
+        let first = from / storage::CREATED_BUCKET_SECS;
+        let last = to / storage::CREATED_BUCKET_SECS;
+        if from > to || last - first >= MAX_CREATED_RANGE_BUCKETS {
+            return Err(EventRegistryError::InvalidTimeRange);
+        }
+
+        // Only the boundary buckets can hold events outside the range
+        let matches = (first..=last).flat_map(|bucket| {
+            let partial = bucket == first || bucket == last;
+            let env = env.clone();
+            storage::get_created_bucket(&env, bucket)
+                .into_iter()
+                .filter(move |event_id| {
+                    !partial
+                        || storage::get_event(&env, event_id.clone())
+                            .is_some_and(|info| info.created_at >= from && info.created_at <= to)
+                })
+        });
+        Ok(agora_pagination::page_from_iter(
+            &env,
+            PageCursor::new(offset, limit),
+            matches,
+        ))
+    }
+
+    /// Adds up to `limit` events of the global index, starting at `offset`, to
+    /// the search indices. Events registered before the indices existed only
+    /// join them on their next update; this backfills them. Only callable by
+    /// the administrator.
+    pub fn reindex_events(env: Env, offset: u32, limit: u32) -> Result<(), EventRegistryError> {
         let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
         admin.require_auth();
+        for event_id in storage::get_events_page(&env, PageCursor::new(offset, limit)).iter() {
+            if let Some(event_info) = storage::get_event(&env, event_id) {
+                storage::index_event(&env, &event_info);
+            }
+        }
+        Ok(())
+    }
+
+    /// Updates the platform fee percentage. Only callable by the administrator.
+    /// Once the multi-sig threshold exceeds 1, use `propose_action` instead;
+    /// fee changes beyond the timelock's delta must go through `queue_action`.
+    pub fn set_platform_fee(env: Env, new_fee_percent: u32) -> Result<(), EventRegistryError> {
+        require_sole_admin(&env)?;
+        if is_timelocked(&env, &AdminAction::SetPlatformFee(new_fee_percent)) {
+            return Err(EventRegistryError::ActionTimelocked);
+        }
+        apply_platform_fee(&env, new_fee_percent)
+    }
+
+    /// Returns the current platform fee percentage.
+    pub fn get_platform_fee(env: Env) -> u32 {
+        storage::get_platform_fee(&env)
+    }
 
-        if new_fee_percent > 10000 {
+    /// Sets a negotiated platform fee for a single event, used by
+    /// `get_event_payment_info` in place of the fee captured at registration.
+    /// Only callable by the administrator; the override may not exceed the
+    /// event's registered fee.
+    pub fn set_event_fee_override(
+        env: Env,
+        event_id: String,
+        fee_bps: u32,
+    ) -> Result<(), EventRegistryError> {
+        require_sole_admin(&env)?;
+        let event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        if fee_bps > event_info.platform_fee_percent {
             return Err(EventRegistryError::InvalidFeePercent);
         }
 
-        storage::set_platform_fee(&env, new_fee_percent);
+        storage::set_event_fee_override(&env, event_id.clone(), fee_bps);
 
-        // Emit fee update event using contract event type
+        #[allow(deprecated)]
         env.events().publish(
-            (AgoraEvent::FeeUpdated,),
-            FeeUpdatedEvent { new_fee_percent },
+            (AgoraEvent::EventFeeOverridden, event_id.clone()),
+            EventFeeOverriddenEvent {
+                event_id,
+                fee_bps,
+                timestamp: env.ledger().timestamp(),
+            },
         );
-
         Ok(())
     }
 
-    /// Returns the current platform fee percentage.
-    pub fn get_platform_fee(env: Env) -> u32 {
-        storage::get_platform_fee(&env)
+    /// Returns the negotiated platform fee of an event, if one was set.
+    pub fn get_event_fee_override(env: Env, event_id: String) -> Option<u32> {
+        storage::get_event_fee_override(&env, event_id)
     }
 
     /// Returns the current administrator address.
@@ -357,47 +804,193 @@ impl EventRegistry {
         storage::get_platform_wallet(&env).ok_or(EventRegistryError::NotInitialized)
     }
 
-    /// Sets the authorized TicketPayment contract address. Only callable by the administrator.
-    ///
-    /// # Arguments
-    /// * `ticket_payment_address` - The address of the TicketPayment contract authorized
-    ///   to call `increment_inventory`.
-    pub fn set_ticket_payment_contract(
-        env: Env,
-        ticket_payment_address: Address,
-    ) -> Result<(), EventRegistryError> {
-        let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
-        admin.require_auth();
+    /// Nominates a new administrator, who replaces the current one in the admin
+    /// set once they call `accept_admin`. Replaces any earlier nomination.
+    /// Only callable by the administrator while the multi-sig threshold is 1.
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), EventRegistryError> {
+        let admin = require_sole_admin(&env)?;
+        validate_address(&env, &new_admin)?;
+        if storage::is_admin(&env, &new_admin) {
+            return Err(EventRegistryError::AdminAlreadyExists);
+        }
+        storage::set_pending_admin(&env, Some(new_admin.clone()));
 
-        validate_address(&env, &ticket_payment_address)?;
+        env.events().publish(
+            (AgoraEvent::AdminTransferStarted,),
+            AdminTransferStartedEvent {
+                admin,
+                pending_admin: Some(new_admin),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
 
-        storage::set_ticket_payment_contract(&env, &ticket_payment_address);
         Ok(())
     }
 
-    /// Returns the authorized TicketPayment contract address.
-    pub fn get_ticket_payment_contract(env: Env) -> Result<Address, EventRegistryError> {
-        storage::get_ticket_payment_contract(&env).ok_or(EventRegistryError::NotInitialized)
+    /// Completes a pending admin transfer. Must be authorized by the nominee.
+    pub fn accept_admin(env: Env) -> Result<(), EventRegistryError> {
+        let new_admin =
+            storage::get_pending_admin(&env).ok_or(EventRegistryError::ProposalNotFound)?;
+        new_admin.require_auth();
+
+        let old_admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
+        let mut config =
+            storage::get_multisig_config(&env).ok_or(EventRegistryError::NotInitialized)?;
+        match config.admins.first_index_of(&old_admin) {
+            Some(index) => config.admins.set(index, new_admin.clone()),
+            None => config.admins.push_back(new_admin.clone()),
+        }
+        storage::set_multisig_config(&env, &config);
+        storage::set_admin(&env, &new_admin);
+        storage::set_pending_admin(&env, None);
+
+        env.events().publish(
+            (AgoraEvent::AdminTransferred,),
+            AdminTransferredEvent {
+                old_admin,
+                new_admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
     }
 
-    /// Increments the current_supply counter for a given event and tier.
-    /// This function is restricted to calls from the authorized TicketPayment contract.
-    ///
-    /// # Arguments
-    /// * `event_id` - The event whose inventory to increment.
-    /// * `tier_id` - The tier whose inventory to increment.
-    ///
-    /// # Errors
-    /// * `UnauthorizedCaller` - If the invoker is not the registered TicketPayment contract.
-    /// * `EventNotFound` - If no event with the given ID exists.
-    /// * `EventInactive` - If the event is not currently active.
-    /// * `TierNotFound` - If the tier does not exist.
-    /// * `TierSupplyExceeded` - If the tier's limit has been reached.
-    /// * `MaxSupplyExceeded` - If the event's max supply has been reached (when max_supply > 0).
-    /// * `SupplyOverflow` - If incrementing would cause an i128 overflow.
-    pub fn increment_inventory(
-        env: Env,
-        event_id: String,
+    /// Withdraws a pending admin nomination before it is accepted.
+    pub fn cancel_admin_transfer(env: Env) -> Result<(), EventRegistryError> {
+        let admin = require_sole_admin(&env)?;
+        if storage::get_pending_admin(&env).is_none() {
+            return Err(EventRegistryError::ProposalNotFound);
+        }
+        storage::set_pending_admin(&env, None);
+
+        env.events().publish(
+            (AgoraEvent::AdminTransferStarted,),
+            AdminTransferStartedEvent {
+                admin,
+                pending_admin: None,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Returns the administrator nominated by `transfer_admin`, if any.
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        storage::get_pending_admin(&env)
+    }
+
+    /// Proposes a new platform wallet, which takes effect once the wallet itself
+    /// calls `accept_platform_wallet`. Replaces any earlier pending proposal.
+    /// Only callable by the administrator while the multi-sig threshold is 1
+    /// and no timelock is configured; otherwise use `SetPlatformWallet`.
+    pub fn propose_platform_wallet(
+        env: Env,
+        new_wallet: Address,
+    ) -> Result<(), EventRegistryError> {
+        require_sole_admin(&env)?;
+        if is_timelocked(&env, &AdminAction::SetPlatformWallet(new_wallet.clone())) {
+            return Err(EventRegistryError::ActionTimelocked);
+        }
+        validate_address(&env, &new_wallet)?;
+        let current_wallet =
+            storage::get_platform_wallet(&env).ok_or(EventRegistryError::NotInitialized)?;
+        storage::set_pending_platform_wallet(&env, Some(new_wallet.clone()));
+
+        env.events().publish(
+            (AgoraEvent::PlatformWalletProposed,),
+            PlatformWalletProposedEvent {
+                current_wallet,
+                proposed_wallet: new_wallet,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Makes the pending platform wallet the platform wallet. Must be
+    /// authorized by the pending wallet, proving it is controlled.
+    pub fn accept_platform_wallet(env: Env) -> Result<(), EventRegistryError> {
+        let new_wallet = storage::get_pending_platform_wallet(&env)
+            .ok_or(EventRegistryError::ProposalNotFound)?;
+        new_wallet.require_auth();
+
+        let old_wallet =
+            storage::get_platform_wallet(&env).ok_or(EventRegistryError::NotInitialized)?;
+        storage::set_platform_wallet(&env, &new_wallet);
+        storage::set_pending_platform_wallet(&env, None);
+
+        env.events().publish(
+            (AgoraEvent::PlatformWalletUpdated,),
+            PlatformWalletUpdatedEvent {
+                old_wallet,
+                new_wallet,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Returns the proposed platform wallet awaiting acceptance, if any.
+    pub fn get_pending_platform_wallet(env: Env) -> Option<Address> {
+        storage::get_pending_platform_wallet(&env)
+    }
+
+    /// Sets the authorized TicketPayment contract address. Only callable by the administrator.
+    ///
+    /// # Arguments
+    /// * `ticket_payment_address` - The address of the TicketPayment contract authorized
+    ///   to call `increment_inventory`.
+    pub fn set_ticket_payment_contract(
+        env: Env,
+        ticket_payment_address: Address,
+    ) -> Result<(), EventRegistryError> {
+        let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
+        admin.require_auth();
+
+        validate_address(&env, &ticket_payment_address)?;
+
+        storage::set_ticket_payment_contract(&env, &ticket_payment_address);
+        Ok(())
+    }
+
+    /// Returns the authorized TicketPayment contract address.
+    pub fn get_ticket_payment_contract(env: Env) -> Result<Address, EventRegistryError> {
+        storage::get_ticket_payment_contract(&env).ok_or(EventRegistryError::NotInitialized)
+    }
+
+    /// Returns the contract's name, version, interface revision and the
+    /// configured TicketPayment counterpart, for explorers and tooling.
+    pub fn get_contract_metadata(env: Env) -> ContractMetadata {
+        ContractMetadata {
+            name: String::from_str(&env, "agora-event-registry"),
+            version: String::from_str(&env, env!("CARGO_PKG_VERSION")),
+            interface_revision: INTERFACE_REVISION,
+            counterpart: storage::get_ticket_payment_contract(&env),
+        }
+    }
+
+    /// Increments the current_supply counter for a given event and tier.
+    /// This function is restricted to calls from the authorized TicketPayment contract.
+    ///
+    /// # Arguments
+    /// * `event_id` - The event whose inventory to increment.
+    /// * `tier_id` - The tier whose inventory to increment.
+    ///
+    /// # Errors
+    /// * `UnauthorizedCaller` - If the invoker is not the registered TicketPayment contract.
+    /// * `EventNotFound` - If no event with the given ID exists.
+    /// * `EventInactive` - If the event is not currently active.
+    /// * `TierNotFound` - If the tier does not exist.
+    /// * `TierSupplyExceeded` - If the tier's limit has been reached.
+    /// * `MaxSupplyExceeded` - If the event's max supply has been reached (when max_supply > 0).
+    /// * `SupplyOverflow` - If incrementing would cause an i128 overflow.
+    pub fn increment_inventory(
+        env: Env,
+        event_id: String,
         tier_id: String,
         quantity: u32,
     ) -> Result<(), EventRegistryError> {
@@ -412,17 +1005,23 @@ impl EventRegistry {
         let mut event_info =
             storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
 
-        if !event_info.is_active || matches!(event_info.status, EventStatus::Cancelled) {
+        if !event_info.is_active
+            || matches!(event_info.status, EventStatus::Cancelled)
+            || event_info.visibility == EventVisibility::Draft
+        {
             return Err(EventRegistryError::EventInactive);
         }
 
         let quantity_i128 = quantity as i128;
+        // Tickets on hold for other buyers are not available to this sale
+        let (tier_held, event_held) = active_holds(&env, &event_id, &tier_id, None);
 
         // Check global supply limits
         if event_info.max_supply > 0 {
             let new_total_supply = event_info
                 .current_supply
                 .checked_add(quantity_i128)
+                .and_then(|supply| supply.checked_add(event_held))
                 .ok_or(EventRegistryError::SupplyOverflow)?;
             if new_total_supply > event_info.max_supply {
                 return Err(EventRegistryError::MaxSupplyExceeded);
@@ -440,7 +1039,11 @@ impl EventRegistry {
             .checked_add(quantity_i128)
             .ok_or(EventRegistryError::SupplyOverflow)?;
 
-        if new_tier_sold > tier.tier_limit {
+        if new_tier_sold
+            .checked_add(tier_held)
+            .ok_or(EventRegistryError::SupplyOverflow)?
+            > tier.tier_limit
+        {
             return Err(EventRegistryError::TierSupplyExceeded);
         }
 
@@ -461,7 +1064,7 @@ impl EventRegistry {
         {
             event_info.goal_met = true;
             env.events().publish(
-                (AgoraEvent::GoalMet,),
+                (AgoraEvent::GoalMet, event_id.clone()),
                 GoalMetEvent {
                     event_id: event_id.clone(),
                     min_sales_target: event_info.min_sales_target,
@@ -474,7 +1077,7 @@ impl EventRegistry {
         storage::update_event(&env, event_info);
 
         env.events().publish(
-            (AgoraEvent::InventoryIncremented,),
+            (AgoraEvent::InventoryIncremented, event_id.clone()),
             InventoryIncrementedEvent {
                 event_id,
                 new_supply,
@@ -539,7 +1142,10 @@ impl EventRegistry {
         storage::update_event(&env, event_info);
 
         env.events().publish(
-            (crate::events::AgoraEvent::InventoryDecremented,),
+            (
+                crate::events::AgoraEvent::InventoryDecremented,
+                event_id.clone(),
+            ),
             crate::events::InventoryDecrementedEvent {
                 event_id,
                 new_supply,
@@ -552,126 +1158,189 @@ impl EventRegistry {
 
     /// Upgrades the contract to a new WASM hash. Only callable by the administrator.
     /// Performs post-upgrade state verification to ensure critical storage is intact.
+    /// Once the multi-sig threshold exceeds 1, use `propose_action` instead; while
+    /// a timelock is configured, use `queue_action`.
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), EventRegistryError> {
-        let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
-        admin.require_auth();
-
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        require_sole_admin(&env)?;
+        if is_timelocked(&env, &AdminAction::Upgrade(new_wasm_hash.clone())) {
+            return Err(EventRegistryError::ActionTimelocked);
+        }
+        apply_upgrade(&env, new_wasm_hash)
+    }
 
-        // Post-upgrade state verification
-        let verified_admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
-        storage::get_platform_wallet(&env).ok_or(EventRegistryError::NotInitialized)?;
+    /// Records that the storage layout was brought from `from_version` up to
+    /// `STORAGE_VERSION` after an upgrade. Event records written under older
+    /// layouts are converted lazily on read and rewritten on their next update,
+    /// so no per-record work is done here. Only callable by the administrator
+    /// while the multi-sig threshold is 1.
+    pub fn migrate(env: Env, from_version: u32) -> Result<(), EventRegistryError> {
+        let admin = require_sole_admin(&env)?;
+        if from_version != storage::get_storage_version(&env) || from_version >= STORAGE_VERSION {
+            return Err(EventRegistryError::InvalidStorageVersion);
+        }
+        storage::set_storage_version(&env, STORAGE_VERSION);
 
+        #[allow(deprecated)]
         env.events().publish(
-            (AgoraEvent::ContractUpgraded,),
-            RegistryUpgradedEvent {
-                admin_address: verified_admin,
+            (AgoraEvent::StorageMigrated,),
+            StorageMigratedEvent {
+                from_version,
+                to_version: STORAGE_VERSION,
+                admin_address: admin,
                 timestamp: env.ledger().timestamp(),
             },
         );
-
         Ok(())
     }
 
+    /// Returns the layout version the contract's storage was last migrated to.
+    pub fn get_storage_version(env: Env) -> u32 {
+        storage::get_storage_version(&env)
+    }
+
     /// Adds an organizer to the blacklist with mandatory audit logging.
-    /// Only callable by the administrator.
+    /// Only callable by the administrator while the multi-sig threshold is 1.
     pub fn blacklist_organizer(
         env: Env,
         organizer_address: Address,
         reason: String,
     ) -> Result<(), EventRegistryError> {
-        let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
-        admin.require_auth();
+        let admin = require_sole_admin(&env)?;
+        apply_blacklist(&env, admin, organizer_address, reason)
+    }
 
-        validate_address(&env, &organizer_address)?;
+    /// Removes an organizer from the blacklist with mandatory audit logging.
+    /// Only callable by the administrator while the multi-sig threshold is 1.
+    pub fn remove_from_blacklist(
+        env: Env,
+        organizer_address: Address,
+        reason: String,
+    ) -> Result<(), EventRegistryError> {
+        let admin = require_sole_admin(&env)?;
+        apply_unblacklist(&env, admin, organizer_address, reason)
+    }
 
-        // Check if already blacklisted
-        if storage::is_blacklisted(&env, &organizer_address) {
+    /// Registers or updates the caller's organizer profile. Changing the
+    /// profile CID of a verified organizer clears its verification.
+    pub fn register_organizer(
+        env: Env,
+        organizer: Address,
+        profile_cid: String,
+    ) -> Result<(), EventRegistryError> {
+        organizer.require_auth();
+        validate_address(&env, &organizer)?;
+        validate_metadata_cid(&env, &profile_cid)?;
+        if storage::is_blacklisted(&env, &organizer) {
             return Err(EventRegistryError::OrganizerBlacklisted);
         }
 
-        // Add to blacklist
-        storage::add_to_blacklist(&env, &organizer_address);
-
-        // Create audit log entry
-        let audit_entry = BlacklistAuditEntry {
-            organizer_address: organizer_address.clone(),
-            added_to_blacklist: true,
-            admin_address: admin.clone(),
-            reason: reason.clone(),
-            timestamp: env.ledger().timestamp(),
+        let now = env.ledger().timestamp();
+        let profile = match storage::get_organizer_profile(&env, &organizer) {
+            Some(profile) if profile.profile_cid == profile_cid => profile,
+            Some(profile) => OrganizerProfile {
+                profile_cid: profile_cid.clone(),
+                verified: false,
+                ..profile
+            },
+            None => OrganizerProfile {
+                organizer: organizer.clone(),
+                profile_cid: profile_cid.clone(),
+                verified: false,
+                registered_at: now,
+                verified_at: 0,
+            },
         };
-        storage::add_blacklist_audit_entry(&env, audit_entry);
+        storage::set_organizer_profile(&env, &profile);
 
-        // Emit blacklist event
+        #[allow(deprecated)]
         env.events().publish(
-            (AgoraEvent::OrganizerBlacklisted,),
-            OrganizerBlacklistedEvent {
-                organizer_address: organizer_address.clone(),
-                admin_address: admin.clone(),
-                reason: reason.clone(),
-                timestamp: env.ledger().timestamp(),
+            (AgoraEvent::OrganizerRegistered,),
+            OrganizerRegisteredEvent {
+                organizer,
+                profile_cid,
+                timestamp: now,
             },
         );
-
-        // Suspend all active events from this organizer
-        suspend_organizer_events(env.clone(), organizer_address)?;
-
         Ok(())
     }
 
-    /// Removes an organizer from the blacklist with mandatory audit logging.
-    /// Only callable by the administrator.
-    pub fn remove_from_blacklist(
-        env: Env,
-        organizer_address: Address,
-        reason: String,
-    ) -> Result<(), EventRegistryError> {
-        let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
-        admin.require_auth();
-
-        validate_address(&env, &organizer_address)?;
-
-        // Check if currently blacklisted
-        if !storage::is_blacklisted(&env, &organizer_address) {
-            return Err(EventRegistryError::OrganizerNotBlacklisted);
+    /// Marks a registered organizer as verified. Only callable by the
+    /// administrator while the multi-sig threshold is 1.
+    pub fn verify_organizer(env: Env, organizer: Address) -> Result<(), EventRegistryError> {
+        let admin = require_sole_admin(&env)?;
+        if storage::is_blacklisted(&env, &organizer) {
+            return Err(EventRegistryError::OrganizerBlacklisted);
         }
+        let mut profile = storage::get_organizer_profile(&env, &organizer)
+            .ok_or(EventRegistryError::InvalidAddress)?;
 
-        // Remove from blacklist
-        storage::remove_from_blacklist(&env, &organizer_address);
-
-        // Create audit log entry
-        let audit_entry = BlacklistAuditEntry {
-            organizer_address: organizer_address.clone(),
-            added_to_blacklist: false,
-            admin_address: admin.clone(),
-            reason: reason.clone(),
-            timestamp: env.ledger().timestamp(),
-        };
-        storage::add_blacklist_audit_entry(&env, audit_entry);
+        let now = env.ledger().timestamp();
+        profile.verified = true;
+        profile.verified_at = now;
+        storage::set_organizer_profile(&env, &profile);
 
-        // Emit removal event
+        #[allow(deprecated)]
         env.events().publish(
-            (AgoraEvent::OrganizerRemovedFromBlacklist,),
-            OrganizerRemovedFromBlacklistEvent {
-                organizer_address,
-                admin_address: admin,
-                reason,
-                timestamp: env.ledger().timestamp(),
+            (AgoraEvent::OrganizerVerified,),
+            OrganizerVerifiedEvent {
+                organizer,
+                admin,
+                timestamp: now,
             },
         );
-
         Ok(())
     }
 
+    /// Returns an organizer's profile, if it registered one.
+    pub fn get_organizer_profile(env: Env, organizer: Address) -> Option<OrganizerProfile> {
+        storage::get_organizer_profile(&env, &organizer)
+    }
+
+    /// Checks if an organizer is verified and not blacklisted.
+    pub fn is_organizer_verified(env: Env, organizer: Address) -> bool {
+        storage::is_verified_organizer(&env, &organizer)
+    }
+
     /// Checks if an organizer is blacklisted.
     pub fn is_organizer_blacklisted(env: Env, organizer_address: Address) -> bool {
         storage::is_blacklisted(&env, &organizer_address)
     }
 
-    /// Retrieves the blacklist audit log.
+    /// Retrieves the first page of the blacklist audit log. Use
+    /// `get_blacklist_log_paginated` to read past it.
     pub fn get_blacklist_audit_log(env: Env) -> Vec<BlacklistAuditEntry> {
-        storage::get_blacklist_audit_log(&env)
+        storage::get_blacklist_audit_log_page(&env, PageCursor::new(0, MAX_PAGE_SIZE))
+    }
+
+    /// Retrieves up to `limit` blacklist audit entries starting at `offset`,
+    /// oldest first.
+    pub fn get_blacklist_log_paginated(
+        env: Env,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<BlacklistAuditEntry> {
+        storage::get_blacklist_audit_log_page(&env, PageCursor::new(offset, limit))
+    }
+
+    /// Returns the number of entries in the blacklist audit log.
+    pub fn get_blacklist_audit_log_count(env: Env) -> u32 {
+        storage::get_blacklist_audit_log_count(&env)
+    }
+
+    /// Retrieves up to `limit` of an organizer's blacklist audit entries
+    /// starting at `offset`, oldest first.
+    pub fn get_blacklist_history(
+        env: Env,
+        organizer: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<BlacklistAuditEntry> {
+        storage::get_blacklist_history_page(&env, &organizer, PageCursor::new(offset, limit))
+    }
+
+    /// Returns the number of blacklist audit entries recorded for an organizer.
+    pub fn get_blacklist_history_count(env: Env, organizer: Address) -> u32 {
+        storage::get_blacklist_history_count(&env, &organizer)
     }
 
     /// Sets a platform-wide promotional discount. Only callable by the administrator.
@@ -688,7 +1357,7 @@ impl EventRegistry {
         let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
         admin.require_auth();
 
-        if global_promo_bps > 10000 {
+        if !is_valid_bps(global_promo_bps) {
             return Err(EventRegistryError::InvalidPromoBps);
         }
 
@@ -718,103 +1387,1653 @@ impl EventRegistry {
         storage::get_promo_expiry(&env)
     }
 
-    /// Marks an event as postponed and sets a temporary refund grace period.
-    /// During this window, all guests may request refunds regardless of their
-    /// ticket tier's standard refundability rules or refund deadlines.
-    pub fn postpone_event(
+    /// Schedules a flash sale on a tier (only by organizer). The payment contract
+    /// applies the discount to purchases made between `start` and `end`; the
+    /// regular price returns automatically once the window closes. Scheduling a
+    /// new sale replaces any previous one for the tier.
+    pub fn set_flash_sale(
         env: Env,
         event_id: String,
-        grace_period_end: u64,
+        tier_id: String,
+        discount_bps: u32,
+        start: u64,
+        end: u64,
     ) -> Result<(), EventRegistryError> {
-        let mut event_info =
+        let event_info =
             storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
 
-        // Only the organizer may postpone their event.
         event_info.organizer_address.require_auth();
 
-        let now = env.ledger().timestamp();
-        if grace_period_end <= now {
-            return Err(EventRegistryError::InvalidGracePeriodEnd);
+        if matches!(event_info.status, EventStatus::Cancelled) {
+            return Err(EventRegistryError::EventCancelled);
+        }
+        if !event_info.tiers.contains_key(tier_id.clone()) {
+            return Err(EventRegistryError::TierNotFound);
+        }
+        // A full discount would hand tickets out for free
+        if discount_bps == 0 || discount_bps >= MAX_BPS {
+            return Err(EventRegistryError::InvalidFlashSaleDiscount);
+        }
+        if end <= start || end <= env.ledger().timestamp() {
+            return Err(EventRegistryError::InvalidFlashSale);
         }
 
-        event_info.is_postponed = true;
-        event_info.grace_period_end = grace_period_end;
-        storage::update_event(&env, event_info.clone());
+        storage::set_flash_sale(
+            &env,
+            event_id.clone(),
+            tier_id.clone(),
+            &FlashSale {
+                discount_bps,
+                start,
+                end,
+            },
+        );
 
         env.events().publish(
-            (AgoraEvent::EventPostponed,),
-            EventPostponedEvent {
+            (AgoraEvent::FlashSaleScheduled, event_id.clone()),
+            FlashSaleScheduledEvent {
                 event_id,
-                organizer_address: event_info.organizer_address,
-                grace_period_end,
-                timestamp: now,
+                tier_id,
+                discount_bps,
+                start,
+                end,
+                timestamp: env.ledger().timestamp(),
             },
         );
 
         Ok(())
     }
 
-    /// Authorizes a new scanner wallet for a specific event
-    pub fn authorize_scanner(
+    /// Returns the flash sale scheduled for a tier, if any (active, upcoming or lapsed).
+    pub fn get_flash_sale(env: Env, event_id: String, tier_id: String) -> Option<FlashSale> {
+        storage::get_flash_sale(&env, event_id, tier_id)
+    }
+
+    /// Registers a multi-event bundle (only by organizer). Every included event
+    /// must belong to the organizer and offer `tier_id`; each bundle sold takes
+    /// one ticket of that tier from every event.
+    pub fn register_bundle(
         env: Env,
-        event_id: String,
-        scanner: Address,
+        organizer: Address,
+        bundle_id: String,
+        event_ids: Vec<String>,
+        tier_id: String,
+        price: i128,
+        max_supply: u32,
     ) -> Result<(), EventRegistryError> {
-        let event_info =
-            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        organizer.require_auth();
 
-        // Only the organizer can authorize scanners
-        event_info.organizer_address.require_auth();
+        if storage::get_bundle(&env, bundle_id.clone()).is_some() {
+            return Err(EventRegistryError::EventAlreadyExists);
+        }
+        if event_ids.is_empty() || event_ids.len() > MAX_BUNDLE_EVENTS || max_supply == 0 {
+            return Err(EventRegistryError::InvalidQuantity);
+        }
+        if price <= 0 {
+            return Err(EventRegistryError::InvalidTokenPrice);
+        }
+        for (i, event_id) in event_ids.iter().enumerate() {
+            if event_ids.first_index_of(&event_id) != Some(i as u32) {
+                return Err(EventRegistryError::InvalidQuantity);
+            }
+            let event_info =
+                storage::get_event(&env, event_id).ok_or(EventRegistryError::EventNotFound)?;
+            if event_info.organizer_address != organizer {
+                return Err(EventRegistryError::Unauthorized);
+            }
+            if matches!(event_info.status, EventStatus::Cancelled) {
+                return Err(EventRegistryError::EventCancelled);
+            }
+            if !event_info.tiers.contains_key(tier_id.clone()) {
+                return Err(EventRegistryError::TierNotFound);
+            }
+        }
 
-        storage::authorize_scanner(&env, event_id.clone(), &scanner);
+        storage::set_bundle(
+            &env,
+            &Bundle {
+                bundle_id: bundle_id.clone(),
+                organizer: organizer.clone(),
+                event_ids: event_ids.clone(),
+                tier_id,
+                price,
+                max_supply,
+                sold: 0,
+            },
+        );
 
+        #[allow(deprecated)]
         env.events().publish(
-            (AgoraEvent::ScannerAuthorized,),
-            ScannerAuthorizedEvent {
-                event_id,
-                scanner,
-                authorized_by: event_info.organizer_address,
+            (AgoraEvent::BundleRegistered,),
+            BundleRegisteredEvent {
+                bundle_id,
+                organizer,
+                event_ids,
+                price,
+                max_supply,
                 timestamp: env.ledger().timestamp(),
             },
         );
-
         Ok(())
     }
 
-    /// Checks if a scanner is authorized for a specific event
-    pub fn is_scanner_authorized(env: Env, event_id: String, scanner: Address) -> bool {
-        storage::is_scanner_authorized(&env, event_id, &scanner)
+    /// Returns a bundle, if registered.
+    pub fn get_bundle(env: Env, bundle_id: String) -> Option<Bundle> {
+        storage::get_bundle(&env, bundle_id)
     }
-}
 
-fn validate_address(env: &Env, address: &Address) -> Result<(), EventRegistryError> {
-    if address == &env.current_contract_address() {
-        return Err(EventRegistryError::InvalidAddress);
-    }
-    Ok(())
-}
+    /// Counts one bundle sale against its supply. Only callable by the
+    /// registered TicketPayment contract, which moves each event's inventory.
+    pub fn record_bundle_sale(env: Env, bundle_id: String) -> Result<(), EventRegistryError> {
+        let ticket_payment_addr =
+            storage::get_ticket_payment_contract(&env).ok_or(EventRegistryError::NotInitialized)?;
+        ticket_payment_addr.require_auth();
 
-fn validate_metadata_cid(env: &Env, cid: &String) -> Result<(), EventRegistryError> {
-    if cid.len() < 46 {
-        return Err(EventRegistryError::InvalidMetadataCid);
+        let mut bundle = storage::get_bundle(&env, bundle_id.clone())
+            .ok_or(EventRegistryError::EventNotFound)?;
+        if bundle.sold >= bundle.max_supply {
+            return Err(EventRegistryError::MaxSupplyExceeded);
+        }
+        bundle.sold += 1;
+        storage::set_bundle(&env, &bundle);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::BundleSold,),
+            BundleSoldEvent {
+                bundle_id,
+                sold: bundle.sold,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
     }
 
-    // We expect CIDv1 base32, which starts with 'b'
-    // Convert to Bytes to check the first character safely
-    let mut bytes = soroban_sdk::Bytes::new(env);
-    bytes.append(&cid.clone().into());
+    /// Returns the base price a tier sells at right now, following its pricing
+    /// mode (e.g. the current step of a Dutch auction). Flash sales and promos
+    /// are applied on top of this by the payment contract.
+    pub fn get_current_price(
+        env: Env,
+        event_id: String,
+        tier_id: String,
+    ) -> Result<i128, EventRegistryError> {
+        let event_info =
+            storage::get_event(&env, event_id).ok_or(EventRegistryError::EventNotFound)?;
+        let tier = event_info
+            .tiers
+            .get(tier_id)
+            .ok_or(EventRegistryError::TierNotFound)?;
+        Ok(tier.price_at(env.ledger().timestamp()))
+    }
 
-    if !bytes.is_empty() && bytes.get(0) != Some(b'b') {
-        return Err(EventRegistryError::InvalidMetadataCid);
+    /// Returns how the platform-wide promo applies to an event.
+    pub fn get_promo_mode(env: Env, event_id: String) -> PromoMode {
+        storage::get_promo_mode(&env, event_id)
     }
 
-    Ok(())
-}
+    /// Requires events declaring `trigger_flag` to also declare `required_flags`
+    /// (e.g. sanctions screening for jurisdiction-limited events). Passing 0 as
+    /// `required_flags` removes the requirement. Only callable by the administrator.
+    pub fn set_policy_requirement(
+        env: Env,
+        trigger_flag: u32,
+        required_flags: u32,
+    ) -> Result<(), EventRegistryError> {
+        let admin = storage::get_admin(&env).ok_or(EventRegistryError::NotInitialized)?;
+        admin.require_auth();
 
-/// Suspends all active events for a blacklisted organizer.
-/// This implements the "Suspension" ripple effect.
-fn suspend_organizer_events(
-    env: Env,
+        if trigger_flag == 0 {
+            return Err(EventRegistryError::InvalidPolicyFlags);
+        }
+        let mut requirements = storage::get_policy_requirements(&env);
+        if required_flags == 0 {
+            requirements.remove(trigger_flag);
+        } else {
+            requirements.set(trigger_flag, required_flags);
+        }
+        storage::set_policy_requirements(&env, &requirements);
+
+        env.events().publish(
+            (AgoraEvent::PolicyRequirementUpdated,),
+            PolicyRequirementUpdatedEvent {
+                trigger_flag,
+                required_flags,
+                admin_address: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the platform's policy requirements (trigger flag -> required flags).
+    pub fn get_policy_requirements(env: Env) -> Map<u32, u32> {
+        storage::get_policy_requirements(&env)
+    }
+
+    /// Returns the policy flags declared for an event at registration.
+    pub fn get_policy_flags(env: Env, event_id: String) -> u32 {
+        storage::get_policy_flags(&env, event_id)
+    }
+
+    /// Returns the organizer-escrow recovery configuration set at registration, if any.
+    pub fn get_recovery_config(env: Env, event_id: String) -> Option<RecoveryConfig> {
+        storage::get_recovery_config(&env, event_id)
+    }
+
+    /// Returns true if the event's organizer is a deployed Wasm contract (e.g. a
+    /// DAO) rather than a plain account.
+    pub fn is_contract_organizer(env: Env, event_id: String) -> Result<bool, EventRegistryError> {
+        let event_info =
+            storage::get_event(&env, event_id).ok_or(EventRegistryError::EventNotFound)?;
+        Ok(matches!(
+            event_info.organizer_address.executable(),
+            Some(Executable::Wasm(_))
+        ))
+    }
+
+    /// Allows or disallows `proposer` to propose organizer actions for an event
+    /// (only by organizer), e.g. the members of a governance contract.
+    pub fn set_action_proposer(
+        env: Env,
+        event_id: String,
+        proposer: Address,
+        allowed: bool,
+    ) -> Result<(), EventRegistryError> {
+        let event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        event_info.organizer_address.require_auth();
+
+        storage::set_action_proposer(&env, event_id.clone(), &proposer, allowed);
+
+        env.events().publish(
+            (AgoraEvent::ActionProposerUpdated, event_id.clone()),
+            ActionProposerUpdatedEvent {
+                event_id,
+                proposer,
+                allowed,
+                updated_by: event_info.organizer_address,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Returns whether `proposer` may propose organizer actions for an event.
+    pub fn is_action_proposer(env: Env, event_id: String, proposer: Address) -> bool {
+        storage::is_action_proposer(&env, event_id, &proposer)
+    }
+
+    /// Records an organizer action for later execution. The organizer or one of
+    /// its allowed proposers must sign; the action only takes effect when the
+    /// organizer calls `execute_organizer_action`.
+    pub fn propose_organizer_action(
+        env: Env,
+        event_id: String,
+        proposer: Address,
+        action: OrganizerAction,
+    ) -> Result<u64, EventRegistryError> {
+        proposer.require_auth();
+        let event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        if proposer != event_info.organizer_address
+            && !storage::is_action_proposer(&env, event_id.clone(), &proposer)
+        {
+            return Err(EventRegistryError::Unauthorized);
+        }
+
+        let proposal_id = storage::get_next_proposal_id(&env);
+        let timestamp = env.ledger().timestamp();
+        storage::store_organizer_proposal(
+            &env,
+            &OrganizerProposal {
+                proposal_id,
+                event_id: event_id.clone(),
+                proposer: proposer.clone(),
+                action: action.clone(),
+                executed: false,
+                created_at: timestamp,
+            },
+        );
+
+        env.events().publish(
+            (AgoraEvent::OrganizerActionProposed, event_id.clone()),
+            OrganizerActionProposedEvent {
+                proposal_id,
+                event_id,
+                proposer,
+                action,
+                timestamp,
+            },
+        );
+
+        Ok(proposal_id)
+    }
+
+    /// Executes a proposed organizer action. Authorization is enforced by the
+    /// underlying organizer entrypoint, so this requires the organizer's auth.
+    pub fn execute_organizer_action(env: Env, proposal_id: u64) -> Result<(), EventRegistryError> {
+        let mut proposal = storage::get_organizer_proposal(&env, proposal_id)
+            .ok_or(EventRegistryError::ProposalNotFound)?;
+        if proposal.executed {
+            return Err(EventRegistryError::ProposalAlreadyExecuted);
+        }
+        let event_info = storage::get_event(&env, proposal.event_id.clone())
+            .ok_or(EventRegistryError::EventNotFound)?;
+
+        let event_id = proposal.event_id.clone();
+        match proposal.action.clone() {
+            OrganizerAction::SetActive(is_active) => {
+                Self::update_event_status(env.clone(), event_id.clone(), is_active)?
+            }
+            OrganizerAction::UpdateMetadata(cid) => {
+                Self::update_metadata(env.clone(), event_id.clone(), cid)?
+            }
+            OrganizerAction::SetVisibility(visibility) => {
+                Self::set_event_visibility(env.clone(), event_id.clone(), visibility)?
+            }
+            OrganizerAction::Postpone(grace_period_end) => {
+                Self::postpone_event(env.clone(), event_id.clone(), grace_period_end)?
+            }
+            OrganizerAction::Cancel => Self::cancel_event(env.clone(), event_id.clone())?,
+        }
+
+        proposal.executed = true;
+        storage::store_organizer_proposal(&env, &proposal);
+
+        env.events().publish(
+            (AgoraEvent::OrganizerActionExecuted, event_id.clone()),
+            OrganizerActionExecutedEvent {
+                proposal_id,
+                event_id,
+                executed_by: event_info.organizer_address,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Returns an organizer action proposal by ID.
+    pub fn get_organizer_proposal(env: Env, proposal_id: u64) -> Option<OrganizerProposal> {
+        storage::get_organizer_proposal(&env, proposal_id)
+    }
+
+    /// Opens a threshold-gated admin proposal. The proposer must be an admin and
+    /// counts as its first approval. `expiration_ledgers` of 0 uses
+    /// `DEFAULT_PROPOSAL_EXPIRY_LEDGERS`.
+    pub fn propose_action(
+        env: Env,
+        proposer: Address,
+        action: AdminAction,
+        expiration_ledgers: u64,
+    ) -> Result<u64, EventRegistryError> {
+        proposer.require_auth();
+        let config =
+            storage::get_multisig_config(&env).ok_or(EventRegistryError::NotInitialized)?;
+        if !config.admins.contains(&proposer) {
+            return Err(EventRegistryError::Unauthorized);
+        }
+        validate_admin_action(&env, &config, &action)?;
+
+        let ledgers = if expiration_ledgers == 0 {
+            DEFAULT_PROPOSAL_EXPIRY_LEDGERS
+        } else {
+            expiration_ledgers
+        };
+        let timestamp = env.ledger().timestamp();
+        let expires_at = timestamp.saturating_add(ledgers.saturating_mul(LEDGER_SECONDS));
+
+        let proposal_id = storage::get_next_proposal_id(&env);
+        let mut approvals = Vec::new(&env);
+        approvals.push_back(proposer.clone());
+        storage::store_proposal(
+            &env,
+            &Proposal {
+                proposal_id,
+                proposer: proposer.clone(),
+                action: action.clone(),
+                approvals,
+                executed: false,
+                created_at: timestamp,
+                expires_at,
+            },
+        );
+
+        env.events().publish(
+            (AgoraEvent::AdminActionProposed,),
+            AdminActionProposedEvent {
+                proposal_id,
+                proposer,
+                action,
+                expires_at,
+                timestamp,
+            },
+        );
+
+        Ok(proposal_id)
+    }
+
+    /// Adds an admin's approval to a pending, unexpired proposal.
+    pub fn approve_action(
+        env: Env,
+        approver: Address,
+        proposal_id: u64,
+    ) -> Result<(), EventRegistryError> {
+        approver.require_auth();
+        if !storage::is_admin(&env, &approver) {
+            return Err(EventRegistryError::Unauthorized);
+        }
+        let mut proposal = load_pending_proposal(&env, proposal_id)?;
+        if proposal.approvals.contains(&approver) {
+            return Err(EventRegistryError::AlreadyApproved);
+        }
+
+        proposal.approvals.push_back(approver.clone());
+        storage::store_proposal(&env, &proposal);
+
+        env.events().publish(
+            (AgoraEvent::AdminActionApproved,),
+            AdminActionApprovedEvent {
+                proposal_id,
+                approver,
+                approvals: proposal.approvals.len(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Applies a proposal once approvals from current admins reach the threshold.
+    /// The action is re-validated against the current admin set before it runs.
+    pub fn execute_action(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+    ) -> Result<(), EventRegistryError> {
+        executor.require_auth();
+        let config =
+            storage::get_multisig_config(&env).ok_or(EventRegistryError::NotInitialized)?;
+        if !config.admins.contains(&executor) {
+            return Err(EventRegistryError::Unauthorized);
+        }
+        let mut proposal = load_pending_proposal(&env, proposal_id)?;
+
+        let approvals = proposal
+            .approvals
+            .iter()
+            .filter(|approver| config.admins.contains(approver))
+            .count() as u32;
+        if approvals < config.threshold {
+            return Err(EventRegistryError::InsufficientApprovals);
+        }
+        validate_admin_action(&env, &config, &proposal.action)?;
+        // Sensitive proposals wait out the timelock counted from their creation
+        if is_timelocked(&env, &proposal.action)
+            && env.ledger().timestamp()
+                < proposal
+                    .created_at
+                    .saturating_add(storage::get_timelock_config(&env).delay)
+        {
+            return Err(EventRegistryError::ActionTimelocked);
+        }
+
+        proposal.executed = true;
+        storage::store_proposal(&env, &proposal);
+        storage::remove_from_active_proposals(&env, proposal_id);
+
+        apply_admin_action(&env, config, executor.clone(), proposal.action.clone())?;
+
+        env.events().publish(
+            (AgoraEvent::AdminActionExecuted,),
+            AdminActionExecutedEvent {
+                proposal_id,
+                executed_by: executor,
+                action: proposal.action,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Schedules an admin action to run once the timelock delay has passed.
+    /// Only callable by the administrator while the multi-sig threshold is 1;
+    /// multi-sig proposals are timelocked from their creation instead.
+    pub fn queue_action(env: Env, action: AdminAction) -> Result<u64, EventRegistryError> {
+        require_sole_admin(&env)?;
+        let config =
+            storage::get_multisig_config(&env).ok_or(EventRegistryError::NotInitialized)?;
+        validate_admin_action(&env, &config, &action)?;
+
+        let timestamp = env.ledger().timestamp();
+        let eta = timestamp.saturating_add(storage::get_timelock_config(&env).delay);
+        let action_id = storage::get_next_proposal_id(&env);
+        storage::store_queued_action(
+            &env,
+            &QueuedAction {
+                action_id,
+                action: action.clone(),
+                eta,
+                executed: false,
+            },
+        );
+
+        env.events().publish(
+            (AgoraEvent::ActionQueued,),
+            ActionQueuedEvent {
+                action_id,
+                action,
+                eta,
+                timestamp,
+            },
+        );
+
+        Ok(action_id)
+    }
+
+    /// Runs a queued admin action once its eta has passed. The action is
+    /// re-validated before it runs.
+    pub fn execute_queued_action(env: Env, action_id: u64) -> Result<(), EventRegistryError> {
+        let admin = require_sole_admin(&env)?;
+        let config =
+            storage::get_multisig_config(&env).ok_or(EventRegistryError::NotInitialized)?;
+        let mut queued = storage::get_queued_action(&env, action_id)
+            .ok_or(EventRegistryError::ProposalNotFound)?;
+        if queued.executed {
+            return Err(EventRegistryError::ProposalAlreadyExecuted);
+        }
+        if env.ledger().timestamp() < queued.eta {
+            return Err(EventRegistryError::ActionTimelocked);
+        }
+        validate_admin_action(&env, &config, &queued.action)?;
+
+        queued.executed = true;
+        storage::store_queued_action(&env, &queued);
+        apply_admin_action(&env, config, admin, queued.action.clone())?;
+
+        env.events().publish(
+            (AgoraEvent::ActionExecuted,),
+            ActionExecutedEvent {
+                action_id,
+                action: queued.action,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Drops a queued admin action that has not been executed.
+    pub fn cancel_queued_action(env: Env, action_id: u64) -> Result<(), EventRegistryError> {
+        require_sole_admin(&env)?;
+        let queued = storage::get_queued_action(&env, action_id)
+            .ok_or(EventRegistryError::ProposalNotFound)?;
+        if queued.executed {
+            return Err(EventRegistryError::ProposalAlreadyExecuted);
+        }
+        storage::remove_queued_action(&env, action_id);
+
+        env.events().publish(
+            (AgoraEvent::ActionCancelled,),
+            ActionCancelledEvent {
+                action_id,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Returns a queued admin action by ID.
+    pub fn get_queued_action(env: Env, action_id: u64) -> Option<QueuedAction> {
+        storage::get_queued_action(&env, action_id)
+    }
+
+    /// Enables or replaces the timelock on sensitive admin actions. Only
+    /// callable directly by the administrator while no timelock is configured;
+    /// afterwards changes go through `queue_action` with `SetTimelock`.
+    pub fn set_timelock(
+        env: Env,
+        delay: u64,
+        fee_delta_bps: u32,
+    ) -> Result<(), EventRegistryError> {
+        require_sole_admin(&env)?;
+        let action = AdminAction::SetTimelock(delay, fee_delta_bps);
+        if is_timelocked(&env, &action) {
+            return Err(EventRegistryError::ActionTimelocked);
+        }
+        let config =
+            storage::get_multisig_config(&env).ok_or(EventRegistryError::NotInitialized)?;
+        validate_admin_action(&env, &config, &action)?;
+        storage::set_timelock_config(
+            &env,
+            &TimelockConfig {
+                delay,
+                fee_delta_bps,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the timelock configuration.
+    pub fn get_timelock(env: Env) -> TimelockConfig {
+        storage::get_timelock_config(&env)
+    }
+
+    /// Returns an admin proposal by ID.
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Option<Proposal> {
+        storage::get_proposal(&env, proposal_id)
+    }
+
+    /// Returns the IDs of admin proposals that have not been executed.
+    pub fn get_active_proposals(env: Env) -> Vec<u64> {
+        storage::get_active_proposals(&env)
+    }
+
+    /// Retrieves up to `limit` active proposal IDs starting at `offset`.
+    pub fn get_active_proposals_paginated(env: Env, offset: u32, limit: u32) -> Vec<u64> {
+        agora_pagination::page(
+            &storage::get_active_proposals(&env),
+            PageCursor::new(offset, limit),
+        )
+    }
+
+    /// Returns the current admin set and approval threshold.
+    pub fn get_multisig_config(env: Env) -> Result<MultiSigConfig, EventRegistryError> {
+        storage::get_multisig_config(&env).ok_or(EventRegistryError::NotInitialized)
+    }
+
+    /// Returns whether an address is part of the multi-sig admin set.
+    pub fn is_admin(env: Env, address: Address) -> bool {
+        storage::is_admin(&env, &address)
+    }
+
+    /// Marks an event as postponed and sets a temporary refund grace period.
+    /// During this window, all guests may request refunds regardless of their
+    /// ticket tier's standard refundability rules or refund deadlines.
+    pub fn postpone_event(
+        env: Env,
+        event_id: String,
+        grace_period_end: u64,
+    ) -> Result<(), EventRegistryError> {
+        let mut event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+
+        // Only the organizer may postpone their event.
+        event_info.organizer_address.require_auth();
+
+        let now = env.ledger().timestamp();
+        if grace_period_end <= now {
+            return Err(EventRegistryError::InvalidGracePeriodEnd);
+        }
+
+        event_info.is_postponed = true;
+        event_info.grace_period_end = grace_period_end;
+        storage::update_event(&env, event_info.clone());
+
+        env.events().publish(
+            (AgoraEvent::EventPostponed, event_id.clone()),
+            EventPostponedEvent {
+                event_id,
+                organizer_address: event_info.organizer_address,
+                grace_period_end,
+                timestamp: now,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Moves an event to `new_start_time` and ends any postponement, closing its
+    /// refund grace window. With `extend_refund_deadline`, a set refund deadline
+    /// moves by as much as the start time did.
+    ///
+    /// # Errors
+    /// * `EventNotFound` - If no event with the given ID exists.
+    /// * `EventCancelled` - If the event was cancelled.
+    /// * `EventInactive` - If the event was already completed.
+    /// * `InvalidStartTime` - If `new_start_time` is not in the future.
+    pub fn reschedule_event(
+        env: Env,
+        event_id: String,
+        new_start_time: u64,
+        extend_refund_deadline: bool,
+    ) -> Result<(), EventRegistryError> {
+        let mut event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        event_info.organizer_address.require_auth();
+        ensure_schedulable(&event_info)?;
+        if new_start_time <= env.ledger().timestamp() {
+            return Err(EventRegistryError::InvalidStartTime);
+        }
+
+        let previous_start_time = event_info.event_start_time;
+        if extend_refund_deadline
+            && event_info.refund_deadline > 0
+            && previous_start_time > 0
+            && new_start_time > previous_start_time
+        {
+            event_info.refund_deadline = event_info
+                .refund_deadline
+                .saturating_add(new_start_time - previous_start_time);
+        }
+        event_info.event_start_time = new_start_time;
+        end_postponement(&env, event_info, previous_start_time);
+        Ok(())
+    }
+
+    /// Ends a postponement without moving the event, closing its refund grace
+    /// window.
+    ///
+    /// # Errors
+    /// * `EventNotFound` - If no event with the given ID exists.
+    /// * `EventCancelled` - If the event was cancelled.
+    /// * `EventInactive` - If the event was already completed.
+    /// * `EventNotPostponed` - If the event is not postponed.
+    pub fn resume_event(env: Env, event_id: String) -> Result<(), EventRegistryError> {
+        let event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        event_info.organizer_address.require_auth();
+        ensure_schedulable(&event_info)?;
+        if !event_info.is_postponed {
+            return Err(EventRegistryError::EventNotPostponed);
+        }
+
+        let start_time = event_info.event_start_time;
+        end_postponement(&env, event_info, start_time);
+        Ok(())
+    }
+
+    /// Authorizes a new scanner wallet for a specific event
+    pub fn authorize_scanner(
+        env: Env,
+        event_id: String,
+        scanner: Address,
+    ) -> Result<(), EventRegistryError> {
+        Self::authorize_scanner_with_window(env, event_id, scanner, 0, 0)
+    }
+
+    /// Authorizes a scanner wallet only between `valid_from` and `valid_until`
+    /// (ledger timestamps, end exclusive). A bound of 0 leaves that side open.
+    /// Re-authorizing a scanner replaces its window.
+    pub fn authorize_scanner_with_window(
+        env: Env,
+        event_id: String,
+        scanner: Address,
+        valid_from: u64,
+        valid_until: u64,
+    ) -> Result<(), EventRegistryError> {
+        let event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+
+        // Only the organizer can authorize scanners
+        event_info.organizer_address.require_auth();
+
+        if valid_until > 0 && valid_until <= valid_from {
+            return Err(EventRegistryError::InvalidScannerWindow);
+        }
+        let window = (valid_from > 0 || valid_until > 0).then_some(ScannerWindow {
+            valid_from,
+            valid_until,
+        });
+        storage::authorize_scanner(&env, event_id.clone(), &scanner, window);
+
+        env.events().publish(
+            (AgoraEvent::ScannerAuthorized, event_id.clone()),
+            ScannerAuthorizedEvent {
+                event_id,
+                scanner,
+                authorized_by: event_info.organizer_address,
+                valid_from,
+                valid_until,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Checks if a scanner is authorized for a specific event right now.
+    /// Time-boxed scanners are rejected outside their window.
+    pub fn is_scanner_authorized(env: Env, event_id: String, scanner: Address) -> bool {
+        storage::is_scanner_authorized(&env, event_id, &scanner)
+    }
+
+    /// Returns the validity window of a time-boxed scanner authorization.
+    pub fn get_scanner_window(
+        env: Env,
+        event_id: String,
+        scanner: Address,
+    ) -> Option<ScannerWindow> {
+        storage::get_scanner_window(&env, event_id, &scanner)
+    }
+
+    /// Switches a tier to assigned seating (only by organizer). `seats` must list
+    /// exactly `tier_limit` unique seat identifiers; an empty list returns the
+    /// tier to general admission. The layout is frozen once the tier has sales.
+    ///
+    /// # Errors
+    /// * `EventNotFound` - If no event with the given ID exists.
+    /// * `TierNotFound` - If the tier does not exist.
+    /// * `InvalidSeatMap` - If the seats are duplicated, do not match the tier
+    ///   limit, exceed `MAX_SEATS_PER_TIER`, or the tier already has sales.
+    pub fn set_seat_map(
+        env: Env,
+        event_id: String,
+        tier_id: String,
+        seats: Vec<String>,
+    ) -> Result<(), EventRegistryError> {
+        let event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+
+        event_info.organizer_address.require_auth();
+
+        let tier = event_info
+            .tiers
+            .get(tier_id.clone())
+            .ok_or(EventRegistryError::TierNotFound)?;
+        if tier.current_sold > 0 {
+            return Err(EventRegistryError::InvalidSeatMap);
+        }
+
+        let seat_count = seats.len();
+        let seat_map = if seats.is_empty() {
+            None
+        } else {
+            let mut unique: Map<String, bool> = Map::new(&env);
+            for seat_id in seats.iter() {
+                unique.set(seat_id, true);
+            }
+            if seat_count > MAX_SEATS_PER_TIER
+                || unique.len() != seat_count
+                || seat_count as i128 != tier.tier_limit
+            {
+                return Err(EventRegistryError::InvalidSeatMap);
+            }
+            Some(SeatMap { seats })
+        };
+        storage::set_seat_map(&env, event_id.clone(), tier_id.clone(), seat_map);
+
+        env.events().publish(
+            (AgoraEvent::SeatMapUpdated, event_id.clone()),
+            SeatMapUpdatedEvent {
+                event_id,
+                tier_id,
+                seat_count,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Returns the seat map of a tier, or None for general admission.
+    pub fn get_seat_map(env: Env, event_id: String, tier_id: String) -> Option<SeatMap> {
+        storage::get_seat_map(&env, event_id, tier_id)
+    }
+
+    /// Returns true if a seat in a tier is currently reserved.
+    pub fn is_seat_reserved(env: Env, event_id: String, tier_id: String, seat_id: String) -> bool {
+        storage::is_seat_reserved(&env, event_id, tier_id, seat_id)
+    }
+
+    /// Reserves a specific seat for a ticket being sold.
+    /// This function is restricted to calls from the authorized TicketPayment contract.
+    ///
+    /// # Errors
+    /// * `SeatNotFound` - If the tier has no seat map or the seat is not in it.
+    /// * `SeatUnavailable` - If the seat is already reserved.
+    pub fn reserve_seat(
+        env: Env,
+        event_id: String,
+        tier_id: String,
+        seat_id: String,
+    ) -> Result<(), EventRegistryError> {
+        let ticket_payment_addr =
+            storage::get_ticket_payment_contract(&env).ok_or(EventRegistryError::NotInitialized)?;
+        ticket_payment_addr.require_auth();
+
+        let seat_map = storage::get_seat_map(&env, event_id.clone(), tier_id.clone())
+            .ok_or(EventRegistryError::SeatNotFound)?;
+        if !seat_map.seats.contains(&seat_id) {
+            return Err(EventRegistryError::SeatNotFound);
+        }
+        if storage::is_seat_reserved(&env, event_id.clone(), tier_id.clone(), seat_id.clone()) {
+            return Err(EventRegistryError::SeatUnavailable);
+        }
+
+        storage::set_seat_reserved(
+            &env,
+            event_id.clone(),
+            tier_id.clone(),
+            seat_id.clone(),
+            true,
+        );
+
+        env.events().publish(
+            (AgoraEvent::SeatReserved, event_id.clone()),
+            SeatReservedEvent {
+                event_id,
+                tier_id,
+                seat_id,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Places a hold on `quantity` tickets of a tier until `expires_at`, so a buyer
+    /// keeps them while completing payment. Held tickets are not sold, but count
+    /// against the tier and event limits until the hold is released or expires.
+    /// Placing a hold under an existing `hold_id` replaces it.
+    /// This function is restricted to calls from the authorized TicketPayment contract.
+    ///
+    /// # Errors
+    /// * `InvalidQuantity` - If `quantity` is zero or `expires_at` has already passed.
+    /// * `EventNotFound` - If no event with the given ID exists.
+    /// * `EventInactive` - If the event is not currently on sale.
+    /// * `TierNotFound` - If the tier does not exist.
+    /// * `TierSupplyExceeded` - If the tier cannot cover the hold.
+    /// * `MaxSupplyExceeded` - If the event's max supply cannot cover the hold.
+    pub fn hold_inventory(
+        env: Env,
+        hold_id: String,
+        event_id: String,
+        tier_id: String,
+        quantity: u32,
+        expires_at: u64,
+    ) -> Result<(), EventRegistryError> {
+        let ticket_payment_addr =
+            storage::get_ticket_payment_contract(&env).ok_or(EventRegistryError::NotInitialized)?;
+        ticket_payment_addr.require_auth();
+
+        if quantity == 0 || expires_at <= env.ledger().timestamp() {
+            return Err(EventRegistryError::InvalidQuantity);
+        }
+        let event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        if !event_info.is_active
+            || matches!(event_info.status, EventStatus::Cancelled)
+            || event_info.visibility == EventVisibility::Draft
+        {
+            return Err(EventRegistryError::EventInactive);
+        }
+        let tier = event_info
+            .tiers
+            .get(tier_id.clone())
+            .ok_or(EventRegistryError::TierNotFound)?;
+
+        let (tier_held, event_held) = active_holds(&env, &event_id, &tier_id, Some(&hold_id));
+        let quantity_i128 = quantity as i128;
+        let tier_total = tier
+            .current_sold
+            .checked_add(tier_held)
+            .and_then(|total| total.checked_add(quantity_i128))
+            .ok_or(EventRegistryError::SupplyOverflow)?;
+        if tier_total > tier.tier_limit {
+            return Err(EventRegistryError::TierSupplyExceeded);
+        }
+        if event_info.max_supply > 0 {
+            let event_total = event_info
+                .current_supply
+                .checked_add(event_held)
+                .and_then(|total| total.checked_add(quantity_i128))
+                .ok_or(EventRegistryError::SupplyOverflow)?;
+            if event_total > event_info.max_supply {
+                return Err(EventRegistryError::MaxSupplyExceeded);
+            }
+        }
+
+        // Drop expired holds while the list is being rewritten anyway
+        let now = env.ledger().timestamp();
+        let mut hold_ids = Vec::new(&env);
+        for id in storage::get_event_holds(&env, event_id.clone()).iter() {
+            if id == hold_id {
+                continue;
+            }
+            match storage::get_inventory_hold(&env, id.clone()) {
+                Some(hold) if hold.expires_at > now => hold_ids.push_back(id),
+                Some(_) => storage::remove_inventory_hold(&env, id),
+                None => {}
+            }
+        }
+        hold_ids.push_back(hold_id.clone());
+        storage::set_event_holds(&env, event_id.clone(), &hold_ids);
+        storage::set_inventory_hold(
+            &env,
+            hold_id.clone(),
+            &InventoryHold {
+                event_id: event_id.clone(),
+                tier_id: tier_id.clone(),
+                quantity,
+                expires_at,
+            },
+        );
+
+        env.events().publish(
+            (AgoraEvent::InventoryHeld, event_id.clone()),
+            InventoryHeldEvent {
+                hold_id,
+                event_id,
+                tier_id,
+                quantity,
+                expires_at,
+                timestamp: now,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Releases an inventory hold before it expires, returning its tickets to
+    /// sale. Releasing a hold that has expired or does not exist is a no-op.
+    /// This function is restricted to calls from the authorized TicketPayment contract.
+    pub fn release_inventory_hold(env: Env, hold_id: String) -> Result<(), EventRegistryError> {
+        let ticket_payment_addr =
+            storage::get_ticket_payment_contract(&env).ok_or(EventRegistryError::NotInitialized)?;
+        ticket_payment_addr.require_auth();
+
+        let Some(hold) = storage::get_inventory_hold(&env, hold_id.clone()) else {
+            return Ok(());
+        };
+        storage::remove_inventory_hold(&env, hold_id.clone());
+        let mut hold_ids = storage::get_event_holds(&env, hold.event_id.clone());
+        if let Some(index) = hold_ids.first_index_of(&hold_id) {
+            hold_ids.remove(index);
+            storage::set_event_holds(&env, hold.event_id.clone(), &hold_ids);
+        }
+
+        if hold.expires_at > env.ledger().timestamp() {
+            env.events().publish(
+                (AgoraEvent::InventoryHoldReleased, hold.event_id.clone()),
+                InventoryHoldReleasedEvent {
+                    hold_id,
+                    event_id: hold.event_id,
+                    tier_id: hold.tier_id,
+                    quantity: hold.quantity,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Returns how many tickets of a tier are currently on hold.
+    pub fn get_held_inventory(env: Env, event_id: String, tier_id: String) -> i128 {
+        active_holds(&env, &event_id, &tier_id, None).0
+    }
+
+    /// Returns an inventory hold, or `None` once it has been released or expired.
+    pub fn get_inventory_hold(env: Env, hold_id: String) -> Option<InventoryHold> {
+        storage::get_inventory_hold(&env, hold_id)
+            .filter(|hold| hold.expires_at > env.ledger().timestamp())
+    }
+
+    /// Releases a reserved seat so it can be sold again (e.g. on refund).
+    /// This function is restricted to calls from the authorized TicketPayment contract.
+    ///
+    /// # Errors
+    /// * `SeatNotFound` - If the seat is not currently reserved.
+    pub fn release_seat(
+        env: Env,
+        event_id: String,
+        tier_id: String,
+        seat_id: String,
+    ) -> Result<(), EventRegistryError> {
+        let ticket_payment_addr =
+            storage::get_ticket_payment_contract(&env).ok_or(EventRegistryError::NotInitialized)?;
+        ticket_payment_addr.require_auth();
+
+        if !storage::is_seat_reserved(&env, event_id.clone(), tier_id.clone(), seat_id.clone()) {
+            return Err(EventRegistryError::SeatNotFound);
+        }
+
+        storage::set_seat_reserved(
+            &env,
+            event_id.clone(),
+            tier_id.clone(),
+            seat_id.clone(),
+            false,
+        );
+
+        env.events().publish(
+            (AgoraEvent::SeatReleased, event_id.clone()),
+            SeatReleasedEvent {
+                event_id,
+                tier_id,
+                seat_id,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Sets how buyer-initiated refunds are handled for an event (only by
+    /// organizer), replacing its refund deadline, restocking fee and per-tier
+    /// refundability. The policy can only change before the first sale.
+    ///
+    /// # Errors
+    /// * `EventNotFound` - If no event with the given ID exists.
+    /// * `InvalidRefundPolicy` - If the policy is malformed or the event has sales.
+    pub fn set_refund_policy(
+        env: Env,
+        event_id: String,
+        policy: RefundPolicy,
+    ) -> Result<(), EventRegistryError> {
+        let event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+
+        event_info.organizer_address.require_auth();
+
+        if event_info.current_supply > 0 || !policy.is_valid() {
+            return Err(EventRegistryError::InvalidRefundPolicy);
+        }
+        storage::set_refund_policy(&env, event_id.clone(), &policy);
+
+        env.events().publish(
+            (AgoraEvent::RefundPolicyUpdated, event_id.clone()),
+            RefundPolicyUpdatedEvent {
+                event_id,
+                policy,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Returns the refund policy of an event, or None if it uses the legacy
+    /// refund fields.
+    pub fn get_refund_policy(env: Env, event_id: String) -> Option<RefundPolicy> {
+        storage::get_refund_policy(&env, event_id)
+    }
+
+    /// Proposes a replacement milestone plan for an event (only by organizer). An
+    /// empty plan releases all revenue. The plan takes effect once approved by the
+    /// admin; a new proposal replaces any pending one.
+    ///
+    /// # Errors
+    /// * `EventNotFound` - If no event with the given ID exists.
+    /// * `EventCancelled` - If the event was cancelled.
+    /// * `InvalidQuantity` - If a milestone has a non-positive sales threshold.
+    /// * `InvalidFeePercent` - If a release percentage exceeds 100%, or the plan
+    ///   would unlock less revenue at current sales than the plan in force.
+    pub fn propose_milestone_update(
+        env: Env,
+        event_id: String,
+        new_plan: Vec<Milestone>,
+    ) -> Result<(), EventRegistryError> {
+        let event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        event_info.organizer_address.require_auth();
+        if event_info.status == EventStatus::Cancelled {
+            return Err(EventRegistryError::EventCancelled);
+        }
+        validate_milestone_update(&event_info, &new_plan)?;
+
+        storage::set_pending_milestone_plan(&env, event_id.clone(), &new_plan);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::MilestonePlanProposed, event_id.clone()),
+            MilestonePlanProposedEvent {
+                event_id,
+                organizer: event_info.organizer_address,
+                plan: new_plan,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Applies an event's pending milestone plan (only by admin). The plan is
+    /// checked again against sales at approval time.
+    ///
+    /// # Errors
+    /// * `EventNotFound` - If no event with the given ID exists.
+    /// * `ProposalNotFound` - If no milestone update is pending for the event.
+    /// * `InvalidFeePercent` - If the plan would now unlock less revenue than the
+    ///   plan in force.
+    pub fn approve_milestone_update(env: Env, event_id: String) -> Result<(), EventRegistryError> {
+        let admin = require_sole_admin(&env)?;
+        let mut event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+        let plan = storage::get_pending_milestone_plan(&env, event_id.clone())
+            .ok_or(EventRegistryError::ProposalNotFound)?;
+        validate_milestone_update(&event_info, &plan)?;
+
+        event_info.milestone_plan = if plan.is_empty() {
+            None
+        } else {
+            Some(plan.clone())
+        };
+        storage::update_event(&env, event_info);
+        storage::remove_pending_milestone_plan(&env, event_id.clone());
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::MilestonePlanUpdated, event_id.clone()),
+            MilestonePlanUpdatedEvent {
+                event_id,
+                admin,
+                plan,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the milestone plan awaiting admin approval for an event, if any.
+    pub fn get_pending_milestone_plan(env: Env, event_id: String) -> Option<Vec<Milestone>> {
+        storage::get_pending_milestone_plan(&env, event_id)
+    }
+
+    /// Sets when an event is scheduled to end (only by organizer). The event can
+    /// be completed once this time has passed; 0 clears it.
+    ///
+    /// # Errors
+    /// * `EventNotFound` - If no event with the given ID exists.
+    /// * `EventCancelled` - If the event was cancelled.
+    /// * `EventInactive` - If the event was already completed.
+    pub fn set_event_end_time(
+        env: Env,
+        event_id: String,
+        end_time: u64,
+    ) -> Result<(), EventRegistryError> {
+        let event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+
+        event_info.organizer_address.require_auth();
+
+        match event_info.status {
+            EventStatus::Cancelled => return Err(EventRegistryError::EventCancelled),
+            EventStatus::Completed => return Err(EventRegistryError::EventInactive),
+            _ => {}
+        }
+        storage::set_event_schedule(
+            &env,
+            event_id.clone(),
+            &EventSchedule {
+                end_time,
+                completed_at: 0,
+            },
+        );
+
+        env.events().publish(
+            (AgoraEvent::EventEndTimeUpdated, event_id.clone()),
+            EventEndTimeUpdatedEvent {
+                event_id,
+                end_time,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Sets when an event starts (only by organizer); 0 clears it. The start
+    /// time anchors the event's transfer lock window.
+    ///
+    /// # Errors
+    /// * `EventNotFound` - If no event with the given ID exists.
+    /// * `EventCancelled` - If the event was cancelled.
+    /// * `EventInactive` - If the event was already completed.
+    pub fn set_event_start_time(
+        env: Env,
+        event_id: String,
+        start_time: u64,
+    ) -> Result<(), EventRegistryError> {
+        let mut event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+
+        event_info.organizer_address.require_auth();
+
+        match event_info.status {
+            EventStatus::Cancelled => return Err(EventRegistryError::EventCancelled),
+            EventStatus::Completed => return Err(EventRegistryError::EventInactive),
+            _ => {}
+        }
+        event_info.event_start_time = start_time;
+        storage::update_event(&env, event_info);
+
+        env.events().publish(
+            (AgoraEvent::EventStartTimeUpdated, event_id.clone()),
+            EventStartTimeUpdatedEvent {
+                event_id,
+                start_time,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Marks an event as completed once its end time has passed (only by
+    /// organizer). Completion is final and is what unlocks `claim_revenue`.
+    ///
+    /// # Errors
+    /// * `EventNotFound` - If no event with the given ID exists.
+    /// * `EventCancelled` - If the event was cancelled.
+    /// * `EventInactive` - If the event was already completed.
+    /// * `EventNotEnded` - If no end time is set or it is still in the future.
+    pub fn complete_event(env: Env, event_id: String) -> Result<(), EventRegistryError> {
+        let mut event_info =
+            storage::get_event(&env, event_id.clone()).ok_or(EventRegistryError::EventNotFound)?;
+
+        event_info.organizer_address.require_auth();
+
+        match event_info.status {
+            EventStatus::Cancelled => return Err(EventRegistryError::EventCancelled),
+            EventStatus::Completed => return Err(EventRegistryError::EventInactive),
+            _ => {}
+        }
+        let now = env.ledger().timestamp();
+        let mut schedule = storage::get_event_schedule(&env, event_id.clone())
+            .filter(|schedule| schedule.end_time > 0 && now >= schedule.end_time)
+            .ok_or(EventRegistryError::EventNotEnded)?;
+
+        event_info.status = EventStatus::Completed;
+        event_info.is_active = false;
+        storage::update_event(&env, event_info.clone());
+        schedule.completed_at = now;
+        storage::set_event_schedule(&env, event_id.clone(), &schedule);
+
+        env.events().publish(
+            (AgoraEvent::EventCompleted, event_id.clone()),
+            EventCompletedEvent {
+                event_id,
+                completed_by: event_info.organizer_address,
+                timestamp: now,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Returns the end time and completion time of an event, or None if no end
+    /// time was ever set.
+    pub fn get_event_schedule(env: Env, event_id: String) -> Option<EventSchedule> {
+        storage::get_event_schedule(&env, event_id)
+    }
+}
+
+fn validate_address(env: &Env, address: &Address) -> Result<(), EventRegistryError> {
+    if address == &env.current_contract_address() {
+        return Err(EventRegistryError::InvalidAddress);
+    }
+    Ok(())
+}
+
+/// Checks declared policy flags against the platform's policy requirements.
+fn validate_policy_flags(env: &Env, flags: u32) -> Result<(), EventRegistryError> {
+    for (trigger_flag, required_flags) in storage::get_policy_requirements(env).iter() {
+        if flags & trigger_flag != 0 && flags & required_flags != required_flags {
+            return Err(EventRegistryError::InvalidPolicyFlags);
+        }
+    }
+    Ok(())
+}
+
+/// Rejects schedule changes to cancelled or completed events.
+fn ensure_schedulable(event_info: &EventInfo) -> Result<(), EventRegistryError> {
+    match event_info.status {
+        EventStatus::Cancelled => Err(EventRegistryError::EventCancelled),
+        EventStatus::Completed => Err(EventRegistryError::EventInactive),
+        _ => Ok(()),
+    }
+}
+
+/// Clears the postponement and refund grace window, stores the event and
+/// announces its schedule.
+fn end_postponement(env: &Env, mut event_info: EventInfo, previous_start_time: u64) {
+    event_info.is_postponed = false;
+    event_info.grace_period_end = 0;
+    storage::update_event(env, event_info.clone());
+
+    #[allow(deprecated)]
+    env.events().publish(
+        (AgoraEvent::EventRescheduled, event_info.event_id.clone()),
+        EventRescheduledEvent {
+            event_id: event_info.event_id,
+            previous_start_time,
+            new_start_time: event_info.event_start_time,
+            refund_deadline: event_info.refund_deadline,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
+
+/// Checks the supply, fee and status invariants an imported event must satisfy.
+fn validate_imported_event(event_info: &EventInfo) -> Result<(), EventRegistryError> {
+    if !is_valid_bps(event_info.platform_fee_percent) {
+        return Err(EventRegistryError::InvalidFeePercent);
+    }
+    if let Some(cap) = event_info.resale_cap_bps {
+        if !is_valid_bps(cap) {
+            return Err(EventRegistryError::InvalidResaleCapBps);
+        }
+    }
+    if !is_valid_bps(event_info.resale_royalty_bps) {
+        return Err(EventRegistryError::InvalidResaleRoyaltyBps);
+    }
+    if event_info.is_active != (event_info.status == EventStatus::Active) {
+        return Err(EventRegistryError::InvalidImportedEvent);
+    }
+    if event_info.max_supply < 0
+        || event_info.current_supply < 0
+        || (event_info.max_supply > 0 && event_info.current_supply > event_info.max_supply)
+    {
+        return Err(EventRegistryError::InvalidImportedEvent);
+    }
+
+    let mut total_tier_limit: i128 = 0;
+    let mut total_sold: i128 = 0;
+    for tier in event_info.tiers.values() {
+        if tier.current_sold < 0 || tier.current_sold > tier.tier_limit {
+            return Err(EventRegistryError::InvalidImportedEvent);
+        }
+        if !tier.has_valid_pricing() {
+            return Err(EventRegistryError::InvalidDutchAuction);
+        }
+        if tier
+            .bulk_discounts
+            .values()
+            .iter()
+            .any(|bps| !is_valid_bps(bps))
+        {
+            return Err(EventRegistryError::InvalidPromoBps);
+        }
+        if tier.token_prices.values().iter().any(|price| price <= 0) {
+            return Err(EventRegistryError::InvalidTokenPrice);
+        }
+        total_tier_limit = total_tier_limit
+            .checked_add(tier.tier_limit)
+            .ok_or(EventRegistryError::SupplyOverflow)?;
+        total_sold = total_sold
+            .checked_add(tier.current_sold)
+            .ok_or(EventRegistryError::SupplyOverflow)?;
+    }
+    if event_info.max_supply > 0 && total_tier_limit > event_info.max_supply {
+        return Err(EventRegistryError::TierLimitExceedsMaxSupply);
+    }
+    if total_sold != event_info.current_supply {
+        return Err(EventRegistryError::InvalidImportedEvent);
+    }
+    Ok(())
+}
+
+/// Validates a registration and stores the new event. The caller must have
+/// checked initialization and the organizer's authorization.
+fn store_new_event(env: &Env, args: &EventRegistrationArgs) -> Result<(), EventRegistryError> {
+    // Check if organizer is blacklisted
+    if storage::is_blacklisted(env, &args.organizer_address) {
+        return Err(EventRegistryError::OrganizerBlacklisted);
+    }
+
+    validate_metadata_cid(env, &args.metadata_cid)?;
+
+    if storage::event_exists(env, args.event_id.clone()) {
+        return Err(EventRegistryError::EventAlreadyExists);
+    }
+
+    // Claim the id in the linked payment contract, which rejects ids outside
+    // this registry's reserved namespace or already sold under another
+    // registry. A missing counterpart does not block it.
+    if let Some(ticket_payment_addr) = storage::get_ticket_payment_contract(env) {
+        let claimed = ticket_payment::TicketPaymentClient::new(env, &ticket_payment_addr)
+            .try_bind_tenant_event(&env.current_contract_address(), &args.event_id);
+        if matches!(claimed, Err(Ok(ref e)) if e.is_type(ScErrorType::Contract)) {
+            return Err(EventRegistryError::EventIdUnavailable);
+        }
+    }
+
+    // Validate tier limits don't exceed max_supply
+    if args.max_supply > 0 {
+        let mut total_tier_limit: i128 = 0;
+        for tier in args.tiers.values() {
+            total_tier_limit = total_tier_limit
+                .checked_add(tier.tier_limit)
+                .ok_or(EventRegistryError::SupplyOverflow)?;
+        }
+        if total_tier_limit > args.max_supply {
+            return Err(EventRegistryError::TierLimitExceedsMaxSupply);
+        }
+    }
+
+    // Validate tier visibility windows, token-specific prices and deposits
+    for tier in args.tiers.values() {
+        if tier.visible_until > 0 && tier.visible_until <= tier.visible_from {
+            return Err(EventRegistryError::InvalidTierVisibilityWindow);
+        }
+        if tier.token_prices.values().iter().any(|price| price <= 0) {
+            return Err(EventRegistryError::InvalidTokenPrice);
+        }
+        if tier.early_bird_price < 0 || tier.usd_price < 0 {
+            return Err(EventRegistryError::InvalidTokenPrice);
+        }
+        if tier.deposit_amount < 0 {
+            return Err(EventRegistryError::InvalidDepositAmount);
+        }
+        if !tier.has_valid_pricing() {
+            return Err(EventRegistryError::InvalidDutchAuction);
+        }
+        if tier
+            .bulk_discounts
+            .values()
+            .iter()
+            .any(|bps| !is_valid_bps(bps))
+        {
+            return Err(EventRegistryError::InvalidPromoBps);
+        }
+    }
+
+    if let Some(recovery_address) = &args.recovery_address {
+        if args.recovery_timeout == 0 || *recovery_address == args.organizer_address {
+            return Err(EventRegistryError::InvalidRecoveryConfig);
+        }
+    }
+
+    validate_policy_flags(env, args.policy_flags)?;
+
+    // Validate resale cap if provided
+    if let Some(cap) = args.resale_cap_bps {
+        if !is_valid_bps(cap) {
+            return Err(EventRegistryError::InvalidResaleCapBps);
+        }
+    }
+    if !is_valid_bps(args.resale_royalty_bps) {
+        return Err(EventRegistryError::InvalidResaleRoyaltyBps);
+    }
+
+    let platform_fee_percent = storage::get_platform_fee(env);
+
+    let event_info = EventInfo {
+        event_id: args.event_id.clone(),
+        organizer_address: args.organizer_address.clone(),
+        payment_address: args.payment_address.clone(),
+        platform_fee_percent,
+        is_active: true,
+        status: EventStatus::Active,
+        created_at: env.ledger().timestamp(),
+        metadata_cid: args.metadata_cid.clone(),
+        max_supply: args.max_supply,
+        current_supply: 0,
+        milestone_plan: args.milestone_plan.clone(),
+        tiers: args.tiers.clone(),
+        refund_deadline: args.refund_deadline,
+        restocking_fee: args.restocking_fee,
+        resale_cap_bps: args.resale_cap_bps,
+        is_postponed: false,
+        grace_period_end: 0,
+        min_sales_target: args.min_sales_target.unwrap_or(0),
+        target_deadline: args.target_deadline.unwrap_or(0),
+        goal_met: false,
+        visibility: args.visibility.clone(),
+        verified: false,
+        max_tickets_per_buyer: 0,
+        resale_royalty_bps: args.resale_royalty_bps,
+        event_start_time: 0,
+        transfer_lock_before_secs: 0,
+    };
+
+    storage::store_event(env, event_info);
+    if args.policy_flags != 0 {
+        storage::set_policy_flags(env, args.event_id.clone(), args.policy_flags);
+    }
+    if args.promo_mode != PromoMode::Shared {
+        storage::set_promo_mode(env, args.event_id.clone(), &args.promo_mode);
+    }
+    if let Some(recovery_address) = args.recovery_address.clone() {
+        storage::set_recovery_config(
+            env,
+            args.event_id.clone(),
+            &RecoveryConfig {
+                recovery_address,
+                inactivity_timeout: args.recovery_timeout,
+            },
+        );
+    }
+
+    #[allow(deprecated)]
+    env.events().publish(
+        (AgoraEvent::EventRegistered, args.event_id.clone()),
+        EventRegisteredEvent {
+            event_id: args.event_id.clone(),
+            organizer_address: args.organizer_address.clone(),
+            payment_address: args.payment_address.clone(),
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+
+    Ok(())
+}
+
+fn validate_metadata_cid(env: &Env, cid: &String) -> Result<(), EventRegistryError> {
+    if cid.len() < 46 {
+        return Err(EventRegistryError::InvalidMetadataCid);
+    }
+
+    // We expect CIDv1 base32, which starts with 'b'
+    // Convert to Bytes to check the first character safely
+    let mut bytes = soroban_sdk::Bytes::new(env);
+    bytes.append(&cid.clone().into());
+
+    if !bytes.is_empty() && bytes.get(0) != Some(b'b') {
+        return Err(EventRegistryError::InvalidMetadataCid);
+    }
+
+    Ok(())
+}
+
+/// Suspends all active events for a blacklisted organizer.
+/// This implements the "Suspension" ripple effect.
+fn suspend_organizer_events(
+    env: Env,
     organizer_address: Address,
 ) -> Result<(), EventRegistryError> {
     let organizer_events = storage::get_organizer_events(&env, &organizer_address);
@@ -848,12 +3067,328 @@ fn suspend_organizer_events(
     Ok(())
 }
 
+/// Share of revenue (bps) a milestone plan releases at `current_supply`: that of
+/// the highest milestone reached, or all of it without a plan.
+fn unlocked_release_percent(plan: &Option<Vec<Milestone>>, current_supply: i128) -> u32 {
+    match plan {
+        Some(milestones) if !milestones.is_empty() => milestones
+            .iter()
+            .filter(|m| current_supply >= m.sales_threshold)
+            .map(|m| m.release_percent)
+            .max()
+            .unwrap_or(0),
+        _ => MAX_BPS,
+    }
+}
+
+/// Checks a replacement milestone plan is well formed and unlocks at least as
+/// much revenue at the event's current sales as the plan in force.
+fn validate_milestone_update(
+    event_info: &EventInfo,
+    new_plan: &Vec<Milestone>,
+) -> Result<(), EventRegistryError> {
+    for milestone in new_plan.iter() {
+        if milestone.sales_threshold <= 0 {
+            return Err(EventRegistryError::InvalidQuantity);
+        }
+        if !is_valid_bps(milestone.release_percent) {
+            return Err(EventRegistryError::InvalidFeePercent);
+        }
+    }
+    let unlocked = unlocked_release_percent(&event_info.milestone_plan, event_info.current_supply);
+    let proposed = unlocked_release_percent(&Some(new_plan.clone()), event_info.current_supply);
+    if proposed < unlocked {
+        return Err(EventRegistryError::InvalidFeePercent);
+    }
+    Ok(())
+}
+
+/// Tickets of `tier_id` and of the whole event on unexpired holds, leaving out
+/// the hold `excluding` (one about to be replaced).
+fn active_holds(
+    env: &Env,
+    event_id: &String,
+    tier_id: &String,
+    excluding: Option<&String>,
+) -> (i128, i128) {
+    let now = env.ledger().timestamp();
+    let mut tier_held: i128 = 0;
+    let mut event_held: i128 = 0;
+    for hold_id in storage::get_event_holds(env, event_id.clone()).iter() {
+        if excluding == Some(&hold_id) {
+            continue;
+        }
+        let Some(hold) = storage::get_inventory_hold(env, hold_id) else {
+            continue;
+        };
+        if hold.expires_at <= now {
+            continue;
+        }
+        event_held += hold.quantity as i128;
+        if hold.tier_id == *tier_id {
+            tier_held += hold.quantity as i128;
+        }
+    }
+    (tier_held, event_held)
+}
+
+/// Authorizes a direct admin call. Direct calls are only honoured while the
+/// multi-sig threshold is 1; above that the action must go through `propose_action`.
+fn require_sole_admin(env: &Env) -> Result<Address, EventRegistryError> {
+    let admin = storage::get_admin(env).ok_or(EventRegistryError::NotInitialized)?;
+    admin.require_auth();
+    let config = storage::get_multisig_config(env).ok_or(EventRegistryError::NotInitialized)?;
+    if config.threshold > 1 {
+        return Err(EventRegistryError::Unauthorized);
+    }
+    Ok(admin)
+}
+
+fn load_pending_proposal(env: &Env, proposal_id: u64) -> Result<Proposal, EventRegistryError> {
+    let proposal =
+        storage::get_proposal(env, proposal_id).ok_or(EventRegistryError::ProposalNotFound)?;
+    if proposal.executed {
+        return Err(EventRegistryError::ProposalAlreadyExecuted);
+    }
+    if env.ledger().timestamp() > proposal.expires_at {
+        return Err(EventRegistryError::ProposalExpired);
+    }
+    Ok(proposal)
+}
+
+fn validate_admin_action(
+    env: &Env,
+    config: &MultiSigConfig,
+    action: &AdminAction,
+) -> Result<(), EventRegistryError> {
+    match action {
+        AdminAction::SetPlatformFee(fee) if !is_valid_bps(*fee) => {
+            Err(EventRegistryError::InvalidFeePercent)
+        }
+        AdminAction::SetPlatformWallet(wallet) => validate_address(env, wallet),
+        AdminAction::AddAdmin(admin) => {
+            validate_address(env, admin)?;
+            if config.admins.contains(admin) {
+                return Err(EventRegistryError::AdminAlreadyExists);
+            }
+            Ok(())
+        }
+        AdminAction::RemoveAdmin(admin) => {
+            if !config.admins.contains(admin) {
+                return Err(EventRegistryError::AdminNotFound);
+            }
+            if config.admins.len() == 1 {
+                return Err(EventRegistryError::CannotRemoveLastAdmin);
+            }
+            Ok(())
+        }
+        AdminAction::SetThreshold(threshold)
+            if *threshold == 0 || *threshold > config.admins.len() =>
+        {
+            Err(EventRegistryError::InvalidThreshold)
+        }
+        AdminAction::SetTimelock(_, fee_delta_bps) if !is_valid_bps(*fee_delta_bps) => {
+            Err(EventRegistryError::InvalidFeePercent)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Whether an admin action must wait out the configured timelock delay.
+fn is_timelocked(env: &Env, action: &AdminAction) -> bool {
+    let timelock = storage::get_timelock_config(env);
+    if timelock.delay == 0 {
+        return false;
+    }
+    match action {
+        AdminAction::SetPlatformFee(fee) => {
+            fee.abs_diff(storage::get_platform_fee(env)) > timelock.fee_delta_bps
+        }
+        AdminAction::SetPlatformWallet(_)
+        | AdminAction::Upgrade(_)
+        | AdminAction::SetTimelock(_, _) => true,
+        _ => false,
+    }
+}
+
+/// Applies a validated admin action on behalf of `executor`.
+fn apply_admin_action(
+    env: &Env,
+    config: MultiSigConfig,
+    executor: Address,
+    action: AdminAction,
+) -> Result<(), EventRegistryError> {
+    match action {
+        AdminAction::SetPlatformFee(fee) => apply_platform_fee(env, fee)?,
+        AdminAction::SetPlatformWallet(wallet) => storage::set_platform_wallet(env, &wallet),
+        AdminAction::BlacklistOrganizer(organizer, reason) => {
+            apply_blacklist(env, executor, organizer, reason)?
+        }
+        AdminAction::RemoveFromBlacklist(organizer, reason) => {
+            apply_unblacklist(env, executor, organizer, reason)?
+        }
+        AdminAction::Upgrade(new_wasm_hash) => apply_upgrade(env, new_wasm_hash)?,
+        AdminAction::AddAdmin(admin) => {
+            let mut config = config;
+            config.admins.push_back(admin);
+            storage::set_multisig_config(env, &config);
+        }
+        AdminAction::RemoveAdmin(admin) => {
+            let mut config = config;
+            if let Some(index) = config.admins.first_index_of(&admin) {
+                config.admins.remove(index);
+            }
+            config.threshold = config.threshold.min(config.admins.len());
+            // Keep the legacy single-admin slot pointing at a current admin.
+            if storage::get_admin(env) == Some(admin) {
+                if let Some(next) = config.admins.first() {
+                    storage::set_admin(env, &next);
+                }
+            }
+            storage::set_multisig_config(env, &config);
+        }
+        AdminAction::SetThreshold(threshold) => {
+            let mut config = config;
+            config.threshold = threshold;
+            storage::set_multisig_config(env, &config);
+        }
+        AdminAction::SetTimelock(delay, fee_delta_bps) => storage::set_timelock_config(
+            env,
+            &TimelockConfig {
+                delay,
+                fee_delta_bps,
+            },
+        ),
+    }
+    Ok(())
+}
+
+fn apply_platform_fee(env: &Env, new_fee_percent: u32) -> Result<(), EventRegistryError> {
+    if !is_valid_bps(new_fee_percent) {
+        return Err(EventRegistryError::InvalidFeePercent);
+    }
+
+    storage::set_platform_fee(env, new_fee_percent);
+
+    // Emit fee update event using contract event type
+    #[allow(deprecated)]
+    env.events().publish(
+        (AgoraEvent::FeeUpdated,),
+        FeeUpdatedEvent { new_fee_percent },
+    );
+
+    Ok(())
+}
+
+fn apply_upgrade(env: &Env, new_wasm_hash: BytesN<32>) -> Result<(), EventRegistryError> {
+    env.deployer().update_current_contract_wasm(new_wasm_hash);
+
+    // Post-upgrade state verification
+    let verified_admin = storage::get_admin(env).ok_or(EventRegistryError::NotInitialized)?;
+    storage::get_platform_wallet(env).ok_or(EventRegistryError::NotInitialized)?;
+
+    #[allow(deprecated)]
+    env.events().publish(
+        (AgoraEvent::ContractUpgraded,),
+        RegistryUpgradedEvent {
+            admin_address: verified_admin,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+
+    Ok(())
+}
+
+fn apply_blacklist(
+    env: &Env,
+    admin: Address,
+    organizer_address: Address,
+    reason: String,
+) -> Result<(), EventRegistryError> {
+    validate_address(env, &organizer_address)?;
+
+    // Check if already blacklisted
+    if storage::is_blacklisted(env, &organizer_address) {
+        return Err(EventRegistryError::OrganizerBlacklisted);
+    }
+
+    // Add to blacklist
+    storage::add_to_blacklist(env, &organizer_address);
+
+    // Create audit log entry
+    let audit_entry = BlacklistAuditEntry {
+        organizer_address: organizer_address.clone(),
+        added_to_blacklist: true,
+        admin_address: admin.clone(),
+        reason: reason.clone(),
+        timestamp: env.ledger().timestamp(),
+    };
+    storage::add_blacklist_audit_entry(env, audit_entry);
+
+    // Emit blacklist event
+    #[allow(deprecated)]
+    env.events().publish(
+        (AgoraEvent::OrganizerBlacklisted,),
+        OrganizerBlacklistedEvent {
+            organizer_address: organizer_address.clone(),
+            admin_address: admin.clone(),
+            reason: reason.clone(),
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+
+    // Suspend all active events from this organizer
+    suspend_organizer_events(env.clone(), organizer_address)?;
+
+    Ok(())
+}
+
+fn apply_unblacklist(
+    env: &Env,
+    admin: Address,
+    organizer_address: Address,
+    reason: String,
+) -> Result<(), EventRegistryError> {
+    validate_address(env, &organizer_address)?;
+
+    // Check if currently blacklisted
+    if !storage::is_blacklisted(env, &organizer_address) {
+        return Err(EventRegistryError::OrganizerNotBlacklisted);
+    }
+
+    // Remove from blacklist
+    storage::remove_from_blacklist(env, &organizer_address);
+
+    // Create audit log entry
+    let audit_entry = BlacklistAuditEntry {
+        organizer_address: organizer_address.clone(),
+        added_to_blacklist: false,
+        admin_address: admin.clone(),
+        reason: reason.clone(),
+        timestamp: env.ledger().timestamp(),
+    };
+    storage::add_blacklist_audit_entry(env, audit_entry);
+
+    // Emit removal event
+    #[allow(deprecated)]
+    env.events().publish(
+        (AgoraEvent::OrganizerRemovedFromBlacklist,),
+        OrganizerRemovedFromBlacklistEvent {
+            organizer_address,
+            admin_address: admin,
+            reason,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod test;
 
 #[cfg(test)]
 mod test_e2e;
 
-// TODO: Uncomment when multisig functions are implemented
-// #[cfg(test)]
-// mod test_multisig;
+#[cfg(test)]
+mod test_multisig;
@@ -1,8 +1,60 @@
-use crate::types::{BlacklistAuditEntry, DataKey, EventInfo, MultiSigConfig, Proposal};
-use soroban_sdk::{vec, Address, Env, String, Vec};
+use crate::types::{
+    BlacklistAuditEntry, Bundle, DataKey, EventInfo, EventInfoV1, EventSchedule, EventStatus,
+    FlashSale, ImportProvenance, IndexKey, InventoryHold, Milestone, MultiSigConfig,
+    OrganizerProfile, OrganizerProposal, PromoMode, Proposal, QueuedAction, RecoveryConfig,
+    RefundPolicy, ScannerWindow, SeatMap, TimelockConfig,
+};
+use agora_pagination::{read_sharded, PageCursor};
+use soroban_sdk::{vec, Address, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec};
 
 const SHARD_SIZE: u32 = 50;
 
+/// Width of the creation-time buckets of the search index (one day).
+pub const CREATED_BUCKET_SECS: u64 = 86_400;
+
+/// Ledgers an event's persistent entries are kept alive for when extended
+/// (about 30 days at 5s ledgers).
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 518_400;
+/// Remaining TTL below which touching an event's entries extends them.
+pub const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+
+/// Extends a persistent entry's TTL if it exists and is close to archival.
+fn extend_persistent_ttl(env: &Env, key: &DataKey) {
+    let storage = env.storage().persistent();
+    if storage.has(key) {
+        storage.extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+}
+
+/// Extends the TTL of an event and every per-event entry stored alongside it,
+/// along with the contract instance. Returns false if the event is unknown.
+pub fn extend_event_ttl(env: &Env, event_id: String) -> bool {
+    let Some(event_info) = read_event(env, event_id.clone()) else {
+        return false;
+    };
+    extend_persistent_ttl(env, &DataKey::Event(event_id.clone()));
+    for key in [
+        DataKey::RecoveryConfig(event_id.clone()),
+        DataKey::PolicyFlags(event_id.clone()),
+        DataKey::PromoMode(event_id.clone()),
+        DataKey::ImportProvenance(event_id.clone()),
+        DataKey::RefundPolicy(event_id.clone()),
+        DataKey::EventSchedule(event_id.clone()),
+        DataKey::EventFeeOverride(event_id.clone()),
+        DataKey::EventHolds(event_id.clone()),
+    ] {
+        extend_persistent_ttl(env, &key);
+    }
+    for tier_id in event_info.tiers.keys() {
+        extend_persistent_ttl(env, &DataKey::FlashSale(event_id.clone(), tier_id.clone()));
+        extend_persistent_ttl(env, &DataKey::SeatMap(event_id.clone(), tier_id));
+    }
+    env.storage()
+        .instance()
+        .extend_ttl(PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    true
+}
+
 /// Sets the administrator address of the contract (legacy function).
 pub fn set_admin(env: &Env, admin: &Address) {
     env.storage().persistent().set(&DataKey::Admin, admin);
@@ -13,6 +65,22 @@ pub fn get_admin(env: &Env) -> Option<Address> {
     env.storage().persistent().get(&DataKey::Admin)
 }
 
+/// Stores the admin nominated by `transfer_admin`, or clears it.
+pub fn set_pending_admin(env: &Env, admin: Option<Address>) {
+    match admin {
+        Some(admin) => env
+            .storage()
+            .persistent()
+            .set(&DataKey::PendingAdmin, &admin),
+        None => env.storage().persistent().remove(&DataKey::PendingAdmin),
+    }
+}
+
+/// Retrieves the admin nominated by `transfer_admin`, if any.
+pub fn get_pending_admin(env: &Env) -> Option<Address> {
+    env.storage().persistent().get(&DataKey::PendingAdmin)
+}
+
 /// Sets the multi-signature configuration.
 pub fn set_multisig_config(env: &Env, config: &MultiSigConfig) {
     env.storage()
@@ -82,6 +150,43 @@ pub fn is_initialized(env: &Env) -> bool {
         .unwrap_or(false)
 }
 
+/// Sets the layout version storage has been migrated to.
+pub fn set_storage_version(env: &Env, version: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::StorageVersion, &version);
+}
+
+/// Retrieves the layout version storage has been migrated to; deployments
+/// that predate versioning are at version 1.
+pub fn get_storage_version(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StorageVersion)
+        .unwrap_or(1)
+}
+
+/// Stores the platform wallet awaiting acceptance, or clears it.
+pub fn set_pending_platform_wallet(env: &Env, wallet: Option<Address>) {
+    match wallet {
+        Some(wallet) => env
+            .storage()
+            .persistent()
+            .set(&DataKey::PendingPlatformWallet, &wallet),
+        None => env
+            .storage()
+            .persistent()
+            .remove(&DataKey::PendingPlatformWallet),
+    }
+}
+
+/// Retrieves the platform wallet awaiting acceptance, if any.
+pub fn get_pending_platform_wallet(env: &Env) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PendingPlatformWallet)
+}
+
 /// Gets the next proposal ID and increments the counter.
 pub fn get_next_proposal_id(env: &Env) -> u64 {
     let current: u64 = env
@@ -127,6 +232,77 @@ pub fn get_proposal(env: &Env, proposal_id: u64) -> Option<Proposal> {
         .get(&DataKey::Proposal(proposal_id))
 }
 
+/// Retrieves the timelock configuration; disabled (no delay) by default.
+pub fn get_timelock_config(env: &Env) -> TimelockConfig {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TimelockConfig)
+        .unwrap_or(TimelockConfig {
+            delay: 0,
+            fee_delta_bps: 0,
+        })
+}
+
+/// Stores the timelock configuration.
+pub fn set_timelock_config(env: &Env, config: &TimelockConfig) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TimelockConfig, config);
+}
+
+/// Stores a queued admin action.
+pub fn store_queued_action(env: &Env, queued: &QueuedAction) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::QueuedAction(queued.action_id), queued);
+}
+
+/// Retrieves a queued admin action by ID.
+pub fn get_queued_action(env: &Env, action_id: u64) -> Option<QueuedAction> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::QueuedAction(action_id))
+}
+
+/// Removes a cancelled admin action from the queue.
+pub fn remove_queued_action(env: &Env, action_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::QueuedAction(action_id));
+}
+
+/// Stores an organizer action proposal.
+pub fn store_organizer_proposal(env: &Env, proposal: &OrganizerProposal) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::OrganizerProposal(proposal.proposal_id), proposal);
+}
+
+/// Retrieves an organizer action proposal by ID.
+pub fn get_organizer_proposal(env: &Env, proposal_id: u64) -> Option<OrganizerProposal> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OrganizerProposal(proposal_id))
+}
+
+/// Allows or disallows an address to propose organizer actions for an event.
+pub fn set_action_proposer(env: &Env, event_id: String, proposer: &Address, allowed: bool) {
+    let key = DataKey::ActionProposer(event_id, proposer.clone());
+    if allowed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Checks if an address may propose organizer actions for an event.
+pub fn is_action_proposer(env: &Env, event_id: String, proposer: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ActionProposer(event_id, proposer.clone()))
+        .unwrap_or(false)
+}
+
 /// Retrieves all active proposal IDs.
 pub fn get_active_proposals(env: &Env) -> Vec<u64> {
     env.storage()
@@ -161,6 +337,7 @@ pub fn store_event(env: &Env, event_info: EventInfo) {
     env.storage()
         .persistent()
         .set(&DataKey::Event(event_id.clone()), &event_info);
+    extend_persistent_ttl(env, &DataKey::Event(event_id.clone()));
 
     // Update organizer's event index if it doesn't exist
     if !has_organizer_event(env, &organizer, event_id.clone()) {
@@ -186,22 +363,246 @@ pub fn store_event(env: &Env, event_info: EventInfo) {
 
         env.storage()
             .persistent()
-            .set(&DataKey::OrganizerEvent(organizer, event_id), &true);
+            .set(&DataKey::OrganizerEvent(organizer, event_id.clone()), &true);
+
+        add_to_global_event_index(env, event_id);
     }
+    index_event(env, &event_info);
+}
+
+/// Appends an event_id to the sharded global event index.
+fn add_to_global_event_index(env: &Env, event_id: String) {
+    let count = get_global_event_count(env);
+    let shard_id = count / SHARD_SIZE;
+
+    let mut shard: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::GlobalEventShard(shard_id))
+        .unwrap_or_else(|| vec![env]);
+
+    shard.push_back(event_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::GlobalEventShard(shard_id), &shard);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::GlobalEventCount, &(count + 1));
+}
+
+/// Retrieves the total number of events in the global index.
+pub fn get_global_event_count(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::GlobalEventCount)
+        .unwrap_or(0)
+}
+
+/// Retrieves the cursor's window of the global index, reading only the shards
+/// that overlap it.
+pub fn get_events_page(env: &Env, cursor: PageCursor) -> Vec<String> {
+    read_sharded(
+        env,
+        get_global_event_count(env),
+        SHARD_SIZE,
+        cursor,
+        |shard_id| {
+            env.storage()
+                .persistent()
+                .get(&DataKey::GlobalEventShard(shard_id))
+        },
+    )
+}
+
+/// Retrieves the cursor's window of an organizer's event_ids.
+pub fn get_organizer_events_page(
+    env: &Env,
+    organizer: &Address,
+    cursor: PageCursor,
+) -> Vec<String> {
+    let count = get_organizer_event_count(env, organizer);
+    read_sharded(env, count, SHARD_SIZE, cursor, |shard_id| {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OrganizerEventShard(organizer.clone(), shard_id))
+    })
 }
 
 /// Updates event data without touching organizer index.
 /// Use this for mutations on already-registered events.
 pub fn update_event(env: &Env, event_info: EventInfo) {
-    let event_id = event_info.event_id.clone();
+    let key = DataKey::Event(event_info.event_id.clone());
+    env.storage().persistent().set(&key, &event_info);
+    extend_persistent_ttl(env, &key);
+    index_event(env, &event_info);
+}
+
+/// Brings an event's search index entries in line with its record: an event
+/// seen for the first time joins its creation bucket and status index, and one
+/// whose status changed moves between status indices.
+pub fn index_event(env: &Env, event_info: &EventInfo) {
+    let entry_key = DataKey::SearchIndex(IndexKey::StatusEntry(event_info.event_id.clone()));
+    let entry: Option<(EventStatus, u32)> = env.storage().persistent().get(&entry_key);
+    match entry {
+        Some((status, _)) if status == event_info.status => return,
+        Some((status, position)) => remove_from_status_index(env, &status, position),
+        None => {
+            let bucket_key = DataKey::SearchIndex(IndexKey::CreatedBucket(
+                event_info.created_at / CREATED_BUCKET_SECS,
+            ));
+            let mut bucket: Vec<String> = env
+                .storage()
+                .persistent()
+                .get(&bucket_key)
+                .unwrap_or_else(|| vec![env]);
+            bucket.push_back(event_info.event_id.clone());
+            env.storage().persistent().set(&bucket_key, &bucket);
+        }
+    }
+
+    let status = event_info.status.clone();
+    let position = get_status_event_count(env, &status);
+    let shard_key =
+        DataKey::SearchIndex(IndexKey::StatusShard(status.clone(), position / SHARD_SIZE));
+    let mut shard: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&shard_key)
+        .unwrap_or_else(|| vec![env]);
+    shard.push_back(event_info.event_id.clone());
+    env.storage().persistent().set(&shard_key, &shard);
+    env.storage().persistent().set(
+        &DataKey::SearchIndex(IndexKey::StatusCount(status.clone())),
+        &(position + 1),
+    );
+    env.storage()
+        .persistent()
+        .set(&entry_key, &(status, position));
+}
+
+/// Removes the event_id at `position` of a status index by moving the last
+/// entry into its place.
+fn remove_from_status_index(env: &Env, status: &EventStatus, position: u32) {
+    let last = get_status_event_count(env, status) - 1;
+    let last_key = DataKey::SearchIndex(IndexKey::StatusShard(status.clone(), last / SHARD_SIZE));
+    let mut last_shard: Vec<String> = env.storage().persistent().get(&last_key).unwrap();
+    let moved = last_shard.pop_back().unwrap();
+    if last_shard.is_empty() {
+        env.storage().persistent().remove(&last_key);
+    } else {
+        env.storage().persistent().set(&last_key, &last_shard);
+    }
+
+    if position != last {
+        let shard_key =
+            DataKey::SearchIndex(IndexKey::StatusShard(status.clone(), position / SHARD_SIZE));
+        let mut shard: Vec<String> = env.storage().persistent().get(&shard_key).unwrap();
+        shard.set(position % SHARD_SIZE, moved.clone());
+        env.storage().persistent().set(&shard_key, &shard);
+        env.storage().persistent().set(
+            &DataKey::SearchIndex(IndexKey::StatusEntry(moved)),
+            &(status.clone(), position),
+        );
+    }
+    env.storage().persistent().set(
+        &DataKey::SearchIndex(IndexKey::StatusCount(status.clone())),
+        &last,
+    );
+}
+
+/// Retrieves the number of events currently indexed under a status.
+pub fn get_status_event_count(env: &Env, status: &EventStatus) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SearchIndex(IndexKey::StatusCount(status.clone())))
+        .unwrap_or(0)
+}
+
+/// Retrieves the cursor's window of the event_ids indexed under a status.
+pub fn get_status_events_page(env: &Env, status: &EventStatus, cursor: PageCursor) -> Vec<String> {
+    read_sharded(
+        env,
+        get_status_event_count(env, status),
+        SHARD_SIZE,
+        cursor,
+        |shard_id| {
+            env.storage()
+                .persistent()
+                .get(&DataKey::SearchIndex(IndexKey::StatusShard(
+                    status.clone(),
+                    shard_id,
+                )))
+        },
+    )
+}
+
+/// Retrieves the event_ids of a creation-time bucket in creation order.
+pub fn get_created_bucket(env: &Env, bucket: u64) -> Vec<String> {
     env.storage()
         .persistent()
-        .set(&DataKey::Event(event_id), &event_info);
+        .get(&DataKey::SearchIndex(IndexKey::CreatedBucket(bucket)))
+        .unwrap_or_else(|| vec![env])
 }
 
 /// Retrieves event information by event_id.
 pub fn get_event(env: &Env, event_id: String) -> Option<EventInfo> {
-    env.storage().persistent().get(&DataKey::Event(event_id))
+    let mut event_info = read_event(env, event_id.clone())?;
+    extend_persistent_ttl(env, &DataKey::Event(event_id));
+    event_info.verified = is_verified_organizer(env, &event_info.organizer_address);
+    Some(event_info)
+}
+
+/// Reads an event record in whichever layout it was written, converting
+/// version 1 records to the current layout. Converted records are persisted
+/// in the current layout the next time the event is updated.
+fn read_event(env: &Env, event_id: String) -> Option<EventInfo> {
+    let mut raw: Map<Symbol, Val> = env.storage().persistent().get(&DataKey::Event(event_id))?;
+    // Version 1 records predate the visibility field
+    if !raw.contains_key(Symbol::new(env, "visibility")) {
+        return Some(
+            EventInfoV1::try_from_val(env, &raw.to_val())
+                .unwrap()
+                .into_current(env),
+        );
+    }
+    // Version 3 records predate the per-buyer ticket limit, which defaults to none
+    let limit_key = Symbol::new(env, "max_tickets_per_buyer");
+    if !raw.contains_key(limit_key.clone()) {
+        raw.set(limit_key, 0u32.into_val(env));
+    }
+    // Version 4 records predate the resale royalty, which defaults to none
+    let royalty_key = Symbol::new(env, "resale_royalty_bps");
+    if !raw.contains_key(royalty_key.clone()) {
+        raw.set(royalty_key, 0u32.into_val(env));
+    }
+    // Version 5 records predate the start time and transfer lock, both unset
+    for field in ["event_start_time", "transfer_lock_before_secs"] {
+        let key = Symbol::new(env, field);
+        if !raw.contains_key(key.clone()) {
+            raw.set(key, 0u64.into_val(env));
+        }
+    }
+    // Version 2 tiers predate early-bird and USD pricing, which default to off
+    let tiers_key = Symbol::new(env, "tiers");
+    let tiers: Map<String, Map<Symbol, Val>> =
+        Map::try_from_val(env, &raw.get(tiers_key.clone()).unwrap()).unwrap();
+    if tiers
+        .values()
+        .iter()
+        .any(|tier| !tier.contains_key(Symbol::new(env, "usd_price")))
+    {
+        let mut upgraded: Map<String, Map<Symbol, Val>> = Map::new(env);
+        for (tier_id, mut tier) in tiers.iter() {
+            for field in ["early_bird_price", "early_bird_quantity", "usd_price"] {
+                tier.set(Symbol::new(env, field), 0i128.into_val(env));
+            }
+            tier.set(Symbol::new(env, "early_bird_deadline"), 0u64.into_val(env));
+            upgraded.set(tier_id, tier);
+        }
+        raw.set(tiers_key, upgraded.to_val());
+    }
+    Some(EventInfo::try_from_val(env, &raw.to_val()).unwrap())
 }
 
 /// Checks if an event with the given event_id exists.
@@ -270,6 +671,27 @@ pub fn get_ticket_payment_contract(env: &Env) -> Option<Address> {
         .get(&DataKey::TicketPaymentContract)
 }
 
+/// Stores an organizer profile.
+pub fn set_organizer_profile(env: &Env, profile: &OrganizerProfile) {
+    env.storage().persistent().set(
+        &DataKey::OrganizerProfile(profile.organizer.clone()),
+        profile,
+    );
+}
+
+/// Retrieves an organizer profile, if the organizer registered one.
+pub fn get_organizer_profile(env: &Env, organizer: &Address) -> Option<OrganizerProfile> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OrganizerProfile(organizer.clone()))
+}
+
+/// Checks if an organizer holds a verified profile and is not blacklisted.
+pub fn is_verified_organizer(env: &Env, organizer: &Address) -> bool {
+    !is_blacklisted(env, organizer)
+        && get_organizer_profile(env, organizer).is_some_and(|profile| profile.verified)
+}
+
 /// Checks if an organizer is blacklisted.
 pub fn is_blacklisted(env: &Env, organizer: &Address) -> bool {
     env.storage()
@@ -292,21 +714,81 @@ pub fn remove_from_blacklist(env: &Env, organizer: &Address) {
         .remove(&DataKey::BlacklistedOrganizer(organizer.clone()));
 }
 
-/// Adds an audit log entry for blacklist actions.
+/// Appends a blacklist audit entry to the global log and to the organizer's
+/// history. Each append rewrites only the last shard of either log.
 pub fn add_blacklist_audit_entry(env: &Env, entry: BlacklistAuditEntry) {
-    let mut audit_log: Vec<BlacklistAuditEntry> = get_blacklist_audit_log(env);
-    audit_log.push_back(entry);
+    let organizer = entry.organizer_address.clone();
+
+    let count = get_blacklist_audit_log_count(env);
+    append_audit_entry(env, DataKey::BlacklistLogShard(count / SHARD_SIZE), &entry);
     env.storage()
         .persistent()
-        .set(&DataKey::BlacklistLog, &audit_log);
+        .set(&DataKey::BlacklistLogCount, &(count + 1));
+
+    let count = get_blacklist_history_count(env, &organizer);
+    append_audit_entry(
+        env,
+        DataKey::BlacklistHistoryShard(organizer.clone(), count / SHARD_SIZE),
+        &entry,
+    );
+    env.storage()
+        .persistent()
+        .set(&DataKey::BlacklistHistoryCount(organizer), &(count + 1));
+}
+
+fn append_audit_entry(env: &Env, shard_key: DataKey, entry: &BlacklistAuditEntry) {
+    let mut shard: Vec<BlacklistAuditEntry> = env
+        .storage()
+        .persistent()
+        .get(&shard_key)
+        .unwrap_or_else(|| vec![env]);
+    shard.push_back(entry.clone());
+    env.storage().persistent().set(&shard_key, &shard);
 }
 
-/// Retrieves the blacklist audit log.
-pub fn get_blacklist_audit_log(env: &Env) -> Vec<BlacklistAuditEntry> {
+/// Retrieves the total number of entries in the blacklist audit log.
+pub fn get_blacklist_audit_log_count(env: &Env) -> u32 {
     env.storage()
         .persistent()
-        .get(&DataKey::BlacklistLog)
-        .unwrap_or_else(|| Vec::new(env))
+        .get(&DataKey::BlacklistLogCount)
+        .unwrap_or(0)
+}
+
+/// Retrieves the cursor's window of the blacklist audit log, oldest first.
+pub fn get_blacklist_audit_log_page(env: &Env, cursor: PageCursor) -> Vec<BlacklistAuditEntry> {
+    read_sharded(
+        env,
+        get_blacklist_audit_log_count(env),
+        SHARD_SIZE,
+        cursor,
+        |shard_id| {
+            env.storage()
+                .persistent()
+                .get(&DataKey::BlacklistLogShard(shard_id))
+        },
+    )
+}
+
+/// Retrieves the number of blacklist audit entries recorded for an organizer.
+pub fn get_blacklist_history_count(env: &Env, organizer: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BlacklistHistoryCount(organizer.clone()))
+        .unwrap_or(0)
+}
+
+/// Retrieves the cursor's window of an organizer's blacklist history, oldest first.
+pub fn get_blacklist_history_page(
+    env: &Env,
+    organizer: &Address,
+    cursor: PageCursor,
+) -> Vec<BlacklistAuditEntry> {
+    let count = get_blacklist_history_count(env, organizer);
+    read_sharded(env, count, SHARD_SIZE, cursor, |shard_id| {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BlacklistHistoryShard(organizer.clone(), shard_id))
+    })
 }
 
 /// Sets the global promotional discount in basis points.
@@ -339,25 +821,292 @@ pub fn get_promo_expiry(env: &Env) -> u64 {
         .unwrap_or(0)
 }
 
-/// Authorizes a scanner for an event.
-pub fn authorize_scanner(env: &Env, event_id: String, scanner: &Address) {
+/// Stores the flash sale scheduled for an event tier.
+pub fn set_flash_sale(env: &Env, event_id: String, tier_id: String, sale: &FlashSale) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::FlashSale(event_id, tier_id), sale);
+}
+
+/// Retrieves the flash sale scheduled for an event tier, if any.
+pub fn get_flash_sale(env: &Env, event_id: String, tier_id: String) -> Option<FlashSale> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FlashSale(event_id, tier_id))
+}
+
+/// Stores a bundle.
+pub fn set_bundle(env: &Env, bundle: &Bundle) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Bundle(bundle.bundle_id.clone()), bundle);
+}
+
+/// Retrieves a bundle, if registered.
+pub fn get_bundle(env: &Env, bundle_id: String) -> Option<Bundle> {
+    env.storage().persistent().get(&DataKey::Bundle(bundle_id))
+}
+
+/// Stores how the platform promo applies to an event.
+pub fn set_promo_mode(env: &Env, event_id: String, mode: &PromoMode) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PromoMode(event_id), mode);
+}
+
+/// Retrieves how the platform promo applies to an event (Shared if unset).
+pub fn get_promo_mode(env: &Env, event_id: String) -> PromoMode {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PromoMode(event_id))
+        .unwrap_or(PromoMode::Shared)
+}
+
+/// Stores the escrow recovery configuration for an event.
+pub fn set_recovery_config(env: &Env, event_id: String, config: &RecoveryConfig) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RecoveryConfig(event_id), config);
+}
+
+/// Retrieves the escrow recovery configuration for an event, if any.
+pub fn get_recovery_config(env: &Env, event_id: String) -> Option<RecoveryConfig> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RecoveryConfig(event_id))
+}
+
+/// Records where an imported event came from.
+pub fn set_import_provenance(env: &Env, event_id: String, provenance: &ImportProvenance) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ImportProvenance(event_id), provenance);
+}
+
+/// Retrieves the import provenance for an event, if it was imported.
+pub fn get_import_provenance(env: &Env, event_id: String) -> Option<ImportProvenance> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ImportProvenance(event_id))
+}
+
+/// Stores the declared policy flags for an event.
+pub fn set_policy_flags(env: &Env, event_id: String, flags: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PolicyFlags(event_id), &flags);
+}
+
+/// Retrieves the declared policy flags for an event (0 if none).
+pub fn get_policy_flags(env: &Env, event_id: String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PolicyFlags(event_id))
+        .unwrap_or(0)
+}
+
+/// Stores the platform's policy requirements (trigger flag -> required flags).
+pub fn set_policy_requirements(env: &Env, requirements: &Map<u32, u32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PolicyRequirements, requirements);
+}
+
+/// Retrieves the platform's policy requirements.
+pub fn get_policy_requirements(env: &Env) -> Map<u32, u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PolicyRequirements)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+/// Authorizes a scanner for an event, optionally only within `window`.
+pub fn authorize_scanner(
+    env: &Env,
+    event_id: String,
+    scanner: &Address,
+    window: Option<ScannerWindow>,
+) {
     env.storage().persistent().set(
-        &DataKey::AuthorizedScanner(event_id, scanner.clone()),
+        &DataKey::AuthorizedScanner(event_id.clone(), scanner.clone()),
         &true,
     );
+    let window_key = DataKey::ScannerWindow(event_id, scanner.clone());
+    match window {
+        Some(window) => env.storage().persistent().set(&window_key, &window),
+        None => env.storage().persistent().remove(&window_key),
+    }
 }
 
 /// Removes authorization for a scanner from an event.
 pub fn remove_scanner(env: &Env, event_id: String, scanner: &Address) {
     env.storage()
         .persistent()
-        .remove(&DataKey::AuthorizedScanner(event_id, scanner.clone()));
+        .remove(&DataKey::AuthorizedScanner(
+            event_id.clone(),
+            scanner.clone(),
+        ));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ScannerWindow(event_id, scanner.clone()));
+}
+
+/// Retrieves the validity window of a scanner, if its authorization is time-boxed.
+pub fn get_scanner_window(env: &Env, event_id: String, scanner: &Address) -> Option<ScannerWindow> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ScannerWindow(event_id, scanner.clone()))
 }
 
-/// Checks if a scanner is authorized for an event.
+/// Checks if a scanner is authorized for an event at the current ledger time.
 pub fn is_scanner_authorized(env: &Env, event_id: String, scanner: &Address) -> bool {
+    let authorized = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AuthorizedScanner(
+            event_id.clone(),
+            scanner.clone(),
+        ))
+        .unwrap_or(false);
+    authorized
+        && get_scanner_window(env, event_id, scanner)
+            .is_none_or(|window| window.contains(env.ledger().timestamp()))
+}
+
+/// Stores the refund policy of an event.
+pub fn set_refund_policy(env: &Env, event_id: String, policy: &RefundPolicy) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RefundPolicy(event_id), policy);
+}
+
+/// Retrieves the refund policy of an event, if one was set.
+pub fn get_refund_policy(env: &Env, event_id: String) -> Option<RefundPolicy> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RefundPolicy(event_id))
+}
+
+/// Stores the schedule of an event.
+pub fn set_event_schedule(env: &Env, event_id: String, schedule: &EventSchedule) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::EventSchedule(event_id), schedule);
+}
+
+/// Retrieves the schedule of an event, if an end time was ever set.
+pub fn get_event_schedule(env: &Env, event_id: String) -> Option<EventSchedule> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EventSchedule(event_id))
+}
+
+/// Stores the negotiated platform fee of an event.
+pub fn set_event_fee_override(env: &Env, event_id: String, fee_bps: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::EventFeeOverride(event_id), &fee_bps);
+}
+
+/// Retrieves the negotiated platform fee of an event, if one was set.
+pub fn get_event_fee_override(env: &Env, event_id: String) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EventFeeOverride(event_id))
+}
+
+/// Stores a milestone plan proposed by an event's organizer, replacing any
+/// earlier proposal.
+pub fn set_pending_milestone_plan(env: &Env, event_id: String, plan: &Vec<Milestone>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PendingMilestonePlan(event_id), plan);
+}
+
+/// Retrieves the milestone plan awaiting admin approval for an event, if any.
+pub fn get_pending_milestone_plan(env: &Env, event_id: String) -> Option<Vec<Milestone>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PendingMilestonePlan(event_id))
+}
+
+pub fn remove_pending_milestone_plan(env: &Env, event_id: String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PendingMilestonePlan(event_id));
+}
+
+/// Stores an inventory hold, replacing any hold with the same id.
+pub fn set_inventory_hold(env: &Env, hold_id: String, hold: &InventoryHold) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::InventoryHold(hold_id), hold);
+}
+
+/// Retrieves an inventory hold, whether or not it has expired.
+pub fn get_inventory_hold(env: &Env, hold_id: String) -> Option<InventoryHold> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InventoryHold(hold_id))
+}
+
+pub fn remove_inventory_hold(env: &Env, hold_id: String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::InventoryHold(hold_id));
+}
+
+/// Retrieves the ids of an event's inventory holds.
+pub fn get_event_holds(env: &Env, event_id: String) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EventHolds(event_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_event_holds(env: &Env, event_id: String, hold_ids: &Vec<String>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::EventHolds(event_id), hold_ids);
+}
+
+/// Stores the seat map of an event tier, or removes it to return the tier to
+/// general admission.
+pub fn set_seat_map(env: &Env, event_id: String, tier_id: String, seat_map: Option<SeatMap>) {
+    let key = DataKey::SeatMap(event_id, tier_id);
+    match seat_map {
+        Some(seat_map) => env.storage().persistent().set(&key, &seat_map),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Retrieves the seat map of an event tier, if it uses assigned seating.
+pub fn get_seat_map(env: &Env, event_id: String, tier_id: String) -> Option<SeatMap> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SeatMap(event_id, tier_id))
+}
+
+/// Marks a seat as reserved or releases it.
+pub fn set_seat_reserved(
+    env: &Env,
+    event_id: String,
+    tier_id: String,
+    seat_id: String,
+    reserved: bool,
+) {
+    let key = DataKey::ReservedSeat(event_id, tier_id, seat_id);
+    if reserved {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Checks whether a seat is currently reserved.
+pub fn is_seat_reserved(env: &Env, event_id: String, tier_id: String, seat_id: String) -> bool {
     env.storage()
         .persistent()
-        .get(&DataKey::AuthorizedScanner(event_id, scanner.clone()))
+        .get(&DataKey::ReservedSeat(event_id, tier_id, seat_id))
         .unwrap_or(false)
 }
@@ -1,10 +1,20 @@
 use super::*;
 use crate::error::EventRegistryError;
-use crate::types::EventStatus;
-use crate::types::{EventInfo, EventRegistrationArgs, TicketTier};
+use crate::events::{
+    CancellationNoticeFailedEvent, EventsBatchRegisteredEvent, MetadataBatchUpdatedEvent,
+};
+use crate::types::{
+    DutchAuction, EventInfo, EventRegistrationArgs, EventSchedule, RefundPolicy, RefundStep,
+    ScannerWindow, TicketTier, TierPricing,
+};
+use crate::types::{EventStatus, EventVisibility, OrganizerAction};
+use crate::types::{
+    POLICY_AGE_RESTRICTED, POLICY_JURISDICTION_LIMITED, POLICY_SANCTIONS_SCREENING,
+};
 use soroban_sdk::{
+    map,
     testutils::{Address as _, EnvTestConfig, Events, Ledger},
-    Address, Env, Map, String,
+    vec, Address, Env, Map, String, TryIntoVal, Vec,
 };
 
 #[test]
@@ -145,6 +155,12 @@ fn test_storage_operations() {
         min_sales_target: 0,
         target_deadline: 0,
         goal_met: false,
+        visibility: EventVisibility::Public,
+        verified: false,
+        max_tickets_per_buyer: 0,
+        resale_royalty_bps: 0,
+        event_start_time: 0,
+        transfer_lock_before_secs: 0,
     };
 
     client.store_event(&event_info);
@@ -198,6 +214,12 @@ fn test_organizer_events_list() {
         min_sales_target: 0,
         target_deadline: 0,
         goal_met: false,
+        visibility: EventVisibility::Public,
+        verified: false,
+        max_tickets_per_buyer: 0,
+        resale_royalty_bps: 0,
+        event_start_time: 0,
+        transfer_lock_before_secs: 0,
     };
 
     let event_2 = EventInfo {
@@ -224,6 +246,12 @@ fn test_organizer_events_list() {
         min_sales_target: 0,
         target_deadline: 0,
         goal_met: false,
+        visibility: EventVisibility::Public,
+        verified: false,
+        max_tickets_per_buyer: 0,
+        resale_royalty_bps: 0,
+        event_start_time: 0,
+        transfer_lock_before_secs: 0,
     };
 
     let contract_id = env.register(EventRegistry, ());
@@ -264,9 +292,19 @@ fn test_register_event_success() {
         TicketTier {
             name: String::from_str(&env, "General"),
             price: 5000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
             tier_limit: 100,
             current_sold: 0,
             is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
         },
     );
 
@@ -283,6 +321,12 @@ fn test_register_event_success() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     let payment_info = client.get_event_payment_info(&event_id);
@@ -330,6 +374,12 @@ fn test_register_event_unlimited_supply() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     let event_info = client.get_event(&event_id).unwrap();
@@ -370,6 +420,12 @@ fn test_register_duplicate_event_fails() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     let result = client.try_register_event(&EventRegistrationArgs {
@@ -385,6 +441,12 @@ fn test_register_duplicate_event_fails() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
     assert_eq!(result, Err(Ok(EventRegistryError::EventAlreadyExists)));
 }
@@ -422,6 +484,12 @@ fn test_get_event_payment_info() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     let info = client.get_event_payment_info(&event_id);
@@ -429,6 +497,168 @@ fn test_get_event_payment_info() {
     assert_eq!(info.platform_fee_percent, 750);
 }
 
+#[test]
+fn test_event_fee_override_takes_precedence() {
+    let env = Env::default();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    env.mock_all_auths();
+
+    client.initialize(&admin, &platform_wallet, &750);
+
+    let event_id = String::from_str(&env, "event_fee");
+    client.register_event(&EventRegistrationArgs {
+        event_id: event_id.clone(),
+        organizer_address: organizer.clone(),
+        payment_address: organizer,
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 50,
+        milestone_plan: None,
+        tiers: Map::new(&env),
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    });
+    assert_eq!(client.get_event_fee_override(&event_id), None);
+
+    // Overrides are negotiated discounts and cannot exceed the registered fee
+    assert_eq!(
+        client.try_set_event_fee_override(&event_id, &751),
+        Err(Ok(EventRegistryError::InvalidFeePercent))
+    );
+    assert_eq!(
+        client.try_set_event_fee_override(&String::from_str(&env, "missing"), &100),
+        Err(Ok(EventRegistryError::EventNotFound))
+    );
+
+    client.set_event_fee_override(&event_id, &300);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(client.get_event_fee_override(&event_id), Some(300));
+    assert_eq!(
+        client
+            .get_event_payment_info(&event_id)
+            .platform_fee_percent,
+        300
+    );
+    // The global fee and the registered event fee are unchanged
+    assert_eq!(client.get_platform_fee(), 750);
+    assert_eq!(
+        client.get_event(&event_id).unwrap().platform_fee_percent,
+        750
+    );
+}
+
+#[test]
+fn test_milestone_plan_update_requires_approval_and_never_locks_revenue() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    client.initialize(&admin, &Address::generate(&env), &500);
+    client.set_ticket_payment_contract(&Address::generate(&env));
+
+    let milestone = |sales_threshold: i128, release_percent: u32| Milestone {
+        sales_threshold,
+        release_percent,
+    };
+    let mut tiers = Map::new(&env);
+    let tier_id = String::from_str(&env, "general");
+    tiers.set(
+        tier_id.clone(),
+        TicketTier {
+            name: String::from_str(&env, "General"),
+            price: 5000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
+            tier_limit: 20,
+            current_sold: 0,
+            is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
+        },
+    );
+    let event_id = String::from_str(&env, "milestones");
+    client.register_event(&EventRegistrationArgs {
+        event_id: event_id.clone(),
+        organizer_address: organizer.clone(),
+        payment_address: organizer,
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 20,
+        milestone_plan: Some(vec![&env, milestone(5, 2000), milestone(10, 10000)]),
+        tiers,
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    });
+    client.increment_inventory(&event_id, &tier_id, &6);
+
+    // 20% is already unlocked at 6 sales and cannot be taken back
+    assert_eq!(
+        client.try_propose_milestone_update(&event_id, &vec![&env, milestone(5, 1000)]),
+        Err(Ok(EventRegistryError::InvalidFeePercent))
+    );
+    assert_eq!(
+        client.try_propose_milestone_update(&event_id, &vec![&env, milestone(0, 5000)]),
+        Err(Ok(EventRegistryError::InvalidQuantity))
+    );
+    assert_eq!(
+        client.try_approve_milestone_update(&event_id),
+        Err(Ok(EventRegistryError::ProposalNotFound))
+    );
+
+    let new_plan = vec![&env, milestone(2, 3000), milestone(8, 10000)];
+    client.propose_milestone_update(&event_id, &new_plan);
+    assert_eq!(
+        client.get_pending_milestone_plan(&event_id),
+        Some(new_plan.clone())
+    );
+    assert_eq!(
+        client.get_event(&event_id).unwrap().milestone_plan,
+        Some(vec![&env, milestone(5, 2000), milestone(10, 10000)])
+    );
+
+    client.approve_milestone_update(&event_id);
+    assert_eq!(
+        client.get_event(&event_id).unwrap().milestone_plan,
+        Some(new_plan)
+    );
+    assert_eq!(client.get_pending_milestone_plan(&event_id), None);
+}
 #[test]
 fn test_update_event_status() {
     let env = Env::default();
@@ -462,6 +692,12 @@ fn test_update_event_status() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
     client.update_event_status(&event_id, &false);
 
@@ -501,6 +737,12 @@ fn test_event_inactive_error() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
     client.update_event_status(&event_id, &false);
 
@@ -541,6 +783,12 @@ fn test_complete_event_lifecycle() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     let payment_info = client.get_event_payment_info(&event_id);
@@ -593,6 +841,12 @@ fn test_update_metadata_success() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     let new_metadata_cid = String::from_str(
@@ -638,6 +892,12 @@ fn test_update_metadata_invalid_cid() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     let wrong_char_cid = String::from_str(
@@ -705,9 +965,19 @@ fn test_increment_inventory_success() {
         TicketTier {
             name: String::from_str(&env, "General"),
             price: 5000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
             tier_limit: 10,
             current_sold: 0,
             is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
         },
     );
 
@@ -724,6 +994,12 @@ fn test_increment_inventory_success() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     client.increment_inventory(&event_id, &tier_id, &1);
@@ -772,9 +1048,19 @@ fn test_increment_inventory_max_supply_exceeded() {
         TicketTier {
             name: String::from_str(&env, "General"),
             price: 5000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
             tier_limit: 2,
             current_sold: 0,
             is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
         },
     );
 
@@ -791,6 +1077,12 @@ fn test_increment_inventory_max_supply_exceeded() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     client.increment_inventory(&event_id, &tier_id, &1);
@@ -834,9 +1126,19 @@ fn test_increment_inventory_unlimited_supply() {
         TicketTier {
             name: String::from_str(&env, "General"),
             price: 5000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
             tier_limit: 1000,
             current_sold: 0,
             is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
         },
     );
 
@@ -853,6 +1155,12 @@ fn test_increment_inventory_unlimited_supply() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     for _ in 0..10 {
@@ -914,9 +1222,19 @@ fn test_increment_inventory_inactive_event() {
         TicketTier {
             name: String::from_str(&env, "General"),
             price: 5000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
             tier_limit: 100,
             current_sold: 0,
             is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
         },
     );
     client.register_event(&EventRegistrationArgs {
@@ -932,6 +1250,12 @@ fn test_increment_inventory_inactive_event() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     client.update_event_status(&event_id, &false);
@@ -969,9 +1293,19 @@ fn test_increment_inventory_persists_across_reads() {
         TicketTier {
             name: String::from_str(&env, "General"),
             price: 5000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
             tier_limit: 50,
             current_sold: 0,
             is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
         },
     );
     client.register_event(&EventRegistrationArgs {
@@ -987,6 +1321,12 @@ fn test_increment_inventory_persists_across_reads() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     for _ in 0..5 {
@@ -1029,9 +1369,19 @@ fn test_tier_limit_exceeds_max_supply() {
         TicketTier {
             name: String::from_str(&env, "General"),
             price: 5000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
             tier_limit: 60,
             current_sold: 0,
             is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
         },
     );
     tiers.set(
@@ -1039,9 +1389,19 @@ fn test_tier_limit_exceeds_max_supply() {
         TicketTier {
             name: String::from_str(&env, "VIP"),
             price: 10000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
             tier_limit: 50,
             current_sold: 0,
             is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
         },
     );
 
@@ -1058,6 +1418,12 @@ fn test_tier_limit_exceeds_max_supply() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
     assert_eq!(
         result,
@@ -1094,9 +1460,19 @@ fn test_tier_not_found() {
         TicketTier {
             name: String::from_str(&env, "General"),
             price: 5000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
             tier_limit: 100,
             current_sold: 0,
             is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
         },
     );
 
@@ -1113,6 +1489,12 @@ fn test_tier_not_found() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     let wrong_tier_id = String::from_str(&env, "nonexistent");
@@ -1150,9 +1532,19 @@ fn test_tier_supply_exceeded() {
         TicketTier {
             name: String::from_str(&env, "VIP"),
             price: 10000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
             tier_limit: 3,
             current_sold: 0,
             is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
         },
     );
 
@@ -1169,6 +1561,12 @@ fn test_tier_supply_exceeded() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     client.increment_inventory(&event_id, &tier_id, &1);
@@ -1211,9 +1609,19 @@ fn test_multiple_tiers_inventory() {
         TicketTier {
             name: String::from_str(&env, "General"),
             price: 5000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
             tier_limit: 50,
             current_sold: 0,
             is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
         },
     );
     tiers.set(
@@ -1221,9 +1629,19 @@ fn test_multiple_tiers_inventory() {
         TicketTier {
             name: String::from_str(&env, "VIP"),
             price: 10000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
             tier_limit: 20,
             current_sold: 0,
             is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
         },
     );
 
@@ -1240,6 +1658,12 @@ fn test_multiple_tiers_inventory() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     client.increment_inventory(&event_id, &general_id, &1);
@@ -1291,6 +1715,12 @@ fn test_update_event_status_noop_skips_event() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     let _ = env.events().all();
@@ -1363,6 +1793,12 @@ fn test_blacklist_prevents_event_registration() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     assert_eq!(result, Err(Ok(EventRegistryError::OrganizerBlacklisted)));
@@ -1404,6 +1840,12 @@ fn test_update_metadata_noop_skips_event() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     let _ = env.events().all();
@@ -1483,6 +1925,12 @@ fn test_blacklist_suspends_active_events() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     let event_info = client.get_event(&event_id).unwrap();
@@ -1555,6 +2003,84 @@ fn test_remove_non_blacklisted_fails() {
     assert_eq!(result, Err(Ok(EventRegistryError::OrganizerNotBlacklisted)));
 }
 
+#[test]
+fn test_organizer_verification_surfaces_in_event_info() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let cid = String::from_str(
+        &env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    // Only registered organizers can be verified
+    assert_eq!(
+        client.try_verify_organizer(&organizer),
+        Err(Ok(EventRegistryError::InvalidAddress))
+    );
+    assert_eq!(
+        client.try_register_organizer(&organizer, &String::from_str(&env, "bad")),
+        Err(Ok(EventRegistryError::InvalidMetadataCid))
+    );
+    client.register_organizer(&organizer, &cid);
+    assert_eq!(env.auths()[0].0, organizer);
+
+    let event_id = String::from_str(&env, "verified_event");
+    client.register_event(&EventRegistrationArgs {
+        event_id: event_id.clone(),
+        organizer_address: organizer.clone(),
+        payment_address: organizer.clone(),
+        metadata_cid: cid.clone(),
+        max_supply: 10,
+        milestone_plan: None,
+        tiers: Map::new(&env),
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    });
+    assert!(!client.get_event(&event_id).unwrap().verified);
+
+    client.verify_organizer(&organizer);
+    assert_eq!(env.auths()[0].0, admin);
+    let profile = client.get_organizer_profile(&organizer).unwrap();
+    assert!(profile.verified);
+    assert_eq!(profile.profile_cid, cid);
+    // Events registered before verification pick up the badge
+    assert!(client.get_event(&event_id).unwrap().verified);
+
+    // Swapping the profile document drops the badge until re-verified
+    let new_cid = String::from_str(
+        &env,
+        "bafybeihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku",
+    );
+    client.register_organizer(&organizer, &new_cid);
+    assert!(!client.is_organizer_verified(&organizer));
+    client.verify_organizer(&organizer);
+    assert!(client.is_organizer_verified(&organizer));
+
+    // Blacklisting hides the badge
+    client.blacklist_organizer(&organizer, &String::from_str(&env, "fraud"));
+    assert!(!client.get_event(&event_id).unwrap().verified);
+    assert_eq!(
+        client.try_verify_organizer(&organizer),
+        Err(Ok(EventRegistryError::OrganizerBlacklisted))
+    );
+}
+
 // ==================== Resale Cap Tests ====================
 
 #[test]
@@ -1582,9 +2108,19 @@ fn test_register_event_with_resale_cap() {
         TicketTier {
             name: String::from_str(&env, "General"),
             price: 5000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
             tier_limit: 100,
             current_sold: 0,
             is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
         },
     );
 
@@ -1601,6 +2137,12 @@ fn test_register_event_with_resale_cap() {
         resale_cap_bps: Some(1000), // 10% above face value
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     let event_info = client.get_event(&event_id).unwrap();
@@ -1641,6 +2183,12 @@ fn test_register_event_resale_cap_zero() {
         resale_cap_bps: Some(0), // No markup allowed
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     let event_info = client.get_event(&event_id).unwrap();
@@ -1681,6 +2229,12 @@ fn test_register_event_resale_cap_none() {
         resale_cap_bps: None, // No cap
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     let event_info = client.get_event(&event_id).unwrap();
@@ -1721,6 +2275,12 @@ fn test_postpone_event_sets_grace_period() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     // Set ledger time and grace period end in the future
@@ -1734,6 +2294,152 @@ fn test_postpone_event_sets_grace_period() {
     assert_eq!(event_info.grace_period_end, grace_period_end);
 }
 
+#[test]
+fn test_reschedule_and_resume_end_postponement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    client.initialize(&Address::generate(&env), &Address::generate(&env), &500);
+
+    let event_id = String::from_str(&env, "rescheduled_event");
+    client.register_event(&EventRegistrationArgs {
+        event_id: event_id.clone(),
+        organizer_address: organizer.clone(),
+        payment_address: organizer,
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 100,
+        milestone_plan: None,
+        tiers: Map::new(&env),
+        refund_deadline: 5_000,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    });
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.set_event_start_time(&event_id, &10_000);
+    assert_eq!(
+        client.try_resume_event(&event_id),
+        Err(Ok(EventRegistryError::EventNotPostponed))
+    );
+
+    // Moving the event three hours later pushes the refund deadline as far
+    client.postpone_event(&event_id, &2_000);
+    assert_eq!(
+        client.try_reschedule_event(&event_id, &1_000, &true),
+        Err(Ok(EventRegistryError::InvalidStartTime))
+    );
+    client.reschedule_event(&event_id, &20_800, &true);
+    let event_info = client.get_event(&event_id).unwrap();
+    assert!(!event_info.is_postponed);
+    assert_eq!(event_info.grace_period_end, 0);
+    assert_eq!(event_info.event_start_time, 20_800);
+    assert_eq!(event_info.refund_deadline, 15_800);
+
+    // Resuming keeps the date and the deadline
+    client.postpone_event(&event_id, &2_000);
+    client.resume_event(&event_id);
+    let event_info = client.get_event(&event_id).unwrap();
+    assert!(!event_info.is_postponed);
+    assert_eq!(event_info.grace_period_end, 0);
+    assert_eq!(event_info.event_start_time, 20_800);
+    assert_eq!(event_info.refund_deadline, 15_800);
+}
+
+#[test]
+fn test_search_events_by_status_and_creation_time() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    client.initialize(&Address::generate(&env), &Address::generate(&env), &500);
+
+    // One event on each of the first three days
+    let ids = [
+        String::from_str(&env, "day_0"),
+        String::from_str(&env, "day_1"),
+        String::from_str(&env, "day_2"),
+    ];
+    for (created_at, event_id) in [1_000u64, 90_000, 200_000].into_iter().zip(ids.iter()) {
+        env.ledger().with_mut(|li| li.timestamp = created_at);
+        client.register_event(&EventRegistrationArgs {
+            event_id: event_id.clone(),
+            organizer_address: organizer.clone(),
+            payment_address: organizer.clone(),
+            metadata_cid: String::from_str(
+                &env,
+                "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+            ),
+            max_supply: 100,
+            milestone_plan: None,
+            tiers: Map::new(&env),
+            refund_deadline: 0,
+            restocking_fee: 0,
+            resale_cap_bps: None,
+            min_sales_target: None,
+            target_deadline: None,
+            visibility: EventVisibility::Public,
+            recovery_address: None,
+            recovery_timeout: 0,
+            policy_flags: 0,
+            promo_mode: PromoMode::Shared,
+            resale_royalty_bps: 0,
+        });
+    }
+
+    // Cancelling moves the first event out of the Active index
+    client.cancel_event(&ids[0]);
+    assert_eq!(client.get_status_event_count(&EventStatus::Active), 2);
+    assert_eq!(
+        client.get_events_by_status(&EventStatus::Active, &0, &10),
+        vec![&env, ids[2].clone(), ids[1].clone()]
+    );
+    assert_eq!(
+        client.get_events_by_status(&EventStatus::Cancelled, &0, &10),
+        vec![&env, ids[0].clone()]
+    );
+    assert_eq!(
+        client.get_events_by_status(&EventStatus::Active, &1, &10),
+        vec![&env, ids[1].clone()]
+    );
+
+    assert_eq!(
+        client.get_events_created_between(&0, &100_000, &0, &10),
+        vec![&env, ids[0].clone(), ids[1].clone()]
+    );
+    assert_eq!(
+        client.get_events_created_between(&50_000, &200_000, &0, &10),
+        vec![&env, ids[1].clone(), ids[2].clone()]
+    );
+    assert_eq!(
+        client.get_events_created_between(&0, &300_000, &1, &1),
+        vec![&env, ids[1].clone()]
+    );
+    assert_eq!(
+        client.try_get_events_created_between(&2_000, &1_000, &0, &10),
+        Err(Ok(EventRegistryError::InvalidTimeRange))
+    );
+    assert_eq!(
+        client.try_get_events_created_between(&0, &(MAX_CREATED_RANGE_BUCKETS * 86_400), &0, &10),
+        Err(Ok(EventRegistryError::InvalidTimeRange))
+    );
+}
+
 #[test]
 fn test_register_event_resale_cap_invalid() {
     let env = Env::default();
@@ -1768,10 +2474,52 @@ fn test_register_event_resale_cap_invalid() {
         resale_cap_bps: Some(10001), // Over 100% - invalid
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
     assert_eq!(result, Err(Ok(EventRegistryError::InvalidResaleCapBps)));
 }
 
+#[test]
+fn test_register_event_resale_royalty_invalid() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    client.initialize(&Address::generate(&env), &Address::generate(&env), &500);
+
+    let result = client.try_register_event(&EventRegistrationArgs {
+        event_id: String::from_str(&env, "bad_royalty_event"),
+        organizer_address: organizer.clone(),
+        payment_address: organizer,
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 100,
+        milestone_plan: None,
+        tiers: Map::new(&env),
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 10001,
+    });
+    assert_eq!(result, Err(Ok(EventRegistryError::InvalidResaleRoyaltyBps)));
+}
+
 #[test]
 fn test_cancel_event_success() {
     let env = Env::default();
@@ -1804,6 +2552,12 @@ fn test_cancel_event_success() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     client.cancel_event(&event_id);
@@ -1813,68 +2567,288 @@ fn test_cancel_event_success() {
     assert!(!event_info.is_active);
 }
 
+#[soroban_sdk::contract]
+pub struct MockCancellationListener;
+
+#[soroban_sdk::contractimpl]
+impl MockCancellationListener {
+    pub fn on_event_cancelled(env: Env, event_id: String) {
+        env.storage()
+            .instance()
+            .set(&soroban_sdk::Symbol::new(&env, "cancelled"), &event_id);
+    }
+}
+
 #[test]
-fn test_cancel_already_cancelled_fails() {
+fn test_cancel_event_notifies_ticket_payment() {
     let env = Env::default();
     env.mock_all_auths();
     let contract_id = env.register(EventRegistry, ());
     let client = EventRegistryClient::new(&env, &contract_id);
+    let listener_id = env.register(MockCancellationListener, ());
 
     let admin = Address::generate(&env);
     let organizer = Address::generate(&env);
     let platform_wallet = Address::generate(&env);
     client.initialize(&admin, &platform_wallet, &500);
+    client.set_ticket_payment_contract(&listener_id);
 
-    let event_id = String::from_str(&env, "cancel_twice");
-    let metadata_cid = String::from_str(
-        &env,
-        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
-    );
-    let tiers = Map::new(&env);
+    let event_id = String::from_str(&env, "cancel_me");
     client.register_event(&EventRegistrationArgs {
         event_id: event_id.clone(),
         organizer_address: organizer.clone(),
         payment_address: Address::generate(&env),
-        metadata_cid,
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
         max_supply: 100,
         milestone_plan: None,
-        tiers,
+        tiers: Map::new(&env),
         refund_deadline: 0,
         restocking_fee: 0,
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     client.cancel_event(&event_id);
-    let result = client.try_cancel_event(&event_id);
-    assert_eq!(result, Err(Ok(EventRegistryError::EventAlreadyCancelled)));
+
+    let notified: Option<String> = env.as_contract(&listener_id, || {
+        env.storage()
+            .instance()
+            .get(&soroban_sdk::Symbol::new(&env, "cancelled"))
+    });
+    assert_eq!(notified, Some(event_id.clone()));
+    assert_eq!(
+        client.get_event(&event_id).unwrap().status,
+        EventStatus::Cancelled
+    );
+}
+
+#[soroban_sdk::contract]
+pub struct MockRejectingListener;
+
+#[soroban_sdk::contractimpl]
+impl MockRejectingListener {
+    pub fn on_event_cancelled(_env: Env, _event_id: String) {
+        panic!("escrow unavailable");
+    }
 }
 
 #[test]
-fn test_update_status_on_cancelled_event_fails() {
+fn test_cancel_event_reports_failed_notice() {
     let env = Env::default();
     env.mock_all_auths();
     let contract_id = env.register(EventRegistry, ());
     let client = EventRegistryClient::new(&env, &contract_id);
+    let listener_id = env.register(MockRejectingListener, ());
 
     let admin = Address::generate(&env);
     let organizer = Address::generate(&env);
-    let platform_wallet = Address::generate(&env);
-    client.initialize(&admin, &platform_wallet, &500);
+    client.initialize(&admin, &Address::generate(&env), &500);
+    client.set_ticket_payment_contract(&listener_id);
 
-    let event_id = String::from_str(&env, "no_updates");
-    let metadata_cid = String::from_str(
-        &env,
-        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
-    );
-    let tiers = Map::new(&env);
+    let event_id = String::from_str(&env, "cancel_me");
     client.register_event(&EventRegistrationArgs {
         event_id: event_id.clone(),
         organizer_address: organizer.clone(),
         payment_address: Address::generate(&env),
-        metadata_cid,
-        max_supply: 100,
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 100,
+        milestone_plan: None,
+        tiers: Map::new(&env),
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    });
+
+    // The cancellation stands even though the payment contract rejected it
+    client.cancel_event(&event_id);
+    let events = env.events().all();
+    let failed: CancellationNoticeFailedEvent = events
+        .get(events.len() - 2)
+        .unwrap()
+        .2
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(failed.event_id, event_id);
+    assert_eq!(failed.ticket_payment, listener_id);
+    assert_eq!(
+        client.get_event(&event_id).unwrap().status,
+        EventStatus::Cancelled
+    );
+}
+
+#[soroban_sdk::contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum MockBinderError {
+    Taken = 48,
+}
+
+#[soroban_sdk::contract]
+pub struct MockTenantBinder;
+
+#[soroban_sdk::contractimpl]
+impl MockTenantBinder {
+    pub fn bind_tenant_event(
+        env: Env,
+        registry: Address,
+        event_id: String,
+    ) -> Result<(), MockBinderError> {
+        registry.require_auth();
+        if event_id == String::from_str(&env, "taken") {
+            return Err(MockBinderError::Taken);
+        }
+        env.storage().instance().set(
+            &soroban_sdk::Symbol::new(&env, "bound"),
+            &(registry, event_id),
+        );
+        Ok(())
+    }
+}
+
+#[test]
+fn test_register_event_claims_id_in_ticket_payment() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+    let binder_id = env.register(MockTenantBinder, ());
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    client.initialize(&admin, &Address::generate(&env), &500);
+    client.set_ticket_payment_contract(&binder_id);
+
+    let args = |event_id: &str| EventRegistrationArgs {
+        event_id: String::from_str(&env, event_id),
+        organizer_address: organizer.clone(),
+        payment_address: Address::generate(&env),
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 100,
+        milestone_plan: None,
+        tiers: Map::new(&env),
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    };
+
+    // An id the payment contract already sells under another registry is refused
+    assert_eq!(
+        client.try_register_event(&args("taken")),
+        Err(Ok(EventRegistryError::EventIdUnavailable))
+    );
+    assert!(client.get_event(&String::from_str(&env, "taken")).is_none());
+
+    client.register_event(&args("claimed"));
+    let bound: Option<(Address, String)> = env.as_contract(&binder_id, || {
+        env.storage()
+            .instance()
+            .get(&soroban_sdk::Symbol::new(&env, "bound"))
+    });
+    assert_eq!(
+        bound,
+        Some((contract_id.clone(), String::from_str(&env, "claimed")))
+    );
+}
+
+#[test]
+fn test_cancel_already_cancelled_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let event_id = String::from_str(&env, "cancel_twice");
+    let metadata_cid = String::from_str(
+        &env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let tiers = Map::new(&env);
+    client.register_event(&EventRegistrationArgs {
+        event_id: event_id.clone(),
+        organizer_address: organizer.clone(),
+        payment_address: Address::generate(&env),
+        metadata_cid,
+        max_supply: 100,
+        milestone_plan: None,
+        tiers,
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    });
+
+    client.cancel_event(&event_id);
+    let result = client.try_cancel_event(&event_id);
+    assert_eq!(result, Err(Ok(EventRegistryError::EventAlreadyCancelled)));
+}
+
+#[test]
+fn test_update_status_on_cancelled_event_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let event_id = String::from_str(&env, "no_updates");
+    let metadata_cid = String::from_str(
+        &env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let tiers = Map::new(&env);
+    client.register_event(&EventRegistrationArgs {
+        event_id: event_id.clone(),
+        organizer_address: organizer.clone(),
+        payment_address: Address::generate(&env),
+        metadata_cid,
+        max_supply: 100,
         milestone_plan: None,
         tiers,
         refund_deadline: 0,
@@ -1882,9 +2856,2342 @@ fn test_update_status_on_cancelled_event_fails() {
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     });
 
     client.cancel_event(&event_id);
     let result = client.try_update_event_status(&event_id, &true);
     assert_eq!(result, Err(Ok(EventRegistryError::EventCancelled)));
 }
+
+#[test]
+fn test_event_visibility_controls_listing_and_sales() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let ticket_payment = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+    client.set_ticket_payment_contract(&ticket_payment);
+
+    let metadata_cid = String::from_str(
+        &env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let tier_id = String::from_str(&env, "general");
+    let mut tiers = Map::new(&env);
+    tiers.set(
+        tier_id.clone(),
+        TicketTier {
+            name: String::from_str(&env, "General"),
+            price: 5000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
+            tier_limit: 10,
+            current_sold: 0,
+            is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
+        },
+    );
+
+    let public_id = String::from_str(&env, "public_event");
+    let draft_id = String::from_str(&env, "draft_event");
+    for (event_id, visibility) in [
+        (public_id.clone(), EventVisibility::Public),
+        (draft_id.clone(), EventVisibility::Draft),
+    ] {
+        client.register_event(&EventRegistrationArgs {
+            event_id,
+            organizer_address: organizer.clone(),
+            payment_address: organizer.clone(),
+            metadata_cid: metadata_cid.clone(),
+            max_supply: 10,
+            milestone_plan: None,
+            tiers: tiers.clone(),
+            refund_deadline: 0,
+            restocking_fee: 0,
+            resale_cap_bps: None,
+            min_sales_target: None,
+            target_deadline: None,
+            visibility,
+            recovery_address: None,
+            recovery_timeout: 0,
+            policy_flags: 0,
+            promo_mode: PromoMode::Shared,
+            resale_royalty_bps: 0,
+        });
+    }
+
+    // Only the public event is listed; the draft cannot sell.
+    let listed = client.list_events();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed.get(0).unwrap(), public_id);
+    assert_eq!(
+        client.try_increment_inventory(&draft_id, &tier_id, &1),
+        Err(Ok(EventRegistryError::EventInactive))
+    );
+    assert_eq!(
+        client.try_get_event_payment_info(&draft_id),
+        Err(Ok(EventRegistryError::EventInactive))
+    );
+
+    // Unlisted events sell normally but drop out of discovery.
+    client.set_event_visibility(&public_id, &EventVisibility::Unlisted);
+    assert_eq!(client.list_events().len(), 0);
+    client.increment_inventory(&public_id, &tier_id, &1);
+    assert_eq!(client.get_event(&public_id).unwrap().current_supply, 1);
+
+    // Publishing the draft opens it for sales and lists it.
+    client.set_event_visibility(&draft_id, &EventVisibility::Public);
+    client.increment_inventory(&draft_id, &tier_id, &1);
+    let listed = client.list_events();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed.get(0).unwrap(), draft_id);
+}
+
+#[test]
+fn test_set_event_visibility_on_cancelled_event_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let event_id = String::from_str(&env, "cancelled_visibility");
+    client.register_event(&EventRegistrationArgs {
+        event_id: event_id.clone(),
+        organizer_address: organizer.clone(),
+        payment_address: organizer,
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 100,
+        milestone_plan: None,
+        tiers: Map::new(&env),
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    });
+
+    client.cancel_event(&event_id);
+    let result = client.try_set_event_visibility(&event_id, &EventVisibility::Unlisted);
+    assert_eq!(result, Err(Ok(EventRegistryError::EventCancelled)));
+}
+
+#[test]
+fn test_tier_visibility_window_filters_payment_info() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+    env.ledger().set_timestamp(1000);
+
+    let mut tiers = Map::new(&env);
+    tiers.set(
+        String::from_str(&env, "general"),
+        TicketTier {
+            name: String::from_str(&env, "General"),
+            price: 5000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
+            tier_limit: 10,
+            current_sold: 0,
+            is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
+        },
+    );
+    tiers.set(
+        String::from_str(&env, "vip"),
+        TicketTier {
+            name: String::from_str(&env, "VIP"),
+            price: 20000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
+            tier_limit: 5,
+            current_sold: 0,
+            is_refundable: true,
+            visible_from: 2000,
+            visible_until: 3000,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
+        },
+    );
+
+    let event_id = String::from_str(&env, "staged_event");
+    client.register_event(&EventRegistrationArgs {
+        event_id: event_id.clone(),
+        organizer_address: organizer.clone(),
+        payment_address: organizer.clone(),
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 0,
+        milestone_plan: None,
+        tiers,
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    });
+
+    let vip_id = String::from_str(&env, "vip");
+    let info = client.get_event_payment_info(&event_id);
+    assert_eq!(info.tiers.len(), 1);
+    assert!(info.tiers.get(vip_id.clone()).is_none());
+
+    env.ledger().set_timestamp(2000);
+    let info = client.get_event_payment_info(&event_id);
+    assert_eq!(info.tiers.len(), 2);
+    assert!(info.tiers.get(vip_id.clone()).is_some());
+
+    env.ledger().set_timestamp(3000);
+    let info = client.get_event_payment_info(&event_id);
+    assert!(info.tiers.get(vip_id).is_none());
+
+    // The full event record still carries every tier
+    assert_eq!(client.get_event(&event_id).unwrap().tiers.len(), 2);
+}
+
+#[test]
+fn test_register_event_rejects_inverted_tier_visibility_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let mut tiers = Map::new(&env);
+    tiers.set(
+        String::from_str(&env, "vip"),
+        TicketTier {
+            name: String::from_str(&env, "VIP"),
+            price: 20000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
+            tier_limit: 5,
+            current_sold: 0,
+            is_refundable: true,
+            visible_from: 3000,
+            visible_until: 2000,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
+        },
+    );
+
+    let result = client.try_register_event(&EventRegistrationArgs {
+        event_id: String::from_str(&env, "bad_window"),
+        organizer_address: organizer.clone(),
+        payment_address: organizer,
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 0,
+        milestone_plan: None,
+        tiers,
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    });
+    assert_eq!(
+        result,
+        Err(Ok(EventRegistryError::InvalidTierVisibilityWindow))
+    );
+}
+
+#[test]
+fn test_contract_metadata_reports_payment_counterpart() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let metadata = client.get_contract_metadata();
+    assert_eq!(
+        metadata.name,
+        String::from_str(&env, "agora-event-registry")
+    );
+    assert_eq!(metadata.version, String::from_str(&env, "0.1.0"));
+    assert_eq!(metadata.interface_revision, 2);
+    assert_eq!(metadata.counterpart, None);
+
+    let ticket_payment = Address::generate(&env);
+    client.set_ticket_payment_contract(&ticket_payment);
+    assert_eq!(
+        client.get_contract_metadata().counterpart,
+        Some(ticket_payment)
+    );
+}
+
+#[test]
+fn test_register_event_rejects_non_positive_token_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let mut token_prices = Map::new(&env);
+    token_prices.set(Address::generate(&env), 100_0000000i128);
+    token_prices.set(Address::generate(&env), 0i128);
+
+    let mut tiers = Map::new(&env);
+    tiers.set(
+        String::from_str(&env, "general"),
+        TicketTier {
+            name: String::from_str(&env, "General"),
+            price: 5000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
+            tier_limit: 10,
+            current_sold: 0,
+            is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices,
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
+        },
+    );
+
+    let result = client.try_register_event(&EventRegistrationArgs {
+        event_id: String::from_str(&env, "multi_currency"),
+        organizer_address: organizer.clone(),
+        payment_address: organizer,
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 0,
+        milestone_plan: None,
+        tiers,
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    });
+    assert_eq!(result, Err(Ok(EventRegistryError::InvalidTokenPrice)));
+}
+
+#[test]
+fn test_set_flash_sale_validation_and_storage() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+    env.ledger().set_timestamp(1000);
+
+    let tier_id = String::from_str(&env, "general");
+    let mut tiers = Map::new(&env);
+    tiers.set(
+        tier_id.clone(),
+        TicketTier {
+            name: String::from_str(&env, "General"),
+            price: 5000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
+            tier_limit: 10,
+            current_sold: 0,
+            is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
+        },
+    );
+    let event_id = String::from_str(&env, "flash_event");
+    client.register_event(&EventRegistrationArgs {
+        event_id: event_id.clone(),
+        organizer_address: organizer.clone(),
+        payment_address: organizer,
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 0,
+        milestone_plan: None,
+        tiers,
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    });
+
+    assert_eq!(client.get_flash_sale(&event_id, &tier_id), None);
+    assert_eq!(
+        client.try_set_flash_sale(
+            &event_id,
+            &String::from_str(&env, "vip"),
+            &2000,
+            &1000,
+            &5000
+        ),
+        Err(Ok(EventRegistryError::TierNotFound))
+    );
+    for bps in [0, 10000, 10001] {
+        assert_eq!(
+            client.try_set_flash_sale(&event_id, &tier_id, &bps, &1000, &5000),
+            Err(Ok(EventRegistryError::InvalidFlashSaleDiscount))
+        );
+    }
+    for (bps, start, end) in [(2000, 5000, 5000), (2000, 0, 900)] {
+        assert_eq!(
+            client.try_set_flash_sale(&event_id, &tier_id, &bps, &start, &end),
+            Err(Ok(EventRegistryError::InvalidFlashSale))
+        );
+    }
+
+    client.set_flash_sale(&event_id, &tier_id, &2000, &1000, &87400);
+    let sale = client.get_flash_sale(&event_id, &tier_id).unwrap();
+    assert_eq!(sale.discount_bps, 2000);
+    assert_eq!(sale.start, 1000);
+    assert_eq!(sale.end, 87400);
+}
+
+/// Governance-style organizer: a contract that applies registry proposals once
+/// its own (elided) approval process has passed. It never signs; the registry
+/// authorizes it as the direct invoker of `execute_organizer_action`.
+#[contract]
+pub struct MockGovernanceOrganizer;
+
+#[contractimpl]
+impl MockGovernanceOrganizer {
+    pub fn execute(env: Env, registry: Address, proposal_id: u64) {
+        EventRegistryClient::new(&env, &registry).execute_organizer_action(&proposal_id);
+    }
+}
+
+#[test]
+fn test_contract_organizer_executes_proposed_actions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+    let dao_id = env.register(MockGovernanceOrganizer, ());
+    let dao = MockGovernanceOrganizerClient::new(&env, &dao_id);
+
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let member = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let event_id = String::from_str(&env, "dao_event");
+    let account_event_id = String::from_str(&env, "account_event");
+    for (id, organizer) in [
+        (event_id.clone(), dao_id.clone()),
+        (account_event_id.clone(), Address::generate(&env)),
+    ] {
+        client.register_event(&EventRegistrationArgs {
+            event_id: id,
+            organizer_address: organizer.clone(),
+            payment_address: organizer,
+            metadata_cid: String::from_str(
+                &env,
+                "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+            ),
+            max_supply: 0,
+            milestone_plan: None,
+            tiers: Map::new(&env),
+            refund_deadline: 0,
+            restocking_fee: 0,
+            resale_cap_bps: None,
+            min_sales_target: None,
+            target_deadline: None,
+            visibility: EventVisibility::Public,
+            recovery_address: None,
+            recovery_timeout: 0,
+            policy_flags: 0,
+            promo_mode: PromoMode::Shared,
+            resale_royalty_bps: 0,
+        });
+    }
+    assert!(client.is_contract_organizer(&event_id));
+    assert!(!client.is_contract_organizer(&account_event_id));
+
+    // Only the organizer's allowed proposers may queue actions
+    let action = OrganizerAction::SetVisibility(EventVisibility::Unlisted);
+    assert_eq!(
+        client.try_propose_organizer_action(&event_id, &member, &action),
+        Err(Ok(EventRegistryError::Unauthorized))
+    );
+    client.set_action_proposer(&event_id, &member, &true);
+    assert!(client.is_action_proposer(&event_id, &member));
+    assert!(!client.is_action_proposer(&account_event_id, &member));
+    let proposal_id = client.propose_organizer_action(&event_id, &member, &action);
+    let proposal = client.get_organizer_proposal(&proposal_id).unwrap();
+    assert_eq!(proposal.proposer, member);
+    assert!(!proposal.executed);
+
+    // Stop mocking auth: only the organizer contract itself can execute now.
+    env.set_auths(&[]);
+    assert!(client.try_execute_organizer_action(&proposal_id).is_err());
+
+    dao.execute(&contract_id, &proposal_id);
+    assert_eq!(
+        client.get_event(&event_id).unwrap().visibility,
+        EventVisibility::Unlisted
+    );
+    assert!(
+        client
+            .get_organizer_proposal(&proposal_id)
+            .unwrap()
+            .executed
+    );
+    assert_eq!(
+        client.try_execute_organizer_action(&proposal_id),
+        Err(Ok(EventRegistryError::ProposalAlreadyExecuted))
+    );
+    assert_eq!(
+        client.try_execute_organizer_action(&99),
+        Err(Ok(EventRegistryError::ProposalNotFound))
+    );
+}
+
+/// Custom account organizer: signs through `__check_auth` with a passcode
+/// fixed at deployment, standing in for a smart wallet's signature scheme.
+#[contract]
+pub struct MockPasscodeAccount;
+
+#[soroban_sdk::contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum MockAccountError {
+    WrongPasscode = 1,
+}
+
+#[contractimpl]
+impl MockPasscodeAccount {
+    pub fn __constructor(env: Env, passcode: BytesN<32>) {
+        env.storage()
+            .instance()
+            .set(&soroban_sdk::Symbol::new(&env, "passcode"), &passcode);
+    }
+}
+
+#[contractimpl]
+impl soroban_sdk::auth::CustomAccountInterface for MockPasscodeAccount {
+    type Signature = BytesN<32>;
+    type Error = MockAccountError;
+
+    fn __check_auth(
+        env: Env,
+        _signature_payload: soroban_sdk::crypto::Hash<32>,
+        signature: BytesN<32>,
+        _auth_contexts: Vec<soroban_sdk::auth::Context>,
+    ) -> Result<(), MockAccountError> {
+        let passcode: BytesN<32> = env
+            .storage()
+            .instance()
+            .get(&soroban_sdk::Symbol::new(&env, "passcode"))
+            .unwrap();
+        if signature != passcode {
+            return Err(MockAccountError::WrongPasscode);
+        }
+        Ok(())
+    }
+}
+
+/// Authorizes one top-level registry call for `account`, signed with `passcode`.
+fn passcode_auth(
+    env: &Env,
+    account: &Address,
+    registry: &Address,
+    fn_name: &str,
+    args: Vec<soroban_sdk::Val>,
+    passcode: &BytesN<32>,
+    nonce: i64,
+) -> soroban_sdk::xdr::SorobanAuthorizationEntry {
+    use soroban_sdk::xdr::{
+        InvokeContractArgs, ScSymbol, ScVal, SorobanAddressCredentials, SorobanAuthorizationEntry,
+        SorobanAuthorizedFunction, SorobanAuthorizedInvocation, SorobanCredentials, VecM,
+    };
+
+    SorobanAuthorizationEntry {
+        credentials: SorobanCredentials::Address(SorobanAddressCredentials {
+            address: account.into(),
+            nonce,
+            signature_expiration_ledger: env.ledger().sequence() + 100,
+            signature: ScVal::from(passcode),
+        }),
+        root_invocation: SorobanAuthorizedInvocation {
+            function: SorobanAuthorizedFunction::ContractFn(InvokeContractArgs {
+                contract_address: registry.into(),
+                function_name: ScSymbol(fn_name.try_into().unwrap()),
+                args: VecM::from(args),
+            }),
+            sub_invocations: VecM::default(),
+        },
+    }
+}
+
+#[test]
+fn test_custom_account_organizer_proposes_and_executes_actions() {
+    use soroban_sdk::IntoVal;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+    let passcode = BytesN::from_array(&env, &[7; 32]);
+    let wrong_passcode = BytesN::from_array(&env, &[8; 32]);
+    let account = env.register(MockPasscodeAccount, (passcode.clone(),));
+
+    client.initialize(&Address::generate(&env), &Address::generate(&env), &500);
+    let event_id = String::from_str(&env, "wallet_event");
+    client.register_event(&EventRegistrationArgs {
+        event_id: event_id.clone(),
+        organizer_address: account.clone(),
+        payment_address: account.clone(),
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 0,
+        milestone_plan: None,
+        tiers: Map::new(&env),
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    });
+    assert!(client.is_contract_organizer(&event_id));
+
+    // From here on every signature goes through the account's __check_auth
+    let action = OrganizerAction::SetVisibility(EventVisibility::Unlisted);
+    let propose_args: Vec<soroban_sdk::Val> =
+        (event_id.clone(), account.clone(), action.clone()).into_val(&env);
+    assert!(client
+        .set_auths(&[passcode_auth(
+            &env,
+            &account,
+            &contract_id,
+            "propose_organizer_action",
+            propose_args.clone(),
+            &wrong_passcode,
+            1,
+        )])
+        .try_propose_organizer_action(&event_id, &account, &action)
+        .is_err());
+
+    let proposal_id = client
+        .set_auths(&[passcode_auth(
+            &env,
+            &account,
+            &contract_id,
+            "propose_organizer_action",
+            propose_args,
+            &passcode,
+            2,
+        )])
+        .propose_organizer_action(&event_id, &account, &action);
+
+    client
+        .set_auths(&[passcode_auth(
+            &env,
+            &account,
+            &contract_id,
+            "execute_organizer_action",
+            (proposal_id,).into_val(&env),
+            &passcode,
+            3,
+        )])
+        .execute_organizer_action(&proposal_id);
+    assert_eq!(
+        client.get_event(&event_id).unwrap().visibility,
+        EventVisibility::Unlisted
+    );
+}
+
+#[test]
+fn test_register_event_rejects_negative_deposit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let mut tiers = Map::new(&env);
+    tiers.set(
+        String::from_str(&env, "general"),
+        TicketTier {
+            name: String::from_str(&env, "General"),
+            price: 5000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
+            tier_limit: 10,
+            current_sold: 0,
+            is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: -1,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
+        },
+    );
+
+    let result = client.try_register_event(&EventRegistrationArgs {
+        event_id: String::from_str(&env, "deposit_event"),
+        organizer_address: organizer.clone(),
+        payment_address: organizer,
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 0,
+        milestone_plan: None,
+        tiers,
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    });
+    assert_eq!(result, Err(Ok(EventRegistryError::InvalidDepositAmount)));
+}
+
+#[test]
+fn test_register_event_stores_recovery_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let recovery = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let args = |event_id: &str, recovery_address: Option<Address>, recovery_timeout: u64| {
+        EventRegistrationArgs {
+            event_id: String::from_str(&env, event_id),
+            organizer_address: organizer.clone(),
+            payment_address: organizer.clone(),
+            metadata_cid: String::from_str(
+                &env,
+                "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+            ),
+            max_supply: 0,
+            milestone_plan: None,
+            tiers: Map::new(&env),
+            refund_deadline: 0,
+            restocking_fee: 0,
+            resale_cap_bps: None,
+            min_sales_target: None,
+            target_deadline: None,
+            visibility: EventVisibility::Public,
+            recovery_address,
+            recovery_timeout,
+            policy_flags: 0,
+            promo_mode: PromoMode::Shared,
+            resale_royalty_bps: 0,
+        }
+    };
+
+    assert_eq!(
+        client.try_register_event(&args("bad_timeout", Some(recovery.clone()), 0)),
+        Err(Ok(EventRegistryError::InvalidRecoveryConfig))
+    );
+    assert_eq!(
+        client.try_register_event(&args("self_recovery", Some(organizer.clone()), 100)),
+        Err(Ok(EventRegistryError::InvalidRecoveryConfig))
+    );
+
+    client.register_event(&args("recoverable", Some(recovery.clone()), 86400));
+    let config = client
+        .get_recovery_config(&String::from_str(&env, "recoverable"))
+        .unwrap();
+    assert_eq!(config.recovery_address, recovery);
+    assert_eq!(config.inactivity_timeout, 86400);
+
+    client.register_event(&args("plain", None, 0));
+    assert_eq!(
+        client.get_recovery_config(&String::from_str(&env, "plain")),
+        None
+    );
+}
+
+#[test]
+fn test_policy_flags_and_requirements() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let args = |event_id: &str, policy_flags: u32| EventRegistrationArgs {
+        event_id: String::from_str(&env, event_id),
+        organizer_address: organizer.clone(),
+        payment_address: organizer.clone(),
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 0,
+        milestone_plan: None,
+        tiers: Map::new(&env),
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    };
+
+    // Jurisdiction-limited events must also screen for sanctions.
+    client.set_policy_requirement(&POLICY_JURISDICTION_LIMITED, &POLICY_SANCTIONS_SCREENING);
+    assert_eq!(
+        client
+            .get_policy_requirements()
+            .get(POLICY_JURISDICTION_LIMITED),
+        Some(POLICY_SANCTIONS_SCREENING)
+    );
+    assert_eq!(
+        client.try_set_policy_requirement(&0, &POLICY_SANCTIONS_SCREENING),
+        Err(Ok(EventRegistryError::InvalidPolicyFlags))
+    );
+
+    assert_eq!(
+        client.try_register_event(&args("geo_only", POLICY_JURISDICTION_LIMITED)),
+        Err(Ok(EventRegistryError::InvalidPolicyFlags))
+    );
+
+    let flags = POLICY_JURISDICTION_LIMITED | POLICY_SANCTIONS_SCREENING | POLICY_AGE_RESTRICTED;
+    client.register_event(&args("geo_screened", flags));
+    client.register_event(&args("unflagged", 0));
+
+    let screened = String::from_str(&env, "geo_screened");
+    assert_eq!(client.get_policy_flags(&screened), flags);
+    assert_eq!(client.get_event_payment_info(&screened).policy_flags, flags);
+    assert_eq!(
+        client.get_policy_flags(&String::from_str(&env, "unflagged")),
+        0
+    );
+
+    // Removing the requirement allows the previously rejected combination.
+    client.set_policy_requirement(&POLICY_JURISDICTION_LIMITED, &0);
+    assert!(client.get_policy_requirements().is_empty());
+    client.register_event(&args("geo_only", POLICY_JURISDICTION_LIMITED));
+}
+
+#[test]
+fn test_update_metadata_batch_reports_failures() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let other_organizer = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let old_cid = String::from_str(
+        &env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let new_cid = String::from_str(
+        &env,
+        "bafkreifh22222222222222222222222222222222222222222222222222",
+    );
+    let args = |event_id: &str, owner: &Address| EventRegistrationArgs {
+        event_id: String::from_str(&env, event_id),
+        organizer_address: owner.clone(),
+        payment_address: owner.clone(),
+        metadata_cid: old_cid.clone(),
+        max_supply: 0,
+        milestone_plan: None,
+        tiers: Map::new(&env),
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    };
+    client.register_event(&args("own_a", &organizer));
+    client.register_event(&args("own_b", &organizer));
+    client.register_event(&args("own_c", &organizer));
+    client.register_event(&args("foreign", &other_organizer));
+
+    assert_eq!(
+        client.try_update_metadata_batch(&organizer, &soroban_sdk::vec![&env]),
+        Err(Ok(EventRegistryError::InvalidBatchSize))
+    );
+    let mut oversized = soroban_sdk::vec![&env];
+    for _ in 0..=MAX_METADATA_BATCH {
+        oversized.push_back((String::from_str(&env, "own_a"), new_cid.clone()));
+    }
+    assert_eq!(
+        client.try_update_metadata_batch(&organizer, &oversized),
+        Err(Ok(EventRegistryError::InvalidBatchSize))
+    );
+
+    let updates = soroban_sdk::vec![
+        &env,
+        (String::from_str(&env, "own_a"), new_cid.clone()),
+        (
+            String::from_str(&env, "own_b"),
+            String::from_str(&env, "bad")
+        ),
+        (String::from_str(&env, "own_c"), old_cid.clone()),
+        (String::from_str(&env, "foreign"), new_cid.clone()),
+        (String::from_str(&env, "missing"), new_cid.clone()),
+    ];
+    assert_eq!(client.update_metadata_batch(&organizer, &updates), 1);
+
+    let summary: MetadataBatchUpdatedEvent = env
+        .events()
+        .all()
+        .last()
+        .unwrap()
+        .2
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(summary.updated_count, 1);
+    assert_eq!(summary.unchanged_count, 1);
+    assert_eq!(
+        summary.failed_event_ids,
+        soroban_sdk::vec![
+            &env,
+            String::from_str(&env, "own_b"),
+            String::from_str(&env, "foreign"),
+            String::from_str(&env, "missing"),
+        ]
+    );
+
+    let get_cid = |id: &str| {
+        client
+            .get_event(&String::from_str(&env, id))
+            .unwrap()
+            .metadata_cid
+    };
+    assert_eq!(get_cid("own_a"), new_cid);
+    assert_eq!(get_cid("own_b"), old_cid);
+    assert_eq!(get_cid("foreign"), old_cid);
+}
+
+#[test]
+fn test_register_event_stores_promo_mode() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let args = |event_id: &str, promo_mode: PromoMode| EventRegistrationArgs {
+        event_id: String::from_str(&env, event_id),
+        organizer_address: organizer.clone(),
+        payment_address: organizer.clone(),
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 0,
+        milestone_plan: None,
+        tiers: Map::new(&env),
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode,
+        resale_royalty_bps: 0,
+    };
+    client.register_event(&args("shared", PromoMode::Shared));
+    client.register_event(&args("opt_out", PromoMode::OptOut));
+    client.register_event(&args("absorbed", PromoMode::PlatformAbsorbed));
+
+    let mode = |id: &str| client.get_promo_mode(&String::from_str(&env, id));
+    assert_eq!(mode("shared"), PromoMode::Shared);
+    assert_eq!(mode("opt_out"), PromoMode::OptOut);
+    assert_eq!(mode("absorbed"), PromoMode::PlatformAbsorbed);
+    assert_eq!(mode("unknown"), PromoMode::Shared);
+}
+
+#[test]
+fn test_import_event_validates_and_blocks_double_import() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 1_000);
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let legacy_registry = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let mut tiers = Map::new(&env);
+    tiers.set(
+        String::from_str(&env, "general"),
+        TicketTier {
+            name: String::from_str(&env, "General"),
+            price: 1_000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
+            tier_limit: 50,
+            current_sold: 12,
+            is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
+        },
+    );
+    let event_id = String::from_str(&env, "legacy_1");
+    let event_info = EventInfo {
+        event_id: event_id.clone(),
+        organizer_address: organizer.clone(),
+        payment_address: organizer.clone(),
+        platform_fee_percent: 250,
+        is_active: true,
+        status: EventStatus::Active,
+        created_at: 42,
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 50,
+        current_supply: 12,
+        milestone_plan: None,
+        tiers,
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        is_postponed: false,
+        grace_period_end: 0,
+        min_sales_target: 0,
+        target_deadline: 0,
+        goal_met: false,
+        visibility: EventVisibility::Public,
+        verified: false,
+        max_tickets_per_buyer: 0,
+        resale_royalty_bps: 0,
+        event_start_time: 0,
+        transfer_lock_before_secs: 0,
+    };
+
+    // Supply that disagrees with the tier totals is rejected
+    let mut inconsistent = event_info.clone();
+    inconsistent.current_supply = 13;
+    assert_eq!(
+        client.try_import_event(&inconsistent, &legacy_registry, &900),
+        Err(Ok(EventRegistryError::InvalidImportedEvent))
+    );
+    // Provenance must point at another deployment and a past ledger
+    assert_eq!(
+        client.try_import_event(&event_info, &contract_id, &900),
+        Err(Ok(EventRegistryError::InvalidAddress))
+    );
+    assert_eq!(
+        client.try_import_event(&event_info, &legacy_registry, &1_001),
+        Err(Ok(EventRegistryError::InvalidImportedEvent))
+    );
+
+    client.import_event(&event_info, &legacy_registry, &900);
+    assert_eq!(client.get_event(&event_id).unwrap(), event_info);
+    assert_eq!(client.get_organizer_events(&organizer).len(), 1);
+    let provenance = client.get_import_provenance(&event_id).unwrap();
+    assert_eq!(provenance.source_contract, legacy_registry);
+    assert_eq!(provenance.source_ledger, 900);
+    assert_eq!(provenance.imported_by, admin);
+
+    assert_eq!(
+        client.try_import_event(&event_info, &legacy_registry, &900),
+        Err(Ok(EventRegistryError::EventAlreadyImported))
+    );
+}
+
+#[test]
+fn test_paginated_event_listing_spans_shards() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let other_organizer = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let ids = [
+        "e00", "e01", "e02", "e03", "e04", "e05", "e06", "e07", "e08", "e09", "e10", "e11", "e12",
+        "e13", "e14", "e15", "e16", "e17", "e18", "e19", "e20", "e21", "e22", "e23", "e24", "e25",
+        "e26", "e27", "e28", "e29", "e30", "e31", "e32", "e33", "e34", "e35", "e36", "e37", "e38",
+        "e39", "e40", "e41", "e42", "e43", "e44", "e45", "e46", "e47", "e48", "e49", "e50", "e51",
+        "e52", "e53", "e54", "e55",
+    ];
+    for (i, id) in ids.iter().enumerate() {
+        client.register_event(&EventRegistrationArgs {
+            event_id: String::from_str(&env, id),
+            organizer_address: organizer.clone(),
+            payment_address: organizer.clone(),
+            metadata_cid: String::from_str(
+                &env,
+                "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+            ),
+            max_supply: 0,
+            milestone_plan: None,
+            tiers: Map::new(&env),
+            refund_deadline: 0,
+            restocking_fee: 0,
+            resale_cap_bps: None,
+            min_sales_target: None,
+            target_deadline: None,
+            visibility: if i % 10 == 0 {
+                EventVisibility::Unlisted
+            } else {
+                EventVisibility::Public
+            },
+            recovery_address: None,
+            recovery_timeout: 0,
+            policy_flags: 0,
+            promo_mode: PromoMode::Shared,
+            resale_royalty_bps: 0,
+        });
+    }
+    assert_eq!(client.get_event_count(), 56);
+    assert_eq!(client.get_organizer_event_count(&organizer), 56);
+    assert_eq!(client.get_organizer_event_count(&other_organizer), 0);
+
+    // A page that straddles the 50-entry shard boundary
+    let page = client.get_organizer_events_paginated(&organizer, &45, &10);
+    assert_eq!(page.len(), 10);
+    assert_eq!(page.get(0).unwrap(), String::from_str(&env, "e45"));
+    assert_eq!(page.get(9).unwrap(), String::from_str(&env, "e54"));
+
+    // The tail page is truncated and offsets past the end are empty
+    assert_eq!(
+        client
+            .get_organizer_events_paginated(&organizer, &50, &10)
+            .len(),
+        6
+    );
+    assert_eq!(
+        client
+            .get_organizer_events_paginated(&organizer, &56, &10)
+            .len(),
+        0
+    );
+    assert_eq!(
+        client
+            .get_organizer_events_paginated(&other_organizer, &0, &10)
+            .len(),
+        0
+    );
+
+    // Global listing skips unlisted events (e40 and e50) within the window
+    let listed = client.list_events_paginated(&40, &12);
+    assert_eq!(listed.len(), 10);
+    assert_eq!(listed.get(0).unwrap(), String::from_str(&env, "e41"));
+    assert_eq!(listed.get(9).unwrap(), String::from_str(&env, "e51"));
+
+    // Limits are clamped to MAX_PAGE_SIZE
+    assert_eq!(
+        client
+            .get_organizer_events_paginated(&organizer, &0, &u32::MAX)
+            .len(),
+        56
+    );
+}
+
+#[test]
+fn test_blacklist_audit_log_paginated() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let reason = String::from_str(&env, "Chargeback abuse");
+    client.blacklist_organizer(&organizer, &reason);
+    client.remove_from_blacklist(&organizer, &reason);
+    client.blacklist_organizer(&organizer, &reason);
+    assert_eq!(client.get_blacklist_audit_log_count(), 3);
+
+    let page = client.get_blacklist_log_paginated(&1, &5);
+    assert_eq!(page.len(), 2);
+    assert!(!page.get(0).unwrap().added_to_blacklist);
+    assert!(page.get(1).unwrap().added_to_blacklist);
+    assert_eq!(client.get_blacklist_log_paginated(&3, &5).len(), 0);
+}
+
+#[test]
+fn test_blacklist_history_is_per_organizer_across_shards() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let repeat_offender = Address::generate(&env);
+    let other = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let reason = String::from_str(&env, "Chargeback abuse");
+    client.blacklist_organizer(&other, &reason);
+    // 52 entries spill the offender's history into a second shard
+    for _ in 0..26 {
+        client.blacklist_organizer(&repeat_offender, &reason);
+        client.remove_from_blacklist(&repeat_offender, &reason);
+    }
+
+    assert_eq!(client.get_blacklist_audit_log_count(), 53);
+    assert_eq!(client.get_blacklist_history_count(&repeat_offender), 52);
+    assert_eq!(client.get_blacklist_history_count(&other), 1);
+
+    let history = client.get_blacklist_history(&other, &0, &10);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().organizer_address, other);
+
+    let page = client.get_blacklist_history(&repeat_offender, &48, &10);
+    assert_eq!(page.len(), 4);
+    for (i, entry) in page.iter().enumerate() {
+        assert_eq!(entry.organizer_address, repeat_offender);
+        assert_eq!(entry.added_to_blacklist, i % 2 == 0);
+    }
+
+    // The global log interleaves every organizer in order
+    let global = client.get_blacklist_log_paginated(&49, &10);
+    assert_eq!(global.len(), 4);
+    assert_eq!(global.get(3).unwrap().organizer_address, repeat_offender);
+    assert_eq!(
+        client
+            .get_blacklist_log_paginated(&0, &1)
+            .get(0)
+            .unwrap()
+            .organizer_address,
+        other
+    );
+}
+
+#[test]
+fn test_register_events_batch_is_all_or_nothing() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let payment_addr = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let args = |event_id: &str, organizer: &Address, metadata_cid: &str| EventRegistrationArgs {
+        event_id: String::from_str(&env, event_id),
+        organizer_address: organizer.clone(),
+        payment_address: payment_addr.clone(),
+        metadata_cid: String::from_str(&env, metadata_cid),
+        max_supply: 0,
+        milestone_plan: None,
+        tiers: Map::new(&env),
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    };
+    let cid = "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+
+    // One invalid event rejects the whole batch
+    let batch = soroban_sdk::vec![
+        &env,
+        args("fest_day_1", &organizer, cid),
+        args("fest_day_2", &organizer, "bad"),
+    ];
+    assert_eq!(
+        client.try_register_events_batch(&batch),
+        Err(Ok(EventRegistryError::InvalidMetadataCid))
+    );
+    assert!(!client.event_exists(&String::from_str(&env, "fest_day_1")));
+
+    // Events must all belong to the authorizing organizer
+    let batch = soroban_sdk::vec![
+        &env,
+        args("fest_day_1", &organizer, cid),
+        args("fest_day_2", &Address::generate(&env), cid),
+    ];
+    assert_eq!(
+        client.try_register_events_batch(&batch),
+        Err(Ok(EventRegistryError::Unauthorized))
+    );
+
+    // Empty and oversized batches are rejected
+    assert_eq!(
+        client.try_register_events_batch(&soroban_sdk::vec![&env]),
+        Err(Ok(EventRegistryError::InvalidBatchSize))
+    );
+    let mut oversized = soroban_sdk::vec![&env];
+    for _ in 0..=MAX_REGISTRATION_BATCH {
+        oversized.push_back(args("fest_day_1", &organizer, cid));
+    }
+    assert_eq!(
+        client.try_register_events_batch(&oversized),
+        Err(Ok(EventRegistryError::InvalidBatchSize))
+    );
+
+    let batch = soroban_sdk::vec![
+        &env,
+        args("fest_day_1", &organizer, cid),
+        args("fest_day_2", &organizer, cid),
+        args("fest_day_3", &organizer, cid),
+    ];
+    assert_eq!(client.register_events_batch(&batch), 3);
+
+    let summary: EventsBatchRegisteredEvent = env
+        .events()
+        .all()
+        .last()
+        .unwrap()
+        .2
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(summary.organizer_address, organizer);
+    assert_eq!(summary.count, 3);
+    assert_eq!(
+        summary.event_ids.get(2).unwrap(),
+        String::from_str(&env, "fest_day_3")
+    );
+    assert_eq!(client.get_organizer_event_count(&organizer), 3);
+
+    // Duplicates, within the batch or of existing events, fail the batch
+    let batch = soroban_sdk::vec![
+        &env,
+        args("fest_day_4", &organizer, cid),
+        args("fest_day_4", &organizer, cid)
+    ];
+    assert_eq!(
+        client.try_register_events_batch(&batch),
+        Err(Ok(EventRegistryError::EventAlreadyExists))
+    );
+    assert!(!client.event_exists(&String::from_str(&env, "fest_day_4")));
+}
+
+#[test]
+fn test_time_boxed_scanner_authorization() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let door = Address::generate(&env);
+    let staff = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let event_id = String::from_str(&env, "gig");
+    client.register_event(&EventRegistrationArgs {
+        event_id: event_id.clone(),
+        organizer_address: organizer.clone(),
+        payment_address: organizer.clone(),
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 0,
+        milestone_plan: None,
+        tiers: Map::new(&env),
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    });
+
+    assert_eq!(
+        client.try_authorize_scanner_with_window(&event_id, &door, &2000, &1000),
+        Err(Ok(EventRegistryError::InvalidScannerWindow))
+    );
+    client.authorize_scanner_with_window(&event_id, &door, &1000, &2000);
+    client.authorize_scanner(&event_id, &staff);
+    assert_eq!(
+        client.get_scanner_window(&event_id, &door),
+        Some(ScannerWindow {
+            valid_from: 1000,
+            valid_until: 2000
+        })
+    );
+    assert_eq!(client.get_scanner_window(&event_id, &staff), None);
+
+    env.ledger().set_timestamp(999);
+    assert!(!client.is_scanner_authorized(&event_id, &door));
+    assert!(client.is_scanner_authorized(&event_id, &staff));
+    env.ledger().set_timestamp(1000);
+    assert!(client.is_scanner_authorized(&event_id, &door));
+    env.ledger().set_timestamp(2000);
+    assert!(!client.is_scanner_authorized(&event_id, &door));
+
+    // Re-authorizing without a window lifts the restriction
+    client.authorize_scanner(&event_id, &door);
+    assert!(client.is_scanner_authorized(&event_id, &door));
+    assert_eq!(client.get_scanner_window(&event_id, &door), None);
+}
+
+#[test]
+fn test_seat_map_reserve_and_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let ticket_payment = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+    client.set_ticket_payment_contract(&ticket_payment);
+
+    let event_id = String::from_str(&env, "theatre");
+    let tier_id = String::from_str(&env, "stalls");
+    let mut tiers = Map::new(&env);
+    tiers.set(
+        tier_id.clone(),
+        TicketTier {
+            name: String::from_str(&env, "Stalls"),
+            price: 5000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
+            tier_limit: 2,
+            current_sold: 0,
+            is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
+        },
+    );
+    client.register_event(&EventRegistrationArgs {
+        event_id: event_id.clone(),
+        organizer_address: organizer.clone(),
+        payment_address: organizer.clone(),
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 0,
+        milestone_plan: None,
+        tiers,
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    });
+
+    let a1 = String::from_str(&env, "A-1");
+    let a2 = String::from_str(&env, "A-2");
+    assert_eq!(
+        client.try_set_seat_map(&event_id, &tier_id, &vec![&env, a1.clone(), a1.clone()]),
+        Err(Ok(EventRegistryError::InvalidSeatMap))
+    );
+    assert_eq!(
+        client.try_set_seat_map(&event_id, &tier_id, &vec![&env, a1.clone()]),
+        Err(Ok(EventRegistryError::InvalidSeatMap))
+    );
+    client.set_seat_map(&event_id, &tier_id, &vec![&env, a1.clone(), a2.clone()]);
+    assert_eq!(
+        client.get_seat_map(&event_id, &tier_id).unwrap().seats,
+        vec![&env, a1.clone(), a2.clone()]
+    );
+
+    client.reserve_seat(&event_id, &tier_id, &a1);
+    assert!(client.is_seat_reserved(&event_id, &tier_id, &a1));
+    assert_eq!(
+        client.try_reserve_seat(&event_id, &tier_id, &a1),
+        Err(Ok(EventRegistryError::SeatUnavailable))
+    );
+    assert_eq!(
+        client.try_reserve_seat(&event_id, &tier_id, &String::from_str(&env, "Z-9")),
+        Err(Ok(EventRegistryError::SeatNotFound))
+    );
+
+    // The layout is frozen once the tier has sales
+    client.increment_inventory(&event_id, &tier_id, &1);
+    assert_eq!(
+        client.try_set_seat_map(&event_id, &tier_id, &Vec::new(&env)),
+        Err(Ok(EventRegistryError::InvalidSeatMap))
+    );
+
+    client.release_seat(&event_id, &tier_id, &a1);
+    assert!(!client.is_seat_reserved(&event_id, &tier_id, &a1));
+    assert_eq!(
+        client.try_release_seat(&event_id, &tier_id, &a1),
+        Err(Ok(EventRegistryError::SeatNotFound))
+    );
+}
+
+#[test]
+fn test_inventory_holds_count_against_supply_until_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+    client.set_ticket_payment_contract(&Address::generate(&env));
+
+    let event_id = String::from_str(&env, "club");
+    let tier_id = String::from_str(&env, "floor");
+    let mut tiers = Map::new(&env);
+    tiers.set(
+        tier_id.clone(),
+        TicketTier {
+            name: String::from_str(&env, "Floor"),
+            price: 5000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
+            tier_limit: 3,
+            current_sold: 0,
+            is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
+        },
+    );
+    client.register_event(&EventRegistrationArgs {
+        event_id: event_id.clone(),
+        organizer_address: organizer.clone(),
+        payment_address: organizer.clone(),
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 0,
+        milestone_plan: None,
+        tiers,
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    });
+
+    let hold_a = String::from_str(&env, "hold_a");
+    let hold_b = String::from_str(&env, "hold_b");
+    assert_eq!(
+        client.try_hold_inventory(&hold_a, &event_id, &tier_id, &2, &1_000),
+        Err(Ok(EventRegistryError::InvalidQuantity))
+    );
+    client.hold_inventory(&hold_a, &event_id, &tier_id, &2, &1_300);
+    assert_eq!(client.get_held_inventory(&event_id, &tier_id), 2);
+    assert_eq!(
+        client.get_inventory_hold(&hold_a).unwrap().expires_at,
+        1_300
+    );
+
+    // Held tickets are unavailable to other holds and sales
+    assert_eq!(
+        client.try_hold_inventory(&hold_b, &event_id, &tier_id, &2, &1_300),
+        Err(Ok(EventRegistryError::TierSupplyExceeded))
+    );
+    assert_eq!(
+        client.try_increment_inventory(&event_id, &tier_id, &2),
+        Err(Ok(EventRegistryError::TierSupplyExceeded))
+    );
+    client.increment_inventory(&event_id, &tier_id, &1);
+
+    // Releasing a hold lets its tickets be sold
+    client.release_inventory_hold(&hold_a);
+    assert_eq!(client.get_held_inventory(&event_id, &tier_id), 0);
+    assert_eq!(client.get_inventory_hold(&hold_a), None);
+    client.hold_inventory(&hold_b, &event_id, &tier_id, &2, &1_300);
+    assert_eq!(
+        client.try_increment_inventory(&event_id, &tier_id, &1),
+        Err(Ok(EventRegistryError::TierSupplyExceeded))
+    );
+
+    // Expired holds release themselves
+    env.ledger().with_mut(|li| li.timestamp = 1_300);
+    assert_eq!(client.get_held_inventory(&event_id, &tier_id), 0);
+    assert_eq!(client.get_inventory_hold(&hold_b), None);
+    client.increment_inventory(&event_id, &tier_id, &2);
+    assert_eq!(client.get_event(&event_id).unwrap().current_supply, 3);
+    client.release_inventory_hold(&hold_b);
+}
+
+#[test]
+fn test_refund_policy_validated_and_frozen_by_sales() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    client.initialize(&admin, &Address::generate(&env), &500);
+    client.set_ticket_payment_contract(&Address::generate(&env));
+
+    let event_id = String::from_str(&env, "concert");
+    let tier_id = String::from_str(&env, "general");
+    let mut tiers = Map::new(&env);
+    tiers.set(
+        tier_id.clone(),
+        TicketTier {
+            name: String::from_str(&env, "General"),
+            price: 5000000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
+            tier_limit: 10,
+            current_sold: 0,
+            is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
+        },
+    );
+    client.register_event(&EventRegistrationArgs {
+        event_id: event_id.clone(),
+        organizer_address: organizer.clone(),
+        payment_address: organizer.clone(),
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 0,
+        milestone_plan: None,
+        tiers,
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    });
+    assert_eq!(client.get_refund_policy(&event_id), None);
+
+    let step = |until: u64, refund_bps: u32| RefundStep { until, refund_bps };
+    // Schedules must step forward in time and never raise the refund
+    for bad in [
+        RefundPolicy::Schedule(vec![&env]),
+        RefundPolicy::Schedule(vec![&env, step(200, 5000), step(100, 2500)]),
+        RefundPolicy::Schedule(vec![&env, step(100, 5000), step(200, 7500)]),
+        RefundPolicy::Schedule(vec![&env, step(100, 10_001)]),
+        RefundPolicy::FullUntil(0),
+    ] {
+        assert_eq!(
+            client.try_set_refund_policy(&event_id, &bad),
+            Err(Ok(EventRegistryError::InvalidRefundPolicy))
+        );
+    }
+    let schedule = RefundPolicy::Schedule(vec![&env, step(100, 10_000), step(200, 5000)]);
+    client.set_refund_policy(&event_id, &schedule);
+    assert_eq!(client.get_refund_policy(&event_id), Some(schedule));
+
+    // Buyers purchased under the policy, so it can no longer change
+    client.increment_inventory(&event_id, &tier_id, &1);
+    assert_eq!(
+        client.try_set_refund_policy(&event_id, &RefundPolicy::NoRefund),
+        Err(Ok(EventRegistryError::InvalidRefundPolicy))
+    );
+}
+
+#[test]
+fn test_dutch_auction_tier_price_decays_to_floor() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let tier_id = String::from_str(&env, "drop");
+    let auction_args = |event_id: &str, floor_price: i128| {
+        let mut tiers = Map::new(&env);
+        tiers.set(
+            tier_id.clone(),
+            TicketTier {
+                name: String::from_str(&env, "Drop"),
+                price: 1000,
+                early_bird_price: 0,
+                early_bird_deadline: 0,
+                early_bird_quantity: 0,
+                usd_price: 0,
+                tier_limit: 10,
+                current_sold: 0,
+                is_refundable: true,
+                visible_from: 0,
+                visible_until: 0,
+                token_prices: Map::new(&env),
+                deposit_amount: 0,
+                pricing: TierPricing::DutchAuction(DutchAuction {
+                    floor_price,
+                    start: 100,
+                    end: 700,
+                    step_seconds: 100,
+                }),
+                bulk_discounts: Map::new(&env),
+            },
+        );
+        EventRegistrationArgs {
+            event_id: String::from_str(&env, event_id),
+            organizer_address: organizer.clone(),
+            payment_address: organizer.clone(),
+            metadata_cid: String::from_str(
+                &env,
+                "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+            ),
+            max_supply: 0,
+            milestone_plan: None,
+            tiers,
+            refund_deadline: 0,
+            restocking_fee: 0,
+            resale_cap_bps: None,
+            min_sales_target: None,
+            target_deadline: None,
+            visibility: EventVisibility::Public,
+            recovery_address: None,
+            recovery_timeout: 0,
+            policy_flags: 0,
+            promo_mode: PromoMode::Shared,
+            resale_royalty_bps: 0,
+        }
+    };
+
+    // The floor must sit below the starting price
+    assert_eq!(
+        client.try_register_event(&auction_args("bad", 1000)),
+        Err(Ok(EventRegistryError::InvalidDutchAuction))
+    );
+
+    client.register_event(&auction_args("drop", 400));
+    let event_id = String::from_str(&env, "drop");
+    env.ledger().set_timestamp(50);
+    assert_eq!(client.get_current_price(&event_id, &tier_id), 1000);
+    env.ledger().set_timestamp(250);
+    assert_eq!(client.get_current_price(&event_id, &tier_id), 900);
+    env.ledger().set_timestamp(700);
+    assert_eq!(client.get_current_price(&event_id, &tier_id), 400);
+    assert_eq!(
+        client.try_get_current_price(&event_id, &String::from_str(&env, "nope")),
+        Err(Ok(EventRegistryError::TierNotFound))
+    );
+}
+
+#[test]
+fn test_register_event_rejects_invalid_bulk_discount() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let args = |event_id: &str, bulk_discounts: Map<u32, u32>| {
+        let mut tiers = Map::new(&env);
+        tiers.set(
+            String::from_str(&env, "general"),
+            TicketTier {
+                name: String::from_str(&env, "General"),
+                price: 5000000,
+                early_bird_price: 0,
+                early_bird_deadline: 0,
+                early_bird_quantity: 0,
+                usd_price: 0,
+                tier_limit: 10,
+                current_sold: 0,
+                is_refundable: true,
+                visible_from: 0,
+                visible_until: 0,
+                token_prices: Map::new(&env),
+                deposit_amount: 0,
+                pricing: TierPricing::Fixed,
+                bulk_discounts,
+            },
+        );
+        EventRegistrationArgs {
+            event_id: String::from_str(&env, event_id),
+            organizer_address: organizer.clone(),
+            payment_address: organizer.clone(),
+            metadata_cid: String::from_str(
+                &env,
+                "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+            ),
+            max_supply: 0,
+            milestone_plan: None,
+            tiers,
+            refund_deadline: 0,
+            restocking_fee: 0,
+            resale_cap_bps: None,
+            min_sales_target: None,
+            target_deadline: None,
+            visibility: EventVisibility::Public,
+            recovery_address: None,
+            recovery_timeout: 0,
+            policy_flags: 0,
+            promo_mode: PromoMode::Shared,
+            resale_royalty_bps: 0,
+        }
+    };
+
+    assert_eq!(
+        client.try_register_event(&args("too_deep", map![&env, (5u32, 10_001u32)])),
+        Err(Ok(EventRegistryError::InvalidPromoBps))
+    );
+    client.register_event(&args(
+        "group",
+        map![&env, (5u32, 1000u32), (10u32, 2000u32)],
+    ));
+    let tier = client
+        .get_event(&String::from_str(&env, "group"))
+        .unwrap()
+        .tiers
+        .get(String::from_str(&env, "general"))
+        .unwrap();
+    assert_eq!(tier.bulk_discounts.get(10), Some(2000));
+}
+
+#[test]
+fn test_complete_event_requires_passed_end_time() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    client.initialize(&admin, &Address::generate(&env), &500);
+
+    let event_id = String::from_str(&env, "festival");
+    client.register_event(&EventRegistrationArgs {
+        event_id: event_id.clone(),
+        organizer_address: organizer.clone(),
+        payment_address: organizer.clone(),
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 0,
+        milestone_plan: None,
+        tiers: Map::new(&env),
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    });
+
+    // Without an end time the event cannot be completed
+    assert_eq!(
+        client.try_complete_event(&event_id),
+        Err(Ok(EventRegistryError::EventNotEnded))
+    );
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.set_event_end_time(&event_id, &5_000);
+    assert_eq!(
+        client.try_complete_event(&event_id),
+        Err(Ok(EventRegistryError::EventNotEnded))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    client.complete_event(&event_id);
+    let event_info = client.get_event(&event_id).unwrap();
+    assert_eq!(event_info.status, EventStatus::Completed);
+    assert!(!event_info.is_active);
+    assert_eq!(
+        client.get_event_schedule(&event_id),
+        Some(EventSchedule {
+            end_time: 5_000,
+            completed_at: 5_000,
+        })
+    );
+
+    // Completion is final
+    assert_eq!(
+        client.try_update_event_status(&event_id, &true),
+        Err(Ok(EventRegistryError::EventInactive))
+    );
+    assert_eq!(
+        client.try_set_event_end_time(&event_id, &9_000),
+        Err(Ok(EventRegistryError::EventInactive))
+    );
+    assert_eq!(
+        client.try_cancel_event(&event_id),
+        Err(Ok(EventRegistryError::EventInactive))
+    );
+}
+
+#[test]
+fn test_bump_event_extends_event_ttl() {
+    use soroban_sdk::testutils::storage::Persistent as _;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let event_id = String::from_str(&env, "festival");
+    client.register_event(&EventRegistrationArgs {
+        event_id: event_id.clone(),
+        organizer_address: organizer.clone(),
+        payment_address: organizer.clone(),
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 0,
+        milestone_plan: None,
+        tiers: Map::new(&env),
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
+    });
+
+    let key = crate::types::DataKey::Event(event_id.clone());
+    let ttl = || env.as_contract(&contract_id, || env.storage().persistent().get_ttl(&key));
+    assert_eq!(ttl(), storage::PERSISTENT_BUMP_AMOUNT);
+
+    // Once the remaining TTL falls under the threshold a bump restores it
+    let elapsed = storage::PERSISTENT_BUMP_AMOUNT - storage::PERSISTENT_LIFETIME_THRESHOLD + 1;
+    env.ledger().with_mut(|li| li.sequence_number += elapsed);
+    assert_eq!(ttl(), storage::PERSISTENT_LIFETIME_THRESHOLD - 1);
+    client.bump_event(&event_id);
+    assert_eq!(ttl(), storage::PERSISTENT_BUMP_AMOUNT);
+
+    assert_eq!(
+        client.try_bump_event(&String::from_str(&env, "missing")),
+        Err(Ok(EventRegistryError::EventNotFound))
+    );
+}
+
+#[test]
+fn test_migrate_and_read_version_1_event_records() {
+    use crate::types::{DataKey, EventInfoV1, TicketTierV1};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let platform_wallet = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+    assert_eq!(client.get_storage_version(), STORAGE_VERSION);
+    assert_eq!(
+        client.try_migrate(&1),
+        Err(Ok(EventRegistryError::InvalidStorageVersion))
+    );
+
+    // Simulate a deployment upgraded from before versioning, holding a v1 record
+    let event_id = String::from_str(&env, "legacy");
+    let tier_id = String::from_str(&env, "general");
+    let mut tiers = Map::new(&env);
+    tiers.set(
+        tier_id.clone(),
+        TicketTierV1 {
+            name: String::from_str(&env, "General"),
+            price: 1000,
+            tier_limit: 10,
+            current_sold: 4,
+            is_refundable: true,
+        },
+    );
+    let legacy = EventInfoV1 {
+        event_id: event_id.clone(),
+        organizer_address: organizer.clone(),
+        payment_address: organizer.clone(),
+        platform_fee_percent: 500,
+        is_active: true,
+        status: EventStatus::Active,
+        created_at: 0,
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 10,
+        current_supply: 4,
+        milestone_plan: None,
+        tiers,
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        is_postponed: false,
+        grace_period_end: 0,
+        min_sales_target: 0,
+        target_deadline: 0,
+        goal_met: false,
+    };
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().remove(&DataKey::StorageVersion);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Event(event_id.clone()), &legacy);
+    });
+    assert_eq!(client.get_storage_version(), 1);
+
+    let event_info = client.get_event(&event_id).unwrap();
+    assert_eq!(event_info.visibility, EventVisibility::Public);
+    assert_eq!(event_info.current_supply, 4);
+    let tier = event_info.tiers.get(tier_id).unwrap();
+    assert_eq!(tier.price, 1000);
+    assert_eq!(tier.pricing, TierPricing::Fixed);
+    assert_eq!(tier.deposit_amount, 0);
+
+    // Updating the converted record rewrites it in the current layout
+    client.update_event_status(&event_id, &false);
+    let stored = env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .get::<_, EventInfo>(&DataKey::Event(event_id.clone()))
+    });
+    assert!(!stored.unwrap().is_active);
+
+    client.migrate(&1);
+    assert_eq!(client.get_storage_version(), STORAGE_VERSION);
+    assert_eq!(
+        client.try_migrate(&1),
+        Err(Ok(EventRegistryError::InvalidStorageVersion))
+    );
+}
+
+#[test]
+fn test_read_version_2_tiers_without_early_bird_pricing() {
+    use crate::types::DataKey;
+    use soroban_sdk::{Symbol, Val};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(EventRegistry, ());
+    let client = EventRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    client.initialize(&admin, &Address::generate(&env), &500);
+
+    let event_id = String::from_str(&env, "v2_event");
+    let tier_id = String::from_str(&env, "general");
+    let mut tiers = Map::new(&env);
+    tiers.set(
+        tier_id.clone(),
+        TicketTier {
+            name: String::from_str(&env, "General"),
+            price: 1000,
+            early_bird_price: 800,
+            early_bird_deadline: 100,
+            early_bird_quantity: 5,
+            usd_price: 10,
+            tier_limit: 10,
+            current_sold: 0,
+            is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
+        },
+    );
+    client.register_event(&EventRegistrationArgs {
+        event_id: event_id.clone(),
+        organizer_address: organizer.clone(),
+        payment_address: organizer,
+        metadata_cid: String::from_str(
+            &env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        max_supply: 10,
+        milestone_plan: None,
+        tiers,
+        refund_deadline: 0,
+        restocking_fee: 0,
+        resale_cap_bps: None,
+        min_sales_target: None,
+        target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 750,
+    });
+
+    client.set_max_tickets_per_buyer(&event_id, &4);
+    assert_eq!(
+        client.get_event(&event_id).unwrap().max_tickets_per_buyer,
+        4
+    );
+    assert_eq!(
+        client.get_event_payment_info(&event_id).resale_royalty_bps,
+        750
+    );
+    client.set_event_start_time(&event_id, &50_000);
+    client.set_transfer_lock_before_secs(&event_id, &3_600);
+    let event_info = client.get_event(&event_id).unwrap();
+    assert_eq!(event_info.event_start_time, 50_000);
+    assert_eq!(event_info.transfer_lock_before_secs, 3_600);
+
+    // Rewrite the record as version 2 stored it, without the newer fields
+    env.as_contract(&contract_id, || {
+        let key = DataKey::Event(event_id.clone());
+        let mut raw: Map<Symbol, Val> = env.storage().persistent().get(&key).unwrap();
+        let tiers_key = Symbol::new(&env, "tiers");
+        let tiers: Map<String, Map<Symbol, Val>> = raw
+            .get(tiers_key.clone())
+            .unwrap()
+            .try_into_val(&env)
+            .unwrap();
+        let mut legacy_tiers: Map<String, Map<Symbol, Val>> = Map::new(&env);
+        for (id, mut tier) in tiers.iter() {
+            for field in [
+                "early_bird_price",
+                "early_bird_deadline",
+                "early_bird_quantity",
+                "usd_price",
+            ] {
+                tier.remove(Symbol::new(&env, field));
+            }
+            legacy_tiers.set(id, tier);
+        }
+        raw.set(tiers_key, legacy_tiers.to_val());
+        raw.remove(Symbol::new(&env, "max_tickets_per_buyer"));
+        raw.remove(Symbol::new(&env, "resale_royalty_bps"));
+        raw.remove(Symbol::new(&env, "event_start_time"));
+        raw.remove(Symbol::new(&env, "transfer_lock_before_secs"));
+        env.storage().persistent().set(&key, &raw);
+    });
+
+    let event_info = client.get_event(&event_id).unwrap();
+    assert_eq!(event_info.max_tickets_per_buyer, 0);
+    assert_eq!(event_info.resale_royalty_bps, 0);
+    assert_eq!(event_info.event_start_time, 0);
+    assert_eq!(event_info.transfer_lock_before_secs, 0);
+    let tier = event_info.tiers.get(tier_id).unwrap();
+    assert_eq!(tier.price, 1000);
+    assert_eq!(tier.early_bird_price, 0);
+    assert_eq!(tier.early_bird_deadline, 0);
+    assert_eq!(tier.early_bird_quantity, 0);
+    assert_eq!(tier.usd_price, 0);
+    let payment_info = client.get_event_payment_info(&event_id);
+    assert_eq!(payment_info.tiers.len(), 1);
+}
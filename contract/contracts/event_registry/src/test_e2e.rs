@@ -1,6 +1,6 @@
 use super::*;
 use crate::error::EventRegistryError;
-use crate::types::{EventRegistrationArgs, EventStatus, TicketTier};
+use crate::types::{EventRegistrationArgs, EventStatus, EventVisibility, TicketTier, TierPricing};
 use soroban_sdk::{testutils::Address as _, Address, Env, Map, String};
 
 /// Helper: initialize the contract and return (client, admin, platform_wallet).
@@ -38,6 +38,12 @@ fn make_event_args(
         resale_cap_bps: None,
         min_sales_target: None,
         target_deadline: None,
+        visibility: EventVisibility::Public,
+        recovery_address: None,
+        recovery_timeout: 0,
+        policy_flags: 0,
+        promo_mode: PromoMode::Shared,
+        resale_royalty_bps: 0,
     }
 }
 
@@ -49,9 +55,19 @@ fn single_tier(env: &Env, tier_limit: i128) -> Map<String, TicketTier> {
         TicketTier {
             name: String::from_str(env, "General"),
             price: 1000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
             tier_limit,
             current_sold: 0,
             is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(env),
         },
     );
     tiers
@@ -123,9 +139,19 @@ fn test_e2e_zero_max_supply_means_unlimited() {
         TicketTier {
             name: String::from_str(&env, "General"),
             price: 1000,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
             tier_limit: i128::MAX,
             current_sold: 0,
             is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(&env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(&env),
         },
     );
     let args = make_event_args(&env, "evt_unlim", &organizer, 0, tiers);
@@ -353,3 +379,84 @@ fn test_e2e_min_goal_tracking() {
     let info = client.get_event(&event_id).unwrap();
     assert!(info.goal_met);
 }
+
+// ---------------------------------------------------------------------------
+// 8. Multi-event bundles
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_e2e_bundle_registration_and_supply() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _pw) = setup(&env);
+    let ticket_payment = Address::generate(&env);
+    client.set_ticket_payment_contract(&ticket_payment);
+
+    let organizer = Address::generate(&env);
+    let rival = Address::generate(&env);
+    client.register_event(&make_event_args(
+        &env,
+        "night_1",
+        &organizer,
+        0,
+        single_tier(&env, 10),
+    ));
+    client.register_event(&make_event_args(
+        &env,
+        "night_2",
+        &organizer,
+        0,
+        single_tier(&env, 10),
+    ));
+    client.register_event(&make_event_args(
+        &env,
+        "other",
+        &rival,
+        0,
+        single_tier(&env, 10),
+    ));
+
+    let bundle_id = String::from_str(&env, "season");
+    let tier_id = String::from_str(&env, "tier_1");
+    let nights = soroban_sdk::vec![
+        &env,
+        String::from_str(&env, "night_1"),
+        String::from_str(&env, "night_2"),
+    ];
+    // Every event must belong to the organizer and offer the tier
+    let mut with_rival = nights.clone();
+    with_rival.push_back(String::from_str(&env, "other"));
+    assert_eq!(
+        client.try_register_bundle(&organizer, &bundle_id, &with_rival, &tier_id, &1500, &1),
+        Err(Ok(EventRegistryError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_register_bundle(
+            &organizer,
+            &bundle_id,
+            &nights,
+            &String::from_str(&env, "vip"),
+            &1500,
+            &1
+        ),
+        Err(Ok(EventRegistryError::TierNotFound))
+    );
+
+    client.register_bundle(&organizer, &bundle_id, &nights, &tier_id, &1500, &1);
+    assert_eq!(env.auths()[0].0, organizer);
+    assert_eq!(
+        client.try_register_bundle(&organizer, &bundle_id, &nights, &tier_id, &1500, &1),
+        Err(Ok(EventRegistryError::EventAlreadyExists))
+    );
+    let bundle = client.get_bundle(&bundle_id).unwrap();
+    assert_eq!(bundle.event_ids, nights);
+    assert_eq!(bundle.price, 1500);
+
+    client.record_bundle_sale(&bundle_id);
+    assert_eq!(env.auths()[0].0, ticket_payment);
+    assert_eq!(client.get_bundle(&bundle_id).unwrap().sold, 1);
+    assert_eq!(
+        client.try_record_bundle_sale(&bundle_id),
+        Err(Ok(EventRegistryError::MaxSupplyExceeded))
+    );
+}
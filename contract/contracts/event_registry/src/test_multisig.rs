@@ -1,3 +1,5 @@
+use crate::error::EventRegistryError;
+use crate::types::AdminAction;
 use crate::{EventRegistry, EventRegistryClient};
 use soroban_sdk::{testutils::Address as _, testutils::Ledger, Address, Env};
 
@@ -37,10 +39,10 @@ fn test_create_proposal_add_admin() {
     client.initialize(&admin1, &platform_wallet, &500);
 
     // Create proposal to add admin2
-    let proposal_id = client.propose_add_admin(&admin1, &admin2, &0);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::AddAdmin(admin2.clone()), &0);
 
     // Verify proposal was created
-    let proposal = client.get_proposal(&proposal_id);
+    let proposal = client.get_proposal(&proposal_id).unwrap();
     assert_eq!(proposal.proposal_id, proposal_id);
     assert_eq!(proposal.proposer, admin1);
     assert_eq!(proposal.approvals.len(), 1); // Proposer auto-approves
@@ -55,14 +57,14 @@ fn test_execute_proposal_single_admin() {
     client.initialize(&admin1, &platform_wallet, &500);
 
     // Create and execute proposal to add admin2 (threshold = 1, so auto-approved)
-    let proposal_id = client.propose_add_admin(&admin1, &admin2, &0);
-    client.execute_proposal(&admin1, &proposal_id);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::AddAdmin(admin2.clone()), &0);
+    client.execute_action(&admin1, &proposal_id);
 
     // Verify admin2 was added
     assert!(client.is_admin(&admin2));
 
     // Verify proposal was marked as executed
-    let proposal = client.get_proposal(&proposal_id);
+    let proposal = client.get_proposal(&proposal_id).unwrap();
     assert!(proposal.executed);
 }
 
@@ -74,28 +76,28 @@ fn test_multisig_workflow_add_admin() {
     client.initialize(&admin1, &platform_wallet, &500);
 
     // Add admin2 (threshold = 1)
-    let proposal_id = client.propose_add_admin(&admin1, &admin2, &0);
-    client.execute_proposal(&admin1, &proposal_id);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::AddAdmin(admin2.clone()), &0);
+    client.execute_action(&admin1, &proposal_id);
 
     // Set threshold to 2
-    let proposal_id = client.propose_set_threshold(&admin1, &2, &0);
-    client.execute_proposal(&admin1, &proposal_id);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::SetThreshold(2), &0);
+    client.execute_action(&admin1, &proposal_id);
 
     let config = client.get_multisig_config();
     assert_eq!(config.threshold, 2);
 
     // Now try to add admin3 - requires 2 approvals
-    let proposal_id = client.propose_add_admin(&admin1, &admin3, &0);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::AddAdmin(admin3.clone()), &0);
 
     // Try to execute with only 1 approval - should fail
-    let result = client.try_execute_proposal(&admin1, &proposal_id);
+    let result = client.try_execute_action(&admin1, &proposal_id);
     assert!(result.is_err());
 
     // Admin2 approves
-    client.approve_proposal(&admin2, &proposal_id);
+    client.approve_action(&admin2, &proposal_id);
 
     // Now execute should succeed
-    client.execute_proposal(&admin1, &proposal_id);
+    client.execute_action(&admin1, &proposal_id);
 
     // Verify admin3 was added
     assert!(client.is_admin(&admin3));
@@ -110,20 +112,24 @@ fn test_propose_set_platform_wallet() {
     client.initialize(&admin1, &platform_wallet, &500);
 
     // Add admin2 and set threshold to 2
-    let proposal_id = client.propose_add_admin(&admin1, &admin2, &0);
-    client.execute_proposal(&admin1, &proposal_id);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::AddAdmin(admin2.clone()), &0);
+    client.execute_action(&admin1, &proposal_id);
 
-    let proposal_id = client.propose_set_threshold(&admin1, &2, &0);
-    client.execute_proposal(&admin1, &proposal_id);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::SetThreshold(2), &0);
+    client.execute_action(&admin1, &proposal_id);
 
     // Propose to change platform wallet
-    let proposal_id = client.propose_set_platform_wallet(&admin1, &new_wallet, &0);
+    let proposal_id = client.propose_action(
+        &admin1,
+        &AdminAction::SetPlatformWallet(new_wallet.clone()),
+        &0,
+    );
 
     // Admin2 approves
-    client.approve_proposal(&admin2, &proposal_id);
+    client.approve_action(&admin2, &proposal_id);
 
     // Execute
-    client.execute_proposal(&admin1, &proposal_id);
+    client.execute_action(&admin1, &proposal_id);
 
     // Verify wallet was changed
     let current_wallet = client.get_platform_wallet();
@@ -138,24 +144,24 @@ fn test_remove_admin_with_multisig() {
     client.initialize(&admin1, &platform_wallet, &500);
 
     // Add admin2 and admin3
-    let proposal_id = client.propose_add_admin(&admin1, &admin2, &0);
-    client.execute_proposal(&admin1, &proposal_id);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::AddAdmin(admin2.clone()), &0);
+    client.execute_action(&admin1, &proposal_id);
 
-    let proposal_id = client.propose_add_admin(&admin1, &admin3, &0);
-    client.execute_proposal(&admin1, &proposal_id);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::AddAdmin(admin3.clone()), &0);
+    client.execute_action(&admin1, &proposal_id);
 
     // Set threshold to 2
-    let proposal_id = client.propose_set_threshold(&admin1, &2, &0);
-    client.execute_proposal(&admin1, &proposal_id);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::SetThreshold(2), &0);
+    client.execute_action(&admin1, &proposal_id);
 
     // Propose to remove admin3
-    let proposal_id = client.propose_remove_admin(&admin1, &admin3, &0);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::RemoveAdmin(admin3.clone()), &0);
 
     // Admin2 approves
-    client.approve_proposal(&admin2, &proposal_id);
+    client.approve_action(&admin2, &proposal_id);
 
     // Execute
-    client.execute_proposal(&admin1, &proposal_id);
+    client.execute_action(&admin1, &proposal_id);
 
     // Verify admin3 was removed
     assert!(!client.is_admin(&admin3));
@@ -166,7 +172,7 @@ fn test_remove_admin_with_multisig() {
 }
 
 #[test]
-#[should_panic(expected = "CannotRemoveLastAdmin")]
+#[should_panic(expected = "Error(Contract, #36)")] // CannotRemoveLastAdmin
 fn test_cannot_remove_last_admin() {
     let (env, client, admin1, _, _) = create_test_env();
     let platform_wallet = Address::generate(&env);
@@ -174,11 +180,11 @@ fn test_cannot_remove_last_admin() {
     client.initialize(&admin1, &platform_wallet, &500);
 
     // Try to remove the only admin - should fail
-    client.propose_remove_admin(&admin1, &admin1, &0);
+    client.propose_action(&admin1, &AdminAction::RemoveAdmin(admin1.clone()), &0);
 }
 
 #[test]
-#[should_panic(expected = "AdminAlreadyExists")]
+#[should_panic(expected = "Error(Contract, #34)")] // AdminAlreadyExists
 fn test_cannot_add_duplicate_admin() {
     let (env, client, admin1, _, _) = create_test_env();
     let platform_wallet = Address::generate(&env);
@@ -186,11 +192,11 @@ fn test_cannot_add_duplicate_admin() {
     client.initialize(&admin1, &platform_wallet, &500);
 
     // Try to add admin1 again - should fail
-    client.propose_add_admin(&admin1, &admin1, &0);
+    client.propose_action(&admin1, &AdminAction::AddAdmin(admin1.clone()), &0);
 }
 
 #[test]
-#[should_panic(expected = "AlreadyApproved")]
+#[should_panic(expected = "Error(Contract, #38)")] // AlreadyApproved
 fn test_cannot_approve_twice() {
     let (env, client, admin1, admin2, admin3) = create_test_env();
     let platform_wallet = Address::generate(&env);
@@ -198,22 +204,22 @@ fn test_cannot_approve_twice() {
     client.initialize(&admin1, &platform_wallet, &500);
 
     // Add admin2
-    let proposal_id = client.propose_add_admin(&admin1, &admin2, &0);
-    client.execute_proposal(&admin1, &proposal_id);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::AddAdmin(admin2.clone()), &0);
+    client.execute_action(&admin1, &proposal_id);
 
     // Set threshold to 2
-    let proposal_id = client.propose_set_threshold(&admin1, &2, &0);
-    client.execute_proposal(&admin1, &proposal_id);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::SetThreshold(2), &0);
+    client.execute_action(&admin1, &proposal_id);
 
     // Create proposal to add admin3
-    let proposal_id = client.propose_add_admin(&admin1, &admin3, &0);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::AddAdmin(admin3.clone()), &0);
 
     // Admin1 tries to approve again (already auto-approved as proposer)
-    client.approve_proposal(&admin1, &proposal_id);
+    client.approve_action(&admin1, &proposal_id);
 }
 
 #[test]
-#[should_panic(expected = "ProposalAlreadyExecuted")]
+#[should_panic(expected = "Error(Contract, #29)")] // ProposalAlreadyExecuted
 fn test_cannot_execute_twice() {
     let (env, client, admin1, admin2, _) = create_test_env();
     let platform_wallet = Address::generate(&env);
@@ -221,15 +227,15 @@ fn test_cannot_execute_twice() {
     client.initialize(&admin1, &platform_wallet, &500);
 
     // Create and execute proposal
-    let proposal_id = client.propose_add_admin(&admin1, &admin2, &0);
-    client.execute_proposal(&admin1, &proposal_id);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::AddAdmin(admin2.clone()), &0);
+    client.execute_action(&admin1, &proposal_id);
 
     // Try to execute again - should fail
-    client.execute_proposal(&admin1, &proposal_id);
+    client.execute_action(&admin1, &proposal_id);
 }
 
 #[test]
-#[should_panic(expected = "InvalidThreshold")]
+#[should_panic(expected = "Error(Contract, #37)")] // InvalidThreshold
 fn test_invalid_threshold_too_high() {
     let (env, client, admin1, _, _) = create_test_env();
     let platform_wallet = Address::generate(&env);
@@ -237,11 +243,11 @@ fn test_invalid_threshold_too_high() {
     client.initialize(&admin1, &platform_wallet, &500);
 
     // Try to set threshold higher than admin count - should fail
-    client.propose_set_threshold(&admin1, &5, &0);
+    client.propose_action(&admin1, &AdminAction::SetThreshold(5), &0);
 }
 
 #[test]
-#[should_panic(expected = "InvalidThreshold")]
+#[should_panic(expected = "Error(Contract, #37)")] // InvalidThreshold
 fn test_invalid_threshold_zero() {
     let (env, client, admin1, _, _) = create_test_env();
     let platform_wallet = Address::generate(&env);
@@ -249,7 +255,7 @@ fn test_invalid_threshold_zero() {
     client.initialize(&admin1, &platform_wallet, &500);
 
     // Try to set threshold to 0 - should fail
-    client.propose_set_threshold(&admin1, &0, &0);
+    client.propose_action(&admin1, &AdminAction::SetThreshold(0), &0);
 }
 
 #[test]
@@ -260,14 +266,14 @@ fn test_get_active_proposals() {
     client.initialize(&admin1, &platform_wallet, &500);
 
     // Create multiple proposals
-    let proposal_id1 = client.propose_add_admin(&admin1, &admin2, &0);
-    let proposal_id2 = client.propose_add_admin(&admin1, &admin3, &0);
+    let proposal_id1 = client.propose_action(&admin1, &AdminAction::AddAdmin(admin2.clone()), &0);
+    let proposal_id2 = client.propose_action(&admin1, &AdminAction::AddAdmin(admin3.clone()), &0);
 
     let active_proposals = client.get_active_proposals();
     assert_eq!(active_proposals.len(), 2);
 
     // Execute one proposal
-    client.execute_proposal(&admin1, &proposal_id1);
+    client.execute_action(&admin1, &proposal_id1);
 
     // Should have one less active proposal
     let active_proposals = client.get_active_proposals();
@@ -283,14 +289,14 @@ fn test_proposal_expiration() {
     client.initialize(&admin1, &platform_wallet, &500);
 
     // Add admin2 and set threshold to 2
-    let proposal_id = client.propose_add_admin(&admin1, &admin2, &0);
-    client.execute_proposal(&admin1, &proposal_id);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::AddAdmin(admin2.clone()), &0);
+    client.execute_action(&admin1, &proposal_id);
 
-    let proposal_id = client.propose_set_threshold(&admin1, &2, &0);
-    client.execute_proposal(&admin1, &proposal_id);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::SetThreshold(2), &0);
+    client.execute_action(&admin1, &proposal_id);
 
     // Create proposal with short expiration (10 ledgers)
-    let proposal_id = client.propose_add_admin(&admin1, &admin3, &10);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::AddAdmin(admin3.clone()), &10);
 
     // Advance ledger past expiration
     env.ledger().with_mut(|li| {
@@ -298,7 +304,7 @@ fn test_proposal_expiration() {
     });
 
     // Try to approve - should fail due to expiration
-    let result = client.try_approve_proposal(&admin2, &proposal_id);
+    let result = client.try_approve_action(&admin2, &proposal_id);
     assert!(result.is_err());
 }
 
@@ -310,27 +316,212 @@ fn test_threshold_adjustment_on_admin_removal() {
     client.initialize(&admin1, &platform_wallet, &500);
 
     // Add admin2 and admin3
-    let proposal_id = client.propose_add_admin(&admin1, &admin2, &0);
-    client.execute_proposal(&admin1, &proposal_id);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::AddAdmin(admin2.clone()), &0);
+    client.execute_action(&admin1, &proposal_id);
 
-    let proposal_id = client.propose_add_admin(&admin1, &admin3, &0);
-    client.execute_proposal(&admin1, &proposal_id);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::AddAdmin(admin3.clone()), &0);
+    client.execute_action(&admin1, &proposal_id);
 
     // Set threshold to 3 (all admins required)
-    let proposal_id = client.propose_set_threshold(&admin1, &3, &0);
-    client.execute_proposal(&admin1, &proposal_id);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::SetThreshold(3), &0);
+    client.execute_action(&admin1, &proposal_id);
 
     let config = client.get_multisig_config();
     assert_eq!(config.threshold, 3);
 
     // Propose to remove admin3 (requires all 3 approvals)
-    let proposal_id = client.propose_remove_admin(&admin1, &admin3, &0);
-    client.approve_proposal(&admin2, &proposal_id);
-    client.approve_proposal(&admin3, &proposal_id);
-    client.execute_proposal(&admin1, &proposal_id);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::RemoveAdmin(admin3.clone()), &0);
+    client.approve_action(&admin2, &proposal_id);
+    client.approve_action(&admin3, &proposal_id);
+    client.execute_action(&admin1, &proposal_id);
 
     // Threshold should be adjusted to 2 (can't be higher than admin count)
     let config = client.get_multisig_config();
     assert_eq!(config.admins.len(), 2);
     assert_eq!(config.threshold, 2);
 }
+
+#[test]
+fn test_threshold_gates_direct_admin_calls() {
+    let (env, client, admin1, admin2, _) = create_test_env();
+    let platform_wallet = Address::generate(&env);
+    let organizer = Address::generate(&env);
+
+    client.initialize(&admin1, &platform_wallet, &500);
+
+    let proposal_id = client.propose_action(&admin1, &AdminAction::AddAdmin(admin2.clone()), &0);
+    client.execute_action(&admin1, &proposal_id);
+    let proposal_id = client.propose_action(&admin1, &AdminAction::SetThreshold(2), &0);
+    client.execute_action(&admin1, &proposal_id);
+
+    // Direct fee changes and blacklisting are refused above threshold 1
+    assert_eq!(
+        client.try_set_platform_fee(&700),
+        Err(Ok(EventRegistryError::Unauthorized))
+    );
+    let reason = soroban_sdk::String::from_str(&env, "fraud");
+    assert_eq!(
+        client.try_blacklist_organizer(&organizer, &reason),
+        Err(Ok(EventRegistryError::Unauthorized))
+    );
+
+    // The same changes go through once a second admin approves
+    let fee_id = client.propose_action(&admin1, &AdminAction::SetPlatformFee(700), &0);
+    assert_eq!(
+        client.try_execute_action(&admin1, &fee_id),
+        Err(Ok(EventRegistryError::InsufficientApprovals))
+    );
+    client.approve_action(&admin2, &fee_id);
+    client.execute_action(&admin2, &fee_id);
+    assert_eq!(client.get_platform_fee(), 700);
+
+    let blacklist_id = client.propose_action(
+        &admin2,
+        &AdminAction::BlacklistOrganizer(organizer.clone(), reason),
+        &0,
+    );
+    client.approve_action(&admin1, &blacklist_id);
+    client.execute_action(&admin1, &blacklist_id);
+    assert!(client.is_organizer_blacklisted(&organizer));
+
+    // Non-admins cannot propose
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_propose_action(&outsider, &AdminAction::SetPlatformFee(100), &0),
+        Err(Ok(EventRegistryError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_timelock_gates_sensitive_actions() {
+    let (env, client, admin, _, _) = create_test_env();
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+    client.set_timelock(&86_400, &100);
+
+    // Small fee changes still apply directly; larger ones and wallet changes queue
+    client.set_platform_fee(&550);
+    assert_eq!(
+        client.try_set_platform_fee(&900),
+        Err(Ok(EventRegistryError::ActionTimelocked))
+    );
+    assert_eq!(
+        client.try_set_timelock(&0, &0),
+        Err(Ok(EventRegistryError::ActionTimelocked))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let fee_id = client.queue_action(&AdminAction::SetPlatformFee(900));
+    let new_wallet = Address::generate(&env);
+    let wallet_id = client.queue_action(&AdminAction::SetPlatformWallet(new_wallet.clone()));
+    assert_eq!(
+        client.get_queued_action(&fee_id).unwrap().eta,
+        1_000 + 86_400
+    );
+    assert_eq!(
+        client.try_execute_queued_action(&fee_id),
+        Err(Ok(EventRegistryError::ActionTimelocked))
+    );
+
+    client.cancel_queued_action(&wallet_id);
+    assert!(client.get_queued_action(&wallet_id).is_none());
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + 86_400);
+    client.execute_queued_action(&fee_id);
+    assert_eq!(client.get_platform_fee(), 900);
+    assert_eq!(
+        client.try_execute_queued_action(&fee_id),
+        Err(Ok(EventRegistryError::ProposalAlreadyExecuted))
+    );
+    assert_eq!(
+        client.try_execute_queued_action(&wallet_id),
+        Err(Ok(EventRegistryError::ProposalNotFound))
+    );
+    assert_eq!(client.get_platform_wallet(), platform_wallet);
+}
+
+#[test]
+fn test_timelock_delays_sensitive_proposals() {
+    let (env, client, admin1, admin2, _) = create_test_env();
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin1, &platform_wallet, &500);
+    client.set_timelock(&3_600, &0);
+
+    // Membership changes are not timelocked
+    let add_id = client.propose_action(&admin1, &AdminAction::AddAdmin(admin2.clone()), &0);
+    client.execute_action(&admin1, &add_id);
+
+    let new_wallet = Address::generate(&env);
+    let wallet_id = client.propose_action(
+        &admin1,
+        &AdminAction::SetPlatformWallet(new_wallet.clone()),
+        &0,
+    );
+    assert_eq!(
+        client.try_execute_action(&admin1, &wallet_id),
+        Err(Ok(EventRegistryError::ActionTimelocked))
+    );
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    client.execute_action(&admin1, &wallet_id);
+    assert_eq!(client.get_platform_wallet(), new_wallet);
+}
+
+#[test]
+fn test_platform_wallet_rotation_requires_acceptance() {
+    let (env, client, admin, _, _) = create_test_env();
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin, &platform_wallet, &500);
+
+    let new_wallet = Address::generate(&env);
+    client.propose_platform_wallet(&new_wallet);
+    assert_eq!(client.get_platform_wallet(), platform_wallet);
+    assert_eq!(
+        client.get_pending_platform_wallet(),
+        Some(new_wallet.clone())
+    );
+
+    client.accept_platform_wallet();
+    assert_eq!(env.auths()[0].0, new_wallet);
+    assert_eq!(client.get_platform_wallet(), new_wallet);
+    assert_eq!(
+        client.try_accept_platform_wallet(),
+        Err(Ok(EventRegistryError::ProposalNotFound))
+    );
+
+    // With a timelock, rotations are queued as `SetPlatformWallet` instead
+    client.set_timelock(&3_600, &0);
+    assert_eq!(
+        client.try_propose_platform_wallet(&Address::generate(&env)),
+        Err(Ok(EventRegistryError::ActionTimelocked))
+    );
+}
+
+#[test]
+fn test_admin_transfer_replaces_admin_on_acceptance() {
+    let (env, client, admin1, admin2, _) = create_test_env();
+    let platform_wallet = Address::generate(&env);
+    client.initialize(&admin1, &platform_wallet, &500);
+
+    client.transfer_admin(&admin2);
+    client.cancel_admin_transfer();
+    assert_eq!(
+        client.try_accept_admin(),
+        Err(Ok(EventRegistryError::ProposalNotFound))
+    );
+    assert_eq!(
+        client.try_transfer_admin(&admin1),
+        Err(Ok(EventRegistryError::AdminAlreadyExists))
+    );
+
+    client.transfer_admin(&admin2);
+    assert_eq!(client.get_admin(), admin1);
+    assert_eq!(client.get_pending_admin(), Some(admin2.clone()));
+    client.accept_admin();
+    assert_eq!(env.auths()[0].0, admin2);
+
+    assert_eq!(client.get_admin(), admin2);
+    assert!(client.is_admin(&admin2));
+    assert!(!client.is_admin(&admin1));
+    assert_eq!(client.get_multisig_config().admins.len(), 1);
+    assert_eq!(client.get_pending_admin(), None);
+}
@@ -1,100 +1,165 @@
-use soroban_sdk::{contracttype, Address, Map, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String, Vec};
 
-/// Represents a ticket tier with its own pricing and supply
+pub use agora_interface::registry::{
+    Bundle, DutchAuction, EventInfo, EventSchedule, EventStatus, EventVisibility, FlashSale,
+    Milestone, PaymentInfo, PromoMode, RecoveryConfig, RefundPolicy, RefundStep, SeatMap,
+    TicketTier, TierPricing,
+};
+
+/// Most events a single bundle may include.
+pub const MAX_BUNDLE_EVENTS: u32 = 10;
+
+/// Period during which an authorized scanner may check tickets in. A bound of
+/// 0 leaves that side of the window open.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct TicketTier {
-    /// Name of the tier (e.g., "General", "VIP", "Reserved")
-    pub name: String,
-    /// Price for this tier in stroops
-    pub price: i128,
-    /// Maximum tickets available for this tier
-    pub tier_limit: i128,
-    /// Current number of tickets sold for this tier
-    pub current_sold: i128,
-    /// Indicates whether tickets in this tier can be refunded by the buyer
-    pub is_refundable: bool,
+pub struct ScannerWindow {
+    pub valid_from: u64,
+    pub valid_until: u64,
 }
 
-/// Represents an early revenue release milestone.
+impl ScannerWindow {
+    /// Returns true if `now` falls within the window.
+    pub fn contains(&self, now: u64) -> bool {
+        now >= self.valid_from && (self.valid_until == 0 || now < self.valid_until)
+    }
+}
+
+/// Short-lived hold on tier inventory placed by the TicketPayment contract while
+/// a buyer completes payment. Held tickets count against the tier and event
+/// limits until `expires_at`, after which they are released automatically.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Milestone {
-    /// The number of tickets sold to reach this milestone
-    pub sales_threshold: i128,
-    /// Percentage of the available revenue to release (in basis points, 10000 = 100%)
-    pub release_percent: u32,
+pub struct InventoryHold {
+    pub event_id: String,
+    pub tier_id: String,
+    pub quantity: u32,
+    pub expires_at: u64,
+}
+
+/// Where an event imported via `import_event` came from.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImportProvenance {
+    /// The legacy registry deployment the event was exported from
+    pub source_contract: Address,
+    /// Ledger sequence at which the legacy state was snapshotted
+    pub source_ledger: u32,
+    /// Admin that performed the import
+    pub imported_by: Address,
+    /// Timestamp of the import
+    pub imported_at: u64,
 }
 
+/// Ticket tier as stored by version 1 deployments, before visibility windows,
+/// token prices, deposits, pricing modes, bulk discounts and early-bird or
+/// USD pricing.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub enum EventStatus {
-    Active,
-    Inactive,
-    Cancelled,
+pub struct TicketTierV1 {
+    pub name: String,
+    pub price: i128,
+    pub tier_limit: i128,
+    pub current_sold: i128,
+    pub is_refundable: bool,
+}
+
+impl TicketTierV1 {
+    /// Converts to the current layout with every newer field at its default.
+    pub fn into_current(self, env: &Env) -> TicketTier {
+        TicketTier {
+            name: self.name,
+            price: self.price,
+            early_bird_price: 0,
+            early_bird_deadline: 0,
+            early_bird_quantity: 0,
+            usd_price: 0,
+            tier_limit: self.tier_limit,
+            current_sold: self.current_sold,
+            is_refundable: self.is_refundable,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: Map::new(env),
+            deposit_amount: 0,
+            pricing: TierPricing::Fixed,
+            bulk_discounts: Map::new(env),
+        }
+    }
 }
 
-/// Represents information about an event in the registry.
+/// Event record as stored by version 1 deployments, before visibility and
+/// organizer verification were tracked.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct EventInfo {
-    /// Unique identifier for the event
+pub struct EventInfoV1 {
     pub event_id: String,
-    /// The wallet address of the event organizer
     pub organizer_address: Address,
-    /// The address where payments for this event should be routed
     pub payment_address: Address,
-    /// The percentage fee taken by the platform (e.g., 5 for 5%)
     pub platform_fee_percent: u32,
-    /// Whether the event is currently active and accepting payments
     pub is_active: bool,
-    /// The current status of the event
     pub status: EventStatus,
-    /// Timestamp when the event was created
     pub created_at: u64,
-    /// IPFS Content Identifier storing rich metadata details
     pub metadata_cid: String,
-    /// Maximum number of tickets available for this event (0 = unlimited)
     pub max_supply: i128,
-    /// Current number of tickets that have been successfully purchased
     pub current_supply: i128,
-    /// Optional milestone plan for early revenue release
     pub milestone_plan: Option<Vec<Milestone>>,
-    /// Map of tier_id to TicketTier for multi-tiered pricing
-    pub tiers: Map<String, TicketTier>,
-    /// Deadline for guests to request a refund (Unix timestamp)
+    pub tiers: Map<String, TicketTierV1>,
     pub refund_deadline: u64,
-    /// Fee deducted from refund amount
     pub restocking_fee: i128,
-    /// Optional resale price cap in basis points above face value.
-    /// None = no cap (free market), Some(0) = no markup, Some(1000) = max 10% above face value.
     pub resale_cap_bps: Option<u32>,
-    /// Indicates whether the event is currently postponed (date shifted)
-    /// and in a temporary refund grace period window.
     pub is_postponed: bool,
-    /// Timestamp (Unix) when the temporary refund grace period for a
-    /// postponed event ends. 0 means no grace period active.
     pub grace_period_end: u64,
-    /// Minimum number of tickets that must be sold for the event to proceed
     pub min_sales_target: i128,
-    /// Deadline by which the min_sales_target must be met (Unix timestamp)
     pub target_deadline: u64,
-    /// Whether the minimum sales target has been reached
     pub goal_met: bool,
 }
 
-/// Payment information for an event
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct PaymentInfo {
-    /// The address where payments for this event should be routed
-    pub payment_address: Address,
-    /// The percentage fee taken by the platform
-    pub platform_fee_percent: u32,
-    /// Map of tier_id to TicketTier for multi-tiered pricing
-    pub tiers: Map<String, TicketTier>,
+impl EventInfoV1 {
+    /// Converts to the current layout; the event is listed publicly and its
+    /// `verified` flag is left for the caller to refresh.
+    pub fn into_current(self, env: &Env) -> EventInfo {
+        let mut tiers = Map::new(env);
+        for (tier_id, tier) in self.tiers.iter() {
+            tiers.set(tier_id, tier.into_current(env));
+        }
+        EventInfo {
+            event_id: self.event_id,
+            organizer_address: self.organizer_address,
+            payment_address: self.payment_address,
+            platform_fee_percent: self.platform_fee_percent,
+            is_active: self.is_active,
+            status: self.status,
+            created_at: self.created_at,
+            metadata_cid: self.metadata_cid,
+            max_supply: self.max_supply,
+            current_supply: self.current_supply,
+            milestone_plan: self.milestone_plan,
+            tiers,
+            refund_deadline: self.refund_deadline,
+            restocking_fee: self.restocking_fee,
+            resale_cap_bps: self.resale_cap_bps,
+            is_postponed: self.is_postponed,
+            grace_period_end: self.grace_period_end,
+            min_sales_target: self.min_sales_target,
+            target_deadline: self.target_deadline,
+            goal_met: self.goal_met,
+            visibility: EventVisibility::Public,
+            verified: false,
+            max_tickets_per_buyer: 0,
+            resale_royalty_bps: 0,
+            event_start_time: 0,
+            transfer_lock_before_secs: 0,
+        }
+    }
 }
 
+/// Event policy flag: attendees must meet a minimum age.
+pub const POLICY_AGE_RESTRICTED: u32 = 1 << 0;
+/// Event policy flag: sales are limited to certain jurisdictions.
+pub const POLICY_JURISDICTION_LIMITED: u32 = 1 << 1;
+/// Event policy flag: buyers from sanctioned countries must be screened out.
+pub const POLICY_SANCTIONS_SCREENING: u32 = 1 << 2;
+
 /// Arguments required to register a new event
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -110,10 +175,36 @@ pub struct EventRegistrationArgs {
     pub restocking_fee: i128,
     /// Optional resale price cap in basis points above face value.
     pub resale_cap_bps: Option<u32>,
+    /// Organizer royalty on every on-chain resale, in basis points (0 for none)
+    pub resale_royalty_bps: u32,
     /// Minimum number of tickets that must be sold for the event to proceed
     pub min_sales_target: Option<i128>,
     /// Deadline by which the min_sales_target must be met (Unix timestamp)
     pub target_deadline: Option<u64>,
+    /// Initial listing visibility
+    pub visibility: EventVisibility,
+    /// Optional address that may claim the organizer escrow after inactivity
+    pub recovery_address: Option<Address>,
+    /// Seconds without organizer activity before recovery (required with an address)
+    pub recovery_timeout: u64,
+    /// Declared policy flags (`POLICY_*` bitfield), 0 for none
+    pub policy_flags: u32,
+    /// How the platform-wide promo applies to this event
+    pub promo_mode: PromoMode,
+}
+
+/// Self-registered organizer profile, verified by the admin.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrganizerProfile {
+    pub organizer: Address,
+    /// IPFS CID of the off-chain profile document
+    pub profile_cid: String,
+    /// Whether the admin verified this profile; reset when the CID changes
+    pub verified: bool,
+    pub registered_at: u64,
+    /// When the profile was last verified, 0 if never
+    pub verified_at: u64,
 }
 
 /// Audit log entry for blacklist actions
@@ -142,6 +233,61 @@ pub struct MultiSigConfig {
     pub threshold: u32,
 }
 
+/// Ledgers a proposal stays open when `expiration_ledgers` is 0 (~7 days).
+pub const DEFAULT_PROPOSAL_EXPIRY_LEDGERS: u64 = 120_960;
+/// Average ledger close time used to convert ledgers into an expiry timestamp.
+pub const LEDGER_SECONDS: u64 = 5;
+
+/// A threshold-gated admin action applied when its proposal is executed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminAction {
+    /// Equivalent to `set_platform_fee`
+    SetPlatformFee(u32),
+    /// Replaces the platform wallet
+    SetPlatformWallet(Address),
+    /// Equivalent to `blacklist_organizer` (organizer, reason)
+    BlacklistOrganizer(Address, String),
+    /// Equivalent to `remove_from_blacklist` (organizer, reason)
+    RemoveFromBlacklist(Address, String),
+    /// Equivalent to `upgrade`
+    Upgrade(BytesN<32>),
+    /// Adds an address to the admin set
+    AddAdmin(Address),
+    /// Removes an address from the admin set, lowering the threshold if needed
+    RemoveAdmin(Address),
+    /// Changes the number of approvals required to execute a proposal
+    SetThreshold(u32),
+    /// Replaces the timelock configuration (delay, fee_delta_bps)
+    SetTimelock(u64, u32),
+}
+
+/// Delay imposed on sensitive admin actions: upgrades, platform wallet and
+/// timelock changes, and platform fee changes larger than `fee_delta_bps`.
+/// A `delay` of 0 disables the timelock.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimelockConfig {
+    /// Seconds between queueing (or proposing) an action and executing it
+    pub delay: u64,
+    /// Largest platform fee change, in basis points, applied without delay
+    pub fee_delta_bps: u32,
+}
+
+/// An admin action waiting out the timelock before it can be executed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedAction {
+    /// Unique identifier (shared counter with admin proposals)
+    pub action_id: u64,
+    /// The action to perform on execution
+    pub action: AdminAction,
+    /// Timestamp from which the action can be executed
+    pub eta: u64,
+    /// Whether the action has been executed
+    pub executed: bool,
+}
+
 /// Represents a governance proposal
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -150,8 +296,8 @@ pub struct Proposal {
     pub proposal_id: u64,
     /// Address that created the proposal
     pub proposer: Address,
-    /// Description of the proposal
-    pub description: String,
+    /// The action to perform on execution
+    pub action: AdminAction,
     /// Addresses that have approved this proposal
     pub approvals: Vec<Address>,
     /// Whether the proposal has been executed
@@ -162,6 +308,41 @@ pub struct Proposal {
     pub expires_at: u64,
 }
 
+/// An organizer action that can be proposed and later executed by the
+/// event's organizer (typically a governance contract).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OrganizerAction {
+    /// Equivalent to `update_event_status`
+    SetActive(bool),
+    /// Equivalent to `update_metadata`
+    UpdateMetadata(String),
+    /// Equivalent to `set_event_visibility`
+    SetVisibility(EventVisibility),
+    /// Equivalent to `postpone_event`
+    Postpone(u64),
+    /// Equivalent to `cancel_event`
+    Cancel,
+}
+
+/// A pending or executed organizer action proposal for an event.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrganizerProposal {
+    /// Unique identifier for the proposal (shared counter with admin proposals)
+    pub proposal_id: u64,
+    /// The event the action applies to
+    pub event_id: String,
+    /// Address that created the proposal
+    pub proposer: Address,
+    /// The action to perform on execution
+    pub action: OrganizerAction,
+    /// Whether the proposal has been executed
+    pub executed: bool,
+    /// Timestamp when the proposal was created
+    pub created_at: u64,
+}
+
 /// Storage keys for the Event Registry contract.
 #[contracttype]
 pub enum DataKey {
@@ -187,8 +368,14 @@ pub enum DataKey {
     TicketPaymentContract,
     /// Mapping of organizer address to blacklist status (Persistent)
     BlacklistedOrganizer(Address),
-    /// List of blacklisted organizer addresses for audit purposes (Persistent)
-    BlacklistLog,
+    /// Shard of the global blacklist audit log (Persistent)
+    BlacklistLogShard(u32),
+    /// Total number of entries in the global blacklist audit log (Persistent)
+    BlacklistLogCount,
+    /// Shard of an organizer's blacklist audit history (Persistent)
+    BlacklistHistoryShard(Address, u32),
+    /// Number of entries in an organizer's blacklist audit history (Persistent)
+    BlacklistHistoryCount(Address),
     /// Global promotional discount in basis points (e.g., 1500 = 15%)
     GlobalPromoBps,
     /// Expiry timestamp for the global promotional discount
@@ -201,4 +388,85 @@ pub enum DataKey {
     ActiveProposals,
     /// Mapping of (event_id, scanner_address) to bool
     AuthorizedScanner(String, Address),
+    /// Sharded global index of all event_ids (Persistent)
+    GlobalEventShard(u32),
+    /// Total number of events in the global index (Persistent)
+    GlobalEventCount,
+    /// Mapping of (event_id, tier_id) to its scheduled FlashSale (Persistent)
+    FlashSale(String, String),
+    /// Mapping of proposal_id to OrganizerProposal (Persistent)
+    OrganizerProposal(u64),
+    /// Mapping of (event_id, proposer) to true if they may propose organizer actions (Persistent)
+    ActionProposer(String, Address),
+    /// Mapping of event_id to its escrow RecoveryConfig (Persistent)
+    RecoveryConfig(String),
+    /// Mapping of event_id to its declared policy flags (Persistent)
+    PolicyFlags(String),
+    /// Map of trigger flag to the flags it requires (Persistent)
+    PolicyRequirements,
+    /// Mapping of event_id to its PromoMode, absent for Shared (Persistent)
+    PromoMode(String),
+    /// Mapping of event_id to its ImportProvenance, set once (Persistent)
+    ImportProvenance(String),
+    /// Mapping of (event_id, scanner_address) to its ScannerWindow, absent when unbounded
+    ScannerWindow(String, Address),
+    /// Mapping of (event_id, tier_id) to its SeatMap, absent for general admission
+    SeatMap(String, String),
+    /// Mapping of (event_id, tier_id, seat_id) to true while the seat is reserved
+    ReservedSeat(String, String, String),
+    /// Mapping of event_id to its RefundPolicy, absent for the legacy fields (Persistent)
+    RefundPolicy(String),
+    /// Mapping of event_id to its EventSchedule, absent until an end time is set (Persistent)
+    EventSchedule(String),
+    /// Timelock configuration for sensitive admin actions (Persistent)
+    TimelockConfig,
+    /// Mapping of action_id to QueuedAction, removed on cancellation (Persistent)
+    QueuedAction(u64),
+    /// Platform wallet proposed by the admin, awaiting its own acceptance (Persistent)
+    PendingPlatformWallet,
+    /// Admin nominated by `transfer_admin`, awaiting acceptance (Persistent)
+    PendingAdmin,
+    /// Mapping of event_id to its negotiated platform fee in bps (Persistent)
+    EventFeeOverride(String),
+    /// Mapping of organizer address to its OrganizerProfile (Persistent)
+    OrganizerProfile(Address),
+    /// Mapping of bundle_id to Bundle (Persistent)
+    Bundle(String),
+    /// Mapping of event_id to the milestone plan awaiting admin approval (Persistent)
+    PendingMilestonePlan(String),
+    /// Mapping of hold_id to its InventoryHold (Persistent)
+    InventoryHold(String),
+    /// Mapping of event_id to the ids of its holds, pruned as they expire (Persistent)
+    EventHolds(String),
+    /// Layout version storage was last migrated to, absent before versioning (Persistent)
+    StorageVersion,
+    /// Entries of the event search indices (Persistent)
+    SearchIndex(IndexKey),
+}
+
+/// Keys of the event search indices, nested so `DataKey` stays within the
+/// contracttype variant limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IndexKey {
+    /// Shard of the event_ids currently in a status
+    StatusShard(EventStatus, u32),
+    /// Number of event_ids currently in a status
+    StatusCount(EventStatus),
+    /// Mapping of event_id to its indexed status and position, absent until indexed
+    StatusEntry(String),
+    /// Event_ids created within a `CREATED_BUCKET_SECS` window, in creation order
+    CreatedBucket(u64),
+}
+
+/// Machine-readable description of a deployed contract and how it is wired.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractMetadata {
+    pub name: String,
+    pub version: String,
+    /// Revision of the public interface, bumped on breaking entrypoint changes
+    pub interface_revision: u32,
+    /// Address of the counterpart Agora contract, if configured
+    pub counterpart: Option<Address>,
 }
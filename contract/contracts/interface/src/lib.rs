@@ -0,0 +1,10 @@
+#![no_std]
+
+//! Types and clients shared across the Agora contracts.
+//!
+//! Every type that crosses a contract boundary is defined here once, so the
+//! contract that returns it and the contracts that decode it cannot drift
+//! apart. Each module mirrors the public interface of one contract.
+
+pub mod payment;
+pub mod registry;
@@ -0,0 +1,9 @@
+//! Interface of the TicketPayment contract, as called by the registry.
+
+use soroban_sdk::{contractclient, Address, Env, String};
+
+#[contractclient(name = "TicketPaymentClient")]
+pub trait TicketPaymentInterface {
+    fn on_event_cancelled(env: Env, event_id: String);
+    fn bind_tenant_event(env: Env, registry: Address, event_id: String);
+}
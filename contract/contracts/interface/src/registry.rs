@@ -0,0 +1,368 @@
+//! Interface of the EventRegistry contract: the event records it returns and
+//! the client the TicketPayment contract calls it through.
+
+use agora_money::{decaying_price, is_valid_bps};
+use soroban_sdk::{contractclient, contracttype, Address, Env, Map, String, Vec};
+
+/// Maximum number of steps in a refund schedule.
+pub const MAX_REFUND_STEPS: u32 = 10;
+
+/// Represents a ticket tier with its own pricing and supply
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketTier {
+    /// Name of the tier (e.g., "General", "VIP", "Reserved")
+    pub name: String,
+    /// Price for this tier in stroops
+    pub price: i128,
+    /// Price charged while early-bird sales last, in place of `price`
+    pub early_bird_price: i128,
+    /// Timestamp after which early-bird sales end (0 = no deadline)
+    pub early_bird_deadline: u64,
+    /// Number of tickets sold at the early-bird price (0 = no quantity limit)
+    pub early_bird_quantity: i128,
+    /// Price in USD at 7 decimals, converted via the price oracle (0 = not USD-priced)
+    pub usd_price: i128,
+    /// Maximum tickets available for this tier
+    pub tier_limit: i128,
+    /// Current number of tickets sold for this tier
+    pub current_sold: i128,
+    /// Indicates whether tickets in this tier can be refunded by the buyer
+    pub is_refundable: bool,
+    /// Timestamp from which the tier is listed and purchasable (0 = immediately)
+    pub visible_from: u64,
+    /// Timestamp after which the tier is hidden again (0 = never)
+    pub visible_until: u64,
+    /// Explicit per-token prices (token -> price); tokens not listed use `price`
+    pub token_prices: Map<Address, i128>,
+    /// Refundable damage deposit charged per ticket on top of the price (0 = none)
+    pub deposit_amount: i128,
+    /// How `price` evolves over time; token-specific prices are always fixed
+    pub pricing: TierPricing,
+    /// Group discounts (minimum quantity -> discount bps); a purchase gets the
+    /// discount of the largest threshold its quantity reaches
+    pub bulk_discounts: Map<u32, u32>,
+}
+
+impl TicketTier {
+    /// Returns true if the tier's visibility window contains `timestamp`.
+    pub fn is_visible_at(&self, timestamp: u64) -> bool {
+        (self.visible_from == 0 || timestamp >= self.visible_from)
+            && (self.visible_until == 0 || timestamp < self.visible_until)
+    }
+
+    /// Returns the base price charged at `timestamp` under the tier's pricing mode.
+    pub fn price_at(&self, timestamp: u64) -> i128 {
+        match &self.pricing {
+            TierPricing::Fixed => self.price,
+            TierPricing::DutchAuction(auction) => decaying_price(
+                self.price,
+                auction.floor_price,
+                auction.start,
+                auction.end,
+                auction.step_seconds,
+                timestamp,
+            )
+            .unwrap_or(auction.floor_price),
+        }
+    }
+
+    /// Returns true if the pricing mode is internally consistent with `price`.
+    pub fn has_valid_pricing(&self) -> bool {
+        match &self.pricing {
+            TierPricing::Fixed => true,
+            TierPricing::DutchAuction(auction) => {
+                auction.floor_price > 0
+                    && auction.floor_price < self.price
+                    && auction.end > auction.start
+            }
+        }
+    }
+}
+
+/// How a tier's price evolves over time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TierPricing {
+    /// The tier always sells at its `price`
+    Fixed,
+    /// The price decays from the tier's `price` down to a floor
+    DutchAuction(DutchAuction),
+}
+
+/// Descending-price schedule for a tier: `price` until `start`, falling to
+/// `floor_price` at `end` and holding there.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DutchAuction {
+    /// Lowest price, reached at `end`
+    pub floor_price: i128,
+    /// Timestamp at which the price starts to fall
+    pub start: u64,
+    /// Timestamp at which the floor is reached
+    pub end: u64,
+    /// Seconds between price drops (0 = decays continuously)
+    pub step_seconds: u64,
+}
+
+/// A time-boxed discount on a tier. The discount applies while
+/// `start <= now < end` and lapses automatically afterwards.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FlashSale {
+    /// Discount off the tier price in basis points (10000 = 100%)
+    pub discount_bps: u32,
+    /// Timestamp at which the sale starts
+    pub start: u64,
+    /// Timestamp at which the sale ends and the regular price is restored
+    pub end: u64,
+}
+
+/// A season pass or multi-event bundle: one ticket in `tier_id` of each of
+/// `event_ids`, sold together at `price` in the default token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bundle {
+    pub bundle_id: String,
+    pub organizer: Address,
+    pub event_ids: Vec<String>,
+    /// Tier every included event sells the bundled ticket from
+    pub tier_id: String,
+    pub price: i128,
+    pub max_supply: u32,
+    pub sold: u32,
+}
+
+/// One step of a refund schedule: tickets refunded at or before `until` get
+/// `refund_bps` of what was paid back.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundStep {
+    pub until: u64,
+    pub refund_bps: u32,
+}
+
+/// How buyer-initiated refunds are handled for an event. When set, it takes
+/// the place of the event's `refund_deadline` and `restocking_fee` and of the
+/// tiers' `is_refundable`; cancellations and failed sales goals always refund
+/// in full regardless.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RefundPolicy {
+    /// Full refunds until the timestamp, none after
+    FullUntil(u64),
+    /// Refunds stepping down over time; none after the last step
+    Schedule(Vec<RefundStep>),
+    /// Tickets are never refunded
+    NoRefund,
+}
+
+impl RefundPolicy {
+    /// Whether the policy is well-formed: a schedule needs 1 to
+    /// `MAX_REFUND_STEPS` steps with increasing deadlines and non-increasing,
+    /// valid percentages.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            RefundPolicy::FullUntil(until) => *until > 0,
+            RefundPolicy::NoRefund => true,
+            RefundPolicy::Schedule(steps) => {
+                if steps.is_empty() || steps.len() > MAX_REFUND_STEPS {
+                    return false;
+                }
+                let mut previous: Option<RefundStep> = None;
+                for step in steps.iter() {
+                    if !is_valid_bps(step.refund_bps) {
+                        return false;
+                    }
+                    if let Some(prev) = previous {
+                        if step.until <= prev.until || step.refund_bps > prev.refund_bps {
+                            return false;
+                        }
+                    }
+                    previous = Some(step);
+                }
+                true
+            }
+        }
+    }
+}
+
+/// When an event is scheduled to end and when it was marked completed.
+/// `completed_at` is 0 until `complete_event` succeeds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventSchedule {
+    pub end_time: u64,
+    pub completed_at: u64,
+}
+
+/// Assigned-seating layout for a tier. Every ticket sold in a seated tier claims
+/// exactly one of `seats`, and each seat can be held by at most one ticket.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeatMap {
+    /// Seat identifiers (e.g. "A-12"), one per ticket in the tier
+    pub seats: Vec<String>,
+}
+
+/// Dead-man's switch for an event's organizer escrow: if the organizer shows no
+/// activity for `inactivity_timeout` seconds, `recovery_address` may claim it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryConfig {
+    /// Address allowed to claim the organizer escrow after the timeout
+    pub recovery_address: Address,
+    /// Seconds without organizer activity before recovery can be initiated
+    pub inactivity_timeout: u64,
+}
+
+/// Represents an early revenue release milestone.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Milestone {
+    /// The number of tickets sold to reach this milestone
+    pub sales_threshold: i128,
+    /// Percentage of the available revenue to release (in basis points, 10000 = 100%)
+    pub release_percent: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EventStatus {
+    Active,
+    Inactive,
+    Cancelled,
+    /// The event took place; final payouts may be released
+    Completed,
+}
+
+/// Controls how an event is surfaced to buyers.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EventVisibility {
+    /// Listed in discovery views and open for sales
+    Public,
+    /// Hidden from discovery views but purchasable via a direct link
+    Unlisted,
+    /// Work in progress: hidden from discovery and closed for sales
+    Draft,
+}
+
+/// Represents information about an event in the registry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventInfo {
+    /// Unique identifier for the event
+    pub event_id: String,
+    /// The wallet address of the event organizer
+    pub organizer_address: Address,
+    /// The address where payments for this event should be routed
+    pub payment_address: Address,
+    /// The percentage fee taken by the platform (e.g., 5 for 5%)
+    pub platform_fee_percent: u32,
+    /// Whether the event is currently active and accepting payments
+    pub is_active: bool,
+    /// The current status of the event
+    pub status: EventStatus,
+    /// Timestamp when the event was created
+    pub created_at: u64,
+    /// IPFS Content Identifier storing rich metadata details
+    pub metadata_cid: String,
+    /// Maximum number of tickets available for this event (0 = unlimited)
+    pub max_supply: i128,
+    /// Current number of tickets that have been successfully purchased
+    pub current_supply: i128,
+    /// Optional milestone plan for early revenue release
+    pub milestone_plan: Option<Vec<Milestone>>,
+    /// Map of tier_id to TicketTier for multi-tiered pricing
+    pub tiers: Map<String, TicketTier>,
+    /// Deadline for guests to request a refund (Unix timestamp)
+    pub refund_deadline: u64,
+    /// Fee deducted from refund amount
+    pub restocking_fee: i128,
+    /// Optional resale price cap in basis points above face value.
+    /// None = no cap (free market), Some(0) = no markup, Some(1000) = max 10% above face value.
+    pub resale_cap_bps: Option<u32>,
+    /// Indicates whether the event is currently postponed (date shifted)
+    /// and in a temporary refund grace period window.
+    pub is_postponed: bool,
+    /// Timestamp (Unix) when the temporary refund grace period for a
+    /// postponed event ends. 0 means no grace period active.
+    pub grace_period_end: u64,
+    /// Minimum number of tickets that must be sold for the event to proceed
+    pub min_sales_target: i128,
+    /// Deadline by which the min_sales_target must be met (Unix timestamp)
+    pub target_deadline: u64,
+    /// Whether the minimum sales target has been reached
+    pub goal_met: bool,
+    /// Listing visibility controlled by the organizer
+    pub visibility: EventVisibility,
+    /// Whether the organizer is verified, refreshed from its profile on every read
+    pub verified: bool,
+    /// Most tickets a single buyer may hold for the event (0 = unlimited)
+    pub max_tickets_per_buyer: u32,
+    /// Organizer royalty on every on-chain resale, in basis points
+    pub resale_royalty_bps: u32,
+    /// When the event starts (Unix timestamp), 0 if not set
+    pub event_start_time: u64,
+    /// Seconds before the start during which tickets cannot change hands (0 = never)
+    pub transfer_lock_before_secs: u64,
+}
+
+/// How the platform-wide promo (`set_global_promo`) applies to an event.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PromoMode {
+    /// Discount comes off the total before the fee split (organizer shares the cost)
+    Shared,
+    /// The event never receives the platform promo
+    OptOut,
+    /// Discount is taken from the platform fee only, capped at the fee
+    PlatformAbsorbed,
+}
+
+/// Payment information for an event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentInfo {
+    /// The address where payments for this event should be routed
+    pub payment_address: Address,
+    /// The percentage fee taken by the platform
+    pub platform_fee_percent: u32,
+    /// Map of tier_id to TicketTier for multi-tiered pricing
+    pub tiers: Map<String, TicketTier>,
+    /// Declared policy flags (`POLICY_*` bitfield) frontends should enforce
+    pub policy_flags: u32,
+    /// Organizer royalty on every on-chain resale, in basis points
+    pub resale_royalty_bps: u32,
+}
+
+#[contractclient(name = "Client")]
+pub trait EventRegistryInterface {
+    fn get_event_payment_info(env: Env, event_id: String) -> PaymentInfo;
+    fn get_event(env: Env, event_id: String) -> Option<EventInfo>;
+    fn increment_inventory(env: Env, event_id: String, tier_id: String, quantity: u32);
+    fn decrement_inventory(env: Env, event_id: String, tier_id: String);
+    fn get_global_promo_bps(env: Env) -> u32;
+    fn get_promo_expiry(env: Env) -> u64;
+    fn is_scanner_authorized(env: Env, event_id: String, scanner: Address) -> bool;
+    fn get_flash_sale(env: Env, event_id: String, tier_id: String) -> Option<FlashSale>;
+    fn get_recovery_config(env: Env, event_id: String) -> Option<RecoveryConfig>;
+    fn get_promo_mode(env: Env, event_id: String) -> PromoMode;
+    fn get_seat_map(env: Env, event_id: String, tier_id: String) -> Option<SeatMap>;
+    fn reserve_seat(env: Env, event_id: String, tier_id: String, seat_id: String);
+    fn release_seat(env: Env, event_id: String, tier_id: String, seat_id: String);
+    fn get_refund_policy(env: Env, event_id: String) -> Option<RefundPolicy>;
+    fn get_event_schedule(env: Env, event_id: String) -> Option<EventSchedule>;
+    fn get_bundle(env: Env, bundle_id: String) -> Option<Bundle>;
+    fn record_bundle_sale(env: Env, bundle_id: String);
+    fn hold_inventory(
+        env: Env,
+        hold_id: String,
+        event_id: String,
+        tier_id: String,
+        quantity: u32,
+        expires_at: u64,
+    );
+    fn release_inventory_hold(env: Env, hold_id: String);
+}
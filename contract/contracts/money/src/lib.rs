@@ -0,0 +1,114 @@
+#![no_std]
+
+//! Checked monetary arithmetic shared by the Agora contracts.
+//!
+//! Amounts are `i128` token units and rates are basis points. Every helper
+//! returns `None` instead of overflowing, and rounds toward zero exactly like
+//! `amount * bps / 10_000` would, without ever forming that product.
+
+/// Basis points in a whole (100%).
+pub const MAX_BPS: u32 = 10_000;
+
+const BPS: i128 = MAX_BPS as i128;
+
+/// Whether `bps` is a valid proportion, i.e. at most 100%.
+pub fn is_valid_bps(bps: u32) -> bool {
+    bps <= MAX_BPS
+}
+
+/// `amount * bps / 10_000`, rounded toward zero. Splitting `amount` into whole
+/// multiples of 10_000 and a remainder keeps intermediates in range, so only a
+/// result that itself overflows `i128` yields `None`.
+pub fn mul_div_bps(amount: i128, bps: u32) -> Option<i128> {
+    let bps = bps as i128;
+    let whole = (amount / BPS).checked_mul(bps)?;
+    // |remainder| < 10_000 and bps < 2^32, so this product cannot overflow
+    let part = (amount % BPS) * bps / BPS;
+    whole.checked_add(part)
+}
+
+/// `amount` raised by `bps` on top of itself, e.g. a cap 20% above face value.
+pub fn add_bps(amount: i128, bps: u32) -> Option<i128> {
+    mul_div_bps(amount, MAX_BPS.checked_add(bps)?)
+}
+
+/// `price` less a `bps` discount. Rejects discounts above 100%.
+pub fn apply_discount(price: i128, bps: u32) -> Option<i128> {
+    if !is_valid_bps(bps) {
+        return None;
+    }
+    mul_div_bps(price, MAX_BPS - bps)
+}
+
+/// Splits `amount` into `(fee, net)` at `fee_bps`. Rounding dust stays in
+/// `net`, so `fee + net == amount` always holds.
+pub fn split_fee(amount: i128, fee_bps: u32) -> Option<(i128, i128)> {
+    if !is_valid_bps(fee_bps) {
+        return None;
+    }
+    let fee = mul_div_bps(amount, fee_bps)?;
+    Some((fee, amount.checked_sub(fee)?))
+}
+
+/// Splits `total` into `parts` equal shares plus the remainder the shares leave
+/// over, so `share * parts + remainder == total`.
+pub fn split_evenly(total: i128, parts: u32) -> Option<(i128, i128)> {
+    let parts = parts as i128;
+    Some((total.checked_div(parts)?, total.checked_rem(parts)?))
+}
+
+/// Price of a descending (Dutch) auction at `now`: `start_price` until `start`,
+/// falling linearly to `floor_price` at `end` and holding there. With `step` > 0
+/// the price only drops every `step` seconds after `start`. Rounds toward
+/// `start_price`, so buyers never pay less than the schedule allows.
+pub fn decaying_price(
+    start_price: i128,
+    floor_price: i128,
+    start: u64,
+    end: u64,
+    step: u64,
+    now: u64,
+) -> Option<i128> {
+    if now <= start {
+        return Some(start_price);
+    }
+    if now >= end {
+        return Some(floor_price);
+    }
+    let mut elapsed = now - start;
+    if step > 0 {
+        elapsed -= elapsed % step;
+    }
+    let window = (end - start) as i128;
+    let elapsed = elapsed as i128;
+    let spread = start_price.checked_sub(floor_price)?;
+    // Same split as `mul_div_bps`: spread * elapsed / window without the product
+    let whole = (spread / window).checked_mul(elapsed)?;
+    let part = (spread % window).checked_mul(elapsed)? / window;
+    start_price.checked_sub(whole.checked_add(part)?)
+}
+
+/// `amount` converted at the exchange rate `numerator / denominator`, rounded
+/// toward zero. Like `mul_div_bps`, only a result that overflows yields `None`
+/// (as does a zero or negative `denominator`).
+pub fn convert_at_rate(amount: i128, numerator: i128, denominator: i128) -> Option<i128> {
+    if denominator <= 0 {
+        return None;
+    }
+    let whole = (amount / denominator).checked_mul(numerator)?;
+    let part = (amount % denominator).checked_mul(numerator)? / denominator;
+    whole.checked_add(part)
+}
+
+/// Re-expresses `amount` in a token with `to_decimals` decimals instead of
+/// `from_decimals`. Gaining precision multiplies; losing it rounds toward zero.
+pub fn rescale_decimals(amount: i128, from_decimals: u32, to_decimals: u32) -> Option<i128> {
+    if to_decimals >= from_decimals {
+        amount.checked_mul(10i128.checked_pow(to_decimals - from_decimals)?)
+    } else {
+        amount.checked_div(10i128.checked_pow(from_decimals - to_decimals)?)
+    }
+}
+
+#[cfg(test)]
+mod test;
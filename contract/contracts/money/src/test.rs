@@ -0,0 +1,125 @@
+use super::*;
+
+#[test]
+fn test_mul_div_bps_matches_naive_math() {
+    for amount in [
+        0i128,
+        1,
+        9_999,
+        10_000,
+        10_001,
+        123_456_789,
+        -1,
+        -10_001,
+        -987_654_321,
+    ] {
+        for bps in [0u32, 1, 250, 5_000, 9_999, 10_000, 15_000] {
+            assert_eq!(
+                mul_div_bps(amount, bps),
+                Some(amount * bps as i128 / 10_000),
+                "{amount} @ {bps}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_mul_div_bps_at_i128_extremes() {
+    // The naive product overflows; the split computation does not
+    assert!(i128::MAX.checked_mul(10_000).is_none());
+    assert_eq!(mul_div_bps(i128::MAX, MAX_BPS), Some(i128::MAX));
+    assert_eq!(mul_div_bps(i128::MIN, MAX_BPS), Some(i128::MIN));
+    assert_eq!(mul_div_bps(i128::MAX, 0), Some(0));
+    assert_eq!(mul_div_bps(i128::MAX, 5_000), Some(i128::MAX / 2));
+    assert_eq!(mul_div_bps(i128::MIN, 5_000), Some(i128::MIN / 2));
+    // Results that do not fit are reported rather than wrapped
+    assert_eq!(mul_div_bps(i128::MAX, MAX_BPS + 1), None);
+    assert_eq!(mul_div_bps(i128::MIN, u32::MAX), None);
+    assert_eq!(add_bps(i128::MAX, 1), None);
+    assert_eq!(add_bps(1, u32::MAX), None);
+}
+
+#[test]
+fn test_add_bps_and_apply_discount() {
+    assert_eq!(add_bps(1_000, 2_000), Some(1_200));
+    assert_eq!(add_bps(999, 1), Some(999));
+    assert_eq!(apply_discount(1_000, 0), Some(1_000));
+    assert_eq!(apply_discount(1_000, 2_500), Some(750));
+    assert_eq!(apply_discount(1_000, MAX_BPS), Some(0));
+    assert_eq!(apply_discount(1_000, MAX_BPS + 1), None);
+    assert_eq!(
+        apply_discount(i128::MAX, 1),
+        Some(mul_div_bps(i128::MAX, 9_999).unwrap())
+    );
+    // Discounted prices round down, as buyers are charged
+    assert_eq!(apply_discount(3, 5_000), Some(1));
+}
+
+#[test]
+fn test_split_fee_never_loses_dust() {
+    for amount in [0i128, 1, 3, 7, 10_001, 999_999_999, i128::MAX, i128::MIN] {
+        for bps in [0u32, 1, 333, 500, 10_000] {
+            let (fee, net) = split_fee(amount, bps).unwrap();
+            assert_eq!(fee.checked_add(net), Some(amount), "{amount} @ {bps}");
+        }
+    }
+    assert_eq!(split_fee(199, 500), Some((9, 190)));
+    assert_eq!(split_fee(100, MAX_BPS + 1), None);
+}
+
+#[test]
+fn test_split_evenly_accounts_for_remainder() {
+    assert_eq!(split_evenly(100, 3), Some((33, 1)));
+    assert_eq!(split_evenly(-100, 3), Some((-33, -1)));
+    assert_eq!(
+        split_evenly(i128::MAX, 7),
+        Some((i128::MAX / 7, i128::MAX % 7))
+    );
+    assert_eq!(split_evenly(100, 0), None);
+}
+
+#[test]
+fn test_decaying_price_linear_and_stepwise() {
+    // 1_000 down to 400 over 100..700
+    assert_eq!(decaying_price(1_000, 400, 100, 700, 0, 0), Some(1_000));
+    assert_eq!(decaying_price(1_000, 400, 100, 700, 0, 100), Some(1_000));
+    assert_eq!(decaying_price(1_000, 400, 100, 700, 0, 101), Some(999));
+    assert_eq!(decaying_price(1_000, 400, 100, 700, 0, 400), Some(700));
+    assert_eq!(decaying_price(1_000, 400, 100, 700, 0, 700), Some(400));
+    assert_eq!(decaying_price(1_000, 400, 100, 700, 0, 9_999), Some(400));
+    // Drops of 100 every 100 seconds
+    assert_eq!(decaying_price(1_000, 400, 100, 700, 100, 199), Some(1_000));
+    assert_eq!(decaying_price(1_000, 400, 100, 700, 100, 200), Some(900));
+    assert_eq!(decaying_price(1_000, 400, 100, 700, 100, 699), Some(500));
+    // Odd spreads round toward the start price
+    assert_eq!(decaying_price(10, 0, 0, 3, 0, 1), Some(7));
+    // The naive spread * elapsed product would overflow here
+    assert_eq!(
+        decaying_price(i128::MAX, 0, 0, 1 << 62, 0, 1 << 61),
+        Some(i128::MAX / 2 + 1)
+    );
+}
+
+#[test]
+fn test_convert_at_rate_and_rescale_decimals() {
+    // 100 USDC at 8.3333333 XLM per USDC
+    assert_eq!(
+        convert_at_rate(100_0000000, 8_3333333, 1_0000000),
+        Some(833_3333300)
+    );
+    assert_eq!(convert_at_rate(10, 1, 3), Some(3));
+    assert_eq!(convert_at_rate(-10, 1, 3), Some(-3));
+    assert_eq!(convert_at_rate(i128::MAX, 3, 3), Some(i128::MAX));
+    assert_eq!(convert_at_rate(i128::MAX, 2, 1), None);
+    assert_eq!(convert_at_rate(1, 1, 0), None);
+
+    assert_eq!(rescale_decimals(1_2345678, 7, 7), Some(1_2345678));
+    assert_eq!(
+        rescale_decimals(1_2345678, 7, 18),
+        Some(1_2345678 * 10i128.pow(11))
+    );
+    // Precision lost when moving to fewer decimals rounds toward zero
+    assert_eq!(rescale_decimals(1_2345678, 7, 2), Some(123));
+    assert_eq!(rescale_decimals(1, 0, 39), None);
+    assert_eq!(rescale_decimals(i128::MAX, 0, 1), None);
+}
@@ -0,0 +1,30 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum OracleAdapterError {
+    NotInitialized = 1,
+    AlreadyInitialized = 2,
+    PriceUnavailable = 3,
+    StalePriceData = 4,
+    InvalidMaxAge = 5,
+    ArithmeticError = 6,
+}
+
+impl core::fmt::Display for OracleAdapterError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            OracleAdapterError::NotInitialized => write!(f, "Contract not initialized"),
+            OracleAdapterError::AlreadyInitialized => write!(f, "Contract already initialized"),
+            OracleAdapterError::PriceUnavailable => {
+                write!(f, "Price source has no price for this asset")
+            }
+            OracleAdapterError::StalePriceData => {
+                write!(f, "Latest price is older than the allowed age")
+            }
+            OracleAdapterError::InvalidMaxAge => write!(f, "Maximum price age must be positive"),
+            OracleAdapterError::ArithmeticError => write!(f, "Arithmetic overflow"),
+        }
+    }
+}
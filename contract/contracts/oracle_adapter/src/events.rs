@@ -0,0 +1,22 @@
+use soroban_sdk::{contracttype, Address};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OracleAdapterEvent {
+    SourceUpdated,
+    MaxAgeUpdated,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SourceUpdatedEvent {
+    pub old_source: Address,
+    pub new_source: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaxAgeUpdatedEvent {
+    pub old_max_age: u64,
+    pub new_max_age: u64,
+}
@@ -0,0 +1,158 @@
+#![no_std]
+
+use crate::error::OracleAdapterError;
+use crate::events::{MaxAgeUpdatedEvent, OracleAdapterEvent, SourceUpdatedEvent};
+use crate::types::{Asset, PriceData, RATE_DECIMALS};
+use agora_money::convert_at_rate;
+use soroban_sdk::{contract, contractimpl, contractmeta, Address, Env};
+
+pub mod error;
+pub mod events;
+pub mod storage;
+pub mod types;
+
+contractmeta!(key = "name", val = "agora-oracle-adapter");
+contractmeta!(key = "version", val = "0.1.0");
+
+// Reflector-style price feed interface
+pub mod reflector {
+    use crate::types::{Asset, PriceData};
+    use soroban_sdk::{contractclient, Env};
+
+    #[contractclient(name = "ReflectorClient")]
+    pub trait ReflectorInterface {
+        fn decimals(env: Env) -> u32;
+        fn lastprice(env: Env, asset: Asset) -> Option<PriceData>;
+    }
+}
+
+/// Serves token/USD rates to ticket_payment from a Reflector-style feed. The
+/// adapter is what ticket_payment's oracle address points at: it refuses
+/// observations older than the configured age and turns the feed's USD prices
+/// into the per-dollar rates checkout converts with, so the feed can be
+/// replaced without touching the payment contract.
+#[contract]
+pub struct OracleAdapter;
+
+#[contractimpl]
+#[allow(deprecated)]
+impl OracleAdapter {
+    /// Initializes the adapter. Can only be called once.
+    ///
+    /// # Arguments
+    /// * `admin` - Address allowed to change the source and staleness bound.
+    /// * `source` - Reflector-style price feed to read from.
+    /// * `max_age` - Oldest observation, in seconds, still served.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        source: Address,
+        max_age: u64,
+    ) -> Result<(), OracleAdapterError> {
+        if storage::is_initialized(&env) {
+            return Err(OracleAdapterError::AlreadyInitialized);
+        }
+        if max_age == 0 {
+            return Err(OracleAdapterError::InvalidMaxAge);
+        }
+        storage::set_admin(&env, &admin);
+        storage::set_source(&env, &source);
+        storage::set_max_age(&env, max_age);
+        Ok(())
+    }
+
+    /// Points the adapter at a different price feed. Only callable by the administrator.
+    pub fn set_source(env: Env, source: Address) -> Result<(), OracleAdapterError> {
+        require_admin(&env)?;
+        let old_source = storage::get_source(&env).ok_or(OracleAdapterError::NotInitialized)?;
+        storage::set_source(&env, &source);
+
+        env.events().publish(
+            (OracleAdapterEvent::SourceUpdated,),
+            SourceUpdatedEvent {
+                old_source,
+                new_source: source,
+            },
+        );
+        Ok(())
+    }
+
+    /// Changes the oldest observation, in seconds, still served. Only callable
+    /// by the administrator.
+    pub fn set_max_age(env: Env, max_age: u64) -> Result<(), OracleAdapterError> {
+        require_admin(&env)?;
+        if max_age == 0 {
+            return Err(OracleAdapterError::InvalidMaxAge);
+        }
+        let old_max_age = storage::get_max_age(&env).ok_or(OracleAdapterError::NotInitialized)?;
+        storage::set_max_age(&env, max_age);
+
+        env.events().publish(
+            (OracleAdapterEvent::MaxAgeUpdated,),
+            MaxAgeUpdatedEvent {
+                old_max_age,
+                new_max_age: max_age,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the rate for a Stellar asset: units of the asset per US dollar
+    /// at `RATE_DECIMALS`, with the feed's observation time. Fails with
+    /// `StalePriceData` when the latest observation is older than the maximum age.
+    pub fn lastprice(env: Env, asset: Address) -> Result<PriceData, OracleAdapterError> {
+        let source = storage::get_source(&env).ok_or(OracleAdapterError::NotInitialized)?;
+        let max_age = storage::get_max_age(&env).ok_or(OracleAdapterError::NotInitialized)?;
+        let feed = reflector::ReflectorClient::new(&env, &source);
+
+        let observed = match feed.try_lastprice(&Asset::Stellar(asset)) {
+            Ok(Ok(Some(data))) if data.price > 0 => data,
+            _ => return Err(OracleAdapterError::PriceUnavailable),
+        };
+        // Observations stamped ahead of the ledger are treated as current
+        if env.ledger().timestamp().saturating_sub(observed.timestamp) > max_age {
+            return Err(OracleAdapterError::StalePriceData);
+        }
+
+        let decimals = match feed.try_decimals() {
+            Ok(Ok(decimals)) => decimals,
+            _ => return Err(OracleAdapterError::PriceUnavailable),
+        };
+        // units per dollar = 1 / (dollars per unit), rescaled to RATE_DECIMALS
+        let rate = 10i128
+            .checked_pow(decimals)
+            .zip(10i128.checked_pow(RATE_DECIMALS))
+            .and_then(|(feed_unit, rate_unit)| {
+                convert_at_rate(rate_unit, feed_unit, observed.price)
+            })
+            .ok_or(OracleAdapterError::ArithmeticError)?;
+        // Assets worth more than 10^RATE_DECIMALS dollars have no usable rate
+        if rate == 0 {
+            return Err(OracleAdapterError::PriceUnavailable);
+        }
+
+        Ok(PriceData {
+            price: rate,
+            timestamp: observed.timestamp,
+        })
+    }
+
+    /// Returns the configured price feed.
+    pub fn source(env: Env) -> Result<Address, OracleAdapterError> {
+        storage::get_source(&env).ok_or(OracleAdapterError::NotInitialized)
+    }
+
+    /// Returns the oldest observation, in seconds, still served.
+    pub fn max_age(env: Env) -> Result<u64, OracleAdapterError> {
+        storage::get_max_age(&env).ok_or(OracleAdapterError::NotInitialized)
+    }
+}
+
+fn require_admin(env: &Env) -> Result<(), OracleAdapterError> {
+    let admin = storage::get_admin(env).ok_or(OracleAdapterError::NotInitialized)?;
+    admin.require_auth();
+    Ok(())
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,30 @@
+use crate::types::DataKey;
+use soroban_sdk::{Address, Env};
+
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Admin)
+}
+
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+pub fn set_source(env: &Env, source: &Address) {
+    env.storage().instance().set(&DataKey::Source, source);
+}
+
+pub fn get_source(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Source)
+}
+
+pub fn set_max_age(env: &Env, max_age: u64) {
+    env.storage().instance().set(&DataKey::MaxAge, &max_age);
+}
+
+pub fn get_max_age(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&DataKey::MaxAge)
+}
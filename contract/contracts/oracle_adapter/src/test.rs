@@ -0,0 +1,98 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+/// Reflector-style feed with 14 decimals and prices set per asset.
+#[contract]
+pub struct MockReflector;
+
+#[contractimpl]
+impl MockReflector {
+    pub fn decimals(_env: Env) -> u32 {
+        14
+    }
+
+    pub fn lastprice(env: Env, asset: Asset) -> Option<PriceData> {
+        env.storage().instance().get(&asset)
+    }
+
+    pub fn set_price(env: Env, asset: Asset, price: i128, timestamp: u64) {
+        env.storage()
+            .instance()
+            .set(&asset, &PriceData { price, timestamp });
+    }
+}
+
+fn setup(env: &Env) -> (OracleAdapterClient<'static>, MockReflectorClient<'static>) {
+    let feed_id = env.register(MockReflector, ());
+    let contract_id = env.register(OracleAdapter, ());
+    let client = OracleAdapterClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &feed_id, &300);
+    (client, MockReflectorClient::new(env, &feed_id))
+}
+
+#[test]
+fn test_initialize_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, feed) = setup(&env);
+
+    assert_eq!(client.source(), feed.address);
+    assert_eq!(client.max_age(), 300);
+    assert_eq!(
+        client.try_initialize(&Address::generate(&env), &feed.address, &300),
+        Err(Ok(OracleAdapterError::AlreadyInitialized))
+    );
+    assert_eq!(
+        client.try_set_max_age(&0),
+        Err(Ok(OracleAdapterError::InvalidMaxAge))
+    );
+}
+
+#[test]
+fn test_lastprice_inverts_feed_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (client, feed) = setup(&env);
+    let xlm = Address::generate(&env);
+
+    assert_eq!(
+        client.try_lastprice(&xlm),
+        Err(Ok(OracleAdapterError::PriceUnavailable))
+    );
+
+    // XLM at $0.12 is 8.3333333 XLM per dollar
+    feed.set_price(&Asset::Stellar(xlm.clone()), &12_000_000_000_000, &900);
+    assert_eq!(
+        client.lastprice(&xlm),
+        PriceData {
+            price: 8_3333333,
+            timestamp: 900,
+        }
+    );
+}
+
+#[test]
+fn test_lastprice_rejects_stale_observations() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (client, feed) = setup(&env);
+    let usdc = Address::generate(&env);
+
+    feed.set_price(&Asset::Stellar(usdc.clone()), &100_000_000_000_000, &700);
+    assert_eq!(client.lastprice(&usdc).price, 1_0000000);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_001);
+    assert_eq!(
+        client.try_lastprice(&usdc),
+        Err(Ok(OracleAdapterError::StalePriceData))
+    );
+
+    // Widening the bound serves the same observation again
+    client.set_max_age(&3_600);
+    assert_eq!(client.lastprice(&usdc).timestamp, 700);
+}
@@ -0,0 +1,36 @@
+use soroban_sdk::{contracttype, Address, Symbol};
+
+/// Decimals of the rates this adapter serves, matching ticket_payment.
+pub const RATE_DECIMALS: u32 = 7;
+
+/// An asset as identified by Reflector-style price feeds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Asset {
+    /// A Stellar asset, by its token contract
+    Stellar(Address),
+    /// An off-chain asset, by ticker
+    Other(Symbol),
+}
+
+/// A price observation. The feed reports US dollars per unit of the asset at
+/// the feed's decimals; the adapter serves units of the asset per US dollar at
+/// `RATE_DECIMALS`, the rate ticket_payment converts prices with.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    /// Ledger timestamp of the observation
+    pub timestamp: u64,
+}
+
+/// Storage keys for the Oracle Adapter contract.
+#[contracttype]
+pub enum DataKey {
+    /// Administrator allowed to change the source and staleness bound
+    Admin,
+    /// Reflector-style price feed the adapter reads from
+    Source,
+    /// Oldest observation, in seconds, the adapter still serves
+    MaxAge,
+}
@@ -0,0 +1,102 @@
+#![no_std]
+
+//! Pagination helpers shared by the Agora contracts.
+//!
+//! Every paginated view takes an `offset` and a `limit` and returns at most
+//! [`MAX_PAGE_SIZE`] items, so no single call can outgrow the ledger read
+//! budget however large the underlying collection becomes.
+
+use soroban_sdk::{Env, IntoVal, TryFromVal, Val, Vec};
+
+/// Largest page returned by a paginated view; bigger limits are clamped.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// A bounded window into an ordered collection.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PageCursor {
+    pub offset: u32,
+    pub limit: u32,
+}
+
+impl PageCursor {
+    /// Builds a cursor, clamping `limit` to [`MAX_PAGE_SIZE`].
+    pub fn new(offset: u32, limit: u32) -> Self {
+        Self {
+            offset,
+            limit: limit.min(MAX_PAGE_SIZE),
+        }
+    }
+
+    /// Exclusive end of this page within a collection of `total` items.
+    pub fn end(&self, total: u32) -> u32 {
+        self.offset.saturating_add(self.limit).min(total)
+    }
+
+    /// The cursor of the following page, or `None` once `total` is reached.
+    pub fn next(&self, total: u32) -> Option<Self> {
+        let end = self.end(total);
+        if end >= total || self.limit == 0 {
+            None
+        } else {
+            Some(Self::new(end, self.limit))
+        }
+    }
+}
+
+/// Copies the cursor's window out of an in-memory collection.
+pub fn page<T>(items: &Vec<T>, cursor: PageCursor) -> Vec<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let end = cursor.end(items.len());
+    items.slice(cursor.offset.min(end)..end)
+}
+
+/// Collects the cursor's window from `items`, stopping as soon as the page is
+/// full. Use for collections whose storage layout cannot be indexed directly.
+pub fn page_from_iter<T>(env: &Env, cursor: PageCursor, items: impl Iterator<Item = T>) -> Vec<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let mut page = Vec::new(env);
+    for item in items
+        .skip(cursor.offset as usize)
+        .take(cursor.limit as usize)
+    {
+        page.push_back(item);
+    }
+    page
+}
+
+/// Reads the cursor's window from a collection of `total` items stored in
+/// consecutive shards of `shard_size`, loading only the shards it touches.
+pub fn read_sharded<T>(
+    env: &Env,
+    total: u32,
+    shard_size: u32,
+    cursor: PageCursor,
+    load_shard: impl Fn(u32) -> Option<Vec<T>>,
+) -> Vec<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let mut page = Vec::new(env);
+    let end = cursor.end(total);
+    let mut position = cursor.offset;
+    while position < end {
+        let shard_id = position / shard_size;
+        let shard_end = end.min((shard_id + 1) * shard_size);
+        if let Some(shard) = load_shard(shard_id) {
+            for index in (position % shard_size)..(shard_end - shard_id * shard_size) {
+                if let Some(item) = shard.get(index) {
+                    page.push_back(item);
+                }
+            }
+        }
+        position = shard_end;
+    }
+    page
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,46 @@
+use super::*;
+use soroban_sdk::{vec, Env};
+
+#[test]
+fn test_cursor_clamps_and_advances() {
+    let cursor = PageCursor::new(0, 500);
+    assert_eq!(cursor.limit, MAX_PAGE_SIZE);
+    assert_eq!(cursor.end(250), 100);
+    assert_eq!(cursor.next(250), Some(PageCursor::new(100, 100)));
+    assert_eq!(PageCursor::new(200, 100).next(250), None);
+    assert_eq!(PageCursor::new(0, 0).next(250), None);
+}
+
+#[test]
+fn test_page_and_iter_windows_match() {
+    let env = Env::default();
+    let items = vec![&env, 1u32, 2, 3, 4, 5];
+
+    assert_eq!(page(&items, PageCursor::new(1, 2)), vec![&env, 2u32, 3]);
+    assert_eq!(page(&items, PageCursor::new(4, 10)), vec![&env, 5u32]);
+    assert_eq!(page(&items, PageCursor::new(9, 10)).len(), 0);
+    assert_eq!(
+        page_from_iter(&env, PageCursor::new(1, 2), items.iter()),
+        vec![&env, 2u32, 3]
+    );
+}
+
+#[test]
+fn test_read_sharded_spans_shards() {
+    let env = Env::default();
+    let shards = vec![
+        &env,
+        vec![&env, 0u32, 1, 2],
+        vec![&env, 3u32, 4, 5],
+        vec![&env, 6u32],
+    ];
+
+    let read = |offset, limit| {
+        read_sharded(&env, 7, 3, PageCursor::new(offset, limit), |id| {
+            shards.get(id)
+        })
+    };
+    assert_eq!(read(2, 3), vec![&env, 2u32, 3, 4]);
+    assert_eq!(read(5, 10), vec![&env, 5u32, 6]);
+    assert_eq!(read(7, 10).len(), 0);
+}
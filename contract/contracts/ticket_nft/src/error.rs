@@ -0,0 +1,26 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TicketNftError {
+    NotInitialized = 1,
+    AlreadyInitialized = 2,
+    TokenNotFound = 3,
+    NotTokenOwner = 4,
+    InvalidAddress = 5,
+}
+
+impl core::fmt::Display for TicketNftError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TicketNftError::NotInitialized => write!(f, "Contract not initialized"),
+            TicketNftError::AlreadyInitialized => write!(f, "Contract already initialized"),
+            TicketNftError::TokenNotFound => write!(f, "Ticket token not found"),
+            TicketNftError::NotTokenOwner => {
+                write!(f, "Address does not own this ticket token")
+            }
+            TicketNftError::InvalidAddress => write!(f, "Invalid Stellar address"),
+        }
+    }
+}
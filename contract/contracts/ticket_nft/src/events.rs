@@ -0,0 +1,35 @@
+use soroban_sdk::{contracttype, Address, String};
+
+/// First topic of every ticket token event, followed by the event_id and
+/// payment_id the token was minted for.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TicketNftEvent {
+    Minted,
+    Burned,
+    Transferred,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketMintedEvent {
+    pub token_id: u64,
+    pub to: Address,
+    pub event_id: String,
+    pub payment_id: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketBurnedEvent {
+    pub token_id: u64,
+    pub from: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketTransferredEvent {
+    pub token_id: u64,
+    pub from: Address,
+    pub to: Address,
+}
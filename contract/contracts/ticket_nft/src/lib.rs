@@ -0,0 +1,193 @@
+#![no_std]
+
+use crate::error::TicketNftError;
+use crate::events::{TicketBurnedEvent, TicketMintedEvent, TicketNftEvent, TicketTransferredEvent};
+use crate::types::TicketToken;
+use soroban_sdk::{contract, contractimpl, contractmeta, Address, Env, String};
+
+pub mod error;
+pub mod events;
+pub mod storage;
+pub mod types;
+
+contractmeta!(key = "name", val = "agora-ticket-nft");
+contractmeta!(key = "version", val = "0.1.0");
+
+/// Non-fungible ticket collection. Each token mirrors one ticket_payment
+/// sub-payment: the payment contract is the sole minter and moves tokens in
+/// lockstep with purchases, transfers and refunds, so resale caps and transfer
+/// fees are always enforced by ticket_payment. Holders and marketplaces read
+/// ownership here.
+#[contract]
+pub struct TicketNft;
+
+#[contractimpl]
+#[allow(deprecated)]
+impl TicketNft {
+    /// Initializes the collection. Can only be called once.
+    ///
+    /// # Arguments
+    /// * `admin` - Address allowed to rotate the minter.
+    /// * `minter` - The ticket_payment contract that mints, burns and transfers.
+    /// * `name` - Collection name shown by wallets.
+    /// * `symbol` - Collection symbol shown by wallets.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        minter: Address,
+        name: String,
+        symbol: String,
+    ) -> Result<(), TicketNftError> {
+        if storage::is_initialized(&env) {
+            return Err(TicketNftError::AlreadyInitialized);
+        }
+        if admin == env.current_contract_address() || minter == env.current_contract_address() {
+            return Err(TicketNftError::InvalidAddress);
+        }
+        storage::set_admin(&env, &admin);
+        storage::set_minter(&env, &minter);
+        storage::set_metadata(&env, &name, &symbol);
+        Ok(())
+    }
+
+    /// Replaces the minter. Only callable by the administrator.
+    pub fn set_minter(env: Env, minter: Address) -> Result<(), TicketNftError> {
+        let admin = storage::get_admin(&env).ok_or(TicketNftError::NotInitialized)?;
+        admin.require_auth();
+        storage::set_minter(&env, &minter);
+        Ok(())
+    }
+
+    /// Mints a ticket token to `to` and returns its token_id. Only callable by the minter.
+    pub fn mint(
+        env: Env,
+        to: Address,
+        event_id: String,
+        payment_id: String,
+    ) -> Result<u64, TicketNftError> {
+        require_minter(&env)?;
+
+        let token_id = storage::next_token_id(&env);
+        storage::set_token(
+            &env,
+            &TicketToken {
+                token_id,
+                owner: to.clone(),
+                event_id: event_id.clone(),
+                payment_id: payment_id.clone(),
+                minted_at: env.ledger().timestamp(),
+            },
+        );
+        storage::adjust_balance(&env, &to, true);
+        storage::set_total_supply(&env, storage::get_total_supply(&env) + 1);
+
+        env.events().publish(
+            (TicketNftEvent::Minted, event_id.clone(), payment_id.clone()),
+            TicketMintedEvent {
+                token_id,
+                to,
+                event_id,
+                payment_id,
+            },
+        );
+
+        Ok(token_id)
+    }
+
+    /// Burns a ticket token. Only callable by the minter.
+    pub fn burn(env: Env, token_id: u64) -> Result<(), TicketNftError> {
+        require_minter(&env)?;
+
+        let token = storage::get_token(&env, token_id).ok_or(TicketNftError::TokenNotFound)?;
+        storage::remove_token(&env, token_id);
+        storage::adjust_balance(&env, &token.owner, false);
+        storage::set_total_supply(&env, storage::get_total_supply(&env).saturating_sub(1));
+
+        env.events().publish(
+            (TicketNftEvent::Burned, token.event_id, token.payment_id),
+            TicketBurnedEvent {
+                token_id,
+                from: token.owner,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Moves a ticket token from `from` to `to`. Only callable by the minter,
+    /// which has already authorized the holder and applied its transfer rules.
+    pub fn transfer(
+        env: Env,
+        from: Address,
+        to: Address,
+        token_id: u64,
+    ) -> Result<(), TicketNftError> {
+        require_minter(&env)?;
+
+        let mut token = storage::get_token(&env, token_id).ok_or(TicketNftError::TokenNotFound)?;
+        if token.owner != from {
+            return Err(TicketNftError::NotTokenOwner);
+        }
+        token.owner = to.clone();
+        storage::set_token(&env, &token);
+        storage::adjust_balance(&env, &from, false);
+        storage::adjust_balance(&env, &to, true);
+
+        env.events().publish(
+            (
+                TicketNftEvent::Transferred,
+                token.event_id,
+                token.payment_id,
+            ),
+            TicketTransferredEvent { token_id, from, to },
+        );
+
+        Ok(())
+    }
+
+    /// Returns the holder of a ticket token.
+    pub fn owner_of(env: Env, token_id: u64) -> Result<Address, TicketNftError> {
+        storage::get_token(&env, token_id)
+            .map(|token| token.owner)
+            .ok_or(TicketNftError::TokenNotFound)
+    }
+
+    /// Returns the full record for a ticket token, if it exists.
+    pub fn token_info(env: Env, token_id: u64) -> Option<TicketToken> {
+        storage::get_token(&env, token_id)
+    }
+
+    /// Returns how many ticket tokens an address holds.
+    pub fn balance(env: Env, owner: Address) -> u32 {
+        storage::get_balance(&env, &owner)
+    }
+
+    /// Returns the number of ticket tokens currently in existence.
+    pub fn total_supply(env: Env) -> u64 {
+        storage::get_total_supply(&env)
+    }
+
+    /// Returns the configured minter.
+    pub fn minter(env: Env) -> Result<Address, TicketNftError> {
+        storage::get_minter(&env).ok_or(TicketNftError::NotInitialized)
+    }
+
+    /// Returns the collection name.
+    pub fn name(env: Env) -> Result<String, TicketNftError> {
+        storage::get_name(&env).ok_or(TicketNftError::NotInitialized)
+    }
+
+    /// Returns the collection symbol.
+    pub fn symbol(env: Env) -> Result<String, TicketNftError> {
+        storage::get_symbol(&env).ok_or(TicketNftError::NotInitialized)
+    }
+}
+
+fn require_minter(env: &Env) -> Result<(), TicketNftError> {
+    let minter = storage::get_minter(env).ok_or(TicketNftError::NotInitialized)?;
+    minter.require_auth();
+    Ok(())
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,96 @@
+use crate::types::{DataKey, TicketToken};
+use soroban_sdk::{Address, Env, String};
+
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Admin)
+}
+
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+pub fn set_minter(env: &Env, minter: &Address) {
+    env.storage().instance().set(&DataKey::Minter, minter);
+}
+
+pub fn get_minter(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Minter)
+}
+
+pub fn set_metadata(env: &Env, name: &String, symbol: &String) {
+    env.storage().instance().set(&DataKey::Name, name);
+    env.storage().instance().set(&DataKey::Symbol, symbol);
+}
+
+pub fn get_name(env: &Env) -> Option<String> {
+    env.storage().instance().get(&DataKey::Name)
+}
+
+pub fn get_symbol(env: &Env) -> Option<String> {
+    env.storage().instance().get(&DataKey::Symbol)
+}
+
+/// Returns the next token_id and advances the counter.
+pub fn next_token_id(env: &Env) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextTokenId)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextTokenId, &(id + 1));
+    id
+}
+
+pub fn get_total_supply(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalSupply)
+        .unwrap_or(0)
+}
+
+pub fn set_total_supply(env: &Env, supply: u64) {
+    env.storage().instance().set(&DataKey::TotalSupply, &supply);
+}
+
+pub fn set_token(env: &Env, token: &TicketToken) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Token(token.token_id), token);
+}
+
+pub fn get_token(env: &Env, token_id: u64) -> Option<TicketToken> {
+    env.storage().persistent().get(&DataKey::Token(token_id))
+}
+
+pub fn remove_token(env: &Env, token_id: u64) {
+    env.storage().persistent().remove(&DataKey::Token(token_id));
+}
+
+pub fn get_balance(env: &Env, owner: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Balance(owner.clone()))
+        .unwrap_or(0)
+}
+
+/// Adjusts an owner's balance, dropping the entry when it reaches zero.
+pub fn adjust_balance(env: &Env, owner: &Address, increase: bool) {
+    let balance = get_balance(env, owner);
+    let updated = if increase {
+        balance + 1
+    } else {
+        balance.saturating_sub(1)
+    };
+    let key = DataKey::Balance(owner.clone());
+    if updated == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &updated);
+    }
+}
@@ -0,0 +1,110 @@
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+fn setup(env: &Env) -> (TicketNftClient<'static>, Address) {
+    let contract_id = env.register(TicketNft, ());
+    let client = TicketNftClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let minter = Address::generate(env);
+    client.initialize(
+        &admin,
+        &minter,
+        &String::from_str(env, "Agora Tickets"),
+        &String::from_str(env, "AGT"),
+    );
+    (client, minter)
+}
+
+#[test]
+fn test_initialize_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, minter) = setup(&env);
+
+    assert_eq!(client.minter(), minter);
+    assert_eq!(client.name(), String::from_str(&env, "Agora Tickets"));
+    assert_eq!(client.symbol(), String::from_str(&env, "AGT"));
+    assert_eq!(client.total_supply(), 0);
+
+    let other = Address::generate(&env);
+    assert_eq!(
+        client.try_initialize(
+            &other,
+            &other,
+            &String::from_str(&env, "x"),
+            &String::from_str(&env, "x")
+        ),
+        Err(Ok(TicketNftError::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_mint_transfer_burn_lifecycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _minter) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let event_id = String::from_str(&env, "event_1");
+
+    let first = client.mint(&alice, &event_id, &String::from_str(&env, "pay_1"));
+    let second = client.mint(&alice, &event_id, &String::from_str(&env, "pay_2"));
+    assert_eq!((first, second), (0, 1));
+    assert_eq!(client.balance(&alice), 2);
+    assert_eq!(client.total_supply(), 2);
+
+    let info = client.token_info(&first).unwrap();
+    assert_eq!(info.owner, alice);
+    assert_eq!(info.payment_id, String::from_str(&env, "pay_1"));
+
+    client.transfer(&alice, &bob, &first);
+    assert_eq!(client.owner_of(&first), bob);
+    assert_eq!(client.balance(&alice), 1);
+    assert_eq!(client.balance(&bob), 1);
+    assert_eq!(
+        client.try_transfer(&alice, &bob, &first),
+        Err(Ok(TicketNftError::NotTokenOwner))
+    );
+
+    client.burn(&first);
+    assert_eq!(client.balance(&bob), 0);
+    assert_eq!(client.total_supply(), 1);
+    assert!(client.token_info(&first).is_none());
+    assert_eq!(
+        client.try_owner_of(&first),
+        Err(Ok(TicketNftError::TokenNotFound))
+    );
+    assert_eq!(
+        client.try_burn(&first),
+        Err(Ok(TicketNftError::TokenNotFound))
+    );
+
+    // Token ids are never reused after a burn
+    assert_eq!(
+        client.mint(&bob, &event_id, &String::from_str(&env, "pay_3")),
+        2
+    );
+}
+
+#[test]
+#[should_panic] // Authentication failure
+fn test_only_minter_can_mint() {
+    let env = Env::default();
+    let contract_id = env.register(TicketNft, ());
+    let client = TicketNftClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let minter = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &minter,
+        &String::from_str(&env, "Agora Tickets"),
+        &String::from_str(&env, "AGT"),
+    );
+
+    let alice = Address::generate(&env);
+    client.mint(
+        &alice,
+        &String::from_str(&env, "event_1"),
+        &String::from_str(&env, "pay_1"),
+    );
+}
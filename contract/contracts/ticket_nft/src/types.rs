@@ -0,0 +1,38 @@
+use soroban_sdk::{contracttype, Address, String};
+
+/// A minted ticket. The token mirrors one ticket_payment sub-payment.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketToken {
+    /// Sequential identifier assigned at mint
+    pub token_id: u64,
+    /// Current holder of the ticket
+    pub owner: Address,
+    /// The event the ticket admits to
+    pub event_id: String,
+    /// The ticket_payment payment record backing this token
+    pub payment_id: String,
+    /// Timestamp when the token was minted
+    pub minted_at: u64,
+}
+
+/// Storage keys for the Ticket NFT contract.
+#[contracttype]
+pub enum DataKey {
+    /// Administrator allowed to rotate the minter
+    Admin,
+    /// Contract (normally ticket_payment) allowed to mint, burn and transfer
+    Minter,
+    /// Collection name
+    Name,
+    /// Collection symbol
+    Symbol,
+    /// Next token_id to assign (also the number ever minted)
+    NextTokenId,
+    /// Number of tokens currently in existence
+    TotalSupply,
+    /// Mapping of token_id to TicketToken (Persistent)
+    Token(u64),
+    /// Number of tokens held by an address (Persistent)
+    Balance(Address),
+}
@@ -1,32 +1,117 @@
 use crate::storage::{
-    add_discount_hash, add_payment_to_buyer_index, add_to_active_escrow_by_token,
-    add_to_active_escrow_total, add_to_daily_withdrawn_amount,
+    add_bulk_refunded, add_frozen_dispute_amount, add_held_funds, add_payment_to_buyer_index,
+    add_sponsorship, add_to_active_escrow_by_token, add_to_active_escrow_total,
+    add_to_daily_withdrawn_amount, add_to_refund_fees_collected, add_to_tenant_fees_collected,
     add_to_total_fees_collected_by_token, add_to_total_volume_processed, add_token_to_whitelist,
-    get_admin, get_bulk_refund_index, get_daily_withdrawn_amount, get_event_balance,
-    get_event_payments, get_event_registry, get_oracle_address, get_partial_refund_index,
-    get_partial_refund_percentage, get_payment, get_platform_wallet, get_slippage_bps,
-    get_total_fees_collected_by_token, get_transfer_fee, get_withdrawal_cap, has_price_switched,
-    is_discount_hash_used, is_discount_hash_valid, is_event_disputed, is_initialized, is_paused,
-    is_token_whitelisted, mark_discount_hash_used, remove_payment_from_buyer_index,
-    remove_token_from_whitelist, set_admin, set_bulk_refund_index, set_event_dispute_status,
-    set_event_registry, set_initialized, set_is_paused, set_oracle_address,
-    set_partial_refund_index, set_partial_refund_percentage, set_platform_wallet,
-    set_price_switched, set_slippage_bps, set_transfer_fee, set_usdc_token, set_withdrawal_cap,
+    append_ledger_entry, append_resale_record, append_scan_record, append_settlement_record,
+    bind_event_registry, extend_event_ttl, extend_payment_ttl, get_admin, get_attendance,
+    get_attendance_count, get_attendance_minter, get_attended_events, get_bound_event_registry,
+    get_box_office_allotment, get_box_office_reserved, get_bulk_refund_deferred,
+    get_bulk_refund_index, get_bulk_refunded, get_bundle_purchase, get_buyer_payment_count,
+    get_buyer_payments_page, get_checkin_commitment, get_checkin_stats, get_coupon,
+    get_coupon_cursor, get_daily_withdrawn_amount, get_deposit_window_start,
+    get_discount_buyer_uses, get_discount_code, get_dispute_bond, get_event_balance,
+    get_event_balance_tokens, get_event_ledger, get_event_ledger_count, get_event_listings,
+    get_event_paused_by, get_event_payment_count, get_event_payments, get_event_registry,
+    get_event_registry_for, get_event_settlement_periods, get_event_token_balance,
+    get_failed_purchases, get_fee_event_at, get_fee_event_count, get_flash_sale_state,
+    get_frozen_dispute_amount, get_installment_plan, get_lifetime_fees_collected, get_listing,
+    get_loyalty_earn_bps, get_loyalty_point_value, get_loyalty_points, get_namespace_registry,
+    get_operator_permissions, get_oracle_address, get_organizer_activity, get_partial_refund_index,
+    get_partial_refund_percentage, get_partial_refund_void_threshold, get_payment,
+    get_payment_dispute, get_payout_config, get_pending_admin, get_pending_platform_wallet,
+    get_platform_wallet, get_poll, get_poll_count, get_promo_stats, get_promoter_earnings,
+    get_promoter_share, get_purchase_terms, get_receipt, get_recovery_claim, get_referral_bps,
+    get_referral_rewards, get_refund_fees_collected, get_refund_pool, get_refund_processing_fee,
+    get_refund_spike_config, get_refund_window, get_resale_fee_bps, get_resale_history,
+    get_resale_royalty_bps, get_reservation, get_revenue_challenge_window, get_rewards_contract,
+    get_scan_history, get_settlement_count, get_settlement_history, get_slippage_bps,
+    get_sponsor_slot, get_sponsor_slot_ids, get_sponsorships, get_storage_version,
+    get_tenant_fees_collected, get_tenant_namespace, get_ticket_delegate, get_ticket_deposit,
+    get_ticket_nft_contract, get_ticket_token_id, get_tier_change_fee, get_token_rescue,
+    get_total_fees_collected_by_token, get_transfer_fee, get_transfer_offer, get_treasury_tokens,
+    get_treasury_wallet, get_voucher, get_whitelisted_tokens, get_withdrawal_cap,
+    get_yield_adapter, get_yield_deployed, get_yield_deployed_token_count, get_yield_reserve_bps,
+    has_price_switched, increment_discount_buyer_uses, is_buyer_limit_exempt, is_checked_out,
+    is_dispute_arbiter, is_event_disputed, is_initialized, is_locked_transfer_approved, is_paused,
+    is_payment_disputed, is_poll_ticket_counted, is_reentry_allowed, is_referral_opted_out,
+    is_registry_allowed, is_threshold_notified, is_ticket_listed, is_token_whitelisted,
+    mark_poll_ticket_counted, record_attendance, record_checkin_stats, record_event_withdrawal,
+    record_failed_purchase, record_organizer_activity, record_promo_usage, remove_coupon,
+    remove_listing, remove_payment_from_buyer_index, remove_recovery_claim, remove_reservation,
+    remove_token_from_whitelist, remove_token_rescue, remove_transfer_offer,
+    reserve_tenant_namespace, set_admin, set_attendance_minter, set_box_office_allotment,
+    set_bulk_refund_deferred, set_bulk_refund_index, set_bundle_purchase, set_buyer_limit_exempt,
+    set_checked_out, set_checkin_commitment, set_coupon, set_coupon_cursor,
+    set_deposit_window_start, set_discount_code, set_dispute_arbiter, set_dispute_bond,
+    set_event_dispute_status, set_event_paused_by, set_event_registry, set_flash_sale_state,
+    set_initialized, set_installment_plan, set_is_paused, set_listing,
+    set_locked_transfer_approved, set_loyalty_earn_bps, set_loyalty_point_value,
+    set_loyalty_points, set_operator_permissions, set_oracle_address, set_partial_refund_index,
+    set_partial_refund_percentage, set_partial_refund_void_threshold, set_payment_dispute,
+    set_payout_config, set_pending_admin, set_pending_platform_wallet, set_platform_wallet,
+    set_poll, set_poll_count, set_price_switched, set_promoter_earnings, set_promoter_share,
+    set_purchase_terms, set_receipt, set_recovery_claim, set_reentry_allowed, set_referral_bps,
+    set_referral_opted_out, set_referral_rewards, set_refund_pool, set_refund_processing_fee,
+    set_refund_spike_config, set_refund_window, set_registry_allowed, set_resale_fee_bps,
+    set_resale_royalty_bps, set_reservation, set_revenue_challenge_window, set_rewards_contract,
+    set_slippage_bps, set_sponsor_slot, set_sponsor_slot_ids, set_storage_version,
+    set_threshold_notified, set_ticket_commissions, set_ticket_delegate, set_ticket_deposit,
+    set_ticket_nft_contract, set_ticket_token_id, set_tier_change_fee, set_token_rescue,
+    set_transfer_fee, set_transfer_offer, set_treasury_wallet, set_usdc_token, set_voucher,
+    set_withdrawal_cap, set_yield_adapter, set_yield_deployed, set_yield_reserve_bps,
     store_payment, subtract_from_active_escrow_by_token, subtract_from_active_escrow_total,
-    subtract_from_total_fees_collected_by_token, update_event_balance,
+    subtract_from_total_fees_collected_by_token, take_event_platform_fee, take_ticket_commissions,
+    update_event_balance,
+};
+use crate::types::{
+    AttendanceRecord, BulkRefundProgress, BundlePurchase, CancellationPreview, CheckInStats,
+    ContractMetadata, DiscountCode, DiscountSpec, EventAvailability, EventSalesSummary, EventStats,
+    FailedPurchase, FlashSaleState, InstallmentPlan, LedgerEntry, LedgerEntryType, Listing,
+    Payment, PaymentDispute, PaymentStatus, PayoutConfig, Poll, PricingBreakdown, PromoScope,
+    PromoStats, PurchaseOptions, PurchaseTerms, Receipt, RefundPool, RefundProcessingFee,
+    RefundSpikeConfig, RefundWindow, ResaleRecord, Reservation, ScanRecord, SettlementRecord,
+    SettlementReportLine, SponsorSlot, Sponsorship, TicketCommissions, TicketDeposit,
+    TierAvailability, TierSalesSummary, TokenRescue, TransferOffer, TreasuryBalance,
+    TreasuryReportLine, Voucher, INSTALLMENT_INTERVAL, MAX_EVENT_SPONSORS, MAX_INSTALLMENTS,
+    MAX_MEMO_LEN, MAX_PAYMENT_ID_LEN, MAX_POLL_OPTIONS, MAX_RESERVATION_TTL, MAX_SPONSOR_SLOTS,
+    MAX_TENANT_EVENT_ID_LEN, MAX_TENANT_NAMESPACE_LEN, OPERATOR_BULK_REFUND,
+    OPERATOR_PARTIAL_REFUND, ORACLE_DECIMALS, ORACLE_PRICE_SCALE, REFUND_CLAIM_WINDOW,
+    STORAGE_VERSION, TRANSFER_OFFER_TTL,
 };
-use crate::types::{Payment, PaymentStatus};
 use crate::{
     error::TicketPaymentError,
     events::{
-        AgoraEvent, BulkRefundProcessedEvent, ContractPausedEvent, ContractUpgraded,
-        DiscountCodeAppliedEvent, DisputeStatusChangedEvent, FeeSettledEvent,
-        GlobalPromoAppliedEvent, InitializationEvent, PartialRefundProcessedEvent,
-        PaymentProcessedEvent, PaymentStatusChangedEvent, PriceSwitchedEvent, RevenueClaimedEvent,
-        TicketTransferredEvent,
+        AdminTransferStartedEvent, AdminTransferredEvent, AgoraEvent,
+        AttendanceCouponsAirdroppedEvent, BoxOfficeAllocatedEvent, BoxOfficeTicketAssignedEvent,
+        BulkRefundProcessedEvent, ContractPausedEvent, ContractUpgraded,
+        DepositDeductionFlaggedEvent, DepositReleasedEvent, DiscountCodeAppliedEvent,
+        DisputeOpenedEvent, DisputeResolvedEvent, DisputeStatusChangedEvent, EscrowRecoveredEvent,
+        EscrowRecoveryInitiatedEvent, EscrowRecoveryVetoedEvent, EventOperatorGrantedEvent,
+        EventPausedEvent, FeeSettledEvent, FeesSettledBatchEvent, FlashSaleEndedEvent,
+        FlashSaleStartedEvent, GlobalPromoAppliedEvent, GroupDiscountAppliedEvent,
+        InitializationEvent, InstallmentPaidEvent, InstallmentPlanForfeitedEvent,
+        InstallmentPlanStartedEvent, PartialRefundProcessedEvent, PaymentPartiallyRefundedEvent,
+        PaymentProcessedEvent, PaymentStatusChangedEvent, PlatformWalletProposedEvent,
+        PlatformWalletUpdatedEvent, PollOpenedEvent, PollVoteCastEvent, PriceSwitchedEvent,
+        RefundBatchCompletedEvent, RefundFeeCollectedEvent, RefundPoolClaimedEvent,
+        RefundPoolCreatedEvent, RefundPoolSweptEvent, RefundSpikeDetectedEvent,
+        RevenueClaimedEvent, SponsorSlotDefinedEvent, SponsorSlotPurchasedEvent,
+        StorageMigratedEvent, TicketDelegatedEvent, TicketListedEvent, TicketListingCancelledEvent,
+        TicketResoldEvent, TicketTransferredEvent, TokenRescueCancelledEvent,
+        TokenRescueProposedEvent, TokensRescuedEvent, TransferOfferCancelledEvent,
+        TransferOfferedEvent, WithdrawableThresholdReachedEvent, YieldCreditedEvent,
     },
 };
-use soroban_sdk::{contract, contractimpl, token, Address, Bytes, BytesN, Env, String, Vec};
+use agora_money::{
+    add_bps, convert_at_rate, decaying_price, is_valid_bps, mul_div_bps, rescale_decimals,
+    split_evenly, split_fee, MAX_BPS,
+};
+use agora_pagination::PageCursor;
+use soroban_sdk::{
+    contract, contractimpl, contractmeta, token, xdr::ToXdr, Address, Bytes, BytesN, Env, Map,
+    String, Vec,
+};
 
 // Price Oracle interface
 pub mod price_oracle {
@@ -45,87 +130,85 @@ pub mod price_oracle {
     }
 }
 
-// Event Registry interface
-pub mod event_registry {
+// Loyalty rewards hook interface
+pub mod rewards {
     use soroban_sdk::{contractclient, Address, Env, String};
 
-    #[soroban_sdk::contracttype]
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub enum EventStatus {
-        Active,
-        Inactive,
-        Cancelled,
+    #[contractclient(name = "RewardsClient")]
+    pub trait RewardsInterface {
+        fn on_purchase_confirmed(
+            env: Env,
+            buyer: Address,
+            event_id: String,
+            payment_id: String,
+            amount: i128,
+        );
+        fn on_check_in(env: Env, holder: Address, event_id: String, payment_id: String);
     }
+}
 
-    #[soroban_sdk::contracttype]
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct PaymentInfo {
-        pub payment_address: Address,
-        pub platform_fee_percent: u32,
-    }
+// Proof-of-attendance minter interface
+pub mod attendance {
+    use soroban_sdk::{contractclient, Address, Env, String};
 
-    #[soroban_sdk::contracttype]
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct EventInventory {
-        pub current_supply: i128,
-        pub max_supply: i128,
+    #[contractclient(name = "AttendanceMinterClient")]
+    pub trait AttendanceMinterInterface {
+        fn mint_attendance(env: Env, attendee: Address, event_id: String, payment_id: String);
     }
+}
 
-    #[contractclient(name = "Client")]
-    pub trait EventRegistryInterface {
-        fn get_event_payment_info(env: Env, event_id: String) -> PaymentInfo;
-        fn get_event(env: Env, event_id: String) -> Option<EventInfo>;
-        fn increment_inventory(env: Env, event_id: String, tier_id: String, quantity: u32);
-        fn decrement_inventory(env: Env, event_id: String, tier_id: String);
-        fn get_global_promo_bps(env: Env) -> u32;
-        fn get_promo_expiry(env: Env) -> u64;
-        fn is_scanner_authorized(env: Env, event_id: String, scanner: Address) -> bool;
-    }
+// Yield strategy interface. Deposits are transferred to the strategy before
+// `deposit` is called; `withdraw` must transfer `amount` back to `to`, and
+// `balance` reports what the contract could withdraw, earnings included.
+pub mod yield_adapter {
+    use soroban_sdk::{contractclient, Address, Env};
 
-    #[soroban_sdk::contracttype]
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct TicketTier {
-        pub name: String,
-        pub price: i128,
-        pub early_bird_price: i128,
-        pub early_bird_deadline: u64,
-        pub usd_price: i128,
-        pub tier_limit: i128,
-        pub current_sold: i128,
-        pub is_refundable: bool,
+    #[contractclient(name = "YieldAdapterClient")]
+    pub trait YieldAdapterInterface {
+        fn deposit(env: Env, token: Address, amount: i128);
+        fn withdraw(env: Env, token: Address, amount: i128, to: Address);
+        fn balance(env: Env, token: Address) -> i128;
     }
+}
 
-    #[soroban_sdk::contracttype]
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct Milestone {
-        pub sales_threshold: i128,
-        pub release_percent: u32,
-    }
+// Ticket NFT collection interface
+pub mod ticket_nft {
+    use soroban_sdk::{contractclient, Address, Env, String};
 
-    #[soroban_sdk::contracttype]
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct EventInfo {
-        pub event_id: String,
-        pub organizer_address: Address,
-        pub payment_address: Address,
-        pub platform_fee_percent: u32,
-        pub is_active: bool,
-        pub status: EventStatus,
-        pub created_at: u64,
-        pub metadata_cid: String,
-        pub max_supply: i128,
-        pub current_supply: i128,
-        pub milestone_plan: Option<soroban_sdk::Vec<Milestone>>,
-        pub tiers: soroban_sdk::Map<String, TicketTier>,
-        pub refund_deadline: u64,
-        pub restocking_fee: i128,
-        pub resale_cap_bps: Option<u32>,
-        pub min_sales_target: i128,
-        pub target_deadline: u64,
-        pub goal_met: bool,
+    #[contractclient(name = "TicketNftClient")]
+    pub trait TicketNftInterface {
+        fn mint(env: Env, to: Address, event_id: String, payment_id: String) -> u64;
+        fn burn(env: Env, token_id: u64);
+        fn transfer(env: Env, from: Address, to: Address, token_id: u64);
     }
 }
 
+// Event Registry interface
+pub use agora_interface::registry as event_registry;
+
+/// Revision of the public interface exposed via `get_contract_metadata`.
+pub const INTERFACE_REVISION: u32 = 2;
+
+contractmeta!(key = "name", val = "agora-ticket-payment");
+contractmeta!(key = "version", val = env!("CARGO_PKG_VERSION"));
+contractmeta!(key = "interface_revision", val = "2");
+contractmeta!(key = "counterpart", val = "agora-event-registry");
+
+/// Upper bound on events processed by a single `settle_all_platform_fees` call.
+const MAX_SETTLEMENT_BATCH: u32 = 100;
+
+/// How long after an event ends the organizer may flag damage-deposit deductions.
+pub const DEPOSIT_CLAIM_WINDOW: u64 = 7 * 24 * 60 * 60;
+
+/// How long the admin may veto an initiated organizer-escrow recovery.
+pub const RECOVERY_VETO_WINDOW: u64 = 3 * 24 * 60 * 60;
+
+/// Delay between proposing a token rescue and being able to execute it.
+pub const RESCUE_TIMELOCK: u64 = 2 * 24 * 60 * 60;
+
+/// Discount granted by codes registered through `add_discount_hashes`.
+const DISCOUNT_CODE_BPS: u32 = 1000;
+
 #[contract]
 pub struct TicketPaymentContract;
 
@@ -154,6 +237,7 @@ impl TicketPaymentContract {
         set_usdc_token(&env, usdc_token.clone());
         set_platform_wallet(&env, platform_wallet.clone());
         set_event_registry(&env, event_registry.clone());
+        set_storage_version(&env, STORAGE_VERSION);
         set_initialized(&env, true);
 
         // Whitelist USDC by default
@@ -172,6 +256,20 @@ impl TicketPaymentContract {
         Ok(())
     }
 
+    /// Returns the contract's name, version, interface revision and the
+    /// configured EventRegistry counterpart, for explorers and tooling.
+    pub fn get_contract_metadata(env: Env) -> ContractMetadata {
+        ContractMetadata {
+            name: String::from_str(&env, "agora-ticket-payment"),
+            version: String::from_str(&env, env!("CARGO_PKG_VERSION")),
+            interface_revision: INTERFACE_REVISION,
+            counterpart: env
+                .storage()
+                .persistent()
+                .get(&crate::types::DataKey::EventRegistry),
+        }
+    }
+
     /// Pauses or resumes the contract. Only callable by the multi-sig admin.
     /// Upgrade and emergency-withdrawal remain available while the contract is paused.
     pub fn set_pause(env: Env, paused: bool) -> Result<(), TicketPaymentError> {
@@ -194,6 +292,48 @@ impl TicketPaymentContract {
         is_paused(&env)
     }
 
+    /// Freezes or resumes purchases, resales and transfers of a single event
+    /// while the rest of the platform keeps selling; refunds stay available.
+    /// `caller` must be the admin or the event's organizer, and an organizer
+    /// cannot lift a pause set by the admin.
+    pub fn set_event_pause(
+        env: Env,
+        event_id: String,
+        paused: bool,
+        caller: Address,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        caller.require_auth();
+        if caller != admin {
+            let event_info = Self::fetch_event(&env, &event_id)?;
+            if caller != event_info.organizer_address {
+                return Err(TicketPaymentError::Unauthorized);
+            }
+            if get_event_paused_by(&env, event_id.clone()) == Some(admin) {
+                return Err(TicketPaymentError::Unauthorized);
+            }
+            record_organizer_activity(&env, event_id.clone());
+        }
+        set_event_paused_by(&env, event_id.clone(), paused.then(|| caller.clone()));
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::EventPaused, event_id.clone()),
+            EventPausedEvent {
+                event_id,
+                paused,
+                changed_by: caller,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns whether sales and transfers of an event are paused.
+    pub fn is_event_paused(env: Env, event_id: String) -> bool {
+        get_event_paused_by(&env, event_id).is_some()
+    }
+
     /// Sets or clears a dispute for an event. Only callable by admin.
     pub fn set_event_dispute(
         env: Env,
@@ -206,7 +346,7 @@ impl TicketPaymentContract {
         set_event_dispute_status(&env, event_id.clone(), disputed);
 
         env.events().publish(
-            (AgoraEvent::DisputeStatusChanged,),
+            (AgoraEvent::DisputeStatusChanged, event_id.clone()),
             DisputeStatusChangedEvent {
                 event_id,
                 is_disputed: disputed,
@@ -222,6 +362,267 @@ impl TicketPaymentContract {
         is_event_disputed(&env, event_id)
     }
 
+    /// Configures the refund circuit breaker: once buyer-initiated refunds of an
+    /// event within `window` seconds exceed `threshold_bps` of its sales, the event
+    /// is flagged as disputed, halting organizer withdrawals until the admin
+    /// clears it. A threshold of 0 disables the breaker. Only callable by admin.
+    pub fn set_refund_spike_config(
+        env: Env,
+        threshold_bps: u32,
+        window: u64,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        if !is_valid_bps(threshold_bps) {
+            return Err(TicketPaymentError::InvalidFeeBps);
+        }
+        if threshold_bps > 0 && window == 0 {
+            panic!("Window must be positive");
+        }
+        set_refund_spike_config(
+            &env,
+            &RefundSpikeConfig {
+                threshold_bps,
+                window,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the refund circuit breaker configuration.
+    pub fn get_refund_spike_config(env: Env) -> RefundSpikeConfig {
+        get_refund_spike_config(&env)
+    }
+
+    /// Sets how many seconds after an event's completion its revenue stays
+    /// locked so disputes can still be raised. Only callable by admin.
+    pub fn set_revenue_challenge_window(
+        env: Env,
+        window_seconds: u64,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+
+        set_revenue_challenge_window(&env, window_seconds);
+        Ok(())
+    }
+
+    /// Returns the revenue challenge window in seconds (0 when disabled).
+    pub fn get_revenue_challenge_window(env: Env) -> u64 {
+        get_revenue_challenge_window(&env)
+    }
+
+    /// Grants or revokes the arbiter role, which resolves payment disputes.
+    /// Only callable by admin.
+    pub fn set_dispute_arbiter(
+        env: Env,
+        arbiter: Address,
+        enabled: bool,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+
+        set_dispute_arbiter(&env, arbiter, enabled);
+        Ok(())
+    }
+
+    /// Returns whether an address may resolve payment disputes.
+    pub fn is_dispute_arbiter(env: Env, arbiter: Address) -> bool {
+        is_dispute_arbiter(&env, arbiter)
+    }
+
+    /// Sets the bond, in the payment token, deposited with each new dispute.
+    /// Only callable by admin.
+    pub fn set_dispute_bond(env: Env, amount: i128) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+
+        if amount < 0 {
+            panic!("Dispute bond cannot be negative");
+        }
+        set_dispute_bond(&env, amount);
+        Ok(())
+    }
+
+    /// Returns the bond deposited with each new dispute.
+    pub fn get_dispute_bond(env: Env) -> i128 {
+        get_dispute_bond(&env)
+    }
+
+    /// Opens a dispute over a single payment on behalf of its buyer or the
+    /// event's organizer, depositing the dispute bond. Until an arbiter resolves
+    /// it, the payment's organizer share is held out of payouts and the ticket
+    /// cannot be refunded, transferred or listed. A payment can be disputed once.
+    pub fn open_dispute(
+        env: Env,
+        payment_id: String,
+        opener: Address,
+        reason_cid: String,
+    ) -> Result<(), TicketPaymentError> {
+        if !is_initialized(&env) {
+            panic!("Contract not initialized");
+        }
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        opener.require_auth();
+
+        let payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        let event_info = Self::fetch_event(&env, &payment.event_id)?;
+        if opener != payment.buyer_address && opener != event_info.organizer_address {
+            return Err(TicketPaymentError::Unauthorized);
+        }
+        if !matches!(
+            payment.status,
+            PaymentStatus::Pending | PaymentStatus::Confirmed | PaymentStatus::CheckedIn
+        ) {
+            return Err(TicketPaymentError::InvalidPaymentStatus);
+        }
+        if get_payment_dispute(&env, payment_id.clone()).is_some() {
+            return Err(TicketPaymentError::EventDisputed);
+        }
+        if is_ticket_listed(&env, payment_id.clone()) {
+            return Err(TicketPaymentError::TicketListed);
+        }
+
+        let bond = get_dispute_bond(&env);
+        let bond_token = crate::storage::get_usdc_token(&env);
+        if bond > 0 {
+            token::Client::new(&env, &bond_token).transfer(
+                &opener,
+                env.current_contract_address(),
+                &bond,
+            );
+            add_held_funds(&env, bond_token.clone(), bond);
+        }
+        let now = env.ledger().timestamp();
+        let dispute = PaymentDispute {
+            payment_id: payment_id.clone(),
+            event_id: payment.event_id.clone(),
+            opened_by: opener.clone(),
+            reason_cid: reason_cid.clone(),
+            bond,
+            bond_token,
+            frozen_amount: payment.organizer_amount,
+            opened_at: now,
+            resolved: false,
+            buyer_share_bps: 0,
+        };
+        set_payment_dispute(&env, &dispute);
+        add_frozen_dispute_amount(&env, payment.event_id.clone(), dispute.frozen_amount);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (
+                AgoraEvent::DisputeOpened,
+                payment.event_id.clone(),
+                payment_id.clone(),
+            ),
+            DisputeOpenedEvent {
+                payment_id,
+                event_id: payment.event_id,
+                opened_by: opener,
+                reason_cid,
+                bond,
+                frozen_amount: dispute.frozen_amount,
+                timestamp: now,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Resolves an open dispute, refunding `buyer_share_bps` of the frozen
+    /// organizer share to the buyer and releasing the rest to the organizer's
+    /// escrow. The bond goes back to the opener when the ruling favors their
+    /// side and to the other party otherwise. Returns the buyer's refund.
+    pub fn resolve_dispute(
+        env: Env,
+        arbiter: Address,
+        payment_id: String,
+        buyer_share_bps: u32,
+    ) -> Result<i128, TicketPaymentError> {
+        if !is_initialized(&env) {
+            panic!("Contract not initialized");
+        }
+        arbiter.require_auth();
+        if !is_dispute_arbiter(&env, arbiter.clone()) {
+            return Err(TicketPaymentError::Unauthorized);
+        }
+        if !is_valid_bps(buyer_share_bps) {
+            panic!("Buyer share cannot exceed 100%");
+        }
+
+        let payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        let mut dispute = get_payment_dispute(&env, payment_id.clone())
+            .filter(|dispute| !dispute.resolved)
+            .ok_or(TicketPaymentError::InvalidPaymentStatus)?;
+        let event_info = Self::fetch_event(&env, &payment.event_id)?;
+
+        dispute.resolved = true;
+        dispute.buyer_share_bps = buyer_share_bps;
+        set_payment_dispute(&env, &dispute);
+        add_frozen_dispute_amount(&env, payment.event_id.clone(), -dispute.frozen_amount);
+
+        let buyer_amount = mul_div_bps(dispute.frozen_amount, buyer_share_bps)
+            .ok_or(TicketPaymentError::ArithmeticError)?;
+        let buyer = payment.buyer_address.clone();
+        if buyer_amount > 0 {
+            let registry_client = event_registry::Client::new(
+                &env,
+                &get_event_registry_for(&env, payment.event_id.clone()),
+            );
+            Self::pay_partial_refund(&env, &registry_client, payment, buyer_amount)?;
+        }
+
+        let opener_is_buyer = dispute.opened_by == buyer;
+        let opener_prevailed = if opener_is_buyer {
+            buyer_share_bps * 2 >= MAX_BPS
+        } else {
+            buyer_share_bps * 2 <= MAX_BPS
+        };
+        let bond_recipient = match (opener_prevailed, opener_is_buyer) {
+            (true, _) => dispute.opened_by.clone(),
+            (false, true) => event_info.organizer_address.clone(),
+            (false, false) => buyer,
+        };
+        if dispute.bond > 0 {
+            token::Client::new(&env, &dispute.bond_token).transfer(
+                &env.current_contract_address(),
+                &bond_recipient,
+                &dispute.bond,
+            );
+            add_held_funds(&env, dispute.bond_token.clone(), -dispute.bond);
+        }
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (
+                AgoraEvent::DisputeResolved,
+                dispute.event_id.clone(),
+                payment_id.clone(),
+            ),
+            DisputeResolvedEvent {
+                payment_id,
+                event_id: dispute.event_id,
+                arbiter,
+                buyer_amount,
+                organizer_amount: dispute.frozen_amount - buyer_amount,
+                bond_recipient,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(buyer_amount)
+    }
+
+    /// Returns the dispute over a payment, open or resolved, if one was raised.
+    pub fn get_payment_dispute(env: Env, payment_id: String) -> Option<PaymentDispute> {
+        get_payment_dispute(&env, payment_id)
+    }
+
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
         let admin = get_admin(&env).expect("Admin not set");
         admin.require_auth();
@@ -244,6 +645,35 @@ impl TicketPaymentContract {
         );
     }
 
+    /// Records that the storage layout was brought from `from_version` up to
+    /// `STORAGE_VERSION` after an upgrade. Payments written under older layouts
+    /// are converted lazily on read, so no per-record work is done here.
+    /// Only callable by admin.
+    pub fn migrate(env: Env, from_version: u32) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        if from_version != get_storage_version(&env) || from_version >= STORAGE_VERSION {
+            return Err(TicketPaymentError::InvalidStorageVersion);
+        }
+        set_storage_version(&env, STORAGE_VERSION);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::StorageMigrated,),
+            StorageMigratedEvent {
+                from_version,
+                to_version: STORAGE_VERSION,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the layout version the contract's storage was last migrated to.
+    pub fn get_storage_version(env: Env) -> u32 {
+        get_storage_version(&env)
+    }
+
     pub fn add_token(env: Env, token: Address) {
         let admin = get_admin(&env).expect("Admin not set");
         admin.require_auth();
@@ -260,70 +690,535 @@ impl TicketPaymentContract {
         is_token_whitelisted(&env, &token)
     }
 
-    /// Sets the oracle contract address. Only callable by admin.
-    pub fn set_oracle(env: Env, oracle_address: Address) -> Result<(), TicketPaymentError> {
+    /// Registers (or with `None`, removes) the loyalty rewards contract notified on
+    /// confirmed purchases and check-ins. Only callable by admin.
+    pub fn set_rewards_contract(
+        env: Env,
+        rewards_contract: Option<Address>,
+    ) -> Result<(), TicketPaymentError> {
         let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
         admin.require_auth();
-        set_oracle_address(&env, &oracle_address);
+        set_rewards_contract(&env, rewards_contract);
         Ok(())
     }
 
-    /// Sets the slippage tolerance in basis points. Only callable by admin.
-    /// Maximum allowed value is 5000 (50%).
-    pub fn set_slippage_bps(env: Env, bps: u32) -> Result<(), TicketPaymentError> {
+    /// Returns the registered loyalty rewards contract, if any.
+    pub fn get_rewards_contract(env: Env) -> Option<Address> {
+        get_rewards_contract(&env)
+    }
+
+    /// Registers (or with `None`, removes) the strategy contract idle escrow can
+    /// be deposited in. Only callable by admin, and only while nothing is
+    /// deployed, since withdrawals are always made from the current adapter.
+    pub fn set_yield_adapter(env: Env, adapter: Option<Address>) -> Result<(), TicketPaymentError> {
         let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
         admin.require_auth();
-        if bps > 5000 {
-            return Err(TicketPaymentError::InvalidSlippageBps);
+        if get_yield_deployed_token_count(&env) > 0 {
+            return Err(TicketPaymentError::YieldFundsDeployed);
         }
-        set_slippage_bps(&env, bps);
+        set_yield_adapter(&env, adapter);
         Ok(())
     }
 
-    /// Returns the current oracle price for an asset.
-    pub fn get_asset_price(
-        env: Env,
-        asset: Address,
-    ) -> Result<price_oracle::PriceData, TicketPaymentError> {
-        let oracle_addr =
-            get_oracle_address(&env).ok_or(TicketPaymentError::OracleNotConfigured)?;
-        let oracle_client = price_oracle::OracleClient::new(&env, &oracle_addr);
-        oracle_client
-            .lastprice(&asset)
-            .ok_or(TicketPaymentError::OraclePriceUnavailable)
+    /// Returns the registered yield strategy, if any.
+    pub fn get_yield_adapter(env: Env) -> Option<Address> {
+        get_yield_adapter(&env)
     }
 
-    /// Returns the current slippage tolerance in basis points.
-    pub fn get_slippage(env: Env) -> u32 {
-        get_slippage_bps(&env)
+    /// Sets the share of a token's active escrow, in bps, that must remain in the
+    /// contract when depositing into the yield strategy. Only callable by admin.
+    pub fn set_yield_reserve_bps(env: Env, bps: u32) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        if bps > MAX_BPS {
+            return Err(TicketPaymentError::InvalidFeeBps);
+        }
+        set_yield_reserve_bps(&env, bps);
+        Ok(())
     }
 
-    /// Processes a payment for an event ticket.
-    #[allow(clippy::too_many_arguments)]
-    pub fn process_payment(
+    /// Returns the liquid reserve requirement in basis points of active escrow.
+    pub fn get_yield_reserve_bps(env: Env) -> u32 {
+        get_yield_reserve_bps(&env)
+    }
+
+    /// Returns how much of a token is currently deposited in the yield strategy.
+    pub fn get_yield_deployed(env: Env, token_address: Address) -> i128 {
+        get_yield_deployed(&env, token_address)
+    }
+
+    /// Moves idle escrow into the yield strategy. The contract's remaining balance
+    /// of the token must still cover the reserve share of its active escrow, which
+    /// is what buyers could be refunded. Only callable by admin.
+    pub fn deposit_idle_escrow(
         env: Env,
-        payment_id: String,
-        event_id: String,
-        ticket_tier_id: String,
-        buyer_address: Address,
         token_address: Address,
-        amount: i128, // price for ONE ticket
-        quantity: u32,
-        code_preimage: Option<Bytes>,
-        referrer: Option<Address>,
-    ) -> Result<String, TicketPaymentError> {
-        if !is_initialized(&env) {
-            panic!("Contract not initialized");
-        }
-        if is_paused(&env) {
-            return Err(TicketPaymentError::ContractPaused);
+        amount: i128,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        let adapter = get_yield_adapter(&env).ok_or(TicketPaymentError::InvalidAddress)?;
+        if amount <= 0 {
+            panic!("Amount must be positive");
         }
-        buyer_address.require_auth();
 
-        if let Some(ref ref_addr) = referrer {
-            if ref_addr == &buyer_address {
-                return Err(TicketPaymentError::SelfReferralNotAllowed);
-            }
+        let token_client = token::Client::new(&env, &token_address);
+        let contract_address = env.current_contract_address();
+        let reserve = mul_div_bps(
+            crate::storage::get_active_escrow_by_token(&env, token_address.clone()),
+            get_yield_reserve_bps(&env),
+        )
+        .ok_or(TicketPaymentError::ArithmeticError)?;
+        if token_client.balance(&contract_address) - amount < reserve {
+            return Err(TicketPaymentError::NoFundsAvailable);
+        }
+
+        token_client.transfer(&contract_address, &adapter, &amount);
+        yield_adapter::YieldAdapterClient::new(&env, &adapter).deposit(&token_address, &amount);
+        let deployed = get_yield_deployed(&env, token_address.clone());
+        set_yield_deployed(&env, token_address, deployed + amount);
+        Ok(())
+    }
+
+    /// Brings funds back from the yield strategy, up to the adapter's balance.
+    /// Withdrawals repay the deployed principal first; anything above it is
+    /// strategy earnings and is credited to the token's platform fees. Only
+    /// callable by admin.
+    pub fn withdraw_from_yield(
+        env: Env,
+        token_address: Address,
+        amount: i128,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        let adapter = get_yield_adapter(&env).ok_or(TicketPaymentError::InvalidAddress)?;
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+        let adapter_client = yield_adapter::YieldAdapterClient::new(&env, &adapter);
+        if amount > adapter_client.balance(&token_address) {
+            return Err(TicketPaymentError::NoFundsAvailable);
+        }
+
+        let token_client = token::Client::new(&env, &token_address);
+        let contract_address = env.current_contract_address();
+        let balance_before = token_client.balance(&contract_address);
+        adapter_client.withdraw(&token_address, &amount, &contract_address);
+        if token_client.balance(&contract_address) - balance_before != amount {
+            return Err(TicketPaymentError::TransferVerificationFailed);
+        }
+
+        let deployed = get_yield_deployed(&env, token_address.clone());
+        let principal = amount.min(deployed);
+        set_yield_deployed(&env, token_address.clone(), deployed - principal);
+        let earnings = amount - principal;
+        if earnings > 0 {
+            add_to_total_fees_collected_by_token(&env, token_address.clone(), earnings);
+            add_to_active_escrow_total(&env, earnings);
+            add_to_active_escrow_by_token(&env, token_address.clone(), earnings);
+
+            #[allow(deprecated)]
+            env.events().publish(
+                (AgoraEvent::YieldCredited,),
+                YieldCreditedEvent {
+                    token: token_address,
+                    amount: earnings,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// Registers (or with `None`, removes) the ticket NFT collection. While set,
+    /// every ticket is minted as a token on purchase, moved on `transfer_ticket`
+    /// and burned on refund; a failing NFT call reverts the ticket operation so
+    /// the two never diverge. This contract must be the collection's minter.
+    /// Tickets issued before registration have no token. Only callable by admin.
+    pub fn set_ticket_nft_contract(
+        env: Env,
+        nft_contract: Option<Address>,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        set_ticket_nft_contract(&env, nft_contract);
+        Ok(())
+    }
+
+    /// Returns the registered ticket NFT collection, if any.
+    pub fn get_ticket_nft_contract(env: Env) -> Option<Address> {
+        get_ticket_nft_contract(&env)
+    }
+
+    /// Returns the NFT token_id backing a ticket, if one was minted.
+    pub fn get_ticket_token_id(env: Env, payment_id: String) -> Option<u64> {
+        get_ticket_token_id(&env, payment_id)
+    }
+
+    /// Sets the oracle contract address, normally an `oracle_adapter` that
+    /// guards against stale rates. Only callable by admin.
+    pub fn set_oracle(env: Env, oracle_address: Address) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        set_oracle_address(&env, &oracle_address);
+        Ok(())
+    }
+
+    /// Sets the slippage tolerance in basis points. Only callable by admin.
+    /// Maximum allowed value is 5000 (50%).
+    pub fn set_slippage_bps(env: Env, bps: u32) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        if bps > 5000 {
+            return Err(TicketPaymentError::InvalidSlippageBps);
+        }
+        set_slippage_bps(&env, bps);
+        Ok(())
+    }
+
+    /// Returns the current oracle price for an asset.
+    pub fn get_asset_price(
+        env: Env,
+        asset: Address,
+    ) -> Result<price_oracle::PriceData, TicketPaymentError> {
+        let oracle_addr =
+            get_oracle_address(&env).ok_or(TicketPaymentError::OracleNotConfigured)?;
+        match price_oracle::OracleClient::new(&env, &oracle_addr).try_lastprice(&asset) {
+            Ok(Ok(Some(data))) => Ok(data),
+            _ => Err(TicketPaymentError::OraclePriceUnavailable),
+        }
+    }
+
+    /// Sets the share of the platform fee, in bps, accrued to the referrer of a
+    /// purchase. Only callable by admin.
+    pub fn set_referral_bps(env: Env, bps: u32) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        if bps > MAX_BPS {
+            return Err(TicketPaymentError::InvalidFeeBps);
+        }
+        set_referral_bps(&env, bps);
+        Ok(())
+    }
+
+    /// Returns the referrer share of the platform fee in basis points.
+    pub fn get_referral_bps(env: Env) -> u32 {
+        get_referral_bps(&env)
+    }
+
+    /// Lets an organizer stop paying referral rewards on their event's sales.
+    /// Purchases naming a referrer still succeed; the platform keeps the full fee.
+    pub fn set_referral_opt_out(
+        env: Env,
+        event_id: String,
+        opted_out: bool,
+    ) -> Result<(), TicketPaymentError> {
+        let registry_client =
+            event_registry::Client::new(&env, &get_event_registry_for(&env, event_id.clone()));
+        let event_info = match registry_client.try_get_event(&event_id) {
+            Ok(Ok(Some(info))) => info,
+            _ => return Err(TicketPaymentError::EventNotFound),
+        };
+        event_info.organizer_address.require_auth();
+        record_organizer_activity(&env, event_id.clone());
+        set_referral_opted_out(&env, event_id, opted_out);
+        Ok(())
+    }
+
+    /// Returns whether an event pays no referral rewards.
+    pub fn is_referral_opted_out(env: Env, event_id: String) -> bool {
+        is_referral_opted_out(&env, event_id)
+    }
+
+    /// Returns a referrer's unclaimed rewards in `token`.
+    pub fn get_referral_rewards(env: Env, referrer: Address, token: Address) -> i128 {
+        get_referral_rewards(&env, referrer, token)
+    }
+
+    /// Pays out a referrer's accrued rewards in `token` and returns the amount.
+    pub fn claim_referral_rewards(
+        env: Env,
+        referrer: Address,
+        token: Address,
+    ) -> Result<i128, TicketPaymentError> {
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        referrer.require_auth();
+        let amount = get_referral_rewards(&env, referrer.clone(), token.clone());
+        if amount <= 0 {
+            return Err(TicketPaymentError::NoFundsAvailable);
+        }
+        set_referral_rewards(&env, referrer.clone(), token.clone(), 0);
+        subtract_from_active_escrow_total(&env, amount);
+        subtract_from_active_escrow_by_token(&env, token.clone(), amount);
+        token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &referrer,
+            &amount,
+        );
+        Ok(amount)
+    }
+
+    /// Registers a promoter of an event, paid `share_bps` of the organizer portion
+    /// of every sale made through their tag. A share of 0 removes the promoter.
+    pub fn add_promoter(
+        env: Env,
+        event_id: String,
+        promoter: Address,
+        share_bps: u32,
+    ) -> Result<(), TicketPaymentError> {
+        let registry_client =
+            event_registry::Client::new(&env, &get_event_registry_for(&env, event_id.clone()));
+        let event_info = match registry_client.try_get_event(&event_id) {
+            Ok(Ok(Some(info))) => info,
+            _ => return Err(TicketPaymentError::EventNotFound),
+        };
+        event_info.organizer_address.require_auth();
+        record_organizer_activity(&env, event_id.clone());
+        if share_bps > MAX_BPS {
+            return Err(TicketPaymentError::InvalidFeeBps);
+        }
+        set_promoter_share(&env, event_id, promoter, share_bps);
+        Ok(())
+    }
+
+    /// Returns a promoter's share of an event's organizer portion, or 0.
+    pub fn get_promoter_share(env: Env, event_id: String, promoter: Address) -> u32 {
+        get_promoter_share(&env, event_id, promoter)
+    }
+
+    /// Returns a promoter's unclaimed commissions in `token`.
+    pub fn get_promoter_earnings(env: Env, promoter: Address, token: Address) -> i128 {
+        get_promoter_earnings(&env, promoter, token)
+    }
+
+    /// Pays out a promoter's accrued commissions in `token` and returns the amount.
+    pub fn claim_promoter_earnings(
+        env: Env,
+        promoter: Address,
+        token: Address,
+    ) -> Result<i128, TicketPaymentError> {
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        promoter.require_auth();
+        let amount = get_promoter_earnings(&env, promoter.clone(), token.clone());
+        if amount <= 0 {
+            return Err(TicketPaymentError::NoFundsAvailable);
+        }
+        set_promoter_earnings(&env, promoter.clone(), token.clone(), 0);
+        subtract_from_active_escrow_total(&env, amount);
+        subtract_from_active_escrow_by_token(&env, token.clone(), amount);
+        token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &promoter,
+            &amount,
+        );
+        Ok(amount)
+    }
+
+    /// Returns the current slippage tolerance in basis points.
+    pub fn get_slippage(env: Env) -> u32 {
+        get_slippage_bps(&env)
+    }
+
+    /// Quotes one ticket of a tier in `token_address`, in the token's base units
+    /// and before promotions or discounts. Oracle-based quotes are what
+    /// `process_payment` compares the paid amount against, within slippage.
+    pub fn quote_ticket_price(
+        env: Env,
+        event_id: String,
+        ticket_tier_id: String,
+        token_address: Address,
+    ) -> Result<i128, TicketPaymentError> {
+        if !is_token_whitelisted(&env, &token_address) {
+            return Err(TicketPaymentError::TokenNotWhitelisted);
+        }
+        let registry_client =
+            event_registry::Client::new(&env, &get_event_registry_for(&env, event_id.clone()));
+        let event_info = match registry_client.try_get_event(&event_id) {
+            Ok(Ok(Some(info))) => info,
+            _ => return Err(TicketPaymentError::EventNotFound),
+        };
+        let tier = event_info
+            .tiers
+            .get(ticket_tier_id)
+            .ok_or(TicketPaymentError::TierNotFound)?;
+        let (price, _) = quote_tier_price(&env, &tier, &token_address, env.ledger().timestamp())?;
+        Ok(price)
+    }
+
+    /// Holds `quantity` tickets of a tier for `buyer` for `ttl` seconds (at most
+    /// `MAX_RESERVATION_TTL`), so they cannot sell out while the buyer approves
+    /// the token transfer. The hold lapses on its own once it expires.
+    pub fn reserve_tickets(
+        env: Env,
+        reservation_id: String,
+        buyer: Address,
+        event_id: String,
+        tier_id: String,
+        quantity: u32,
+        ttl: u64,
+    ) -> Result<Reservation, TicketPaymentError> {
+        if !is_initialized(&env) {
+            panic!("Contract not initialized");
+        }
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        ensure_event_not_paused(&env, &event_id)?;
+        buyer.require_auth();
+        if quantity == 0 {
+            panic!("Quantity must be positive");
+        }
+        if ttl == 0 || ttl > MAX_RESERVATION_TTL {
+            panic!("Reservation ttl out of range");
+        }
+        if Self::get_reservation(env.clone(), reservation_id.clone()).is_some() {
+            return Err(TicketPaymentError::PaymentAlreadyExists);
+        }
+        unused_payment_ids(&env, &reservation_id, quantity)?;
+
+        let registry_client =
+            event_registry::Client::new(&env, &get_event_registry_for(&env, event_id.clone()));
+        let event_info = match registry_client.try_get_event(&event_id) {
+            Ok(Ok(Some(info))) => info,
+            _ => return Err(TicketPaymentError::EventNotFound),
+        };
+        if !event_info.tiers.contains_key(tier_id.clone()) {
+            return Err(TicketPaymentError::TierNotFound);
+        }
+        let expires_at = env.ledger().timestamp() + ttl;
+        match registry_client.try_hold_inventory(
+            &reservation_id,
+            &event_id,
+            &tier_id,
+            &quantity,
+            &expires_at,
+        ) {
+            Ok(Ok(())) => {}
+            _ => return Err(TicketPaymentError::MaxSupplyExceeded),
+        }
+
+        let reservation = Reservation {
+            reservation_id,
+            buyer,
+            event_id,
+            tier_id,
+            quantity,
+            expires_at,
+        };
+        set_reservation(&env, &reservation);
+        Ok(reservation)
+    }
+
+    /// Pays for a live reservation, exactly as `process_payment` would for its
+    /// buyer, tier and quantity. The held tickets are released to the sale.
+    pub fn complete_reserved_payment(
+        env: Env,
+        reservation_id: String,
+        token_address: Address,
+        amount: i128,
+        code_preimage: Option<Bytes>,
+        referrer: Option<Address>,
+        options: Option<PurchaseOptions>,
+    ) -> Result<Vec<String>, TicketPaymentError> {
+        let reservation = Self::get_reservation(env.clone(), reservation_id.clone())
+            .ok_or(TicketPaymentError::PaymentNotFound)?;
+        Self::release_reservation(&env, &reservation);
+        Self::process_payment(
+            env,
+            reservation_id,
+            reservation.event_id,
+            reservation.tier_id,
+            reservation.buyer,
+            token_address,
+            amount,
+            reservation.quantity,
+            code_preimage,
+            referrer,
+            options,
+        )
+    }
+
+    /// Gives up a live reservation, returning its tickets to sale early.
+    pub fn cancel_reservation(env: Env, reservation_id: String) -> Result<(), TicketPaymentError> {
+        let reservation = Self::get_reservation(env.clone(), reservation_id)
+            .ok_or(TicketPaymentError::PaymentNotFound)?;
+        reservation.buyer.require_auth();
+        Self::release_reservation(&env, &reservation);
+        Ok(())
+    }
+
+    /// Returns a reservation while it still holds tickets.
+    pub fn get_reservation(env: Env, reservation_id: String) -> Option<Reservation> {
+        get_reservation(&env, reservation_id)
+            .filter(|reservation| reservation.expires_at > env.ledger().timestamp())
+    }
+
+    fn release_reservation(env: &Env, reservation: &Reservation) {
+        remove_reservation(env, reservation.reservation_id.clone());
+        event_registry::Client::new(
+            env,
+            &get_event_registry_for(env, reservation.event_id.clone()),
+        )
+        .release_inventory_hold(&reservation.reservation_id);
+    }
+
+    /// Pre-flight check for `process_payment`: fails as the purchase would if
+    /// `payment_id` is already taken, the token is not accepted or the tier is
+    /// unknown, and otherwise returns the purchase's pricing before any codes.
+    pub fn quote_payment(
+        env: Env,
+        payment_id: String,
+        event_id: String,
+        ticket_tier_id: String,
+        token_address: Address,
+        quantity: u32,
+    ) -> Result<PricingBreakdown, TicketPaymentError> {
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        if quantity == 0 {
+            panic!("Quantity must be positive");
+        }
+        unused_payment_ids(&env, &payment_id, quantity)?;
+        let price =
+            Self::quote_ticket_price(env.clone(), event_id.clone(), ticket_tier_id, token_address)?;
+        Self::preview_pricing_breakdown(env, event_id, price, quantity, 0, false)
+    }
+
+    /// Processes a payment for an event ticket. Returns the id of each ticket
+    /// created: `payment_id` itself for a single ticket, or `<payment_id>-<index>`
+    /// per ticket of a batch.
+    #[allow(clippy::too_many_arguments)]
+    pub fn process_payment(
+        env: Env,
+        payment_id: String,
+        event_id: String,
+        ticket_tier_id: String,
+        buyer_address: Address,
+        token_address: Address,
+        amount: i128, // price for ONE ticket
+        quantity: u32,
+        code_preimage: Option<Bytes>,
+        referrer: Option<Address>,
+        options: Option<PurchaseOptions>,
+    ) -> Result<Vec<String>, TicketPaymentError> {
+        if !is_initialized(&env) {
+            panic!("Contract not initialized");
+        }
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        ensure_event_not_paused(&env, &event_id)?;
+        buyer_address.require_auth();
+
+        if let Some(ref ref_addr) = referrer {
+            if ref_addr == &buyer_address {
+                return Err(TicketPaymentError::SelfReferralNotAllowed);
+            }
         }
 
         if amount <= 0 {
@@ -333,53 +1228,66 @@ impl TicketPaymentContract {
         if quantity == 0 {
             panic!("Quantity must be positive");
         }
+        // Replayed requests must not overwrite existing tickets or charge twice
+        let ticket_ids = unused_payment_ids(&env, &payment_id, quantity)?;
 
         if !is_token_whitelisted(&env, &token_address) {
             return Err(TicketPaymentError::TokenNotWhitelisted);
         }
 
-        let total_amount = amount
-            .checked_mul(quantity as i128)
-            .ok_or(TicketPaymentError::ArithmeticError)?;
+        // Resolve the registry tenant this event belongs to. Tenant events are
+        // bound by their registry at registration; others follow the default.
+        let event_registry_addr = resolve_event_registry(
+            &env,
+            event_id.clone(),
+            options.as_ref().and_then(|o| o.registry.clone()),
+        )?;
 
         // Apply platform-wide global promo if active (self-expiring via timestamp check)
-        let event_registry_addr_promo = get_event_registry(&env);
-        let registry_client_promo = event_registry::Client::new(&env, &event_registry_addr_promo);
+        let registry_client_promo = event_registry::Client::new(&env, &event_registry_addr);
         let global_promo_bps = registry_client_promo.get_global_promo_bps();
         let promo_expiry = registry_client_promo.get_promo_expiry();
         let current_ts = env.ledger().timestamp();
 
-        let (after_promo, promo_applied_bps) = if global_promo_bps > 0 && current_ts < promo_expiry
+        let promo_mode = Self::resolve_promo_mode(&registry_client_promo, &event_id);
+
+        let promo_applied_bps = if global_promo_bps > 0
+            && current_ts < promo_expiry
+            && promo_mode != event_registry::PromoMode::OptOut
         {
-            let discounted = total_amount
-                .checked_mul((10000 - global_promo_bps as i128) as i128)
-                .and_then(|v| v.checked_div(10000))
-                .ok_or(TicketPaymentError::ArithmeticError)?;
-            (discounted, global_promo_bps)
+            global_promo_bps
         } else {
-            (total_amount, 0u32)
+            0u32
         };
 
-        // Optionally apply a discount code (10% off) on top of the promo price
-        let (effective_total, discount_code_hash) = if let Some(preimage) = code_preimage {
+        // Optionally apply a discount code on top of the promo price
+        let discount_code = if let Some(preimage) = code_preimage {
             let hash: soroban_sdk::BytesN<32> = env.crypto().sha256(&preimage).into();
-            if !is_discount_hash_valid(&env, &hash) {
+            let code =
+                get_discount_code(&env, hash).ok_or(TicketPaymentError::InvalidDiscountCode)?;
+            if code.event_id != event_id
+                || (code.spec.expiry > 0 && current_ts >= code.spec.expiry)
+                || code
+                    .spec
+                    .tier_restriction
+                    .as_ref()
+                    .is_some_and(|tier_id| tier_id != &ticket_tier_id)
+            {
                 return Err(TicketPaymentError::InvalidDiscountCode);
             }
-            if is_discount_hash_used(&env, &hash) {
+            if (code.spec.max_uses > 0 && code.uses_so_far >= code.spec.max_uses)
+                || (code.spec.max_uses_per_buyer > 0
+                    && get_discount_buyer_uses(&env, code.spec.hash.clone(), buyer_address.clone())
+                        >= code.spec.max_uses_per_buyer)
+            {
                 return Err(TicketPaymentError::DiscountCodeAlreadyUsed);
             }
-            // 10% discount
-            let discounted = after_promo
-                .checked_mul(90)
-                .and_then(|v| v.checked_div(100))
-                .ok_or(TicketPaymentError::ArithmeticError)?;
-            (discounted, Some(hash))
+            Some(code)
         } else {
-            (after_promo, None)
+            None
         };
+        let code_bps = discount_code.as_ref().map_or(0, |code| code.spec.bps);
         // 1. Query Event Registry for event info and check inventory
-        let event_registry_addr = get_event_registry(&env);
         let registry_client = event_registry::Client::new(&env, &event_registry_addr);
 
         let event_info = match registry_client.try_get_event(&event_id) {
@@ -390,9 +1298,11 @@ impl TicketPaymentContract {
 
         if !event_info.is_active
             || matches!(event_info.status, event_registry::EventStatus::Cancelled)
+            || event_info.visibility == event_registry::EventVisibility::Draft
         {
             return Err(TicketPaymentError::EventInactive);
         }
+        ensure_within_buyer_limit(&env, &event_info, &buyer_address, quantity)?;
 
         let tier = event_info
             .tiers
@@ -401,57 +1311,96 @@ impl TicketPaymentContract {
 
         let current_time = env.ledger().timestamp();
 
-        if tier.usd_price > 0 {
-            // ── Oracle-based USD pricing ──────────────────────────────────
-            let oracle_addr =
-                get_oracle_address(&env).ok_or(TicketPaymentError::OracleNotConfigured)?;
-            let oracle_client = price_oracle::OracleClient::new(&env, &oracle_addr);
-            let price_data = oracle_client
-                .lastprice(&token_address)
-                .ok_or(TicketPaymentError::OraclePriceUnavailable)?;
-
-            // expected = usd_price * oracle_price / 1_0000000
-            let expected = tier
-                .usd_price
-                .checked_mul(price_data.price)
-                .and_then(|v| v.checked_div(1_0000000))
-                .ok_or(TicketPaymentError::ArithmeticError)?;
+        // Staged tiers cannot be bought outside their visibility window
+        if (tier.visible_from > 0 && current_time < tier.visible_from)
+            || (tier.visible_until > 0 && current_time >= tier.visible_until)
+        {
+            return Err(TicketPaymentError::TierNotVisible);
+        }
 
-            let bps = get_slippage_bps(&env) as i128;
-            let min_amount = expected
-                .checked_mul(10000 - bps)
-                .and_then(|v| v.checked_div(10000))
-                .ok_or(TicketPaymentError::ArithmeticError)?;
-            let max_amount = expected
-                .checked_mul(10000 + bps)
-                .and_then(|v| v.checked_div(10000))
+        // Seated tiers sell specific seats, one per ticket; general admission takes none.
+        // Registries without seat maps only offer general admission.
+        let seats = options
+            .as_ref()
+            .map(|o| o.seats.clone())
+            .unwrap_or(Vec::new(&env));
+        let is_seated = matches!(
+            registry_client.try_get_seat_map(&event_id, &ticket_tier_id),
+            Ok(Ok(Some(_)))
+        );
+        if seats.len() != if is_seated { quantity } else { 0 } {
+            return Err(TicketPaymentError::InvalidSeatSelection);
+        }
+
+        let flash_sale_bps = Self::resolve_flash_sale(
+            &env,
+            &registry_client,
+            &event_id,
+            &ticket_tier_id,
+            current_time,
+        );
+
+        // Attendance coupons are redeemed automatically and stack on any flash sale
+        let coupon_bps = get_coupon(&env, event_id.clone(), buyer_address.clone());
+        if coupon_bps > 0 {
+            remove_coupon(&env, event_id.clone(), buyer_address.clone());
+        }
+        // Group discounts follow the quantity bought and stack on both
+        let group_bps = bulk_discount_bps(&tier, quantity);
+        let discounted_before_group = |price: i128| {
+            apply_discount_bps(apply_discount_bps(price, flash_sale_bps)?, coupon_bps)
+        };
+        let discounted =
+            |price: i128| apply_discount_bps(discounted_before_group(price)?, group_bps);
+
+        // Undiscounted unit price, for recording flash-sale and coupon discounts
+        let (quoted, is_oracle_quote) =
+            quote_tier_price(&env, &tier, &token_address, current_time)?;
+        let expected = discounted(quoted)?;
+        let list_price = if is_oracle_quote {
+            // Oracle quotes move between signing and execution, so the amount
+            // only has to fall within the slippage tolerance
+            let bps = options
+                .as_ref()
+                .and_then(|o| o.max_slippage_bps)
+                .map_or(get_slippage_bps(&env), |b| b.min(get_slippage_bps(&env)));
+            let min_amount = mul_div_bps(expected, MAX_BPS.saturating_sub(bps))
                 .ok_or(TicketPaymentError::ArithmeticError)?;
+            let max_amount = add_bps(expected, bps).ok_or(TicketPaymentError::ArithmeticError)?;
 
             if amount < min_amount || amount > max_amount {
                 return Err(TicketPaymentError::PriceOutsideSlippage);
             }
+            amount
+                .checked_add(quoted - expected)
+                .ok_or(TicketPaymentError::ArithmeticError)?
         } else {
-            // ── Exact token-price matching (existing behaviour) ───────────
-            let mut active_price = tier.price;
-
-            if tier.early_bird_deadline > 0 && current_time <= tier.early_bird_deadline {
-                active_price = tier.early_bird_price;
-            }
-
-            if amount != active_price {
+            if amount != expected {
                 return Err(TicketPaymentError::InvalidPrice);
             }
-        }
+            quoted
+        };
+
+        let group_saved = if group_bps > 0 {
+            let before_group = discounted_before_group(list_price)?;
+            before_group
+                .checked_sub(apply_discount_bps(before_group, group_bps)?)
+                .and_then(|v| v.checked_mul(quantity as i128))
+                .ok_or(TicketPaymentError::ArithmeticError)?
+        } else {
+            0
+        };
 
-        // Check if we just transitioned from early bird to standard
-        if tier.early_bird_deadline > 0
-            && current_time > tier.early_bird_deadline
+        // Check if we just transitioned from early bird to standard, either because
+        // the deadline passed or because the early-bird quantity sold out
+        if (tier.early_bird_deadline > 0 || tier.early_bird_quantity > 0)
+            && !is_early_bird_active(&tier, current_time)
             && !has_price_switched(&env, event_id.clone(), ticket_tier_id.clone())
         {
             set_price_switched(&env, event_id.clone(), ticket_tier_id.clone());
             #[allow(deprecated)]
             env.events().publish(
-                (AgoraEvent::PriceSwitched,),
+                (AgoraEvent::PriceSwitched, event_id.clone()),
                 PriceSwitchedEvent {
                     event_id: event_id.clone(),
                     tier_id: ticket_tier_id.clone(),
@@ -461,134 +1410,333 @@ impl TicketPaymentContract {
             );
         }
 
-        // 2. Calculate platform fee (platform_fee_percent is in bps, 10000 = 100%)
-        let mut total_platform_fee = effective_total
-            .checked_mul(event_info.platform_fee_percent as i128)
-            .and_then(|v| v.checked_div(10000))
-            .ok_or(TicketPaymentError::ArithmeticError)?;
-        let total_organizer_amount = effective_total
-            .checked_sub(total_platform_fee)
-            .ok_or(TicketPaymentError::ArithmeticError)?;
-
-        let referral_reward = if referrer.is_some() {
-            let reward = total_platform_fee
-                .checked_mul(20)
-                .and_then(|v| v.checked_div(100))
-                .ok_or(TicketPaymentError::ArithmeticError)?; // 20%
-            total_platform_fee = total_platform_fee
-                .checked_sub(reward)
-                .ok_or(TicketPaymentError::ArithmeticError)?;
-            reward
+        // 2. Calculate promo/discount reductions, platform fee and referral cut
+        let referral_bps = if referrer.is_some() {
+            Self::resolve_referral_bps(&env, &event_id)
         } else {
             0
         };
+        let pricing = compute_pricing_breakdown(
+            amount,
+            quantity,
+            promo_applied_bps,
+            code_bps,
+            event_info.platform_fee_percent,
+            referral_bps,
+            promo_mode == event_registry::PromoMode::PlatformAbsorbed,
+        )?;
+        let total_amount = pricing.gross;
+        let effective_total = pricing.net_paid;
+        let total_platform_fee = pricing.platform_fee;
+        let referral_reward = pricing.referral_cut;
+
+        // Promoters are paid a share of the organizer portion of sales they bring in
+        let promoter = options.as_ref().and_then(|o| o.promoter.clone());
+        let promoter_cut = match promoter {
+            Some(ref promoter_addr) => {
+                if promoter_addr == &buyer_address {
+                    return Err(TicketPaymentError::SelfReferralNotAllowed);
+                }
+                let share_bps = get_promoter_share(&env, event_id.clone(), promoter_addr.clone());
+                if share_bps == 0 {
+                    return Err(TicketPaymentError::InvalidAddress);
+                }
+                mul_div_bps(pricing.organizer_net, share_bps)
+                    .ok_or(TicketPaymentError::ArithmeticError)?
+            }
+            None => 0,
+        };
+        let total_organizer_amount = pricing.organizer_net - promoter_cut;
 
         // 3. Transfer tokens to contract (escrow)
         let token_client = token::Client::new(&env, &token_address);
         let contract_address = env.current_contract_address();
 
+        // Damage deposits are escrowed separately from the ticket price
+        let deposit_total = tier
+            .deposit_amount
+            .checked_mul(quantity as i128)
+            .ok_or(TicketPaymentError::ArithmeticError)?;
+
+        // A prepaid voucher, already held by the contract, covers as much of the
+        // ticket price as its balance allows; the buyer pays the rest
+        let mut voucher = match options.as_ref().and_then(|o| o.voucher_code.clone()) {
+            Some(code) => Some(redeemable_voucher(
+                &env,
+                &code,
+                &buyer_address,
+                &token_address,
+            )?),
+            None => None,
+        };
+        let voucher_credit = voucher
+            .as_ref()
+            .map_or(0, |v| v.balance.min(effective_total));
+        let buyer_due = effective_total - voucher_credit;
+
         // Verify allowance
         let allowance = token_client.allowance(&buyer_address, &contract_address);
-        if allowance < effective_total {
+        if allowance
+            < buyer_due
+                .checked_add(deposit_total)
+                .ok_or(TicketPaymentError::ArithmeticError)?
+        {
             return Err(TicketPaymentError::InsufficientAllowance);
         }
 
-        // Get balance before transfer
-        let balance_before = token_client.balance(&contract_address);
+        if buyer_due > 0 {
+            // Get balance before transfer
+            let balance_before = token_client.balance(&contract_address);
 
-        // Transfer full amount to contract
-        token_client.transfer_from(
-            &contract_address,
-            &buyer_address,
-            &contract_address,
-            &effective_total,
-        );
+            // Transfer the amount due to contract
+            token_client.transfer_from(
+                &contract_address,
+                &buyer_address,
+                &contract_address,
+                &buyer_due,
+            );
 
-        // Verify balance after transfer
-        let balance_after = token_client.balance(&contract_address);
-        if balance_after
-            .checked_sub(balance_before)
-            .ok_or(TicketPaymentError::ArithmeticError)?
-            != effective_total
-        {
-            return Err(TicketPaymentError::TransferVerificationFailed);
+            // Verify balance after transfer
+            let balance_after = token_client.balance(&contract_address);
+            if balance_after
+                .checked_sub(balance_before)
+                .ok_or(TicketPaymentError::ArithmeticError)?
+                != buyer_due
+            {
+                return Err(TicketPaymentError::TransferVerificationFailed);
+            }
+        }
+        if let Some(ref mut v) = voucher {
+            v.balance -= voucher_credit;
+            v.holder = Some(buyer_address.clone());
+            set_voucher(&env, v);
+            add_held_funds(&env, token_address.clone(), -voucher_credit);
         }
 
-        // Transfer referral reward if applicable
+        // Accrue the referral reward; referrers claim it separately
         if let Some(ref ref_addr) = referrer {
             if referral_reward > 0 {
-                token_client.transfer(&contract_address, ref_addr, &referral_reward);
+                let accrued = get_referral_rewards(&env, ref_addr.clone(), token_address.clone());
+                set_referral_rewards(
+                    &env,
+                    ref_addr.clone(),
+                    token_address.clone(),
+                    accrued + referral_reward,
+                );
             }
         }
 
+        if deposit_total > 0 {
+            token_client.transfer_from(
+                &contract_address,
+                &buyer_address,
+                &contract_address,
+                &deposit_total,
+            );
+            add_held_funds(&env, token_address.clone(), deposit_total);
+        }
+
         // 4. Update escrow balances
         update_event_balance(
             &env,
             event_id.clone(),
+            &token_address,
             total_organizer_amount,
             total_platform_fee,
         );
+        check_withdrawal_threshold(&env, &event_info)?;
+        append_ledger_entry(
+            &env,
+            event_id.clone(),
+            LedgerEntryType::Sale,
+            effective_total,
+            token_address.clone(),
+            buyer_address.clone(),
+        );
+        let discount = list_price
+            .checked_sub(amount)
+            .and_then(|v| v.checked_mul(quantity as i128))
+            .and_then(|v| v.checked_add(total_amount - effective_total))
+            .ok_or(TicketPaymentError::ArithmeticError)?;
+        if discount > 0 {
+            append_ledger_entry(
+                &env,
+                event_id.clone(),
+                LedgerEntryType::Discount,
+                discount,
+                token_address.clone(),
+                buyer_address.clone(),
+            );
+        }
+        if promo_mode == event_registry::PromoMode::PlatformAbsorbed {
+            // What the organizer would have netted had it shared the promo cost
+            let shared = compute_pricing_breakdown(
+                amount,
+                quantity,
+                promo_applied_bps,
+                code_bps,
+                event_info.platform_fee_percent,
+                referral_bps,
+                false,
+            )?;
+            let absorbed = pricing.organizer_net - shared.organizer_net;
+            if absorbed > 0 {
+                append_ledger_entry(
+                    &env,
+                    event_id.clone(),
+                    LedgerEntryType::PromoAbsorbed,
+                    absorbed,
+                    token_address.clone(),
+                    buyer_address.clone(),
+                );
+            }
+        }
+        if let Some(ref promoter_addr) = promoter {
+            if promoter_cut > 0 {
+                append_ledger_entry(
+                    &env,
+                    event_id.clone(),
+                    LedgerEntryType::PromoterCommission,
+                    promoter_cut,
+                    token_address.clone(),
+                    promoter_addr.clone(),
+                );
+                let earned =
+                    get_promoter_earnings(&env, promoter_addr.clone(), token_address.clone());
+                set_promoter_earnings(
+                    &env,
+                    promoter_addr.clone(),
+                    token_address.clone(),
+                    earned + promoter_cut,
+                );
+            }
+        }
         add_to_total_volume_processed(&env, total_amount);
         add_to_total_fees_collected_by_token(&env, token_address.clone(), total_platform_fee);
+        add_to_tenant_fees_collected(
+            &env,
+            event_registry_addr.clone(),
+            token_address.clone(),
+            total_platform_fee,
+        );
         add_to_active_escrow_total(&env, total_amount);
         add_to_active_escrow_by_token(&env, token_address.clone(), total_amount);
 
-        // 5. Mark the discount code as used (after funds are safely transferred)
-        if let Some(hash) = discount_code_hash.clone() {
-            mark_discount_hash_used(&env, hash);
+        // 5. Count the discount code use (after funds are safely transferred)
+        if let Some(mut code) = discount_code.clone() {
+            code.uses_so_far += 1;
+            set_discount_code(&env, &code);
+            increment_discount_buyer_uses(&env, code.spec.hash, buyer_address.clone());
         }
 
         // 6. Increment inventory after successful payment
         registry_client.increment_inventory(&event_id, &ticket_tier_id, &quantity);
+        for seat_id in seats.iter() {
+            registry_client.reserve_seat(&event_id, &ticket_tier_id, &seat_id);
+        }
 
         // 7. Create payment records for each individual ticket
-        let quantity_i128 = quantity as i128;
-        let platform_fee_per_ticket = total_platform_fee
-            .checked_div(quantity_i128)
-            .ok_or(TicketPaymentError::ArithmeticError)?;
-        let organizer_amount_per_ticket = total_organizer_amount
-            .checked_div(quantity_i128)
-            .ok_or(TicketPaymentError::ArithmeticError)?;
+        // Rounding dust goes to the first ticket so the records sum to the totals
+        let (platform_fee_per_ticket, platform_fee_dust) =
+            split_evenly(total_platform_fee, quantity)
+                .ok_or(TicketPaymentError::ArithmeticError)?;
+        let (organizer_amount_per_ticket, organizer_amount_dust) =
+            split_evenly(total_organizer_amount, quantity)
+                .ok_or(TicketPaymentError::ArithmeticError)?;
+        let (promoter_cut_per_ticket, promoter_cut_dust) =
+            split_evenly(promoter_cut, quantity).ok_or(TicketPaymentError::ArithmeticError)?;
+        let (referral_cut_per_ticket, referral_cut_dust) =
+            split_evenly(referral_reward, quantity).ok_or(TicketPaymentError::ArithmeticError)?;
         let created_at = env.ledger().timestamp();
+        let terms_hash: BytesN<32> = env
+            .crypto()
+            .sha256(
+                &(
+                    event_info.metadata_cid.clone(),
+                    event_info.refund_deadline,
+                    event_info.restocking_fee,
+                    event_info.resale_cap_bps,
+                    tier.is_refundable,
+                )
+                    .to_xdr(&env),
+            )
+            .into();
         let empty_tx_hash = String::from_str(&env, "");
+        let (identity_hash, memo) = match options {
+            Some(o) => (o.identity_hash, o.memo),
+            None => (None, None),
+        };
+        validate_memo(&memo)?;
 
-        for i in 0..quantity {
-            // Re-initialize the sub_payment_id with a unique ID for each ticket in a batch.
-            // Since concatenation is complex in Soroban no_std, we use a match for common indices.
-            let sub_payment_id = if quantity == 1 {
-                payment_id.clone()
-            } else {
-                match i {
-                    0 => String::from_str(&env, "p-0"),
-                    1 => String::from_str(&env, "p-1"),
-                    2 => String::from_str(&env, "p-2"),
-                    3 => String::from_str(&env, "p-3"),
-                    4 => String::from_str(&env, "p-4"),
-                    _ => String::from_str(&env, "p-many"),
-                }
-            };
-
+        for (i, sub_payment_id) in (0..quantity).zip(ticket_ids.iter()) {
             let payment = Payment {
                 payment_id: sub_payment_id.clone(),
                 event_id: event_id.clone(),
                 buyer_address: buyer_address.clone(),
                 ticket_tier_id: ticket_tier_id.clone(),
                 amount,
-                platform_fee: platform_fee_per_ticket,
-                organizer_amount: organizer_amount_per_ticket,
+                token_address: token_address.clone(),
+                platform_fee: platform_fee_per_ticket + if i == 0 { platform_fee_dust } else { 0 },
+                organizer_amount: organizer_amount_per_ticket
+                    + if i == 0 { organizer_amount_dust } else { 0 },
                 status: PaymentStatus::Pending,
                 transaction_hash: empty_tx_hash.clone(),
                 created_at,
                 confirmed_at: None,
                 refunded_amount: 0,
+                identity_hash: identity_hash.clone(),
+                memo: memo.clone(),
+                seat_id: seats.get(i),
             };
 
             store_payment(&env, payment);
+            mint_ticket_nft(&env, &buyer_address, &event_id, &sub_payment_id);
+            if promoter_cut > 0 || referral_reward > 0 {
+                set_ticket_commissions(
+                    &env,
+                    sub_payment_id.clone(),
+                    &TicketCommissions {
+                        promoter: promoter.clone(),
+                        promoter_cut: promoter_cut_per_ticket
+                            + if i == 0 { promoter_cut_dust } else { 0 },
+                        referrer: referrer.clone(),
+                        referral_cut: referral_cut_per_ticket
+                            + if i == 0 { referral_cut_dust } else { 0 },
+                    },
+                );
+            }
+            set_purchase_terms(
+                &env,
+                sub_payment_id.clone(),
+                &PurchaseTerms {
+                    token: token_address.clone(),
+                    quantity,
+                    terms_hash: terms_hash.clone(),
+                },
+            );
+
+            if tier.deposit_amount > 0 {
+                set_ticket_deposit(
+                    &env,
+                    sub_payment_id,
+                    &TicketDeposit {
+                        amount: tier.deposit_amount,
+                        token: token_address.clone(),
+                        deduction: 0,
+                        reason: None,
+                        released: false,
+                    },
+                );
+            }
         }
 
         // 8. Emit payment event
         env.events().publish(
-            (AgoraEvent::PaymentProcessed,),
+            (
+                AgoraEvent::PaymentProcessed,
+                event_id.clone(),
+                payment_id.clone(),
+            ),
             PaymentProcessedEvent {
                 payment_id: payment_id.clone(),
                 event_id: event_id.clone(),
@@ -599,15 +1747,43 @@ impl TicketPaymentContract {
             },
         );
 
+        if group_bps > 0 {
+            env.events().publish(
+                (
+                    AgoraEvent::GroupDiscountApplied,
+                    event_id.clone(),
+                    payment_id.clone(),
+                ),
+                GroupDiscountAppliedEvent {
+                    payment_id: payment_id.clone(),
+                    event_id: event_id.clone(),
+                    quantity,
+                    discount_bps: group_bps,
+                    saved_amount: group_saved,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+
         // 9. Emit discount applied event if a code was used
-        if let Some(hash) = discount_code_hash {
-            let discount_amount = total_amount.checked_sub(effective_total).unwrap_or(0);
+        if let Some(code) = discount_code {
+            let discount_amount = pricing.promo_reduction + pricing.code_discount;
+            record_promo_usage(
+                &env,
+                PromoScope::Event(event_id.clone()),
+                pricing.code_discount,
+                effective_total,
+            );
             env.events().publish(
-                (AgoraEvent::DiscountCodeApplied,),
+                (
+                    AgoraEvent::DiscountCodeApplied,
+                    event_id.clone(),
+                    payment_id.clone(),
+                ),
                 DiscountCodeAppliedEvent {
                     payment_id: payment_id.clone(),
                     event_id: event_id.clone(),
-                    code_hash: hash,
+                    code_hash: code.spec.hash,
                     discount_amount,
                     timestamp: env.ledger().timestamp(),
                 },
@@ -616,9 +1792,19 @@ impl TicketPaymentContract {
 
         // 10. Emit global promo applied event if promo was active
         if promo_applied_bps > 0 {
-            let promo_discount_amount = total_amount.checked_sub(after_promo).unwrap_or(0);
+            let promo_discount_amount = pricing.promo_reduction;
+            record_promo_usage(
+                &env,
+                PromoScope::Global(promo_expiry),
+                promo_discount_amount,
+                effective_total,
+            );
             env.events().publish(
-                (AgoraEvent::GlobalPromoApplied,),
+                (
+                    AgoraEvent::GlobalPromoApplied,
+                    event_id.clone(),
+                    payment_id.clone(),
+                ),
                 GlobalPromoAppliedEvent {
                     payment_id: payment_id.clone(),
                     event_id: event_id.clone(),
@@ -629,36 +1815,426 @@ impl TicketPaymentContract {
             );
         }
 
-        Ok(payment_id)
+        Ok(ticket_ids)
+    }
+
+    /// Buys a registry bundle: one ticket in the bundle's tier of each included
+    /// event, paid at the bundle price in the default token. `payment_ids` names
+    /// the linked payment created per event, in the bundle's event order; the
+    /// price is split evenly across them, with rounding dust on the first.
+    /// `registry` names the tenant offering the bundle (the default registry if
+    /// `None`); every bundled event must belong to it.
+    pub fn process_bundle_payment(
+        env: Env,
+        purchase_id: String,
+        bundle_id: String,
+        buyer_address: Address,
+        payment_ids: Vec<String>,
+        registry: Option<Address>,
+    ) -> Result<String, TicketPaymentError> {
+        if !is_initialized(&env) {
+            panic!("Contract not initialized");
+        }
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        buyer_address.require_auth();
+
+        if get_bundle_purchase(&env, purchase_id.clone()).is_some() {
+            return Err(TicketPaymentError::PaymentAlreadyExists);
+        }
+        let default_registry = get_event_registry(&env);
+        let event_registry_addr = registry.unwrap_or_else(|| default_registry.clone());
+        if !is_registry_allowed(&env, &event_registry_addr) {
+            return Err(TicketPaymentError::RegistryNotAllowed);
+        }
+        let registry_client = event_registry::Client::new(&env, &event_registry_addr);
+        let bundle = match registry_client.try_get_bundle(&bundle_id) {
+            Ok(Ok(Some(bundle))) => bundle,
+            _ => return Err(TicketPaymentError::EventNotFound),
+        };
+        if payment_ids.len() != bundle.event_ids.len() {
+            return Err(TicketPaymentError::InvalidBundlePaymentIds);
+        }
+        // Each linked payment is a single-ticket purchase, so its id follows
+        // the same rules; a repeated id would overwrite an earlier payment
+        let mut checked_ids: Vec<String> = Vec::new(&env);
+        for payment_id in payment_ids.iter() {
+            if checked_ids.contains(&payment_id) {
+                return Err(TicketPaymentError::InvalidBundlePaymentIds);
+            }
+            checked_ids.append(&unused_payment_ids(&env, &payment_id, 1)?);
+        }
+
+        let token_address = crate::storage::get_usdc_token(&env);
+        collect_payment(&env, &token_address, &buyer_address, bundle.price)?;
+        // The registry rejects sold-out bundles, and each event's inventory
+        // call below rejects inactive events and sold-out tiers
+        registry_client.record_bundle_sale(&bundle_id);
+
+        let (amount_per_event, dust) = split_evenly(bundle.price, bundle.event_ids.len())
+            .ok_or(TicketPaymentError::ArithmeticError)?;
+        let created_at = env.ledger().timestamp();
+        for (i, (event_id, payment_id)) in
+            bundle.event_ids.iter().zip(payment_ids.iter()).enumerate()
+        {
+            ensure_event_not_paused(&env, &event_id)?;
+            // The default registry's events are never bound to a tenant
+            if event_registry_addr != default_registry
+                && get_bound_event_registry(&env, event_id.clone()).as_ref()
+                    != Some(&event_registry_addr)
+            {
+                return Err(TicketPaymentError::RegistryNotAllowed);
+            }
+            if let Some(event_info) = registry_client.get_event(&event_id) {
+                ensure_within_buyer_limit(&env, &event_info, &buyer_address, 1)?;
+            }
+            let amount = amount_per_event + if i == 0 { dust } else { 0 };
+            let platform_fee_percent = registry_client
+                .get_event_payment_info(&event_id)
+                .platform_fee_percent;
+            let platform_fee = mul_div_bps(amount, platform_fee_percent)
+                .ok_or(TicketPaymentError::ArithmeticError)?;
+            let organizer_amount = amount - platform_fee;
+
+            registry_client.increment_inventory(&event_id, &bundle.tier_id, &1);
+            update_event_balance(
+                &env,
+                event_id.clone(),
+                &token_address,
+                organizer_amount,
+                platform_fee,
+            );
+            append_ledger_entry(
+                &env,
+                event_id.clone(),
+                LedgerEntryType::Sale,
+                amount,
+                token_address.clone(),
+                buyer_address.clone(),
+            );
+            add_to_total_volume_processed(&env, amount);
+            add_to_total_fees_collected_by_token(&env, token_address.clone(), platform_fee);
+            add_to_tenant_fees_collected(
+                &env,
+                event_registry_addr.clone(),
+                token_address.clone(),
+                platform_fee,
+            );
+
+            store_payment(
+                &env,
+                Payment {
+                    payment_id: payment_id.clone(),
+                    event_id: event_id.clone(),
+                    buyer_address: buyer_address.clone(),
+                    ticket_tier_id: bundle.tier_id.clone(),
+                    amount,
+                    token_address: token_address.clone(),
+                    platform_fee,
+                    organizer_amount,
+                    status: PaymentStatus::Pending,
+                    transaction_hash: String::from_str(&env, ""),
+                    created_at,
+                    confirmed_at: None,
+                    refunded_amount: 0,
+                    identity_hash: None,
+                    memo: None,
+                    seat_id: None,
+                },
+            );
+            mint_ticket_nft(&env, &buyer_address, &event_id, &payment_id);
+
+            #[allow(deprecated)]
+            env.events().publish(
+                (
+                    AgoraEvent::PaymentProcessed,
+                    event_id.clone(),
+                    payment_id.clone(),
+                ),
+                PaymentProcessedEvent {
+                    payment_id,
+                    event_id,
+                    buyer_address: buyer_address.clone(),
+                    amount,
+                    platform_fee,
+                    timestamp: created_at,
+                },
+            );
+        }
+
+        set_bundle_purchase(
+            &env,
+            &BundlePurchase {
+                purchase_id: purchase_id.clone(),
+                bundle_id,
+                buyer: buyer_address,
+                payment_ids,
+                amount: bundle.price,
+                created_at,
+            },
+        );
+        Ok(purchase_id)
+    }
+
+    /// Sells a prepaid gift voucher worth `value` of `token_address`, paid by
+    /// `purchaser` and redeemable through `PurchaseOptions::voucher_code` until
+    /// `expires_at`. Only the SHA-256 hash of the code is stored.
+    pub fn mint_voucher(
+        env: Env,
+        purchaser: Address,
+        code_hash: BytesN<32>,
+        token_address: Address,
+        value: i128,
+        expires_at: u64,
+    ) -> Result<(), TicketPaymentError> {
+        if !is_initialized(&env) {
+            return Err(TicketPaymentError::NotInitialized);
+        }
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        purchaser.require_auth();
+        if !is_token_whitelisted(&env, &token_address) {
+            return Err(TicketPaymentError::TokenNotWhitelisted);
+        }
+        if value <= 0 {
+            panic!("Voucher value must be positive");
+        }
+        let created_at = env.ledger().timestamp();
+        if expires_at <= created_at {
+            panic!("Voucher expiry must be in the future");
+        }
+        if get_voucher(&env, code_hash.clone()).is_some() {
+            panic!("Voucher already exists");
+        }
+
+        // Voucher value stays outside event escrow until it pays for a ticket
+        let token_client = token::Client::new(&env, &token_address);
+        let contract_address = env.current_contract_address();
+        if token_client.allowance(&purchaser, &contract_address) < value {
+            return Err(TicketPaymentError::InsufficientAllowance);
+        }
+        token_client.transfer_from(&contract_address, &purchaser, &contract_address, &value);
+        add_held_funds(&env, token_address.clone(), value);
+
+        set_voucher(
+            &env,
+            &Voucher {
+                code_hash,
+                purchaser,
+                token: token_address,
+                value,
+                balance: value,
+                holder: None,
+                created_at,
+                expires_at,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the unspent balance of an expired voucher to its purchaser.
+    pub fn reclaim_voucher(env: Env, code_hash: BytesN<32>) -> Result<i128, TicketPaymentError> {
+        let mut voucher =
+            get_voucher(&env, code_hash).ok_or(TicketPaymentError::InvalidDiscountCode)?;
+        voucher.purchaser.require_auth();
+        if env.ledger().timestamp() < voucher.expires_at {
+            return Err(TicketPaymentError::RecoveryNotReady);
+        }
+        if voucher.balance <= 0 {
+            return Err(TicketPaymentError::NoFundsAvailable);
+        }
+        let amount = voucher.balance;
+        token::Client::new(&env, &voucher.token).transfer(
+            &env.current_contract_address(),
+            &voucher.purchaser,
+            &amount,
+        );
+        voucher.balance = 0;
+        set_voucher(&env, &voucher);
+        add_held_funds(&env, voucher.token, -amount);
+        Ok(amount)
+    }
+
+    /// Returns a voucher and its remaining balance, if any.
+    pub fn get_voucher(env: Env, code_hash: BytesN<32>) -> Option<Voucher> {
+        get_voucher(&env, code_hash)
+    }
+
+    /// Returns a bundle purchase and the payments it created, if any.
+    pub fn get_bundle_purchase(env: Env, purchase_id: String) -> Option<BundlePurchase> {
+        get_bundle_purchase(&env, purchase_id)
     }
 
     /// Confirms a payment after backend verification.
-    pub fn confirm_payment(env: Env, payment_id: String, transaction_hash: String) {
+    pub fn confirm_payment(
+        env: Env,
+        payment_id: String,
+        transaction_hash: String,
+    ) -> Result<(), TicketPaymentError> {
         if !is_initialized(&env) {
             panic!("Contract not initialized");
         }
         let admin = get_admin(&env).expect("Admin not set");
         admin.require_auth();
         // In a real scenario, this would be restricted to a specific backend/admin address.
+        let mut receipt_hash = None;
+        let mut event_id = None;
         if let Some(mut payment) = get_payment(&env, payment_id.clone()) {
+            if payment.status == PaymentStatus::Reserved {
+                panic!("Installment plan not fully paid");
+            }
+            // Points, rewards and the receipt are issued on the first confirmation only
+            if payment.status != PaymentStatus::Pending {
+                return Err(TicketPaymentError::InvalidPaymentStatus);
+            }
             payment.status = PaymentStatus::Confirmed;
             payment.confirmed_at = Some(env.ledger().timestamp());
             payment.transaction_hash = transaction_hash.clone();
-            store_payment(&env, payment);
+            store_payment(&env, payment.clone());
+            notify_rewards_purchase(&env, &payment);
+            accrue_loyalty_points(&env, &payment);
+            event_id = Some(payment.event_id.clone());
+
+            if let Some(terms) = get_purchase_terms(&env, payment_id.clone()) {
+                let receipt = Receipt {
+                    payment_id: payment.payment_id,
+                    event_id: payment.event_id,
+                    tier_id: payment.ticket_tier_id,
+                    buyer: payment.buyer_address,
+                    token: terms.token,
+                    quantity: terms.quantity,
+                    unit_amount: payment.amount,
+                    platform_fee: payment.platform_fee,
+                    organizer_amount: payment.organizer_amount,
+                    terms_hash: terms.terms_hash,
+                    confirmed_at: env.ledger().timestamp(),
+                };
+                receipt_hash = Some(receipt_hash_of(&env, &receipt));
+                set_receipt(&env, &receipt);
+            }
         }
 
         // Emit confirmation event
         #[allow(deprecated)]
         env.events().publish(
-            (AgoraEvent::PaymentStatusChanged,),
+            (
+                AgoraEvent::PaymentStatusChanged,
+                event_id,
+                payment_id.clone(),
+            ),
             PaymentStatusChangedEvent {
                 payment_id: payment_id.clone(),
                 old_status: PaymentStatus::Pending,
                 new_status: PaymentStatus::Confirmed,
                 transaction_hash: transaction_hash.clone(),
+                receipt_hash,
                 timestamp: env.ledger().timestamp(),
             },
         );
+        Ok(())
+    }
+
+    /// Sets the loyalty points earned per unit of `token` spent, in bps; 0 stops
+    /// accrual for the token. Only callable by admin.
+    pub fn set_loyalty_earn_rate(
+        env: Env,
+        token: Address,
+        earn_bps: u32,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        if earn_bps > MAX_BPS {
+            return Err(TicketPaymentError::InvalidFeeBps);
+        }
+        set_loyalty_earn_bps(&env, token, earn_bps);
+        Ok(())
+    }
+
+    /// Sets how many token units a loyalty point is worth at redemption, in bps.
+    /// Only callable by admin.
+    pub fn set_loyalty_point_value(env: Env, value_bps: u32) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        if value_bps == 0 || value_bps > MAX_BPS {
+            return Err(TicketPaymentError::InvalidFeeBps);
+        }
+        set_loyalty_point_value(&env, value_bps);
+        Ok(())
+    }
+
+    /// Returns a buyer's unspent loyalty points.
+    pub fn get_loyalty_balance(env: Env, buyer: Address) -> i128 {
+        get_loyalty_points(&env, buyer)
+    }
+
+    /// Redeems loyalty points against a payment still awaiting confirmation.
+    /// The points' value is returned to the buyer out of the payment's platform
+    /// fee, so the organizer's share is unaffected. Returns the rebate paid.
+    pub fn redeem_points(
+        env: Env,
+        payment_id: String,
+        points: i128,
+    ) -> Result<i128, TicketPaymentError> {
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        let mut payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        payment.buyer_address.require_auth();
+        if payment.status != PaymentStatus::Pending {
+            return Err(TicketPaymentError::InvalidPaymentStatus);
+        }
+        if points <= 0 {
+            panic!("Points must be positive");
+        }
+        let balance = get_loyalty_points(&env, payment.buyer_address.clone());
+        if points > balance {
+            return Err(TicketPaymentError::NoFundsAvailable);
+        }
+        let rebate = mul_div_bps(points, get_loyalty_point_value(&env))
+            .ok_or(TicketPaymentError::ArithmeticError)?;
+        if rebate > payment.platform_fee {
+            return Err(TicketPaymentError::InsufficientFees);
+        }
+
+        set_loyalty_points(&env, payment.buyer_address.clone(), balance - points);
+        let token_address = payment.token_address.clone();
+        token::Client::new(&env, &token_address).transfer(
+            &env.current_contract_address(),
+            &payment.buyer_address,
+            &rebate,
+        );
+        append_ledger_entry(
+            &env,
+            payment.event_id.clone(),
+            LedgerEntryType::LoyaltyRebate,
+            rebate,
+            token_address.clone(),
+            payment.buyer_address.clone(),
+        );
+        update_event_balance(&env, payment.event_id.clone(), &token_address, 0, -rebate);
+        // The rebate is platform fee the treasury no longer holds
+        subtract_from_total_fees_collected_by_token(&env, token_address.clone(), rebate);
+        add_to_tenant_fees_collected(
+            &env,
+            get_event_registry_for(&env, payment.event_id.clone()),
+            token_address.clone(),
+            -rebate,
+        );
+        subtract_from_active_escrow_total(&env, rebate);
+        subtract_from_active_escrow_by_token(&env, token_address, rebate);
+
+        payment.amount -= rebate;
+        payment.platform_fee -= rebate;
+        store_payment(&env, payment);
+        Ok(rebate)
+    }
+
+    /// Returns the receipt issued when a payment was confirmed, if any.
+    pub fn get_receipt(env: Env, payment_id: String) -> Option<Receipt> {
+        get_receipt(&env, payment_id)
     }
 
     pub fn request_guest_refund(env: Env, payment_id: String) -> Result<(), TicketPaymentError> {
@@ -669,7 +2245,7 @@ impl TicketPaymentContract {
             return Err(TicketPaymentError::ContractPaused);
         }
 
-        Self::internal_refund(env, payment_id)
+        Self::internal_refund(env, payment_id, true)
     }
 
     /// Triggers a refund as an administrator, regardless of dispute status.
@@ -677,7 +2253,7 @@ impl TicketPaymentContract {
         let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
         admin.require_auth();
 
-        Self::internal_refund(env, payment_id)
+        Self::internal_refund(env, payment_id, false)
     }
 
     /// Public wrapper for automatic refunds, specifically for cancelled events.
@@ -692,7 +2268,7 @@ impl TicketPaymentContract {
         let payment =
             get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
 
-        let event_registry_addr = get_event_registry(&env);
+        let event_registry_addr = get_event_registry_for(&env, payment.event_id.clone());
         let registry_client = event_registry::Client::new(&env, &event_registry_addr);
 
         let event_info = match registry_client.try_get_event(&payment.event_id) {
@@ -710,86 +2286,247 @@ impl TicketPaymentContract {
             return Err(TicketPaymentError::InvalidPaymentStatus);
         }
 
-        Self::internal_refund(env, payment_id)
+        Self::internal_refund(env, payment_id, false)
     }
 
-    fn internal_refund(env: Env, payment_id: String) -> Result<(), TicketPaymentError> {
-        let mut payment =
-            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
-
-        payment.buyer_address.require_auth();
+    /// Sets the platform refund processing fee charged on buyer-initiated refunds.
+    /// Only callable by admin. Cancellations and admin refunds are never charged.
+    pub fn set_refund_processing_fee(
+        env: Env,
+        bps: u32,
+        fixed_amount: i128,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
 
-        if payment.status == PaymentStatus::Refunded || payment.status == PaymentStatus::Failed {
-            return Err(TicketPaymentError::InvalidPaymentStatus);
+        if !is_valid_bps(bps) {
+            return Err(TicketPaymentError::InvalidFeeBps);
+        }
+        if fixed_amount < 0 {
+            return Err(TicketPaymentError::ArithmeticError);
         }
 
-        let event_registry_addr = get_event_registry(&env);
-        let registry_client = event_registry::Client::new(&env, &event_registry_addr);
+        set_refund_processing_fee(&env, &RefundProcessingFee { bps, fixed_amount });
+        Ok(())
+    }
 
-        let event_info = match registry_client.try_get_event(&payment.event_id) {
-            Ok(Ok(Some(info))) => info,
-            _ => return Err(TicketPaymentError::EventNotFound),
-        };
+    /// Returns the configured refund processing fee.
+    pub fn get_refund_processing_fee(env: Env) -> RefundProcessingFee {
+        get_refund_processing_fee(&env)
+    }
 
-        let tier = event_info
-            .tiers
-            .get(payment.ticket_tier_id.clone())
-            .ok_or(TicketPaymentError::TierNotFound)?;
+    /// Cumulative refund processing fees collected for a specific token.
+    /// These are also included in `get_total_fees_collected`.
+    pub fn get_refund_fees_collected(env: Env, token_address: Address) -> i128 {
+        get_refund_fees_collected(&env, token_address)
+    }
 
-        let is_cancelled = matches!(event_info.status, event_registry::EventStatus::Cancelled);
-        let current_ts = env.ledger().timestamp();
-        let goal_failed = !event_info.goal_met
-            && event_info.min_sales_target > 0
-            && current_ts > event_info.target_deadline;
+    /// Returns the flash-sale discount (bps) in effect for a tier at `now`, or 0.
+    /// Start and end events are emitted lazily by the first purchase that
+    /// observes each transition, so a sale needs no follow-up transaction.
+    fn resolve_promo_mode(
+        registry_client: &event_registry::Client,
+        event_id: &String,
+    ) -> event_registry::PromoMode {
+        // Registries without promo modes always share the promo.
+        match registry_client.try_get_promo_mode(event_id) {
+            Ok(Ok(mode)) => mode,
+            _ => event_registry::PromoMode::Shared,
+        }
+    }
 
-        // Check if refundable or if EVENT IS CANCELLED or GOAL FAILED
-        if !tier.is_refundable && !is_cancelled && !goal_failed && event_info.is_active {
-            return Err(TicketPaymentError::TicketNotRefundable);
+    /// Referrer share of the platform fee for a purchase of `event_id`.
+    fn resolve_referral_bps(env: &Env, event_id: &String) -> u32 {
+        if is_referral_opted_out(env, event_id.clone()) {
+            0
+        } else {
+            get_referral_bps(env)
         }
+    }
 
-        // Validate against refund deadline if event is active and not cancelled
-        if !is_cancelled
-            && event_info.is_active
-            && event_info.refund_deadline > 0
-            && env.ledger().timestamp() > event_info.refund_deadline
+    fn resolve_flash_sale(
+        env: &Env,
+        registry_client: &event_registry::Client,
+        event_id: &String,
+        tier_id: &String,
+        now: u64,
+    ) -> u32 {
+        // Registries without flash-sale support simply have no sale.
+        let sale = match registry_client.try_get_flash_sale(event_id, tier_id) {
+            Ok(Ok(Some(sale))) => sale,
+            _ => return 0,
+        };
+        if now < sale.start {
+            return 0;
+        }
+
+        let state = get_flash_sale_state(env, event_id.clone(), tier_id.clone())
+            .filter(|state| state.start == sale.start);
+        if now < sale.end {
+            if state.is_none() {
+                set_flash_sale_state(
+                    env,
+                    event_id.clone(),
+                    tier_id.clone(),
+                    FlashSaleState {
+                        start: sale.start,
+                        ended: false,
+                    },
+                );
+                #[allow(deprecated)]
+                env.events().publish(
+                    (AgoraEvent::FlashSaleStarted, event_id.clone()),
+                    FlashSaleStartedEvent {
+                        event_id: event_id.clone(),
+                        tier_id: tier_id.clone(),
+                        discount_bps: sale.discount_bps,
+                        end: sale.end,
+                        timestamp: now,
+                    },
+                );
+            }
+            return sale.discount_bps;
+        }
+
+        if let Some(mut state) = state {
+            if !state.ended {
+                state.ended = true;
+                set_flash_sale_state(env, event_id.clone(), tier_id.clone(), state);
+                #[allow(deprecated)]
+                env.events().publish(
+                    (AgoraEvent::FlashSaleEnded, event_id.clone()),
+                    FlashSaleEndedEvent {
+                        event_id: event_id.clone(),
+                        tier_id: tier_id.clone(),
+                        timestamp: now,
+                    },
+                );
+            }
+        }
+        0
+    }
+
+    fn internal_refund(
+        env: Env,
+        payment_id: String,
+        buyer_initiated: bool,
+    ) -> Result<(), TicketPaymentError> {
+        let mut payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+
+        payment.buyer_address.require_auth();
+
+        // Reserved tickets are unwound through `forfeit_installment_plan`
+        if payment.status == PaymentStatus::Refunded
+            || payment.status == PaymentStatus::Failed
+            || payment.status == PaymentStatus::Reserved
         {
-            return Err(TicketPaymentError::RefundDeadlinePassed);
+            return Err(TicketPaymentError::InvalidPaymentStatus);
+        }
+        if is_ticket_listed(&env, payment_id.clone()) {
+            return Err(TicketPaymentError::TicketListed);
+        }
+        if is_payment_disputed(&env, payment_id.clone()) {
+            return Err(TicketPaymentError::EventDisputed);
         }
 
-        // Deduct restocking fee if specified (capped at payment amount)
-        // Bypass restocking fee if the event is cancelled or goal failed.
-        let effective_restocking_fee = if is_cancelled || goal_failed {
-            0
-        } else if event_info.restocking_fee > payment.amount {
-            payment.amount
-        } else if event_info.restocking_fee > 0 {
-            event_info.restocking_fee
-        } else {
-            0
+        let event_registry_addr = get_event_registry_for(&env, payment.event_id.clone());
+        let registry_client = event_registry::Client::new(&env, &event_registry_addr);
+
+        let event_info = match registry_client.try_get_event(&payment.event_id) {
+            Ok(Ok(Some(info))) => info,
+            _ => return Err(TicketPaymentError::EventNotFound),
         };
 
-        let refund_amount = payment
+        let is_cancelled = matches!(event_info.status, event_registry::EventStatus::Cancelled);
+        let current_ts = env.ledger().timestamp();
+        let goal_failed = !event_info.goal_met
+            && event_info.min_sales_target > 0
+            && current_ts > event_info.target_deadline;
+        // Refunds in a postponement's grace window are owed to the guest like
+        // those of a cancelled event
+        let organizer_caused =
+            is_cancelled || goal_failed || in_postponement_grace(&env, &event_info);
+
+        // What the organizer keeps, recorded like a restocking fee
+        let effective_restocking_fee =
+            Self::refund_retention(&env, &registry_client, &event_info, &payment)?;
+
+        // Earlier partial refunds already came back out of the ticket's price
+        let net_of_restocking = payment
             .amount
             .checked_sub(effective_restocking_fee)
-            .ok_or(TicketPaymentError::ArithmeticError)?;
+            .and_then(|v| v.checked_sub(payment.refunded_amount))
+            .ok_or(TicketPaymentError::ArithmeticError)?
+            .max(0);
+
+        // Platform refund processing fee applies only to buyer-initiated refunds,
+        // never to cancellations, failed sales goals or postponements (capped at
+        // what remains).
+        let processing_fee = if buyer_initiated && !organizer_caused {
+            let config = get_refund_processing_fee(&env);
+            let fee = mul_div_bps(payment.amount, config.bps)
+                .and_then(|v| v.checked_add(config.fixed_amount))
+                .ok_or(TicketPaymentError::ArithmeticError)?;
+            core::cmp::min(fee, net_of_restocking)
+        } else {
+            0
+        };
+
+        // Commissions paid on the sale come back out of their recipients'
+        // unclaimed balances; any already claimed are not refunded again
+        let (commission_recovered, commission_withheld) =
+            reverse_ticket_commissions(&env, &payment);
+        let refund_amount = net_of_restocking
+            .checked_sub(processing_fee)
+            .and_then(|v| v.checked_sub(commission_withheld))
+            .ok_or(TicketPaymentError::ArithmeticError)?
+            .max(0);
+        if let Some(mut pool) = get_refund_pool(&env, payment.event_id.clone()) {
+            let from_escrow = (refund_amount - commission_recovered).max(0);
+            draw_refund_pool(&env, &mut pool, &payment.token_address, from_escrow)?;
+            set_refund_pool(&env, &pool);
+        }
 
         // Return ticket to inventory (increments available inventory)
         registry_client.decrement_inventory(&payment.event_id, &payment.ticket_tier_id);
+        if let Some(seat_id) = payment.seat_id.clone() {
+            registry_client.release_seat(&payment.event_id, &payment.ticket_tier_id, &seat_id);
+        }
 
         let old_status = payment.status.clone();
         payment.status = PaymentStatus::Refunded;
         payment.confirmed_at = Some(env.ledger().timestamp());
 
         store_payment(&env, payment.clone());
+        burn_ticket_nft(&env, &payment.payment_id);
 
         // Process token transfer
         if refund_amount > 0 {
-            let token_address = crate::storage::get_usdc_token(&env);
+            let token_address = payment.token_address.clone();
             token::Client::new(&env, &token_address).transfer(
                 &env.current_contract_address(),
                 &payment.buyer_address,
                 &refund_amount,
             );
+            append_ledger_entry(
+                &env,
+                payment.event_id.clone(),
+                LedgerEntryType::Refund,
+                refund_amount,
+                token_address,
+                payment.buyer_address.clone(),
+            );
+        }
+        if effective_restocking_fee > 0 {
+            append_ledger_entry(
+                &env,
+                payment.event_id.clone(),
+                LedgerEntryType::RestockingFee,
+                effective_restocking_fee,
+                payment.token_address.clone(),
+                payment.buyer_address.clone(),
+            );
         }
 
         // Guest receives payment.amount - effective_restocking_fee
@@ -804,33 +2541,64 @@ impl TicketPaymentContract {
         crate::storage::update_event_balance(
             &env,
             payment.event_id.clone(),
+            &payment.token_address,
             -org_adjustment,
             -platform_adjustment,
         );
 
         subtract_from_active_escrow_total(&env, refund_amount);
-        subtract_from_active_escrow_by_token(
-            &env,
-            crate::storage::get_usdc_token(&env),
-            refund_amount,
-        );
+        subtract_from_active_escrow_by_token(&env, payment.token_address.clone(), refund_amount);
+
+        // Processing fee stays in the contract as withdrawable platform revenue,
+        // so it no longer backs the ticket in escrow.
+        if processing_fee > 0 {
+            let token_address = payment.token_address.clone();
+            subtract_from_active_escrow_total(&env, processing_fee);
+            subtract_from_active_escrow_by_token(&env, token_address.clone(), processing_fee);
+            add_to_total_fees_collected_by_token(&env, token_address.clone(), processing_fee);
+            add_to_refund_fees_collected(&env, token_address, processing_fee);
+
+            #[allow(deprecated)]
+            env.events().publish(
+                (
+                    AgoraEvent::RefundFeeCollected,
+                    payment.event_id.clone(),
+                    payment_id.clone(),
+                ),
+                RefundFeeCollectedEvent {
+                    payment_id: payment_id.clone(),
+                    event_id: payment.event_id.clone(),
+                    fee_amount: processing_fee,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+
+        if buyer_initiated && !organizer_caused {
+            record_buyer_refund(&env, payment.event_id.clone());
+        }
 
         // Clear escrow record if both amounts are now zero (fully refunded event)
         let updated_balance = get_event_balance(&env, payment.event_id.clone());
         if updated_balance.organizer_amount == 0 && updated_balance.platform_fee == 0 {
             // Keep the record but ensure it's clean
-            update_event_balance(&env, payment.event_id.clone(), 0, 0);
+            update_event_balance(&env, payment.event_id.clone(), &payment.token_address, 0, 0);
         }
 
         // Emit confirmation event
         #[allow(deprecated)]
         env.events().publish(
-            (AgoraEvent::PaymentStatusChanged,),
+            (
+                AgoraEvent::PaymentStatusChanged,
+                payment.event_id.clone(),
+                payment_id.clone(),
+            ),
             PaymentStatusChangedEvent {
                 payment_id: payment_id.clone(),
                 old_status,
                 new_status: PaymentStatus::Refunded,
                 transaction_hash: String::from_str(&env, "refund"),
+                receipt_hash: None,
                 timestamp: env.ledger().timestamp(),
             },
         );
@@ -838,416 +2606,3775 @@ impl TicketPaymentContract {
         Ok(())
     }
 
-    pub fn get_payment_status(env: Env, payment_id: String) -> Option<Payment> {
-        get_payment(&env, payment_id)
-    }
+    /// What the organizer keeps if `payment` is refunded in full by its buyer
+    /// now, under the event's refund policy or its legacy refund fields, or why
+    /// the ticket cannot be refunded. Cancellations and failed sales goals keep
+    /// nothing.
+    fn refund_retention(
+        env: &Env,
+        registry_client: &event_registry::Client,
+        event_info: &event_registry::EventInfo,
+        payment: &Payment,
+    ) -> Result<i128, TicketPaymentError> {
+        let tier = event_info
+            .tiers
+            .get(payment.ticket_tier_id.clone())
+            .ok_or(TicketPaymentError::TierNotFound)?;
 
-    /// Verifies scanner authorization and marks a ticket as CheckedIn.
-    pub fn check_in(
-        env: Env,
-        payment_id: String,
-        scanner: Address,
-    ) -> Result<(), TicketPaymentError> {
-        if !is_initialized(&env) {
-            panic!("Contract not initialized");
-        }
-        if is_paused(&env) {
-            return Err(TicketPaymentError::ContractPaused);
-        }
+        let is_cancelled = matches!(event_info.status, event_registry::EventStatus::Cancelled);
+        let current_ts = env.ledger().timestamp();
+        let goal_failed = !event_info.goal_met
+            && event_info.min_sales_target > 0
+            && current_ts > event_info.target_deadline;
 
-        let mut payment =
-            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        // A postponed event refunds every guest in full until its grace period ends,
+        // whatever the tier rules and deadlines
+        if in_postponement_grace(env, event_info) {
+            return Ok(0);
+        }
 
-        // Must authenticate the scanner wallet calling this entry point
-        scanner.require_auth();
+        // An event's refund policy replaces its legacy refund fields while it is
+        // active; registries without refund policies only have the legacy fields.
+        let refund_policy = if is_cancelled || goal_failed || !event_info.is_active {
+            None
+        } else {
+            match registry_client.try_get_refund_policy(&payment.event_id) {
+                Ok(Ok(policy)) => policy,
+                _ => None,
+            }
+        };
+        if let Some(policy) = refund_policy {
+            return policy_retained_amount(&policy, payment.amount, current_ts);
+        }
 
-        if payment.status == PaymentStatus::CheckedIn {
-            return Err(TicketPaymentError::TicketAlreadyUsed);
+        // Check if refundable or if EVENT IS CANCELLED or GOAL FAILED
+        if !tier.is_refundable && !is_cancelled && !goal_failed && event_info.is_active {
+            return Err(TicketPaymentError::TicketNotRefundable);
         }
 
-        // Verify scanner authorization
-        let event_registry_addr = get_event_registry(&env);
-        let registry_client = event_registry::Client::new(&env, &event_registry_addr);
-        let is_auth = registry_client.is_scanner_authorized(&payment.event_id, &scanner);
-        if !is_auth {
-            return Err(TicketPaymentError::UnauthorizedScanner);
+        // Validate against refund deadline if event is active and not cancelled
+        if !is_cancelled
+            && event_info.is_active
+            && event_info.refund_deadline > 0
+            && current_ts > event_info.refund_deadline
+        {
+            return Err(TicketPaymentError::RefundDeadlinePassed);
         }
 
-        // Update status and store arrival timestamp
-        payment.status = PaymentStatus::CheckedIn;
-        payment.confirmed_at = Some(env.ledger().timestamp());
+        // Deduct restocking fee if specified (capped at payment amount)
+        // Bypass restocking fee if the event is cancelled or goal failed.
+        Ok(if is_cancelled || goal_failed {
+            0
+        } else {
+            event_info.restocking_fee.clamp(0, payment.amount)
+        })
+    }
 
-        store_payment(&env, payment.clone());
+    /// Refunds `percentage_bps` of a ticket's price to its buyer without giving
+    /// up the ticket. Only what a full refund would return right now can come
+    /// back, across all partial refunds of the ticket. Once the refunds reach
+    /// the void threshold the ticket is cancelled and returned to inventory.
+    pub fn request_partial_refund(
+        env: Env,
+        payment_id: String,
+        percentage_bps: u32,
+    ) -> Result<i128, TicketPaymentError> {
+        let payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        payment.buyer_address.require_auth();
 
-        #[allow(deprecated)]
-        env.events().publish(
-            (AgoraEvent::TicketCheckedIn,),
-            crate::events::TicketCheckedInEvent {
-                payment_id,
-                event_id: payment.event_id,
-                scanner,
-                timestamp: env.ledger().timestamp(),
-            },
+        let registry_client = event_registry::Client::new(
+            &env,
+            &get_event_registry_for(&env, payment.event_id.clone()),
         );
+        let event_info = match registry_client.try_get_event(&payment.event_id) {
+            Ok(Ok(Some(info))) => info,
+            _ => return Err(TicketPaymentError::EventNotFound),
+        };
+        let retained = Self::refund_retention(&env, &registry_client, &event_info, &payment)?;
+        let refundable = payment.amount - retained;
 
-        Ok(())
-    }
-
-    /// Returns the escrowed balance for an event.
-    pub fn get_event_escrow_balance(env: Env, event_id: String) -> crate::types::EventBalance {
-        get_event_balance(&env, event_id)
+        Self::apply_partial_refund(&env, &registry_client, payment, percentage_bps, refundable)
     }
 
-    /// Withdraw organizer funds from escrow.
-    pub fn withdraw_organizer_funds(
+    /// Refunds `percentage_bps` of a ticket's price to its buyer regardless of
+    /// the event's refund rules, e.g. as compensation. Only callable by admin.
+    /// Like `request_partial_refund`, the ticket stays valid until the refunds
+    /// reach the void threshold.
+    pub fn admin_partial_refund(
         env: Env,
-        event_id: String,
-        token_address: Address,
+        payment_id: String,
+        percentage_bps: u32,
     ) -> Result<i128, TicketPaymentError> {
-        let event_registry_addr = get_event_registry(&env);
-        let registry_client = event_registry::Client::new(&env, &event_registry_addr);
-        let event_info = registry_client
-            .try_get_event(&event_id)
-            .ok()
-            .and_then(|r| r.ok())
-            .flatten()
-            .ok_or(TicketPaymentError::EventNotFound)?;
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
 
-        event_info.organizer_address.require_auth();
+        let payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        let registry_client = event_registry::Client::new(
+            &env,
+            &get_event_registry_for(&env, payment.event_id.clone()),
+        );
+        let refundable = payment.amount;
 
-        let balance = get_event_balance(&env, event_id.clone());
-        // Block all claim_revenue attempts for an event while a dispute is active.
-        if is_event_disputed(&env, event_id.clone()) {
-            return Err(TicketPaymentError::EventDisputed);
-        }
+        Self::apply_partial_refund(&env, &registry_client, payment, percentage_bps, refundable)
+    }
 
-        // Block any further organizer payouts once an event is in the Cancelled state.
-        if matches!(event_info.status, event_registry::EventStatus::Cancelled) {
-            return Err(TicketPaymentError::EventCancelled);
-        }
+    /// Sets the share of a ticket's price, in basis points, that once refunded
+    /// through partial refunds voids the ticket. Only callable by admin.
+    pub fn set_partial_refund_threshold(
+        env: Env,
+        threshold_bps: u32,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
 
-        // Check if goal was met if a target was set
-        if event_info.min_sales_target > 0 && !event_info.goal_met {
-            return Err(TicketPaymentError::GoalNotMet);
+        if threshold_bps == 0 || !is_valid_bps(threshold_bps) {
+            panic!("Threshold must be between 1 and 10000 bps");
         }
+        set_partial_refund_void_threshold(&env, threshold_bps);
+        Ok(())
+    }
 
-        let total_revenue = balance
-            .organizer_amount
-            .checked_add(balance.total_withdrawn)
-            .ok_or(TicketPaymentError::ArithmeticError)?;
-        if total_revenue == 0 {
-            return Ok(0);
-        }
+    /// Returns the partial refund void threshold in basis points.
+    pub fn get_partial_refund_threshold(env: Env) -> u32 {
+        get_partial_refund_void_threshold(&env)
+    }
 
-        let mut release_percent = 10000u32;
-        if let Some(milestones) = event_info.milestone_plan {
-            let mut highest_met = 0u32;
-            for milestone in milestones.iter() {
-                if event_info.current_supply >= milestone.sales_threshold
-                    && milestone.release_percent > highest_met
-                {
-                    highest_met = milestone.release_percent;
-                }
-            }
-            if !milestones.is_empty() {
-                release_percent = highest_met;
-            }
+    /// Pays out a partial refund of `percentage_bps` of the ticket's price from
+    /// the organizer's share, keeping the ticket's cumulative refunds within
+    /// `refundable`, and voids the ticket once they reach the threshold.
+    fn apply_partial_refund(
+        env: &Env,
+        registry_client: &event_registry::Client,
+        payment: Payment,
+        percentage_bps: u32,
+        refundable: i128,
+    ) -> Result<i128, TicketPaymentError> {
+        if !is_initialized(env) {
+            panic!("Contract not initialized");
+        }
+        if is_paused(env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        if !is_valid_bps(percentage_bps) {
+            panic!("Percentage cannot exceed 100%");
+        }
+        if !matches!(
+            payment.status,
+            PaymentStatus::Pending | PaymentStatus::Confirmed | PaymentStatus::CheckedIn
+        ) {
+            return Err(TicketPaymentError::InvalidPaymentStatus);
+        }
+        if is_ticket_listed(env, payment.payment_id.clone()) {
+            return Err(TicketPaymentError::TicketListed);
+        }
+        if is_payment_disputed(env, payment.payment_id.clone()) {
+            return Err(TicketPaymentError::EventDisputed);
         }
 
-        let max_allowed = total_revenue
-            .checked_mul(release_percent as i128)
-            .and_then(|v| v.checked_div(10000))
+        let refund_amount = mul_div_bps(payment.amount, percentage_bps)
             .ok_or(TicketPaymentError::ArithmeticError)?;
-        let mut available_to_withdraw = max_allowed
-            .checked_sub(balance.total_withdrawn)
+        let refunded_total = payment
+            .refunded_amount
+            .checked_add(refund_amount)
             .ok_or(TicketPaymentError::ArithmeticError)?;
-
-        if available_to_withdraw <= 0 {
-            return Ok(0);
+        if refunded_total > refundable {
+            return Err(TicketPaymentError::TicketNotRefundable);
         }
-
-        if available_to_withdraw > balance.organizer_amount {
-            available_to_withdraw = balance.organizer_amount;
+        // Partial refunds come out of the organizer's share, as event-wide ones do
+        if refund_amount <= 0 || refund_amount > payment.organizer_amount {
+            return Err(TicketPaymentError::NoFundsAvailable);
         }
 
-        token::Client::new(&env, &token_address).transfer(
+        Self::pay_partial_refund(env, registry_client, payment, refund_amount)?;
+        Ok(refund_amount)
+    }
+
+    /// Pays `refund_amount` of a ticket's organizer share back to its buyer,
+    /// voiding the ticket once its cumulative refunds reach the threshold.
+    fn pay_partial_refund(
+        env: &Env,
+        registry_client: &event_registry::Client,
+        mut payment: Payment,
+        refund_amount: i128,
+    ) -> Result<(), TicketPaymentError> {
+        let refunded_total = payment
+            .refunded_amount
+            .checked_add(refund_amount)
+            .ok_or(TicketPaymentError::ArithmeticError)?;
+        let token_address = payment.token_address.clone();
+        if let Some(mut pool) = get_refund_pool(env, payment.event_id.clone()) {
+            draw_refund_pool(env, &mut pool, &token_address, refund_amount)?;
+            set_refund_pool(env, &pool);
+        }
+        token::Client::new(env, &token_address).transfer(
             &env.current_contract_address(),
-            &event_info.organizer_address,
-            &available_to_withdraw,
+            &payment.buyer_address,
+            &refund_amount,
+        );
+        append_ledger_entry(
+            env,
+            payment.event_id.clone(),
+            LedgerEntryType::Refund,
+            refund_amount,
+            token_address.clone(),
+            payment.buyer_address.clone(),
+        );
+        update_event_balance(
+            env,
+            payment.event_id.clone(),
+            &token_address,
+            -refund_amount,
+            0,
         );
+        subtract_from_active_escrow_total(env, refund_amount);
+        subtract_from_active_escrow_by_token(env, token_address, refund_amount);
 
-        crate::storage::set_event_balance(
-            &env,
-            event_id,
-            crate::types::EventBalance {
-                organizer_amount: balance
-                    .organizer_amount
-                    .checked_sub(available_to_withdraw)
-                    .ok_or(TicketPaymentError::ArithmeticError)?,
-                total_withdrawn: balance
-                    .total_withdrawn
-                    .checked_add(available_to_withdraw)
-                    .ok_or(TicketPaymentError::ArithmeticError)?,
-                platform_fee: balance.platform_fee,
+        payment.refunded_amount = refunded_total;
+        payment.organizer_amount -= refund_amount;
+        let void_at = mul_div_bps(payment.amount, get_partial_refund_void_threshold(env))
+            .ok_or(TicketPaymentError::ArithmeticError)?;
+        let voided = refunded_total >= void_at;
+        if voided {
+            registry_client.decrement_inventory(&payment.event_id, &payment.ticket_tier_id);
+            if let Some(seat_id) = payment.seat_id.clone() {
+                registry_client.release_seat(&payment.event_id, &payment.ticket_tier_id, &seat_id);
+            }
+            payment.status = PaymentStatus::Refunded;
+            payment.confirmed_at = Some(env.ledger().timestamp());
+            burn_ticket_nft(env, &payment.payment_id);
+        }
+        store_payment(env, payment.clone());
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (
+                AgoraEvent::PaymentPartiallyRefunded,
+                payment.event_id.clone(),
+                payment.payment_id.clone(),
+            ),
+            PaymentPartiallyRefundedEvent {
+                payment_id: payment.payment_id,
+                event_id: payment.event_id,
+                amount: refund_amount,
+                refunded_total,
+                voided,
+                timestamp: env.ledger().timestamp(),
             },
         );
-        subtract_from_active_escrow_total(&env, available_to_withdraw);
-        subtract_from_active_escrow_by_token(&env, token_address, available_to_withdraw);
 
-        Ok(available_to_withdraw)
+        Ok(())
     }
 
-    /// Settles platform fees from an event escrow into the global treasury pool.
-    pub fn settle_platform_fees(
+    pub fn get_payment_status(env: Env, payment_id: String) -> Option<Payment> {
+        get_payment(&env, payment_id)
+    }
+
+    /// Extends the storage TTL of a payment and the records kept alongside it
+    /// (index entries, purchase terms, receipt, deposit). Callable by anyone.
+    pub fn bump_payment(env: Env, payment_id: String) -> Result<(), TicketPaymentError> {
+        if !extend_payment_ttl(&env, payment_id) {
+            return Err(TicketPaymentError::PaymentNotFound);
+        }
+        Ok(())
+    }
+
+    /// Moves a ticket to a pricier tier of the same event. The buyer pays the
+    /// difference between the new tier's current price and what the ticket
+    /// cost, in the token the ticket was paid in; the old tier's inventory and
+    /// seat are released. Seated tiers cannot be upgraded into. Returns the
+    /// amount charged.
+    pub fn upgrade_ticket(
         env: Env,
-        event_id: String,
-        _token_address: Address,
+        payment_id: String,
+        new_tier_id: String,
     ) -> Result<i128, TicketPaymentError> {
-        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
-        admin.require_auth();
-
-        let balance = get_event_balance(&env, event_id.clone());
-        if balance.platform_fee == 0 {
-            return Ok(0);
+        let (mut payment, event_registry_addr, new_price, platform_fee_percent) =
+            Self::prepare_tier_change(&env, &payment_id, &new_tier_id)?;
+        let event_id = payment.event_id.clone();
+        let difference = new_price
+            .checked_sub(payment.amount)
+            .ok_or(TicketPaymentError::ArithmeticError)?;
+        if difference <= 0 {
+            return Err(TicketPaymentError::InvalidPrice);
         }
+        let platform_fee = mul_div_bps(difference, platform_fee_percent)
+            .ok_or(TicketPaymentError::ArithmeticError)?;
+        let organizer_amount = difference - platform_fee;
 
-        // We clarify that these are now "Settled" but they remain in the contract
-        // until a bulk withdrawal is made via `withdraw_platform_fees`.
-        crate::storage::set_event_balance(
+        let token_address = payment.token_address.clone();
+        collect_payment(&env, &token_address, &payment.buyer_address, difference)?;
+        update_event_balance(
             &env,
             event_id.clone(),
-            crate::types::EventBalance {
-                organizer_amount: balance.organizer_amount,
-                total_withdrawn: balance.total_withdrawn,
-                platform_fee: 0,
-            },
+            &token_address,
+            organizer_amount,
+            platform_fee,
         );
+        append_ledger_entry(
+            &env,
+            event_id,
+            LedgerEntryType::Sale,
+            difference,
+            token_address.clone(),
+            payment.buyer_address.clone(),
+        );
+        add_to_total_volume_processed(&env, difference);
+        add_to_total_fees_collected_by_token(&env, token_address.clone(), platform_fee);
+        add_to_tenant_fees_collected(&env, event_registry_addr, token_address, platform_fee);
+
+        payment.amount = new_price;
+        payment.platform_fee += platform_fee;
+        payment.organizer_amount += organizer_amount;
+        Self::finish_tier_change(&env, payment, new_tier_id);
+        Ok(difference)
+    }
 
-        // Emit settlement event
-        #[allow(deprecated)]
-        env.events().publish(
-            (AgoraEvent::FeeSettled,),
-            FeeSettledEvent {
-                event_id,
-                platform_wallet: get_platform_wallet(&env),
-                fee_amount: balance.platform_fee,
-                fee_bps: 0, // Not applicable here
-                timestamp: env.ledger().timestamp(),
-            },
+    /// Moves a ticket to a cheaper tier of the same event and refunds the
+    /// price difference, less the event's tier change fee, in the token the
+    /// ticket was paid in. The organizer keeps the change fee. Returns the
+    /// amount refunded.
+    pub fn downgrade_ticket(
+        env: Env,
+        payment_id: String,
+        new_tier_id: String,
+    ) -> Result<i128, TicketPaymentError> {
+        let (mut payment, _, new_price, platform_fee_percent) =
+            Self::prepare_tier_change(&env, &payment_id, &new_tier_id)?;
+        // Partially refunded tickets no longer carry their full price
+        if payment.refunded_amount > 0 {
+            return Err(TicketPaymentError::InvalidPaymentStatus);
+        }
+        let event_id = payment.event_id.clone();
+        let difference = payment
+            .amount
+            .checked_sub(new_price)
+            .ok_or(TicketPaymentError::ArithmeticError)?;
+        if difference <= 0 {
+            return Err(TicketPaymentError::InvalidPrice);
+        }
+        let platform_fee = mul_div_bps(difference, platform_fee_percent)
+            .ok_or(TicketPaymentError::ArithmeticError)?
+            .min(payment.platform_fee);
+        let organizer_amount = difference - platform_fee;
+        let change_fee = get_tier_change_fee(&env, event_id.clone()).min(difference);
+        let refund_amount = difference - change_fee;
+
+        let token_address = payment.token_address.clone();
+        if refund_amount > 0 {
+            token::Client::new(&env, &token_address).transfer(
+                &env.current_contract_address(),
+                &payment.buyer_address,
+                &refund_amount,
+            );
+            append_ledger_entry(
+                &env,
+                event_id.clone(),
+                LedgerEntryType::Refund,
+                refund_amount,
+                token_address.clone(),
+                payment.buyer_address.clone(),
+            );
+            subtract_from_active_escrow_total(&env, refund_amount);
+            subtract_from_active_escrow_by_token(&env, token_address.clone(), refund_amount);
+        }
+        if change_fee > 0 {
+            append_ledger_entry(
+                &env,
+                event_id.clone(),
+                LedgerEntryType::RestockingFee,
+                change_fee,
+                token_address,
+                payment.buyer_address.clone(),
+            );
+        }
+        // The change fee stays in escrow as organizer revenue, outside the ticket
+        update_event_balance(
+            &env,
+            event_id,
+            &payment.token_address,
+            change_fee - organizer_amount,
+            -platform_fee,
         );
 
-        Ok(balance.platform_fee)
+        payment.amount = new_price;
+        payment.platform_fee -= platform_fee;
+        payment.organizer_amount -= organizer_amount;
+        Self::finish_tier_change(&env, payment, new_tier_id);
+        Ok(refund_amount)
     }
 
-    /// Withdraw accumulated platform fees from the contract treasury.
-    /// Incorporates a daily withdrawal cap and requires admin (multi-sig) authorization.
-    pub fn withdraw_platform_fees(
+    /// Sets the fee withheld from the refund of a ticket downgrade. Only
+    /// callable by the organizer.
+    pub fn set_tier_change_fee(
         env: Env,
+        event_id: String,
         amount: i128,
-        token_address: Address,
     ) -> Result<(), TicketPaymentError> {
-        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
-        admin.require_auth();
+        let event_registry_addr = get_event_registry_for(&env, event_id.clone());
+        let registry_client = event_registry::Client::new(&env, &event_registry_addr);
+        let event_info = match registry_client.try_get_event(&event_id) {
+            Ok(Ok(Some(info))) => info,
+            _ => return Err(TicketPaymentError::EventNotFound),
+        };
 
-        if amount <= 0 {
-            return Err(TicketPaymentError::ArithmeticError);
+        event_info.organizer_address.require_auth();
+        record_organizer_activity(&env, event_id.clone());
+
+        if amount < 0 {
+            panic!("Tier change fee must be non-negative");
         }
+        set_tier_change_fee(&env, event_id, amount);
+        Ok(())
+    }
 
-        // 1. Verify that the amount requested is less than or equal to the recorded total_fees_collected.
-        let total_accumulated = get_total_fees_collected_by_token(&env, token_address.clone());
-        if amount > total_accumulated {
-            return Err(TicketPaymentError::InsufficientFees);
+    /// Returns the fee withheld from the refund of a ticket downgrade.
+    pub fn get_tier_change_fee(env: Env, event_id: String) -> i128 {
+        get_tier_change_fee(&env, event_id)
+    }
+
+    /// Validates a move of `payment_id` into `new_tier_id` and moves one unit
+    /// of inventory, and any seat, off the old tier. Returns the payment, the
+    /// event's registry, the new tier's price in the payment's token and the
+    /// event's platform fee.
+    fn prepare_tier_change(
+        env: &Env,
+        payment_id: &String,
+        new_tier_id: &String,
+    ) -> Result<(Payment, Address, i128, u32), TicketPaymentError> {
+        if !is_initialized(env) {
+            panic!("Contract not initialized");
+        }
+        if is_paused(env) {
+            return Err(TicketPaymentError::ContractPaused);
         }
 
-        // 2. Incorporate a 'Withdrawal Cap' per day.
-        let cap = get_withdrawal_cap(&env, token_address.clone());
-        if cap > 0 {
-            let current_day = env.ledger().timestamp() / 86400;
-            let already_withdrawn =
-                get_daily_withdrawn_amount(&env, token_address.clone(), current_day);
-            if already_withdrawn
-                .checked_add(amount)
-                .ok_or(TicketPaymentError::ArithmeticError)?
-                > cap
-            {
-                return Err(TicketPaymentError::WithdrawalCapExceeded);
-            }
-            add_to_daily_withdrawn_amount(&env, token_address.clone(), current_day, amount);
+        let payment =
+            get_payment(env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        payment.buyer_address.require_auth();
+
+        if payment.status != PaymentStatus::Pending && payment.status != PaymentStatus::Confirmed {
+            return Err(TicketPaymentError::InvalidPaymentStatus);
+        }
+        if is_ticket_listed(env, payment_id.clone()) {
+            return Err(TicketPaymentError::TicketListed);
+        }
+        if is_payment_disputed(env, payment_id.clone()) {
+            return Err(TicketPaymentError::EventDisputed);
+        }
+        if *new_tier_id == payment.ticket_tier_id {
+            panic!("Ticket is already in this tier");
         }
 
-        // 3. Process the transfer
-        let platform_wallet = get_platform_wallet(&env);
-        token::Client::new(&env, &token_address).transfer(
-            &env.current_contract_address(),
-            &platform_wallet,
-            &amount,
-        );
+        let event_id = payment.event_id.clone();
+        let event_registry_addr = get_event_registry_for(env, event_id.clone());
+        let registry_client = event_registry::Client::new(env, &event_registry_addr);
+        let event_info = match registry_client.try_get_event(&event_id) {
+            Ok(Ok(Some(info))) => info,
+            _ => return Err(TicketPaymentError::EventNotFound),
+        };
+        if !event_info.is_active
+            || matches!(event_info.status, event_registry::EventStatus::Cancelled)
+        {
+            return Err(TicketPaymentError::EventInactive);
+        }
 
-        // 4. Update global accounting
-        subtract_from_total_fees_collected_by_token(&env, token_address.clone(), amount);
-        subtract_from_active_escrow_total(&env, amount);
-        subtract_from_active_escrow_by_token(&env, token_address, amount);
+        let tier = event_info
+            .tiers
+            .get(new_tier_id.clone())
+            .ok_or(TicketPaymentError::TierNotFound)?;
+        let now = env.ledger().timestamp();
+        if (tier.visible_from > 0 && now < tier.visible_from)
+            || (tier.visible_until > 0 && now >= tier.visible_until)
+        {
+            return Err(TicketPaymentError::TierNotVisible);
+        }
+        if matches!(
+            registry_client.try_get_seat_map(&event_id, new_tier_id),
+            Ok(Ok(Some(_)))
+        ) {
+            panic!("Seated tiers cannot be changed into");
+        }
 
-        Ok(())
+        let (new_price, _) = quote_tier_price(env, &tier, &payment.token_address, now)?;
+        let platform_fee_percent = registry_client
+            .get_event_payment_info(&event_id)
+            .platform_fee_percent;
+
+        // The registry rejects the new tier if it is sold out, aborting the change
+        registry_client.increment_inventory(&event_id, new_tier_id, &1);
+        registry_client.decrement_inventory(&event_id, &payment.ticket_tier_id);
+        if let Some(seat_id) = payment.seat_id.clone() {
+            registry_client.release_seat(&event_id, &payment.ticket_tier_id, &seat_id);
+        }
+
+        Ok((
+            payment,
+            event_registry_addr,
+            new_price,
+            platform_fee_percent,
+        ))
     }
 
-    /// Sets a daily withdrawal cap for a specific token.
-    pub fn set_withdrawal_cap(
+    /// Stores a re-priced payment under its new tier and announces the change.
+    fn finish_tier_change(env: &Env, mut payment: Payment, new_tier_id: String) {
+        payment.ticket_tier_id = new_tier_id;
+        payment.seat_id = None;
+        store_payment(env, payment.clone());
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (
+                AgoraEvent::PaymentStatusChanged,
+                payment.event_id.clone(),
+                payment.payment_id.clone(),
+            ),
+            PaymentStatusChangedEvent {
+                payment_id: payment.payment_id,
+                old_status: payment.status.clone(),
+                new_status: payment.status,
+                transaction_hash: String::from_str(env, "tier_change"),
+                receipt_hash: None,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Verifies scanner authorization and marks a ticket as CheckedIn. Scanners
+    /// with a time-boxed authorization are rejected outside their window.
+    /// A checked-out ticket of an event allowing re-entry is admitted again.
+    /// Tickets with a check-in commitment must use `check_in_with_proof`.
+    pub fn check_in(
         env: Env,
-        token: Address,
-        amount: i128,
+        payment_id: String,
+        scanner: Address,
     ) -> Result<(), TicketPaymentError> {
-        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
-        admin.require_auth();
+        // Committed tickets are only admitted with a proof
+        if get_checkin_commitment(&env, payment_id.clone()).is_some() {
+            return Err(TicketPaymentError::InvalidCheckInProof);
+        }
+        Self::admit_ticket(env, payment_id, scanner)
+    }
 
-        if amount < 0 {
-            return Err(TicketPaymentError::ArithmeticError);
+    /// Check in a ticket whose holder committed to a hash chain with
+    /// `set_check_in_commitment`. The scanner submits the preimage of the current
+    /// commitment, which then becomes the commitment for the next entry, so a
+    /// captured code cannot be replayed.
+    pub fn check_in_with_proof(
+        env: Env,
+        payment_id: String,
+        scanner: Address,
+        preimage: BytesN<32>,
+    ) -> Result<(), TicketPaymentError> {
+        let commitment = get_checkin_commitment(&env, payment_id.clone())
+            .ok_or(TicketPaymentError::InvalidCheckInProof)?;
+        let hash: BytesN<32> = env.crypto().sha256(&preimage.clone().into()).into();
+        if hash != commitment {
+            return Err(TicketPaymentError::InvalidCheckInProof);
         }
+        Self::admit_ticket(env.clone(), payment_id.clone(), scanner)?;
+        set_checkin_commitment(&env, payment_id, Some(preimage));
+        Ok(())
+    }
 
-        set_withdrawal_cap(&env, token, amount);
+    /// Requires check-ins of a ticket to prove possession: `commitment` is the
+    /// last link `sha256^n(seed)` of a hash chain whose earlier links the holder's
+    /// wallet shows at the gate, newest first. `None` returns the ticket to plain
+    /// scanner check-in. Only callable by the holder; cleared when it changes hands.
+    pub fn set_check_in_commitment(
+        env: Env,
+        payment_id: String,
+        commitment: Option<BytesN<32>>,
+    ) -> Result<(), TicketPaymentError> {
+        let payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        payment.buyer_address.require_auth();
+        if !matches!(
+            payment.status,
+            PaymentStatus::Pending | PaymentStatus::Confirmed | PaymentStatus::CheckedIn
+        ) {
+            return Err(TicketPaymentError::InvalidPaymentStatus);
+        }
+        set_checkin_commitment(&env, payment_id, commitment);
         Ok(())
     }
 
-    /// Claim revenue after event completion.
-    pub fn claim_revenue(
+    /// Returns the hash a ticket's next check-in proof must preimage, if any.
+    pub fn get_check_in_commitment(env: Env, payment_id: String) -> Option<BytesN<32>> {
+        get_checkin_commitment(&env, payment_id)
+    }
+
+    fn admit_ticket(
         env: Env,
-        event_id: String,
-        token_address: Address,
-    ) -> Result<i128, TicketPaymentError> {
+        payment_id: String,
+        scanner: Address,
+    ) -> Result<(), TicketPaymentError> {
+        if !is_initialized(&env) {
+            panic!("Contract not initialized");
+        }
         if is_paused(&env) {
             return Err(TicketPaymentError::ContractPaused);
         }
-        let event_registry_addr = get_event_registry(&env);
-        let registry_client = event_registry::Client::new(&env, &event_registry_addr);
 
-        let event_info = registry_client
-            .try_get_event(&event_id)
-            .ok()
-            .and_then(|r| r.ok())
-            .flatten()
-            .ok_or(TicketPaymentError::EventNotFound)?;
+        let mut payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
 
-        event_info.organizer_address.require_auth();
+        // Must authenticate the scanner wallet calling this entry point
+        scanner.require_auth();
 
-        if event_info.is_active {
-            return Err(TicketPaymentError::EventNotCompleted);
+        let reentering = payment.status == PaymentStatus::CheckedIn;
+        if reentering
+            && !(is_reentry_allowed(&env, payment.event_id.clone())
+                && is_checked_out(&env, payment_id.clone()))
+        {
+            return Err(TicketPaymentError::TicketAlreadyUsed);
         }
-
-        // Check if goal was met if a target was set
-        if event_info.min_sales_target > 0 && !event_info.goal_met {
-            return Err(TicketPaymentError::GoalNotMet);
+        if payment.status == PaymentStatus::Reserved {
+            return Err(TicketPaymentError::InvalidPaymentStatus);
         }
-
-        let balance = get_event_balance(&env, event_id.clone());
-        if balance.organizer_amount == 0 && balance.platform_fee == 0 {
-            return Err(TicketPaymentError::NoFundsAvailable);
+        if is_ticket_listed(&env, payment_id.clone()) {
+            return Err(TicketPaymentError::TicketListed);
         }
 
-        let platform_wallet = get_platform_wallet(&env);
-        let token_client = token::Client::new(&env, &token_address);
-        let contract_address = env.current_contract_address();
-        let timestamp = env.ledger().timestamp();
+        require_authorized_scanner(&env, &payment.event_id, &scanner)?;
+        append_scan_record(&env, payment_id.clone(), scanner.clone(), true);
+        record_checkin_stats(
+            &env,
+            payment.event_id.clone(),
+            payment.ticket_tier_id.clone(),
+            scanner.clone(),
+            reentering,
+        );
 
-        let platform_fee_amount = balance.platform_fee;
-        let organizer_amount = balance.organizer_amount;
+        if reentering {
+            // Attendance, rewards and proofs were granted on the first entry
+            set_checked_out(&env, payment_id.clone(), false);
+        } else {
+            // Update status and store arrival timestamp
+            payment.status = PaymentStatus::CheckedIn;
+            payment.confirmed_at = Some(env.ledger().timestamp());
 
-        // Settlement logic: platform fees stay in the contract but are cleared from EventBalance.
-        // They are already tracked in TotalFeesCollected.
-        if platform_fee_amount > 0 {
-            #[allow(deprecated)]
-            env.events().publish(
-                (AgoraEvent::FeeSettled,),
-                FeeSettledEvent {
-                    event_id: event_id.clone(),
-                    platform_wallet: platform_wallet.clone(),
-                    fee_amount: platform_fee_amount,
-                    fee_bps: event_info.platform_fee_percent,
-                    timestamp,
+            store_payment(&env, payment.clone());
+            // A delegate attends in the holder's place; the holder keeps the ticket
+            let attendee = get_ticket_delegate(&env, payment_id.clone())
+                .unwrap_or_else(|| payment.buyer_address.clone());
+            record_attendance(
+                &env,
+                payment.event_id.clone(),
+                AttendanceRecord {
+                    payment_id: payment_id.clone(),
+                    attendee: attendee.clone(),
+                    checked_in_at: env.ledger().timestamp(),
                 },
             );
+            notify_rewards_check_in(&env, &payment);
+            mint_attendance_token(&env, &payment, &attendee);
+        }
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (
+                AgoraEvent::TicketCheckedIn,
+                payment.event_id.clone(),
+                payment_id.clone(),
+            ),
+            crate::events::TicketCheckedInEvent {
+                payment_id,
+                event_id: payment.event_id,
+                scanner,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Returns a page of the event's check-ins, in check-in order.
+    /// At most `MAX_PAGE_SIZE` records are returned per call.
+    pub fn get_event_attendance(
+        env: Env,
+        event_id: String,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<AttendanceRecord> {
+        get_attendance(&env, event_id, PageCursor::new(offset, limit))
+    }
+
+    /// Returns an event's check-in counters in total, per tier and per scanner,
+    /// to watch door throughput and reconcile entries against tickets sold.
+    pub fn get_checkin_stats(env: Env, event_id: String) -> CheckInStats {
+        get_checkin_stats(&env, event_id)
+    }
+
+    /// Returns how many tickets have been checked in for an event.
+    pub fn get_event_attendance_count(env: Env, event_id: String) -> u32 {
+        get_attendance_count(&env, event_id)
+    }
+
+    /// Returns the events a holder has checked into, oldest first.
+    pub fn get_attended_events(env: Env, holder: Address) -> Vec<String> {
+        get_attended_events(&env, holder)
+    }
+
+    /// Registers (or with `None`, removes) the contract asked to mint a
+    /// proof-of-attendance token on each check-in. Only callable by the organizer.
+    pub fn set_attendance_minter(
+        env: Env,
+        event_id: String,
+        minter: Option<Address>,
+    ) -> Result<(), TicketPaymentError> {
+        let event_registry_addr = get_event_registry_for(&env, event_id.clone());
+        let registry_client = event_registry::Client::new(&env, &event_registry_addr);
+        let event_info = match registry_client.try_get_event(&event_id) {
+            Ok(Ok(Some(info))) => info,
+            _ => return Err(TicketPaymentError::EventNotFound),
+        };
+
+        event_info.organizer_address.require_auth();
+        record_organizer_activity(&env, event_id.clone());
+        set_attendance_minter(&env, event_id, minter);
+        Ok(())
+    }
+
+    /// Returns the event's proof-of-attendance minter, if any.
+    pub fn get_attendance_minter(env: Env, event_id: String) -> Option<Address> {
+        get_attendance_minter(&env, event_id)
+    }
+
+    /// Records a checked-in holder leaving the venue so the ticket can be
+    /// scanned in again. Only available for events that allow re-entry.
+    pub fn check_out(
+        env: Env,
+        payment_id: String,
+        scanner: Address,
+    ) -> Result<(), TicketPaymentError> {
+        if !is_initialized(&env) {
+            panic!("Contract not initialized");
+        }
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+
+        let payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        scanner.require_auth();
+
+        if payment.status != PaymentStatus::CheckedIn
+            || is_checked_out(&env, payment_id.clone())
+            || !is_reentry_allowed(&env, payment.event_id.clone())
+        {
+            return Err(TicketPaymentError::InvalidPaymentStatus);
+        }
+        require_authorized_scanner(&env, &payment.event_id, &scanner)?;
+
+        append_scan_record(&env, payment_id.clone(), scanner, false);
+        set_checked_out(&env, payment_id, true);
+        Ok(())
+    }
+
+    /// Returns every gate scan of a ticket, oldest first.
+    pub fn get_scan_history(env: Env, payment_id: String) -> Vec<ScanRecord> {
+        get_scan_history(&env, payment_id)
+    }
+
+    /// Allows or forbids checked-in holders to leave and re-enter the event.
+    /// Only callable by the organizer.
+    pub fn set_allow_reentry(
+        env: Env,
+        event_id: String,
+        allowed: bool,
+    ) -> Result<(), TicketPaymentError> {
+        let event_registry_addr = get_event_registry_for(&env, event_id.clone());
+        let registry_client = event_registry::Client::new(&env, &event_registry_addr);
+        let event_info = match registry_client.try_get_event(&event_id) {
+            Ok(Ok(Some(info))) => info,
+            _ => return Err(TicketPaymentError::EventNotFound),
+        };
+
+        event_info.organizer_address.require_auth();
+        record_organizer_activity(&env, event_id.clone());
+        set_reentry_allowed(&env, event_id, allowed);
+        Ok(())
+    }
+
+    /// Returns whether the event allows re-entry.
+    pub fn is_reentry_allowed(env: Env, event_id: String) -> bool {
+        is_reentry_allowed(&env, event_id)
+    }
+
+    /// Check in a ticket whose holder presented an identity claim at the gate.
+    /// The hash of the presented claim must match the binding recorded at purchase.
+    pub fn check_in_with_identity(
+        env: Env,
+        payment_id: String,
+        scanner: Address,
+        identity_hash: BytesN<32>,
+    ) -> Result<(), TicketPaymentError> {
+        let payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        if payment.identity_hash != Some(identity_hash) {
+            return Err(TicketPaymentError::IdentityMismatch);
+        }
+
+        Self::check_in(env, payment_id, scanner)
+    }
+
+    /// Returns a page of the event's escrow ledger, oldest entry first.
+    /// At most `MAX_PAGE_SIZE` entries are returned per call.
+    pub fn get_event_ledger(
+        env: Env,
+        event_id: String,
+        start: u32,
+        limit: u32,
+    ) -> Vec<LedgerEntry> {
+        get_event_ledger(&env, event_id, PageCursor::new(start, limit))
+    }
+
+    /// Aggregates the event's escrow ledger per token, in order of each token's
+    /// first entry. Built solely from ledger entries, so the figures reconcile
+    /// to the unit with the transfers the contract made.
+    pub fn get_event_settlement_report(env: Env, event_id: String) -> Vec<SettlementReportLine> {
+        let mut lines: Vec<SettlementReportLine> = Vec::new(&env);
+        let count = get_event_ledger_count(&env, event_id.clone());
+        let mut offset = 0;
+        while offset < count {
+            let page = get_event_ledger(&env, event_id.clone(), PageCursor::new(offset, count));
+            if page.is_empty() {
+                break;
+            }
+            offset += page.len();
+            for entry in page.iter() {
+                let index = match lines.iter().position(|line| line.token == entry.token) {
+                    Some(index) => index as u32,
+                    None => {
+                        lines.push_back(SettlementReportLine {
+                            token: entry.token.clone(),
+                            gross_sales: 0,
+                            discounts_granted: 0,
+                            promo_absorbed: 0,
+                            refunds_paid: 0,
+                            restocking_retained: 0,
+                            fees_settled: 0,
+                            royalties: 0,
+                            transfer_fees: 0,
+                            promoter_commissions: 0,
+                            net_to_organizer: 0,
+                        });
+                        lines.len() - 1
+                    }
+                };
+                let mut line = lines.get_unchecked(index);
+                let amount = entry.amount;
+                match entry.entry_type {
+                    LedgerEntryType::Sale => line.gross_sales += amount,
+                    LedgerEntryType::Discount => {
+                        line.gross_sales += amount;
+                        line.discounts_granted += amount;
+                    }
+                    LedgerEntryType::PromoAbsorbed => line.promo_absorbed += amount,
+                    LedgerEntryType::Refund => line.refunds_paid += amount,
+                    LedgerEntryType::RestockingFee => line.restocking_retained += amount,
+                    LedgerEntryType::FeeSettlement => line.fees_settled += amount,
+                    LedgerEntryType::ResaleRoyalty => line.royalties += amount,
+                    LedgerEntryType::TransferRoyalty => line.transfer_fees += amount,
+                    LedgerEntryType::PromoterCommission => line.promoter_commissions += amount,
+                    LedgerEntryType::LoyaltyRebate => line.discounts_granted += amount,
+                    LedgerEntryType::Withdrawal => line.net_to_organizer += amount,
+                }
+                lines.set(index, line);
+            }
+        }
+        lines
+    }
+
+    /// Returns the escrowed balance for an event.
+    pub fn get_event_escrow_balance(env: Env, event_id: String) -> crate::types::EventBalance {
+        get_event_balance(&env, event_id)
+    }
+
+    /// Extends the storage TTL of an event's escrow balance and payment index
+    /// so funds held for long-lived events are not archived. Callable by anyone.
+    pub fn bump_event(env: Env, event_id: String) {
+        extend_event_ttl(&env, event_id);
+    }
+
+    /// Withdraw organizer funds from escrow.
+    pub fn withdraw_organizer_funds(
+        env: Env,
+        event_id: String,
+        token_address: Address,
+    ) -> Result<i128, TicketPaymentError> {
+        let event_registry_addr = get_event_registry_for(&env, event_id.clone());
+        let registry_client = event_registry::Client::new(&env, &event_registry_addr);
+        let event_info = registry_client
+            .try_get_event(&event_id)
+            .ok()
+            .and_then(|r| r.ok())
+            .flatten()
+            .ok_or(TicketPaymentError::EventNotFound)?;
+
+        event_info.organizer_address.require_auth();
+        record_organizer_activity(&env, event_id.clone());
+
+        let balance = get_event_token_balance(&env, event_id.clone(), &token_address);
+        // Block all claim_revenue attempts for an event while a dispute is active.
+        if is_event_disputed(&env, event_id.clone()) {
+            return Err(TicketPaymentError::EventDisputed);
+        }
+
+        // Block any further organizer payouts once an event is in the Cancelled state.
+        if matches!(event_info.status, event_registry::EventStatus::Cancelled) {
+            return Err(TicketPaymentError::EventCancelled);
+        }
+
+        // Check if goal was met if a target was set
+        if event_info.min_sales_target > 0 && !event_info.goal_met {
+            return Err(TicketPaymentError::GoalNotMet);
+        }
+
+        // Shares of payments under arbitration stay in escrow
+        let available_to_withdraw = withdrawable_amount(&event_info, &balance)?
+            .min(balance.organizer_amount - get_frozen_dispute_amount(&env, event_id.clone()));
+        if available_to_withdraw <= 0 {
+            return Ok(0);
+        }
+
+        // In auto-accumulate mode funds stay in escrow until the threshold is met
+        if let Some(config) = get_payout_config(&env, &event_info.organizer_address) {
+            if config.auto_accumulate && available_to_withdraw < config.min_withdrawal {
+                return Ok(0);
+            }
+        }
+
+        token::Client::new(&env, &token_address).transfer(
+            &env.current_contract_address(),
+            &event_info.organizer_address,
+            &available_to_withdraw,
+        );
+        append_ledger_entry(
+            &env,
+            event_id.clone(),
+            LedgerEntryType::Withdrawal,
+            available_to_withdraw,
+            token_address.clone(),
+            event_info.organizer_address.clone(),
+        );
+
+        record_event_withdrawal(
+            &env,
+            event_id.clone(),
+            &token_address,
+            available_to_withdraw,
+        );
+        subtract_from_active_escrow_total(&env, available_to_withdraw);
+        subtract_from_active_escrow_by_token(&env, token_address, available_to_withdraw);
+        // The next accumulation cycle notifies again
+        set_threshold_notified(&env, event_id, false);
+
+        Ok(available_to_withdraw)
+    }
+
+    /// Sets the caller's payout preferences across all their events, or clears
+    /// them with `None`. Once an event's withdrawable amount reaches
+    /// `min_withdrawal`, a `WithdrawableThresholdReached` event is emitted once
+    /// per withdrawal cycle. With `auto_accumulate`, `withdraw_organizer_funds`
+    /// pays nothing until that amount is available.
+    pub fn set_payout_config(
+        env: Env,
+        organizer: Address,
+        config: Option<PayoutConfig>,
+    ) -> Result<(), TicketPaymentError> {
+        organizer.require_auth();
+        if let Some(config) = &config {
+            if config.min_withdrawal <= 0 {
+                return Err(TicketPaymentError::InvalidPrice);
+            }
+        }
+        set_payout_config(&env, &organizer, config);
+        Ok(())
+    }
+
+    /// Returns an organizer's payout preferences, if any.
+    pub fn get_payout_config(env: Env, organizer: Address) -> Option<PayoutConfig> {
+        get_payout_config(&env, &organizer)
+    }
+
+    /// Settles platform fees from an event escrow into the global treasury pool.
+    pub fn settle_platform_fees(
+        env: Env,
+        event_id: String,
+        token_address: Address,
+    ) -> Result<i128, TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+
+        // We clarify that these are now "Settled" but they remain in the contract
+        // until a bulk withdrawal is made via `withdraw_platform_fees`.
+        let fee_amount = take_event_platform_fee(&env, event_id.clone(), &token_address);
+        if fee_amount == 0 {
+            return Ok(0);
+        }
+
+        let platform_wallet = get_treasury_wallet(&env, token_address.clone());
+        append_ledger_entry(
+            &env,
+            event_id.clone(),
+            LedgerEntryType::FeeSettlement,
+            fee_amount,
+            token_address.clone(),
+            platform_wallet.clone(),
+        );
+        let invoice_id = record_fee_settlement(&env, event_id.clone(), fee_amount, token_address);
+
+        // Emit settlement event
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::FeeSettled, event_id.clone()),
+            FeeSettledEvent {
+                event_id,
+                platform_wallet,
+                fee_amount,
+                fee_bps: 0, // Not applicable here
+                invoice_id,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(fee_amount)
+    }
+
+    /// Settles platform fees for up to `limit` events of the fee-event index,
+    /// starting at position `start`. Returns the amount settled per token;
+    /// callers page through the index with `get_fee_event_count`.
+    pub fn settle_all_platform_fees(
+        env: Env,
+        start: u32,
+        limit: u32,
+    ) -> Result<Map<Address, i128>, TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+
+        let end = start
+            .saturating_add(limit.min(MAX_SETTLEMENT_BATCH))
+            .min(get_fee_event_count(&env));
+        let mut events_settled = 0u32;
+        let mut settled_by_token: Map<Address, i128> = Map::new(&env);
+        let platform_wallet = get_treasury_wallet(&env, crate::storage::get_usdc_token(&env));
+
+        for i in start..end {
+            let Some(event_id) = get_fee_event_at(&env, i) else {
+                continue;
+            };
+            // Each token's fees settle into their own ledger entry and invoice
+            let mut settled = false;
+            for token in get_event_balance_tokens(&env, event_id.clone()).iter() {
+                let fee_amount = take_event_platform_fee(&env, event_id.clone(), &token);
+                if fee_amount == 0 {
+                    continue;
+                }
+                append_ledger_entry(
+                    &env,
+                    event_id.clone(),
+                    LedgerEntryType::FeeSettlement,
+                    fee_amount,
+                    token.clone(),
+                    get_treasury_wallet(&env, token.clone()),
+                );
+                record_fee_settlement(&env, event_id.clone(), fee_amount, token.clone());
+                settled = true;
+                let token_total = settled_by_token
+                    .get(token.clone())
+                    .unwrap_or(0)
+                    .checked_add(fee_amount)
+                    .ok_or(TicketPaymentError::ArithmeticError)?;
+                settled_by_token.set(token, token_total);
+            }
+            if settled {
+                events_settled += 1;
+            }
+        }
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::FeesSettledBatch,),
+            FeesSettledBatchEvent {
+                platform_wallet,
+                start,
+                next_start: end.max(start),
+                events_settled,
+                settled_by_token: settled_by_token.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(settled_by_token)
+    }
+
+    /// Returns the number of events in the fee-event index.
+    pub fn get_fee_event_count(env: Env) -> u32 {
+        get_fee_event_count(&env)
+    }
+
+    /// Returns the total number of recorded platform fee settlements.
+    pub fn get_settlement_count(env: Env) -> u32 {
+        get_settlement_count(&env)
+    }
+
+    /// Pages through the platform fee settlement history in settlement order.
+    pub fn get_settlement_history(env: Env, start: u32, limit: u32) -> Vec<SettlementRecord> {
+        get_settlement_history(&env, PageCursor::new(start, limit))
+    }
+
+    /// Allows or disallows an additional event registry tenant. Only callable by admin.
+    /// The default registry set at initialization is always allowed.
+    pub fn set_registry_allowed(
+        env: Env,
+        registry: Address,
+        allowed: bool,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        set_registry_allowed(&env, registry, allowed);
+        Ok(())
+    }
+
+    /// Returns whether purchases may be routed through the given registry.
+    pub fn is_registry_allowed(env: Env, registry: Address) -> bool {
+        is_registry_allowed(&env, &registry)
+    }
+
+    /// Reserves an event id namespace for a registry tenant. The tenant can
+    /// only bind event ids of the form `<namespace>:<id>`, and the default
+    /// registry cannot use them, so tenants never compete for the same ids.
+    /// A namespace is at most `MAX_TENANT_NAMESPACE_LEN` bytes without `:`, and
+    /// a tenant keeps the namespace it was first given. Only callable by admin.
+    pub fn reserve_tenant_namespace(
+        env: Env,
+        registry: Address,
+        namespace: String,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        if registry == get_event_registry(&env) {
+            return Err(TicketPaymentError::RegistryNotAllowed);
+        }
+        let len = namespace.len() as usize;
+        if len == 0 || len > MAX_TENANT_NAMESPACE_LEN as usize {
+            return Err(TicketPaymentError::TenantNamespaceUnavailable);
+        }
+        let mut buf = [0u8; MAX_TENANT_NAMESPACE_LEN as usize];
+        namespace.copy_into_slice(&mut buf[..len]);
+        if buf[..len].contains(&b':') {
+            return Err(TicketPaymentError::TenantNamespaceUnavailable);
+        }
+        if let Some(current) = get_tenant_namespace(&env, &registry) {
+            return match current == namespace {
+                true => Ok(()),
+                false => Err(TicketPaymentError::TenantNamespaceUnavailable),
+            };
+        }
+        if get_namespace_registry(&env, namespace.clone()).is_some() {
+            return Err(TicketPaymentError::TenantNamespaceUnavailable);
+        }
+        reserve_tenant_namespace(&env, &registry, namespace);
+        Ok(())
+    }
+
+    /// Returns the event id namespace reserved for a registry tenant, if any.
+    pub fn get_tenant_namespace(env: Env, registry: Address) -> Option<String> {
+        get_tenant_namespace(&env, &registry)
+    }
+
+    /// Binds an event to the registry tenant that registered it. Called by each
+    /// registry as it registers an event. A tenant can only bind ids within its
+    /// reserved namespace, and the default registry, whose events stay unbound,
+    /// cannot use a reserved namespace, so each event id keeps its escrow,
+    /// balances and payments within a single tenant.
+    pub fn bind_tenant_event(
+        env: Env,
+        registry: Address,
+        event_id: String,
+    ) -> Result<(), TicketPaymentError> {
+        registry.require_auth();
+        let bound = get_bound_event_registry(&env, event_id.clone());
+        let default_registry = get_event_registry(&env);
+        let namespace = event_id_namespace(&env, &event_id);
+        if registry == default_registry {
+            let reserved = namespace.is_some_and(|ns| get_namespace_registry(&env, ns).is_some());
+            return match bound.is_some() || reserved {
+                true => Err(TicketPaymentError::RegistryNotAllowed),
+                false => Ok(()),
+            };
+        }
+        if !is_registry_allowed(&env, &registry) {
+            return Err(TicketPaymentError::RegistryNotAllowed);
+        }
+        if namespace.is_none() || namespace != get_tenant_namespace(&env, &registry) {
+            return Err(TicketPaymentError::TenantNamespaceUnavailable);
+        }
+        match bound {
+            Some(b) if b == registry => return Ok(()),
+            Some(_) => return Err(TicketPaymentError::RegistryNotAllowed),
+            None => {}
+        }
+        let known_to_default = matches!(
+            event_registry::Client::new(&env, &default_registry).try_get_event(&event_id),
+            Ok(Ok(Some(_)))
+        );
+        if known_to_default || get_event_payment_count(&env, event_id.clone()) > 0 {
+            return Err(TicketPaymentError::RegistryNotAllowed);
+        }
+        bind_event_registry(&env, event_id, &registry);
+        Ok(())
+    }
+
+    /// Returns the registry an event is bound to (the default registry if unbound).
+    pub fn get_event_registry_for(env: Env, event_id: String) -> Address {
+        get_event_registry_for(&env, event_id)
+    }
+
+    /// Returns the cumulative platform fees collected for a registry tenant in a token.
+    pub fn get_tenant_fees_collected(env: Env, registry: Address, token: Address) -> i128 {
+        get_tenant_fees_collected(&env, registry, token)
+    }
+
+    /// Withdraw accumulated platform fees from the contract treasury.
+    /// Incorporates a daily withdrawal cap and requires admin (multi-sig) authorization.
+    pub fn withdraw_platform_fees(
+        env: Env,
+        amount: i128,
+        token_address: Address,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+
+        if amount <= 0 {
+            return Err(TicketPaymentError::ArithmeticError);
+        }
+
+        // 1. Verify that the amount requested is less than or equal to the recorded total_fees_collected.
+        let total_accumulated = get_total_fees_collected_by_token(&env, token_address.clone());
+        if amount > total_accumulated {
+            return Err(TicketPaymentError::InsufficientFees);
+        }
+
+        // 2. Incorporate a 'Withdrawal Cap' per day.
+        let cap = get_withdrawal_cap(&env, token_address.clone());
+        if cap > 0 {
+            let current_day = env.ledger().timestamp() / 86400;
+            let already_withdrawn =
+                get_daily_withdrawn_amount(&env, token_address.clone(), current_day);
+            if already_withdrawn
+                .checked_add(amount)
+                .ok_or(TicketPaymentError::ArithmeticError)?
+                > cap
+            {
+                return Err(TicketPaymentError::WithdrawalCapExceeded);
+            }
+            add_to_daily_withdrawn_amount(&env, token_address.clone(), current_day, amount);
+        }
+
+        // 3. Process the transfer to the token's treasury wallet
+        let platform_wallet = get_treasury_wallet(&env, token_address.clone());
+        token::Client::new(&env, &token_address).transfer(
+            &env.current_contract_address(),
+            &platform_wallet,
+            &amount,
+        );
+
+        // 4. Update global accounting
+        subtract_from_total_fees_collected_by_token(&env, token_address.clone(), amount);
+        subtract_from_active_escrow_total(&env, amount);
+        subtract_from_active_escrow_by_token(&env, token_address, amount);
+
+        Ok(())
+    }
+
+    /// Sets a daily withdrawal cap for a specific token.
+    pub fn set_withdrawal_cap(
+        env: Env,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+
+        if amount < 0 {
+            return Err(TicketPaymentError::ArithmeticError);
+        }
+
+        set_withdrawal_cap(&env, token, amount);
+        Ok(())
+    }
+
+    /// Routes a token's platform fees to a dedicated payout wallet (e.g. USDC to a
+    /// custodian), or back to the platform wallet with `None`. Only callable by admin.
+    pub fn set_treasury_wallet(
+        env: Env,
+        token: Address,
+        wallet: Option<Address>,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        if let Some(wallet) = &wallet {
+            validate_address(&env, wallet)?;
+        }
+        set_treasury_wallet(&env, token, wallet);
+        Ok(())
+    }
+
+    /// Nominates a new admin, who takes over once they call `accept_admin`.
+    /// Replaces any earlier nomination. Only callable by admin.
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        validate_address(&env, &new_admin)?;
+
+        set_pending_admin(&env, Some(new_admin.clone()));
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::AdminTransferStarted,),
+            AdminTransferStartedEvent {
+                admin,
+                pending_admin: Some(new_admin),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Completes a pending admin transfer. Must be authorized by the nominee.
+    pub fn accept_admin(env: Env) -> Result<(), TicketPaymentError> {
+        let new_admin = get_pending_admin(&env).ok_or(TicketPaymentError::Unauthorized)?;
+        new_admin.require_auth();
+
+        let old_admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        set_admin(&env, &new_admin);
+        set_pending_admin(&env, None);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::AdminTransferred,),
+            AdminTransferredEvent {
+                old_admin,
+                new_admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Withdraws a pending admin nomination before it is accepted. Only
+    /// callable by admin.
+    pub fn cancel_admin_transfer(env: Env) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        if get_pending_admin(&env).is_none() {
+            return Err(TicketPaymentError::Unauthorized);
+        }
+
+        set_pending_admin(&env, None);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::AdminTransferStarted,),
+            AdminTransferStartedEvent {
+                admin,
+                pending_admin: None,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the admin nominated by `transfer_admin`, if any.
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        get_pending_admin(&env)
+    }
+
+    /// Proposes a new platform wallet, which takes effect once the wallet itself
+    /// calls `accept_platform_wallet`. Replaces any earlier pending proposal.
+    /// Only callable by admin.
+    pub fn propose_platform_wallet(
+        env: Env,
+        new_wallet: Address,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        validate_address(&env, &new_wallet)?;
+
+        set_pending_platform_wallet(&env, Some(new_wallet.clone()));
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::PlatformWalletProposed,),
+            PlatformWalletProposedEvent {
+                current_wallet: get_platform_wallet(&env),
+                proposed_wallet: new_wallet,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Makes the pending platform wallet the platform wallet, which receives
+    /// fees of tokens without a dedicated treasury wallet. Must be authorized
+    /// by the pending wallet, proving it is controlled.
+    pub fn accept_platform_wallet(env: Env) -> Result<(), TicketPaymentError> {
+        let new_wallet =
+            get_pending_platform_wallet(&env).ok_or(TicketPaymentError::Unauthorized)?;
+        new_wallet.require_auth();
+
+        let old_wallet = get_platform_wallet(&env);
+        set_platform_wallet(&env, new_wallet.clone());
+        set_pending_platform_wallet(&env, None);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::PlatformWalletUpdated,),
+            PlatformWalletUpdatedEvent {
+                old_wallet,
+                new_wallet,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the platform wallet.
+    pub fn get_platform_wallet(env: Env) -> Address {
+        get_platform_wallet(&env)
+    }
+
+    /// Returns the proposed platform wallet awaiting acceptance, if any.
+    pub fn get_pending_platform_wallet(env: Env) -> Option<Address> {
+        get_pending_platform_wallet(&env)
+    }
+
+    /// Returns the wallet a token's platform fees are paid out to.
+    pub fn get_treasury_wallet(env: Env, token: Address) -> Address {
+        get_treasury_wallet(&env, token)
+    }
+
+    /// Lists the platform fee treasury of every token that has collected fees,
+    /// with its payout wallet, withdrawable balance and today's cap usage.
+    pub fn get_treasury_balances(env: Env) -> Vec<TreasuryBalance> {
+        let current_day = env.ledger().timestamp() / 86400;
+        let mut balances = Vec::new(&env);
+        for token in get_treasury_tokens(&env).iter() {
+            balances.push_back(TreasuryBalance {
+                wallet: get_treasury_wallet(&env, token.clone()),
+                balance: get_total_fees_collected_by_token(&env, token.clone()),
+                withdrawal_cap: get_withdrawal_cap(&env, token.clone()),
+                withdrawn_today: get_daily_withdrawn_amount(&env, token.clone(), current_day),
+                token,
+            });
+        }
+        balances
+    }
+
+    /// Reports, per whitelisted token and per token still holding platform fees,
+    /// the fees collected over its lifetime and available for withdrawal, the
+    /// escrow still refundable to buyers and today's use of the withdrawal cap.
+    pub fn get_treasury_report(env: Env) -> Vec<TreasuryReportLine> {
+        let mut tokens = get_whitelisted_tokens(&env);
+        for token in get_treasury_tokens(&env).iter() {
+            if !tokens.contains(&token) {
+                tokens.push_back(token);
+            }
+        }
+
+        let current_day = env.ledger().timestamp() / 86400;
+        let mut report = Vec::new(&env);
+        for token in tokens.iter() {
+            let available_fees = get_total_fees_collected_by_token(&env, token.clone());
+            let withdrawal_cap = get_withdrawal_cap(&env, token.clone());
+            let withdrawn_today = get_daily_withdrawn_amount(&env, token.clone(), current_day);
+            let cap_used_bps = if withdrawal_cap > 0 {
+                (withdrawn_today * MAX_BPS as i128 / withdrawal_cap) as u32
+            } else {
+                0
+            };
+            report.push_back(TreasuryReportLine {
+                lifetime_fees: get_lifetime_fees_collected(&env, token.clone()),
+                escrow_at_risk: (crate::storage::get_active_escrow_by_token(&env, token.clone())
+                    - available_fees)
+                    .max(0),
+                available_fees,
+                withdrawal_cap,
+                withdrawn_today,
+                cap_used_bps,
+                token,
+            });
+        }
+        report
+    }
+
+    /// Claim revenue after event completion.
+    pub fn claim_revenue(
+        env: Env,
+        event_id: String,
+        token_address: Address,
+    ) -> Result<i128, TicketPaymentError> {
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        let event_registry_addr = get_event_registry_for(&env, event_id.clone());
+        let registry_client = event_registry::Client::new(&env, &event_registry_addr);
+
+        let event_info = registry_client
+            .try_get_event(&event_id)
+            .ok()
+            .and_then(|r| r.ok())
+            .flatten()
+            .ok_or(TicketPaymentError::EventNotFound)?;
+
+        event_info.organizer_address.require_auth();
+        record_organizer_activity(&env, event_id.clone());
+
+        if event_info.status != event_registry::EventStatus::Completed {
+            return Err(TicketPaymentError::EventNotCompleted);
+        }
+        if is_event_disputed(&env, event_id.clone()) {
+            return Err(TicketPaymentError::EventDisputed);
+        }
+        // Revenue stays locked for the challenge window after completion
+        let challenge_window = get_revenue_challenge_window(&env);
+        if challenge_window > 0 {
+            let completed_at = registry_client
+                .try_get_event_schedule(&event_id)
+                .ok()
+                .and_then(|r| r.ok())
+                .flatten()
+                .map(|schedule| schedule.completed_at)
+                .filter(|completed_at| *completed_at > 0)
+                .ok_or(TicketPaymentError::EventNotCompleted)?;
+            if env.ledger().timestamp() < completed_at.saturating_add(challenge_window) {
+                return Err(TicketPaymentError::EventNotCompleted);
+            }
+        }
+
+        // Check if goal was met if a target was set
+        if event_info.min_sales_target > 0 && !event_info.goal_met {
+            return Err(TicketPaymentError::GoalNotMet);
+        }
+
+        let balance = get_event_token_balance(&env, event_id.clone(), &token_address);
+        if balance.organizer_amount == 0 && balance.platform_fee == 0 {
+            return Err(TicketPaymentError::NoFundsAvailable);
+        }
+
+        let platform_wallet = get_treasury_wallet(&env, token_address.clone());
+        let token_client = token::Client::new(&env, &token_address);
+        let contract_address = env.current_contract_address();
+        let timestamp = env.ledger().timestamp();
+
+        let platform_fee_amount = balance.platform_fee;
+        // Shares of payments under arbitration stay in escrow
+        let frozen_amount = get_frozen_dispute_amount(&env, event_id.clone()).max(0);
+        let organizer_amount = (balance.organizer_amount - frozen_amount).max(0);
+
+        // Settlement logic: platform fees stay in the contract but are cleared from EventBalance.
+        // They are already tracked in TotalFeesCollected.
+        if platform_fee_amount > 0 {
+            append_ledger_entry(
+                &env,
+                event_id.clone(),
+                LedgerEntryType::FeeSettlement,
+                platform_fee_amount,
+                token_address.clone(),
+                platform_wallet.clone(),
+            );
+            let invoice_id = record_fee_settlement(
+                &env,
+                event_id.clone(),
+                platform_fee_amount,
+                token_address.clone(),
+            );
+
+            #[allow(deprecated)]
+            env.events().publish(
+                (AgoraEvent::FeeSettled, event_id.clone()),
+                FeeSettledEvent {
+                    event_id: event_id.clone(),
+                    platform_wallet: platform_wallet.clone(),
+                    fee_amount: platform_fee_amount,
+                    fee_bps: event_info.platform_fee_percent,
+                    invoice_id,
+                    timestamp,
+                },
+            );
+        }
+
+        // Transfer net revenue to organizer
+        if organizer_amount > 0 {
+            token_client.transfer(
+                &contract_address,
+                &event_info.payment_address,
+                &organizer_amount,
+            );
+            append_ledger_entry(
+                &env,
+                event_id.clone(),
+                LedgerEntryType::Withdrawal,
+                organizer_amount,
+                token_address.clone(),
+                event_info.payment_address.clone(),
+            );
+        }
+
+        // Update balances
+        take_event_platform_fee(&env, event_id.clone(), &token_address);
+        record_event_withdrawal(&env, event_id.clone(), &token_address, organizer_amount);
+
+        let total_transferred = organizer_amount;
+        if total_transferred > 0 {
+            subtract_from_active_escrow_total(&env, total_transferred);
+            subtract_from_active_escrow_by_token(&env, token_address, total_transferred);
+        }
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::RevenueClaimed, event_id.clone()),
+            RevenueClaimedEvent {
+                event_id,
+                organizer_address: event_info.organizer_address,
+                amount: organizer_amount,
+                timestamp,
+            },
+        );
+
+        Ok(organizer_amount)
+    }
+
+    /// Returns all payments for a specific buyer.
+    pub fn get_buyer_payments(env: Env, buyer_address: Address) -> soroban_sdk::Vec<String> {
+        crate::storage::get_buyer_payments(&env, buyer_address)
+    }
+
+    /// Returns up to `limit` of a buyer's payment ids starting at `offset`.
+    pub fn get_buyer_payments_paginated(
+        env: Env,
+        buyer_address: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<String> {
+        get_buyer_payments_page(&env, buyer_address, PageCursor::new(offset, limit))
+    }
+
+    /// Returns up to `limit` of a buyer's payments in full, optionally only those
+    /// for `event_id` and in `status`. `offset` counts matching payments.
+    pub fn get_buyer_payments_detailed(
+        env: Env,
+        buyer_address: Address,
+        event_id: Option<String>,
+        status: Option<PaymentStatus>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Payment> {
+        crate::storage::get_buyer_payments_filtered_page(
+            &env,
+            buyer_address,
+            event_id,
+            status,
+            PageCursor::new(offset, limit),
+        )
+    }
+
+    /// Returns how many payments a buyer currently holds.
+    pub fn get_buyer_payment_count(env: Env, buyer_address: Address) -> u32 {
+        get_buyer_payment_count(&env, buyer_address)
+    }
+
+    /// Sets the transfer fee for an event. Only the organizer can call this.
+    pub fn set_transfer_fee(
+        env: Env,
+        event_id: String,
+        amount: i128,
+    ) -> Result<(), TicketPaymentError> {
+        if !is_initialized(&env) {
+            panic!("Contract not initialized");
+        }
+
+        let event_registry_addr = get_event_registry_for(&env, event_id.clone());
+        let registry_client = event_registry::Client::new(&env, &event_registry_addr);
+
+        let event_info = match registry_client.try_get_event(&event_id) {
+            Ok(Ok(Some(info))) => info,
+            _ => return Err(TicketPaymentError::EventNotFound),
+        };
+
+        event_info.organizer_address.require_auth();
+        record_organizer_activity(&env, event_id.clone());
+
+        if amount < 0 {
+            panic!("Transfer fee must be non-negative");
+        }
+
+        set_transfer_fee(&env, event_id, amount);
+        Ok(())
+    }
+
+    /// Pre-mints `quantity` tickets of a tier into an allotment held by a box office
+    /// `custodian`. The tickets count against tier and event supply immediately and
+    /// are handed to walk-up buyers later via `assign_from_allotment`.
+    pub fn allocate_box_office(
+        env: Env,
+        event_id: String,
+        tier_id: String,
+        quantity: u32,
+        custodian: Address,
+    ) -> Result<(), TicketPaymentError> {
+        if !is_initialized(&env) {
+            panic!("Contract not initialized");
+        }
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        if quantity == 0 {
+            panic!("Quantity must be positive");
+        }
+
+        let event_registry_addr = get_event_registry_for(&env, event_id.clone());
+        let registry_client = event_registry::Client::new(&env, &event_registry_addr);
+
+        let event_info = match registry_client.try_get_event(&event_id) {
+            Ok(Ok(Some(info))) => info,
+            _ => return Err(TicketPaymentError::EventNotFound),
+        };
+        event_info.organizer_address.require_auth();
+        record_organizer_activity(&env, event_id.clone());
+
+        // Supply is reserved in the registry exactly as for a regular sale
+        match registry_client.try_increment_inventory(&event_id, &tier_id, &quantity) {
+            Ok(Ok(())) => {}
+            _ => return Err(TicketPaymentError::MaxSupplyExceeded),
+        }
+
+        let remaining =
+            get_box_office_allotment(&env, event_id.clone(), tier_id.clone(), custodian.clone())
+                .checked_add(quantity)
+                .ok_or(TicketPaymentError::ArithmeticError)?;
+        set_box_office_allotment(
+            &env,
+            event_id.clone(),
+            tier_id.clone(),
+            custodian.clone(),
+            remaining,
+        );
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::BoxOfficeAllocated, event_id.clone()),
+            BoxOfficeAllocatedEvent {
+                event_id,
+                tier_id,
+                custodian,
+                quantity,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Assigns one ticket from a custodian's allotment to a walk-up buyer. Payment
+    /// was taken in cash at the box office, so the ticket is recorded as confirmed
+    /// with no on-chain amount.
+    pub fn assign_from_allotment(
+        env: Env,
+        payment_id: String,
+        event_id: String,
+        tier_id: String,
+        custodian: Address,
+        buyer: Address,
+    ) -> Result<(), TicketPaymentError> {
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        custodian.require_auth();
+        if get_payment(&env, payment_id.clone()).is_some() {
+            return Err(TicketPaymentError::PaymentAlreadyExists);
+        }
+
+        let remaining =
+            get_box_office_allotment(&env, event_id.clone(), tier_id.clone(), custodian.clone());
+        if remaining == 0 {
+            return Err(TicketPaymentError::MaxSupplyExceeded);
+        }
+        set_box_office_allotment(
+            &env,
+            event_id.clone(),
+            tier_id.clone(),
+            custodian.clone(),
+            remaining - 1,
+        );
+
+        let now = env.ledger().timestamp();
+        store_payment(
+            &env,
+            Payment {
+                payment_id: payment_id.clone(),
+                event_id: event_id.clone(),
+                buyer_address: buyer.clone(),
+                ticket_tier_id: tier_id.clone(),
+                amount: 0,
+                token_address: crate::storage::get_usdc_token(&env),
+                platform_fee: 0,
+                organizer_amount: 0,
+                status: PaymentStatus::Confirmed,
+                transaction_hash: String::from_str(&env, ""),
+                created_at: now,
+                confirmed_at: Some(now),
+                refunded_amount: 0,
+                identity_hash: None,
+                memo: None,
+                seat_id: None,
+            },
+        );
+
+        mint_ticket_nft(&env, &buyer, &event_id, &payment_id);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (
+                AgoraEvent::BoxOfficeTicketAssigned,
+                event_id.clone(),
+                payment_id.clone(),
+            ),
+            BoxOfficeTicketAssignedEvent {
+                payment_id,
+                event_id,
+                tier_id,
+                custodian,
+                buyer,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the number of unassigned tickets in a custodian's allotment.
+    pub fn get_box_office_allotment(
+        env: Env,
+        event_id: String,
+        tier_id: String,
+        custodian: Address,
+    ) -> u32 {
+        get_box_office_allotment(&env, event_id, tier_id, custodian)
+    }
+
+    /// Records a failed purchase attempt for support triage. A reverted
+    /// `process_payment` leaves no state behind, so the buyer's client reports the
+    /// error code it received. Ids that already hold a payment are rejected.
+    pub fn report_failed_purchase(
+        env: Env,
+        payment_id: String,
+        event_id: String,
+        buyer: Address,
+        reason_code: u32,
+    ) -> Result<(), TicketPaymentError> {
+        buyer.require_auth();
+        if get_payment(&env, payment_id.clone()).is_some() {
+            return Err(TicketPaymentError::InvalidPaymentStatus);
+        }
+        record_failed_purchase(
+            &env,
+            FailedPurchase {
+                payment_id,
+                event_id,
+                buyer,
+                reason_code,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the most recent failed purchase attempts, oldest first. Only the
+    /// administrator may read them.
+    pub fn get_failed_purchases(env: Env) -> Result<Vec<FailedPurchase>, TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        Ok(get_failed_purchases(&env))
+    }
+
+    /// Grants every checked-in attendee of `source_event_id` a coupon worth
+    /// `discount_bps` off tickets for `target_event_id`, processing at most
+    /// `batch_size` payments per call. Both events must belong to the caller.
+    /// Coupons are redeemed automatically by the holder's next purchase for the
+    /// target event. Returns the number of coupons granted; 0 once all
+    /// attendees have been processed.
+    pub fn airdrop_attendance_coupons(
+        env: Env,
+        source_event_id: String,
+        target_event_id: String,
+        discount_bps: u32,
+        batch_size: u32,
+    ) -> Result<u32, TicketPaymentError> {
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        if discount_bps == 0 || !is_valid_bps(discount_bps) {
+            return Err(TicketPaymentError::InvalidDiscountBps);
+        }
+
+        let source = Self::fetch_event(&env, &source_event_id)?;
+        let target = Self::fetch_event(&env, &target_event_id)?;
+        source.organizer_address.require_auth();
+        if target.organizer_address != source.organizer_address {
+            return Err(TicketPaymentError::Unauthorized);
+        }
+        record_organizer_activity(&env, source_event_id.clone());
+
+        let payment_ids = get_event_payments(&env, source_event_id.clone());
+        let start_index = get_coupon_cursor(&env, source_event_id.clone(), target_event_id.clone());
+        let end_index = core::cmp::min(start_index.saturating_add(batch_size), payment_ids.len());
+        if start_index >= end_index {
+            return Ok(0);
+        }
+
+        let mut granted = 0u32;
+        for i in start_index..end_index {
+            let payment = match get_payment(&env, payment_ids.get(i).unwrap()) {
+                Some(payment) if payment.status == PaymentStatus::CheckedIn => payment,
+                _ => continue,
+            };
+            // Attendees with several tickets hold one coupon, at the best rate
+            let holder = payment.buyer_address;
+            if get_coupon(&env, target_event_id.clone(), holder.clone()) < discount_bps {
+                set_coupon(&env, target_event_id.clone(), holder, discount_bps);
+                granted += 1;
+            }
+        }
+        set_coupon_cursor(
+            &env,
+            source_event_id.clone(),
+            target_event_id.clone(),
+            end_index,
+        );
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::AttendanceCouponsAirdropped,),
+            AttendanceCouponsAirdroppedEvent {
+                source_event_id,
+                target_event_id,
+                discount_bps,
+                granted,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(granted)
+    }
+
+    /// Returns the unredeemed attendance coupon (in bps) a holder has for an event.
+    pub fn get_coupon(env: Env, event_id: String, holder: Address) -> u32 {
+        get_coupon(&env, event_id, holder)
+    }
+
+    /// Opens a poll for an event's ticket holders with `option_count` options and
+    /// voting until `deadline`. Only tickets bought by the time the poll opens can
+    /// vote. Returns the poll id.
+    pub fn open_poll(
+        env: Env,
+        event_id: String,
+        question_hash: BytesN<32>,
+        option_count: u32,
+        deadline: u64,
+    ) -> Result<u32, TicketPaymentError> {
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        let event_info = Self::fetch_event(&env, &event_id)?;
+        event_info.organizer_address.require_auth();
+        record_organizer_activity(&env, event_id.clone());
+
+        let now = env.ledger().timestamp();
+        if !(2..=MAX_POLL_OPTIONS).contains(&option_count) || deadline <= now {
+            return Err(TicketPaymentError::InvalidPoll);
+        }
+
+        let mut tallies = Vec::new(&env);
+        for _ in 0..option_count {
+            tallies.push_back(0u32);
+        }
+        let poll_id = get_poll_count(&env, event_id.clone());
+        set_poll(
+            &env,
+            event_id.clone(),
+            poll_id,
+            &Poll {
+                question_hash: question_hash.clone(),
+                tallies,
+                created_at: now,
+                deadline,
+            },
+        );
+        set_poll_count(&env, event_id.clone(), poll_id + 1);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::PollOpened, event_id.clone()),
+            PollOpenedEvent {
+                event_id,
+                poll_id,
+                question_hash,
+                option_count,
+                deadline,
+            },
+        );
+        Ok(poll_id)
+    }
+
+    /// Casts the voter's eligible tickets for `option`. Each ticket counts once per
+    /// poll, even if it changes hands, so later votes by the same voter only add
+    /// tickets not yet counted. Returns the weight added.
+    pub fn vote(
+        env: Env,
+        event_id: String,
+        poll_id: u32,
+        voter: Address,
+        option: u32,
+    ) -> Result<u32, TicketPaymentError> {
+        voter.require_auth();
+
+        let mut poll =
+            get_poll(&env, event_id.clone(), poll_id).ok_or(TicketPaymentError::InvalidPoll)?;
+        let now = env.ledger().timestamp();
+        if now >= poll.deadline {
+            return Err(TicketPaymentError::PollClosed);
+        }
+        let tally = poll
+            .tallies
+            .get(option)
+            .ok_or(TicketPaymentError::InvalidPoll)?;
+
+        let mut weight = 0u32;
+        for payment_id in crate::storage::get_buyer_payments(&env, voter.clone()).iter() {
+            let eligible = match get_payment(&env, payment_id.clone()) {
+                Some(payment) => {
+                    payment.event_id == event_id
+                        && payment.created_at <= poll.created_at
+                        && matches!(
+                            payment.status,
+                            PaymentStatus::Confirmed | PaymentStatus::CheckedIn
+                        )
+                }
+                None => false,
+            };
+            if eligible
+                && !is_poll_ticket_counted(&env, event_id.clone(), poll_id, payment_id.clone())
+            {
+                mark_poll_ticket_counted(&env, event_id.clone(), poll_id, payment_id);
+                weight += 1;
+            }
+        }
+        if weight == 0 {
+            return Err(TicketPaymentError::Unauthorized);
+        }
+        poll.tallies.set(option, tally + weight);
+        set_poll(&env, event_id.clone(), poll_id, &poll);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::PollVoteCast, event_id.clone()),
+            PollVoteCastEvent {
+                event_id,
+                poll_id,
+                voter,
+                option,
+                weight,
+                timestamp: now,
+            },
+        );
+        Ok(weight)
+    }
+
+    /// Returns a poll with its current tallies.
+    pub fn get_poll(env: Env, event_id: String, poll_id: u32) -> Option<Poll> {
+        get_poll(&env, event_id, poll_id)
+    }
+
+    /// Returns the winning option once voting has closed, or `None` while the poll
+    /// is open, if nobody voted, or on a tie. Organizer governance contracts can
+    /// require an outcome before executing a reschedule or cancellation.
+    pub fn get_poll_outcome(env: Env, event_id: String, poll_id: u32) -> Option<u32> {
+        let poll = get_poll(&env, event_id, poll_id)?;
+        if env.ledger().timestamp() < poll.deadline {
+            return None;
+        }
+        let mut winner = None;
+        let mut best = 0u32;
+        for (option, tally) in poll.tallies.iter().enumerate() {
+            if tally > best {
+                best = tally;
+                winner = Some(option as u32);
+            } else if tally == best {
+                winner = None;
+            }
+        }
+        winner
+    }
+
+    /// Grants an automation address the `OPERATOR_*` permissions in `permissions`
+    /// for one event, replacing any earlier grant; 0 revokes. Only the organizer
+    /// may grant.
+    pub fn grant_event_operator(
+        env: Env,
+        event_id: String,
+        operator: Address,
+        permissions: u32,
+    ) -> Result<(), TicketPaymentError> {
+        let event_info = Self::fetch_event(&env, &event_id)?;
+        event_info.organizer_address.require_auth();
+        record_organizer_activity(&env, event_id.clone());
+        validate_address(&env, &operator)?;
+
+        set_operator_permissions(&env, event_id.clone(), operator.clone(), permissions);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::EventOperatorGranted, event_id.clone()),
+            EventOperatorGrantedEvent {
+                event_id,
+                operator,
+                permissions,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the `OPERATOR_*` permissions an address holds for an event.
+    pub fn get_event_operator_permissions(env: Env, event_id: String, operator: Address) -> u32 {
+        get_operator_permissions(&env, event_id, operator)
+    }
+
+    /// Exempts a wallet, such as a box office buying on behalf of walk-up guests,
+    /// from the event's `max_tickets_per_buyer` limit, or revokes the exemption.
+    /// Only the organizer may change exemptions.
+    pub fn set_buyer_limit_exempt(
+        env: Env,
+        event_id: String,
+        wallet: Address,
+        exempt: bool,
+    ) -> Result<(), TicketPaymentError> {
+        let event_info = Self::fetch_event(&env, &event_id)?;
+        event_info.organizer_address.require_auth();
+        record_organizer_activity(&env, event_id.clone());
+        validate_address(&env, &wallet)?;
+        set_buyer_limit_exempt(&env, event_id, wallet, exempt);
+        Ok(())
+    }
+
+    /// Lets a ticket change hands inside its event's transfer lock window, or
+    /// withdraws that approval. The approval lapses once the ticket moves. Only
+    /// the organizer may approve.
+    pub fn approve_locked_transfer(
+        env: Env,
+        payment_id: String,
+        approved: bool,
+    ) -> Result<(), TicketPaymentError> {
+        let payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        let event_info = Self::fetch_event(&env, &payment.event_id)?;
+        event_info.organizer_address.require_auth();
+        record_organizer_activity(&env, payment.event_id);
+        set_locked_transfer_approved(&env, payment_id, approved);
+        Ok(())
+    }
+
+    /// Returns whether the organizer approved moving a ticket during the lock.
+    pub fn is_locked_transfer_approved(env: Env, payment_id: String) -> bool {
+        is_locked_transfer_approved(&env, payment_id)
+    }
+
+    /// Returns whether a wallet is exempt from an event's per-buyer limit.
+    pub fn is_buyer_limit_exempt(env: Env, event_id: String, wallet: Address) -> bool {
+        is_buyer_limit_exempt(&env, event_id, wallet)
+    }
+
+    /// Defines or updates a sponsor slot for an event. Redefining a slot keeps its
+    /// sales, so `quantity` cannot drop below the number already sold. Events hold
+    /// at most `MAX_SPONSOR_SLOTS` slots and `MAX_EVENT_SPONSORS` sponsorships in
+    /// total. Only the organizer may define slots.
+    pub fn define_sponsor_slot(
+        env: Env,
+        event_id: String,
+        slot_id: String,
+        name: String,
+        price: i128,
+        benefits_cid: String,
+        quantity: u32,
+    ) -> Result<(), TicketPaymentError> {
+        let event_info = Self::fetch_event(&env, &event_id)?;
+        event_info.organizer_address.require_auth();
+        record_organizer_activity(&env, event_id.clone());
+
+        let existing = get_sponsor_slot(&env, event_id.clone(), slot_id.clone());
+        let sold = existing.as_ref().map(|slot| slot.sold).unwrap_or(0);
+        if price <= 0 || quantity == 0 || quantity < sold {
+            return Err(TicketPaymentError::InvalidSponsorSlot);
+        }
+
+        let mut slot_ids = get_sponsor_slot_ids(&env, event_id.clone());
+        let mut total_quantity = quantity;
+        for id in slot_ids.iter() {
+            if id != slot_id {
+                if let Some(other) = get_sponsor_slot(&env, event_id.clone(), id) {
+                    total_quantity = total_quantity.saturating_add(other.quantity);
+                }
+            }
+        }
+        if total_quantity > MAX_EVENT_SPONSORS {
+            return Err(TicketPaymentError::InvalidSponsorSlot);
+        }
+        if existing.is_none() {
+            if slot_ids.len() >= MAX_SPONSOR_SLOTS {
+                return Err(TicketPaymentError::InvalidSponsorSlot);
+            }
+            slot_ids.push_back(slot_id.clone());
+            set_sponsor_slot_ids(&env, event_id.clone(), &slot_ids);
+        }
+
+        set_sponsor_slot(
+            &env,
+            event_id.clone(),
+            &SponsorSlot {
+                slot_id: slot_id.clone(),
+                name,
+                price,
+                benefits_cid,
+                quantity,
+                sold,
+            },
+        );
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::SponsorSlotDefined, event_id.clone()),
+            SponsorSlotDefinedEvent {
+                event_id,
+                slot_id,
+                price,
+                quantity,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Buys one unit of a sponsor slot. The price is escrowed and split with the
+    /// event's platform fee exactly like a ticket sale, so it is withdrawn and
+    /// settled through the same escrow balances.
+    pub fn purchase_sponsor_slot(
+        env: Env,
+        event_id: String,
+        slot_id: String,
+        sponsor: Address,
+        token_address: Address,
+    ) -> Result<(), TicketPaymentError> {
+        if !is_initialized(&env) {
+            return Err(TicketPaymentError::NotInitialized);
+        }
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        sponsor.require_auth();
+        if !is_token_whitelisted(&env, &token_address) {
+            return Err(TicketPaymentError::TokenNotWhitelisted);
+        }
+
+        let event_info = Self::fetch_event(&env, &event_id)?;
+        if !event_info.is_active
+            || matches!(event_info.status, event_registry::EventStatus::Cancelled)
+        {
+            return Err(TicketPaymentError::EventInactive);
+        }
+        let mut slot = get_sponsor_slot(&env, event_id.clone(), slot_id.clone())
+            .ok_or(TicketPaymentError::InvalidSponsorSlot)?;
+        if slot.sold >= slot.quantity {
+            return Err(TicketPaymentError::SponsorSlotSoldOut);
+        }
+
+        let pricing = compute_pricing_breakdown(
+            slot.price,
+            1,
+            0,
+            0,
+            event_info.platform_fee_percent,
+            0,
+            false,
+        )?;
+
+        let token_client = token::Client::new(&env, &token_address);
+        let contract_address = env.current_contract_address();
+        if token_client.allowance(&sponsor, &contract_address) < pricing.net_paid {
+            return Err(TicketPaymentError::InsufficientAllowance);
+        }
+        token_client.transfer_from(
+            &contract_address,
+            &sponsor,
+            &contract_address,
+            &pricing.net_paid,
+        );
+
+        update_event_balance(
+            &env,
+            event_id.clone(),
+            &token_address,
+            pricing.organizer_net,
+            pricing.platform_fee,
+        );
+        check_withdrawal_threshold(&env, &event_info)?;
+        append_ledger_entry(
+            &env,
+            event_id.clone(),
+            LedgerEntryType::Sale,
+            pricing.net_paid,
+            token_address.clone(),
+            sponsor.clone(),
+        );
+        add_to_total_volume_processed(&env, pricing.gross);
+        add_to_total_fees_collected_by_token(&env, token_address.clone(), pricing.platform_fee);
+        add_to_tenant_fees_collected(
+            &env,
+            get_event_registry_for(&env, event_id.clone()),
+            token_address.clone(),
+            pricing.platform_fee,
+        );
+        add_to_active_escrow_total(&env, pricing.gross);
+        add_to_active_escrow_by_token(&env, token_address.clone(), pricing.gross);
+
+        slot.sold += 1;
+        set_sponsor_slot(&env, event_id.clone(), &slot);
+        let purchased_at = env.ledger().timestamp();
+        add_sponsorship(
+            &env,
+            event_id.clone(),
+            Sponsorship {
+                slot_id: slot_id.clone(),
+                sponsor: sponsor.clone(),
+                token: token_address.clone(),
+                amount: pricing.net_paid,
+                platform_fee: pricing.platform_fee,
+                purchased_at,
+            },
+        );
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::SponsorSlotPurchased, event_id.clone()),
+            SponsorSlotPurchasedEvent {
+                event_id,
+                slot_id,
+                sponsor,
+                token: token_address,
+                amount: pricing.net_paid,
+                platform_fee: pricing.platform_fee,
+                timestamp: purchased_at,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns an event's sponsor slots in definition order.
+    pub fn get_sponsor_slots(env: Env, event_id: String) -> Vec<SponsorSlot> {
+        let mut slots = Vec::new(&env);
+        for slot_id in get_sponsor_slot_ids(&env, event_id.clone()).iter() {
+            if let Some(slot) = get_sponsor_slot(&env, event_id.clone(), slot_id) {
+                slots.push_back(slot);
+            }
+        }
+        slots
+    }
+
+    /// Returns an event's sponsorships in purchase order.
+    pub fn get_event_sponsors(env: Env, event_id: String) -> Vec<Sponsorship> {
+        get_sponsorships(&env, event_id)
+    }
+
+    /// Returns remaining inventory per tier and for the whole event. Registry sales
+    /// include box-office allotments, so unassigned allotments are reported as
+    /// `reserved` rather than `sold`.
+    pub fn get_availability(
+        env: Env,
+        event_id: String,
+    ) -> Result<EventAvailability, TicketPaymentError> {
+        let registry_client =
+            event_registry::Client::new(&env, &get_event_registry_for(&env, event_id.clone()));
+        let event_info = match registry_client.try_get_event(&event_id) {
+            Ok(Ok(Some(info))) => info,
+            _ => return Err(TicketPaymentError::EventNotFound),
+        };
+
+        let mut tiers = Map::new(&env);
+        let mut sold = 0i128;
+        let mut reserved = 0i128;
+        let mut available = 0i128;
+        for (tier_id, tier) in event_info.tiers.iter() {
+            let tier_reserved =
+                get_box_office_reserved(&env, event_id.clone(), tier_id.clone()) as i128;
+            let tier_sold = tier.current_sold - tier_reserved;
+            let tier_available = (tier.tier_limit - tier.current_sold).max(0);
+            sold += tier_sold;
+            reserved += tier_reserved;
+            available += tier_available;
+            tiers.set(
+                tier_id,
+                TierAvailability {
+                    limit: tier.tier_limit,
+                    sold: tier_sold,
+                    reserved: tier_reserved,
+                    available: tier_available,
+                },
+            );
+        }
+        if event_info.max_supply > 0 {
+            available = available.min((event_info.max_supply - event_info.current_supply).max(0));
+        }
+
+        Ok(EventAvailability {
+            tiers,
+            max_supply: event_info.max_supply,
+            sold,
+            reserved,
+            available,
+        })
+    }
+
+    /// Opens the damage-deposit deduction window for an event once it has been
+    /// completed or its end time has passed. Permissionless and idempotent;
+    /// returns the window start.
+    pub fn open_deposit_window(env: Env, event_id: String) -> Result<u64, TicketPaymentError> {
+        let registry_client =
+            event_registry::Client::new(&env, &get_event_registry_for(&env, event_id.clone()));
+        let event_info = match registry_client.try_get_event(&event_id) {
+            Ok(Ok(Some(info))) => info,
+            _ => return Err(TicketPaymentError::EventNotFound),
+        };
+        Self::ensure_deposit_window(&env, &registry_client, &event_id, &event_info)
+    }
+
+    /// Flags a deduction (with reason) against a ticket's damage deposit. Only the
+    /// organizer may flag, and only within `DEPOSIT_CLAIM_WINDOW` after the event.
+    /// A later flag replaces the earlier one.
+    pub fn flag_deposit_deduction(
+        env: Env,
+        payment_id: String,
+        amount: i128,
+        reason: String,
+    ) -> Result<(), TicketPaymentError> {
+        let payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        let mut deposit = get_ticket_deposit(&env, payment_id.clone())
+            .filter(|d| !d.released)
+            .ok_or(TicketPaymentError::NoFundsAvailable)?;
+
+        let registry_client = event_registry::Client::new(
+            &env,
+            &get_event_registry_for(&env, payment.event_id.clone()),
+        );
+        let event_info = match registry_client.try_get_event(&payment.event_id) {
+            Ok(Ok(Some(info))) => info,
+            _ => return Err(TicketPaymentError::EventNotFound),
+        };
+        event_info.organizer_address.require_auth();
+        record_organizer_activity(&env, payment.event_id.clone());
+
+        let window_start =
+            Self::ensure_deposit_window(&env, &registry_client, &payment.event_id, &event_info)?;
+        let now = env.ledger().timestamp();
+        if now >= window_start.saturating_add(DEPOSIT_CLAIM_WINDOW) {
+            return Err(TicketPaymentError::RefundDeadlinePassed);
+        }
+        if amount < 0 || amount > deposit.amount {
+            return Err(TicketPaymentError::InvalidDepositDeduction);
+        }
+
+        deposit.deduction = amount;
+        deposit.reason = Some(reason.clone());
+        set_ticket_deposit(&env, payment_id.clone(), &deposit);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (
+                AgoraEvent::DepositDeductionFlagged,
+                payment.event_id.clone(),
+                payment_id.clone(),
+            ),
+            DepositDeductionFlaggedEvent {
+                payment_id,
+                amount,
+                reason,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// Permissionless crank: once the deduction window has closed, refunds the
+    /// undeducted deposit to the current ticket holder and pays any deduction to
+    /// the organizer. Returns the amount refunded.
+    pub fn release_deposit(env: Env, payment_id: String) -> Result<i128, TicketPaymentError> {
+        let payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        let mut deposit = get_ticket_deposit(&env, payment_id.clone())
+            .filter(|d| !d.released)
+            .ok_or(TicketPaymentError::NoFundsAvailable)?;
+
+        let window_start = get_deposit_window_start(&env, payment.event_id.clone())
+            .ok_or(TicketPaymentError::EventNotCompleted)?;
+        let now = env.ledger().timestamp();
+        if now < window_start.saturating_add(DEPOSIT_CLAIM_WINDOW) {
+            return Err(TicketPaymentError::EventNotCompleted);
+        }
+
+        let refunded = deposit
+            .amount
+            .checked_sub(deposit.deduction)
+            .ok_or(TicketPaymentError::ArithmeticError)?;
+        deposit.released = true;
+        set_ticket_deposit(&env, payment_id.clone(), &deposit);
+        add_held_funds(&env, deposit.token.clone(), -deposit.amount);
+
+        let token_client = token::Client::new(&env, &deposit.token);
+        let contract_address = env.current_contract_address();
+        if refunded > 0 {
+            token_client.transfer(&contract_address, &payment.buyer_address, &refunded);
+        }
+        if deposit.deduction > 0 {
+            let registry_client = event_registry::Client::new(
+                &env,
+                &get_event_registry_for(&env, payment.event_id.clone()),
+            );
+            let event_info = match registry_client.try_get_event(&payment.event_id) {
+                Ok(Ok(Some(info))) => info,
+                _ => return Err(TicketPaymentError::EventNotFound),
+            };
+            token_client.transfer(
+                &contract_address,
+                &event_info.payment_address,
+                &deposit.deduction,
+            );
+        }
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (
+                AgoraEvent::DepositReleased,
+                payment.event_id.clone(),
+                payment_id.clone(),
+            ),
+            DepositReleasedEvent {
+                payment_id,
+                refunded,
+                deducted: deposit.deduction,
+                timestamp: now,
+            },
+        );
+        Ok(refunded)
+    }
+
+    /// Returns the damage deposit held for a ticket, if any.
+    pub fn get_ticket_deposit(env: Env, payment_id: String) -> Option<TicketDeposit> {
+        get_ticket_deposit(&env, payment_id)
+    }
+
+    /// Records the start of an event's deposit window the first time it is
+    /// observed after the event stopped being active.
+    fn ensure_deposit_window(
+        env: &Env,
+        registry_client: &event_registry::Client,
+        event_id: &String,
+        event_info: &event_registry::EventInfo,
+    ) -> Result<u64, TicketPaymentError> {
+        if let Some(start) = get_deposit_window_start(env, event_id.clone()) {
+            return Ok(start);
+        }
+        // Deactivating an event is reversible, so only its completion or the
+        // end of its schedule opens the window
+        let now = env.ledger().timestamp();
+        let ended = event_info.status == event_registry::EventStatus::Completed
+            || registry_client
+                .try_get_event_schedule(event_id)
+                .ok()
+                .and_then(|r| r.ok())
+                .flatten()
+                .is_some_and(|schedule| schedule.end_time > 0 && now >= schedule.end_time);
+        if !ended {
+            return Err(TicketPaymentError::EventNotCompleted);
+        }
+        set_deposit_window_start(env, event_id.clone(), now);
+        Ok(now)
+    }
+
+    /// Heartbeat proving the organizer still controls their keys. Resets the
+    /// inactivity timer and voids any pending escrow recovery.
+    pub fn record_organizer_activity(env: Env, event_id: String) -> Result<(), TicketPaymentError> {
+        let event_info = Self::fetch_event(&env, &event_id)?;
+        event_info.organizer_address.require_auth();
+        record_organizer_activity(&env, event_id);
+        Ok(())
+    }
+
+    /// Starts recovery of an organizer escrow once the organizer has been inactive
+    /// for the timeout configured at registration. Only the recovery address may
+    /// initiate; the admin can veto within `RECOVERY_VETO_WINDOW`.
+    pub fn initiate_escrow_recovery(env: Env, event_id: String) -> Result<(), TicketPaymentError> {
+        let event_info = Self::fetch_event(&env, &event_id)?;
+        let config = Self::fetch_recovery_config(&env, &event_id)?;
+        config.recovery_address.require_auth();
+
+        if get_recovery_claim(&env, event_id.clone()).is_some() {
+            return Err(TicketPaymentError::RecoveryNotReady);
+        }
+        let last_activity =
+            get_organizer_activity(&env, event_id.clone()).max(event_info.created_at);
+        let now = env.ledger().timestamp();
+        if now < last_activity.saturating_add(config.inactivity_timeout) {
+            return Err(TicketPaymentError::RecoveryNotReady);
+        }
+        set_recovery_claim(&env, event_id.clone(), now);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::EscrowRecoveryInitiated, event_id.clone()),
+            EscrowRecoveryInitiatedEvent {
+                event_id,
+                recovery_address: config.recovery_address,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// Cancels a pending escrow recovery. Only callable by admin.
+    pub fn veto_escrow_recovery(env: Env, event_id: String) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        if get_recovery_claim(&env, event_id.clone()).is_none() {
+            return Err(TicketPaymentError::RecoveryNotReady);
+        }
+        remove_recovery_claim(&env, event_id.clone());
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::EscrowRecoveryVetoed, event_id.clone()),
+            EscrowRecoveryVetoedEvent {
+                event_id,
+                admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Pays the remaining organizer escrow in every token to the recovery
+    /// address once the veto window of a pending recovery has elapsed, and
+    /// closes the claim. Returns the amount recovered per token.
+    pub fn claim_escrow_recovery(
+        env: Env,
+        event_id: String,
+    ) -> Result<Map<Address, i128>, TicketPaymentError> {
+        let event_info = Self::fetch_event(&env, &event_id)?;
+        let config = Self::fetch_recovery_config(&env, &event_id)?;
+        config.recovery_address.require_auth();
+
+        let initiated_at = get_recovery_claim(&env, event_id.clone())
+            .ok_or(TicketPaymentError::RecoveryNotReady)?;
+        let now = env.ledger().timestamp();
+        if now < initiated_at.saturating_add(RECOVERY_VETO_WINDOW) {
+            return Err(TicketPaymentError::RecoveryNotReady);
+        }
+        if is_event_disputed(&env, event_id.clone()) {
+            return Err(TicketPaymentError::EventDisputed);
+        }
+        // Buyers of a cancelled event are owed refunds from this escrow
+        if matches!(event_info.status, event_registry::EventStatus::Cancelled) {
+            return Err(TicketPaymentError::EventCancelled);
+        }
+
+        let frozen = get_frozen_dispute_amount(&env, event_id.clone()).max(0);
+        let mut recovered: Map<Address, i128> = Map::new(&env);
+        for token_address in get_event_balance_tokens(&env, event_id.clone()).iter() {
+            let balance = get_event_token_balance(&env, event_id.clone(), &token_address);
+            let amount = balance.organizer_amount - frozen;
+            if amount <= 0 {
+                continue;
+            }
+
+            token::Client::new(&env, &token_address).transfer(
+                &env.current_contract_address(),
+                &config.recovery_address,
+                &amount,
+            );
+            append_ledger_entry(
+                &env,
+                event_id.clone(),
+                LedgerEntryType::Withdrawal,
+                amount,
+                token_address.clone(),
+                config.recovery_address.clone(),
+            );
+            record_event_withdrawal(&env, event_id.clone(), &token_address, amount);
+            subtract_from_active_escrow_total(&env, amount);
+            subtract_from_active_escrow_by_token(&env, token_address.clone(), amount);
+            recovered.set(token_address.clone(), amount);
+
+            #[allow(deprecated)]
+            env.events().publish(
+                (AgoraEvent::EscrowRecovered, event_id.clone()),
+                EscrowRecoveredEvent {
+                    event_id: event_id.clone(),
+                    recovery_address: config.recovery_address.clone(),
+                    token: token_address,
+                    amount,
+                    timestamp: now,
+                },
+            );
+        }
+        remove_recovery_claim(&env, event_id);
+        Ok(recovered)
+    }
+
+    /// Returns when the pending escrow recovery for an event was initiated, if any.
+    pub fn get_recovery_claim(env: Env, event_id: String) -> Option<u64> {
+        get_recovery_claim(&env, event_id)
+    }
+
+    /// Returns how much of a token the contract holds beyond its tracked escrow
+    /// liabilities, counting funds deposited in the yield strategy as held. Active
+    /// escrow includes unclaimed referral rewards and promoter commissions; damage
+    /// deposits, voucher balances and dispute bonds are owed on top of it. This
+    /// is the most `rescue_tokens` can withdraw.
+    pub fn get_rescuable_amount(env: Env, token_address: Address) -> i128 {
+        let held = token::Client::new(&env, &token_address)
+            .balance(&env.current_contract_address())
+            + get_yield_deployed(&env, token_address.clone());
+        let owed = crate::storage::get_active_escrow_by_token(&env, token_address.clone())
+            + crate::storage::get_held_funds(&env, token_address);
+        (held - owed).max(0)
+    }
+
+    /// Schedules withdrawal of tokens sent to the contract outside any tracked
+    /// flow, such as direct transfers or leftovers of a delisted token. The rescue
+    /// becomes executable after `RESCUE_TIMELOCK` and replaces any earlier pending
+    /// rescue of the same token. Only callable by admin.
+    pub fn propose_token_rescue(
+        env: Env,
+        token_address: Address,
+        amount: i128,
+        to: Address,
+    ) -> Result<u64, TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        validate_address(&env, &to)?;
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+        if amount > Self::get_rescuable_amount(env.clone(), token_address.clone()) {
+            return Err(TicketPaymentError::NoFundsAvailable);
+        }
+
+        let now = env.ledger().timestamp();
+        set_token_rescue(
+            &env,
+            &TokenRescue {
+                token: token_address.clone(),
+                amount,
+                to: to.clone(),
+                proposed_at: now,
+            },
+        );
+        let executable_at = now + RESCUE_TIMELOCK;
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::TokenRescueProposed,),
+            TokenRescueProposedEvent {
+                token: token_address,
+                amount,
+                to,
+                executable_at,
+            },
+        );
+        Ok(executable_at)
+    }
+
+    /// Withdraws a rescue proposed with the same `token_address`, `amount` and
+    /// `to` once its timelock has elapsed. The amount must still exceed tracked
+    /// escrow liabilities at execution time. Only callable by admin.
+    pub fn rescue_tokens(
+        env: Env,
+        token_address: Address,
+        amount: i128,
+        to: Address,
+    ) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+
+        let rescue = get_token_rescue(&env, token_address.clone())
+            .ok_or(TicketPaymentError::RescueNotReady)?;
+        let now = env.ledger().timestamp();
+        if rescue.amount != amount
+            || rescue.to != to
+            || now < rescue.proposed_at.saturating_add(RESCUE_TIMELOCK)
+        {
+            return Err(TicketPaymentError::RescueNotReady);
+        }
+        if amount > Self::get_rescuable_amount(env.clone(), token_address.clone()) {
+            return Err(TicketPaymentError::NoFundsAvailable);
+        }
+        remove_token_rescue(&env, token_address.clone());
+
+        token::Client::new(&env, &token_address).transfer(
+            &env.current_contract_address(),
+            &to,
+            &amount,
+        );
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::TokensRescued,),
+            TokensRescuedEvent {
+                token: token_address,
+                amount,
+                to,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// Withdraws the pending rescue of a token. Only callable by admin.
+    pub fn cancel_token_rescue(env: Env, token_address: Address) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        if get_token_rescue(&env, token_address.clone()).is_none() {
+            return Err(TicketPaymentError::RescueNotReady);
+        }
+        remove_token_rescue(&env, token_address.clone());
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::TokenRescueCancelled,),
+            TokenRescueCancelledEvent {
+                token: token_address,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the pending rescue of a token, if any.
+    pub fn get_token_rescue(env: Env, token_address: Address) -> Option<TokenRescue> {
+        get_token_rescue(&env, token_address)
+    }
+
+    /// Requires the organizer's authorization, or, when an operator acts, the
+    /// operator's authorization and a grant covering `permission`.
+    fn authorize_event_action(
+        env: &Env,
+        event_id: &String,
+        event_info: &event_registry::EventInfo,
+        operator: Option<Address>,
+        permission: u32,
+    ) -> Result<(), TicketPaymentError> {
+        match operator {
+            None => {
+                event_info.organizer_address.require_auth();
+                record_organizer_activity(env, event_id.clone());
+            }
+            Some(operator) => {
+                operator.require_auth();
+                let granted = get_operator_permissions(env, event_id.clone(), operator);
+                if granted & permission != permission {
+                    return Err(TicketPaymentError::Unauthorized);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn fetch_event(
+        env: &Env,
+        event_id: &String,
+    ) -> Result<event_registry::EventInfo, TicketPaymentError> {
+        let registry_client =
+            event_registry::Client::new(env, &get_event_registry_for(env, event_id.clone()));
+        match registry_client.try_get_event(event_id) {
+            Ok(Ok(Some(info))) => Ok(info),
+            _ => Err(TicketPaymentError::EventNotFound),
+        }
+    }
+
+    fn fetch_recovery_config(
+        env: &Env,
+        event_id: &String,
+    ) -> Result<event_registry::RecoveryConfig, TicketPaymentError> {
+        let registry_client =
+            event_registry::Client::new(env, &get_event_registry_for(env, event_id.clone()));
+        match registry_client.try_get_recovery_config(event_id) {
+            Ok(Ok(Some(config))) => Ok(config),
+            _ => Err(TicketPaymentError::Unauthorized),
+        }
+    }
+
+    /// Lends a ticket to `attendee`, who is then recorded as the one attending
+    /// when it is checked in. The holder keeps ownership, including the right to
+    /// refund or transfer it, and can revoke the delegation until check-in.
+    pub fn delegate_ticket(
+        env: Env,
+        payment_id: String,
+        attendee: Address,
+    ) -> Result<(), TicketPaymentError> {
+        let payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        payment.buyer_address.require_auth();
+        if !matches!(
+            payment.status,
+            PaymentStatus::Pending | PaymentStatus::Confirmed
+        ) {
+            return Err(TicketPaymentError::InvalidPaymentStatus);
+        }
+        if attendee == payment.buyer_address {
+            panic!("Attendee must differ from the holder");
+        }
+        set_ticket_delegate(&env, payment_id.clone(), Some(attendee.clone()));
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (
+                AgoraEvent::TicketDelegated,
+                payment.event_id.clone(),
+                payment_id.clone(),
+            ),
+            TicketDelegatedEvent {
+                payment_id,
+                holder: payment.buyer_address,
+                attendee: Some(attendee),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Ends a delegation so the holder attends in person. Only callable by the holder.
+    pub fn revoke_delegation(env: Env, payment_id: String) -> Result<(), TicketPaymentError> {
+        let payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        payment.buyer_address.require_auth();
+        if get_ticket_delegate(&env, payment_id.clone()).is_none() {
+            return Ok(());
+        }
+        set_ticket_delegate(&env, payment_id.clone(), None);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (
+                AgoraEvent::TicketDelegated,
+                payment.event_id.clone(),
+                payment_id.clone(),
+            ),
+            TicketDelegatedEvent {
+                payment_id,
+                holder: payment.buyer_address,
+                attendee: None,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the attendee a ticket is delegated to, if any.
+    pub fn get_ticket_delegate(env: Env, payment_id: String) -> Option<Address> {
+        get_ticket_delegate(&env, payment_id)
+    }
+
+    /// Transfers a ticket from the current holder to a new owner.
+    /// If `sale_price` is provided, it is validated against the event's resale cap
+    /// and a positive price is settled on-chain: the new owner pays it, the
+    /// platform resale fee and organizer royalty are retained and the seller
+    /// receives the rest. Unpriced transfers pay the event's transfer fee.
+    pub fn transfer_ticket(
+        env: Env,
+        payment_id: String,
+        to: Address,
+        sale_price: Option<i128>,
+        memo: Option<Bytes>,
+    ) -> Result<(), TicketPaymentError> {
+        if !is_initialized(&env) {
+            panic!("Contract not initialized");
+        }
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+
+        let mut payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        ensure_event_not_paused(&env, &payment.event_id)?;
+
+        if payment.status != PaymentStatus::Confirmed {
+            return Err(TicketPaymentError::InvalidPaymentStatus);
+        }
+        if is_payment_disputed(&env, payment_id.clone()) {
+            return Err(TicketPaymentError::EventDisputed);
+        }
+
+        let from = payment.buyer_address.clone();
+        from.require_auth();
+        validate_memo(&memo)?;
+
+        if from == to {
+            return Err(TicketPaymentError::InvalidAddress);
+        }
+        if is_ticket_listed(&env, payment_id.clone()) {
+            return Err(TicketPaymentError::TicketListed);
+        }
+
+        // Validate resale price against the organizer's cap
+        let price = sale_price.unwrap_or(0);
+        if price < 0 {
+            return Err(TicketPaymentError::InvalidPrice);
+        }
+        if sale_price.is_some() {
+            Self::check_resale_cap(&env, &payment, &payment.token_address, price)?;
+        }
+        if price > 0 {
+            // The new owner pays the sale price through the contract
+            to.require_auth();
+        }
+
+        complete_transfer(&env, &mut payment, &to, price, memo)
+    }
+
+    /// Rejects a resale price above the event's `resale_cap_bps` over face value.
+    fn check_resale_cap(
+        env: &Env,
+        payment: &Payment,
+        token: &Address,
+        price: i128,
+    ) -> Result<(), TicketPaymentError> {
+        let event_registry_addr = get_event_registry_for(env, payment.event_id.clone());
+        let registry_client = event_registry::Client::new(env, &event_registry_addr);
+
+        if let Some(event_info) = registry_client.get_event(&payment.event_id) {
+            if let Some(cap_bps) = event_info.resale_cap_bps {
+                // Face value of the tier, quoted in the token the resale settles in
+                let tier = event_info
+                    .tiers
+                    .get(payment.ticket_tier_id.clone())
+                    .ok_or(TicketPaymentError::TierNotFound)?;
+                let (original_price, _) =
+                    quote_tier_price(env, &tier, token, env.ledger().timestamp())?;
+
+                let max_price =
+                    add_bps(original_price, cap_bps).ok_or(TicketPaymentError::ArithmeticError)?;
+
+                if price > max_price {
+                    return Err(TicketPaymentError::ResalePriceExceedsCap);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the royalty in basis points the organizer receives from every
+    /// on-chain resale of this event's tickets, overriding the royalty the event
+    /// was registered with. Only the organizer may set it.
+    pub fn set_resale_royalty(
+        env: Env,
+        event_id: String,
+        royalty_bps: u32,
+    ) -> Result<(), TicketPaymentError> {
+        let event_info = Self::fetch_event(&env, &event_id)?;
+        event_info.organizer_address.require_auth();
+        record_organizer_activity(&env, event_id.clone());
+        if !is_valid_bps(royalty_bps) {
+            return Err(TicketPaymentError::InvalidFeeBps);
+        }
+        set_resale_royalty_bps(&env, event_id, royalty_bps);
+        Ok(())
+    }
+
+    /// Sets the platform fee in basis points taken from every on-chain resale:
+    /// marketplace sales, paid transfer offers and priced transfers. Only
+    /// callable by admin.
+    pub fn set_resale_fee_bps(env: Env, bps: u32) -> Result<(), TicketPaymentError> {
+        let admin = get_admin(&env).ok_or(TicketPaymentError::NotInitialized)?;
+        admin.require_auth();
+        if bps > MAX_BPS {
+            return Err(TicketPaymentError::InvalidFeeBps);
+        }
+        set_resale_fee_bps(&env, bps);
+        Ok(())
+    }
+
+    /// Returns the platform fee on resales in basis points.
+    pub fn get_resale_fee_bps(env: Env) -> u32 {
+        get_resale_fee_bps(&env)
+    }
+
+    /// Returns the organizer royalty on resales of an event's tickets, in bps.
+    pub fn get_resale_royalty(env: Env, event_id: String) -> u32 {
+        resale_royalty_bps(&env, &event_id)
+    }
+
+    /// Lists a confirmed ticket for resale at `price` in `token`. The price must
+    /// respect the event's resale cap. The ticket stays in escrow until the
+    /// listing is bought or cancelled.
+    pub fn list_ticket_for_sale(
+        env: Env,
+        payment_id: String,
+        price: i128,
+        token: Address,
+    ) -> Result<(), TicketPaymentError> {
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        let payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        ensure_event_not_paused(&env, &payment.event_id)?;
+        if payment.status != PaymentStatus::Confirmed {
+            return Err(TicketPaymentError::InvalidPaymentStatus);
+        }
+        let seller = payment.buyer_address.clone();
+        seller.require_auth();
+        if is_ticket_listed(&env, payment_id.clone()) {
+            return Err(TicketPaymentError::TicketListed);
+        }
+        if is_payment_disputed(&env, payment_id.clone()) {
+            return Err(TicketPaymentError::EventDisputed);
+        }
+        if price <= 0 {
+            return Err(TicketPaymentError::InvalidPrice);
+        }
+        if !is_token_whitelisted(&env, &token) {
+            return Err(TicketPaymentError::TokenNotWhitelisted);
+        }
+        Self::check_resale_cap(&env, &payment, &token, price)?;
+
+        set_listing(
+            &env,
+            &Listing {
+                payment_id: payment_id.clone(),
+                event_id: payment.event_id.clone(),
+                seller: seller.clone(),
+                token: token.clone(),
+                price,
+                listed_at: env.ledger().timestamp(),
+            },
+        );
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (
+                AgoraEvent::TicketListed,
+                payment.event_id.clone(),
+                payment_id.clone(),
+            ),
+            TicketListedEvent {
+                payment_id,
+                event_id: payment.event_id,
+                seller,
+                token,
+                price,
+            },
+        );
+        Ok(())
+    }
+
+    /// Withdraws a listing, releasing the ticket back to the seller.
+    pub fn cancel_listing(env: Env, payment_id: String) -> Result<(), TicketPaymentError> {
+        let listing = get_listing(&env, payment_id.clone())
+            .ok_or(TicketPaymentError::InvalidPaymentStatus)?;
+        listing.seller.require_auth();
+        remove_listing(&env, &listing);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (
+                AgoraEvent::TicketListingCancelled,
+                listing.event_id.clone(),
+                payment_id.clone(),
+            ),
+            TicketListingCancelledEvent {
+                payment_id,
+                seller: listing.seller,
+            },
+        );
+        Ok(())
+    }
+
+    /// Buys a listed ticket. In one step the buyer pays the listing price, the
+    /// organizer royalty is credited to the event escrow, the seller receives the
+    /// rest, and the ticket (and its NFT, if any) moves to the buyer.
+    pub fn buy_listed_ticket(
+        env: Env,
+        payment_id: String,
+        buyer: Address,
+    ) -> Result<(), TicketPaymentError> {
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        buyer.require_auth();
+        let listing = get_listing(&env, payment_id.clone())
+            .ok_or(TicketPaymentError::InvalidPaymentStatus)?;
+        ensure_event_not_paused(&env, &listing.event_id)?;
+        let mut payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        // A cancelled event's bulk refund can settle a ticket while it is listed
+        if payment.status != PaymentStatus::Confirmed || payment.buyer_address != listing.seller {
+            return Err(TicketPaymentError::InvalidPaymentStatus);
+        }
+        if buyer == listing.seller {
+            return Err(TicketPaymentError::InvalidAddress);
+        }
+
+        ensure_transfer_unlocked(&env, &payment)?;
+        remove_listing(&env, &listing);
+        settle_resale(&env, &payment, &listing.token, &buyer, listing.price)?;
+        reassign_ticket(&env, &mut payment, &buyer, None);
+        Ok(())
+    }
+
+    /// Offers a confirmed ticket to `to`, who takes it with `accept_transfer`
+    /// paying `price` through the contract. Replaces any earlier offer and
+    /// lapses after `TRANSFER_OFFER_TTL`. Returns the expiry timestamp.
+    pub fn offer_transfer(
+        env: Env,
+        payment_id: String,
+        to: Address,
+        price: i128,
+    ) -> Result<u64, TicketPaymentError> {
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        let payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        ensure_event_not_paused(&env, &payment.event_id)?;
+        if payment.status != PaymentStatus::Confirmed {
+            return Err(TicketPaymentError::InvalidPaymentStatus);
+        }
+        if is_payment_disputed(&env, payment_id.clone()) {
+            return Err(TicketPaymentError::EventDisputed);
+        }
+        let from = payment.buyer_address.clone();
+        from.require_auth();
+        if from == to {
+            return Err(TicketPaymentError::InvalidAddress);
+        }
+        if price < 0 {
+            return Err(TicketPaymentError::InvalidPrice);
+        }
+        if is_ticket_listed(&env, payment_id.clone()) {
+            return Err(TicketPaymentError::TicketListed);
+        }
+        Self::check_resale_cap(&env, &payment, &payment.token_address, price)?;
+
+        let expires_at = env
+            .ledger()
+            .timestamp()
+            .checked_add(TRANSFER_OFFER_TTL)
+            .ok_or(TicketPaymentError::ArithmeticError)?;
+        set_transfer_offer(
+            &env,
+            &TransferOffer {
+                payment_id: payment_id.clone(),
+                event_id: payment.event_id.clone(),
+                from: from.clone(),
+                to: to.clone(),
+                token: payment.token_address.clone(),
+                price,
+                expires_at,
+            },
+        );
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (
+                AgoraEvent::TransferOffered,
+                payment.event_id.clone(),
+                payment_id.clone(),
+            ),
+            TransferOfferedEvent {
+                payment_id,
+                from,
+                to,
+                price,
+                expires_at,
+            },
+        );
+        Ok(expires_at)
+    }
+
+    /// Withdraws the holder's pending transfer offer.
+    pub fn cancel_transfer_offer(env: Env, payment_id: String) -> Result<(), TicketPaymentError> {
+        let offer = get_transfer_offer(&env, payment_id.clone())
+            .ok_or(TicketPaymentError::InvalidPaymentStatus)?;
+        offer.from.require_auth();
+        remove_transfer_offer(&env, payment_id.clone());
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (
+                AgoraEvent::TransferOfferCancelled,
+                offer.event_id.clone(),
+                payment_id.clone(),
+            ),
+            TransferOfferCancelledEvent {
+                payment_id,
+                from: offer.from,
+            },
+        );
+        Ok(())
+    }
+
+    /// Completes a pending offer: the recipient pays the offer price, split
+    /// like a marketplace resale, and receives the ticket. A free offer
+    /// charges the event's transfer fee to the holder, as `transfer_ticket` does.
+    pub fn accept_transfer(env: Env, payment_id: String) -> Result<(), TicketPaymentError> {
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        let offer = get_transfer_offer(&env, payment_id.clone())
+            .ok_or(TicketPaymentError::InvalidPaymentStatus)?;
+        offer.to.require_auth();
+        if env.ledger().timestamp() >= offer.expires_at {
+            return Err(TicketPaymentError::TransferOfferExpired);
+        }
+        ensure_event_not_paused(&env, &offer.event_id)?;
+        let mut payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        // The ticket may have been refunded or moved since the offer was made
+        if payment.status != PaymentStatus::Confirmed || payment.buyer_address != offer.from {
+            return Err(TicketPaymentError::InvalidPaymentStatus);
+        }
+        if is_payment_disputed(&env, payment_id.clone()) {
+            return Err(TicketPaymentError::EventDisputed);
+        }
+        if is_ticket_listed(&env, payment_id.clone()) {
+            return Err(TicketPaymentError::TicketListed);
+        }
+
+        complete_transfer(&env, &mut payment, &offer.to, offer.price, None)
+    }
+
+    /// Returns a ticket's settled on-chain resales, oldest first.
+    pub fn get_resale_history(env: Env, payment_id: String) -> Vec<ResaleRecord> {
+        get_resale_history(&env, payment_id)
+    }
+
+    /// Returns the pending transfer offer for a ticket, if any.
+    pub fn get_transfer_offer(env: Env, payment_id: String) -> Option<TransferOffer> {
+        get_transfer_offer(&env, payment_id)
+    }
+
+    /// Returns the active listing for a ticket, if any.
+    pub fn get_listing(env: Env, payment_id: String) -> Option<Listing> {
+        get_listing(&env, payment_id)
+    }
+
+    /// Returns the payment ids of an event's listed tickets, oldest first.
+    pub fn get_event_listings(env: Env, event_id: String) -> Vec<String> {
+        get_event_listings(&env, event_id)
+    }
+
+    /// Returns up to `limit` of an event's listed payment ids starting at `offset`.
+    pub fn get_event_listings_paginated(
+        env: Env,
+        event_id: String,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<String> {
+        agora_pagination::page(
+            &get_event_listings(&env, event_id),
+            PageCursor::new(offset, limit),
+        )
+    }
+
+    /// Reserves a ticket paid for in `num_installments` equal installments of the
+    /// tier's price in the default token, one due every `INSTALLMENT_INTERVAL`.
+    /// The first installment is charged now and the tier's inventory is held;
+    /// the ticket stays `Reserved` until the last one is paid. Promotions and
+    /// discount codes do not apply to installment plans.
+    pub fn start_installment_plan(
+        env: Env,
+        payment_id: String,
+        event_id: String,
+        ticket_tier_id: String,
+        buyer_address: Address,
+        num_installments: u32,
+    ) -> Result<InstallmentPlan, TicketPaymentError> {
+        if !is_initialized(&env) {
+            panic!("Contract not initialized");
+        }
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+        ensure_event_not_paused(&env, &event_id)?;
+        buyer_address.require_auth();
+
+        if !(2..=MAX_INSTALLMENTS).contains(&num_installments) {
+            panic!("Installments must be between 2 and MAX_INSTALLMENTS");
+        }
+        if get_payment(&env, payment_id.clone()).is_some() {
+            return Err(TicketPaymentError::PaymentAlreadyExists);
+        }
+
+        let event_registry_addr = resolve_event_registry(&env, event_id.clone(), None)?;
+        let registry_client = event_registry::Client::new(&env, &event_registry_addr);
+        let event_info = match registry_client.try_get_event(&event_id) {
+            Ok(Ok(Some(info))) => info,
+            _ => return Err(TicketPaymentError::EventNotFound),
+        };
+        if !event_info.is_active
+            || matches!(event_info.status, event_registry::EventStatus::Cancelled)
+            || event_info.visibility == event_registry::EventVisibility::Draft
+        {
+            return Err(TicketPaymentError::EventInactive);
+        }
+        ensure_within_buyer_limit(&env, &event_info, &buyer_address, 1)?;
+        let tier = event_info
+            .tiers
+            .get(ticket_tier_id.clone())
+            .ok_or(TicketPaymentError::TierNotFound)?;
+        let now = env.ledger().timestamp();
+        if (tier.visible_from > 0 && now < tier.visible_from)
+            || (tier.visible_until > 0 && now >= tier.visible_until)
+        {
+            return Err(TicketPaymentError::TierNotVisible);
+        }
+
+        let token_address = crate::storage::get_usdc_token(&env);
+        let total_amount = match tier.token_prices.get(token_address.clone()) {
+            Some(price) => price,
+            None => base_tier_price(&tier, now)?,
+        };
+        if total_amount <= 0 {
+            return Err(TicketPaymentError::InvalidPrice);
+        }
+        // Rounding dust is collected with the first installment
+        let (installment, dust) = split_evenly(total_amount, num_installments)
+            .ok_or(TicketPaymentError::ArithmeticError)?;
+        let first_installment = installment
+            .checked_add(dust)
+            .ok_or(TicketPaymentError::ArithmeticError)?;
+        collect_payment(&env, &token_address, &buyer_address, first_installment)?;
+
+        // Hold the ticket's inventory for the duration of the plan
+        registry_client.increment_inventory(&event_id, &ticket_tier_id, &1);
+
+        let pricing = compute_pricing_breakdown(
+            total_amount,
+            1,
+            0,
+            0,
+            event_info.platform_fee_percent,
+            0,
+            false,
+        )?;
+        store_payment(
+            &env,
+            Payment {
+                payment_id: payment_id.clone(),
+                event_id: event_id.clone(),
+                buyer_address: buyer_address.clone(),
+                ticket_tier_id,
+                amount: total_amount,
+                token_address: token_address.clone(),
+                platform_fee: pricing.platform_fee,
+                organizer_amount: pricing.organizer_net,
+                status: PaymentStatus::Reserved,
+                transaction_hash: String::from_str(&env, ""),
+                created_at: now,
+                confirmed_at: None,
+                refunded_amount: 0,
+                identity_hash: None,
+                memo: None,
+                seat_id: None,
+            },
+        );
+
+        let plan = InstallmentPlan {
+            payment_id: payment_id.clone(),
+            token: token_address,
+            total_amount,
+            paid_amount: first_installment,
+            num_installments,
+            installments_paid: 1,
+            next_due: now + INSTALLMENT_INTERVAL,
+        };
+        set_installment_plan(&env, &plan);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (
+                AgoraEvent::InstallmentPlanStarted,
+                event_id.clone(),
+                payment_id.clone(),
+            ),
+            InstallmentPlanStartedEvent {
+                payment_id,
+                event_id,
+                buyer: buyer_address,
+                total_amount,
+                num_installments,
+                next_due: plan.next_due,
+                timestamp: now,
+            },
+        );
+
+        Ok(plan)
+    }
+
+    /// Pays the next installment of a plan on or before its deadline. The final
+    /// installment completes the purchase: the ticket moves to `Pending` like any
+    /// other sale, its proceeds are credited to the event escrow and its NFT is
+    /// minted.
+    pub fn pay_installment(
+        env: Env,
+        payment_id: String,
+    ) -> Result<InstallmentPlan, TicketPaymentError> {
+        if !is_initialized(&env) {
+            panic!("Contract not initialized");
+        }
+        if is_paused(&env) {
+            return Err(TicketPaymentError::ContractPaused);
+        }
+
+        let mut plan = get_installment_plan(&env, payment_id.clone())
+            .ok_or(TicketPaymentError::PaymentNotFound)?;
+        let mut payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        payment.buyer_address.require_auth();
+
+        if payment.status != PaymentStatus::Reserved {
+            return Err(TicketPaymentError::InvalidPaymentStatus);
+        }
+        let now = env.ledger().timestamp();
+        if now > plan.next_due {
+            return Err(TicketPaymentError::InstallmentOverdue);
+        }
+
+        let (installment, _) = split_evenly(plan.total_amount, plan.num_installments)
+            .ok_or(TicketPaymentError::ArithmeticError)?;
+        collect_payment(&env, &plan.token, &payment.buyer_address, installment)?;
+        plan.paid_amount = plan
+            .paid_amount
+            .checked_add(installment)
+            .ok_or(TicketPaymentError::ArithmeticError)?;
+        plan.installments_paid += 1;
+
+        if plan.installments_paid < plan.num_installments {
+            plan.next_due += INSTALLMENT_INTERVAL;
+        } else {
+            plan.next_due = 0;
+            payment.status = PaymentStatus::Pending;
+            store_payment(&env, payment.clone());
+
+            let event_id = payment.event_id.clone();
+            let event_registry_addr = get_event_registry_for(&env, event_id.clone());
+            update_event_balance(
+                &env,
+                event_id.clone(),
+                &payment.token_address,
+                payment.organizer_amount,
+                payment.platform_fee,
+            );
+            append_ledger_entry(
+                &env,
+                event_id.clone(),
+                LedgerEntryType::Sale,
+                plan.total_amount,
+                plan.token.clone(),
+                payment.buyer_address.clone(),
+            );
+            add_to_total_volume_processed(&env, plan.total_amount);
+            add_to_total_fees_collected_by_token(&env, plan.token.clone(), payment.platform_fee);
+            add_to_tenant_fees_collected(
+                &env,
+                event_registry_addr.clone(),
+                plan.token.clone(),
+                payment.platform_fee,
+            );
+            if let Ok(Ok(Some(event_info))) =
+                event_registry::Client::new(&env, &event_registry_addr).try_get_event(&event_id)
+            {
+                check_withdrawal_threshold(&env, &event_info)?;
+            }
+            mint_ticket_nft(&env, &payment.buyer_address, &event_id, &payment_id);
+        }
+        set_installment_plan(&env, &plan);
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (
+                AgoraEvent::InstallmentPaid,
+                payment.event_id.clone(),
+                payment_id.clone(),
+            ),
+            InstallmentPaidEvent {
+                payment_id,
+                amount: installment,
+                installments_paid: plan.installments_paid,
+                next_due: plan.next_due,
+                timestamp: now,
+            },
+        );
+
+        Ok(plan)
+    }
+
+    /// Forfeits an installment plan whose deadline has passed; anyone may call
+    /// this. The held ticket returns to inventory and the buyer gets back what
+    /// was paid less the refund processing fee. Plans for cancelled events can
+    /// be forfeited at any time and are refunded in full.
+    pub fn forfeit_installment_plan(
+        env: Env,
+        payment_id: String,
+    ) -> Result<i128, TicketPaymentError> {
+        if !is_initialized(&env) {
+            panic!("Contract not initialized");
         }
 
-        // Transfer net revenue to organizer
-        if organizer_amount > 0 {
-            token_client.transfer(
-                &contract_address,
-                &event_info.payment_address,
-                &organizer_amount,
-            );
+        let plan = get_installment_plan(&env, payment_id.clone())
+            .ok_or(TicketPaymentError::PaymentNotFound)?;
+        let mut payment =
+            get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
+        if payment.status != PaymentStatus::Reserved {
+            return Err(TicketPaymentError::InvalidPaymentStatus);
         }
 
-        // Update balances
-        crate::storage::set_event_balance(
+        let registry_client = event_registry::Client::new(
             &env,
-            event_id.clone(),
-            crate::types::EventBalance {
-                organizer_amount: 0,
-                total_withdrawn: balance.total_withdrawn + organizer_amount,
-                platform_fee: 0,
-            },
+            &get_event_registry_for(&env, payment.event_id.clone()),
         );
+        let event_info = match registry_client.try_get_event(&payment.event_id) {
+            Ok(Ok(Some(info))) => info,
+            _ => return Err(TicketPaymentError::EventNotFound),
+        };
+        let is_cancelled = matches!(event_info.status, event_registry::EventStatus::Cancelled);
+        let now = env.ledger().timestamp();
+        if !is_cancelled && now <= plan.next_due {
+            return Err(TicketPaymentError::InvalidPaymentStatus);
+        }
 
-        let total_transferred = organizer_amount;
-        if total_transferred > 0 {
-            subtract_from_active_escrow_total(&env, total_transferred);
-            subtract_from_active_escrow_by_token(&env, token_address, total_transferred);
+        let fee = if is_cancelled {
+            0
+        } else {
+            let config = get_refund_processing_fee(&env);
+            let fee = mul_div_bps(plan.paid_amount, config.bps)
+                .and_then(|v| v.checked_add(config.fixed_amount))
+                .ok_or(TicketPaymentError::ArithmeticError)?;
+            core::cmp::min(fee, plan.paid_amount)
+        };
+        let refund_amount = plan.paid_amount - fee;
+
+        registry_client.decrement_inventory(&payment.event_id, &payment.ticket_tier_id);
+        payment.status = PaymentStatus::Refunded;
+        payment.refunded_amount = refund_amount;
+        payment.confirmed_at = Some(now);
+        store_payment(&env, payment.clone());
+
+        if refund_amount > 0 {
+            token::Client::new(&env, &plan.token).transfer(
+                &env.current_contract_address(),
+                &payment.buyer_address,
+                &refund_amount,
+            );
+        }
+        subtract_from_active_escrow_total(&env, refund_amount);
+        subtract_from_active_escrow_by_token(&env, plan.token.clone(), refund_amount);
+
+        // The fee stays in the contract as withdrawable platform revenue
+        if fee > 0 {
+            subtract_from_active_escrow_total(&env, fee);
+            subtract_from_active_escrow_by_token(&env, plan.token.clone(), fee);
+            add_to_total_fees_collected_by_token(&env, plan.token.clone(), fee);
+            add_to_refund_fees_collected(&env, plan.token, fee);
         }
 
         #[allow(deprecated)]
         env.events().publish(
-            (AgoraEvent::RevenueClaimed,),
-            RevenueClaimedEvent {
-                event_id,
-                organizer_address: event_info.organizer_address,
-                amount: organizer_amount,
-                timestamp,
+            (
+                AgoraEvent::InstallmentPlanForfeited,
+                payment.event_id.clone(),
+                payment_id.clone(),
+            ),
+            InstallmentPlanForfeitedEvent {
+                payment_id,
+                refunded: refund_amount,
+                fee,
+                timestamp: now,
             },
         );
 
-        Ok(organizer_amount)
+        Ok(refund_amount)
     }
 
-    /// Returns all payments for a specific buyer.
-    pub fn get_buyer_payments(env: Env, buyer_address: Address) -> soroban_sdk::Vec<String> {
-        crate::storage::get_buyer_payments(&env, buyer_address)
+    /// Returns the installment plan of a reserved or plan-paid ticket, if any.
+    pub fn get_installment_plan(env: Env, payment_id: String) -> Option<InstallmentPlan> {
+        get_installment_plan(&env, payment_id)
     }
 
-    /// Sets the transfer fee for an event. Only the organizer can call this.
-    pub fn set_transfer_fee(
-        env: Env,
-        event_id: String,
-        amount: i128,
-    ) -> Result<(), TicketPaymentError> {
+    /// Locks a cancelled event's escrow into a refund pool its buyers are paid
+    /// from. Only callable by the event's registry, which reports the
+    /// cancellation from `cancel_event`; repeated reports are ignored.
+    pub fn on_event_cancelled(env: Env, event_id: String) -> Result<(), TicketPaymentError> {
         if !is_initialized(&env) {
             panic!("Contract not initialized");
         }
+        get_event_registry_for(&env, event_id.clone()).require_auth();
 
-        let event_registry_addr = get_event_registry(&env);
-        let registry_client = event_registry::Client::new(&env, &event_registry_addr);
-
-        let event_info = match registry_client.try_get_event(&event_id) {
-            Ok(Ok(Some(info))) => info,
-            _ => return Err(TicketPaymentError::EventNotFound),
+        if get_refund_pool(&env, event_id.clone()).is_some() {
+            return Ok(());
+        }
+        let mut locked = Map::new(&env);
+        for token in get_event_balance_tokens(&env, event_id.clone()).iter() {
+            let balance = get_event_token_balance(&env, event_id.clone(), &token);
+            let amount = balance
+                .organizer_amount
+                .checked_add(balance.platform_fee)
+                .ok_or(TicketPaymentError::ArithmeticError)?;
+            if amount > 0 {
+                locked.set(token, amount);
+            }
+        }
+        let now = env.ledger().timestamp();
+        let pool = RefundPool {
+            event_id: event_id.clone(),
+            locked,
+            claimed: Map::new(&env),
+            claim_deadline: now + REFUND_CLAIM_WINDOW,
+            swept: Vec::new(&env),
         };
+        set_refund_pool(&env, &pool);
 
-        event_info.organizer_address.require_auth();
-
-        if amount < 0 {
-            panic!("Transfer fee must be non-negative");
-        }
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::RefundPoolCreated, event_id.clone()),
+            RefundPoolCreatedEvent {
+                event_id,
+                locked: pool.locked,
+                claim_deadline: pool.claim_deadline,
+                timestamp: now,
+            },
+        );
 
-        set_transfer_fee(&env, event_id, amount);
         Ok(())
     }
 
-    /// Transfers a ticket from the current holder to a new owner.
-    /// If `sale_price` is provided, it is validated against the event's resale cap.
-    pub fn transfer_ticket(
+    /// Refunds a ticket of a cancelled event in full from the event's refund
+    /// pool. Anyone may call this, e.g. a keeper distributing refunds; the
+    /// funds always go to the ticket's buyer. Claims close at the pool's deadline.
+    pub fn claim_from_refund_pool(
         env: Env,
         payment_id: String,
-        to: Address,
-        sale_price: Option<i128>,
-    ) -> Result<(), TicketPaymentError> {
+    ) -> Result<i128, TicketPaymentError> {
         if !is_initialized(&env) {
             panic!("Contract not initialized");
         }
@@ -1257,93 +6384,198 @@ impl TicketPaymentContract {
 
         let mut payment =
             get_payment(&env, payment_id.clone()).ok_or(TicketPaymentError::PaymentNotFound)?;
-
-        if payment.status != PaymentStatus::Confirmed {
+        let mut pool = get_refund_pool(&env, payment.event_id.clone())
+            .ok_or(TicketPaymentError::NoFundsAvailable)?;
+        let now = env.ledger().timestamp();
+        // Reserved tickets are unwound through `forfeit_installment_plan`
+        if !matches!(
+            payment.status,
+            PaymentStatus::Pending | PaymentStatus::Confirmed | PaymentStatus::CheckedIn
+        ) {
             return Err(TicketPaymentError::InvalidPaymentStatus);
         }
-
-        let from = payment.buyer_address.clone();
-        from.require_auth();
-
-        if from == to {
-            return Err(TicketPaymentError::InvalidAddress);
+        if is_payment_disputed(&env, payment_id.clone()) {
+            return Err(TicketPaymentError::EventDisputed);
         }
 
-        // Validate resale price against the organizer's cap
-        if let Some(price) = sale_price {
-            let event_registry_addr = get_event_registry(&env);
-            let registry_client = event_registry::Client::new(&env, &event_registry_addr);
-
-            if let Some(event_info) = registry_client.get_event(&payment.event_id) {
-                if let Some(cap_bps) = event_info.resale_cap_bps {
-                    // Look up the original tier face-value price
-                    let tier = event_info
-                        .tiers
-                        .get(payment.ticket_tier_id.clone())
-                        .ok_or(TicketPaymentError::TierNotFound)?;
-                    let original_price = tier.price;
-
-                    // max_price = original_price * (10000 + cap_bps) / 10000
-                    let max_price = original_price
-                        .checked_mul(
-                            (10000i128)
-                                .checked_add(cap_bps as i128)
-                                .unwrap_or(i128::MAX),
-                        )
-                        .ok_or(TicketPaymentError::ArithmeticError)?
-                        / 10000;
+        // Earlier partial refunds already came back out of the ticket's price,
+        // and commissions claimed on the sale are not refunded again
+        let (commission_recovered, commission_withheld) =
+            reverse_ticket_commissions(&env, &payment);
+        let refund_amount = payment
+            .amount
+            .checked_sub(payment.refunded_amount)
+            .and_then(|v| v.checked_sub(commission_withheld))
+            .ok_or(TicketPaymentError::ArithmeticError)?
+            .max(0);
+        draw_refund_pool(
+            &env,
+            &mut pool,
+            &payment.token_address,
+            (refund_amount - commission_recovered).max(0),
+        )?;
+        set_refund_pool(&env, &pool);
 
-                    if price > max_price {
-                        return Err(TicketPaymentError::ResalePriceExceedsCap);
-                    }
-                }
-            }
+        if refund_amount > 0 {
+            token::Client::new(&env, &payment.token_address).transfer(
+                &env.current_contract_address(),
+                &payment.buyer_address,
+                &refund_amount,
+            );
+            append_ledger_entry(
+                &env,
+                payment.event_id.clone(),
+                LedgerEntryType::Refund,
+                refund_amount,
+                payment.token_address.clone(),
+                payment.buyer_address.clone(),
+            );
+        }
+        if let Some(listing) = get_listing(&env, payment_id.clone()) {
+            remove_listing(&env, &listing);
         }
+        payment.status = PaymentStatus::Refunded;
+        payment.refunded_amount = payment.amount;
+        payment.confirmed_at = Some(now);
+        store_payment(&env, payment.clone());
+        burn_ticket_nft(&env, &payment_id);
 
-        let transfer_fee = get_transfer_fee(&env, payment.event_id.clone());
+        update_event_balance(
+            &env,
+            payment.event_id.clone(),
+            &payment.token_address,
+            -payment.organizer_amount,
+            -payment.platform_fee,
+        );
+        subtract_from_active_escrow_total(&env, refund_amount);
+        subtract_from_active_escrow_by_token(&env, payment.token_address.clone(), refund_amount);
 
-        if transfer_fee > 0 {
-            let token_address = crate::storage::get_usdc_token(&env);
-            let token_client = token::Client::new(&env, &token_address);
-            let contract_address = env.current_contract_address();
+        #[allow(deprecated)]
+        env.events().publish(
+            (
+                AgoraEvent::RefundPoolClaimed,
+                payment.event_id.clone(),
+                payment_id.clone(),
+            ),
+            RefundPoolClaimedEvent {
+                event_id: payment.event_id,
+                payment_id,
+                buyer: payment.buyer_address,
+                amount: refund_amount,
+                timestamp: now,
+            },
+        );
 
-            // Transfer fee from old owner to contract
-            token_client.transfer_from(&contract_address, &from, &contract_address, &transfer_fee);
+        Ok(refund_amount)
+    }
 
-            // Update escrow balances (fee goes to organizer)
-            update_event_balance(&env, payment.event_id.clone(), transfer_fee, 0);
+    /// Pays the organizer's share of a refund pool's unclaimed funds in
+    /// `token_address` out to the organizer once the claim window has closed.
+    /// Each token is swept once. Platform fees on unclaimed tickets stay with
+    /// the platform.
+    pub fn sweep_unclaimed(
+        env: Env,
+        event_id: String,
+        token_address: Address,
+    ) -> Result<i128, TicketPaymentError> {
+        if !is_initialized(&env) {
+            panic!("Contract not initialized");
         }
+        let event_info = Self::fetch_event(&env, &event_id)?;
+        event_info.organizer_address.require_auth();
 
-        // Update payment record
-        payment.buyer_address = to.clone();
-        let key = crate::types::DataKey::Payment(payment_id.clone());
-        env.storage().persistent().set(&key, &payment);
+        let mut pool =
+            get_refund_pool(&env, event_id.clone()).ok_or(TicketPaymentError::NoFundsAvailable)?;
+        let now = env.ledger().timestamp();
+        if pool.swept.contains(&token_address) || now <= pool.claim_deadline {
+            return Err(TicketPaymentError::NoFundsAvailable);
+        }
+        pool.swept.push_back(token_address.clone());
+        set_refund_pool(&env, &pool);
 
-        // Update indices
-        remove_payment_from_buyer_index(&env, from.clone(), payment_id.clone());
-        add_payment_to_buyer_index(&env, to.clone(), payment_id.clone());
+        let balance = get_event_token_balance(&env, event_id.clone(), &token_address);
+        let amount =
+            (balance.organizer_amount - get_frozen_dispute_amount(&env, event_id.clone())).max(0);
+        if amount > 0 {
+            token::Client::new(&env, &token_address).transfer(
+                &env.current_contract_address(),
+                &event_info.organizer_address,
+                &amount,
+            );
+            append_ledger_entry(
+                &env,
+                event_id.clone(),
+                LedgerEntryType::Withdrawal,
+                amount,
+                token_address.clone(),
+                event_info.organizer_address.clone(),
+            );
+            record_event_withdrawal(&env, event_id.clone(), &token_address, amount);
+            subtract_from_active_escrow_total(&env, amount);
+            subtract_from_active_escrow_by_token(&env, token_address.clone(), amount);
+        }
 
-        // Emit transfer event
         #[allow(deprecated)]
         env.events().publish(
-            (AgoraEvent::TicketTransferred,),
-            TicketTransferredEvent {
-                payment_id,
-                from,
-                to,
-                transfer_fee,
-                timestamp: env.ledger().timestamp(),
+            (AgoraEvent::RefundPoolSwept, event_id.clone()),
+            RefundPoolSweptEvent {
+                event_id,
+                organizer: event_info.organizer_address,
+                token: token_address,
+                amount,
+                timestamp: now,
             },
         );
 
-        Ok(())
+        Ok(amount)
     }
 
-    /// Triggers a bulk refund for a cancelled event. Processes in batches.
+    /// Returns the refund pool of a cancelled event, if one was created.
+    pub fn get_refund_pool(env: Env, event_id: String) -> Option<RefundPool> {
+        get_refund_pool(&env, event_id)
+    }
+
+    /// Triggers a bulk refund for a cancelled event. Processes in batches of
+    /// `batch_size` payments, resuming where the previous batch stopped.
+    /// Reserved and disputed payments are revisited by later batches, so tickets
+    /// paid off or released from a dispute after the scan passed them are still
+    /// refunded.
     pub fn trigger_bulk_refund(
         env: Env,
         event_id: String,
         batch_size: u32,
+    ) -> Result<u32, TicketPaymentError> {
+        Self::run_bulk_refund(env, event_id, batch_size, None)
+    }
+
+    /// Runs a `trigger_bulk_refund` batch on behalf of the organizer. The operator
+    /// needs the `OPERATOR_BULK_REFUND` permission for the event.
+    pub fn trigger_bulk_refund_as_operator(
+        env: Env,
+        operator: Address,
+        event_id: String,
+        batch_size: u32,
+    ) -> Result<u32, TicketPaymentError> {
+        Self::run_bulk_refund(env, event_id, batch_size, Some(operator))
+    }
+
+    /// Returns how far an event's bulk refund has progressed.
+    pub fn get_bulk_refund_progress(env: Env, event_id: String) -> BulkRefundProgress {
+        let (refunded_count, refunded_by_token) = get_bulk_refunded(&env, event_id.clone());
+        BulkRefundProgress {
+            processed: get_bulk_refund_index(&env, event_id.clone()),
+            total: get_event_payment_count(&env, event_id.clone()),
+            deferred: get_bulk_refund_deferred(&env, event_id).len(),
+            refunded_count,
+            refunded_by_token,
+        }
+    }
+
+    fn run_bulk_refund(
+        env: Env,
+        event_id: String,
+        batch_size: u32,
+        operator: Option<Address>,
     ) -> Result<u32, TicketPaymentError> {
         if !is_initialized(&env) {
             panic!("Contract not initialized");
@@ -1352,7 +6584,7 @@ impl TicketPaymentContract {
             return Err(TicketPaymentError::ContractPaused);
         }
 
-        let event_registry_addr = get_event_registry(&env);
+        let event_registry_addr = get_event_registry_for(&env, event_id.clone());
         let registry_client = event_registry::Client::new(&env, &event_registry_addr);
 
         let event_info = match registry_client.try_get_event(&event_id) {
@@ -1360,7 +6592,7 @@ impl TicketPaymentContract {
             _ => return Err(TicketPaymentError::EventNotFound),
         };
 
-        event_info.organizer_address.require_auth();
+        Self::authorize_event_action(&env, &event_id, &event_info, operator, OPERATOR_BULK_REFUND)?;
 
         // In a bulk refund, we assume the event is cancelled or inactive
         if event_info.is_active
@@ -1372,67 +6604,346 @@ impl TicketPaymentContract {
         let start_index = get_bulk_refund_index(&env, event_id.clone());
         let payment_ids = get_event_payments(&env, event_id.clone());
         let total_payments = payment_ids.len();
+        let deferred = get_bulk_refund_deferred(&env, event_id.clone());
 
-        if start_index >= total_payments {
+        if start_index >= total_payments && deferred.is_empty() {
             return Ok(0);
         }
 
-        let end_index = core::cmp::min(start_index + batch_size, total_payments);
+        // Payments passed over by earlier batches are revisited first, then the
+        // cursor advances over the rest of the batch. Until the cursor reaches
+        // the end, revisits take at most half the batch so it always advances.
+        let revisit_limit = if start_index < total_payments {
+            batch_size / 2
+        } else {
+            batch_size
+        };
+        let mut indices: Vec<u32> = Vec::new(&env);
+        let mut still_deferred: Vec<u32> = Vec::new(&env);
+        for index in deferred.iter() {
+            if indices.len() < revisit_limit {
+                indices.push_back(index);
+            } else {
+                still_deferred.push_back(index);
+            }
+        }
+        let end_index = core::cmp::min(
+            start_index.saturating_add(batch_size - indices.len()),
+            total_payments,
+        );
+        for index in start_index..end_index {
+            indices.push_back(index);
+        }
+
+        // A cancelled event's refunds all come out of its refund pool
+        let mut pool = get_refund_pool(&env, event_id.clone());
+
         let mut processed_count = 0;
         let mut total_refunded = 0;
-        let mut balance = get_event_balance(&env, event_id.clone());
+        // Per token (organizer, platform fee) reversals; persisted once per batch
+        let mut reversed: Map<Address, (i128, i128)> = Map::new(&env);
+        let mut refunded_by_token: Map<Address, i128> = Map::new(&env);
 
-        let token_address = crate::storage::get_usdc_token(&env);
-        let token_client = token::Client::new(&env, &token_address);
         let contract_address = env.current_contract_address();
 
-        for i in start_index..end_index {
+        for i in indices.iter() {
             let payment_id = payment_ids.get(i).unwrap();
             if let Some(mut payment) = get_payment(&env, payment_id.clone()) {
-                if payment.status == PaymentStatus::Confirmed {
-                    // Refund full amount to buyer
-                    token_client.transfer(
-                        &contract_address,
-                        &payment.buyer_address,
-                        &payment.amount,
+                // Disputed payments are settled by their arbiter instead, and
+                // reserved ones are still being paid off; both are revisited in
+                // case they become refundable later
+                let disputed = is_payment_disputed(&env, payment_id.clone());
+                if matches!(
+                    payment.status,
+                    PaymentStatus::Pending | PaymentStatus::Confirmed
+                ) && !disputed
+                {
+                    // Earlier partial refunds already came back out of the ticket's
+                    // price, and commissions claimed on the sale are not refunded again
+                    let remaining = payment
+                        .amount
+                        .checked_sub(payment.refunded_amount)
+                        .ok_or(TicketPaymentError::ArithmeticError)?;
+                    if remaining <= 0 {
+                        continue;
+                    }
+                    let (commission_recovered, commission_withheld) =
+                        reverse_ticket_commissions(&env, &payment);
+                    let refund_amount = (remaining - commission_withheld).max(0);
+                    if let Some(pool) = pool.as_mut() {
+                        draw_refund_pool(
+                            &env,
+                            pool,
+                            &payment.token_address,
+                            (refund_amount - commission_recovered).max(0),
+                        )?;
+                    }
+                    // Refund the rest to the buyer in the token they paid with
+                    if refund_amount > 0 {
+                        token::Client::new(&env, &payment.token_address).transfer(
+                            &contract_address,
+                            &payment.buyer_address,
+                            &refund_amount,
+                        );
+                        append_ledger_entry(
+                            &env,
+                            event_id.clone(),
+                            LedgerEntryType::Refund,
+                            refund_amount,
+                            payment.token_address.clone(),
+                            payment.buyer_address.clone(),
+                        );
+                    }
+                    subtract_from_active_escrow_by_token(
+                        &env,
+                        payment.token_address.clone(),
+                        refund_amount,
                     );
 
                     // Update payment status
                     payment.status = PaymentStatus::Refunded;
+                    payment.refunded_amount = payment.amount;
                     payment.confirmed_at = Some(env.ledger().timestamp());
                     store_payment(&env, payment.clone());
+                    burn_ticket_nft(&env, &payment.payment_id);
+
+                    let (organizer, fee) = reversed
+                        .get(payment.token_address.clone())
+                        .unwrap_or((0, 0));
+                    reversed.set(
+                        payment.token_address.clone(),
+                        (
+                            organizer + payment.organizer_amount,
+                            fee + payment.platform_fee,
+                        ),
+                    );
 
-                    // Update event balance in-memory; persist once per batch.
-                    balance.organizer_amount -= payment.organizer_amount;
-                    balance.platform_fee -= payment.platform_fee;
-
-                    total_refunded += payment.amount;
+                    let token_refunded = refunded_by_token
+                        .get(payment.token_address.clone())
+                        .unwrap_or(0);
+                    refunded_by_token.set(
+                        payment.token_address.clone(),
+                        token_refunded + refund_amount,
+                    );
+                    total_refunded += refund_amount;
                     processed_count += 1;
+                } else if matches!(
+                    payment.status,
+                    PaymentStatus::Pending | PaymentStatus::Reserved | PaymentStatus::Confirmed
+                ) {
+                    still_deferred.push_back(i);
                 }
             }
         }
 
+        for (token, (organizer, fee)) in reversed.iter() {
+            update_event_balance(&env, event_id.clone(), &token, -organizer, -fee);
+        }
+        if let Some(pool) = pool {
+            set_refund_pool(&env, &pool);
+        }
         if processed_count > 0 {
-            crate::storage::set_event_balance(&env, event_id.clone(), balance);
             subtract_from_active_escrow_total(&env, total_refunded);
-            subtract_from_active_escrow_by_token(&env, token_address, total_refunded);
+            add_bulk_refunded(&env, event_id.clone(), processed_count, &refunded_by_token);
         }
 
         set_bulk_refund_index(&env, event_id.clone(), end_index);
+        set_bulk_refund_deferred(&env, event_id.clone(), &still_deferred);
 
         // Emit bulk refund event
         #[allow(deprecated)]
         env.events().publish(
-            (AgoraEvent::BulkRefundProcessed,),
+            (AgoraEvent::BulkRefundProcessed, event_id.clone()),
             BulkRefundProcessedEvent {
+                event_id: event_id.clone(),
+                refund_count: processed_count,
+                total_refunded,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (AgoraEvent::RefundBatchCompleted, event_id.clone()),
+            RefundBatchCompletedEvent {
                 event_id,
                 refund_count: processed_count,
                 total_refunded,
+                processed: end_index,
+                total: total_payments,
+                deferred: still_deferred.len(),
                 timestamp: env.ledger().timestamp(),
             },
         );
 
-        Ok(processed_count)
+        Ok(processed_count)
+    }
+
+    /// Returns up to `limit` of an event's payments in full, optionally only those
+    /// in `status_filter`. `offset` counts matching payments.
+    pub fn get_event_payments_paginated(
+        env: Env,
+        event_id: String,
+        offset: u32,
+        limit: u32,
+        status_filter: Option<PaymentStatus>,
+    ) -> Vec<Payment> {
+        crate::storage::get_event_payments_filtered_page(
+            &env,
+            event_id,
+            status_filter,
+            PageCursor::new(offset, limit),
+        )
+    }
+
+    /// Tallies an event's payments by tier and status, with the revenue still
+    /// held for tickets that have not been refunded.
+    pub fn get_event_sales_summary(env: Env, event_id: String) -> EventSalesSummary {
+        let mut summary = EventSalesSummary {
+            tiers: soroban_sdk::Map::new(&env),
+            status_counts: soroban_sdk::Map::new(&env),
+            gross_revenue: 0,
+            net_revenue: 0,
+        };
+
+        for payment_id in get_event_payments(&env, event_id).iter() {
+            let Some(payment) = get_payment(&env, payment_id) else {
+                continue;
+            };
+            let count = summary
+                .status_counts
+                .get(payment.status.clone())
+                .unwrap_or(0);
+            summary.status_counts.set(payment.status.clone(), count + 1);
+
+            let mut tier =
+                summary
+                    .tiers
+                    .get(payment.ticket_tier_id.clone())
+                    .unwrap_or(TierSalesSummary {
+                        sold: 0,
+                        refunded: 0,
+                        gross_revenue: 0,
+                        net_revenue: 0,
+                    });
+            match payment.status {
+                PaymentStatus::Refunded => tier.refunded += 1,
+                PaymentStatus::Failed => {}
+                _ => {
+                    let gross = payment.amount.saturating_sub(payment.refunded_amount);
+                    tier.sold += 1;
+                    tier.gross_revenue = tier.gross_revenue.saturating_add(gross);
+                    tier.net_revenue = tier.net_revenue.saturating_add(payment.organizer_amount);
+                    summary.gross_revenue = summary.gross_revenue.saturating_add(gross);
+                    summary.net_revenue =
+                        summary.net_revenue.saturating_add(payment.organizer_amount);
+                }
+            }
+            summary.tiers.set(payment.ticket_tier_id, tier);
+        }
+        summary
+    }
+
+    /// Returns an event's revenue, fees, refunds and sell-through in a single call.
+    pub fn get_event_stats(env: Env, event_id: String) -> EventStats {
+        let balance = get_event_balance(&env, event_id.clone());
+        let mut stats = EventStats {
+            gross_revenue: 0,
+            net_to_organizer: balance.organizer_amount + balance.total_withdrawn,
+            fees_accrued: balance.platform_fee,
+            refund_count: 0,
+            refund_amount: 0,
+            sold_bps_by_tier: soroban_sdk::Map::new(&env),
+        };
+
+        let count = get_event_ledger_count(&env, event_id.clone());
+        let mut offset = 0;
+        while offset < count {
+            let page = get_event_ledger(&env, event_id.clone(), PageCursor::new(offset, count));
+            if page.is_empty() {
+                break;
+            }
+            offset += page.len();
+            for entry in page.iter() {
+                match entry.entry_type {
+                    LedgerEntryType::Sale | LedgerEntryType::Discount => {
+                        stats.gross_revenue += entry.amount
+                    }
+                    LedgerEntryType::Refund => {
+                        stats.refund_count += 1;
+                        stats.refund_amount += entry.amount;
+                    }
+                    LedgerEntryType::FeeSettlement => stats.fees_accrued += entry.amount,
+                    _ => {}
+                }
+            }
+        }
+
+        let registry_client =
+            event_registry::Client::new(&env, &get_event_registry_for(&env, event_id.clone()));
+        if let Ok(Ok(Some(event_info))) = registry_client.try_get_event(&event_id) {
+            for (tier_id, tier) in event_info.tiers.iter() {
+                let sold_bps = if tier.tier_limit > 0 {
+                    (tier.current_sold * MAX_BPS as i128 / tier.tier_limit) as u32
+                } else {
+                    0
+                };
+                stats.sold_bps_by_tier.set(tier_id, sold_bps);
+            }
+        }
+        stats
+    }
+
+    /// Summarizes the refund liability of cancelling an event without changing state.
+    pub fn preview_cancellation(env: Env, event_id: String) -> CancellationPreview {
+        let mut refundable_by_token: soroban_sdk::Map<Address, i128> = soroban_sdk::Map::new(&env);
+        let mut confirmed_count = 0u32;
+        let mut total_refundable: i128 = 0;
+        let mut platform_fees_reversed: i128 = 0;
+
+        for payment_id in get_event_payments(&env, event_id.clone()).iter() {
+            if let Some(payment) = get_payment(&env, payment_id) {
+                if payment.status == PaymentStatus::Confirmed {
+                    confirmed_count += 1;
+                    let refundable = payment.amount.saturating_sub(payment.refunded_amount);
+                    total_refundable = total_refundable.saturating_add(refundable);
+                    let by_token = refundable_by_token
+                        .get(payment.token_address.clone())
+                        .unwrap_or(0);
+                    refundable_by_token.set(
+                        payment.token_address.clone(),
+                        by_token.saturating_add(refundable),
+                    );
+                    platform_fees_reversed =
+                        platform_fees_reversed.saturating_add(payment.platform_fee);
+                }
+            }
+        }
+
+        let mut escrow_by_token: soroban_sdk::Map<Address, i128> = soroban_sdk::Map::new(&env);
+        for token in get_event_balance_tokens(&env, event_id.clone()).iter() {
+            let balance = get_event_token_balance(&env, event_id.clone(), &token);
+            escrow_by_token.set(
+                token,
+                balance
+                    .organizer_amount
+                    .saturating_add(balance.platform_fee),
+            );
+        }
+        let mut shortfall_by_token: soroban_sdk::Map<Address, i128> = soroban_sdk::Map::new(&env);
+        for (token, refundable) in refundable_by_token.iter() {
+            let escrow = escrow_by_token.get(token.clone()).unwrap_or(0);
+            shortfall_by_token.set(token, refundable.saturating_sub(escrow).max(0));
+        }
+
+        CancellationPreview {
+            confirmed_count,
+            refundable_by_token,
+            total_refundable,
+            platform_fees_reversed,
+            escrow_by_token,
+            shortfall_by_token,
+        }
     }
 
     /// Issues a partial refund to all guests for an event. Processes in batches.
@@ -1442,6 +6953,28 @@ impl TicketPaymentContract {
         event_id: String,
         percentage_bps: u32,
         batch_size: u32,
+    ) -> Result<u32, TicketPaymentError> {
+        Self::run_partial_refund(env, event_id, percentage_bps, batch_size, None)
+    }
+
+    /// Runs an `issue_partial_refund` batch on behalf of the organizer. The operator
+    /// needs the `OPERATOR_PARTIAL_REFUND` permission for the event.
+    pub fn issue_partial_refund_as_operator(
+        env: Env,
+        operator: Address,
+        event_id: String,
+        percentage_bps: u32,
+        batch_size: u32,
+    ) -> Result<u32, TicketPaymentError> {
+        Self::run_partial_refund(env, event_id, percentage_bps, batch_size, Some(operator))
+    }
+
+    fn run_partial_refund(
+        env: Env,
+        event_id: String,
+        percentage_bps: u32,
+        batch_size: u32,
+        operator: Option<Address>,
     ) -> Result<u32, TicketPaymentError> {
         if !is_initialized(&env) {
             panic!("Contract not initialized");
@@ -1449,11 +6982,11 @@ impl TicketPaymentContract {
         if is_paused(&env) {
             return Err(TicketPaymentError::ContractPaused);
         }
-        if percentage_bps > 10000 {
+        if !is_valid_bps(percentage_bps) {
             panic!("Percentage cannot exceed 100%");
         }
 
-        let event_registry_addr = get_event_registry(&env);
+        let event_registry_addr = get_event_registry_for(&env, event_id.clone());
         let registry_client = event_registry::Client::new(&env, &event_registry_addr);
 
         let event_info = match registry_client.try_get_event(&event_id) {
@@ -1461,7 +6994,13 @@ impl TicketPaymentContract {
             _ => return Err(TicketPaymentError::EventNotFound),
         };
 
-        event_info.organizer_address.require_auth();
+        Self::authorize_event_action(
+            &env,
+            &event_id,
+            &event_info,
+            operator,
+            OPERATOR_PARTIAL_REFUND,
+        )?;
 
         let start_index = get_partial_refund_index(&env, event_id.clone());
         let payment_ids = get_event_payments(&env, event_id.clone());
@@ -1486,34 +7025,55 @@ impl TicketPaymentContract {
         let end_index = core::cmp::min(start_index + batch_size, total_payments);
         let mut processed_count = 0;
         let mut total_refunded = 0;
-        let mut balance = get_event_balance(&env, event_id.clone());
+        // Per token refunds; persisted once per batch
+        let mut reversed: Map<Address, i128> = Map::new(&env);
+        // A cancelled event's refunds all come out of its refund pool
+        let mut pool = get_refund_pool(&env, event_id.clone());
 
-        let token_address = crate::storage::get_usdc_token(&env);
-        let token_client = token::Client::new(&env, &token_address);
         let contract_address = env.current_contract_address();
 
         for i in start_index..end_index {
             let payment_id = payment_ids.get(i).unwrap();
             if let Some(mut payment) = get_payment(&env, payment_id.clone()) {
-                if payment.status == PaymentStatus::Confirmed {
-                    let refund_amount = (payment
-                        .amount
-                        .checked_mul(active_pct as i128)
-                        .ok_or(TicketPaymentError::ArithmeticError)?)
-                        / 10000;
+                if payment.status == PaymentStatus::Confirmed
+                    && !is_payment_disputed(&env, payment_id.clone())
+                {
+                    let refund_amount = mul_div_bps(payment.amount, active_pct)
+                        .ok_or(TicketPaymentError::ArithmeticError)?;
 
                     if refund_amount > 0 && payment.organizer_amount >= refund_amount {
-                        token_client.transfer(
+                        if let Some(pool) = pool.as_mut() {
+                            draw_refund_pool(&env, pool, &payment.token_address, refund_amount)?;
+                        }
+                        token::Client::new(&env, &payment.token_address).transfer(
                             &contract_address,
                             &payment.buyer_address,
                             &refund_amount,
                         );
+                        append_ledger_entry(
+                            &env,
+                            event_id.clone(),
+                            LedgerEntryType::Refund,
+                            refund_amount,
+                            payment.token_address.clone(),
+                            payment.buyer_address.clone(),
+                        );
+                        subtract_from_active_escrow_by_token(
+                            &env,
+                            payment.token_address.clone(),
+                            refund_amount,
+                        );
 
                         payment.refunded_amount += refund_amount;
                         payment.organizer_amount -= refund_amount;
                         store_payment(&env, payment.clone());
 
-                        balance.organizer_amount -= refund_amount;
+                        let token_refunded =
+                            reversed.get(payment.token_address.clone()).unwrap_or(0);
+                        reversed.set(
+                            payment.token_address.clone(),
+                            token_refunded + refund_amount,
+                        );
                         total_refunded += refund_amount;
                         processed_count += 1;
                     }
@@ -1521,10 +7081,14 @@ impl TicketPaymentContract {
             }
         }
 
+        for (token, refunded) in reversed.iter() {
+            update_event_balance(&env, event_id.clone(), &token, -refunded, 0);
+        }
+        if let Some(pool) = pool {
+            set_refund_pool(&env, &pool);
+        }
         if processed_count > 0 {
-            crate::storage::set_event_balance(&env, event_id.clone(), balance);
             subtract_from_active_escrow_total(&env, total_refunded);
-            subtract_from_active_escrow_by_token(&env, token_address, total_refunded);
         }
 
         set_partial_refund_index(&env, event_id.clone(), end_index);
@@ -1538,7 +7102,7 @@ impl TicketPaymentContract {
         // Emit partial refund event
         #[allow(deprecated)]
         env.events().publish(
-            (AgoraEvent::PartialRefundProcessed,),
+            (AgoraEvent::PartialRefundProcessed, event_id.clone()),
             PartialRefundProcessedEvent {
                 event_id,
                 refund_count: processed_count,
@@ -1551,6 +7115,53 @@ impl TicketPaymentContract {
         Ok(processed_count)
     }
 
+    /// Effectiveness metrics for a promo: payments using it, gross discount granted
+    /// and net revenue collected. Global promos are identified by their expiry.
+    pub fn get_promo_stats(env: Env, scope: PromoScope) -> PromoStats {
+        get_promo_stats(&env, scope)
+    }
+
+    /// Previews the pricing breakdown for a purchase against the event's current
+    /// promo and platform fee, without validating price, inventory or codes.
+    /// `code_bps` is the discount of the code to be presented, or 0 for none.
+    pub fn preview_pricing_breakdown(
+        env: Env,
+        event_id: String,
+        amount: i128,
+        quantity: u32,
+        code_bps: u32,
+        with_referrer: bool,
+    ) -> Result<PricingBreakdown, TicketPaymentError> {
+        let referral_bps = if with_referrer {
+            Self::resolve_referral_bps(&env, &event_id)
+        } else {
+            0
+        };
+        let registry_client =
+            event_registry::Client::new(&env, &get_event_registry_for(&env, event_id.clone()));
+        let event_info = match registry_client.try_get_event(&event_id) {
+            Ok(Ok(Some(info))) => info,
+            _ => return Err(TicketPaymentError::EventNotFound),
+        };
+        let promo_mode = Self::resolve_promo_mode(&registry_client, &event_id);
+        let promo_bps = if env.ledger().timestamp() < registry_client.get_promo_expiry()
+            && promo_mode != event_registry::PromoMode::OptOut
+        {
+            registry_client.get_global_promo_bps()
+        } else {
+            0
+        };
+        compute_pricing_breakdown(
+            amount,
+            quantity,
+            promo_bps,
+            code_bps,
+            event_info.platform_fee_percent,
+            referral_bps,
+            promo_mode == event_registry::PromoMode::PlatformAbsorbed,
+        )
+    }
+
     /// Protocol-wide gross ticket volume processed (all tokens combined).
     pub fn get_total_volume_processed(env: Env) -> i128 {
         crate::storage::get_total_volume_processed(&env)
@@ -1588,12 +7199,35 @@ impl TicketPaymentContract {
         env: Env,
         event_id: String,
         hashes: Vec<BytesN<32>>,
+    ) -> Result<(), TicketPaymentError> {
+        let mut codes = Vec::new(&env);
+        for hash in hashes.iter() {
+            codes.push_back(DiscountSpec {
+                hash,
+                bps: DISCOUNT_CODE_BPS,
+                max_uses: 1,
+                max_uses_per_buyer: 0,
+                expiry: 0,
+                tier_restriction: None,
+            });
+        }
+        Self::add_discount_codes(env, event_id, codes)
+    }
+
+    /// Allows an event organizer to register hashed discount codes with their own
+    /// discount, use limit, expiry and optional tier restriction. Codes apply only
+    /// to the event they were registered for; re-registering a code of the same
+    /// event updates its terms and keeps its use count.
+    pub fn add_discount_codes(
+        env: Env,
+        event_id: String,
+        codes: Vec<DiscountSpec>,
     ) -> Result<(), TicketPaymentError> {
         if !is_initialized(&env) {
             panic!("Contract not initialized");
         }
 
-        let event_registry_addr = get_event_registry(&env);
+        let event_registry_addr = get_event_registry_for(&env, event_id.clone());
         let registry_client = event_registry::Client::new(&env, &event_registry_addr);
 
         let event_info = match registry_client.try_get_event(&event_id) {
@@ -1604,12 +7238,39 @@ impl TicketPaymentContract {
         // Only the event organizer may upload discount codes for their event
         event_info.organizer_address.require_auth();
 
-        for hash in hashes.iter() {
-            add_discount_hash(&env, hash);
+        for spec in codes.iter() {
+            if spec.bps == 0 || spec.bps > MAX_BPS {
+                return Err(TicketPaymentError::InvalidDiscountBps);
+            }
+            let uses_so_far = match get_discount_code(&env, spec.hash.clone()) {
+                Some(existing) if existing.event_id != event_id => {
+                    return Err(TicketPaymentError::Unauthorized);
+                }
+                Some(existing) => existing.uses_so_far,
+                None => 0,
+            };
+            set_discount_code(
+                &env,
+                &DiscountCode {
+                    event_id: event_id.clone(),
+                    spec,
+                    uses_so_far,
+                },
+            );
         }
 
         Ok(())
     }
+
+    /// Returns a registered discount code and its use count, if any.
+    pub fn get_discount_code(env: Env, code_hash: BytesN<32>) -> Option<DiscountCode> {
+        get_discount_code(&env, code_hash)
+    }
+
+    /// Returns how many purchases `buyer` has made with a discount code.
+    pub fn get_discount_code_uses(env: Env, code_hash: BytesN<32>, buyer: Address) -> u32 {
+        get_discount_buyer_uses(&env, code_hash, buyer)
+    }
 }
 
 fn validate_address(env: &Env, address: &Address) -> Result<(), TicketPaymentError> {
@@ -1618,3 +7279,900 @@ fn validate_address(env: &Env, address: &Address) -> Result<(), TicketPaymentErr
     }
     Ok(())
 }
+
+/// Hash of a receipt as published in the confirmation event: the SHA-256 of its
+/// XDR encoding, so wallets can verify a receipt fetched via `get_receipt`.
+pub fn receipt_hash_of(env: &Env, receipt: &Receipt) -> BytesN<32> {
+    env.crypto().sha256(&receipt.clone().to_xdr(env)).into()
+}
+
+/// Deterministic invoice identifier for a fee settlement: the SHA-256 of the XDR
+/// encoding of `(event_id, period, amount, token)`. Off-chain systems can
+/// recompute it from the settlement history to reconcile bank and chain records.
+pub fn fee_invoice_id(
+    env: &Env,
+    event_id: String,
+    period: u32,
+    amount: i128,
+    token: Address,
+) -> BytesN<32> {
+    env.crypto()
+        .sha256(&(event_id, period, amount, token).to_xdr(env))
+        .into()
+}
+
+/// Appends a settlement to the history under the event's next period and
+/// returns its invoice ID.
+fn record_fee_settlement(env: &Env, event_id: String, amount: i128, token: Address) -> BytesN<32> {
+    let period = get_event_settlement_periods(env, event_id.clone());
+    let invoice_id = fee_invoice_id(env, event_id.clone(), period, amount, token.clone());
+    append_settlement_record(
+        env,
+        SettlementRecord {
+            invoice_id: invoice_id.clone(),
+            event_id,
+            period,
+            amount,
+            token,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+    invoice_id
+}
+
+/// Splits a purchase of `quantity` tickets at `unit_price` into every intermediate
+/// value used by `process_payment`: the global promo (`promo_bps`) is applied first,
+/// then the discount code (`code_bps`, 0 without one), then the platform fee (`platform_fee_bps`) is taken
+/// from the amount paid and `referral_bps` of it goes to the referrer (0 without one).
+///
+/// With `platform_absorbs_promo` the promo comes out of the platform fee only: the
+/// organizer nets what it would without the promo, and the promo is capped at the fee.
+pub fn compute_pricing_breakdown(
+    unit_price: i128,
+    quantity: u32,
+    promo_bps: u32,
+    code_bps: u32,
+    platform_fee_bps: u32,
+    referral_bps: u32,
+    platform_absorbs_promo: bool,
+) -> Result<PricingBreakdown, TicketPaymentError> {
+    let apply_code = |price: i128| apply_discount_bps(price, code_bps);
+    let fee_on = |price: i128| {
+        mul_div_bps(price, platform_fee_bps).ok_or(TicketPaymentError::ArithmeticError)
+    };
+
+    let gross = unit_price
+        .checked_mul(quantity as i128)
+        .ok_or(TicketPaymentError::ArithmeticError)?;
+    let after_promo = apply_discount_bps(gross, promo_bps)?;
+    let promo_net = apply_code(after_promo)?;
+    let mut net_paid = promo_net;
+
+    let (total_fee, organizer_net) = if platform_absorbs_promo {
+        let list_net = apply_code(gross)?;
+        let organizer_net = list_net - fee_on(list_net)?;
+        net_paid = net_paid.max(organizer_net);
+        (net_paid - organizer_net, organizer_net)
+    } else {
+        let total_fee = fee_on(net_paid)?;
+        (total_fee, net_paid - total_fee)
+    };
+    let referral_cut =
+        mul_div_bps(total_fee, referral_bps).ok_or(TicketPaymentError::ArithmeticError)?;
+
+    let code_discount = after_promo - promo_net;
+    Ok(PricingBreakdown {
+        gross,
+        promo_reduction: gross - code_discount - net_paid,
+        code_discount,
+        net_paid,
+        platform_fee: total_fee - referral_cut,
+        referral_cut,
+        organizer_net,
+    })
+}
+
+/// The tier's price at `now` when no token-specific price applies: the current
+/// step of a Dutch auction, the early-bird price while it lasts, or `price`.
+fn base_tier_price(
+    tier: &event_registry::TicketTier,
+    now: u64,
+) -> Result<i128, TicketPaymentError> {
+    match tier.pricing {
+        event_registry::TierPricing::DutchAuction(ref auction) => decaying_price(
+            tier.price,
+            auction.floor_price,
+            auction.start,
+            auction.end,
+            auction.step_seconds,
+            now,
+        )
+        .ok_or(TicketPaymentError::ArithmeticError),
+        event_registry::TierPricing::Fixed if is_early_bird_active(tier, now) => {
+            Ok(tier.early_bird_price)
+        }
+        event_registry::TierPricing::Fixed => Ok(tier.price),
+    }
+}
+
+/// Quotes one ticket of `tier` in `token`'s base units, before discounts, and
+/// whether the quote comes from the price oracle. In order of precedence:
+/// - an explicit price for the token;
+/// - the tier's USD price at the oracle rate;
+/// - with an oracle configured, the default-token price converted at the
+///   cross rate between the two tokens;
+/// - otherwise the tier's base price, taken as-is.
+fn quote_tier_price(
+    env: &Env,
+    tier: &event_registry::TicketTier,
+    token_address: &Address,
+    now: u64,
+) -> Result<(i128, bool), TicketPaymentError> {
+    if let Some(token_price) = tier.token_prices.get(token_address.clone()) {
+        return Ok((token_price, false));
+    }
+    if tier.usd_price > 0 {
+        // usd_price is 7-decimal fixed-point, like the oracle's rates
+        let amount = convert_at_rate(
+            tier.usd_price,
+            oracle_rate(env, token_address)?,
+            ORACLE_PRICE_SCALE,
+        )
+        .ok_or(TicketPaymentError::ArithmeticError)?;
+        return Ok((to_token_units(env, token_address, amount)?, true));
+    }
+
+    let base_price = base_tier_price(tier, now)?;
+    let default_token = crate::storage::get_usdc_token(env);
+    if *token_address == default_token || get_oracle_address(env).is_none() {
+        return Ok((base_price, false));
+    }
+    let from_rate = oracle_rate(env, &default_token)?;
+    let to_rate = oracle_rate(env, token_address)?;
+    let default_decimals = token::Client::new(env, &default_token).decimals();
+    let amount = rescale_decimals(base_price, default_decimals, ORACLE_DECIMALS)
+        .and_then(|v| convert_at_rate(v, to_rate, from_rate))
+        .ok_or(TicketPaymentError::ArithmeticError)?;
+    Ok((to_token_units(env, token_address, amount)?, true))
+}
+
+/// The oracle's rate for `token`: units of the token per US dollar, scaled by
+/// `ORACLE_PRICE_SCALE`. Oracles that reject the lookup, such as the oracle
+/// adapter refusing stale data, leave the price unavailable.
+fn oracle_rate(env: &Env, token_address: &Address) -> Result<i128, TicketPaymentError> {
+    let oracle_addr = get_oracle_address(env).ok_or(TicketPaymentError::OracleNotConfigured)?;
+    match price_oracle::OracleClient::new(env, &oracle_addr).try_lastprice(token_address) {
+        Ok(Ok(Some(data))) if data.price > 0 => Ok(data.price),
+        _ => Err(TicketPaymentError::OraclePriceUnavailable),
+    }
+}
+
+/// Converts an oracle-scaled token amount into the token's own base units.
+fn to_token_units(
+    env: &Env,
+    token_address: &Address,
+    amount: i128,
+) -> Result<i128, TicketPaymentError> {
+    let decimals = token::Client::new(env, token_address).decimals();
+    rescale_decimals(amount, ORACLE_DECIMALS, decimals).ok_or(TicketPaymentError::ArithmeticError)
+}
+
+/// The part of a ticket's `amount` the organizer keeps when it is refunded at
+/// `now` under `policy`, or why it cannot be refunded.
+fn policy_retained_amount(
+    policy: &event_registry::RefundPolicy,
+    amount: i128,
+    now: u64,
+) -> Result<i128, TicketPaymentError> {
+    match policy {
+        event_registry::RefundPolicy::NoRefund => Err(TicketPaymentError::TicketNotRefundable),
+        event_registry::RefundPolicy::FullUntil(until) if now <= *until => Ok(0),
+        event_registry::RefundPolicy::FullUntil(_) => Err(TicketPaymentError::RefundDeadlinePassed),
+        event_registry::RefundPolicy::Schedule(steps) => {
+            let step = steps
+                .iter()
+                .find(|step| now <= step.until)
+                .ok_or(TicketPaymentError::RefundDeadlinePassed)?;
+            mul_div_bps(amount, step.refund_bps)
+                .and_then(|refunded| amount.checked_sub(refunded))
+                .ok_or(TicketPaymentError::ArithmeticError)
+        }
+    }
+}
+
+/// Rejects event-scoped sales and transfers while the event is paused.
+fn ensure_event_not_paused(env: &Env, event_id: &String) -> Result<(), TicketPaymentError> {
+    if get_event_paused_by(env, event_id.clone()).is_some() {
+        return Err(TicketPaymentError::EventPaused);
+    }
+    Ok(())
+}
+
+/// Takes the commissions accrued on a ticket's sale back out of the unclaimed
+/// balances they were credited to, as the ticket is refunded in full. Returns
+/// `(recovered, withheld)`: what came back from unclaimed balances, and what was
+/// already claimed and so must be left out of the refund.
+fn reverse_ticket_commissions(env: &Env, payment: &Payment) -> (i128, i128) {
+    let Some(commissions) = take_ticket_commissions(env, payment.payment_id.clone()) else {
+        return (0, 0);
+    };
+    let token = payment.token_address.clone();
+    let mut recovered = 0;
+    if let Some(promoter) = commissions.promoter {
+        let earned = get_promoter_earnings(env, promoter.clone(), token.clone());
+        let taken = commissions.promoter_cut.min(earned).max(0);
+        set_promoter_earnings(env, promoter, token.clone(), earned - taken);
+        recovered += taken;
+    }
+    if let Some(referrer) = commissions.referrer {
+        let accrued = get_referral_rewards(env, referrer.clone(), token.clone());
+        let taken = commissions.referral_cut.min(accrued).max(0);
+        set_referral_rewards(env, referrer, token, accrued - taken);
+        recovered += taken;
+    }
+    let accrued = commissions.promoter_cut + commissions.referral_cut;
+    (recovered, accrued - recovered)
+}
+
+/// Books `amount` of `token` as refunded out of a cancelled event's refund
+/// pool, so every refund path draws on the same locked escrow. Refunds close
+/// with the pool's claim window; the organizer can only sweep after it.
+fn draw_refund_pool(
+    env: &Env,
+    pool: &mut RefundPool,
+    token: &Address,
+    amount: i128,
+) -> Result<(), TicketPaymentError> {
+    if env.ledger().timestamp() > pool.claim_deadline {
+        return Err(TicketPaymentError::RefundDeadlinePassed);
+    }
+    let claimed = pool
+        .claimed
+        .get(token.clone())
+        .unwrap_or(0)
+        .checked_add(amount)
+        .ok_or(TicketPaymentError::ArithmeticError)?;
+    if claimed > pool.locked.get(token.clone()).unwrap_or(0) {
+        return Err(TicketPaymentError::NoFundsAvailable);
+    }
+    pool.claimed.set(token.clone(), claimed);
+    Ok(())
+}
+
+/// Rejects a purchase of `quantity` tickets that would leave the buyer holding
+/// more of the event's tickets than its `max_tickets_per_buyer`. Every ticket the
+/// buyer currently holds counts, whatever its status, unless refunded or failed.
+fn ensure_within_buyer_limit(
+    env: &Env,
+    event_info: &event_registry::EventInfo,
+    buyer: &Address,
+    quantity: u32,
+) -> Result<(), TicketPaymentError> {
+    let limit = event_info.max_tickets_per_buyer;
+    if limit == 0 || is_buyer_limit_exempt(env, event_info.event_id.clone(), buyer.clone()) {
+        return Ok(());
+    }
+    let mut held: u32 = 0;
+    for payment_id in crate::storage::get_buyer_payments(env, buyer.clone()).iter() {
+        if let Some(payment) = get_payment(env, payment_id) {
+            if payment.event_id == event_info.event_id
+                && !matches!(
+                    payment.status,
+                    PaymentStatus::Refunded | PaymentStatus::Failed
+                )
+            {
+                held += 1;
+            }
+        }
+    }
+    if held.saturating_add(quantity) > limit {
+        return Err(TicketPaymentError::BuyerLimitExceeded);
+    }
+    Ok(())
+}
+
+/// Counts a buyer-initiated refund towards the event's refund window and trips
+/// the circuit breaker when the window's refunds exceed the configured share of
+/// the event's sales. Tripping flags the event as disputed and starts a new window.
+fn record_buyer_refund(env: &Env, event_id: String) {
+    let config = get_refund_spike_config(env);
+    if config.threshold_bps == 0 {
+        return;
+    }
+    let now = env.ledger().timestamp();
+    let mut window = match get_refund_window(env, event_id.clone()) {
+        Some(window) if now < window.started_at.saturating_add(config.window) => window,
+        _ => RefundWindow {
+            started_at: now,
+            refunds: 0,
+        },
+    };
+    window.refunds += 1;
+
+    let sales = get_event_payment_count(env, event_id.clone());
+    if (window.refunds as u64) * (MAX_BPS as u64) <= (sales as u64) * (config.threshold_bps as u64)
+    {
+        set_refund_window(env, event_id, &window);
+        return;
+    }
+    set_refund_window(
+        env,
+        event_id.clone(),
+        &RefundWindow {
+            started_at: now,
+            refunds: 0,
+        },
+    );
+    if is_event_disputed(env, event_id.clone()) {
+        return;
+    }
+    set_event_dispute_status(env, event_id.clone(), true);
+
+    #[allow(deprecated)]
+    env.events().publish(
+        (AgoraEvent::RefundSpikeDetected, event_id.clone()),
+        RefundSpikeDetectedEvent {
+            event_id,
+            refunds: window.refunds,
+            sales,
+            window_start: window.started_at,
+            timestamp: now,
+        },
+    );
+}
+
+/// Pulls `amount` of `token` from `payer` into escrow using the allowance
+/// granted to this contract.
+fn collect_payment(
+    env: &Env,
+    token_address: &Address,
+    payer: &Address,
+    amount: i128,
+) -> Result<(), TicketPaymentError> {
+    let token_client = token::Client::new(env, token_address);
+    let contract_address = env.current_contract_address();
+    if token_client.allowance(payer, &contract_address) < amount {
+        return Err(TicketPaymentError::InsufficientAllowance);
+    }
+    token_client.transfer_from(&contract_address, payer, &contract_address, &amount);
+    add_to_active_escrow_total(env, amount);
+    add_to_active_escrow_by_token(env, token_address.clone(), amount);
+    Ok(())
+}
+
+/// Returns the tier's group discount for `quantity` tickets: that of the largest
+/// `bulk_discounts` threshold reached, or 0.
+fn bulk_discount_bps(tier: &event_registry::TicketTier, quantity: u32) -> u32 {
+    let mut bps = 0;
+    for (min_quantity, discount_bps) in tier.bulk_discounts.iter() {
+        if quantity >= min_quantity {
+            bps = discount_bps;
+        }
+    }
+    bps
+}
+
+/// Early-bird pricing ends at `early_bird_deadline` or once `early_bird_quantity`
+/// tickets have sold, whichever comes first (0 disables either limit).
+fn is_early_bird_active(tier: &event_registry::TicketTier, now: u64) -> bool {
+    if tier.early_bird_deadline == 0 && tier.early_bird_quantity == 0 {
+        return false;
+    }
+    (tier.early_bird_deadline == 0 || now <= tier.early_bird_deadline)
+        && (tier.early_bird_quantity == 0 || tier.current_sold < tier.early_bird_quantity)
+}
+
+/// Notifies the rewards contract, if any, of a confirmed purchase. Errors are
+/// swallowed so a broken rewards contract can never block payments.
+fn notify_rewards_purchase(env: &Env, payment: &Payment) {
+    if let Some(addr) = get_rewards_contract(env) {
+        let _ = rewards::RewardsClient::new(env, &addr).try_on_purchase_confirmed(
+            &payment.buyer_address,
+            &payment.event_id,
+            &payment.payment_id,
+            &payment.amount,
+        );
+    }
+}
+
+/// Amount the organizer may withdraw now: escrowed revenue released by the
+/// highest milestone reached, less what was already withdrawn.
+fn withdrawable_amount(
+    event_info: &event_registry::EventInfo,
+    balance: &crate::types::EventBalance,
+) -> Result<i128, TicketPaymentError> {
+    let total_revenue = balance
+        .organizer_amount
+        .checked_add(balance.total_withdrawn)
+        .ok_or(TicketPaymentError::ArithmeticError)?;
+    if total_revenue == 0 {
+        return Ok(0);
+    }
+
+    let mut release_percent = MAX_BPS;
+    if let Some(milestones) = &event_info.milestone_plan {
+        let mut highest_met = 0u32;
+        for milestone in milestones.iter() {
+            if event_info.current_supply >= milestone.sales_threshold
+                && milestone.release_percent > highest_met
+            {
+                highest_met = milestone.release_percent;
+            }
+        }
+        if !milestones.is_empty() {
+            release_percent = highest_met;
+        }
+    }
+
+    let max_allowed =
+        mul_div_bps(total_revenue, release_percent).ok_or(TicketPaymentError::ArithmeticError)?;
+    let available = max_allowed
+        .checked_sub(balance.total_withdrawn)
+        .ok_or(TicketPaymentError::ArithmeticError)?;
+    Ok(available.min(balance.organizer_amount))
+}
+
+/// Emits `WithdrawableThresholdReached` the first time an event's withdrawable
+/// amount reaches its organizer's `min_withdrawal` since the last withdrawal.
+fn check_withdrawal_threshold(
+    env: &Env,
+    event_info: &event_registry::EventInfo,
+) -> Result<(), TicketPaymentError> {
+    let config = match get_payout_config(env, &event_info.organizer_address) {
+        Some(config) => config,
+        None => return Ok(()),
+    };
+    let event_id = event_info.event_id.clone();
+    if is_threshold_notified(env, event_id.clone()) {
+        return Ok(());
+    }
+    let withdrawable = withdrawable_amount(event_info, &get_event_balance(env, event_id.clone()))?;
+    if withdrawable < config.min_withdrawal {
+        return Ok(());
+    }
+    set_threshold_notified(env, event_id.clone(), true);
+
+    #[allow(deprecated)]
+    env.events().publish(
+        (AgoraEvent::WithdrawableThresholdReached, event_id.clone()),
+        WithdrawableThresholdReachedEvent {
+            event_id,
+            organizer_address: event_info.organizer_address.clone(),
+            withdrawable,
+            min_withdrawal: config.min_withdrawal,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+    Ok(())
+}
+
+/// Moves a ticket to a new holder: the payment record, buyer indices and NFT.
+/// An identity binding or delegation belongs to the previous holder and is cleared.
+fn reassign_ticket(env: &Env, payment: &mut Payment, to: &Address, memo: Option<Bytes>) {
+    let from = payment.buyer_address.clone();
+    payment.buyer_address = to.clone();
+    payment.identity_hash = None;
+    payment.memo = memo;
+    let key = crate::types::DataKey::Payment(payment.payment_id.clone());
+    env.storage().persistent().set(&key, payment);
+    set_ticket_delegate(env, payment.payment_id.clone(), None);
+    remove_transfer_offer(env, payment.payment_id.clone());
+    set_locked_transfer_approved(env, payment.payment_id.clone(), false);
+    set_checkin_commitment(env, payment.payment_id.clone(), None);
+
+    remove_payment_from_buyer_index(env, from.clone(), payment.payment_id.clone());
+    add_payment_to_buyer_index(env, to.clone(), payment.payment_id.clone());
+    transfer_ticket_nft(env, &payment.payment_id, &from, to);
+}
+
+/// Charges the event's transfer fee to a ticket's current holder, crediting
+/// the organizer. Returns the fee charged.
+fn charge_transfer_fee(env: &Env, payment: &Payment) -> i128 {
+    let transfer_fee = get_transfer_fee(env, payment.event_id.clone());
+    if transfer_fee > 0 {
+        let token_address = payment.token_address.clone();
+        let token_client = token::Client::new(env, &token_address);
+        let contract_address = env.current_contract_address();
+
+        // Transfer fee from old owner to contract
+        token_client.transfer_from(
+            &contract_address,
+            &payment.buyer_address,
+            &contract_address,
+            &transfer_fee,
+        );
+
+        // Update escrow balances (fee goes to organizer)
+        update_event_balance(
+            env,
+            payment.event_id.clone(),
+            &token_address,
+            transfer_fee,
+            0,
+        );
+        append_ledger_entry(
+            env,
+            payment.event_id.clone(),
+            LedgerEntryType::TransferRoyalty,
+            transfer_fee,
+            token_address,
+            payment.buyer_address.clone(),
+        );
+    }
+    transfer_fee
+}
+
+/// Returns the organizer royalty on an event's resales: the one set on this
+/// contract, else the one the event was registered with.
+fn resale_royalty_bps(env: &Env, event_id: &String) -> u32 {
+    get_resale_royalty_bps(env, event_id.clone()).unwrap_or_else(|| {
+        event_registry::Client::new(env, &get_event_registry_for(env, event_id.clone()))
+            .get_event(event_id)
+            .map_or(0, |event_info| event_info.resale_royalty_bps)
+    })
+}
+
+/// Whether a postponed event is still inside its refund grace period.
+fn in_postponement_grace(env: &Env, event_info: &event_registry::EventInfo) -> bool {
+    event_info.is_postponed && env.ledger().timestamp() <= event_info.grace_period_end
+}
+
+/// Rejects moving a ticket within `transfer_lock_before_secs` of its event's
+/// start, and after the start, unless the organizer approved the transfer.
+fn ensure_transfer_unlocked(env: &Env, payment: &Payment) -> Result<(), TicketPaymentError> {
+    if is_locked_transfer_approved(env, payment.payment_id.clone()) {
+        return Ok(());
+    }
+    let registry_client =
+        event_registry::Client::new(env, &get_event_registry_for(env, payment.event_id.clone()));
+    if let Some(event_info) = registry_client.get_event(&payment.event_id) {
+        let lock = event_info.transfer_lock_before_secs;
+        let start = event_info.event_start_time;
+        if lock > 0 && start > 0 && env.ledger().timestamp().saturating_add(lock) >= start {
+            return Err(TicketPaymentError::TransferLocked);
+        }
+    }
+    Ok(())
+}
+
+/// Moves a ticket to `to`. A positive `price` is settled as a resale paid by
+/// `to`; otherwise the holder pays the event's transfer fee.
+fn complete_transfer(
+    env: &Env,
+    payment: &mut Payment,
+    to: &Address,
+    price: i128,
+    memo: Option<Bytes>,
+) -> Result<(), TicketPaymentError> {
+    ensure_transfer_unlocked(env, payment)?;
+    let from = payment.buyer_address.clone();
+    if price > 0 {
+        let token = payment.token_address.clone();
+        settle_resale(env, payment, &token, to, price)?;
+        reassign_ticket(env, payment, to, memo);
+        return Ok(());
+    }
+
+    let transfer_fee = charge_transfer_fee(env, payment);
+    reassign_ticket(env, payment, to, memo);
+
+    #[allow(deprecated)]
+    env.events().publish(
+        (
+            AgoraEvent::TicketTransferred,
+            payment.event_id.clone(),
+            payment.payment_id.clone(),
+        ),
+        TicketTransferredEvent {
+            payment_id: payment.payment_id.clone(),
+            from,
+            to: to.clone(),
+            transfer_fee,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+    Ok(())
+}
+
+/// Collects a resale `price` from `buyer` for a ticket still held by its
+/// seller. The platform resale fee and organizer royalty stay in escrow; the
+/// seller is paid the rest directly. The sale is added to the ticket's resale
+/// history.
+fn settle_resale(
+    env: &Env,
+    payment: &Payment,
+    token: &Address,
+    buyer: &Address,
+    price: i128,
+) -> Result<(), TicketPaymentError> {
+    let event_id = payment.event_id.clone();
+    let seller = payment.buyer_address.clone();
+    // A paid transfer is a purchase, so it counts towards the buyer's limit
+    let registry_client =
+        event_registry::Client::new(env, &get_event_registry_for(env, event_id.clone()));
+    if let Some(event_info) = registry_client.get_event(&event_id) {
+        ensure_within_buyer_limit(env, &event_info, buyer, 1)?;
+    }
+    let (platform_fee, after_fee) =
+        split_fee(price, get_resale_fee_bps(env)).ok_or(TicketPaymentError::ArithmeticError)?;
+    let royalty = mul_div_bps(price, resale_royalty_bps(env, &event_id))
+        .ok_or(TicketPaymentError::ArithmeticError)?;
+    let seller_proceeds = after_fee
+        .checked_sub(royalty)
+        .filter(|proceeds| *proceeds >= 0)
+        .ok_or(TicketPaymentError::InvalidFeeBps)?;
+
+    let token_client = token::Client::new(env, token);
+    let contract_address = env.current_contract_address();
+    if token_client.allowance(buyer, &contract_address) < price {
+        return Err(TicketPaymentError::InsufficientAllowance);
+    }
+    if seller_proceeds > 0 {
+        token_client.transfer_from(&contract_address, buyer, &seller, &seller_proceeds);
+    }
+    let retained = platform_fee + royalty;
+    if retained > 0 {
+        collect_payment(env, token, buyer, retained)?;
+        update_event_balance(env, event_id.clone(), token, royalty, platform_fee);
+    }
+    if royalty > 0 {
+        append_ledger_entry(
+            env,
+            event_id.clone(),
+            LedgerEntryType::ResaleRoyalty,
+            royalty,
+            token.clone(),
+            buyer.clone(),
+        );
+    }
+    if platform_fee > 0 {
+        add_to_total_fees_collected_by_token(env, token.clone(), platform_fee);
+        add_to_tenant_fees_collected(
+            env,
+            get_event_registry_for(env, event_id),
+            token.clone(),
+            platform_fee,
+        );
+    }
+
+    let resold_at = env.ledger().timestamp();
+    append_resale_record(
+        env,
+        payment.payment_id.clone(),
+        ResaleRecord {
+            seller: seller.clone(),
+            buyer: buyer.clone(),
+            token: token.clone(),
+            price,
+            platform_fee,
+            royalty,
+            resold_at,
+        },
+    );
+
+    #[allow(deprecated)]
+    env.events().publish(
+        (
+            AgoraEvent::TicketResold,
+            payment.event_id.clone(),
+            payment.payment_id.clone(),
+        ),
+        TicketResoldEvent {
+            payment_id: payment.payment_id.clone(),
+            seller,
+            buyer: buyer.clone(),
+            token: token.clone(),
+            price,
+            platform_fee,
+            royalty,
+            timestamp: resold_at,
+        },
+    );
+    Ok(())
+}
+
+/// Mints the NFT for a newly issued ticket when a collection is registered.
+fn mint_ticket_nft(env: &Env, holder: &Address, event_id: &String, payment_id: &String) {
+    if let Some(addr) = get_ticket_nft_contract(env) {
+        let token_id =
+            ticket_nft::TicketNftClient::new(env, &addr).mint(holder, event_id, payment_id);
+        set_ticket_token_id(env, payment_id.clone(), Some(token_id));
+    }
+}
+
+/// Moves a ticket's NFT to its new holder, if the ticket has one.
+fn transfer_ticket_nft(env: &Env, payment_id: &String, from: &Address, to: &Address) {
+    if let (Some(addr), Some(token_id)) = (
+        get_ticket_nft_contract(env),
+        get_ticket_token_id(env, payment_id.clone()),
+    ) {
+        ticket_nft::TicketNftClient::new(env, &addr).transfer(from, to, &token_id);
+    }
+}
+
+/// Burns a refunded ticket's NFT, if the ticket has one.
+fn burn_ticket_nft(env: &Env, payment_id: &String) {
+    if let (Some(addr), Some(token_id)) = (
+        get_ticket_nft_contract(env),
+        get_ticket_token_id(env, payment_id.clone()),
+    ) {
+        ticket_nft::TicketNftClient::new(env, &addr).burn(&token_id);
+        set_ticket_token_id(env, payment_id.clone(), None);
+    }
+}
+
+/// Fails unless the registry currently authorizes `scanner` for the event.
+fn require_authorized_scanner(
+    env: &Env,
+    event_id: &String,
+    scanner: &Address,
+) -> Result<(), TicketPaymentError> {
+    let event_registry_addr = get_event_registry_for(env, event_id.clone());
+    let registry_client = event_registry::Client::new(env, &event_registry_addr);
+    if !registry_client.is_scanner_authorized(event_id, scanner) {
+        return Err(TicketPaymentError::UnauthorizedScanner);
+    }
+    Ok(())
+}
+
+/// Notifies the rewards contract, if any, of a check-in, isolating failures.
+fn notify_rewards_check_in(env: &Env, payment: &Payment) {
+    if let Some(addr) = get_rewards_contract(env) {
+        let _ = rewards::RewardsClient::new(env, &addr).try_on_check_in(
+            &payment.buyer_address,
+            &payment.event_id,
+            &payment.payment_id,
+        );
+    }
+}
+
+/// Asks the event's attendance minter, if any, to mint a proof-of-attendance
+/// token for `attendee`. Errors are swallowed so a broken minter can never block entry.
+fn mint_attendance_token(env: &Env, payment: &Payment, attendee: &Address) {
+    if let Some(addr) = get_attendance_minter(env, payment.event_id.clone()) {
+        let _ = attendance::AttendanceMinterClient::new(env, &addr).try_mint_attendance(
+            attendee,
+            &payment.event_id,
+            &payment.payment_id,
+        );
+    }
+}
+
+/// Looks up the voucher for a code presented at checkout and checks that it can
+/// pay for a purchase by `buyer` in `token_address`.
+fn redeemable_voucher(
+    env: &Env,
+    code: &Bytes,
+    buyer: &Address,
+    token_address: &Address,
+) -> Result<Voucher, TicketPaymentError> {
+    let hash: BytesN<32> = env.crypto().sha256(code).into();
+    let voucher = get_voucher(env, hash).ok_or(TicketPaymentError::InvalidDiscountCode)?;
+    if env.ledger().timestamp() >= voucher.expires_at || &voucher.token != token_address {
+        return Err(TicketPaymentError::InvalidDiscountCode);
+    }
+    if voucher
+        .holder
+        .as_ref()
+        .is_some_and(|holder| holder != buyer)
+    {
+        return Err(TicketPaymentError::Unauthorized);
+    }
+    if voucher.balance <= 0 {
+        return Err(TicketPaymentError::DiscountCodeAlreadyUsed);
+    }
+    Ok(voucher)
+}
+
+/// Credits the buyer of a confirmed payment with loyalty points at the earn
+/// rate of the token it was paid in.
+fn accrue_loyalty_points(env: &Env, payment: &Payment) {
+    let earned = mul_div_bps(
+        payment.amount,
+        get_loyalty_earn_bps(env, payment.token_address.clone()),
+    )
+    .unwrap_or(0);
+    if earned > 0 {
+        let points = get_loyalty_points(env, payment.buyer_address.clone());
+        set_loyalty_points(env, payment.buyer_address.clone(), points + earned);
+    }
+}
+
+/// Reduces `price` by `bps` basis points.
+fn apply_discount_bps(price: i128, bps: u32) -> Result<i128, TicketPaymentError> {
+    agora_money::apply_discount(price, bps).ok_or(TicketPaymentError::ArithmeticError)
+}
+
+/// Returns the ids of the tickets a purchase of `quantity` under `payment_id`
+/// would create, failing if any of them is already taken.
+fn unused_payment_ids(
+    env: &Env,
+    payment_id: &String,
+    quantity: u32,
+) -> Result<Vec<String>, TicketPaymentError> {
+    if quantity > 1 && payment_id.len() > MAX_PAYMENT_ID_LEN {
+        return Err(TicketPaymentError::PaymentIdTooLong);
+    }
+    let mut ids = Vec::new(env);
+    for i in 0..quantity {
+        // Each ticket of a batch gets its own id, `<payment_id>-<index>`
+        let id = if quantity == 1 {
+            payment_id.clone()
+        } else {
+            batch_payment_id(env, payment_id, i)
+        };
+        if get_payment(env, id.clone()).is_some() {
+            return Err(TicketPaymentError::PaymentAlreadyExists);
+        }
+        ids.push_back(id);
+    }
+    Ok(ids)
+}
+
+/// Derives the id of the `index`th ticket of a batch purchase from the
+/// purchase's `payment_id`, as `<payment_id>-<index>`. `payment_id` is at
+/// most `MAX_PAYMENT_ID_LEN` bytes, as checked by `unused_payment_ids`.
+fn batch_payment_id(env: &Env, payment_id: &String, index: u32) -> String {
+    let len = payment_id.len() as usize;
+    // Room for the separator and the ten digits of u32::MAX
+    let mut buf = [0u8; MAX_PAYMENT_ID_LEN as usize + 11];
+    payment_id.copy_into_slice(&mut buf[..len]);
+    buf[len] = b'-';
+
+    let mut digits = [0u8; 10];
+    let mut n = index;
+    let mut count = 0;
+    loop {
+        digits[count] = b'0' + (n % 10) as u8;
+        count += 1;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    for (offset, digit) in digits[..count].iter().rev().enumerate() {
+        buf[len + 1 + offset] = *digit;
+    }
+    String::from_bytes(env, &buf[..len + 1 + count])
+}
+
+fn validate_memo(memo: &Option<Bytes>) -> Result<(), TicketPaymentError> {
+    match memo {
+        Some(memo) if memo.len() > MAX_MEMO_LEN => Err(TicketPaymentError::MemoTooLong),
+        _ => Ok(()),
+    }
+}
+
+/// Resolves the registry tenant for a purchase: the registry that bound the
+/// event at registration, or the default registry. An explicit registry must
+/// be allowed and must match.
+/// Returns the namespace of a `<namespace>:<id>` event id: the bytes before
+/// the first `:`, if it is a valid tenant namespace. Ids longer than
+/// `MAX_TENANT_EVENT_ID_LEN` have no namespace.
+fn event_id_namespace(env: &Env, event_id: &String) -> Option<String> {
+    let len = event_id.len() as usize;
+    if len > MAX_TENANT_EVENT_ID_LEN as usize {
+        return None;
+    }
+    let mut buf = [0u8; MAX_TENANT_EVENT_ID_LEN as usize];
+    event_id.copy_into_slice(&mut buf[..len]);
+    let split = buf[..len].iter().position(|b| *b == b':')?;
+    if split == 0 || split > MAX_TENANT_NAMESPACE_LEN as usize {
+        return None;
+    }
+    Some(String::from_bytes(env, &buf[..split]))
+}
+
+fn resolve_event_registry(
+    env: &Env,
+    event_id: String,
+    requested: Option<Address>,
+) -> Result<Address, TicketPaymentError> {
+    let registry = get_event_registry_for(env, event_id);
+    match requested {
+        Some(r) if r != registry || !is_registry_allowed(env, &r) => {
+            Err(TicketPaymentError::RegistryNotAllowed)
+        }
+        _ => Ok(registry),
+    }
+}
@@ -19,7 +19,6 @@ pub enum TicketPaymentError {
     TransferVerificationFailed = 13,
     ArithmeticError = 14,
     SelfReferralNotAllowed = 15,
-    PriceMismatch = 16,
     InvalidPrice = 17,
     InvalidDiscountCode = 18,
     DiscountCodeAlreadyUsed = 19,
@@ -40,6 +39,33 @@ pub enum TicketPaymentError {
     OraclePriceUnavailable = 41,
     PriceOutsideSlippage = 42,
     InvalidSlippageBps = 43,
+    InvalidFeeBps = 44,
+    TierNotVisible = 45,
+    IdentityMismatch = 46,
+    MemoTooLong = 47,
+    RegistryNotAllowed = 48,
+    InvalidDepositDeduction = 49,
+    RecoveryNotReady = 50,
+    InvalidDiscountBps = 51,
+    InvalidPoll = 52,
+    PollClosed = 53,
+    InvalidSponsorSlot = 54,
+    SponsorSlotSoldOut = 55,
+    TicketListed = 56,
+    InstallmentOverdue = 57,
+    PaymentAlreadyExists = 58,
+    InvalidStorageVersion = 59,
+    RescueNotReady = 60,
+    EventPaused = 61,
+    BuyerLimitExceeded = 62,
+    TransferOfferExpired = 63,
+    TransferLocked = 64,
+    InvalidCheckInProof = 65,
+    YieldFundsDeployed = 66,
+    InvalidSeatSelection = 67,
+    InvalidBundlePaymentIds = 68,
+    PaymentIdTooLong = 69,
+    TenantNamespaceUnavailable = 70,
 }
 
 impl core::fmt::Display for TicketPaymentError {
@@ -72,9 +98,6 @@ impl core::fmt::Display for TicketPaymentError {
             TicketPaymentError::SelfReferralNotAllowed => {
                 write!(f, "Self-referral is not allowed")
             }
-            TicketPaymentError::PriceMismatch => {
-                write!(f, "Price mismatch")
-            }
             TicketPaymentError::InvalidPrice => {
                 write!(
                     f,
@@ -128,6 +151,99 @@ impl core::fmt::Display for TicketPaymentError {
             TicketPaymentError::InvalidSlippageBps => {
                 write!(f, "Slippage basis points out of range (max 5000)")
             }
+            TicketPaymentError::InvalidFeeBps => {
+                write!(f, "Fee basis points out of range (max 10000)")
+            }
+            TicketPaymentError::TierNotVisible => {
+                write!(f, "Ticket tier is not currently on sale")
+            }
+            TicketPaymentError::IdentityMismatch => {
+                write!(f, "Presented identity does not match the ticket's binding")
+            }
+            TicketPaymentError::MemoTooLong => {
+                write!(f, "Memo exceeds the maximum allowed length")
+            }
+            TicketPaymentError::RegistryNotAllowed => {
+                write!(f, "Registry is not allowed for this event")
+            }
+            TicketPaymentError::TenantNamespaceUnavailable => {
+                write!(
+                    f,
+                    "Event id namespace is invalid or reserved by another registry"
+                )
+            }
+            TicketPaymentError::InvalidDepositDeduction => {
+                write!(f, "Deposit deduction must be between zero and the deposit")
+            }
+            TicketPaymentError::RecoveryNotReady => {
+                write!(f, "Escrow recovery is not available yet")
+            }
+            TicketPaymentError::InvalidDiscountBps => {
+                write!(f, "Discount must be between 1 and 10000 basis points")
+            }
+            TicketPaymentError::InvalidPoll => write!(f, "Poll or poll option is invalid"),
+            TicketPaymentError::PollClosed => write!(f, "Poll voting has closed"),
+            TicketPaymentError::InvalidSponsorSlot => {
+                write!(f, "Sponsor slot is unknown or its definition is invalid")
+            }
+            TicketPaymentError::SponsorSlotSoldOut => write!(f, "Sponsor slot is sold out"),
+            TicketPaymentError::TicketListed => {
+                write!(f, "Ticket is listed for resale; cancel the listing first")
+            }
+            TicketPaymentError::InstallmentOverdue => {
+                write!(
+                    f,
+                    "Installment deadline has passed; the plan can only be forfeited"
+                )
+            }
+            TicketPaymentError::PaymentAlreadyExists => {
+                write!(f, "A payment with this id already exists")
+            }
+            TicketPaymentError::InvalidStorageVersion => {
+                write!(f, "Storage is not at the version being migrated from")
+            }
+            TicketPaymentError::BuyerLimitExceeded => {
+                write!(f, "Purchase exceeds the event's per-buyer ticket limit")
+            }
+            TicketPaymentError::EventPaused => {
+                write!(f, "Sales and transfers of this event are paused")
+            }
+            TicketPaymentError::PaymentIdTooLong => {
+                write!(f, "Payment id of a batch purchase exceeds 64 bytes")
+            }
+            TicketPaymentError::InvalidBundlePaymentIds => {
+                write!(f, "Bundle purchases need one payment id per bundled event")
+            }
+            TicketPaymentError::InvalidSeatSelection => {
+                write!(
+                    f,
+                    "Seated tiers need one seat per ticket; other tiers take none"
+                )
+            }
+            TicketPaymentError::YieldFundsDeployed => {
+                write!(f, "Funds are still deployed in the current yield adapter")
+            }
+            TicketPaymentError::InvalidCheckInProof => {
+                write!(
+                    f,
+                    "Check-in proof is missing or does not match the commitment"
+                )
+            }
+            TicketPaymentError::TransferLocked => {
+                write!(
+                    f,
+                    "Transfers are locked this close to the event start without organizer approval"
+                )
+            }
+            TicketPaymentError::TransferOfferExpired => {
+                write!(f, "Transfer offer has expired")
+            }
+            TicketPaymentError::RescueNotReady => {
+                write!(
+                    f,
+                    "No matching token rescue is pending or its timelock has not elapsed"
+                )
+            }
         }
     }
 }
@@ -1,6 +1,10 @@
 use crate::types::PaymentStatus;
-use soroban_sdk::{contracttype, Address, BytesN, String};
+use soroban_sdk::{contracttype, Address, BytesN, Map, String};
 
+/// First topic of every event the contract publishes. Events about a single
+/// event carry its event_id as the second topic, and events about a single
+/// payment add its payment_id as the third, so indexers can subscribe to one
+/// event or ticket without decoding event data.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AgoraEvent {
@@ -18,7 +22,53 @@ pub enum AgoraEvent {
     ContractPaused,
     DisputeStatusChanged,
     PartialRefundProcessed,
+    PaymentPartiallyRefunded,
     TicketCheckedIn,
+    RefundFeeCollected,
+    FeesSettledBatch,
+    FlashSaleStarted,
+    FlashSaleEnded,
+    BoxOfficeAllocated,
+    BoxOfficeTicketAssigned,
+    DepositDeductionFlagged,
+    DepositReleased,
+    EscrowRecoveryInitiated,
+    EscrowRecoveryVetoed,
+    EscrowRecovered,
+    AttendanceCouponsAirdropped,
+    PollOpened,
+    PollVoteCast,
+    EventOperatorGranted,
+    SponsorSlotDefined,
+    SponsorSlotPurchased,
+    TicketListed,
+    TicketListingCancelled,
+    TicketResold,
+    WithdrawableThresholdReached,
+    GroupDiscountApplied,
+    InstallmentPlanStarted,
+    InstallmentPaid,
+    InstallmentPlanForfeited,
+    RefundPoolCreated,
+    RefundPoolClaimed,
+    RefundPoolSwept,
+    DisputeOpened,
+    DisputeResolved,
+    PlatformWalletProposed,
+    PlatformWalletUpdated,
+    AdminTransferStarted,
+    AdminTransferred,
+    StorageMigrated,
+    TokenRescueProposed,
+    TokenRescueCancelled,
+    TokensRescued,
+    RefundSpikeDetected,
+    EventPaused,
+    TicketDelegated,
+    TransferOffered,
+    TransferOfferCancelled,
+    RefundBatchCompleted,
+    YieldCredited,
 }
 
 #[contracttype]
@@ -39,6 +89,7 @@ pub struct PaymentStatusChangedEvent {
     pub old_status: PaymentStatus,
     pub new_status: PaymentStatus,
     pub transaction_hash: String,
+    pub receipt_hash: Option<BytesN<32>>, // set when a receipt was issued
     pub timestamp: u64,
 }
 
@@ -56,6 +107,49 @@ pub struct ContractUpgraded {
     pub old_wasm_hash: BytesN<32>,
     pub new_wasm_hash: BytesN<32>,
 }
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageMigratedEvent {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenRescueProposedEvent {
+    pub token: Address,
+    pub amount: i128,
+    pub to: Address,
+    pub executable_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenRescueCancelledEvent {
+    pub token: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokensRescuedEvent {
+    pub token: Address,
+    pub amount: i128,
+    pub to: Address,
+    pub timestamp: u64,
+}
+
+/// Strategy earnings above the deployed principal, credited to platform fees.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldCreditedEvent {
+    pub token: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TicketTransferredEvent {
@@ -84,6 +178,18 @@ pub struct BulkRefundProcessedEvent {
     pub timestamp: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundBatchCompletedEvent {
+    pub event_id: String,
+    pub refund_count: u32,
+    pub total_refunded: i128,
+    pub processed: u32,
+    pub total: u32,
+    pub deferred: u32,
+    pub timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DiscountCodeAppliedEvent {
@@ -110,6 +216,19 @@ pub struct FeeSettledEvent {
     pub platform_wallet: Address,
     pub fee_amount: i128,
     pub fee_bps: u32,
+    pub invoice_id: BytesN<32>,
+    pub timestamp: u64,
+}
+
+/// Summary of a `settle_all_platform_fees` batch over the fee-event index.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeesSettledBatchEvent {
+    pub platform_wallet: Address,
+    pub start: u32,
+    pub next_start: u32,
+    pub events_settled: u32,
+    pub settled_by_token: Map<Address, i128>,
     pub timestamp: u64,
 }
 
@@ -123,6 +242,17 @@ pub struct GlobalPromoAppliedEvent {
     pub timestamp: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupDiscountAppliedEvent {
+    pub payment_id: String,
+    pub event_id: String,
+    pub quantity: u32,
+    pub discount_bps: u32,
+    pub saved_amount: i128,
+    pub timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ContractPausedEvent {
@@ -138,6 +268,25 @@ pub struct DisputeStatusChangedEvent {
     pub timestamp: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventPausedEvent {
+    pub event_id: String,
+    pub paused: bool,
+    pub changed_by: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundSpikeDetectedEvent {
+    pub event_id: String,
+    pub refunds: u32,
+    pub sales: u32,
+    pub window_start: u64,
+    pub timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PartialRefundProcessedEvent {
@@ -148,6 +297,29 @@ pub struct PartialRefundProcessedEvent {
     pub timestamp: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentPartiallyRefundedEvent {
+    pub payment_id: String,
+    pub event_id: String,
+    pub amount: i128,
+    /// Cumulative amount refunded on the ticket, including this refund
+    pub refunded_total: i128,
+    /// Whether the refunds reached the void threshold and cancelled the ticket
+    pub voided: bool,
+    pub timestamp: u64,
+}
+
+/// `attendee` is `None` when a delegation is revoked.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketDelegatedEvent {
+    pub payment_id: String,
+    pub holder: Address,
+    pub attendee: Option<Address>,
+    pub timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TicketCheckedInEvent {
@@ -156,3 +328,332 @@ pub struct TicketCheckedInEvent {
     pub scanner: Address,
     pub timestamp: u64,
 }
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundFeeCollectedEvent {
+    pub payment_id: String,
+    pub event_id: String,
+    pub fee_amount: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FlashSaleStartedEvent {
+    pub event_id: String,
+    pub tier_id: String,
+    pub discount_bps: u32,
+    pub end: u64,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FlashSaleEndedEvent {
+    pub event_id: String,
+    pub tier_id: String,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BoxOfficeAllocatedEvent {
+    pub event_id: String,
+    pub tier_id: String,
+    pub custodian: Address,
+    pub quantity: u32,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BoxOfficeTicketAssignedEvent {
+    pub payment_id: String,
+    pub event_id: String,
+    pub tier_id: String,
+    pub custodian: Address,
+    pub buyer: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositDeductionFlaggedEvent {
+    pub payment_id: String,
+    pub amount: i128,
+    pub reason: String,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositReleasedEvent {
+    pub payment_id: String,
+    pub refunded: i128,
+    pub deducted: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowRecoveryInitiatedEvent {
+    pub event_id: String,
+    pub recovery_address: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowRecoveryVetoedEvent {
+    pub event_id: String,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowRecoveredEvent {
+    pub event_id: String,
+    pub recovery_address: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttendanceCouponsAirdroppedEvent {
+    pub source_event_id: String,
+    pub target_event_id: String,
+    pub discount_bps: u32,
+    pub granted: u32,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PollOpenedEvent {
+    pub event_id: String,
+    pub poll_id: u32,
+    pub question_hash: BytesN<32>,
+    pub option_count: u32,
+    pub deadline: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PollVoteCastEvent {
+    pub event_id: String,
+    pub poll_id: u32,
+    pub voter: Address,
+    pub option: u32,
+    pub weight: u32,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventOperatorGrantedEvent {
+    pub event_id: String,
+    pub operator: Address,
+    pub permissions: u32,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SponsorSlotDefinedEvent {
+    pub event_id: String,
+    pub slot_id: String,
+    pub price: i128,
+    pub quantity: u32,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SponsorSlotPurchasedEvent {
+    pub event_id: String,
+    pub slot_id: String,
+    pub sponsor: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub platform_fee: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketListedEvent {
+    pub payment_id: String,
+    pub event_id: String,
+    pub seller: Address,
+    pub token: Address,
+    pub price: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketListingCancelledEvent {
+    pub payment_id: String,
+    pub seller: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferOfferedEvent {
+    pub payment_id: String,
+    pub from: Address,
+    pub to: Address,
+    pub price: i128,
+    pub expires_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferOfferCancelledEvent {
+    pub payment_id: String,
+    pub from: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketResoldEvent {
+    pub payment_id: String,
+    pub seller: Address,
+    pub buyer: Address,
+    pub token: Address,
+    pub price: i128,
+    pub platform_fee: i128,
+    pub royalty: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawableThresholdReachedEvent {
+    pub event_id: String,
+    pub organizer_address: Address,
+    pub withdrawable: i128,
+    pub min_withdrawal: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InstallmentPlanStartedEvent {
+    pub payment_id: String,
+    pub event_id: String,
+    pub buyer: Address,
+    pub total_amount: i128,
+    pub num_installments: u32,
+    pub next_due: u64,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InstallmentPaidEvent {
+    pub payment_id: String,
+    pub amount: i128,
+    pub installments_paid: u32,
+    pub next_due: u64,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InstallmentPlanForfeitedEvent {
+    pub payment_id: String,
+    pub refunded: i128,
+    pub fee: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundPoolCreatedEvent {
+    pub event_id: String,
+    pub locked: Map<Address, i128>,
+    pub claim_deadline: u64,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundPoolClaimedEvent {
+    pub event_id: String,
+    pub payment_id: String,
+    pub buyer: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundPoolSweptEvent {
+    pub event_id: String,
+    pub organizer: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeOpenedEvent {
+    pub payment_id: String,
+    pub event_id: String,
+    pub opened_by: Address,
+    pub reason_cid: String,
+    pub bond: i128,
+    pub frozen_amount: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeResolvedEvent {
+    pub payment_id: String,
+    pub event_id: String,
+    pub arbiter: Address,
+    pub buyer_amount: i128,
+    pub organizer_amount: i128,
+    pub bond_recipient: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformWalletProposedEvent {
+    pub current_wallet: Address,
+    pub proposed_wallet: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformWalletUpdatedEvent {
+    pub old_wallet: Address,
+    pub new_wallet: Address,
+    pub timestamp: u64,
+}
+
+/// Emitted when an admin transfer is started, or with `pending_admin` of None
+/// when the pending transfer is cancelled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferStartedEvent {
+    pub admin: Address,
+    pub pending_admin: Option<Address>,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferredEvent {
+    pub old_admin: Address,
+    pub new_admin: Address,
+    pub timestamp: u64,
+}
@@ -1,8 +1,71 @@
-use crate::types::{DataKey, EventBalance, Payment, PaymentStatus};
-use soroban_sdk::{vec, Address, Env, String, Vec};
+use crate::types::{
+    AttendanceRecord, BundlePurchase, CheckInStats, CouponKey, DataKey, DepositKey, DiscountCode,
+    DisputeKey, EventBalance, EventLedgerKey, FailedPurchase, FeeEventIndexKey, FlashSaleState,
+    InstallmentKey, InstallmentPlan, IntegrationKey, LedgerEntry, LedgerEntryType, Listing,
+    MarketKey, OperatorKey, PartialRefundKey, Payment, PaymentDispute, PaymentStatus, PaymentV1,
+    PayoutConfig, Poll, PollKey, PromoScope, PromoStats, PurchaseTerms, Receipt, ReceiptKey,
+    RecoveryKey, ReferralKey, RefundKey, RefundPool, RefundProcessingFee, RefundSpikeConfig,
+    RefundWindow, ResaleRecord, Reservation, ScanRecord, SettlementKey, SettlementRecord,
+    SponsorKey, SponsorSlot, Sponsorship, TenantKey, TicketCommissions, TicketDeposit, TokenRescue,
+    TransferOffer, TreasuryKey, Voucher, WithdrawalKey, MAX_FAILED_PURCHASES,
+};
+use agora_money::MAX_BPS;
+use agora_pagination::{page_from_iter, read_sharded, PageCursor};
+use soroban_sdk::{vec, Address, Env, Map, String, Symbol, TryFromVal, Val, Vec};
 
 const SHARD_SIZE: u32 = 100;
 
+/// Ledgers payment and escrow entries are kept alive for when extended
+/// (about 30 days at 5s ledgers).
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 518_400;
+/// Remaining TTL below which touching an entry extends it.
+pub const PERSISTENT_LIFETIME_THRESHOLD: u32 = 120_960;
+
+/// Extends a persistent entry's TTL if it exists and is close to archival.
+fn extend_persistent_ttl(env: &Env, key: &DataKey) {
+    let storage = env.storage().persistent();
+    if storage.has(key) {
+        storage.extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+}
+
+fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+/// Extends the TTL of a payment, its index entries, terms, receipt and
+/// deposit. Returns false if the payment is unknown.
+pub fn extend_payment_ttl(env: &Env, payment_id: String) -> bool {
+    let Some(payment) = get_payment(env, payment_id.clone()) else {
+        return false;
+    };
+    for key in [
+        DataKey::EventPayment(payment.event_id, payment_id.clone()),
+        DataKey::BuyerPayment(payment.buyer_address, payment_id.clone()),
+        DataKey::Receipt(ReceiptKey::Terms(payment_id.clone())),
+        DataKey::Receipt(ReceiptKey::Issued(payment_id.clone())),
+        DataKey::Deposit(DepositKey::Ticket(payment_id)),
+    ] {
+        extend_persistent_ttl(env, &key);
+    }
+    extend_instance_ttl(env);
+    true
+}
+
+/// Extends the TTL of an event's escrow balance and payment index.
+pub fn extend_event_ttl(env: &Env, event_id: String) {
+    let count = get_event_payment_count(env, event_id.clone());
+    for shard_id in 0..count.div_ceil(SHARD_SIZE) {
+        extend_persistent_ttl(env, &DataKey::EventPaymentShard(event_id.clone(), shard_id));
+    }
+    extend_persistent_ttl(env, &DataKey::EventPaymentCount(event_id.clone()));
+    extend_persistent_ttl(env, &DataKey::TransferFee(event_id.clone()));
+    extend_persistent_ttl(env, &DataKey::Balances(event_id));
+    extend_instance_ttl(env);
+}
+
 pub fn set_admin(env: &Env, admin: &Address) {
     env.storage().persistent().set(&DataKey::Admin, admin);
 }
@@ -11,11 +74,26 @@ pub fn get_admin(env: &Env) -> Option<Address> {
     env.storage().persistent().get(&DataKey::Admin)
 }
 
+pub fn set_pending_admin(env: &Env, admin: Option<Address>) {
+    match admin {
+        Some(admin) => env
+            .storage()
+            .persistent()
+            .set(&DataKey::PendingAdmin, &admin),
+        None => env.storage().persistent().remove(&DataKey::PendingAdmin),
+    }
+}
+
+pub fn get_pending_admin(env: &Env) -> Option<Address> {
+    env.storage().persistent().get(&DataKey::PendingAdmin)
+}
+
 pub fn store_payment(env: &Env, payment: Payment) {
     let key = DataKey::Payment(payment.payment_id.clone());
     let exists = env.storage().persistent().has(&key);
 
     env.storage().persistent().set(&key, &payment);
+    extend_persistent_ttl(env, &key);
 
     if !exists {
         // Index by event
@@ -32,7 +110,19 @@ pub fn store_payment(env: &Env, payment: Payment) {
 
 pub fn get_payment(env: &Env, payment_id: String) -> Option<Payment> {
     let key = DataKey::Payment(payment_id);
-    env.storage().persistent().get(&key)
+    let raw: Map<Symbol, Val> = env.storage().persistent().get(&key)?;
+    extend_persistent_ttl(env, &key);
+    // Version 1 records predate multi-token payments and are converted on read;
+    // they are written back in the current layout on their next update.
+    if raw.contains_key(Symbol::new(env, "token_address")) {
+        Some(Payment::try_from_val(env, &raw.to_val()).unwrap())
+    } else {
+        Some(
+            PaymentV1::try_from_val(env, &raw.to_val())
+                .unwrap()
+                .into_current(get_usdc_token(env)),
+        )
+    }
 }
 
 pub fn update_payment_status(
@@ -56,6 +146,29 @@ pub fn get_event_payment_count(env: &Env, event_id: String) -> u32 {
         .unwrap_or(0)
 }
 
+/// Returns a page of an event's payments in purchase order, skipping those not
+/// in `status`. The cursor counts matching payments only.
+pub fn get_event_payments_filtered_page(
+    env: &Env,
+    event_id: String,
+    status: Option<PaymentStatus>,
+    cursor: PageCursor,
+) -> Vec<Payment> {
+    let num_shards = get_event_payment_count(env, event_id.clone()).div_ceil(SHARD_SIZE);
+    let payments = (0..num_shards)
+        .flat_map(|i| {
+            let shard: Vec<String> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::EventPaymentShard(event_id.clone(), i))
+                .unwrap_or_else(|| vec![env]);
+            shard.into_iter()
+        })
+        .filter_map(|payment_id| get_payment(env, payment_id))
+        .filter(|payment| status.as_ref().is_none_or(|s| payment.status == *s));
+    page_from_iter(env, cursor, payments)
+}
+
 pub fn get_event_payments(env: &Env, event_id: String) -> Vec<String> {
     let count = get_event_payment_count(env, event_id.clone());
     let mut all_payments = vec![env];
@@ -78,6 +191,45 @@ pub fn get_event_payments(env: &Env, event_id: String) -> Vec<String> {
     all_payments
 }
 
+/// Returns the cursor's window of a buyer's payment ids. Shards shrink when
+/// tickets are transferred away, so they are walked in order rather than indexed.
+pub fn get_buyer_payments_page(
+    env: &Env,
+    buyer_address: Address,
+    cursor: PageCursor,
+) -> Vec<String> {
+    page_from_iter(env, cursor, buyer_payment_ids(env, buyer_address))
+}
+
+/// Returns a page of a buyer's payments, skipping those outside the given
+/// event and status. The cursor counts matching payments only.
+pub fn get_buyer_payments_filtered_page(
+    env: &Env,
+    buyer_address: Address,
+    event_id: Option<String>,
+    status: Option<PaymentStatus>,
+    cursor: PageCursor,
+) -> Vec<Payment> {
+    let payments = buyer_payment_ids(env, buyer_address)
+        .filter_map(|payment_id| get_payment(env, payment_id))
+        .filter(|payment| event_id.as_ref().is_none_or(|id| payment.event_id == *id))
+        .filter(|payment| status.as_ref().is_none_or(|s| payment.status == *s));
+    page_from_iter(env, cursor, payments)
+}
+
+/// Iterates a buyer's payment ids in purchase order, one shard at a time.
+fn buyer_payment_ids(env: &Env, buyer_address: Address) -> impl Iterator<Item = String> + '_ {
+    let num_shards = get_buyer_payment_count(env, buyer_address.clone()).div_ceil(SHARD_SIZE);
+    (0..num_shards).flat_map(move |i| {
+        let shard: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BuyerPaymentShard(buyer_address.clone(), i))
+            .unwrap_or_else(|| vec![env]);
+        shard.into_iter()
+    })
+}
+
 pub fn get_buyer_payment_count(env: &Env, buyer_address: Address) -> u32 {
     env.storage()
         .persistent()
@@ -134,6 +286,20 @@ pub fn get_platform_wallet(env: &Env) -> Address {
         .expect("Platform wallet not set")
 }
 
+pub fn set_pending_platform_wallet(env: &Env, wallet: Option<Address>) {
+    let key = DataKey::Treasury(TreasuryKey::PendingPlatformWallet);
+    match wallet {
+        Some(wallet) => env.storage().persistent().set(&key, &wallet),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_pending_platform_wallet(env: &Env) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Treasury(TreasuryKey::PendingPlatformWallet))
+}
+
 pub fn set_event_registry(env: &Env, address: Address) {
     env.storage()
         .persistent()
@@ -160,6 +326,21 @@ pub fn is_initialized(env: &Env) -> bool {
         .unwrap_or(false)
 }
 
+pub fn set_storage_version(env: &Env, version: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::StorageVersion, &version);
+}
+
+/// Layout version storage has been migrated to; 1 for deployments that
+/// predate versioning.
+pub fn get_storage_version(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StorageVersion)
+        .unwrap_or(1)
+}
+
 pub fn set_is_paused(env: &Env, paused: bool) {
     env.storage().persistent().set(&DataKey::IsPaused, &paused);
 }
@@ -175,12 +356,34 @@ pub fn add_token_to_whitelist(env: &Env, token: &Address) {
     env.storage()
         .persistent()
         .set(&DataKey::TokenWhitelist(token.clone()), &true);
+    let mut tokens = get_whitelisted_tokens(env);
+    if !tokens.contains(token) {
+        tokens.push_back(token.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Treasury(TreasuryKey::Whitelisted), &tokens);
+    }
 }
 
 pub fn remove_token_from_whitelist(env: &Env, token: &Address) {
     env.storage()
         .persistent()
         .remove(&DataKey::TokenWhitelist(token.clone()));
+    let mut tokens = get_whitelisted_tokens(env);
+    if let Some(index) = tokens.first_index_of(token) {
+        tokens.remove(index);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Treasury(TreasuryKey::Whitelisted), &tokens);
+    }
+}
+
+/// Tokens whitelisted since the list was introduced, in whitelisting order.
+pub fn get_whitelisted_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Treasury(TreasuryKey::Whitelisted))
+        .unwrap_or_else(|| vec![env])
 }
 
 pub fn is_token_whitelisted(env: &Env, token: &Address) -> bool {
@@ -191,9 +394,11 @@ pub fn is_token_whitelisted(env: &Env, token: &Address) -> bool {
 }
 
 pub fn get_event_balance(env: &Env, event_id: String) -> EventBalance {
+    let key = DataKey::Balances(event_id);
+    extend_persistent_ttl(env, &key);
     env.storage()
         .persistent()
-        .get(&DataKey::Balances(event_id))
+        .get(&key)
         .unwrap_or(EventBalance {
             organizer_amount: 0,
             total_withdrawn: 0,
@@ -201,27 +406,158 @@ pub fn get_event_balance(env: &Env, event_id: String) -> EventBalance {
         })
 }
 
+/// Adjusts an event's balance for money held in `token`. The aggregate
+/// balance always moves; tokens other than the default one also keep their
+/// own slice so refunds, fees and payouts can be settled in the right asset.
 pub fn update_event_balance(
     env: &Env,
     event_id: String,
+    token: &Address,
     organizer_amount: i128,
     platform_fee: i128,
 ) {
     let mut balance = get_event_balance(env, event_id.clone());
-    balance.organizer_amount = balance
-        .organizer_amount
-        .checked_add(organizer_amount)
-        .unwrap();
-    balance.platform_fee = balance.platform_fee.checked_add(platform_fee).unwrap();
+    add_to_balance(&mut balance, organizer_amount, platform_fee, 0);
+    set_event_balance(env, event_id.clone(), balance);
+
+    if *token != get_usdc_token(env) {
+        let mut slices = get_event_token_slices(env, event_id.clone());
+        let mut slice = slices.get(token.clone()).unwrap_or(zero_balance());
+        add_to_balance(&mut slice, organizer_amount, platform_fee, 0);
+        slices.set(token.clone(), slice);
+        set_event_token_slices(env, event_id.clone(), &slices);
+    }
+
+    if platform_fee > 0 {
+        add_to_fee_event_index(env, event_id);
+    }
+}
+
+/// Moves `amount` of the event's organizer share in `token` to withdrawn.
+pub fn record_event_withdrawal(env: &Env, event_id: String, token: &Address, amount: i128) {
+    let mut balance = get_event_balance(env, event_id.clone());
+    add_to_balance(&mut balance, -amount, 0, amount);
+    set_event_balance(env, event_id.clone(), balance);
+
+    if *token != get_usdc_token(env) {
+        let mut slices = get_event_token_slices(env, event_id.clone());
+        let mut slice = slices.get(token.clone()).unwrap_or(zero_balance());
+        add_to_balance(&mut slice, -amount, 0, amount);
+        slices.set(token.clone(), slice);
+        set_event_token_slices(env, event_id, &slices);
+    }
+}
+
+/// Clears the event's platform fee held in `token` and returns it.
+pub fn take_event_platform_fee(env: &Env, event_id: String, token: &Address) -> i128 {
+    let fee = get_event_token_balance(env, event_id.clone(), token).platform_fee;
+    if fee != 0 {
+        update_event_balance(env, event_id, token, 0, -fee);
+    }
+    fee
+}
+
+/// The part of an event's balance held in `token`.
+pub fn get_event_token_balance(env: &Env, event_id: String, token: &Address) -> EventBalance {
+    let slices = get_event_token_slices(env, event_id.clone());
+    if *token != get_usdc_token(env) {
+        return slices.get(token.clone()).unwrap_or(zero_balance());
+    }
+    let mut balance = get_event_balance(env, event_id);
+    for slice in slices.values() {
+        add_to_balance(
+            &mut balance,
+            -slice.organizer_amount,
+            -slice.platform_fee,
+            -slice.total_withdrawn,
+        );
+    }
+    balance
+}
+
+/// Every token the event holds a balance in, default token first.
+pub fn get_event_balance_tokens(env: &Env, event_id: String) -> Vec<Address> {
+    let mut tokens = vec![env, get_usdc_token(env)];
+    for token in get_event_token_slices(env, event_id).keys() {
+        tokens.push_back(token);
+    }
+    tokens
+}
+
+fn get_event_token_slices(env: &Env, event_id: String) -> Map<Address, EventBalance> {
+    let key = DataKey::EventLedger(EventLedgerKey::TokenBalances(event_id));
+    extend_persistent_ttl(env, &key);
     env.storage()
         .persistent()
-        .set(&DataKey::Balances(event_id), &balance);
+        .get(&key)
+        .unwrap_or(Map::new(env))
 }
 
-pub fn set_event_balance(env: &Env, event_id: String, balance: EventBalance) {
+fn set_event_token_slices(env: &Env, event_id: String, slices: &Map<Address, EventBalance>) {
+    let key = DataKey::EventLedger(EventLedgerKey::TokenBalances(event_id));
+    env.storage().persistent().set(&key, slices);
+    extend_persistent_ttl(env, &key);
+}
+
+fn zero_balance() -> EventBalance {
+    EventBalance {
+        organizer_amount: 0,
+        total_withdrawn: 0,
+        platform_fee: 0,
+    }
+}
+
+fn add_to_balance(balance: &mut EventBalance, organizer: i128, fee: i128, withdrawn: i128) {
+    balance.organizer_amount = balance.organizer_amount.checked_add(organizer).unwrap();
+    balance.platform_fee = balance.platform_fee.checked_add(fee).unwrap();
+    balance.total_withdrawn = balance.total_withdrawn.checked_add(withdrawn).unwrap();
+}
+
+fn add_to_fee_event_index(env: &Env, event_id: String) {
+    let member_key = DataKey::FeeEventIndex(FeeEventIndexKey::Member(event_id.clone()));
+    if env.storage().persistent().has(&member_key) {
+        return;
+    }
+
+    let count = get_fee_event_count(env);
+    let shard_key = DataKey::FeeEventIndex(FeeEventIndexKey::Shard(count / SHARD_SIZE));
+    let mut shard: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&shard_key)
+        .unwrap_or_else(|| vec![env]);
+    shard.push_back(event_id);
+    env.storage().persistent().set(&shard_key, &shard);
+
+    env.storage().persistent().set(
+        &DataKey::FeeEventIndex(FeeEventIndexKey::Count),
+        &(count + 1),
+    );
+    env.storage().persistent().set(&member_key, &true);
+}
+
+pub fn get_fee_event_count(env: &Env) -> u32 {
     env.storage()
         .persistent()
-        .set(&DataKey::Balances(event_id), &balance);
+        .get(&DataKey::FeeEventIndex(FeeEventIndexKey::Count))
+        .unwrap_or(0)
+}
+
+/// Returns the indexed event at `index`, or None if out of range.
+pub fn get_fee_event_at(env: &Env, index: u32) -> Option<String> {
+    let shard: Vec<String> =
+        env.storage()
+            .persistent()
+            .get(&DataKey::FeeEventIndex(FeeEventIndexKey::Shard(
+                index / SHARD_SIZE,
+            )))?;
+    shard.get(index % SHARD_SIZE)
+}
+
+pub fn set_event_balance(env: &Env, event_id: String, balance: EventBalance) {
+    let key = DataKey::Balances(event_id);
+    env.storage().persistent().set(&key, &balance);
+    extend_persistent_ttl(env, &key);
 }
 
 pub fn set_transfer_fee(env: &Env, event_id: String, fee: i128) {
@@ -237,6 +573,19 @@ pub fn get_transfer_fee(env: &Env, event_id: String) -> i128 {
         .unwrap_or(0)
 }
 
+pub fn set_tier_change_fee(env: &Env, event_id: String, fee: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Refund(RefundKey::ChangeFee(event_id)), &fee);
+}
+
+pub fn get_tier_change_fee(env: &Env, event_id: String) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Refund(RefundKey::ChangeFee(event_id)))
+        .unwrap_or(0)
+}
+
 pub fn add_payment_to_event_index(env: &Env, event_id: String, payment_id: String) {
     if env
         .storage()
@@ -368,28 +717,64 @@ pub fn get_bulk_refund_index(env: &Env, event_id: String) -> u32 {
 }
 
 pub fn set_partial_refund_index(env: &Env, event_id: String, index: u32) {
-    env.storage()
-        .persistent()
-        .set(&DataKey::PartialRefundIndex(event_id), &index);
+    env.storage().persistent().set(
+        &DataKey::PartialRefund(PartialRefundKey::Index(event_id)),
+        &index,
+    );
 }
 
 pub fn get_partial_refund_index(env: &Env, event_id: String) -> u32 {
     env.storage()
         .persistent()
-        .get(&DataKey::PartialRefundIndex(event_id))
+        .get(&DataKey::PartialRefund(PartialRefundKey::Index(event_id)))
         .unwrap_or(0)
 }
 
 pub fn set_partial_refund_percentage(env: &Env, event_id: String, percentage_bps: u32) {
+    env.storage().persistent().set(
+        &DataKey::PartialRefund(PartialRefundKey::Percentage(event_id)),
+        &percentage_bps,
+    );
+}
+
+pub fn get_partial_refund_percentage(env: &Env, event_id: String) -> u32 {
     env.storage()
         .persistent()
-        .set(&DataKey::PartialRefundPercentage(event_id), &percentage_bps);
+        .get(&DataKey::PartialRefund(PartialRefundKey::Percentage(
+            event_id,
+        )))
+        .unwrap_or(0)
 }
 
-pub fn get_partial_refund_percentage(env: &Env, event_id: String) -> u32 {
+pub fn set_partial_refund_void_threshold(env: &Env, threshold_bps: u32) {
+    env.storage().persistent().set(
+        &DataKey::PartialRefund(PartialRefundKey::VoidThreshold),
+        &threshold_bps,
+    );
+}
+
+/// Share of a ticket's price, in bps, that once refunded in part voids the
+/// ticket. Defaults to the full price.
+pub fn get_partial_refund_void_threshold(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PartialRefund(PartialRefundKey::VoidThreshold))
+        .unwrap_or(MAX_BPS)
+}
+
+/// Sets how long after an event's completion its revenue stays locked.
+pub fn set_revenue_challenge_window(env: &Env, window_seconds: u64) {
+    env.storage().persistent().set(
+        &DataKey::Settlement(SettlementKey::ChallengeWindow),
+        &window_seconds,
+    );
+}
+
+/// Seconds after completion before revenue can be claimed. Defaults to 0.
+pub fn get_revenue_challenge_window(env: &Env) -> u64 {
     env.storage()
         .persistent()
-        .get(&DataKey::PartialRefundPercentage(event_id))
+        .get(&DataKey::Settlement(SettlementKey::ChallengeWindow))
         .unwrap_or(0)
 }
 
@@ -428,11 +813,31 @@ pub fn get_total_fees_collected_by_token(env: &Env, token: Address) -> i128 {
 }
 
 pub fn add_to_total_fees_collected_by_token(env: &Env, token: Address, amount: i128) {
+    let mut tokens = get_treasury_tokens(env);
+    if !tokens.contains(&token) {
+        tokens.push_back(token.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Treasury(TreasuryKey::Tokens), &tokens);
+    }
     let current = get_total_fees_collected_by_token(env, token.clone());
     env.storage().persistent().set(
-        &DataKey::TotalFeesCollected(token),
+        &DataKey::TotalFeesCollected(token.clone()),
         &current.checked_add(amount).unwrap(),
     );
+    let lifetime = get_lifetime_fees_collected(env, token.clone());
+    env.storage().persistent().set(
+        &DataKey::Treasury(TreasuryKey::LifetimeFees(token)),
+        &lifetime.checked_add(amount).unwrap(),
+    );
+}
+
+/// Platform fees a token has ever collected, withdrawals included.
+pub fn get_lifetime_fees_collected(env: &Env, token: Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Treasury(TreasuryKey::LifetimeFees(token)))
+        .unwrap_or(0)
 }
 
 pub fn subtract_from_total_fees_collected_by_token(env: &Env, token: Address, amount: i128) {
@@ -446,31 +851,64 @@ pub fn subtract_from_total_fees_collected_by_token(env: &Env, token: Address, am
 pub fn set_withdrawal_cap(env: &Env, token: Address, amount: i128) {
     env.storage()
         .persistent()
-        .set(&DataKey::WithdrawalCap(token), &amount);
+        .set(&DataKey::Withdrawal(WithdrawalKey::Cap(token)), &amount);
 }
 
 pub fn get_withdrawal_cap(env: &Env, token: Address) -> i128 {
     env.storage()
         .persistent()
-        .get(&DataKey::WithdrawalCap(token))
+        .get(&DataKey::Withdrawal(WithdrawalKey::Cap(token)))
         .unwrap_or(0)
 }
 
 pub fn get_daily_withdrawn_amount(env: &Env, token: Address, day: u64) -> i128 {
     env.storage()
         .persistent()
-        .get(&DataKey::DailyWithdrawalAmount(token, day))
+        .get(&DataKey::Withdrawal(WithdrawalKey::Daily(token, day)))
         .unwrap_or(0)
 }
 
 pub fn add_to_daily_withdrawn_amount(env: &Env, token: Address, day: u64, amount: i128) {
     let current = get_daily_withdrawn_amount(env, token.clone(), day);
     env.storage().persistent().set(
-        &DataKey::DailyWithdrawalAmount(token, day),
+        &DataKey::Withdrawal(WithdrawalKey::Daily(token, day)),
         &current.checked_add(amount).unwrap(),
     );
 }
 
+pub fn get_payout_config(env: &Env, organizer: &Address) -> Option<PayoutConfig> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Withdrawal(WithdrawalKey::PayoutConfig(
+            organizer.clone(),
+        )))
+}
+
+pub fn set_payout_config(env: &Env, organizer: &Address, config: Option<PayoutConfig>) {
+    let key = DataKey::Withdrawal(WithdrawalKey::PayoutConfig(organizer.clone()));
+    match config {
+        Some(config) => env.storage().persistent().set(&key, &config),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn is_threshold_notified(env: &Env, event_id: String) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Withdrawal(WithdrawalKey::ThresholdNotified(
+            event_id,
+        )))
+}
+
+pub fn set_threshold_notified(env: &Env, event_id: String, notified: bool) {
+    let key = DataKey::Withdrawal(WithdrawalKey::ThresholdNotified(event_id));
+    if notified {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
 pub fn get_active_escrow_total(env: &Env) -> i128 {
     env.storage()
         .persistent()
@@ -501,84 +939,1521 @@ pub fn get_active_escrow_by_token(env: &Env, token: Address) -> i128 {
 
 pub fn add_to_active_escrow_by_token(env: &Env, token: Address, amount: i128) {
     let current = get_active_escrow_by_token(env, token.clone());
-    env.storage().persistent().set(
-        &DataKey::ActiveEscrowByToken(token),
-        &current.checked_add(amount).unwrap(),
-    );
+    let key = DataKey::ActiveEscrowByToken(token);
+    env.storage()
+        .persistent()
+        .set(&key, &current.checked_add(amount).unwrap());
+    extend_persistent_ttl(env, &key);
 }
 
 pub fn subtract_from_active_escrow_by_token(env: &Env, token: Address, amount: i128) {
     let current = get_active_escrow_by_token(env, token.clone());
-    env.storage().persistent().set(
-        &DataKey::ActiveEscrowByToken(token),
-        &current.checked_sub(amount).unwrap(),
-    );
+    let key = DataKey::ActiveEscrowByToken(token);
+    env.storage()
+        .persistent()
+        .set(&key, &current.checked_sub(amount).unwrap());
+    extend_persistent_ttl(env, &key);
+}
+
+/// User funds a token holds outside event escrow: damage deposits, unspent
+/// voucher balances and open dispute bonds.
+pub fn get_held_funds(env: &Env, token: Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Treasury(TreasuryKey::HeldFunds(token)))
+        .unwrap_or(0)
+}
+
+pub fn add_held_funds(env: &Env, token: Address, delta: i128) {
+    let key = DataKey::Treasury(TreasuryKey::HeldFunds(token.clone()));
+    let held = get_held_funds(env, token).checked_add(delta).unwrap();
+    env.storage().persistent().set(&key, &held);
+    extend_persistent_ttl(env, &key);
 }
 
 // ── Discount code registry ────────────────────────────────────────────────────
 
-/// Register a SHA-256 hash as a valid (unused) discount code.
-pub fn add_discount_hash(env: &Env, hash: soroban_sdk::BytesN<32>) {
+pub fn get_discount_code(env: &Env, hash: soroban_sdk::BytesN<32>) -> Option<DiscountCode> {
     env.storage()
         .persistent()
-        .set(&DataKey::DiscountCodeHash(hash), &true);
+        .get(&DataKey::DiscountCodeHash(hash))
 }
 
-/// Returns `true` if the hash has been registered as a discount code.
-pub fn is_discount_hash_valid(env: &Env, hash: &soroban_sdk::BytesN<32>) -> bool {
+pub fn set_discount_code(env: &Env, code: &DiscountCode) {
     env.storage()
         .persistent()
-        .get(&DataKey::DiscountCodeHash(hash.clone()))
-        .unwrap_or(false)
+        .set(&DataKey::DiscountCodeHash(code.spec.hash.clone()), code);
+}
+
+pub fn get_discount_buyer_uses(env: &Env, hash: soroban_sdk::BytesN<32>, buyer: Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Coupon(CouponKey::DiscountUses(hash, buyer)))
+        .unwrap_or(0)
+}
+
+/// Counts one more purchase by `buyer` with the discount code `hash`.
+pub fn increment_discount_buyer_uses(env: &Env, hash: soroban_sdk::BytesN<32>, buyer: Address) {
+    let uses = get_discount_buyer_uses(env, hash.clone(), buyer.clone());
+    env.storage().persistent().set(
+        &DataKey::Coupon(CouponKey::DiscountUses(hash, buyer)),
+        &(uses + 1),
+    );
 }
 
-/// Returns `true` if the hash has already been redeemed.
-pub fn is_discount_hash_used(env: &Env, hash: &soroban_sdk::BytesN<32>) -> bool {
+pub fn is_event_disputed(env: &Env, event_id: String) -> bool {
     env.storage()
         .persistent()
-        .get(&DataKey::DiscountCodeUsed(hash.clone()))
+        .get(&DataKey::Dispute(DisputeKey::Event(event_id)))
         .unwrap_or(false)
 }
 
-/// Mark a discount code hash as spent so it cannot be reused.
-pub fn mark_discount_hash_used(env: &Env, hash: soroban_sdk::BytesN<32>) {
+pub fn set_event_dispute_status(env: &Env, event_id: String, disputed: bool) {
     env.storage()
         .persistent()
-        .set(&DataKey::DiscountCodeUsed(hash), &true);
+        .set(&DataKey::Dispute(DisputeKey::Event(event_id)), &disputed);
 }
 
-pub fn is_event_disputed(env: &Env, event_id: String) -> bool {
+pub fn get_payment_dispute(env: &Env, payment_id: String) -> Option<PaymentDispute> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Dispute(DisputeKey::Payment(payment_id)))
+}
+
+pub fn set_payment_dispute(env: &Env, dispute: &PaymentDispute) {
+    env.storage().persistent().set(
+        &DataKey::Dispute(DisputeKey::Payment(dispute.payment_id.clone())),
+        dispute,
+    );
+}
+
+/// Whether a payment has a dispute awaiting arbitration.
+pub fn is_payment_disputed(env: &Env, payment_id: String) -> bool {
+    get_payment_dispute(env, payment_id).is_some_and(|dispute| !dispute.resolved)
+}
+
+/// Organizer share of an event held back by its open payment disputes.
+pub fn get_frozen_dispute_amount(env: &Env, event_id: String) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Dispute(DisputeKey::Frozen(event_id)))
+        .unwrap_or(0)
+}
+
+pub fn add_frozen_dispute_amount(env: &Env, event_id: String, delta: i128) {
+    let frozen = get_frozen_dispute_amount(env, event_id.clone()) + delta;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Dispute(DisputeKey::Frozen(event_id)), &frozen);
+}
+
+pub fn is_dispute_arbiter(env: &Env, arbiter: Address) -> bool {
     env.storage()
         .persistent()
-        .get(&DataKey::DisputeStatus(event_id))
+        .get(&DataKey::Dispute(DisputeKey::Arbiter(arbiter)))
         .unwrap_or(false)
 }
 
-pub fn set_event_dispute_status(env: &Env, event_id: String, disputed: bool) {
+pub fn set_dispute_arbiter(env: &Env, arbiter: Address, enabled: bool) {
+    let key = DataKey::Dispute(DisputeKey::Arbiter(arbiter));
+    if enabled {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Bond an opener deposits with a dispute. Defaults to 0.
+pub fn get_dispute_bond(env: &Env) -> i128 {
     env.storage()
         .persistent()
-        .set(&DataKey::DisputeStatus(event_id), &disputed);
+        .get(&DataKey::Dispute(DisputeKey::Bond))
+        .unwrap_or(0)
 }
 
-// ── Oracle configuration ──────────────────────────────────────────────────────
+pub fn set_dispute_bond(env: &Env, amount: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Dispute(DisputeKey::Bond), &amount);
+}
 
-pub fn set_oracle_address(env: &Env, address: &Address) {
+pub fn get_event_paused_by(env: &Env, event_id: String) -> Option<Address> {
     env.storage()
         .persistent()
-        .set(&DataKey::OracleAddress, address);
+        .get(&DataKey::Dispute(DisputeKey::Paused(event_id)))
 }
 
-pub fn get_oracle_address(env: &Env) -> Option<Address> {
-    env.storage().persistent().get(&DataKey::OracleAddress)
+pub fn set_event_paused_by(env: &Env, event_id: String, paused_by: Option<Address>) {
+    let key = DataKey::Dispute(DisputeKey::Paused(event_id));
+    match paused_by {
+        Some(address) => env.storage().persistent().set(&key, &address),
+        None => env.storage().persistent().remove(&key),
+    }
 }
 
-pub fn set_slippage_bps(env: &Env, bps: u32) {
-    env.storage().persistent().set(&DataKey::SlippageBps, &bps);
+pub fn get_refund_spike_config(env: &Env) -> RefundSpikeConfig {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Dispute(DisputeKey::SpikeConfig))
+        .unwrap_or(RefundSpikeConfig {
+            threshold_bps: 0,
+            window: 0,
+        })
 }
 
-pub fn get_slippage_bps(env: &Env) -> u32 {
+pub fn set_refund_spike_config(env: &Env, config: &RefundSpikeConfig) {
     env.storage()
         .persistent()
-        .get(&DataKey::SlippageBps)
-        .unwrap_or(200)
+        .set(&DataKey::Dispute(DisputeKey::SpikeConfig), config);
+}
+
+pub fn get_refund_window(env: &Env, event_id: String) -> Option<RefundWindow> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Dispute(DisputeKey::RefundWindow(event_id)))
+}
+
+pub fn set_refund_window(env: &Env, event_id: String, window: &RefundWindow) {
+    env.storage().persistent().set(
+        &DataKey::Dispute(DisputeKey::RefundWindow(event_id)),
+        window,
+    );
+}
+
+// ── Oracle configuration ──────────────────────────────────────────────────────
+
+pub fn set_oracle_address(env: &Env, address: &Address) {
+    env.storage().persistent().set(
+        &DataKey::Integration(IntegrationKey::OracleContract),
+        address,
+    );
+}
+
+pub fn get_oracle_address(env: &Env) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Integration(IntegrationKey::OracleContract))
+}
+
+pub fn set_rewards_contract(env: &Env, address: Option<Address>) {
+    match address {
+        Some(address) => env.storage().persistent().set(
+            &DataKey::Integration(IntegrationKey::RewardsContract),
+            &address,
+        ),
+        None => env
+            .storage()
+            .persistent()
+            .remove(&DataKey::Integration(IntegrationKey::RewardsContract)),
+    }
+}
+
+pub fn get_rewards_contract(env: &Env) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Integration(IntegrationKey::RewardsContract))
+}
+
+pub fn set_yield_adapter(env: &Env, address: Option<Address>) {
+    let key = DataKey::Integration(IntegrationKey::YieldAdapter);
+    match address {
+        Some(address) => env.storage().persistent().set(&key, &address),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_yield_adapter(env: &Env) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Integration(IntegrationKey::YieldAdapter))
+}
+
+/// Share of active escrow kept liquid, in bps; all of it unless set.
+pub fn get_yield_reserve_bps(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Integration(IntegrationKey::YieldReserveBps))
+        .unwrap_or(MAX_BPS)
+}
+
+pub fn set_yield_reserve_bps(env: &Env, bps: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Integration(IntegrationKey::YieldReserveBps), &bps);
+}
+
+pub fn get_yield_deployed(env: &Env, token: Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Integration(IntegrationKey::YieldDeployed(token)))
+        .unwrap_or(0)
+}
+
+pub fn set_yield_deployed(env: &Env, token: Address, amount: i128) {
+    let previous = get_yield_deployed(env, token.clone());
+    env.storage().persistent().set(
+        &DataKey::Integration(IntegrationKey::YieldDeployed(token)),
+        &amount,
+    );
+    let tokens = get_yield_deployed_token_count(env);
+    let tokens = match (previous != 0, amount != 0) {
+        (false, true) => tokens + 1,
+        (true, false) => tokens - 1,
+        _ => return,
+    };
+    env.storage().persistent().set(
+        &DataKey::Integration(IntegrationKey::YieldDeployedTokens),
+        &tokens,
+    );
+}
+
+/// Number of tokens with funds currently deposited in the yield strategy.
+pub fn get_yield_deployed_token_count(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Integration(IntegrationKey::YieldDeployedTokens))
+        .unwrap_or(0)
+}
+
+pub fn set_ticket_nft_contract(env: &Env, address: Option<Address>) {
+    let key = DataKey::Integration(IntegrationKey::TicketNftContract);
+    match address {
+        Some(address) => env.storage().persistent().set(&key, &address),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_ticket_nft_contract(env: &Env) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Integration(IntegrationKey::TicketNftContract))
+}
+
+pub fn set_attendance_minter(env: &Env, event_id: String, minter: Option<Address>) {
+    let key = DataKey::Integration(IntegrationKey::AttendanceMinter(event_id));
+    match minter {
+        Some(minter) => env.storage().persistent().set(&key, &minter),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_attendance_minter(env: &Env, event_id: String) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Integration(IntegrationKey::AttendanceMinter(
+            event_id,
+        )))
+}
+
+pub fn set_ticket_token_id(env: &Env, payment_id: String, token_id: Option<u64>) {
+    let key = DataKey::Integration(IntegrationKey::TicketNftToken(payment_id));
+    match token_id {
+        Some(token_id) => env.storage().persistent().set(&key, &token_id),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_ticket_token_id(env: &Env, payment_id: String) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Integration(IntegrationKey::TicketNftToken(
+            payment_id,
+        )))
+}
+
+pub fn set_slippage_bps(env: &Env, bps: u32) {
+    env.storage().persistent().set(
+        &DataKey::Integration(IntegrationKey::OracleSlippageBps),
+        &bps,
+    );
+}
+
+pub fn get_slippage_bps(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Integration(IntegrationKey::OracleSlippageBps))
+        .unwrap_or(200)
+}
+
+// ── Refund processing fee ─────────────────────────────────────────────────────
+
+pub fn set_refund_processing_fee(env: &Env, fee: &RefundProcessingFee) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Refund(RefundKey::ProcessingFee), fee);
+}
+
+pub fn get_refund_processing_fee(env: &Env) -> RefundProcessingFee {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Refund(RefundKey::ProcessingFee))
+        .unwrap_or(RefundProcessingFee {
+            bps: 0,
+            fixed_amount: 0,
+        })
+}
+
+pub fn get_refund_fees_collected(env: &Env, token: Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Refund(RefundKey::FeesCollected(token)))
+        .unwrap_or(0)
+}
+
+pub fn add_to_refund_fees_collected(env: &Env, token: Address, amount: i128) {
+    let current = get_refund_fees_collected(env, token.clone());
+    env.storage().persistent().set(
+        &DataKey::Refund(RefundKey::FeesCollected(token)),
+        &current.checked_add(amount).unwrap(),
+    );
+}
+
+// ── Promo effectiveness ───────────────────────────────────────────────────────
+
+pub fn get_promo_stats(env: &Env, scope: PromoScope) -> PromoStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PromoStats(scope))
+        .unwrap_or(PromoStats {
+            payment_count: 0,
+            gross_discount: 0,
+            net_revenue: 0,
+        })
+}
+
+/// Record one payment that used the given promo.
+pub fn record_promo_usage(env: &Env, scope: PromoScope, discount: i128, net_revenue: i128) {
+    let mut stats = get_promo_stats(env, scope.clone());
+    stats.payment_count += 1;
+    stats.gross_discount = stats.gross_discount.checked_add(discount).unwrap();
+    stats.net_revenue = stats.net_revenue.checked_add(net_revenue).unwrap();
+    env.storage()
+        .persistent()
+        .set(&DataKey::PromoStats(scope), &stats);
+}
+
+// ── Flash sales ───────────────────────────────────────────────────────────────
+
+pub fn get_flash_sale_state(
+    env: &Env,
+    event_id: String,
+    tier_id: String,
+) -> Option<FlashSaleState> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FlashSaleState(event_id, tier_id))
+}
+
+pub fn set_flash_sale_state(env: &Env, event_id: String, tier_id: String, state: FlashSaleState) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::FlashSaleState(event_id, tier_id), &state);
+}
+
+// ── Event escrow ledger ───────────────────────────────────────────────────────
+
+pub fn append_ledger_entry(
+    env: &Env,
+    event_id: String,
+    entry_type: LedgerEntryType,
+    amount: i128,
+    token: Address,
+    counterparty: Address,
+) {
+    let count = get_event_ledger_count(env, event_id.clone());
+    let shard_key =
+        DataKey::EventLedger(EventLedgerKey::Shard(event_id.clone(), count / SHARD_SIZE));
+    let mut shard: Vec<LedgerEntry> = env
+        .storage()
+        .persistent()
+        .get(&shard_key)
+        .unwrap_or_else(|| vec![env]);
+    shard.push_back(LedgerEntry {
+        entry_type,
+        amount,
+        token,
+        counterparty,
+        timestamp: env.ledger().timestamp(),
+    });
+    env.storage().persistent().set(&shard_key, &shard);
+    env.storage().persistent().set(
+        &DataKey::EventLedger(EventLedgerKey::Count(event_id)),
+        &(count + 1),
+    );
+}
+
+pub fn get_event_ledger_count(env: &Env, event_id: String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EventLedger(EventLedgerKey::Count(event_id)))
+        .unwrap_or(0)
+}
+
+/// Returns the cursor's window of an event's escrow ledger.
+pub fn get_event_ledger(env: &Env, event_id: String, cursor: PageCursor) -> Vec<LedgerEntry> {
+    let total = get_event_ledger_count(env, event_id.clone());
+    read_sharded(env, total, SHARD_SIZE, cursor, |shard_id| {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EventLedger(EventLedgerKey::Shard(
+                event_id.clone(),
+                shard_id,
+            )))
+    })
+}
+
+// ── Registry tenants ──────────────────────────────────────────────────────────
+
+/// The default registry is always allowed; others must be added by the admin.
+pub fn is_registry_allowed(env: &Env, registry: &Address) -> bool {
+    if *registry == get_event_registry(env) {
+        return true;
+    }
+    env.storage()
+        .persistent()
+        .get(&DataKey::Tenant(TenantKey::Allowed(registry.clone())))
+        .unwrap_or(false)
+}
+
+pub fn set_registry_allowed(env: &Env, registry: Address, allowed: bool) {
+    let key = DataKey::Tenant(TenantKey::Allowed(registry));
+    if allowed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn get_bound_event_registry(env: &Env, event_id: String) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Tenant(TenantKey::EventRegistry(event_id)))
+}
+
+pub fn bind_event_registry(env: &Env, event_id: String, registry: &Address) {
+    env.storage().persistent().set(
+        &DataKey::Tenant(TenantKey::EventRegistry(event_id)),
+        registry,
+    );
+}
+
+pub fn get_tenant_namespace(env: &Env, registry: &Address) -> Option<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Tenant(TenantKey::NamespaceOf(registry.clone())))
+}
+
+pub fn get_namespace_registry(env: &Env, namespace: String) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Tenant(TenantKey::Namespace(namespace)))
+}
+
+pub fn reserve_tenant_namespace(env: &Env, registry: &Address, namespace: String) {
+    env.storage().persistent().set(
+        &DataKey::Tenant(TenantKey::Namespace(namespace.clone())),
+        registry,
+    );
+    env.storage().persistent().set(
+        &DataKey::Tenant(TenantKey::NamespaceOf(registry.clone())),
+        &namespace,
+    );
+}
+
+/// Returns the registry an event is bound to, falling back to the default registry.
+pub fn get_event_registry_for(env: &Env, event_id: String) -> Address {
+    get_bound_event_registry(env, event_id).unwrap_or_else(|| get_event_registry(env))
+}
+
+pub fn get_tenant_fees_collected(env: &Env, registry: Address, token: Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Tenant(TenantKey::FeesCollected(registry, token)))
+        .unwrap_or(0)
+}
+
+pub fn add_to_tenant_fees_collected(env: &Env, registry: Address, token: Address, amount: i128) {
+    let current = get_tenant_fees_collected(env, registry.clone(), token.clone());
+    env.storage().persistent().set(
+        &DataKey::Tenant(TenantKey::FeesCollected(registry, token)),
+        &current.checked_add(amount).unwrap(),
+    );
+}
+
+// ── Box office allotments ─────────────────────────────────────────────────────
+
+pub fn get_box_office_allotment(
+    env: &Env,
+    event_id: String,
+    tier_id: String,
+    custodian: Address,
+) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BoxOffice(event_id, tier_id, custodian))
+        .unwrap_or(0)
+}
+
+pub fn set_box_office_allotment(
+    env: &Env,
+    event_id: String,
+    tier_id: String,
+    custodian: Address,
+    remaining: u32,
+) {
+    let previous =
+        get_box_office_allotment(env, event_id.clone(), tier_id.clone(), custodian.clone());
+    let reserved =
+        get_box_office_reserved(env, event_id.clone(), tier_id.clone()) + remaining - previous;
+    let reserved_key = DataKey::BoxOfficeReserved(event_id.clone(), tier_id.clone());
+    if reserved == 0 {
+        env.storage().persistent().remove(&reserved_key);
+    } else {
+        env.storage().persistent().set(&reserved_key, &reserved);
+    }
+
+    let key = DataKey::BoxOffice(event_id, tier_id, custodian);
+    if remaining == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &remaining);
+    }
+}
+
+/// Unassigned box-office tickets for a tier, summed over all custodians.
+pub fn get_box_office_reserved(env: &Env, event_id: String, tier_id: String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BoxOfficeReserved(event_id, tier_id))
+        .unwrap_or(0)
+}
+
+// ── Damage deposits ───────────────────────────────────────────────────────────
+
+pub fn get_ticket_deposit(env: &Env, payment_id: String) -> Option<TicketDeposit> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Deposit(DepositKey::Ticket(payment_id)))
+}
+
+pub fn set_ticket_deposit(env: &Env, payment_id: String, deposit: &TicketDeposit) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Deposit(DepositKey::Ticket(payment_id)), deposit);
+}
+
+pub fn get_deposit_window_start(env: &Env, event_id: String) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Deposit(DepositKey::WindowStart(event_id)))
+}
+
+pub fn set_deposit_window_start(env: &Env, event_id: String, timestamp: u64) {
+    env.storage().persistent().set(
+        &DataKey::Deposit(DepositKey::WindowStart(event_id)),
+        &timestamp,
+    );
+}
+
+// ── Organizer escrow recovery ─────────────────────────────────────────────────
+
+pub fn get_organizer_activity(env: &Env, event_id: String) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Recovery(RecoveryKey::LastActivity(event_id)))
+        .unwrap_or(0)
+}
+
+/// Records organizer activity for an event. Any activity voids a pending
+/// recovery claim, since the organizer has evidently not lost access.
+pub fn record_organizer_activity(env: &Env, event_id: String) {
+    env.storage().persistent().set(
+        &DataKey::Recovery(RecoveryKey::LastActivity(event_id.clone())),
+        &env.ledger().timestamp(),
+    );
+    remove_recovery_claim(env, event_id);
+}
+
+pub fn get_recovery_claim(env: &Env, event_id: String) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Recovery(RecoveryKey::Claim(event_id)))
+}
+
+pub fn set_recovery_claim(env: &Env, event_id: String, initiated_at: u64) {
+    env.storage().persistent().set(
+        &DataKey::Recovery(RecoveryKey::Claim(event_id)),
+        &initiated_at,
+    );
+}
+
+pub fn remove_recovery_claim(env: &Env, event_id: String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Recovery(RecoveryKey::Claim(event_id)));
+}
+
+pub fn get_token_rescue(env: &Env, token: Address) -> Option<TokenRescue> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Recovery(RecoveryKey::Rescue(token)))
+}
+
+pub fn set_token_rescue(env: &Env, rescue: &TokenRescue) {
+    env.storage().persistent().set(
+        &DataKey::Recovery(RecoveryKey::Rescue(rescue.token.clone())),
+        rescue,
+    );
+}
+
+pub fn remove_token_rescue(env: &Env, token: Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Recovery(RecoveryKey::Rescue(token)));
+}
+
+// ── Fee settlement history ────────────────────────────────────────────────────
+
+/// Number of platform fee settlements recorded so far for an event; the next
+/// settlement uses this value as its period.
+pub fn get_event_settlement_periods(env: &Env, event_id: String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Settlement(SettlementKey::EventPeriods(event_id)))
+        .unwrap_or(0)
+}
+
+pub fn get_settlement_count(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Settlement(SettlementKey::Count))
+        .unwrap_or(0)
+}
+
+pub fn append_settlement_record(env: &Env, record: SettlementRecord) {
+    let count = get_settlement_count(env);
+    let shard_key = DataKey::Settlement(SettlementKey::Shard(count / SHARD_SIZE));
+    let mut shard: Vec<SettlementRecord> = env
+        .storage()
+        .persistent()
+        .get(&shard_key)
+        .unwrap_or_else(|| vec![env]);
+    let event_id = record.event_id.clone();
+    let period = record.period;
+    shard.push_back(record);
+    env.storage().persistent().set(&shard_key, &shard);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Settlement(SettlementKey::Count), &(count + 1));
+    env.storage().persistent().set(
+        &DataKey::Settlement(SettlementKey::EventPeriods(event_id)),
+        &(period + 1),
+    );
+}
+
+/// Returns up to `limit` settlement records (capped at one shard) starting at `start`.
+pub fn get_settlement_history(env: &Env, cursor: PageCursor) -> Vec<SettlementRecord> {
+    read_sharded(
+        env,
+        get_settlement_count(env),
+        SHARD_SIZE,
+        cursor,
+        |shard_id| {
+            env.storage()
+                .persistent()
+                .get(&DataKey::Settlement(SettlementKey::Shard(shard_id)))
+        },
+    )
+}
+
+// ── Purchase receipts ─────────────────────────────────────────────────────────
+
+pub fn set_purchase_terms(env: &Env, payment_id: String, terms: &PurchaseTerms) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Receipt(ReceiptKey::Terms(payment_id)), terms);
+}
+
+pub fn get_purchase_terms(env: &Env, payment_id: String) -> Option<PurchaseTerms> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Receipt(ReceiptKey::Terms(payment_id)))
+}
+
+pub fn set_receipt(env: &Env, receipt: &Receipt) {
+    env.storage().persistent().set(
+        &DataKey::Receipt(ReceiptKey::Issued(receipt.payment_id.clone())),
+        receipt,
+    );
+}
+
+pub fn get_receipt(env: &Env, payment_id: String) -> Option<Receipt> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Receipt(ReceiptKey::Issued(payment_id)))
+}
+
+pub fn set_bundle_purchase(env: &Env, purchase: &BundlePurchase) {
+    env.storage().persistent().set(
+        &DataKey::Receipt(ReceiptKey::Bundle(purchase.purchase_id.clone())),
+        purchase,
+    );
+}
+
+pub fn get_bundle_purchase(env: &Env, purchase_id: String) -> Option<BundlePurchase> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Receipt(ReceiptKey::Bundle(purchase_id)))
+}
+
+// ── Failed purchase records ───────────────────────────────────────────────────
+
+pub fn get_failed_purchases(env: &Env) -> Vec<FailedPurchase> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FailedPurchases)
+        .unwrap_or_else(|| vec![env])
+}
+
+/// Appends a failed purchase, evicting the oldest once `MAX_FAILED_PURCHASES` are held.
+pub fn record_failed_purchase(env: &Env, record: FailedPurchase) {
+    let mut records = get_failed_purchases(env);
+    while records.len() >= MAX_FAILED_PURCHASES {
+        records.pop_front();
+    }
+    records.push_back(record);
+    env.storage()
+        .persistent()
+        .set(&DataKey::FailedPurchases, &records);
+}
+
+// ── Referral program ──────────────────────────────────────────────────────────
+
+/// Share of the platform fee paid to referrers, 20% unless set by the admin.
+pub fn get_referral_bps(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Referral(ReferralKey::ShareBps))
+        .unwrap_or(2000)
+}
+
+pub fn set_referral_bps(env: &Env, bps: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Referral(ReferralKey::ShareBps), &bps);
+}
+
+pub fn is_referral_opted_out(env: &Env, event_id: String) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Referral(ReferralKey::OptedOut(event_id)))
+        .unwrap_or(false)
+}
+
+pub fn set_referral_opted_out(env: &Env, event_id: String, opted_out: bool) {
+    env.storage().persistent().set(
+        &DataKey::Referral(ReferralKey::OptedOut(event_id)),
+        &opted_out,
+    );
+}
+
+pub fn get_referral_rewards(env: &Env, referrer: Address, token: Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Referral(ReferralKey::Accrued(referrer, token)))
+        .unwrap_or(0)
+}
+
+pub fn set_referral_rewards(env: &Env, referrer: Address, token: Address, amount: i128) {
+    env.storage().persistent().set(
+        &DataKey::Referral(ReferralKey::Accrued(referrer, token)),
+        &amount,
+    );
+}
+
+pub fn get_promoter_share(env: &Env, event_id: String, promoter: Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Referral(ReferralKey::Promoter(
+            event_id, promoter,
+        )))
+        .unwrap_or(0)
+}
+
+pub fn set_promoter_share(env: &Env, event_id: String, promoter: Address, share_bps: u32) {
+    env.storage().persistent().set(
+        &DataKey::Referral(ReferralKey::Promoter(event_id, promoter)),
+        &share_bps,
+    );
+}
+
+pub fn get_promoter_earnings(env: &Env, promoter: Address, token: Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Referral(ReferralKey::PromoterEarnings(
+            promoter, token,
+        )))
+        .unwrap_or(0)
+}
+
+pub fn set_promoter_earnings(env: &Env, promoter: Address, token: Address, amount: i128) {
+    env.storage().persistent().set(
+        &DataKey::Referral(ReferralKey::PromoterEarnings(promoter, token)),
+        &amount,
+    );
+}
+
+pub fn set_ticket_commissions(env: &Env, payment_id: String, commissions: &TicketCommissions) {
+    env.storage().persistent().set(
+        &DataKey::Referral(ReferralKey::TicketCommissions(payment_id)),
+        commissions,
+    );
+}
+
+/// Removes and returns the commissions recorded for a ticket's sale.
+pub fn take_ticket_commissions(env: &Env, payment_id: String) -> Option<TicketCommissions> {
+    let key = DataKey::Referral(ReferralKey::TicketCommissions(payment_id));
+    let commissions = env.storage().persistent().get(&key);
+    if commissions.is_some() {
+        env.storage().persistent().remove(&key);
+    }
+    commissions
+}
+
+// ── Loyalty points ────────────────────────────────────────────────────────────
+
+pub fn get_loyalty_earn_bps(env: &Env, token: Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Coupon(CouponKey::LoyaltyEarnBps(token)))
+        .unwrap_or(0)
+}
+
+pub fn set_loyalty_earn_bps(env: &Env, token: Address, bps: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Coupon(CouponKey::LoyaltyEarnBps(token)), &bps);
+}
+
+/// Token units a point is worth, in bps; one unit per point unless set.
+pub fn get_loyalty_point_value(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Coupon(CouponKey::LoyaltyPointValue))
+        .unwrap_or(MAX_BPS)
+}
+
+pub fn set_loyalty_point_value(env: &Env, value_bps: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Coupon(CouponKey::LoyaltyPointValue), &value_bps);
+}
+
+pub fn get_loyalty_points(env: &Env, holder: Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Coupon(CouponKey::LoyaltyPoints(holder)))
+        .unwrap_or(0)
+}
+
+pub fn set_loyalty_points(env: &Env, holder: Address, points: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Coupon(CouponKey::LoyaltyPoints(holder)), &points);
+}
+
+// ── Gift vouchers ─────────────────────────────────────────────────────────────
+
+pub fn get_voucher(env: &Env, code_hash: soroban_sdk::BytesN<32>) -> Option<Voucher> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Coupon(CouponKey::Voucher(code_hash)))
+}
+
+pub fn set_voucher(env: &Env, voucher: &Voucher) {
+    env.storage().persistent().set(
+        &DataKey::Coupon(CouponKey::Voucher(voucher.code_hash.clone())),
+        voucher,
+    );
+}
+
+// ── Attendance coupons ────────────────────────────────────────────────────────
+
+pub fn get_coupon(env: &Env, event_id: String, holder: Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Coupon(CouponKey::Grant(event_id, holder)))
+        .unwrap_or(0)
+}
+
+pub fn set_coupon(env: &Env, event_id: String, holder: Address, discount_bps: u32) {
+    env.storage().persistent().set(
+        &DataKey::Coupon(CouponKey::Grant(event_id, holder)),
+        &discount_bps,
+    );
+}
+
+pub fn remove_coupon(env: &Env, event_id: String, holder: Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Coupon(CouponKey::Grant(event_id, holder)));
+}
+
+pub fn get_coupon_cursor(env: &Env, source_event_id: String, target_event_id: String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Coupon(CouponKey::Cursor(
+            source_event_id,
+            target_event_id,
+        )))
+        .unwrap_or(0)
+}
+
+pub fn set_coupon_cursor(env: &Env, source_event_id: String, target_event_id: String, index: u32) {
+    env.storage().persistent().set(
+        &DataKey::Coupon(CouponKey::Cursor(source_event_id, target_event_id)),
+        &index,
+    );
+}
+
+/// Appends a check-in to the event's attendance index and, on the holder's
+/// first check-in for the event, to the holder's attended events.
+pub fn record_attendance(env: &Env, event_id: String, record: AttendanceRecord) {
+    let count = get_attendance_count(env, event_id.clone());
+    let shard_key = DataKey::Coupon(CouponKey::AttendanceShard(
+        event_id.clone(),
+        count / SHARD_SIZE,
+    ));
+    let mut shard: Vec<AttendanceRecord> = env
+        .storage()
+        .persistent()
+        .get(&shard_key)
+        .unwrap_or_else(|| vec![env]);
+    let attendee = record.attendee.clone();
+    shard.push_back(record);
+    env.storage().persistent().set(&shard_key, &shard);
+    env.storage().persistent().set(
+        &DataKey::Coupon(CouponKey::AttendanceCount(event_id.clone())),
+        &(count + 1),
+    );
+
+    let mut attended = get_attended_events(env, attendee.clone());
+    if !attended.contains(&event_id) {
+        attended.push_back(event_id);
+        env.storage().persistent().set(
+            &DataKey::Coupon(CouponKey::AttendedEvents(attendee)),
+            &attended,
+        );
+    }
+}
+
+pub fn get_checkin_stats(env: &Env, event_id: String) -> CheckInStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Coupon(CouponKey::CheckInStats(event_id)))
+        .unwrap_or_else(|| CheckInStats {
+            checked_in: 0,
+            reentries: 0,
+            by_tier: Map::new(env),
+            by_scanner: Map::new(env),
+        })
+}
+
+/// Counts an admitted scan of a ticket of `tier_id` by `scanner`.
+pub fn record_checkin_stats(
+    env: &Env,
+    event_id: String,
+    tier_id: String,
+    scanner: Address,
+    reentry: bool,
+) {
+    let mut stats = get_checkin_stats(env, event_id.clone());
+    if reentry {
+        stats.reentries += 1;
+    } else {
+        stats.checked_in += 1;
+        stats
+            .by_tier
+            .set(tier_id.clone(), stats.by_tier.get(tier_id).unwrap_or(0) + 1);
+    }
+    stats.by_scanner.set(
+        scanner.clone(),
+        stats.by_scanner.get(scanner).unwrap_or(0) + 1,
+    );
+    env.storage()
+        .persistent()
+        .set(&DataKey::Coupon(CouponKey::CheckInStats(event_id)), &stats);
+}
+
+pub fn get_attendance_count(env: &Env, event_id: String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Coupon(CouponKey::AttendanceCount(event_id)))
+        .unwrap_or(0)
+}
+
+/// Returns the cursor's window of an event's attendance index.
+pub fn get_attendance(env: &Env, event_id: String, cursor: PageCursor) -> Vec<AttendanceRecord> {
+    let total = get_attendance_count(env, event_id.clone());
+    read_sharded(env, total, SHARD_SIZE, cursor, |shard_id| {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Coupon(CouponKey::AttendanceShard(
+                event_id.clone(),
+                shard_id,
+            )))
+    })
+}
+
+pub fn get_attended_events(env: &Env, holder: Address) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Coupon(CouponKey::AttendedEvents(holder)))
+        .unwrap_or_else(|| vec![env])
+}
+
+pub fn is_reentry_allowed(env: &Env, event_id: String) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Coupon(CouponKey::ReentryAllowed(event_id)))
+        .unwrap_or(false)
+}
+
+pub fn set_reentry_allowed(env: &Env, event_id: String, allowed: bool) {
+    env.storage().persistent().set(
+        &DataKey::Coupon(CouponKey::ReentryAllowed(event_id)),
+        &allowed,
+    );
+}
+
+pub fn is_checked_out(env: &Env, payment_id: String) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Coupon(CouponKey::CheckedOut(payment_id)))
+}
+
+pub fn set_checked_out(env: &Env, payment_id: String, checked_out: bool) {
+    let key = DataKey::Coupon(CouponKey::CheckedOut(payment_id));
+    if checked_out {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn get_ticket_delegate(env: &Env, payment_id: String) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Coupon(CouponKey::Delegate(payment_id)))
+}
+
+pub fn set_ticket_delegate(env: &Env, payment_id: String, attendee: Option<Address>) {
+    let key = DataKey::Coupon(CouponKey::Delegate(payment_id));
+    match attendee {
+        Some(attendee) => env.storage().persistent().set(&key, &attendee),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_checkin_commitment(env: &Env, payment_id: String) -> Option<soroban_sdk::BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Coupon(CouponKey::Commitment(payment_id)))
+}
+
+pub fn set_checkin_commitment(
+    env: &Env,
+    payment_id: String,
+    commitment: Option<soroban_sdk::BytesN<32>>,
+) {
+    let key = DataKey::Coupon(CouponKey::Commitment(payment_id));
+    match commitment {
+        Some(commitment) => env.storage().persistent().set(&key, &commitment),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_scan_history(env: &Env, payment_id: String) -> Vec<ScanRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Coupon(CouponKey::ScanHistory(payment_id)))
+        .unwrap_or_else(|| vec![env])
+}
+
+pub fn append_scan_record(env: &Env, payment_id: String, scanner: Address, entry: bool) {
+    let mut history = get_scan_history(env, payment_id.clone());
+    history.push_back(ScanRecord {
+        scanner,
+        timestamp: env.ledger().timestamp(),
+        entry,
+    });
+    env.storage().persistent().set(
+        &DataKey::Coupon(CouponKey::ScanHistory(payment_id)),
+        &history,
+    );
+}
+
+// ── Ticket-holder polls ───────────────────────────────────────────────────────
+
+pub fn get_poll(env: &Env, event_id: String, poll_id: u32) -> Option<Poll> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Poll(PollKey::Poll(event_id, poll_id)))
+}
+
+pub fn set_poll(env: &Env, event_id: String, poll_id: u32, poll: &Poll) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Poll(PollKey::Poll(event_id, poll_id)), poll);
+}
+
+pub fn get_poll_count(env: &Env, event_id: String) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Poll(PollKey::Count(event_id)))
+        .unwrap_or(0)
+}
+
+pub fn set_poll_count(env: &Env, event_id: String, count: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Poll(PollKey::Count(event_id)), &count);
+}
+
+pub fn is_poll_ticket_counted(
+    env: &Env,
+    event_id: String,
+    poll_id: u32,
+    payment_id: String,
+) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Poll(PollKey::Counted(
+            event_id, poll_id, payment_id,
+        )))
+}
+
+pub fn mark_poll_ticket_counted(env: &Env, event_id: String, poll_id: u32, payment_id: String) {
+    env.storage().persistent().set(
+        &DataKey::Poll(PollKey::Counted(event_id, poll_id, payment_id)),
+        &true,
+    );
+}
+
+// ── Per-token treasuries ──────────────────────────────────────────────────────
+
+/// Tokens that have ever collected platform fees, in first-collected order.
+pub fn get_treasury_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Treasury(TreasuryKey::Tokens))
+        .unwrap_or_else(|| vec![env])
+}
+
+pub fn set_treasury_wallet(env: &Env, token: Address, wallet: Option<Address>) {
+    let key = DataKey::Treasury(TreasuryKey::Wallet(token));
+    match wallet {
+        Some(wallet) => env.storage().persistent().set(&key, &wallet),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Payout wallet for a token's platform fees, defaulting to the platform wallet.
+pub fn get_treasury_wallet(env: &Env, token: Address) -> Address {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Treasury(TreasuryKey::Wallet(token)))
+        .unwrap_or_else(|| get_platform_wallet(env))
+}
+
+// ── Event operators ───────────────────────────────────────────────────────────
+
+pub fn get_operator_permissions(env: &Env, event_id: String, operator: Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Operator(OperatorKey::Grant(event_id, operator)))
+        .unwrap_or(0)
+}
+
+pub fn set_operator_permissions(env: &Env, event_id: String, operator: Address, permissions: u32) {
+    let key = DataKey::Operator(OperatorKey::Grant(event_id, operator));
+    if permissions == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &permissions);
+    }
+}
+
+pub fn is_buyer_limit_exempt(env: &Env, event_id: String, wallet: Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Operator(OperatorKey::LimitExempt(
+            event_id, wallet,
+        )))
+}
+
+pub fn set_buyer_limit_exempt(env: &Env, event_id: String, wallet: Address, exempt: bool) {
+    let key = DataKey::Operator(OperatorKey::LimitExempt(event_id, wallet));
+    if exempt {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+// ── Sponsor slots ─────────────────────────────────────────────────────────────
+
+pub fn get_sponsor_slot(env: &Env, event_id: String, slot_id: String) -> Option<SponsorSlot> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Sponsor(SponsorKey::Slot(event_id, slot_id)))
+}
+
+pub fn set_sponsor_slot(env: &Env, event_id: String, slot: &SponsorSlot) {
+    env.storage().persistent().set(
+        &DataKey::Sponsor(SponsorKey::Slot(event_id, slot.slot_id.clone())),
+        slot,
+    );
+}
+
+pub fn get_sponsor_slot_ids(env: &Env, event_id: String) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Sponsor(SponsorKey::SlotIds(event_id)))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn set_sponsor_slot_ids(env: &Env, event_id: String, slot_ids: &Vec<String>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Sponsor(SponsorKey::SlotIds(event_id)), slot_ids);
+}
+
+pub fn get_sponsorships(env: &Env, event_id: String) -> Vec<Sponsorship> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Sponsor(SponsorKey::Sponsors(event_id)))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn add_sponsorship(env: &Env, event_id: String, sponsorship: Sponsorship) {
+    let mut sponsorships = get_sponsorships(env, event_id.clone());
+    sponsorships.push_back(sponsorship);
+    env.storage().persistent().set(
+        &DataKey::Sponsor(SponsorKey::Sponsors(event_id)),
+        &sponsorships,
+    );
+}
+
+// ── Resale marketplace ────────────────────────────────────────────────────────
+
+pub fn get_listing(env: &Env, payment_id: String) -> Option<Listing> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Market(MarketKey::Listing(payment_id)))
+}
+
+pub fn is_ticket_listed(env: &Env, payment_id: String) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Market(MarketKey::Listing(payment_id)))
+}
+
+pub fn set_listing(env: &Env, listing: &Listing) {
+    env.storage().persistent().set(
+        &DataKey::Market(MarketKey::Listing(listing.payment_id.clone())),
+        listing,
+    );
+    let mut listed = get_event_listings(env, listing.event_id.clone());
+    listed.push_back(listing.payment_id.clone());
+    env.storage().persistent().set(
+        &DataKey::Market(MarketKey::EventListings(listing.event_id.clone())),
+        &listed,
+    );
+}
+
+pub fn remove_listing(env: &Env, listing: &Listing) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Market(MarketKey::Listing(
+            listing.payment_id.clone(),
+        )));
+    let mut listed = get_event_listings(env, listing.event_id.clone());
+    if let Some(index) = listed.first_index_of(&listing.payment_id) {
+        listed.remove(index);
+    }
+    env.storage().persistent().set(
+        &DataKey::Market(MarketKey::EventListings(listing.event_id.clone())),
+        &listed,
+    );
+}
+
+pub fn get_event_listings(env: &Env, event_id: String) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Market(MarketKey::EventListings(event_id)))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn get_resale_royalty_bps(env: &Env, event_id: String) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Market(MarketKey::RoyaltyBps(event_id)))
+}
+
+pub fn set_resale_royalty_bps(env: &Env, event_id: String, bps: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Market(MarketKey::RoyaltyBps(event_id)), &bps);
+}
+
+pub fn get_resale_history(env: &Env, payment_id: String) -> Vec<ResaleRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Market(MarketKey::Resales(payment_id)))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn append_resale_record(env: &Env, payment_id: String, record: ResaleRecord) {
+    let mut history = get_resale_history(env, payment_id.clone());
+    history.push_back(record);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Market(MarketKey::Resales(payment_id)), &history);
+}
+
+pub fn get_resale_fee_bps(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Market(MarketKey::PlatformFeeBps))
+        .unwrap_or(0)
+}
+
+pub fn set_resale_fee_bps(env: &Env, bps: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Market(MarketKey::PlatformFeeBps), &bps);
+}
+
+pub fn is_locked_transfer_approved(env: &Env, payment_id: String) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Market(MarketKey::LockApproved(payment_id)))
+}
+
+pub fn set_locked_transfer_approved(env: &Env, payment_id: String, approved: bool) {
+    let key = DataKey::Market(MarketKey::LockApproved(payment_id));
+    if approved {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn get_transfer_offer(env: &Env, payment_id: String) -> Option<TransferOffer> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Market(MarketKey::Offer(payment_id)))
+}
+
+pub fn set_transfer_offer(env: &Env, offer: &TransferOffer) {
+    env.storage().persistent().set(
+        &DataKey::Market(MarketKey::Offer(offer.payment_id.clone())),
+        offer,
+    );
+}
+
+pub fn remove_transfer_offer(env: &Env, payment_id: String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Market(MarketKey::Offer(payment_id)));
+}
+
+pub fn get_installment_plan(env: &Env, payment_id: String) -> Option<InstallmentPlan> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Installment(InstallmentKey::Plan(payment_id)))
+}
+
+pub fn set_installment_plan(env: &Env, plan: &InstallmentPlan) {
+    env.storage().persistent().set(
+        &DataKey::Installment(InstallmentKey::Plan(plan.payment_id.clone())),
+        plan,
+    );
+}
+
+pub fn get_reservation(env: &Env, reservation_id: String) -> Option<Reservation> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Installment(InstallmentKey::Reservation(
+            reservation_id,
+        )))
+}
+
+pub fn set_reservation(env: &Env, reservation: &Reservation) {
+    env.storage().persistent().set(
+        &DataKey::Installment(InstallmentKey::Reservation(
+            reservation.reservation_id.clone(),
+        )),
+        reservation,
+    );
+}
+
+pub fn remove_reservation(env: &Env, reservation_id: String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Installment(InstallmentKey::Reservation(
+            reservation_id,
+        )));
+}
+
+pub fn get_refund_pool(env: &Env, event_id: String) -> Option<RefundPool> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Refund(RefundKey::Pool(event_id)))
+}
+
+/// Indices of an event's payments the bulk refund passed before they could be
+/// refunded, in the order they are revisited.
+pub fn get_bulk_refund_deferred(env: &Env, event_id: String) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Refund(RefundKey::BulkDeferred(event_id)))
+        .unwrap_or_else(|| vec![env])
+}
+
+pub fn set_bulk_refund_deferred(env: &Env, event_id: String, deferred: &Vec<u32>) {
+    let key = DataKey::Refund(RefundKey::BulkDeferred(event_id));
+    if deferred.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, deferred);
+    }
+}
+
+/// Number of payments and total amount an event's bulk refund has paid out.
+pub fn get_bulk_refunded(env: &Env, event_id: String) -> (u32, Map<Address, i128>) {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Refund(RefundKey::BulkRefunded(event_id)))
+        .unwrap_or_else(|| (0, Map::new(env)))
+}
+
+pub fn add_bulk_refunded(env: &Env, event_id: String, count: u32, refunded: &Map<Address, i128>) {
+    let (refunded_count, mut refunded_by_token) = get_bulk_refunded(env, event_id.clone());
+    for (token, amount) in refunded.iter() {
+        let total = refunded_by_token.get(token.clone()).unwrap_or(0);
+        refunded_by_token.set(token, total.checked_add(amount).unwrap());
+    }
+    env.storage().persistent().set(
+        &DataKey::Refund(RefundKey::BulkRefunded(event_id)),
+        &(refunded_count + count, refunded_by_token),
+    );
+}
+
+pub fn set_refund_pool(env: &Env, pool: &RefundPool) {
+    env.storage().persistent().set(
+        &DataKey::Refund(RefundKey::Pool(pool.event_id.clone())),
+        pool,
+    );
 }
@@ -2,11 +2,11 @@ use super::contract::{
     event_registry, price_oracle, TicketPaymentContract, TicketPaymentContractClient,
 };
 use super::storage::*;
-use super::types::{Payment, PaymentStatus};
+use super::types::{DiscountSpec, Payment, PaymentStatus, PromoScope, PurchaseOptions};
 use crate::error::TicketPaymentError;
 use soroban_sdk::{
     testutils::{Address as _, EnvTestConfig, Events, Ledger},
-    token, Address, Bytes, Env, IntoVal, String, Symbol, TryIntoVal,
+    token, vec, Address, Bytes, Env, IntoVal, String, Symbol, TryIntoVal,
 };
 
 // Mock registry that returns a cancelled event
@@ -18,6 +18,9 @@ impl MockCancelledRegistry {
         event_registry::PaymentInfo {
             payment_address: Address::generate(&env),
             platform_fee_percent: 500,
+            tiers: soroban_sdk::Map::new(&env),
+            policy_flags: 0,
+            resale_royalty_bps: 0,
         }
     }
     pub fn get_event(env: Env, event_id: String) -> Option<event_registry::EventInfo> {
@@ -42,10 +45,17 @@ impl MockCancelledRegistry {
                         price: 1000,
                         early_bird_price: 1000,
                         early_bird_deadline: 0,
+                        early_bird_quantity: 0,
                         usd_price: 0,
                         tier_limit: 100,
                         current_sold: 0,
                         is_refundable: false,
+                        visible_from: 0,
+                        visible_until: 0,
+                        token_prices: soroban_sdk::Map::new(&env),
+                        deposit_amount: 0,
+                        pricing: event_registry::TierPricing::Fixed,
+                        bulk_discounts: soroban_sdk::Map::new(&env),
                     },
                 );
                 tiers
@@ -53,9 +63,17 @@ impl MockCancelledRegistry {
             refund_deadline: 0,
             restocking_fee: 100,
             resale_cap_bps: None,
+            is_postponed: false,
+            grace_period_end: 0,
             min_sales_target: 0,
             target_deadline: 0,
             goal_met: false,
+            visibility: event_registry::EventVisibility::Public,
+            verified: false,
+            max_tickets_per_buyer: 0,
+            resale_royalty_bps: 0,
+            event_start_time: 0,
+            transfer_lock_before_secs: 0,
         })
     }
     pub fn decrement_inventory(_env: Env, _event_id: String, _tier_id: String) {}
@@ -71,6 +89,9 @@ impl MockEventRegistry {
         event_registry::PaymentInfo {
             payment_address: Address::generate(&env),
             platform_fee_percent: 500, // 5%
+            tiers: soroban_sdk::Map::new(&env),
+            policy_flags: 0,
+            resale_royalty_bps: 0,
         }
     }
 
@@ -104,10 +125,17 @@ impl MockEventRegistry {
                             price: 1000_0000000i128,
                             early_bird_price: 800_0000000i128,
                             early_bird_deadline: 0,
+                            early_bird_quantity: 0,
                             usd_price: 0,
                             tier_limit: 100,
                             current_sold: 0,
                             is_refundable: true,
+                            visible_from: 0,
+                            visible_until: 0,
+                            token_prices: soroban_sdk::Map::new(&env),
+                            deposit_amount: 0,
+                            pricing: event_registry::TierPricing::Fixed,
+                            bulk_discounts: soroban_sdk::Map::new(&env),
                         },
                     );
                     tiers
@@ -115,9 +143,17 @@ impl MockEventRegistry {
                 refund_deadline: 0,
                 restocking_fee: 0,
                 resale_cap_bps: None,
+                is_postponed: false,
+                grace_period_end: 0,
                 min_sales_target: 0,
                 target_deadline: 0,
                 goal_met: false,
+                visibility: event_registry::EventVisibility::Public,
+                verified: false,
+                max_tickets_per_buyer: 0,
+                resale_royalty_bps: 0,
+                event_start_time: 0,
+                transfer_lock_before_secs: 0,
             });
         }
         None
@@ -143,6 +179,9 @@ impl MockEventRegistry2 {
         event_registry::PaymentInfo {
             payment_address: Address::generate(&env),
             platform_fee_percent: 250, // 2.5%
+            tiers: soroban_sdk::Map::new(&env),
+            policy_flags: 0,
+            resale_royalty_bps: 0,
         }
     }
 
@@ -171,10 +210,17 @@ impl MockEventRegistry2 {
                         price: 10000_0000000i128,
                         early_bird_price: 8000_0000000i128,
                         early_bird_deadline: 0,
+                        early_bird_quantity: 0,
                         usd_price: 0,
                         tier_limit: 100,
                         current_sold: 0,
                         is_refundable: true,
+                        visible_from: 0,
+                        visible_until: 0,
+                        token_prices: soroban_sdk::Map::new(&env),
+                        deposit_amount: 0,
+                        pricing: event_registry::TierPricing::Fixed,
+                        bulk_discounts: soroban_sdk::Map::new(&env),
                     },
                 );
                 tiers
@@ -182,9 +228,17 @@ impl MockEventRegistry2 {
             refund_deadline: 0,
             restocking_fee: 0,
             resale_cap_bps: None,
+            is_postponed: false,
+            grace_period_end: 0,
             min_sales_target: 0,
             target_deadline: 0,
             goal_met: false,
+            visibility: event_registry::EventVisibility::Public,
+            verified: false,
+            max_tickets_per_buyer: 0,
+            resale_royalty_bps: 0,
+            event_start_time: 0,
+            transfer_lock_before_secs: 0,
         })
     }
 
@@ -294,8 +348,9 @@ fn test_process_payment_success() {
         &1,
         &None,
         &None,
+        &None,
     );
-    assert_eq!(result_id, payment_id);
+    assert_eq!(result_id, vec![&env, payment_id.clone()]);
 
     // Check escrow balances
     let escrow_balance = client.get_event_escrow_balance(&event_id);
@@ -353,6 +408,7 @@ fn test_confirm_payment() {
         buyer_address: buyer,
         ticket_tier_id: String::from_str(&env, "t1"),
         amount: 100,
+        token_address: Address::generate(&env),
         platform_fee: 5,
         organizer_amount: 95,
         status: PaymentStatus::Pending,
@@ -360,6 +416,9 @@ fn test_confirm_payment() {
         created_at: 100,
         confirmed_at: None,
         refunded_amount: 0,
+        identity_hash: None,
+        memo: None,
+        seat_id: None,
     };
 
     env.as_contract(&client.address, || {
@@ -394,6 +453,7 @@ fn test_process_payment_zero_amount() {
         &1,
         &None,
         &None,
+        &None,
     );
 }
 
@@ -430,8 +490,13 @@ fn test_batch_purchase_success() {
         &quantity,
         &None,
         &None,
+        &None,
+    );
+    assert_eq!(result_id.len(), quantity);
+    assert_eq!(
+        result_id.get(2).unwrap(),
+        String::from_str(&env, "batch_1-2")
     );
-    assert_eq!(result_id, payment_id);
 
     // Check escrow balances
     let escrow_balance = client.get_event_escrow_balance(&event_id);
@@ -441,20 +506,15 @@ fn test_batch_purchase_success() {
 
     // Check individual payment records - check at least first two
     // Check individual payment records - check at least first two
-    let sub_id_0 = match 0 {
-        0 => String::from_str(&env, "p-0"),
-        _ => String::from_str(&env, "p-many"),
-    };
-    let payment_0 = client.get_payment_status(&sub_id_0).unwrap();
+    let payment_0 = client
+        .get_payment_status(&String::from_str(&env, "batch_1-0"))
+        .unwrap();
     assert_eq!(payment_0.amount, amount_per_ticket);
 
-    let sub_id_1 = match 1 {
-        1 => String::from_str(&env, "p-1"),
-        _ => String::from_str(&env, "p-many"),
-    };
-    let payment_1 = client.get_payment_status(&sub_id_1).unwrap();
-    assert_eq!(payment_1.amount, amount_per_ticket);
-    assert_eq!(payment_1.amount, amount_per_ticket);
+    let payment_4 = client
+        .get_payment_status(&String::from_str(&env, "batch_1-4"))
+        .unwrap();
+    assert_eq!(payment_4.amount, amount_per_ticket);
 }
 
 #[test]
@@ -489,6 +549,7 @@ fn test_fee_calculation_variants() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     let payment = client
@@ -528,6 +589,7 @@ fn test_process_payment_not_found() {
         &1,
         &None,
         &None,
+        &None,
     );
     // Since panic inside get_event_payment_info cannot easily map to get_code() == 2 right now without explicit Error returning in the mock,
     // this might return a generic EventNotFound due to our fallback logic.
@@ -708,6 +770,7 @@ fn test_process_payment_with_non_whitelisted_token() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     assert_eq!(res, Err(Ok(TicketPaymentError::TokenNotWhitelisted)));
@@ -748,6 +811,7 @@ fn test_process_payment_with_multiple_tokens() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     client.process_payment(
@@ -760,6 +824,7 @@ fn test_process_payment_with_multiple_tokens() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     // Check escrow balances instead of direct transfers
@@ -811,10 +876,17 @@ impl MockEventRegistryMaxSupply {
                         price: 1000_0000000i128,
                         early_bird_price: 800_0000000i128,
                         early_bird_deadline: 0,
+                        early_bird_quantity: 0,
                         usd_price: 0,
                         tier_limit: 100,
                         current_sold: 0,
                         is_refundable: true,
+                        visible_from: 0,
+                        visible_until: 0,
+                        token_prices: soroban_sdk::Map::new(&env),
+                        deposit_amount: 0,
+                        pricing: event_registry::TierPricing::Fixed,
+                        bulk_discounts: soroban_sdk::Map::new(&env),
                     },
                 );
                 tiers
@@ -822,9 +894,17 @@ impl MockEventRegistryMaxSupply {
             refund_deadline: 0,
             restocking_fee: 0,
             resale_cap_bps: None,
+            is_postponed: false,
+            grace_period_end: 0,
             min_sales_target: 0,
             target_deadline: 0,
             goal_met: false,
+            visibility: event_registry::EventVisibility::Public,
+            verified: false,
+            max_tickets_per_buyer: 0,
+            resale_royalty_bps: 0,
+            event_start_time: 0,
+            transfer_lock_before_secs: 0,
         })
     }
 
@@ -871,6 +951,7 @@ fn test_process_payment_max_supply_exceeded() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     assert!(res.is_err());
@@ -886,6 +967,9 @@ impl MockEventRegistryWithInventory {
         event_registry::PaymentInfo {
             payment_address: Address::generate(&env),
             platform_fee_percent: 500,
+            tiers: soroban_sdk::Map::new(&env),
+            policy_flags: 0,
+            resale_royalty_bps: 0,
         }
     }
 
@@ -917,10 +1001,17 @@ impl MockEventRegistryWithInventory {
                         price: 1000_0000000i128,
                         early_bird_price: 800_0000000i128,
                         early_bird_deadline: 0,
+                        early_bird_quantity: 0,
                         usd_price: 0,
                         tier_limit: 100,
                         current_sold: 0,
                         is_refundable: true,
+                        visible_from: 0,
+                        visible_until: 0,
+                        token_prices: soroban_sdk::Map::new(&env),
+                        deposit_amount: 0,
+                        pricing: event_registry::TierPricing::Fixed,
+                        bulk_discounts: soroban_sdk::Map::new(&env),
                     },
                 );
                 tiers
@@ -928,9 +1019,17 @@ impl MockEventRegistryWithInventory {
             refund_deadline: 0,
             restocking_fee: 0,
             resale_cap_bps: None,
+            is_postponed: false,
+            grace_period_end: 0,
             min_sales_target: 0,
             target_deadline: 0,
             goal_met: false,
+            visibility: event_registry::EventVisibility::Public,
+            verified: false,
+            max_tickets_per_buyer: 0,
+            resale_royalty_bps: 0,
+            event_start_time: 0,
+            transfer_lock_before_secs: 0,
         })
     }
 
@@ -982,8 +1081,9 @@ fn test_inventory_increment_on_successful_payment() {
         &1,
         &None,
         &None,
+        &None,
     );
-    assert_eq!(result1, String::from_str(&env, "pay_1"));
+    assert_eq!(result1, vec![&env, String::from_str(&env, "pay_1")]);
 
     // Process second payment - should also succeed
     let result2 = client.process_payment(
@@ -996,8 +1096,9 @@ fn test_inventory_increment_on_successful_payment() {
         &1,
         &None,
         &None,
+        &None,
     );
-    assert_eq!(result2, String::from_str(&env, "pay_2"));
+    assert_eq!(result2, vec![&env, String::from_str(&env, "pay_2")]);
 }
 
 #[test]
@@ -1026,6 +1127,7 @@ fn test_withdraw_organizer_funds() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     let balance = client.get_event_escrow_balance(&event_id);
@@ -1064,6 +1166,7 @@ fn test_withdraw_platform_fees() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     let balance = client.get_event_escrow_balance(&event_id);
@@ -1094,6 +1197,9 @@ impl MockEventRegistryWithMilestones {
         event_registry::PaymentInfo {
             payment_address: Address::generate(&env),
             platform_fee_percent: 500,
+            tiers: soroban_sdk::Map::new(&env),
+            policy_flags: 0,
+            resale_royalty_bps: 0,
         }
     }
 
@@ -1135,10 +1241,17 @@ impl MockEventRegistryWithMilestones {
                         price: 1000_000000i128,
                         early_bird_price: 800_000000i128,
                         early_bird_deadline: 0,
+                        early_bird_quantity: 0,
                         usd_price: 0,
                         tier_limit: 100,
                         current_sold: 0,
                         is_refundable: true,
+                        visible_from: 0,
+                        visible_until: 0,
+                        token_prices: soroban_sdk::Map::new(&env),
+                        deposit_amount: 0,
+                        pricing: event_registry::TierPricing::Fixed,
+                        bulk_discounts: soroban_sdk::Map::new(&env),
                     },
                 );
                 tiers
@@ -1146,9 +1259,17 @@ impl MockEventRegistryWithMilestones {
             refund_deadline: 0,
             restocking_fee: 0,
             resale_cap_bps: None,
+            is_postponed: false,
+            grace_period_end: 0,
             min_sales_target: 0,
             target_deadline: 0,
             goal_met: false,
+            visibility: event_registry::EventVisibility::Public,
+            verified: false,
+            max_tickets_per_buyer: 0,
+            resale_royalty_bps: 0,
+            event_start_time: 0,
+            transfer_lock_before_secs: 0,
         })
     }
 
@@ -1203,6 +1324,7 @@ fn test_withdraw_with_milestones() {
         &1,
         &None,
         &None,
+        &None,
     );
     let withdrawn1 = client.withdraw_organizer_funds(&event_id, &usdc_id);
     assert_eq!(withdrawn1, 0); // Still 0%
@@ -1218,6 +1340,7 @@ fn test_withdraw_with_milestones() {
         &1,
         &None,
         &None,
+        &None,
     );
     let withdrawn2 = client.withdraw_organizer_funds(&event_id, &usdc_id);
     let expected_revenue_2_tickets = 190_0000000i128; // 95 + 95
@@ -1239,6 +1362,7 @@ fn test_withdraw_with_milestones() {
         &1,
         &None,
         &None,
+        &None,
     );
     let withdrawn4 = client.withdraw_organizer_funds(&event_id, &usdc_id);
     let expected_revenue_3_tickets = 285_0000000i128; // 95 * 3
@@ -1256,6 +1380,7 @@ fn test_withdraw_with_milestones() {
         &1,
         &None,
         &None,
+        &None,
     );
     let withdrawn5 = client.withdraw_organizer_funds(&event_id, &usdc_id);
     let expected_revenue_4_tickets = 380_0000000i128;
@@ -1281,7 +1406,7 @@ fn test_withdraw_with_milestones() {
 fn test_transfer_ticket_success() {
     let env = Env::default();
     env.mock_all_auths();
-    let (client, _admin, _usdc_id, _, _) = setup_test(&env);
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
     let buyer = Address::generate(&env);
     let new_owner = Address::generate(&env);
     let payment_id = String::from_str(&env, "pay_1");
@@ -1293,6 +1418,7 @@ fn test_transfer_ticket_success() {
         buyer_address: buyer.clone(),
         ticket_tier_id: String::from_str(&env, "t1"),
         amount: 1000,
+        token_address: usdc_id.clone(),
         platform_fee: 50,
         organizer_amount: 950,
         status: PaymentStatus::Confirmed,
@@ -1300,13 +1426,16 @@ fn test_transfer_ticket_success() {
         created_at: 100,
         confirmed_at: Some(101),
         refunded_amount: 0,
+        identity_hash: None,
+        memo: None,
+        seat_id: None,
     };
 
     env.as_contract(&client.address, || {
         store_payment(&env, payment);
     });
 
-    client.transfer_ticket(&payment_id, &new_owner, &None);
+    client.transfer_ticket(&payment_id, &new_owner, &None, &None);
 
     let updated = client.get_payment_status(&payment_id).unwrap();
     assert_eq!(updated.buyer_address, new_owner);
@@ -1353,6 +1482,7 @@ fn test_transfer_ticket_with_fee() {
         buyer_address: buyer.clone(),
         ticket_tier_id: String::from_str(&env, "t1"),
         amount: 1000,
+        token_address: usdc_id.clone(),
         platform_fee: 50,
         organizer_amount: 950,
         status: PaymentStatus::Confirmed,
@@ -1360,13 +1490,16 @@ fn test_transfer_ticket_with_fee() {
         created_at: 100,
         confirmed_at: Some(101),
         refunded_amount: 0,
+        identity_hash: None,
+        memo: None,
+        seat_id: None,
     };
 
     env.as_contract(&client.address, || {
         store_payment(&env, payment);
     });
 
-    client.transfer_ticket(&payment_id, &new_owner, &None);
+    client.transfer_ticket(&payment_id, &new_owner, &None, &None);
 
     // Verify fee deduction
     let new_escrow = client.get_event_escrow_balance(&event_id);
@@ -1395,6 +1528,7 @@ fn test_transfer_ticket_unauthorized() {
         buyer_address: buyer.clone(),
         ticket_tier_id: String::from_str(&env, "t1"),
         amount: 1000,
+        token_address: Address::generate(&env),
         platform_fee: 50,
         organizer_amount: 950,
         status: PaymentStatus::Confirmed,
@@ -1402,6 +1536,9 @@ fn test_transfer_ticket_unauthorized() {
         created_at: 100,
         confirmed_at: Some(101),
         refunded_amount: 0,
+        identity_hash: None,
+        memo: None,
+        seat_id: None,
     };
 
     env.as_contract(&client.address, || {
@@ -1411,7 +1548,7 @@ fn test_transfer_ticket_unauthorized() {
     // Thief tries to transfer buyer's ticket WITHOUT mock_all_auths().
     // The contract calls `from.require_auth()`, where `from` is `buyer`.
     // Since we didn't mock_all_auths() or sign for `buyer`, this MUST panic.
-    client.transfer_ticket(&payment_id, &thief, &None);
+    client.transfer_ticket(&payment_id, &thief, &None, &None);
 }
 
 // Mock Event Registry With Early Bird Pricing
@@ -1424,6 +1561,9 @@ impl MockEventRegistryEarlyBird {
         event_registry::PaymentInfo {
             payment_address: Address::generate(&env),
             platform_fee_percent: 500, // 5%
+            tiers: soroban_sdk::Map::new(&env),
+            policy_flags: 0,
+            resale_royalty_bps: 0,
         }
     }
 
@@ -1452,10 +1592,17 @@ impl MockEventRegistryEarlyBird {
                         price: 1500_0000000i128, // Standard 150 USDC
                         early_bird_price: 1000_0000000i128, // Early Bird 100 USDC
                         early_bird_deadline: 1000000, // Deadline at timestamp 1,000,000
+                        early_bird_quantity: 0,
                         usd_price: 0,
                         tier_limit: 1000,
                         current_sold: 0,
                         is_refundable: true,
+                        visible_from: 0,
+                        visible_until: 0,
+                        token_prices: soroban_sdk::Map::new(&env),
+                        deposit_amount: 0,
+                        pricing: event_registry::TierPricing::Fixed,
+                        bulk_discounts: soroban_sdk::Map::new(&env),
                     },
                 );
                 tiers
@@ -1463,9 +1610,17 @@ impl MockEventRegistryEarlyBird {
             refund_deadline: 0,
             restocking_fee: 0,
             resale_cap_bps: None,
+            is_postponed: false,
+            grace_period_end: 0,
             min_sales_target: 0,
             target_deadline: 0,
             goal_met: false,
+            visibility: event_registry::EventVisibility::Public,
+            verified: false,
+            max_tickets_per_buyer: 0,
+            resale_royalty_bps: 0,
+            event_start_time: 0,
+            transfer_lock_before_secs: 0,
         })
     }
 
@@ -1516,9 +1671,10 @@ fn test_early_bird_pricing_active() {
         &1,
         &None,
         &None,
+        &None,
     );
 
-    assert_eq!(result_id, payment_id);
+    assert_eq!(result_id, vec![&env, payment_id.clone()]);
 }
 
 #[test]
@@ -1559,6 +1715,7 @@ fn test_early_bird_pricing_expired() {
         &1,
         &None,
         &None,
+        &None,
     );
     assert_eq!(result_fail, Err(Ok(TicketPaymentError::InvalidPrice)));
 
@@ -1574,8 +1731,9 @@ fn test_early_bird_pricing_expired() {
         &1,
         &None,
         &None,
+        &None,
     );
-    assert_eq!(result_success, payment_id_success);
+    assert_eq!(result_success, vec![&env, payment_id_success.clone()]);
 }
 
 #[test]
@@ -1618,6 +1776,7 @@ fn test_price_switched_event_emitted_exactly_once() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     // After setting ledger exactly at the deadline (still early bird)
@@ -1632,6 +1791,7 @@ fn test_price_switched_event_emitted_exactly_once() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     // Setting ledger past deadline triggers switch
@@ -1646,6 +1806,7 @@ fn test_price_switched_event_emitted_exactly_once() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     // And another payment long past deadline
@@ -1660,6 +1821,7 @@ fn test_price_switched_event_emitted_exactly_once() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     // Now count the occurrences of PriceSwitchedEvent in the logs
@@ -1721,6 +1883,7 @@ fn test_bulk_refund_success() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     usdc_token.mint(&buyer2, &ticket_price);
@@ -1735,6 +1898,7 @@ fn test_bulk_refund_success() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     // Confirm them
@@ -1778,6 +1942,55 @@ fn test_bulk_refund_success() {
     );
 }
 
+#[test]
+fn test_bulk_refund_pays_only_what_partial_refunds_left() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let usdc = token::Client::new(&env, &usdc_id);
+    let buyer = Address::generate(&env);
+    let event_id = String::from_str(&env, "event_1");
+    let payment_id = String::from_str(&env, "p1");
+    let ticket_price = 1000_0000000i128;
+
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &ticket_price);
+    usdc.approve(&buyer, &client.address, &ticket_price, &9999);
+    client.process_payment(
+        &payment_id,
+        &event_id,
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &ticket_price,
+        &1,
+        &None,
+        &None,
+        &None,
+    );
+    client.confirm_payment(&payment_id, &String::from_str(&env, "h1"));
+
+    let partial = client.admin_partial_refund(&payment_id, &2000);
+    assert_eq!(client.trigger_bulk_refund(&event_id, &10), 1);
+
+    // The buyer gets back the ticket's price once, not the price on top of
+    // the partial refund
+    assert_eq!(usdc.balance(&buyer), ticket_price);
+    assert_eq!(
+        client
+            .get_bulk_refund_progress(&event_id)
+            .refunded_by_token
+            .get(usdc_id.clone()),
+        Some(ticket_price - partial)
+    );
+    let payment = client.get_payment_status(&payment_id).unwrap();
+    assert_eq!(payment.status, PaymentStatus::Refunded);
+    assert_eq!(payment.refunded_amount, ticket_price);
+    let balance = client.get_event_escrow_balance(&event_id);
+    assert_eq!(balance.organizer_amount, 0);
+    assert_eq!(balance.platform_fee, 0);
+}
+
 #[test]
 fn test_bulk_refund_batching() {
     let env = Env::default();
@@ -1811,6 +2024,7 @@ fn test_bulk_refund_batching() {
             &1,
             &None,
             &None,
+            &None,
         );
         client.confirm_payment(pid, &String::from_str(&env, "h"));
     }
@@ -1828,6 +2042,148 @@ fn test_bulk_refund_batching() {
     assert_eq!(count3, 0);
 }
 
+#[test]
+fn test_bulk_refund_revisits_payments_disputed_during_the_scan() {
+    use crate::events::{AgoraEvent, RefundBatchCompletedEvent};
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let usdc_token = token::StellarAssetClient::new(&env, &usdc_id);
+
+    let event_id = String::from_str(&env, "event_1");
+    let tier_id = String::from_str(&env, "tier_1");
+    let ticket_price = 1000_0000000i128;
+
+    let pay = |pid: &String| {
+        let buyer = Address::generate(&env);
+        usdc_token.mint(&buyer, &ticket_price);
+        token::Client::new(&env, &usdc_id).approve(&buyer, &client.address, &ticket_price, &9999);
+        client.process_payment(
+            pid,
+            &event_id,
+            &tier_id,
+            &buyer,
+            &usdc_id,
+            &ticket_price,
+            &1,
+            &None,
+            &None,
+            &None,
+        );
+        client.confirm_payment(pid, &String::from_str(&env, "h"));
+        buyer
+    };
+    let pids = [
+        String::from_str(&env, "p0"),
+        String::from_str(&env, "p1"),
+        String::from_str(&env, "p2"),
+        String::from_str(&env, "p3"),
+    ];
+    pay(&pids[0]);
+    pay(&pids[1]);
+    let disputing_buyer = pay(&pids[2]);
+    client.open_dispute(&pids[2], &disputing_buyer, &String::from_str(&env, "r"));
+
+    // The disputed payment is passed over and deferred
+    assert_eq!(client.trigger_bulk_refund(&event_id, &2), 2);
+    assert_eq!(client.trigger_bulk_refund(&event_id, &2), 0);
+    let progress = client.get_bulk_refund_progress(&event_id);
+    assert_eq!((progress.processed, progress.total), (3, 3));
+    assert_eq!(progress.deferred, 1);
+    assert_eq!(progress.refunded_count, 2);
+
+    // Once resolved it is refunded alongside payments made after cancellation
+    let arbiter = Address::generate(&env);
+    client.set_dispute_arbiter(&arbiter, &true);
+    client.resolve_dispute(&arbiter, &pids[2], &0);
+    pay(&pids[3]);
+    assert_eq!(client.trigger_bulk_refund(&event_id, &2), 2);
+
+    let batch: RefundBatchCompletedEvent = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            *topics == (AgoraEvent::RefundBatchCompleted, event_id.clone()).into_val(&env)
+        })
+        .map(|(_, _, data)| data.try_into_val(&env).unwrap())
+        .unwrap();
+    assert_eq!(batch.refund_count, 2);
+    assert_eq!((batch.processed, batch.total, batch.deferred), (4, 4, 0));
+    assert_eq!(
+        client.get_payment_status(&pids[2]).unwrap().status,
+        PaymentStatus::Refunded
+    );
+
+    let progress = client.get_bulk_refund_progress(&event_id);
+    assert_eq!(progress.deferred, 0);
+    assert_eq!(progress.refunded_count, 4);
+    assert_eq!(progress.refunded_by_token.len(), 1);
+    assert_eq!(
+        progress.refunded_by_token.get(usdc_id.clone()),
+        Some(ticket_price * 4)
+    );
+}
+
+#[test]
+fn test_bulk_refund_advances_past_a_batch_of_deferred_payments() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
+    let usdc_token = token::StellarAssetClient::new(&env, &usdc_id);
+
+    let event_id = String::from_str(&env, "event_1");
+    let tier_id = String::from_str(&env, "tier_1");
+    let ticket_price = 1000_0000000i128;
+    let batch_size = 2u32;
+
+    // More disputed payments than fit in a batch, followed by pending ones
+    for i in 0..6u32 {
+        let pid = String::from_str(&env, ["b0", "b1", "b2", "b3", "b4", "b5"][i as usize]);
+        let buyer = Address::generate(&env);
+        usdc_token.mint(&buyer, &ticket_price);
+        token::Client::new(&env, &usdc_id).approve(&buyer, &client.address, &ticket_price, &9999);
+        client.process_payment(
+            &pid,
+            &event_id,
+            &tier_id,
+            &buyer,
+            &usdc_id,
+            &ticket_price,
+            &1,
+            &None,
+            &None,
+            &None,
+        );
+        if i < 4 {
+            client.confirm_payment(&pid, &String::from_str(&env, "h"));
+            client.open_dispute(&pid, &buyer, &String::from_str(&env, "r"));
+        }
+    }
+
+    // The cursor keeps moving while deferred payments pile up, and pending
+    // payments are refunded as they are reached
+    let mut refunded = 0;
+    for _ in 0..5 {
+        refunded += client.trigger_bulk_refund(&event_id, &batch_size);
+    }
+    assert_eq!(refunded, 2);
+    let progress = client.get_bulk_refund_progress(&event_id);
+    assert_eq!((progress.processed, progress.total), (6, 6));
+    assert_eq!(progress.deferred, 4);
+    assert_eq!(progress.refunded_count, 2);
+    assert_eq!(
+        client
+            .get_payment_status(&String::from_str(&env, "b5"))
+            .unwrap()
+            .status,
+        PaymentStatus::Refunded
+    );
+}
+
 #[test]
 fn test_protocol_revenue_reporting_views() {
     let env = Env::default();
@@ -1854,6 +2210,7 @@ fn test_protocol_revenue_reporting_views() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     let expected_fee = (amount * 500) / 10000;
@@ -1895,6 +2252,9 @@ impl MockEventRegistryWithOrganizer {
         event_registry::PaymentInfo {
             payment_address: Address::generate(&env),
             platform_fee_percent: 500,
+            tiers: soroban_sdk::Map::new(&env),
+            policy_flags: 0,
+            resale_royalty_bps: 0,
         }
     }
 
@@ -1935,10 +2295,17 @@ impl MockEventRegistryWithOrganizer {
                         price: 1000_0000000i128,
                         early_bird_price: 800_0000000i128,
                         early_bird_deadline: 0,
+                        early_bird_quantity: 0,
                         usd_price: 0,
                         tier_limit: 100,
                         current_sold: 0,
                         is_refundable: true,
+                        visible_from: 0,
+                        visible_until: 0,
+                        token_prices: soroban_sdk::Map::new(&env),
+                        deposit_amount: 0,
+                        pricing: event_registry::TierPricing::Fixed,
+                        bulk_discounts: soroban_sdk::Map::new(&env),
                     },
                 );
                 tiers
@@ -1946,19 +2313,43 @@ impl MockEventRegistryWithOrganizer {
             refund_deadline: 0,
             restocking_fee: 0,
             resale_cap_bps: None,
+            is_postponed: false,
+            grace_period_end: 0,
             min_sales_target: 0,
             target_deadline: 0,
             goal_met: false,
+            visibility: event_registry::EventVisibility::Public,
+            verified: false,
+            max_tickets_per_buyer: 0,
+            resale_royalty_bps: 0,
+            event_start_time: 0,
+            transfer_lock_before_secs: 0,
         })
     }
 
     pub fn increment_inventory(_env: Env, _event_id: String, _tier_id: String, _quantity: u32) {}
     pub fn decrement_inventory(_env: Env, _event_id: String, _tier_id: String) {}
-    pub fn get_global_promo_bps(_env: Env) -> u32 {
-        0
+
+    pub fn set_promo(env: Env, bps: u32, expiry: u64) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "promo_bps"), &bps);
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "promo_exp"), &expiry);
     }
-    pub fn get_promo_expiry(_env: Env) -> u64 {
-        0
+
+    pub fn get_global_promo_bps(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "promo_bps"))
+            .unwrap_or(0)
+    }
+    pub fn get_promo_expiry(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "promo_exp"))
+            .unwrap_or(0)
     }
 }
 
@@ -2022,6 +2413,7 @@ fn test_add_discount_hashes_and_invalid_code_rejected() {
         &1,
         &Some(wrong_preimage),
         &None,
+        &None,
     );
 
     assert_eq!(res, Err(Ok(TicketPaymentError::InvalidDiscountCode)));
@@ -2055,6 +2447,7 @@ fn test_gas_profile_process_payment_budget() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     let post_budget = env.cost_estimate().budget();
@@ -2096,8 +2489,9 @@ fn test_process_payment_with_valid_discount_code() {
         &1,
         &Some(preimage),
         &None,
+        &None,
     );
-    assert_eq!(result, String::from_str(&env, "pay_1"));
+    assert_eq!(result, vec![&env, String::from_str(&env, "pay_1")]);
 
     let escrow = client.get_event_escrow_balance(&event_id);
     assert_eq!(escrow.platform_fee, 450_000_000);
@@ -2132,6 +2526,7 @@ fn test_discount_code_one_time_use() {
         &1,
         &Some(Bytes::from_slice(&env, b"ONCE_ONLY")),
         &None,
+        &None,
     );
 
     let res = client.try_process_payment(
@@ -2144,10 +2539,170 @@ fn test_discount_code_one_time_use() {
         &1,
         &Some(Bytes::from_slice(&env, b"ONCE_ONLY")),
         &None,
+        &None,
     );
     assert_eq!(res, Err(Ok(TicketPaymentError::DiscountCodeAlreadyUsed)));
 }
 
+#[test]
+fn test_discount_codes_honor_per_code_terms() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let (client, _organizer, _registry_id, usdc_id) = setup_discount_test(&env);
+
+    let event_id = String::from_str(&env, "event_1");
+    let hash_of = |code: &[u8]| -> soroban_sdk::BytesN<32> {
+        env.crypto().sha256(&Bytes::from_slice(&env, code)).into()
+    };
+    let spec =
+        |code: &[u8], bps: u32, max_uses: u32, expiry: u64, tier: Option<&str>| DiscountSpec {
+            hash: hash_of(code),
+            bps,
+            max_uses,
+            max_uses_per_buyer: 0,
+            expiry,
+            tier_restriction: tier.map(|t| String::from_str(&env, t)),
+        };
+    client.add_discount_codes(
+        &event_id,
+        &soroban_sdk::vec![
+            &env,
+            spec(b"QUARTER", 2500, 2, 10_000, Some("tier_1")),
+            spec(b"VIP_ONLY", 5000, 0, 0, Some("tier_vip")),
+            spec(b"STALE", 5000, 0, 500, None),
+        ],
+    );
+    assert_eq!(
+        client.try_add_discount_codes(
+            &event_id,
+            &soroban_sdk::vec![&env, spec(b"FREE", 0, 1, 0, None)]
+        ),
+        Err(Ok(TicketPaymentError::InvalidDiscountBps))
+    );
+    // Another event cannot take over a registered code
+    assert_eq!(
+        client.try_add_discount_codes(
+            &String::from_str(&env, "event_2"),
+            &soroban_sdk::vec![&env, spec(b"QUARTER", 9000, 0, 0, None)],
+        ),
+        Err(Ok(TicketPaymentError::Unauthorized))
+    );
+
+    let buyer = Address::generate(&env);
+    let full_amount = 10_000_000_000_i128;
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &(full_amount * 3));
+    token::Client::new(&env, &usdc_id).approve(&buyer, &client.address, &(full_amount * 3), &99999);
+    let pay = |payment_id: &str, event: &String, code: &[u8]| {
+        client.try_process_payment(
+            &String::from_str(&env, payment_id),
+            event,
+            &String::from_str(&env, "tier_1"),
+            &buyer,
+            &usdc_id,
+            &full_amount,
+            &1,
+            &Some(Bytes::from_slice(&env, code)),
+            &None,
+            &None,
+        )
+    };
+
+    let other_event = String::from_str(&env, "event_2");
+    assert_eq!(
+        pay("pay_other", &other_event, b"QUARTER"),
+        Err(Ok(TicketPaymentError::InvalidDiscountCode))
+    );
+    assert_eq!(
+        pay("pay_vip", &event_id, b"VIP_ONLY"),
+        Err(Ok(TicketPaymentError::InvalidDiscountCode))
+    );
+    assert_eq!(
+        pay("pay_stale", &event_id, b"STALE"),
+        Err(Ok(TicketPaymentError::InvalidDiscountCode))
+    );
+
+    pay("pay_1", &event_id, b"QUARTER").unwrap().unwrap();
+    pay("pay_2", &event_id, b"QUARTER").unwrap().unwrap();
+    assert_eq!(
+        token::Client::new(&env, &usdc_id).balance(&buyer),
+        full_amount * 3 - full_amount * 3 / 2
+    );
+    assert_eq!(
+        client
+            .get_discount_code(&hash_of(b"QUARTER"))
+            .unwrap()
+            .uses_so_far,
+        2
+    );
+    assert_eq!(
+        pay("pay_3", &event_id, b"QUARTER"),
+        Err(Ok(TicketPaymentError::DiscountCodeAlreadyUsed))
+    );
+}
+
+#[test]
+fn test_discount_code_caps_total_and_per_wallet_uses() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _organizer, _registry_id, usdc_id) = setup_discount_test(&env);
+
+    let event_id = String::from_str(&env, "event_1");
+    let code = Bytes::from_slice(&env, b"FIRST_THREE");
+    let hash: soroban_sdk::BytesN<32> = env.crypto().sha256(&code).into();
+    // "First three buyers, one per wallet"
+    client.add_discount_codes(
+        &event_id,
+        &soroban_sdk::vec![
+            &env,
+            DiscountSpec {
+                hash: hash.clone(),
+                bps: 1000,
+                max_uses: 3,
+                max_uses_per_buyer: 1,
+                expiry: 0,
+                tier_restriction: None,
+            },
+        ],
+    );
+
+    let full_amount = 10_000_000_000_i128;
+    let pay = |payment_id: &str, buyer: &Address| {
+        token::StellarAssetClient::new(&env, &usdc_id).mint(buyer, &full_amount);
+        token::Client::new(&env, &usdc_id).approve(buyer, &client.address, &full_amount, &99999);
+        client.try_process_payment(
+            &String::from_str(&env, payment_id),
+            &event_id,
+            &String::from_str(&env, "tier_1"),
+            buyer,
+            &usdc_id,
+            &full_amount,
+            &1,
+            &Some(code.clone()),
+            &None,
+            &None,
+        )
+    };
+
+    let first = Address::generate(&env);
+    pay("pay_1", &first).unwrap().unwrap();
+    assert_eq!(
+        pay("pay_1b", &first),
+        Err(Ok(TicketPaymentError::DiscountCodeAlreadyUsed))
+    );
+    assert_eq!(client.get_discount_code_uses(&hash, &first), 1);
+
+    pay("pay_2", &Address::generate(&env)).unwrap().unwrap();
+    pay("pay_3", &Address::generate(&env)).unwrap().unwrap();
+    assert_eq!(
+        pay("pay_4", &Address::generate(&env)),
+        Err(Ok(TicketPaymentError::DiscountCodeAlreadyUsed))
+    );
+    assert_eq!(client.get_discount_code(&hash).unwrap().uses_so_far, 3);
+}
+
 #[test]
 fn test_process_payment_no_code_unchanged() {
     let env = Env::default();
@@ -2170,6 +2725,7 @@ fn test_process_payment_no_code_unchanged() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     let escrow = client.get_event_escrow_balance(&String::from_str(&env, "event_1"));
@@ -2254,9 +2810,17 @@ impl MockPlatformRegistryE2E {
             refund_deadline: 0,
             restocking_fee: 0,
             resale_cap_bps: None,
+            is_postponed: false,
+            grace_period_end: 0,
             min_sales_target: 0,
             target_deadline: 0,
             goal_met: false,
+            visibility: event_registry::EventVisibility::Public,
+            verified: false,
+            max_tickets_per_buyer: 0,
+            resale_royalty_bps: 0,
+            event_start_time: 0,
+            transfer_lock_before_secs: 0,
         };
 
         env.storage()
@@ -2290,6 +2854,9 @@ impl MockPlatformRegistryE2E {
         event_registry::PaymentInfo {
             payment_address: event.payment_address,
             platform_fee_percent: event.platform_fee_percent,
+            tiers: event.tiers,
+            policy_flags: 0,
+            resale_royalty_bps: 0,
         }
     }
 
@@ -2388,10 +2955,17 @@ fn test_integration_full_platform_day() {
                 price: 1000_0000000i128 + (i as i128 * 200_0000000),
                 early_bird_price: 1000_0000000i128 + (i as i128 * 200_0000000),
                 early_bird_deadline: 0,
+                early_bird_quantity: 0,
                 usd_price: 0,
                 tier_limit: 50,
                 current_sold: 0,
                 is_refundable: true,
+                visible_from: 0,
+                visible_until: 0,
+                token_prices: soroban_sdk::Map::new(&env),
+                deposit_amount: 0,
+                pricing: event_registry::TierPricing::Fixed,
+                bulk_discounts: soroban_sdk::Map::new(&env),
             },
         );
     }
@@ -2441,6 +3015,7 @@ fn test_integration_full_platform_day() {
             &1,
             &None,
             &None,
+            &None,
         );
     }
 
@@ -2507,6 +3082,7 @@ fn test_integration_edge_cases() {
         &1,
         &None,
         &None,
+        &None,
     );
     assert_eq!(empty_res, Err(Ok(TicketPaymentError::TierNotFound)));
 
@@ -2520,10 +3096,17 @@ fn test_integration_edge_cases() {
             price: 1000_0000000i128,
             early_bird_price: 1000_0000000i128,
             early_bird_deadline: 0,
+            early_bird_quantity: 0,
             usd_price: 0,
             tier_limit: 1,
             current_sold: 0,
             is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: soroban_sdk::Map::new(&env),
+            deposit_amount: 0,
+            pricing: event_registry::TierPricing::Fixed,
+            bulk_discounts: soroban_sdk::Map::new(&env),
         },
     );
     registry.create_event(
@@ -2551,6 +3134,7 @@ fn test_integration_edge_cases() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     let buyer2 = Address::generate(&env);
@@ -2571,6 +3155,7 @@ fn test_integration_edge_cases() {
         &1,
         &None,
         &None,
+        &None,
     );
     assert!(sold_res.is_err());
 
@@ -2587,6 +3172,7 @@ fn test_integration_edge_cases() {
         &1,
         &None,
         &None,
+        &None,
     );
     assert!(transfer_res.is_err());
 }
@@ -2623,10 +3209,17 @@ fn test_integration_concurrent_multi_guest_sales_no_state_corruption() {
             price: 1000_0000000i128,
             early_bird_price: 1000_0000000i128,
             early_bird_deadline: 0,
+            early_bird_quantity: 0,
             usd_price: 0,
             tier_limit: 10,
             current_sold: 0,
             is_refundable: true,
+            visible_from: 0,
+            visible_until: 0,
+            token_prices: soroban_sdk::Map::new(&env),
+            deposit_amount: 0,
+            pricing: event_registry::TierPricing::Fixed,
+            bulk_discounts: soroban_sdk::Map::new(&env),
         },
     );
     registry.create_event(&event_id, &organizer, &event_payment_addr, &10, &tiers);
@@ -2634,20 +3227,21 @@ fn test_integration_concurrent_multi_guest_sales_no_state_corruption() {
     let mut success_count = 0u32;
     let mut fail_count = 0u32;
 
+    const GUEST_PAYMENT_IDS: [&str; 20] = [
+        "cg-00", "cg-01", "cg-02", "cg-03", "cg-04", "cg-05", "cg-06", "cg-07", "cg-08", "cg-09",
+        "cg-10", "cg-11", "cg-12", "cg-13", "cg-14", "cg-15", "cg-16", "cg-17", "cg-18", "cg-19",
+    ];
+
     // Simulate concurrent demand with rapid sequential purchases from many guests.
-    for i in 0..20 {
+    for payment_id in GUEST_PAYMENT_IDS {
         let buyer = Address::generate(&env);
         let amount = 1000_0000000i128;
         token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &amount);
         token::Client::new(&env, &usdc_id).approve(&buyer, &payment_client.address, &amount, &9999);
 
-        let pid = if i < 10 {
-            String::from_str(&env, "cg-a")
-        } else {
-            String::from_str(&env, "cg-b")
-        };
+        let pid = String::from_str(&env, payment_id);
         let res = payment_client.try_process_payment(
-            &pid, &event_id, &tier_id, &buyer, &usdc_id, &amount, &1, &None, &None,
+            &pid, &event_id, &tier_id, &buyer, &usdc_id, &amount, &1, &None, &None, &None,
         );
 
         if res.is_ok() {
@@ -2676,6 +3270,9 @@ impl MockEventRegistryRefund {
         event_registry::PaymentInfo {
             payment_address: Address::generate(&env),
             platform_fee_percent: 500,
+            tiers: soroban_sdk::Map::new(&env),
+            policy_flags: 0,
+            resale_royalty_bps: 0,
         }
     }
 
@@ -2704,10 +3301,17 @@ impl MockEventRegistryRefund {
                         price: 1000,
                         early_bird_price: 1000,
                         early_bird_deadline: 0,
+                        early_bird_quantity: 0,
                         usd_price: 0,
                         tier_limit: 100,
                         current_sold: 0,
                         is_refundable: true,
+                        visible_from: 0,
+                        visible_until: 0,
+                        token_prices: soroban_sdk::Map::new(&env),
+                        deposit_amount: 0,
+                        pricing: event_registry::TierPricing::Fixed,
+                        bulk_discounts: soroban_sdk::Map::new(&env),
                     },
                 );
                 tiers
@@ -2715,9 +3319,17 @@ impl MockEventRegistryRefund {
             refund_deadline: 2000,
             restocking_fee: 100,
             resale_cap_bps: None,
+            is_postponed: false,
+            grace_period_end: 0,
             min_sales_target: 0,
             target_deadline: 0,
             goal_met: false,
+            visibility: event_registry::EventVisibility::Public,
+            verified: false,
+            max_tickets_per_buyer: 0,
+            resale_royalty_bps: 0,
+            event_start_time: 0,
+            transfer_lock_before_secs: 0,
         })
     }
 
@@ -2743,6 +3355,9 @@ impl MockEventRegistryWithResaleCap {
         event_registry::PaymentInfo {
             payment_address: Address::generate(&env),
             platform_fee_percent: 500,
+            tiers: soroban_sdk::Map::new(&env),
+            policy_flags: 0,
+            resale_royalty_bps: 0,
         }
     }
 
@@ -2771,10 +3386,28 @@ impl MockEventRegistryWithResaleCap {
                         price: 1000_0000000i128, // 1000 USDC
                         early_bird_price: 800_0000000i128,
                         early_bird_deadline: 0,
+                        early_bird_quantity: 0,
                         usd_price: 0,
                         tier_limit: 100,
                         current_sold: 0,
                         is_refundable: true,
+                        visible_from: 0,
+                        visible_until: 0,
+                        token_prices: {
+                            // A second token with its own face value, if configured
+                            let mut prices = soroban_sdk::Map::new(&env);
+                            let alt: Option<Address> = env
+                                .storage()
+                                .instance()
+                                .get(&soroban_sdk::Symbol::new(&env, "alt_token"));
+                            if let Some(alt) = alt {
+                                prices.set(alt, 10_0000000i128);
+                            }
+                            prices
+                        },
+                        deposit_amount: 0,
+                        pricing: event_registry::TierPricing::Fixed,
+                        bulk_discounts: soroban_sdk::Map::new(&env),
                     },
                 );
                 tiers
@@ -2782,9 +3415,17 @@ impl MockEventRegistryWithResaleCap {
             refund_deadline: 0,
             restocking_fee: 0,
             resale_cap_bps: Some(1000), // 10% above face value
+            is_postponed: false,
+            grace_period_end: 0,
             min_sales_target: 0,
             target_deadline: 0,
             goal_met: false,
+            visibility: event_registry::EventVisibility::Public,
+            verified: false,
+            max_tickets_per_buyer: 0,
+            resale_royalty_bps: 0,
+            event_start_time: 0,
+            transfer_lock_before_secs: 0,
         })
     }
 
@@ -2826,7 +3467,7 @@ fn setup_test_with_resale_cap(
 fn test_transfer_ticket_resale_price_within_cap() {
     let env = Env::default();
     env.mock_all_auths();
-    let (client, _admin, _usdc_id, _, _) = setup_test_with_resale_cap(&env);
+    let (client, _admin, usdc_id, _, _) = setup_test_with_resale_cap(&env);
 
     let buyer = Address::generate(&env);
     let new_owner = Address::generate(&env);
@@ -2838,6 +3479,7 @@ fn test_transfer_ticket_resale_price_within_cap() {
         buyer_address: buyer.clone(),
         ticket_tier_id: String::from_str(&env, "general"),
         amount: 1000_0000000,
+        token_address: usdc_id.clone(),
         platform_fee: 50_0000000,
         organizer_amount: 950_0000000,
         status: PaymentStatus::Confirmed,
@@ -2845,6 +3487,9 @@ fn test_transfer_ticket_resale_price_within_cap() {
         created_at: 100,
         confirmed_at: Some(101),
         refunded_amount: 0,
+        identity_hash: None,
+        memo: None,
+        seat_id: None,
     };
 
     env.as_contract(&client.address, || {
@@ -2852,18 +3497,21 @@ fn test_transfer_ticket_resale_price_within_cap() {
     });
 
     // Sale price at exactly the cap: 1000 * (10000 + 1000) / 10000 = 1100 USDC
-    let sale_price = Some(1100_0000000i128);
-    client.transfer_ticket(&payment_id, &new_owner, &sale_price);
+    let price = 1100_0000000i128;
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&new_owner, &price);
+    token::Client::new(&env, &usdc_id).approve(&new_owner, &client.address, &price, &99999);
+    client.transfer_ticket(&payment_id, &new_owner, &Some(price), &None);
 
     let updated = client.get_payment_status(&payment_id).unwrap();
     assert_eq!(updated.buyer_address, new_owner);
+    assert_eq!(token::Client::new(&env, &usdc_id).balance(&buyer), price);
 }
 
 #[test]
 fn test_transfer_ticket_resale_price_exceeds_cap() {
     let env = Env::default();
     env.mock_all_auths();
-    let (client, _admin, _usdc_id, _, _) = setup_test_with_resale_cap(&env);
+    let (client, _admin, usdc_id, _, _) = setup_test_with_resale_cap(&env);
 
     let buyer = Address::generate(&env);
     let new_owner = Address::generate(&env);
@@ -2875,6 +3523,7 @@ fn test_transfer_ticket_resale_price_exceeds_cap() {
         buyer_address: buyer.clone(),
         ticket_tier_id: String::from_str(&env, "general"),
         amount: 1000_0000000,
+        token_address: usdc_id.clone(),
         platform_fee: 50_0000000,
         organizer_amount: 950_0000000,
         status: PaymentStatus::Confirmed,
@@ -2882,6 +3531,9 @@ fn test_transfer_ticket_resale_price_exceeds_cap() {
         created_at: 100,
         confirmed_at: Some(101),
         refunded_amount: 0,
+        identity_hash: None,
+        memo: None,
+        seat_id: None,
     };
 
     env.as_contract(&client.address, || {
@@ -2890,7 +3542,7 @@ fn test_transfer_ticket_resale_price_exceeds_cap() {
 
     // Sale price above the cap: 1200 USDC > 1100 USDC max
     let sale_price = Some(1200_0000000i128);
-    let result = client.try_transfer_ticket(&payment_id, &new_owner, &sale_price);
+    let result = client.try_transfer_ticket(&payment_id, &new_owner, &sale_price, &None);
     assert_eq!(result, Err(Ok(TicketPaymentError::ResalePriceExceedsCap)));
 
     // Verify ticket was NOT transferred
@@ -2899,13 +3551,59 @@ fn test_transfer_ticket_resale_price_exceeds_cap() {
 }
 
 #[test]
-fn test_transfer_ticket_no_sale_price_with_cap() {
+fn test_list_ticket_resale_cap_uses_listing_token_price() {
     let env = Env::default();
     env.mock_all_auths();
-    let (client, _admin, _usdc_id, _, _) = setup_test_with_resale_cap(&env);
+    let (client, _admin, usdc_id, _, registry_id) = setup_test_with_resale_cap(&env);
+    let alt_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_token(&alt_id);
+    env.as_contract(&registry_id, || {
+        env.storage()
+            .instance()
+            .set(&soroban_sdk::Symbol::new(&env, "alt_token"), &alt_id);
+    });
 
-    let buyer = Address::generate(&env);
-    let new_owner = Address::generate(&env);
+    let payment_id = String::from_str(&env, "pay_cap_alt");
+    let payment = Payment {
+        payment_id: payment_id.clone(),
+        event_id: String::from_str(&env, "event_capped"),
+        buyer_address: Address::generate(&env),
+        ticket_tier_id: String::from_str(&env, "general"),
+        amount: 1000_0000000,
+        token_address: usdc_id.clone(),
+        platform_fee: 50_0000000,
+        organizer_amount: 950_0000000,
+        status: PaymentStatus::Confirmed,
+        transaction_hash: String::from_str(&env, "tx_alt"),
+        created_at: 100,
+        confirmed_at: Some(101),
+        refunded_amount: 0,
+        identity_hash: None,
+        memo: None,
+        seat_id: None,
+    };
+    env.as_contract(&client.address, || {
+        store_payment(&env, payment);
+    });
+
+    // 12 is far below the default-token cap but above 10 * 110% in the listing token
+    assert_eq!(
+        client.try_list_ticket_for_sale(&payment_id, &12_0000000, &alt_id),
+        Err(Ok(TicketPaymentError::ResalePriceExceedsCap))
+    );
+    client.list_ticket_for_sale(&payment_id, &11_0000000, &alt_id);
+}
+
+#[test]
+fn test_transfer_ticket_no_sale_price_with_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, usdc_id, _, _) = setup_test_with_resale_cap(&env);
+
+    let buyer = Address::generate(&env);
+    let new_owner = Address::generate(&env);
     let payment_id = String::from_str(&env, "pay_cap_3");
 
     let payment = Payment {
@@ -2914,6 +3612,7 @@ fn test_transfer_ticket_no_sale_price_with_cap() {
         buyer_address: buyer.clone(),
         ticket_tier_id: String::from_str(&env, "general"),
         amount: 1000_0000000,
+        token_address: usdc_id.clone(),
         platform_fee: 50_0000000,
         organizer_amount: 950_0000000,
         status: PaymentStatus::Confirmed,
@@ -2921,6 +3620,9 @@ fn test_transfer_ticket_no_sale_price_with_cap() {
         created_at: 100,
         confirmed_at: Some(101),
         refunded_amount: 0,
+        identity_hash: None,
+        memo: None,
+        seat_id: None,
     };
 
     env.as_contract(&client.address, || {
@@ -2928,7 +3630,7 @@ fn test_transfer_ticket_no_sale_price_with_cap() {
     });
 
     // No sale price (gift/free transfer) should always succeed
-    client.transfer_ticket(&payment_id, &new_owner, &None);
+    client.transfer_ticket(&payment_id, &new_owner, &None, &None);
 
     let updated = client.get_payment_status(&payment_id).unwrap();
     assert_eq!(updated.buyer_address, new_owner);
@@ -2939,7 +3641,7 @@ fn test_transfer_ticket_sale_price_no_cap() {
     let env = Env::default();
     env.mock_all_auths();
     // Use the default mock registry which has resale_cap_bps: None
-    let (client, _admin, _usdc_id, _, _) = setup_test(&env);
+    let (client, _admin, usdc_id, _, _) = setup_test(&env);
 
     let buyer = Address::generate(&env);
     let new_owner = Address::generate(&env);
@@ -2951,6 +3653,7 @@ fn test_transfer_ticket_sale_price_no_cap() {
         buyer_address: buyer.clone(),
         ticket_tier_id: String::from_str(&env, "tier_1"),
         amount: 1000_0000000,
+        token_address: usdc_id.clone(),
         platform_fee: 50_0000000,
         organizer_amount: 950_0000000,
         status: PaymentStatus::Confirmed,
@@ -2958,6 +3661,9 @@ fn test_transfer_ticket_sale_price_no_cap() {
         created_at: 100,
         confirmed_at: Some(101),
         refunded_amount: 0,
+        identity_hash: None,
+        memo: None,
+        seat_id: None,
     };
 
     env.as_contract(&client.address, || {
@@ -2965,8 +3671,10 @@ fn test_transfer_ticket_sale_price_no_cap() {
     });
 
     // Any sale price should be allowed when no cap is set
-    let sale_price = Some(5000_0000000i128); // 5x the original price
-    client.transfer_ticket(&payment_id, &new_owner, &sale_price);
+    let price = 5000_0000000i128; // 5x the original price
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&new_owner, &price);
+    token::Client::new(&env, &usdc_id).approve(&new_owner, &client.address, &price, &99999);
+    client.transfer_ticket(&payment_id, &new_owner, &Some(price), &None);
 
     let updated = client.get_payment_status(&payment_id).unwrap();
     assert_eq!(updated.buyer_address, new_owner);
@@ -2982,6 +3690,9 @@ impl MockRegistryZeroCap {
         event_registry::PaymentInfo {
             payment_address: Address::generate(&env),
             platform_fee_percent: 500,
+            tiers: soroban_sdk::Map::new(&env),
+            policy_flags: 0,
+            resale_royalty_bps: 0,
         }
     }
 
@@ -3010,10 +3721,17 @@ impl MockRegistryZeroCap {
                         price: 1000_0000000i128,
                         early_bird_price: 0,
                         early_bird_deadline: 0,
+                        early_bird_quantity: 0,
                         usd_price: 0,
                         tier_limit: 100,
                         current_sold: 0,
                         is_refundable: true,
+                        visible_from: 0,
+                        visible_until: 0,
+                        token_prices: soroban_sdk::Map::new(&env),
+                        deposit_amount: 0,
+                        pricing: event_registry::TierPricing::Fixed,
+                        bulk_discounts: soroban_sdk::Map::new(&env),
                     },
                 );
                 tiers
@@ -3021,9 +3739,17 @@ impl MockRegistryZeroCap {
             refund_deadline: 0,
             restocking_fee: 0,
             resale_cap_bps: Some(0), // No markup allowed
+            is_postponed: false,
+            grace_period_end: 0,
             min_sales_target: 0,
             target_deadline: 0,
             goal_met: false,
+            visibility: event_registry::EventVisibility::Public,
+            verified: false,
+            max_tickets_per_buyer: 0,
+            resale_royalty_bps: 0,
+            event_start_time: 0,
+            transfer_lock_before_secs: 0,
         })
     }
 
@@ -3069,6 +3795,7 @@ fn test_request_guest_refund_success_with_fee() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     // Initial escrow: 1000 total. Platform fee 5% = 50. Organizer = 950.
@@ -3124,6 +3851,7 @@ fn test_request_guest_refund_deadline_passed() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     let res = client.try_request_guest_refund(&payment_id);
@@ -3153,6 +3881,7 @@ fn test_platform_fee_withdrawal_with_cap() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     let expected_fee = (amount * 500) / 10000; // 50 USDC
@@ -3229,6 +3958,7 @@ fn test_process_payment_paused() {
         &1,
         &None,
         &None,
+        &None,
     );
     assert_eq!(res, Err(Ok(TicketPaymentError::ContractPaused)));
 }
@@ -3260,7 +3990,7 @@ fn test_transfer_ticket_paused() {
     let (client, _admin, _, _, _) = setup_test(&env);
     client.set_pause(&true);
     let to = Address::generate(&env);
-    let res = client.try_transfer_ticket(&String::from_str(&env, "p1"), &to, &None);
+    let res = client.try_transfer_ticket(&String::from_str(&env, "p1"), &to, &None, &None);
     assert_eq!(res, Err(Ok(TicketPaymentError::ContractPaused)));
 }
 
@@ -3343,6 +4073,7 @@ fn test_claim_automatic_refund_success() {
         buyer_address: buyer.clone(),
         ticket_tier_id: String::from_str(&env, "tier_1"),
         amount: 1000,
+        token_address: usdc_id.clone(),
         platform_fee: 50,
         organizer_amount: 950,
         status: PaymentStatus::Confirmed,
@@ -3350,11 +4081,14 @@ fn test_claim_automatic_refund_success() {
         created_at: 100,
         confirmed_at: Some(101),
         refunded_amount: 0,
+        identity_hash: None,
+        memo: None,
+        seat_id: None,
     };
 
     env.as_contract(&client.address, || {
         store_payment(&env, payment);
-        update_event_balance(&env, String::from_str(&env, "e1"), 950, 50);
+        update_event_balance(&env, String::from_str(&env, "e1"), &usdc_id, 950, 50);
     });
 
     // Mint tokens to contract for refund
@@ -3397,6 +4131,7 @@ fn test_dispute_blocks_withdrawal() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     // Set event as disputed
@@ -3441,6 +4176,7 @@ fn test_admin_refund_during_dispute() {
         &1,
         &None,
         &None,
+        &None,
     );
 
     // Set event as disputed
@@ -3497,6 +4233,9 @@ impl MockEventRegistryUsdPriced {
         event_registry::PaymentInfo {
             payment_address: Address::generate(&env),
             platform_fee_percent: 500, // 5%
+            tiers: soroban_sdk::Map::new(&env),
+            policy_flags: 0,
+            resale_royalty_bps: 0,
         }
     }
 
@@ -3525,10 +4264,17 @@ impl MockEventRegistryUsdPriced {
                         price: 0,
                         early_bird_price: 0,
                         early_bird_deadline: 0,
+                        early_bird_quantity: 0,
                         usd_price: 100_0000000, // $100 USD in 7-decimal fixed-point
                         tier_limit: 100,
                         current_sold: 0,
                         is_refundable: true,
+                        visible_from: 0,
+                        visible_until: 0,
+                        token_prices: soroban_sdk::Map::new(&env),
+                        deposit_amount: 0,
+                        pricing: event_registry::TierPricing::Fixed,
+                        bulk_discounts: soroban_sdk::Map::new(&env),
                     },
                 );
                 tiers
@@ -3536,9 +4282,17 @@ impl MockEventRegistryUsdPriced {
             refund_deadline: 0,
             restocking_fee: 0,
             resale_cap_bps: None,
+            is_postponed: false,
+            grace_period_end: 0,
             min_sales_target: 0,
             target_deadline: 0,
             goal_met: false,
+            visibility: event_registry::EventVisibility::Public,
+            verified: false,
+            max_tickets_per_buyer: 0,
+            resale_royalty_bps: 0,
+            event_start_time: 0,
+            transfer_lock_before_secs: 0,
         })
     }
 
@@ -3609,6 +4363,7 @@ fn test_usd_priced_payment_success() {
         &1,
         &None,
         &None,
+        &None,
     );
     assert!(result.is_ok());
 }
@@ -3637,6 +4392,7 @@ fn test_usd_priced_payment_within_slippage() {
         &1,
         &None,
         &None,
+        &None,
     );
     assert!(result.is_ok());
 }
@@ -3665,6 +4421,7 @@ fn test_usd_priced_payment_above_slippage_fails() {
         &1,
         &None,
         &None,
+        &None,
     );
     assert_eq!(result, Err(Ok(TicketPaymentError::PriceOutsideSlippage)));
 }
@@ -3693,6 +4450,7 @@ fn test_usd_priced_payment_below_slippage_fails() {
         &1,
         &None,
         &None,
+        &None,
     );
     assert_eq!(result, Err(Ok(TicketPaymentError::PriceOutsideSlippage)));
 }
@@ -3730,6 +4488,7 @@ fn test_usd_priced_oracle_not_configured() {
         &1,
         &None,
         &None,
+        &None,
     );
     assert_eq!(result, Err(Ok(TicketPaymentError::OracleNotConfigured)));
 }
@@ -3769,6 +4528,7 @@ fn test_usd_priced_oracle_unavailable() {
         &1,
         &None,
         &None,
+        &None,
     );
     assert_eq!(result, Err(Ok(TicketPaymentError::OraclePriceUnavailable)));
 }
@@ -3796,6 +4556,7 @@ fn test_token_priced_payment_unchanged() {
         &1,
         &None,
         &None,
+        &None,
     );
     assert!(result.is_ok());
 }
@@ -3846,3 +4607,1079 @@ fn test_get_asset_price_returns_oracle_price() {
     assert_eq!(price_data.price, 8_3333333);
     assert_eq!(price_data.timestamp, 1000);
 }
+
+/// Mock oracle with a configurable rate per asset.
+#[soroban_sdk::contract]
+pub struct MockPriceOracleRates;
+
+#[soroban_sdk::contractimpl]
+impl MockPriceOracleRates {
+    pub fn lastprice(env: Env, asset: Address) -> Option<price_oracle::PriceData> {
+        env.storage()
+            .instance()
+            .get(&asset)
+            .map(|price| price_oracle::PriceData {
+                price,
+                timestamp: 1000,
+            })
+    }
+
+    pub fn set_rate(env: Env, asset: Address, price: i128) {
+        env.storage().instance().set(&asset, &price);
+    }
+}
+
+/// Mock token reporting 18 decimals, for quoting only.
+#[soroban_sdk::contract]
+pub struct MockToken18;
+
+#[soroban_sdk::contractimpl]
+impl MockToken18 {
+    pub fn decimals(_env: Env) -> u32 {
+        18
+    }
+}
+
+// 11. Default-token prices convert at the oracle cross rate within the buyer's bounds
+#[test]
+fn test_default_token_price_converted_at_cross_rate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _reg) = setup_test(&env);
+    let xlm_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let wide_id = env.register(MockToken18, ());
+    client.add_token(&xlm_id);
+    client.add_token(&wide_id);
+
+    let event_id = String::from_str(&env, "event_1");
+    let tier_id = String::from_str(&env, "tier_1");
+
+    // Without an oracle other tokens pay the tier price as-is
+    assert_eq!(
+        client.quote_ticket_price(&event_id, &tier_id, &xlm_id),
+        1000_0000000
+    );
+
+    // USDC at $0.99, XLM at $0.12
+    let oracle_id = env.register(MockPriceOracleRates, ());
+    let oracle = MockPriceOracleRatesClient::new(&env, &oracle_id);
+    client.set_oracle(&oracle_id);
+    assert_eq!(
+        client.try_quote_ticket_price(&event_id, &tier_id, &xlm_id),
+        Err(Ok(TicketPaymentError::OraclePriceUnavailable))
+    );
+    oracle.set_rate(&usdc_id, &1_0101010);
+    oracle.set_rate(&xlm_id, &8_3333333);
+    oracle.set_rate(&wide_id, &8_3333333);
+
+    // 1000 USDC * 8.3333333 / 1.0101010 XLM, rescaled for 18-decimal tokens
+    let xlm_price = 8250_0000495i128;
+    assert_eq!(
+        client.quote_ticket_price(&event_id, &tier_id, &xlm_id),
+        xlm_price
+    );
+    assert_eq!(
+        client.quote_ticket_price(&event_id, &tier_id, &wide_id),
+        xlm_price * 10i128.pow(11)
+    );
+    // The default token itself keeps its exact price
+    assert_eq!(
+        client.quote_ticket_price(&event_id, &tier_id, &usdc_id),
+        1000_0000000
+    );
+
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &xlm_id).mint(&buyer, &(xlm_price * 3));
+    token::Client::new(&env, &xlm_id).approve(&buyer, &client.address, &(xlm_price * 3), &99999);
+    let pay = |payment_id: &str, amount: i128, max_slippage_bps: Option<u32>| {
+        client.try_process_payment(
+            &String::from_str(&env, payment_id),
+            &event_id,
+            &tier_id,
+            &buyer,
+            &xlm_id,
+            &amount,
+            &1,
+            &None,
+            &None,
+            &Some(PurchaseOptions {
+                identity_hash: None,
+                memo: None,
+                registry: None,
+                seats: soroban_sdk::Vec::new(&env),
+                max_slippage_bps,
+                voucher_code: None,
+                promoter: None,
+            }),
+        )
+    };
+
+    // 1% over is inside the platform's 2% but outside the buyer's 0.5%
+    let one_percent_over = xlm_price + xlm_price / 100;
+    assert_eq!(
+        pay("pay_xlm_1", one_percent_over, Some(50)),
+        Err(Ok(TicketPaymentError::PriceOutsideSlippage))
+    );
+    // A buyer bound never widens the platform's tolerance
+    assert_eq!(
+        pay("pay_xlm_1", xlm_price + xlm_price / 20, Some(1000)),
+        Err(Ok(TicketPaymentError::PriceOutsideSlippage))
+    );
+    assert!(pay("pay_xlm_1", one_percent_over, None).is_ok());
+    assert!(pay("pay_xlm_2", xlm_price, Some(0)).is_ok());
+}
+
+#[test]
+fn test_refund_processing_fee_charged_on_guest_refund() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let contract_id = env.register(TicketPaymentContract, ());
+    let client = TicketPaymentContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let usdc_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let platform_wallet = Address::generate(&env);
+    let registry_id = env.register(MockEventRegistryRefund, ());
+
+    client.initialize(&admin, &usdc_id, &platform_wallet, &registry_id);
+
+    // 1% + 5 fixed = 15 on a 1000 ticket
+    client.set_refund_processing_fee(&100, &5);
+
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &1000);
+    token::Client::new(&env, &usdc_id).approve(&buyer, &client.address, &1000, &9999);
+
+    let payment_id = String::from_str(&env, "p1");
+    client.process_payment(
+        &payment_id,
+        &String::from_str(&env, "e1"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &1000,
+        &1,
+        &None,
+        &None,
+        &None,
+    );
+
+    client.request_guest_refund(&payment_id);
+
+    // Guest gets 1000 - 100 restocking - 15 processing = 885.
+    let buyer_balance = token::Client::new(&env, &usdc_id).balance(&buyer);
+    assert_eq!(buyer_balance, 885);
+
+    // Organizer keeps only the restocking fee; processing fee goes to platform accounting.
+    let balance = client.get_event_escrow_balance(&String::from_str(&env, "e1"));
+    assert_eq!(balance.organizer_amount, 100);
+    assert_eq!(client.get_refund_fees_collected(&usdc_id), 15);
+    assert_eq!(client.get_total_fees_collected(&usdc_id), 50 + 15);
+    // Neither the refund nor the processing fee is still held in escrow
+    assert_eq!(client.get_active_escrow_total_by_token(&usdc_id), 100);
+}
+
+#[test]
+fn test_refund_processing_fee_not_charged_on_cancellation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(TicketPaymentContract, ());
+    let client = TicketPaymentContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let usdc_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let platform_wallet = Address::generate(&env);
+
+    let registry_id = env.register(MockCancelledRegistry, ());
+    client.initialize(&admin, &usdc_id, &platform_wallet, &registry_id);
+    client.set_refund_processing_fee(&500, &10);
+
+    let buyer = Address::generate(&env);
+    let payment_id = String::from_str(&env, "p1");
+    let payment = Payment {
+        payment_id: payment_id.clone(),
+        event_id: String::from_str(&env, "e1"),
+        buyer_address: buyer.clone(),
+        ticket_tier_id: String::from_str(&env, "tier_1"),
+        amount: 1000,
+        token_address: usdc_id.clone(),
+        platform_fee: 50,
+        organizer_amount: 950,
+        status: PaymentStatus::Confirmed,
+        transaction_hash: String::from_str(&env, "tx"),
+        created_at: 100,
+        confirmed_at: Some(101),
+        refunded_amount: 0,
+        identity_hash: None,
+        memo: None,
+        seat_id: None,
+    };
+
+    env.as_contract(&client.address, || {
+        store_payment(&env, payment);
+        update_event_balance(&env, String::from_str(&env, "e1"), &usdc_id, 950, 50);
+    });
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&client.address, &1000);
+
+    // Guest-initiated refund on a cancelled event is still fee-free.
+    client.request_guest_refund(&payment_id);
+
+    let buyer_balance = token::Client::new(&env, &usdc_id).balance(&buyer);
+    assert_eq!(buyer_balance, 1000);
+    assert_eq!(client.get_refund_fees_collected(&usdc_id), 0);
+}
+
+#[test]
+fn test_set_refund_processing_fee_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, _usdc_id, _platform_wallet, _) = setup_test(&env);
+
+    assert_eq!(
+        client.try_set_refund_processing_fee(&10001, &0),
+        Err(Ok(TicketPaymentError::InvalidFeeBps))
+    );
+    assert_eq!(
+        client.try_set_refund_processing_fee(&0, &-1),
+        Err(Ok(TicketPaymentError::ArithmeticError))
+    );
+
+    client.set_refund_processing_fee(&250, &0);
+    let fee = client.get_refund_processing_fee();
+    assert_eq!(fee.bps, 250);
+    assert_eq!(fee.fixed_amount, 0);
+}
+
+#[test]
+fn test_promo_stats_track_global_promo_and_discount_codes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _organizer, registry_id, usdc_id) = setup_discount_test(&env);
+    MockEventRegistryWithOrganizerClient::new(&env, &registry_id).set_promo(&1000, &5000);
+
+    let event_id = String::from_str(&env, "event_1");
+    let preimage = Bytes::from_slice(&env, b"SUMMER10");
+    let valid_hash: soroban_sdk::BytesN<32> = env.crypto().sha256(&preimage).into();
+    client.add_discount_hashes(&event_id, &soroban_sdk::vec![&env, valid_hash]);
+
+    let buyer = Address::generate(&env);
+    let full_amount = 1000_0000000i128;
+    // 10% global promo, then 10% code discount on the promo price
+    let paid = 810_0000000i128;
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &paid);
+    token::Client::new(&env, &usdc_id).approve(&buyer, &client.address, &paid, &99999);
+
+    client.process_payment(
+        &String::from_str(&env, "pay_promo"),
+        &event_id,
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &full_amount,
+        &1,
+        &Some(preimage),
+        &None,
+        &None,
+    );
+
+    let global = client.get_promo_stats(&PromoScope::Global(5000));
+    assert_eq!(global.payment_count, 1);
+    assert_eq!(global.gross_discount, 100_0000000);
+    assert_eq!(global.net_revenue, paid);
+
+    let codes = client.get_promo_stats(&PromoScope::Event(event_id));
+    assert_eq!(codes.payment_count, 1);
+    assert_eq!(codes.gross_discount, 90_0000000);
+    assert_eq!(codes.net_revenue, paid);
+
+    let unused = client.get_promo_stats(&PromoScope::Global(1));
+    assert_eq!(unused.payment_count, 0);
+}
+
+/// Mock registry returning a VIP tier that is only on sale between t=2000 and t=3000.
+#[soroban_sdk::contract]
+pub struct MockEventRegistryStagedTier;
+
+#[soroban_sdk::contractimpl]
+impl MockEventRegistryStagedTier {
+    pub fn get_event_payment_info(env: Env, _event_id: String) -> event_registry::PaymentInfo {
+        event_registry::PaymentInfo {
+            payment_address: Address::generate(&env),
+            platform_fee_percent: 500,
+            tiers: soroban_sdk::Map::new(&env),
+            policy_flags: 0,
+            resale_royalty_bps: 0,
+        }
+    }
+
+    pub fn get_event(env: Env, event_id: String) -> Option<event_registry::EventInfo> {
+        Some(event_registry::EventInfo {
+            event_id,
+            organizer_address: Address::generate(&env),
+            payment_address: Address::generate(&env),
+            platform_fee_percent: 500,
+            is_active: true,
+            status: event_registry::EventStatus::Active,
+            created_at: 0,
+            metadata_cid: String::from_str(&env, "cid"),
+            max_supply: 0,
+            current_supply: 0,
+            milestone_plan: None,
+            tiers: {
+                let mut tiers = soroban_sdk::Map::new(&env);
+                tiers.set(
+                    String::from_str(&env, "vip"),
+                    event_registry::TicketTier {
+                        name: String::from_str(&env, "VIP"),
+                        price: 1000,
+                        early_bird_price: 1000,
+                        early_bird_deadline: 0,
+                        early_bird_quantity: 0,
+                        usd_price: 0,
+                        tier_limit: 100,
+                        current_sold: 0,
+                        is_refundable: true,
+                        visible_from: 2000,
+                        visible_until: 3000,
+                        token_prices: soroban_sdk::Map::new(&env),
+                        deposit_amount: 0,
+                        pricing: event_registry::TierPricing::Fixed,
+                        bulk_discounts: soroban_sdk::Map::new(&env),
+                    },
+                );
+                tiers
+            },
+            refund_deadline: 0,
+            restocking_fee: 0,
+            resale_cap_bps: None,
+            is_postponed: false,
+            grace_period_end: 0,
+            min_sales_target: 0,
+            target_deadline: 0,
+            goal_met: false,
+            visibility: event_registry::EventVisibility::Public,
+            verified: false,
+            max_tickets_per_buyer: 0,
+            resale_royalty_bps: 0,
+            event_start_time: 0,
+            transfer_lock_before_secs: 0,
+        })
+    }
+
+    pub fn increment_inventory(_env: Env, _event_id: String, _tier_id: String, _quantity: u32) {}
+    pub fn decrement_inventory(_env: Env, _event_id: String, _tier_id: String) {}
+    pub fn get_global_promo_bps(_env: Env) -> u32 {
+        0
+    }
+    pub fn get_promo_expiry(_env: Env) -> u64 {
+        0
+    }
+}
+
+#[test]
+fn test_process_payment_respects_tier_visibility_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(TicketPaymentContract, ());
+    let client = TicketPaymentContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let usdc_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let platform_wallet = Address::generate(&env);
+    let registry_id = env.register(MockEventRegistryStagedTier, ());
+    client.initialize(&admin, &usdc_id, &platform_wallet, &registry_id);
+
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &10_000);
+    token::Client::new(&env, &usdc_id).approve(&buyer, &client.address, &10_000, &99999);
+
+    let event_id = String::from_str(&env, "event_1");
+    let tier_id = String::from_str(&env, "vip");
+
+    // Not yet revealed
+    env.ledger().set_timestamp(1000);
+    let result = client.try_process_payment(
+        &String::from_str(&env, "pay_early"),
+        &event_id,
+        &tier_id,
+        &buyer,
+        &usdc_id,
+        &1000,
+        &1,
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(TicketPaymentError::TierNotVisible)));
+
+    // Inside the window
+    env.ledger().set_timestamp(2500);
+    client.process_payment(
+        &String::from_str(&env, "pay_open"),
+        &event_id,
+        &tier_id,
+        &buyer,
+        &usdc_id,
+        &1000,
+        &1,
+        &None,
+        &None,
+        &None,
+    );
+
+    // Hidden again
+    env.ledger().set_timestamp(3000);
+    let result = client.try_process_payment(
+        &String::from_str(&env, "pay_late"),
+        &event_id,
+        &tier_id,
+        &buyer,
+        &usdc_id,
+        &1000,
+        &1,
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(TicketPaymentError::TierNotVisible)));
+}
+
+#[test]
+fn test_contract_metadata_reports_registry_counterpart() {
+    let env = Env::default();
+    let contract_id = env.register(TicketPaymentContract, ());
+    let client = TicketPaymentContractClient::new(&env, &contract_id);
+
+    let metadata = client.get_contract_metadata();
+    assert_eq!(
+        metadata.name,
+        String::from_str(&env, "agora-ticket-payment")
+    );
+    assert_eq!(metadata.interface_revision, 2);
+    assert_eq!(metadata.counterpart, None);
+
+    let admin = Address::generate(&env);
+    let usdc_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let platform_wallet = Address::generate(&env);
+    let event_registry_id = env.register(MockEventRegistry, ());
+    client.initialize(&admin, &usdc_id, &platform_wallet, &event_registry_id);
+
+    let metadata = client.get_contract_metadata();
+    assert_eq!(metadata.counterpart, Some(event_registry_id));
+}
+
+/// Mock registry whose tier has a fallback price of 500, plus per-token prices
+/// and a flash sale configured through `set_token_price` / `set_flash_sale`.
+#[soroban_sdk::contract]
+pub struct MockEventRegistryConfigurableTier;
+
+#[soroban_sdk::contractimpl]
+impl MockEventRegistryConfigurableTier {
+    pub fn set_token_price(env: Env, token: Address, price: i128) {
+        let key = Symbol::new(&env, "prices");
+        let mut prices: soroban_sdk::Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&key)
+            .unwrap_or_else(|| soroban_sdk::Map::new(&env));
+        prices.set(token, price);
+        env.storage().instance().set(&key, &prices);
+    }
+
+    pub fn set_flash_sale(env: Env, discount_bps: u32, start: u64, end: u64) {
+        env.storage().instance().set(
+            &Symbol::new(&env, "flash"),
+            &event_registry::FlashSale {
+                discount_bps,
+                start,
+                end,
+            },
+        );
+    }
+
+    pub fn get_flash_sale(
+        env: Env,
+        _event_id: String,
+        _tier_id: String,
+    ) -> Option<event_registry::FlashSale> {
+        env.storage().instance().get(&Symbol::new(&env, "flash"))
+    }
+
+    pub fn get_event(env: Env, event_id: String) -> Option<event_registry::EventInfo> {
+        let token_prices = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "prices"))
+            .unwrap_or_else(|| soroban_sdk::Map::new(&env));
+        Some(event_registry::EventInfo {
+            event_id,
+            organizer_address: Address::generate(&env),
+            payment_address: Address::generate(&env),
+            platform_fee_percent: 500,
+            is_active: true,
+            status: event_registry::EventStatus::Active,
+            created_at: 0,
+            metadata_cid: String::from_str(&env, "cid"),
+            max_supply: 0,
+            current_supply: 0,
+            milestone_plan: None,
+            tiers: {
+                let mut tiers = soroban_sdk::Map::new(&env);
+                tiers.set(
+                    String::from_str(&env, "tier_1"),
+                    event_registry::TicketTier {
+                        name: String::from_str(&env, "General"),
+                        price: 500,
+                        early_bird_price: 500,
+                        early_bird_deadline: 0,
+                        early_bird_quantity: 0,
+                        usd_price: 0,
+                        tier_limit: 100,
+                        current_sold: 0,
+                        is_refundable: true,
+                        visible_from: 0,
+                        visible_until: 0,
+                        token_prices,
+                        deposit_amount: 0,
+                        pricing: event_registry::TierPricing::Fixed,
+                        bulk_discounts: soroban_sdk::Map::new(&env),
+                    },
+                );
+                tiers
+            },
+            refund_deadline: 0,
+            restocking_fee: 0,
+            resale_cap_bps: None,
+            is_postponed: false,
+            grace_period_end: 0,
+            min_sales_target: 0,
+            target_deadline: 0,
+            goal_met: false,
+            visibility: event_registry::EventVisibility::Public,
+            verified: false,
+            max_tickets_per_buyer: 0,
+            resale_royalty_bps: 0,
+            event_start_time: 0,
+            transfer_lock_before_secs: 0,
+        })
+    }
+
+    pub fn increment_inventory(_env: Env, _event_id: String, _tier_id: String, _quantity: u32) {}
+    pub fn get_global_promo_bps(_env: Env) -> u32 {
+        0
+    }
+    pub fn get_promo_expiry(_env: Env) -> u64 {
+        0
+    }
+}
+
+#[test]
+fn test_process_payment_uses_token_specific_tier_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(TicketPaymentContract, ());
+    let client = TicketPaymentContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let usdc_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let xlm_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let eurc_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let platform_wallet = Address::generate(&env);
+    let registry_id = env.register(MockEventRegistryConfigurableTier, ());
+    client.initialize(&admin, &usdc_id, &platform_wallet, &registry_id);
+    client.add_token(&xlm_id);
+    client.add_token(&eurc_id);
+
+    let registry = MockEventRegistryConfigurableTierClient::new(&env, &registry_id);
+    registry.set_token_price(&usdc_id, &100);
+    registry.set_token_price(&xlm_id, &1200);
+
+    let buyer = Address::generate(&env);
+    for token_id in [&usdc_id, &xlm_id, &eurc_id] {
+        token::StellarAssetClient::new(&env, token_id).mint(&buyer, &10_000);
+        token::Client::new(&env, token_id).approve(&buyer, &client.address, &10_000, &99999);
+    }
+
+    let event_id = String::from_str(&env, "event_1");
+    let tier_id = String::from_str(&env, "tier_1");
+    let pay = |payment_id: &str, token_id: &Address, amount: i128| {
+        client.try_process_payment(
+            &String::from_str(&env, payment_id),
+            &event_id,
+            &tier_id,
+            &buyer,
+            token_id,
+            &amount,
+            &1,
+            &None,
+            &None,
+            &None,
+        )
+    };
+
+    // Tokens with an explicit price must pay exactly that price
+    assert_eq!(
+        pay("pay_usdc_bad", &usdc_id, 500),
+        Err(Ok(TicketPaymentError::InvalidPrice))
+    );
+    assert!(pay("pay_usdc", &usdc_id, 100).is_ok());
+    assert!(pay("pay_xlm", &xlm_id, 1200).is_ok());
+
+    // Other tokens fall back to the tier's single price
+    assert_eq!(
+        pay("pay_eurc_bad", &eurc_id, 100),
+        Err(Ok(TicketPaymentError::InvalidPrice))
+    );
+    assert!(pay("pay_eurc", &eurc_id, 500).is_ok());
+}
+
+#[test]
+fn test_flash_sale_discount_applies_only_inside_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(TicketPaymentContract, ());
+    let client = TicketPaymentContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let usdc_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let platform_wallet = Address::generate(&env);
+    let registry_id = env.register(MockEventRegistryConfigurableTier, ());
+    client.initialize(&admin, &usdc_id, &platform_wallet, &registry_id);
+
+    // 20% off between t=1000 and t=2000
+    MockEventRegistryConfigurableTierClient::new(&env, &registry_id)
+        .set_flash_sale(&2000, &1000, &2000);
+
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &10_000);
+    token::Client::new(&env, &usdc_id).approve(&buyer, &client.address, &10_000, &99999);
+
+    let event_id = String::from_str(&env, "event_1");
+    let tier_id = String::from_str(&env, "tier_1");
+    let pay = |payment_id: &str, amount: i128| {
+        client.try_process_payment(
+            &String::from_str(&env, payment_id),
+            &event_id,
+            &tier_id,
+            &buyer,
+            &usdc_id,
+            &amount,
+            &1,
+            &None,
+            &None,
+            &None,
+        )
+    };
+    let sale_state = || {
+        env.as_contract(&client.address, || {
+            get_flash_sale_state(&env, event_id.clone(), tier_id.clone())
+        })
+    };
+
+    env.ledger().set_timestamp(500);
+    assert!(pay("pay_before", 500).is_ok());
+    assert_eq!(sale_state(), None);
+
+    env.ledger().set_timestamp(1500);
+    assert_eq!(
+        pay("pay_full_price", 500),
+        Err(Ok(TicketPaymentError::InvalidPrice))
+    );
+    assert!(pay("pay_flash", 400).is_ok());
+    let state = sale_state().unwrap();
+    assert_eq!(state.start, 1000);
+    assert!(!state.ended);
+
+    // Regular price is restored without any further configuration
+    env.ledger().set_timestamp(2000);
+    assert_eq!(
+        pay("pay_stale_flash", 400),
+        Err(Ok(TicketPaymentError::InvalidPrice))
+    );
+    assert!(pay("pay_after", 500).is_ok());
+    assert!(sale_state().unwrap().ended);
+}
+
+#[test]
+fn test_compute_pricing_breakdown_golden_values() {
+    use super::contract::compute_pricing_breakdown;
+
+    // (unit_price, quantity, promo_bps, code_bps, fee_bps, referral_bps, platform_absorbs_promo)
+    type Inputs = (i128, u32, u32, u32, u32, u32, bool);
+    // (gross, promo_reduction, code_discount, net_paid, platform_fee, referral_cut, organizer_net)
+    #[rustfmt::skip]
+    let cases: [(Inputs, [i128; 7]); 12] = [
+        ((1000, 1, 0, 0, 500, 0, false),          [1000, 0, 0, 1000, 50, 0, 950]),
+        ((1000, 1, 0, 0, 500, 2000, false),       [1000, 0, 0, 1000, 40, 10, 950]),
+        ((1000, 2, 1000, 0, 500, 0, false),       [2000, 200, 0, 1800, 90, 0, 1710]),
+        ((1000, 1, 0, 1000, 500, 0, false),       [1000, 0, 100, 900, 45, 0, 855]),
+        ((1000, 3, 2500, 1000, 500, 2000, false), [3000, 750, 225, 2025, 81, 20, 1924]),
+        ((999, 1, 333, 1000, 250, 2000, false),   [999, 34, 97, 868, 17, 4, 847]),
+        ((500, 4, 0, 0, 0, 2000, false),          [2000, 0, 0, 2000, 0, 0, 2000]),
+        ((1000, 1, 10000, 0, 500, 2000, false),   [1000, 1000, 0, 0, 0, 0, 0]),
+        ((1, 1, 0, 0, 500, 2000, false),          [1, 0, 0, 1, 0, 0, 1]),
+        ((1000, 1, 1000, 0, 500, 0, true),        [1000, 50, 0, 950, 0, 0, 950]),
+        ((1000, 1, 200, 0, 500, 2000, true),      [1000, 20, 0, 980, 24, 6, 950]),
+        ((1000, 1, 1000, 1000, 500, 0, true),     [1000, 55, 90, 855, 0, 0, 855]),
+    ];
+
+    for ((unit, qty, promo, code, fee, referral, absorbed), expected) in cases {
+        let b = compute_pricing_breakdown(unit, qty, promo, code, fee, referral, absorbed).unwrap();
+        assert_eq!(
+            [
+                b.gross,
+                b.promo_reduction,
+                b.code_discount,
+                b.net_paid,
+                b.platform_fee,
+                b.referral_cut,
+                b.organizer_net,
+            ],
+            expected,
+            "case {:?}",
+            (unit, qty, promo, code, fee, referral, absorbed)
+        );
+        assert_eq!(
+            b.net_paid,
+            b.platform_fee + b.referral_cut + b.organizer_net
+        );
+        assert_eq!(b.gross, b.net_paid + b.promo_reduction + b.code_discount);
+    }
+
+    assert_eq!(
+        compute_pricing_breakdown(i128::MAX, 2, 0, 0, 500, 0, false),
+        Err(TicketPaymentError::ArithmeticError)
+    );
+}
+
+/// Early-bird tier limited to 2 tickets; `current_sold` tracks real purchases.
+#[soroban_sdk::contract]
+pub struct MockEventRegistryEarlyBirdQuantity;
+
+#[soroban_sdk::contractimpl]
+impl MockEventRegistryEarlyBirdQuantity {
+    pub fn get_event(env: Env, event_id: String) -> Option<event_registry::EventInfo> {
+        let current_sold: i128 = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "sold"))
+            .unwrap_or(0);
+        let mut tiers = soroban_sdk::Map::new(&env);
+        tiers.set(
+            String::from_str(&env, "tier_1"),
+            event_registry::TicketTier {
+                name: String::from_str(&env, "Tier 1"),
+                price: 1500,
+                early_bird_price: 1000,
+                early_bird_deadline: 0,
+                early_bird_quantity: 2,
+                usd_price: 0,
+                tier_limit: 100,
+                current_sold,
+                is_refundable: true,
+                visible_from: 0,
+                visible_until: 0,
+                token_prices: soroban_sdk::Map::new(&env),
+                deposit_amount: 0,
+                pricing: event_registry::TierPricing::Fixed,
+                bulk_discounts: soroban_sdk::Map::new(&env),
+            },
+        );
+        Some(event_registry::EventInfo {
+            event_id,
+            organizer_address: Address::generate(&env),
+            payment_address: Address::generate(&env),
+            platform_fee_percent: 500,
+            is_active: true,
+            status: event_registry::EventStatus::Active,
+            created_at: 0,
+            metadata_cid: String::from_str(
+                &env,
+                "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+            ),
+            max_supply: 0,
+            current_supply: current_sold,
+            milestone_plan: None,
+            tiers,
+            refund_deadline: 0,
+            restocking_fee: 0,
+            resale_cap_bps: None,
+            is_postponed: false,
+            grace_period_end: 0,
+            min_sales_target: 0,
+            target_deadline: 0,
+            goal_met: false,
+            visibility: event_registry::EventVisibility::Public,
+            verified: false,
+            max_tickets_per_buyer: 0,
+            resale_royalty_bps: 0,
+            event_start_time: 0,
+            transfer_lock_before_secs: 0,
+        })
+    }
+
+    pub fn increment_inventory(env: Env, _event_id: String, _tier_id: String, quantity: u32) {
+        let key = Symbol::new(&env, "sold");
+        let sold: i128 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&key, &(sold + quantity as i128));
+    }
+
+    pub fn get_global_promo_bps(_env: Env) -> u32 {
+        0
+    }
+
+    pub fn get_promo_expiry(_env: Env) -> u64 {
+        0
+    }
+}
+
+#[test]
+fn test_early_bird_ends_when_quantity_sells_out() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let client = TicketPaymentContractClient::new(&env, &env.register(TicketPaymentContract, ()));
+    let usdc_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let registry_id = env.register(MockEventRegistryEarlyBirdQuantity, ());
+    client.initialize(
+        &Address::generate(&env),
+        &usdc_id,
+        &Address::generate(&env),
+        &registry_id,
+    );
+
+    let buyer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &10_000);
+    token::Client::new(&env, &usdc_id).approve(&buyer, &client.address, &10_000, &99999);
+
+    let event_id = String::from_str(&env, "event_eb_qty");
+    let tier_id = String::from_str(&env, "tier_1");
+    let pay = |id: &str, amount: i128| {
+        client.try_process_payment(
+            &String::from_str(&env, id),
+            &event_id,
+            &tier_id,
+            &buyer,
+            &usdc_id,
+            &amount,
+            &1,
+            &None,
+            &None,
+            &None,
+        )
+    };
+    let switched = || {
+        env.as_contract(&client.address, || {
+            has_price_switched(&env, event_id.clone(), tier_id.clone())
+        })
+    };
+
+    assert!(pay("pay_eb_1", 1000).is_ok());
+    assert!(pay("pay_eb_2", 1000).is_ok());
+    assert!(!switched());
+
+    // No deadline was set, but the early-bird allocation is gone
+    assert_eq!(
+        pay("pay_eb_3", 1000),
+        Err(Ok(TicketPaymentError::InvalidPrice))
+    );
+    assert!(pay("pay_std_1", 1500).is_ok());
+    assert!(switched());
+}
+
+#[test]
+fn test_platform_wallet_rotation_requires_acceptance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, platform_wallet, _) = setup_test(&env);
+    assert_eq!(
+        client.try_accept_platform_wallet(),
+        Err(Ok(TicketPaymentError::Unauthorized))
+    );
+
+    let new_wallet = Address::generate(&env);
+    client.propose_platform_wallet(&new_wallet);
+    assert_eq!(client.get_platform_wallet(), platform_wallet);
+    assert_eq!(
+        client.get_pending_platform_wallet(),
+        Some(new_wallet.clone())
+    );
+
+    client.accept_platform_wallet();
+    assert_eq!(
+        env.auths()[0].0,
+        new_wallet,
+        "acceptance must be authorized by the new wallet"
+    );
+    assert_eq!(client.get_platform_wallet(), new_wallet);
+    assert_eq!(client.get_pending_platform_wallet(), None);
+    // Tokens without a dedicated treasury wallet now pay out to the new wallet
+    assert_eq!(client.get_treasury_wallet(&usdc_id), new_wallet);
+}
+
+#[test]
+fn test_admin_transfer_requires_acceptance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, _usdc_id, _platform_wallet, _) = setup_test(&env);
+    let new_admin = Address::generate(&env);
+    assert_eq!(
+        client.try_cancel_admin_transfer(),
+        Err(Ok(TicketPaymentError::Unauthorized))
+    );
+
+    // A cancelled nomination can no longer be accepted
+    client.transfer_admin(&new_admin);
+    client.cancel_admin_transfer();
+    assert_eq!(
+        client.try_accept_admin(),
+        Err(Ok(TicketPaymentError::Unauthorized))
+    );
+
+    client.transfer_admin(&new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+    client.accept_admin();
+    assert_eq!(env.auths()[0].0, new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+
+    // Admin-only calls now require the new admin
+    client.set_partial_refund_threshold(&9000);
+    assert_eq!(env.auths()[0].0, new_admin);
+    assert_ne!(env.auths()[0].0, admin);
+}
+
+#[test]
+fn test_bump_payment_and_event_extend_ttls() {
+    use super::types::DataKey;
+    use soroban_sdk::testutils::storage::Persistent as _;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _platform_wallet, _) = setup_test(&env);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&buyer, &amount);
+    token::Client::new(&env, &usdc_id).approve(&buyer, &client.address, &amount, &99999);
+
+    let payment_id = String::from_str(&env, "pay_1");
+    let event_id = String::from_str(&env, "event_1");
+    client.process_payment(
+        &payment_id,
+        &event_id,
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &amount,
+        &1,
+        &None,
+        &None,
+        &None,
+    );
+
+    let ttl = |key: &DataKey| {
+        env.as_contract(&client.address, || env.storage().persistent().get_ttl(key))
+    };
+    let payment_key = DataKey::Payment(payment_id.clone());
+    let balance_key = DataKey::Balances(event_id.clone());
+    assert_eq!(ttl(&payment_key), PERSISTENT_BUMP_AMOUNT);
+    assert_eq!(ttl(&balance_key), PERSISTENT_BUMP_AMOUNT);
+
+    let elapsed = PERSISTENT_BUMP_AMOUNT - PERSISTENT_LIFETIME_THRESHOLD + 1;
+    env.ledger().with_mut(|li| li.sequence_number += elapsed);
+    assert_eq!(ttl(&payment_key), PERSISTENT_LIFETIME_THRESHOLD - 1);
+
+    client.bump_payment(&payment_id);
+    client.bump_event(&event_id);
+    assert_eq!(ttl(&payment_key), PERSISTENT_BUMP_AMOUNT);
+    assert_eq!(ttl(&balance_key), PERSISTENT_BUMP_AMOUNT);
+    assert_eq!(
+        ttl(&DataKey::EventPaymentCount(event_id.clone())),
+        PERSISTENT_BUMP_AMOUNT
+    );
+
+    assert_eq!(
+        client.try_bump_payment(&String::from_str(&env, "missing")),
+        Err(Ok(TicketPaymentError::PaymentNotFound))
+    );
+}
+
+#[test]
+fn test_migrate_and_read_version_1_payments() {
+    use super::types::{DataKey, PaymentV1, STORAGE_VERSION};
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _platform_wallet, _) = setup_test(&env);
+    assert_eq!(client.get_storage_version(), STORAGE_VERSION);
+    assert_eq!(
+        client.try_migrate(&1),
+        Err(Ok(TicketPaymentError::InvalidStorageVersion))
+    );
+
+    // Simulate a deployment upgraded from before versioning, holding a v1 payment
+    let payment_id = String::from_str(&env, "legacy_pay");
+    let legacy = PaymentV1 {
+        payment_id: payment_id.clone(),
+        event_id: String::from_str(&env, "event_1"),
+        buyer_address: Address::generate(&env),
+        ticket_tier_id: String::from_str(&env, "tier_1"),
+        amount: 1000,
+        platform_fee: 50,
+        organizer_amount: 950,
+        status: PaymentStatus::Confirmed,
+        transaction_hash: String::from_str(&env, "tx"),
+        created_at: 0,
+        confirmed_at: Some(0),
+        refunded_amount: 0,
+    };
+    env.as_contract(&client.address, || {
+        env.storage().persistent().remove(&DataKey::StorageVersion);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Payment(payment_id.clone()), &legacy);
+    });
+    assert_eq!(client.get_storage_version(), 1);
+
+    let payment = client.get_payment_status(&payment_id).unwrap();
+    assert_eq!(payment.token_address, usdc_id);
+    assert_eq!(payment.amount, 1000);
+    assert_eq!(payment.status, PaymentStatus::Confirmed);
+    assert_eq!(payment.seat_id, None);
+
+    client.migrate(&1);
+    assert_eq!(client.get_storage_version(), STORAGE_VERSION);
+    assert_eq!(
+        client.try_migrate(&1),
+        Err(Ok(TicketPaymentError::InvalidStorageVersion))
+    );
+}
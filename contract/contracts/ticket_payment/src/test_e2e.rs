@@ -1,9 +1,13 @@
 use super::contract::{event_registry, TicketPaymentContract, TicketPaymentContractClient};
 use super::storage::*;
-use super::types::PaymentStatus;
+use super::types::{
+    LedgerEntryType, PaymentStatus, PurchaseOptions, TierAvailability, MAX_EVENT_SPONSORS,
+    MAX_FAILED_PURCHASES, OPERATOR_BULK_REFUND, OPERATOR_PARTIAL_REFUND,
+};
 use crate::error::TicketPaymentError;
 use soroban_sdk::{
-    testutils::Address as _, testutils::Ledger, token, Address, Env, String, Symbol,
+    testutils::Address as _, testutils::Events, testutils::Ledger, token, vec, Address, Bytes,
+    BytesN, Env, String, Symbol, TryIntoVal, Vec,
 };
 
 // =============================================================================
@@ -21,10 +25,17 @@ impl MockRegistryE2E {
         event_registry::PaymentInfo {
             payment_address: Address::generate(&env),
             platform_fee_percent: 500, // 5%
+            tiers: soroban_sdk::Map::new(&env),
+            policy_flags: 0,
+            resale_royalty_bps: 0,
         }
     }
 
     pub fn get_event(env: Env, event_id: String) -> Option<event_registry::EventInfo> {
+        let unknown: Option<String> = env.storage().instance().get(&Symbol::new(&env, "unknown"));
+        if unknown.as_ref() == Some(&event_id) {
+            return None;
+        }
         // Retrieve stable organizer from storage, or generate one if not stored.
         let org_key = Symbol::new(&env, "organizer");
         let organizer: Address = env
@@ -46,13 +57,58 @@ impl MockRegistryE2E {
         let scanner_key = Symbol::new(&env, "scanner");
         let _scanner: Option<Address> = env.storage().instance().get(&scanner_key);
 
+        let is_active: bool = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "active"))
+            .unwrap_or(true);
+        let deposit_amount: i128 = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "deposit"))
+            .unwrap_or(0);
+        let pricing: event_registry::TierPricing = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "pricing"))
+            .unwrap_or(event_registry::TierPricing::Fixed);
+        let bulk_discounts: soroban_sdk::Map<u32, u32> = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "bulk"))
+            .unwrap_or(soroban_sdk::Map::new(&env));
+        let max_tickets_per_buyer: u32 = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "buyer_limit"))
+            .unwrap_or(0);
+        let resale_royalty_bps: u32 = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "royalty"))
+            .unwrap_or(0);
+        let (event_start_time, transfer_lock_before_secs): (u64, u64) = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "transfer_lock"))
+            .unwrap_or((0, 0));
+        let grace_period_end: u64 = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "grace"))
+            .unwrap_or(0);
+
         Some(event_registry::EventInfo {
             event_id,
             organizer_address: organizer,
             payment_address,
             platform_fee_percent: 500,
-            is_active: true,
-            status: event_registry::EventStatus::Active,
+            is_active,
+            status: env
+                .storage()
+                .instance()
+                .get(&Symbol::new(&env, "status"))
+                .unwrap_or(event_registry::EventStatus::Active),
             created_at: 0,
             metadata_cid: String::from_str(
                 &env,
@@ -70,20 +126,62 @@ impl MockRegistryE2E {
                         price: 1000_0000000i128,
                         early_bird_price: 1000_0000000i128,
                         early_bird_deadline: 0,
+                        early_bird_quantity: 0,
                         usd_price: 0,
                         tier_limit: 1000,
-                        current_sold: 0,
+                        current_sold: current_supply,
                         is_refundable: true,
+                        visible_from: 0,
+                        visible_until: 0,
+                        token_prices: soroban_sdk::Map::new(&env),
+                        deposit_amount,
+                        pricing,
+                        bulk_discounts,
                     },
                 );
+                // Optional premium tier, offered once a test stores its price
+                if let Some(vip_price) = env
+                    .storage()
+                    .instance()
+                    .get::<_, i128>(&Symbol::new(&env, "vip_price"))
+                {
+                    tiers.set(
+                        String::from_str(&env, "tier_vip"),
+                        event_registry::TicketTier {
+                            name: String::from_str(&env, "VIP"),
+                            price: vip_price,
+                            early_bird_price: vip_price,
+                            early_bird_deadline: 0,
+                            early_bird_quantity: 0,
+                            usd_price: 0,
+                            tier_limit: 100,
+                            current_sold: 0,
+                            is_refundable: true,
+                            visible_from: 0,
+                            visible_until: 0,
+                            token_prices: soroban_sdk::Map::new(&env),
+                            deposit_amount: 0,
+                            pricing: event_registry::TierPricing::Fixed,
+                            bulk_discounts: soroban_sdk::Map::new(&env),
+                        },
+                    );
+                }
                 tiers
             },
             refund_deadline: 0,
             restocking_fee: 50_0000000i128, // 50 USDC restocking fee
             resale_cap_bps: None,
+            is_postponed: grace_period_end > 0,
+            grace_period_end,
             min_sales_target: 0,
             target_deadline: 0,
             goal_met: false,
+            visibility: event_registry::EventVisibility::Public,
+            verified: false,
+            max_tickets_per_buyer,
+            resale_royalty_bps,
+            event_start_time,
+            transfer_lock_before_secs,
         })
     }
 
@@ -95,6 +193,58 @@ impl MockRegistryE2E {
             .set(&key, &(current + quantity as i128));
     }
 
+    pub fn get_bundle(env: Env, _bundle_id: String) -> Option<event_registry::Bundle> {
+        env.storage().instance().get(&Symbol::new(&env, "bundle"))
+    }
+
+    pub fn record_bundle_sale(env: Env, _bundle_id: String) {
+        let key = Symbol::new(&env, "bundle");
+        let mut bundle: event_registry::Bundle = env.storage().instance().get(&key).unwrap();
+        if bundle.sold >= bundle.max_supply {
+            panic!("bundle sold out");
+        }
+        bundle.sold += 1;
+        env.storage().instance().set(&key, &bundle);
+    }
+
+    pub fn hold_inventory(
+        env: Env,
+        hold_id: String,
+        _event_id: String,
+        _tier_id: String,
+        quantity: u32,
+        _expires_at: u64,
+    ) {
+        let key = Symbol::new(&env, "holds");
+        let mut holds: soroban_sdk::Map<String, u32> = env
+            .storage()
+            .instance()
+            .get(&key)
+            .unwrap_or(soroban_sdk::Map::new(&env));
+        holds.set(hold_id, quantity);
+        env.storage().instance().set(&key, &holds);
+    }
+
+    pub fn release_inventory_hold(env: Env, hold_id: String) {
+        let key = Symbol::new(&env, "holds");
+        let mut holds: soroban_sdk::Map<String, u32> = env
+            .storage()
+            .instance()
+            .get(&key)
+            .unwrap_or(soroban_sdk::Map::new(&env));
+        holds.remove(hold_id);
+        env.storage().instance().set(&key, &holds);
+    }
+
+    pub fn get_held_inventory(env: Env) -> u32 {
+        let holds: soroban_sdk::Map<String, u32> = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "holds"))
+            .unwrap_or(soroban_sdk::Map::new(&env));
+        holds.values().iter().sum()
+    }
+
     pub fn decrement_inventory(env: Env, _event_id: String, _tier_id: String) {
         let key = Symbol::new(&env, "supply");
         let current: i128 = env.storage().instance().get(&key).unwrap_or(0);
@@ -103,12 +253,40 @@ impl MockRegistryE2E {
         }
     }
 
-    pub fn get_global_promo_bps(_env: Env) -> u32 {
-        0
+    pub fn get_global_promo_bps(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "promo_bps"))
+            .unwrap_or(0)
     }
 
-    pub fn get_promo_expiry(_env: Env) -> u64 {
-        0
+    pub fn get_promo_expiry(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "promo_exp"))
+            .unwrap_or(0)
+    }
+
+    pub fn set_promo(env: Env, bps: u32, expiry: u64) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "promo_bps"), &bps);
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "promo_exp"), &expiry);
+    }
+
+    pub fn get_promo_mode(env: Env, _event_id: String) -> event_registry::PromoMode {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "promo_mode"))
+            .unwrap_or(event_registry::PromoMode::Shared)
+    }
+
+    pub fn set_promo_mode(env: Env, mode: event_registry::PromoMode) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "promo_mode"), &mode);
     }
 
     pub fn is_scanner_authorized(env: Env, _event_id: String, scanner: Address) -> bool {
@@ -130,6 +308,156 @@ impl MockRegistryE2E {
         let key = Symbol::new(&env, "scanner");
         env.storage().instance().set(&key, &scanner);
     }
+
+    pub fn set_active(env: Env, is_active: bool) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "active"), &is_active);
+    }
+
+    pub fn set_pricing(env: Env, pricing: event_registry::TierPricing) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "pricing"), &pricing);
+    }
+
+    pub fn set_bulk_discounts(env: Env, bulk_discounts: soroban_sdk::Map<u32, u32>) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "bulk"), &bulk_discounts);
+    }
+
+    pub fn set_deposit(env: Env, amount: i128) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "deposit"), &amount);
+    }
+
+    pub fn set_buyer_limit(env: Env, max_tickets_per_buyer: u32) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "buyer_limit"), &max_tickets_per_buyer);
+    }
+
+    pub fn set_resale_royalty(env: Env, resale_royalty_bps: u32) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "royalty"), &resale_royalty_bps);
+    }
+
+    pub fn set_postponed(env: Env, grace_period_end: u64) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "grace"), &grace_period_end);
+    }
+
+    pub fn set_transfer_lock(env: Env, event_start_time: u64, transfer_lock_before_secs: u64) {
+        env.storage().instance().set(
+            &Symbol::new(&env, "transfer_lock"),
+            &(event_start_time, transfer_lock_before_secs),
+        );
+    }
+
+    pub fn get_recovery_config(
+        env: Env,
+        _event_id: String,
+    ) -> Option<event_registry::RecoveryConfig> {
+        env.storage().instance().get(&Symbol::new(&env, "recovery"))
+    }
+
+    pub fn set_recovery(env: Env, recovery_address: Address, inactivity_timeout: u64) {
+        env.storage().instance().set(
+            &Symbol::new(&env, "recovery"),
+            &event_registry::RecoveryConfig {
+                recovery_address,
+                inactivity_timeout,
+            },
+        );
+    }
+
+    pub fn get_seat_map(
+        env: Env,
+        _event_id: String,
+        _tier_id: String,
+    ) -> Option<event_registry::SeatMap> {
+        env.storage().instance().get(&Symbol::new(&env, "seat_map"))
+    }
+
+    pub fn set_seat_map(env: Env, seats: soroban_sdk::Vec<String>) {
+        env.storage().instance().set(
+            &Symbol::new(&env, "seat_map"),
+            &event_registry::SeatMap { seats },
+        );
+    }
+
+    pub fn reserve_seat(env: Env, _event_id: String, _tier_id: String, seat_id: String) {
+        let key = Symbol::new(&env, "reserved");
+        let mut reserved: soroban_sdk::Map<String, bool> = env
+            .storage()
+            .instance()
+            .get(&key)
+            .unwrap_or(soroban_sdk::Map::new(&env));
+        assert!(!reserved.contains_key(seat_id.clone()), "seat taken");
+        reserved.set(seat_id, true);
+        env.storage().instance().set(&key, &reserved);
+    }
+
+    pub fn release_seat(env: Env, _event_id: String, _tier_id: String, seat_id: String) {
+        let key = Symbol::new(&env, "reserved");
+        let mut reserved: soroban_sdk::Map<String, bool> =
+            env.storage().instance().get(&key).unwrap();
+        reserved.remove(seat_id);
+        env.storage().instance().set(&key, &reserved);
+    }
+
+    pub fn get_refund_policy(env: Env, _event_id: String) -> Option<event_registry::RefundPolicy> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "refund_policy"))
+    }
+
+    pub fn set_refund_policy(env: Env, policy: event_registry::RefundPolicy) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "refund_policy"), &policy);
+    }
+
+    pub fn get_event_schedule(
+        env: Env,
+        _event_id: String,
+    ) -> Option<event_registry::EventSchedule> {
+        env.storage().instance().get(&Symbol::new(&env, "schedule"))
+    }
+
+    pub fn complete_event(env: Env, completed_at: u64) {
+        env.storage().instance().set(
+            &Symbol::new(&env, "status"),
+            &event_registry::EventStatus::Completed,
+        );
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "active"), &false);
+        env.storage().instance().set(
+            &Symbol::new(&env, "schedule"),
+            &event_registry::EventSchedule {
+                end_time: completed_at,
+                completed_at,
+            },
+        );
+    }
+
+    /// Mirrors the real registry's `cancel_event`, which reports the
+    /// cancellation to the payment contract.
+    pub fn cancel_event(env: Env, ticket_payment: Address, event_id: String) {
+        env.storage().instance().set(
+            &Symbol::new(&env, "status"),
+            &event_registry::EventStatus::Cancelled,
+        );
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "active"), &false);
+        TicketPaymentContractClient::new(&env, &ticket_payment).on_event_cancelled(&event_id);
+    }
 }
 
 /// Mock registry returning a cancelled event — for auto-refund tests.
@@ -142,6 +470,9 @@ impl MockRegistryCancelledE2E {
         event_registry::PaymentInfo {
             payment_address: Address::generate(&env),
             platform_fee_percent: 500,
+            tiers: soroban_sdk::Map::new(&env),
+            policy_flags: 0,
+            resale_royalty_bps: 0,
         }
     }
 
@@ -177,10 +508,17 @@ impl MockRegistryCancelledE2E {
                         price: 1000_0000000i128,
                         early_bird_price: 1000_0000000i128,
                         early_bird_deadline: 0,
+                        early_bird_quantity: 0,
                         usd_price: 0,
                         tier_limit: 100,
                         current_sold: 0,
                         is_refundable: false, // not normally refundable, but cancelled overrides
+                        visible_from: 0,
+                        visible_until: 0,
+                        token_prices: soroban_sdk::Map::new(&env),
+                        deposit_amount: 0,
+                        pricing: event_registry::TierPricing::Fixed,
+                        bulk_discounts: soroban_sdk::Map::new(&env),
                     },
                 );
                 tiers
@@ -188,9 +526,17 @@ impl MockRegistryCancelledE2E {
             refund_deadline: 0,
             restocking_fee: 100_0000000i128,
             resale_cap_bps: None,
+            is_postponed: false,
+            grace_period_end: 0,
             min_sales_target: 0,
             target_deadline: 0,
             goal_met: false,
+            visibility: event_registry::EventVisibility::Public,
+            verified: false,
+            max_tickets_per_buyer: 0,
+            resale_royalty_bps: 0,
+            event_start_time: 0,
+            transfer_lock_before_secs: 0,
         })
     }
 
@@ -220,6 +566,9 @@ impl MockRegistryWithGoal {
         event_registry::PaymentInfo {
             payment_address: Address::generate(&env),
             platform_fee_percent: 500,
+            tiers: soroban_sdk::Map::new(&env),
+            policy_flags: 0,
+            resale_royalty_bps: 0,
         }
     }
 
@@ -268,10 +617,17 @@ impl MockRegistryWithGoal {
                         price: 1000_0000000i128,
                         early_bird_price: 1000_0000000i128,
                         early_bird_deadline: 0,
+                        early_bird_quantity: 0,
                         usd_price: 0,
                         tier_limit: 1000,
                         current_sold: current_supply,
                         is_refundable: false,
+                        visible_from: 0,
+                        visible_until: 0,
+                        token_prices: soroban_sdk::Map::new(&env),
+                        deposit_amount: 0,
+                        pricing: event_registry::TierPricing::Fixed,
+                        bulk_discounts: soroban_sdk::Map::new(&env),
                     },
                 );
                 tiers
@@ -279,9 +635,17 @@ impl MockRegistryWithGoal {
             refund_deadline: 0,
             restocking_fee: 100_0000000i128,
             resale_cap_bps: None,
+            is_postponed: false,
+            grace_period_end: 0,
             min_sales_target,
             target_deadline,
             goal_met,
+            visibility: event_registry::EventVisibility::Public,
+            verified: false,
+            max_tickets_per_buyer: 0,
+            resale_royalty_bps: 0,
+            event_start_time: 0,
+            transfer_lock_before_secs: 0,
         })
     }
 
@@ -329,6 +693,98 @@ impl MockRegistryWithGoal {
     }
 }
 
+/// Rewards hook that accrues points per buyer: the paid amount on confirmation
+/// plus one point per check-in.
+#[soroban_sdk::contract]
+pub struct MockRewards;
+
+#[soroban_sdk::contractimpl]
+impl MockRewards {
+    pub fn on_purchase_confirmed(
+        env: Env,
+        buyer: Address,
+        _event_id: String,
+        _payment_id: String,
+        amount: i128,
+    ) {
+        let points: i128 = env.storage().instance().get(&buyer).unwrap_or(0);
+        env.storage().instance().set(&buyer, &(points + amount));
+    }
+
+    pub fn on_check_in(env: Env, holder: Address, _event_id: String, _payment_id: String) {
+        let points: i128 = env.storage().instance().get(&holder).unwrap_or(0);
+        env.storage().instance().set(&holder, &(points + 1));
+    }
+
+    pub fn points(env: Env, holder: Address) -> i128 {
+        env.storage().instance().get(&holder).unwrap_or(0)
+    }
+}
+
+/// Proof-of-attendance minter that records each minted (attendee, payment) pair.
+#[soroban_sdk::contract]
+pub struct MockAttendanceMinter;
+
+#[soroban_sdk::contractimpl]
+impl MockAttendanceMinter {
+    pub fn mint_attendance(env: Env, attendee: Address, _event_id: String, payment_id: String) {
+        let mut minted: Vec<(Address, String)> = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "minted"))
+            .unwrap_or_else(|| Vec::new(&env));
+        minted.push_back((attendee, payment_id));
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "minted"), &minted);
+    }
+
+    pub fn minted(env: Env) -> Vec<(Address, String)> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "minted"))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+}
+
+/// Yield strategy that simply holds deposited funds.
+#[soroban_sdk::contract]
+pub struct MockYieldStrategy;
+
+#[soroban_sdk::contractimpl]
+impl MockYieldStrategy {
+    pub fn deposit(_env: Env, _token: Address, _amount: i128) {}
+
+    pub fn withdraw(env: Env, token: Address, amount: i128, to: Address) {
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &to, &amount);
+    }
+
+    pub fn balance(env: Env, token: Address) -> i128 {
+        token::Client::new(&env, &token).balance(&env.current_contract_address())
+    }
+}
+
+/// Rewards hook that always fails.
+#[soroban_sdk::contract]
+pub struct MockBrokenRewards;
+
+#[soroban_sdk::contractimpl]
+impl MockBrokenRewards {
+    pub fn on_purchase_confirmed(
+        _env: Env,
+        _buyer: Address,
+        _event_id: String,
+        _payment_id: String,
+        _amount: i128,
+    ) {
+        panic!("rewards unavailable");
+    }
+
+    pub fn on_check_in(_env: Env, _holder: Address, _event_id: String, _payment_id: String) {
+        panic!("rewards unavailable");
+    }
+}
+
 // =============================================================================
 // Helpers
 // =============================================================================
@@ -375,17 +831,21 @@ fn buy_ticket(
     usdc_id: &Address,
     amount: i128,
 ) -> String {
-    client.process_payment(
-        &String::from_str(env, payment_id),
-        &String::from_str(env, event_id),
-        &String::from_str(env, "tier_1"),
-        buyer,
-        usdc_id,
-        &amount,
-        &1,
-        &None,
-        &None,
-    )
+    client
+        .process_payment(
+            &String::from_str(env, payment_id),
+            &String::from_str(env, event_id),
+            &String::from_str(env, "tier_1"),
+            buyer,
+            usdc_id,
+            &amount,
+            &1,
+            &None,
+            &None,
+            &None,
+        )
+        .get(0)
+        .unwrap()
 }
 
 // =============================================================================
@@ -586,10 +1046,7 @@ fn test_e2e_duplicate_payment_id_rejected() {
         &client, &env, "pay_dup", "event_1", &buyer, &usdc_id, amount,
     );
 
-    // Second payment with the same id — the store_payment call will overwrite
-    // the existing record (since payment_id is unique key). The contract doesn't
-    // explicitly reject duplicates at the process_payment level, but the buyer
-    // index won't double-add. Verify the payment record reflects the second write.
+    // A replay of the same payment id is rejected before any funds move
     let result = client.try_process_payment(
         &String::from_str(&env, "pay_dup"),
         &String::from_str(&env, "event_1"),
@@ -600,14 +1057,31 @@ fn test_e2e_duplicate_payment_id_rejected() {
         &1,
         &None,
         &None,
+        &None,
     );
 
-    // The second call should succeed (no explicit duplicate rejection in the contract),
-    // but the buyer index should only have one entry for this payment_id.
-    assert!(result.is_ok());
+    assert_eq!(result, Err(Ok(TicketPaymentError::PaymentAlreadyExists)));
+    assert_eq!(token::Client::new(&env, &usdc_id).balance(&buyer), amount);
+    assert_eq!(
+        client.try_quote_payment(
+            &String::from_str(&env, "pay_dup"),
+            &String::from_str(&env, "event_1"),
+            &String::from_str(&env, "tier_1"),
+            &usdc_id,
+            &1,
+        ),
+        Err(Ok(TicketPaymentError::PaymentAlreadyExists))
+    );
+    let quote = client.quote_payment(
+        &String::from_str(&env, "pay_new"),
+        &String::from_str(&env, "event_1"),
+        &String::from_str(&env, "tier_1"),
+        &usdc_id,
+        &2,
+    );
+    assert_eq!(quote.gross, amount * 2);
+    assert_eq!(quote.net_paid, amount * 2);
     let buyer_payments = client.get_buyer_payments(&buyer);
-    // The buyer should still only see one entry for "pay_dup"
-    // (store_payment checks `exists` before adding to index)
     let mut dup_count = 0u32;
     let target = String::from_str(&env, "pay_dup");
     for i in 0..buyer_payments.len() {
@@ -650,6 +1124,7 @@ fn test_e2e_state_consistent_after_failed_payment() {
         &1,
         &None,
         &None,
+        &None,
     );
     assert_eq!(result, Err(Ok(TicketPaymentError::TokenNotWhitelisted)));
 
@@ -697,36 +1172,37 @@ fn test_e2e_batch_purchase_then_partial_refund() {
         &quantity,
         &None,
         &None,
+        &None,
     );
 
-    // Verify 3 sub-payments exist (p-0, p-1, p-2)
+    // Verify 3 sub-payments exist (batch_1-0, batch_1-1, batch_1-2)
     let p0 = client
-        .get_payment_status(&String::from_str(&env, "p-0"))
+        .get_payment_status(&String::from_str(&env, "batch_1-0"))
         .unwrap();
     let p1 = client
-        .get_payment_status(&String::from_str(&env, "p-1"))
+        .get_payment_status(&String::from_str(&env, "batch_1-1"))
         .unwrap();
     let p2 = client
-        .get_payment_status(&String::from_str(&env, "p-2"))
+        .get_payment_status(&String::from_str(&env, "batch_1-2"))
         .unwrap();
     assert_eq!(p0.amount, amount_per_ticket);
     assert_eq!(p1.amount, amount_per_ticket);
     assert_eq!(p2.amount, amount_per_ticket);
 
-    // Refund one ticket (p-1)
-    client.request_guest_refund(&String::from_str(&env, "p-1"));
+    // Refund one ticket (batch_1-1)
+    client.request_guest_refund(&String::from_str(&env, "batch_1-1"));
 
     let p1_after = client
-        .get_payment_status(&String::from_str(&env, "p-1"))
+        .get_payment_status(&String::from_str(&env, "batch_1-1"))
         .unwrap();
     assert_eq!(p1_after.status, PaymentStatus::Refunded);
 
     // Other two remain pending
     let p0_after = client
-        .get_payment_status(&String::from_str(&env, "p-0"))
+        .get_payment_status(&String::from_str(&env, "batch_1-0"))
         .unwrap();
     let p2_after = client
-        .get_payment_status(&String::from_str(&env, "p-2"))
+        .get_payment_status(&String::from_str(&env, "batch_1-2"))
         .unwrap();
     assert_eq!(p0_after.status, PaymentStatus::Pending);
     assert_eq!(p2_after.status, PaymentStatus::Pending);
@@ -737,6 +1213,69 @@ fn test_e2e_batch_purchase_then_partial_refund() {
     assert_eq!(buyer_balance, amount_per_ticket - restocking_fee);
 }
 
+#[test]
+fn test_e2e_consecutive_batches_get_distinct_payment_ids() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _reg) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 5);
+
+    let buy_batch = |payment_id: &str, quantity: u32| {
+        client.process_payment(
+            &String::from_str(&env, payment_id),
+            &String::from_str(&env, "event_1"),
+            &String::from_str(&env, "tier_1"),
+            &buyer,
+            &usdc_id,
+            &amount,
+            &quantity,
+            &None,
+            &None,
+            &None,
+        )
+    };
+    let first = buy_batch("order_a", 2);
+    let second = buy_batch("order_b", 3);
+    assert_eq!(
+        first,
+        vec![
+            &env,
+            String::from_str(&env, "order_a-0"),
+            String::from_str(&env, "order_a-1"),
+        ]
+    );
+    assert_eq!(second.get(2).unwrap(), String::from_str(&env, "order_b-2"));
+
+    // The second batch left the first one's records intact
+    assert_eq!(client.get_buyer_payment_count(&buyer), 5);
+    for payment_id in first.iter().chain(second.iter()) {
+        let payment = client.get_payment_status(&payment_id).unwrap();
+        assert_eq!(payment.payment_id, payment_id);
+        assert_eq!(payment.buyer_address, buyer);
+    }
+
+    // Batch ids are derived from the purchase id, which must leave room for them
+    let long_id = String::from_bytes(&env, &[b'x'; 65]);
+    assert_eq!(
+        client.try_process_payment(
+            &long_id,
+            &String::from_str(&env, "event_1"),
+            &String::from_str(&env, "tier_1"),
+            &buyer,
+            &usdc_id,
+            &amount,
+            &2,
+            &None,
+            &None,
+            &None,
+        ),
+        Err(Ok(TicketPaymentError::PaymentIdTooLong))
+    );
+}
+
 // =============================================================================
 // 8. Organizer withdrawal after sales
 // =============================================================================
@@ -825,6 +1364,7 @@ fn test_e2e_pause_blocks_operations_resume_allows() {
         &1,
         &None,
         &None,
+        &None,
     );
     assert_eq!(result, Err(Ok(TicketPaymentError::ContractPaused)));
 
@@ -843,6 +1383,7 @@ fn test_e2e_pause_blocks_operations_resume_allows() {
         &1,
         &None,
         &None,
+        &None,
     );
     assert!(result.is_ok());
 }
@@ -875,7 +1416,7 @@ fn test_e2e_ticket_transfer_lifecycle() {
     assert_eq!(payment.buyer_address, buyer);
 
     // Transfer to new owner (no sale price, no transfer fee)
-    client.transfer_ticket(&pay_id, &new_owner, &None);
+    client.transfer_ticket(&pay_id, &new_owner, &None, &None);
 
     let payment = client.get_payment_status(&pay_id).unwrap();
     assert_eq!(payment.buyer_address, new_owner);
@@ -986,3 +1527,4881 @@ fn test_e2e_goal_failed_allows_automated_refund() {
     let buyer_balance = token::Client::new(&env, &usdc_id).balance(&buyer);
     assert_eq!(buyer_balance, amount);
 }
+
+// =============================================================================
+// 12. Identity-bound tickets
+// =============================================================================
+
+#[test]
+fn test_e2e_identity_bound_checkin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+    let scanner = Address::generate(&env);
+    let amount = 1000_0000000i128;
+
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_scanner(env.clone(), scanner.clone());
+    });
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 2);
+
+    let identity = BytesN::from_array(&env, &[7u8; 32]);
+    let other_identity = BytesN::from_array(&env, &[9u8; 32]);
+    let options = PurchaseOptions {
+        identity_hash: Some(identity.clone()),
+        memo: None,
+        registry: None,
+        seats: soroban_sdk::Vec::new(&env),
+        max_slippage_bps: None,
+        voucher_code: None,
+        promoter: None,
+    };
+    let buy_bound = |payment_id: &str| {
+        client
+            .process_payment(
+                &String::from_str(&env, payment_id),
+                &String::from_str(&env, "event_1"),
+                &String::from_str(&env, "tier_1"),
+                &buyer,
+                &usdc_id,
+                &amount,
+                &1,
+                &None,
+                &None,
+                &Some(options.clone()),
+            )
+            .get(0)
+            .unwrap()
+    };
+
+    let pay_id = buy_bound("pay_id1");
+    client.confirm_payment(&pay_id, &String::from_str(&env, "tx_id1"));
+    let payment = client.get_payment_status(&pay_id).unwrap();
+    assert_eq!(payment.identity_hash, Some(identity.clone()));
+
+    // A different identity is turned away at the gate
+    let result = client.try_check_in_with_identity(&pay_id, &scanner, &other_identity);
+    assert_eq!(result, Err(Ok(TicketPaymentError::IdentityMismatch)));
+
+    client.check_in_with_identity(&pay_id, &scanner, &identity);
+    let payment = client.get_payment_status(&pay_id).unwrap();
+    assert_eq!(payment.status, PaymentStatus::CheckedIn);
+
+    // Transferring a bound ticket drops the previous holder's binding
+    let pay_id = buy_bound("pay_id2");
+    client.confirm_payment(&pay_id, &String::from_str(&env, "tx_id2"));
+    client.transfer_ticket(&pay_id, &new_owner, &None, &None);
+    let payment = client.get_payment_status(&pay_id).unwrap();
+    assert_eq!(payment.identity_hash, None);
+    let result = client.try_check_in_with_identity(&pay_id, &scanner, &identity);
+    assert_eq!(result, Err(Ok(TicketPaymentError::IdentityMismatch)));
+}
+
+// =============================================================================
+// 13. Batch platform fee settlement
+// =============================================================================
+
+#[test]
+fn test_e2e_settle_all_platform_fees_in_batches() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, platform_wallet, _reg) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let fee = (amount * 500) / 10000;
+
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 4);
+    buy_ticket(&client, &env, "pay_s1", "event_1", &buyer, &usdc_id, amount);
+    buy_ticket(&client, &env, "pay_s2", "event_2", &buyer, &usdc_id, amount);
+    buy_ticket(&client, &env, "pay_s3", "event_3", &buyer, &usdc_id, amount);
+    assert_eq!(client.get_fee_event_count(), 3);
+
+    assert_eq!(
+        client.settle_all_platform_fees(&0, &2).get(usdc_id.clone()),
+        Some(fee * 2)
+    );
+    assert_eq!(
+        client
+            .get_event_escrow_balance(&String::from_str(&env, "event_2"))
+            .platform_fee,
+        0
+    );
+    assert_eq!(
+        client
+            .get_event_escrow_balance(&String::from_str(&env, "event_3"))
+            .platform_fee,
+        fee
+    );
+
+    assert_eq!(
+        client
+            .settle_all_platform_fees(&2, &10)
+            .get(usdc_id.clone()),
+        Some(fee)
+    );
+    assert!(client.settle_all_platform_fees(&0, &10).is_empty());
+
+    // New sales on an already indexed event are picked up without re-indexing
+    buy_ticket(&client, &env, "pay_s4", "event_1", &buyer, &usdc_id, amount);
+    assert_eq!(client.get_fee_event_count(), 3);
+    assert_eq!(
+        client
+            .settle_all_platform_fees(&0, &10)
+            .get(usdc_id.clone()),
+        Some(fee)
+    );
+
+    client.withdraw_platform_fees(&(fee * 4), &usdc_id);
+    let platform_balance = token::Client::new(&env, &usdc_id).balance(&platform_wallet);
+    assert_eq!(platform_balance, fee * 4);
+}
+
+#[test]
+fn test_e2e_settle_all_platform_fees_settles_each_token_separately() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _reg) = setup_e2e(&env);
+    let eurc_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_token(&eurc_id);
+    let event_id = String::from_str(&env, "event_1");
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let fee = (amount * 500) / 10000;
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+    fund_buyer(&env, &eurc_id, &buyer, &client.address, amount);
+    buy_ticket(&client, &env, "pay_t1", "event_1", &buyer, &usdc_id, amount);
+    buy_ticket(&client, &env, "pay_t2", "event_1", &buyer, &eurc_id, amount);
+
+    let settled = client.settle_all_platform_fees(&0, &10);
+    assert_eq!(settled.len(), 2);
+    assert_eq!(settled.get(usdc_id.clone()), Some(fee));
+    assert_eq!(settled.get(eurc_id.clone()), Some(fee));
+
+    let history = client.get_settlement_history(&0, &10);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().token, usdc_id);
+    assert_eq!(history.get(0).unwrap().amount, fee);
+    assert_eq!(history.get(1).unwrap().token, eurc_id);
+    assert_eq!(history.get(1).unwrap().amount, fee);
+
+    let ledger = client.get_event_ledger(&event_id, &0, &10);
+    assert_eq!(ledger.len(), 4);
+    for (i, token) in [(2, usdc_id), (3, eurc_id)] {
+        let entry = ledger.get(i).unwrap();
+        assert_eq!(entry.entry_type, LedgerEntryType::FeeSettlement);
+        assert_eq!(entry.amount, fee);
+        assert_eq!(entry.token, token);
+    }
+}
+
+// =============================================================================
+// 14. Per-event escrow ledger
+// =============================================================================
+
+#[test]
+fn test_e2e_event_ledger_records_escrow_mutations() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, platform_wallet, registry_id) = setup_e2e(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let event_id = String::from_str(&env, "event_1");
+
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_organizer(env.clone(), organizer.clone());
+    });
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 2);
+
+    buy_ticket(&client, &env, "pay_l1", "event_1", &buyer, &usdc_id, amount);
+    let refunded = buy_ticket(&client, &env, "pay_l2", "event_1", &buyer, &usdc_id, amount);
+    client.request_guest_refund(&refunded);
+    let withdrawn = client.withdraw_organizer_funds(&event_id, &usdc_id);
+    let settled = client.settle_platform_fees(&event_id, &usdc_id);
+
+    let ledger = client.get_event_ledger(&event_id, &0, &10);
+    assert_eq!(ledger.len(), 6);
+
+    let expected = [
+        (LedgerEntryType::Sale, amount, buyer.clone()),
+        (LedgerEntryType::Sale, amount, buyer.clone()),
+        (LedgerEntryType::Refund, amount - 50_0000000, buyer.clone()),
+        (LedgerEntryType::RestockingFee, 50_0000000, buyer.clone()),
+        (LedgerEntryType::Withdrawal, withdrawn, organizer.clone()),
+        (
+            LedgerEntryType::FeeSettlement,
+            settled,
+            platform_wallet.clone(),
+        ),
+    ];
+    for (i, (entry_type, entry_amount, counterparty)) in expected.iter().enumerate() {
+        let entry = ledger.get(i as u32).unwrap();
+        assert_eq!(entry.entry_type, *entry_type);
+        assert_eq!(entry.amount, *entry_amount);
+        assert_eq!(entry.counterparty, *counterparty);
+        assert_eq!(entry.token, usdc_id);
+    }
+
+    // Paging
+    let page = client.get_event_ledger(&event_id, &4, &10);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().entry_type, LedgerEntryType::Withdrawal);
+    assert_eq!(client.get_event_ledger(&event_id, &6, &10).len(), 0);
+    assert_eq!(
+        client
+            .get_event_ledger(&String::from_str(&env, "event_2"), &0, &10)
+            .len(),
+        0
+    );
+
+    // The settlement report reconciles with the ledger per token
+    let report = client.get_event_settlement_report(&event_id);
+    assert_eq!(report.len(), 1);
+    let line = report.get(0).unwrap();
+    assert_eq!(line.token, usdc_id);
+    assert_eq!(line.gross_sales, amount * 2);
+    assert_eq!(line.discounts_granted, 0);
+    assert_eq!(line.refunds_paid, amount - 50_0000000);
+    assert_eq!(line.restocking_retained, 50_0000000);
+    assert_eq!(line.fees_settled, settled);
+    assert_eq!(line.net_to_organizer, withdrawn);
+    assert_eq!(
+        token::Client::new(&env, &usdc_id).balance(&organizer),
+        line.net_to_organizer
+    );
+    assert!(client
+        .get_event_settlement_report(&String::from_str(&env, "event_2"))
+        .is_empty());
+}
+
+// =============================================================================
+// 15. Gift memos
+// =============================================================================
+
+#[test]
+fn test_e2e_purchase_and_transfer_memos() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _reg) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let friend = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 2);
+
+    let buy_with_memo = |payment_id: &str, memo: Bytes| {
+        client.try_process_payment(
+            &String::from_str(&env, payment_id),
+            &String::from_str(&env, "event_1"),
+            &String::from_str(&env, "tier_1"),
+            &buyer,
+            &usdc_id,
+            &amount,
+            &1,
+            &None,
+            &None,
+            &Some(PurchaseOptions {
+                identity_hash: None,
+                memo: Some(memo),
+                registry: None,
+                seats: soroban_sdk::Vec::new(&env),
+                max_slippage_bps: None,
+                voucher_code: None,
+                promoter: None,
+            }),
+        )
+    };
+
+    let oversized = Bytes::from_array(&env, &[0u8; 257]);
+    assert_eq!(
+        buy_with_memo("pay_m0", oversized.clone()),
+        Err(Ok(TicketPaymentError::MemoTooLong))
+    );
+
+    let gift = Bytes::from_slice(&env, b"enc:happy-birthday");
+    let pay_id = buy_with_memo("pay_m1", gift.clone())
+        .unwrap()
+        .unwrap()
+        .get(0)
+        .unwrap();
+    client.confirm_payment(&pay_id, &String::from_str(&env, "tx_m1"));
+    assert_eq!(client.get_payment_status(&pay_id).unwrap().memo, Some(gift));
+
+    assert_eq!(
+        client.try_transfer_ticket(&pay_id, &friend, &None, &Some(oversized)),
+        Err(Ok(TicketPaymentError::MemoTooLong))
+    );
+
+    // A transfer memo replaces the purchase memo
+    let note = Bytes::from_slice(&env, b"enc:enjoy-the-show");
+    client.transfer_ticket(&pay_id, &friend, &None, &Some(note.clone()));
+    let payment = client.get_payment_status(&pay_id).unwrap();
+    assert_eq!(payment.buyer_address, friend);
+    assert_eq!(payment.memo, Some(note));
+}
+
+// =============================================================================
+// 16. Cancellation cost preview
+// =============================================================================
+
+#[test]
+fn test_e2e_preview_cancellation_reports_liability_and_shortfall() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let fee = (amount * 500) / 10000;
+    let event_id = String::from_str(&env, "event_1");
+
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_organizer(env.clone(), organizer.clone());
+    });
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 3);
+
+    for (pay, tx) in [("pay_c1", "tx_c1"), ("pay_c2", "tx_c2")] {
+        let pay_id = buy_ticket(&client, &env, pay, "event_1", &buyer, &usdc_id, amount);
+        client.confirm_payment(&pay_id, &String::from_str(&env, tx));
+    }
+    // Pending payments are not refunded by a bulk refund
+    buy_ticket(&client, &env, "pay_c3", "event_1", &buyer, &usdc_id, amount);
+    // Partial refunds already paid are not owed again
+    let partial = client.admin_partial_refund(&String::from_str(&env, "pay_c1"), &1000);
+
+    let preview = client.preview_cancellation(&event_id);
+    assert_eq!(preview.confirmed_count, 2);
+    assert_eq!(preview.total_refundable, amount * 2 - partial);
+    assert_eq!(
+        preview.refundable_by_token.get(usdc_id.clone()),
+        Some(amount * 2 - partial)
+    );
+    assert_eq!(preview.platform_fees_reversed, fee * 2);
+    assert_eq!(
+        preview.escrow_by_token.get(usdc_id.clone()),
+        Some(amount * 3 - partial)
+    );
+    assert_eq!(preview.shortfall_by_token.get(usdc_id.clone()), Some(0));
+
+    // Once the organizer withdraws, escrow no longer covers the refunds
+    client.withdraw_organizer_funds(&event_id, &usdc_id);
+    let preview = client.preview_cancellation(&event_id);
+    assert_eq!(preview.escrow_by_token.get(usdc_id.clone()), Some(fee * 3));
+    assert_eq!(
+        preview.shortfall_by_token.get(usdc_id.clone()),
+        Some(amount * 2 - partial - fee * 3)
+    );
+
+    // Preview is read-only
+    let payment = client
+        .get_payment_status(&String::from_str(&env, "pay_c1"))
+        .unwrap();
+    assert_eq!(payment.status, PaymentStatus::Confirmed);
+}
+
+// =============================================================================
+// 17. Multiple registry tenants
+// =============================================================================
+
+#[test]
+fn test_e2e_purchase_through_tenant_registry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let tenant_id = env.register(MockRegistryE2E, ());
+    let buyer = Address::generate(&env);
+    let scanner = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let fee = (amount * 500) / 10000;
+    let event_id = String::from_str(&env, "acme:gala");
+
+    env.as_contract(&tenant_id, || {
+        MockRegistryE2E::set_scanner(env.clone(), scanner.clone());
+    });
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 3);
+
+    let buy_via = |payment_id: &str, event_id: &String, registry: &Address| {
+        client.try_process_payment(
+            &String::from_str(&env, payment_id),
+            event_id,
+            &String::from_str(&env, "tier_1"),
+            &buyer,
+            &usdc_id,
+            &amount,
+            &1,
+            &None,
+            &None,
+            &Some(PurchaseOptions {
+                identity_hash: None,
+                memo: None,
+                registry: Some(registry.clone()),
+                seats: soroban_sdk::Vec::new(&env),
+                max_slippage_bps: None,
+                voucher_code: None,
+                promoter: None,
+            }),
+        )
+    };
+
+    // The event id is the tenant's alone; the default registry has no such event
+    env.as_contract(&registry_id, || {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "unknown"), &event_id);
+    });
+
+    // Unknown registries are rejected until the admin allows them
+    assert!(client.is_registry_allowed(&registry_id));
+    assert!(!client.is_registry_allowed(&tenant_id));
+    assert_eq!(
+        client.try_bind_tenant_event(&tenant_id, &event_id),
+        Err(Ok(TicketPaymentError::RegistryNotAllowed))
+    );
+    client.set_registry_allowed(&tenant_id, &true);
+
+    // A tenant only binds ids in the namespace reserved for it
+    assert_eq!(
+        client.try_bind_tenant_event(&tenant_id, &event_id),
+        Err(Ok(TicketPaymentError::TenantNamespaceUnavailable))
+    );
+    for namespace in ["", "ac:me", "a_namespace_too_long"] {
+        assert_eq!(
+            client.try_reserve_tenant_namespace(&tenant_id, &String::from_str(&env, namespace)),
+            Err(Ok(TicketPaymentError::TenantNamespaceUnavailable))
+        );
+    }
+    assert_eq!(
+        client.try_reserve_tenant_namespace(&registry_id, &String::from_str(&env, "main")),
+        Err(Ok(TicketPaymentError::RegistryNotAllowed))
+    );
+    client.reserve_tenant_namespace(&tenant_id, &String::from_str(&env, "acme"));
+    assert_eq!(
+        client.get_tenant_namespace(&tenant_id),
+        Some(String::from_str(&env, "acme"))
+    );
+    assert_eq!(
+        client.try_reserve_tenant_namespace(&tenant_id, &String::from_str(&env, "acme2")),
+        Err(Ok(TicketPaymentError::TenantNamespaceUnavailable))
+    );
+
+    // Buyers cannot bind an event to a registry; only the registry can
+    assert_eq!(
+        buy_via("pay_t0", &event_id, &tenant_id),
+        Err(Ok(TicketPaymentError::RegistryNotAllowed))
+    );
+    client.bind_tenant_event(&tenant_id, &event_id);
+    assert_eq!(
+        env.auths().first().map(|(signer, _)| signer.clone()),
+        Some(tenant_id.clone())
+    );
+    buy_via("pay_t1", &event_id, &tenant_id).unwrap().unwrap();
+    assert_eq!(client.get_event_registry_for(&event_id), tenant_id);
+    assert_eq!(client.get_tenant_fees_collected(&tenant_id, &usdc_id), fee);
+    assert_eq!(client.get_tenant_fees_collected(&registry_id, &usdc_id), 0);
+
+    // The event stays bound to its tenant, and other tenants cannot claim it
+    assert_eq!(
+        buy_via("pay_t2", &event_id, &registry_id),
+        Err(Ok(TicketPaymentError::RegistryNotAllowed))
+    );
+    let other_tenant = env.register(MockRegistryE2E, ());
+    client.set_registry_allowed(&other_tenant, &true);
+    assert_eq!(
+        client.try_reserve_tenant_namespace(&other_tenant, &String::from_str(&env, "acme")),
+        Err(Ok(TicketPaymentError::TenantNamespaceUnavailable))
+    );
+    client.reserve_tenant_namespace(&other_tenant, &String::from_str(&env, "other"));
+    assert_eq!(
+        client.try_bind_tenant_event(&other_tenant, &event_id),
+        Err(Ok(TicketPaymentError::TenantNamespaceUnavailable))
+    );
+
+    // Ids in a reserved namespace are held for the tenant, even unregistered
+    assert_eq!(
+        client.try_bind_tenant_event(&registry_id, &String::from_str(&env, "acme:next")),
+        Err(Ok(TicketPaymentError::RegistryNotAllowed))
+    );
+    client.bind_tenant_event(&registry_id, &String::from_str(&env, "free:next"));
+
+    // Later lookups (scanner authorization) resolve through the tenant registry
+    let pay_id = String::from_str(&env, "pay_t1");
+    client.confirm_payment(&pay_id, &String::from_str(&env, "tx_t1"));
+    client.check_in(&pay_id, &scanner);
+    assert_eq!(
+        client.get_payment_status(&pay_id).unwrap().status,
+        PaymentStatus::CheckedIn
+    );
+
+    // Events of the default registry cannot be claimed by a tenant
+    assert_eq!(
+        client.try_bind_tenant_event(&tenant_id, &String::from_str(&env, "event_1")),
+        Err(Ok(TicketPaymentError::TenantNamespaceUnavailable))
+    );
+    buy_ticket(&client, &env, "pay_d1", "event_1", &buyer, &usdc_id, amount);
+    assert_eq!(
+        buy_via("pay_d2", &String::from_str(&env, "event_1"), &tenant_id),
+        Err(Ok(TicketPaymentError::RegistryNotAllowed))
+    );
+    assert_eq!(
+        client.get_tenant_fees_collected(&registry_id, &usdc_id),
+        fee
+    );
+}
+
+// =============================================================================
+// 18. Pricing breakdown preview
+// =============================================================================
+
+#[test]
+fn test_e2e_pricing_preview_matches_purchase() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let referrer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let event_id = String::from_str(&env, "event_1");
+
+    let preview = client.preview_pricing_breakdown(&event_id, &amount, &2, &0, &true);
+    assert_eq!(preview.gross, amount * 2);
+    assert_eq!(preview.net_paid, amount * 2);
+
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 2);
+    client.process_payment(
+        &String::from_str(&env, "pay_pp"),
+        &event_id,
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &amount,
+        &2,
+        &None,
+        &Some(referrer.clone()),
+        &None,
+    );
+
+    let balance = client.get_event_escrow_balance(&event_id);
+    assert_eq!(balance.organizer_amount, preview.organizer_net);
+    assert_eq!(balance.platform_fee, preview.platform_fee);
+    assert_eq!(
+        client.get_referral_rewards(&referrer, &usdc_id),
+        preview.referral_cut
+    );
+}
+
+// =============================================================================
+// 19. Box office allotments
+// =============================================================================
+
+#[test]
+fn test_e2e_box_office_allotment_lifecycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, _usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let organizer = Address::generate(&env);
+    let custodian = Address::generate(&env);
+    let walk_up = Address::generate(&env);
+    let event_id = String::from_str(&env, "event_1");
+    let tier_id = String::from_str(&env, "tier_1");
+
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_organizer(env.clone(), organizer.clone());
+    });
+
+    client.allocate_box_office(&event_id, &tier_id, &2, &custodian);
+    assert_eq!(
+        client.get_box_office_allotment(&event_id, &tier_id, &custodian),
+        2
+    );
+    let supply = || {
+        env.as_contract(&registry_id, || {
+            env.storage()
+                .instance()
+                .get::<_, i128>(&Symbol::new(&env, "supply"))
+                .unwrap_or(0)
+        })
+    };
+    assert_eq!(supply(), 2);
+
+    for pay in ["box_1", "box_2"] {
+        client.assign_from_allotment(
+            &String::from_str(&env, pay),
+            &event_id,
+            &tier_id,
+            &custodian,
+            &walk_up,
+        );
+    }
+    // Assignments draw from the allotment without touching supply again
+    assert_eq!(supply(), 2);
+    assert_eq!(
+        client.get_box_office_allotment(&event_id, &tier_id, &custodian),
+        0
+    );
+    assert_eq!(
+        client.try_assign_from_allotment(
+            &String::from_str(&env, "box_3"),
+            &event_id,
+            &tier_id,
+            &custodian,
+            &walk_up,
+        ),
+        Err(Ok(TicketPaymentError::MaxSupplyExceeded))
+    );
+
+    let ticket = client
+        .get_payment_status(&String::from_str(&env, "box_1"))
+        .unwrap();
+    assert_eq!(ticket.buyer_address, walk_up);
+    assert_eq!(ticket.status, PaymentStatus::Confirmed);
+    assert_eq!(ticket.amount, 0);
+    assert_eq!(
+        client.get_event_escrow_balance(&event_id).organizer_amount,
+        0
+    );
+}
+
+// =============================================================================
+// 20. Damage deposits
+// =============================================================================
+
+#[test]
+fn test_e2e_damage_deposit_deduction_and_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let deposit = 200_0000000i128;
+    let event_id = String::from_str(&env, "event_1");
+    let usdc = token::Client::new(&env, &usdc_id);
+
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_organizer(env.clone(), organizer.clone());
+        MockRegistryE2E::set_deposit(env.clone(), deposit);
+    });
+    fund_buyer(
+        &env,
+        &usdc_id,
+        &buyer,
+        &client.address,
+        amount * 2 + deposit * 2,
+    );
+
+    let pay_a = buy_ticket(&client, &env, "pay_a", "event_1", &buyer, &usdc_id, amount);
+    let pay_b = buy_ticket(&client, &env, "pay_b", "event_1", &buyer, &usdc_id, amount);
+    assert_eq!(usdc.balance(&client.address), (amount + deposit) * 2);
+    // The deposit is escrowed outside the event balance
+    assert_eq!(
+        client.get_event_escrow_balance(&event_id).organizer_amount,
+        amount * 2 - (amount * 2 * 500) / 10000
+    );
+
+    // Nothing can happen while the event is still running
+    assert_eq!(
+        client.try_flag_deposit_deduction(&pay_a, &50, &String::from_str(&env, "early")),
+        Err(Ok(TicketPaymentError::EventNotCompleted))
+    );
+    assert_eq!(
+        client.try_release_deposit(&pay_a),
+        Err(Ok(TicketPaymentError::EventNotCompleted))
+    );
+
+    // Deactivating the event does not end it
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_active(env.clone(), false);
+    });
+    assert_eq!(
+        client.try_open_deposit_window(&event_id),
+        Err(Ok(TicketPaymentError::EventNotCompleted))
+    );
+
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::complete_event(env.clone(), 1_000);
+    });
+    env.ledger().set_timestamp(1_000);
+    let reason = String::from_str(&env, "broken chair");
+    assert_eq!(
+        client.try_flag_deposit_deduction(&pay_a, &(deposit + 1), &reason),
+        Err(Ok(TicketPaymentError::InvalidDepositDeduction))
+    );
+    client.flag_deposit_deduction(&pay_a, &(deposit / 4), &reason);
+    assert_eq!(
+        client.get_ticket_deposit(&pay_a).unwrap().reason,
+        Some(reason.clone())
+    );
+
+    // The crank only runs after the deduction window closes
+    assert_eq!(
+        client.try_release_deposit(&pay_b),
+        Err(Ok(TicketPaymentError::EventNotCompleted))
+    );
+    env.ledger()
+        .set_timestamp(1_000 + super::contract::DEPOSIT_CLAIM_WINDOW);
+    assert_eq!(
+        client.try_flag_deposit_deduction(&pay_b, &1, &reason),
+        Err(Ok(TicketPaymentError::RefundDeadlinePassed))
+    );
+
+    let buyer_before = usdc.balance(&buyer);
+    assert_eq!(client.release_deposit(&pay_a), deposit - deposit / 4);
+    assert_eq!(client.release_deposit(&pay_b), deposit);
+    assert_eq!(
+        usdc.balance(&buyer),
+        buyer_before + deposit * 2 - deposit / 4
+    );
+    assert_eq!(usdc.balance(&organizer), deposit / 4);
+    assert_eq!(
+        client.try_release_deposit(&pay_a),
+        Err(Ok(TicketPaymentError::NoFundsAvailable))
+    );
+}
+
+// =============================================================================
+// 21. Loyalty rewards hook
+// =============================================================================
+
+#[test]
+fn test_e2e_rewards_hook_notified_and_isolated() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let scanner = Address::generate(&env);
+    let amount = 1000_0000000i128;
+
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_scanner(env.clone(), scanner.clone());
+    });
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 2);
+
+    let rewards_id = env.register(MockRewards, ());
+    let rewards = MockRewardsClient::new(&env, &rewards_id);
+    client.set_rewards_contract(&Some(rewards_id.clone()));
+    assert_eq!(client.get_rewards_contract(), Some(rewards_id));
+
+    let pay_id = buy_ticket(&client, &env, "pay_r1", "event_1", &buyer, &usdc_id, amount);
+    // Pending purchases earn nothing yet
+    assert_eq!(rewards.points(&buyer), 0);
+    client.confirm_payment(&pay_id, &String::from_str(&env, "tx_r1"));
+    assert_eq!(rewards.points(&buyer), amount);
+    client.check_in(&pay_id, &scanner);
+    assert_eq!(rewards.points(&buyer), amount + 1);
+
+    // A failing rewards contract does not block confirmation or check-in
+    client.set_rewards_contract(&Some(env.register(MockBrokenRewards, ())));
+    let pay_id = buy_ticket(&client, &env, "pay_r2", "event_1", &buyer, &usdc_id, amount);
+    client.confirm_payment(&pay_id, &String::from_str(&env, "tx_r2"));
+    client.check_in(&pay_id, &scanner);
+    assert_eq!(
+        client.get_payment_status(&pay_id).unwrap().status,
+        PaymentStatus::CheckedIn
+    );
+
+    client.set_rewards_contract(&None);
+    assert_eq!(client.get_rewards_contract(), None);
+}
+
+// =============================================================================
+// 22. Organizer escrow recovery (dead-man's switch)
+// =============================================================================
+
+#[test]
+fn test_e2e_escrow_recovery_after_organizer_inactivity() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let organizer = Address::generate(&env);
+    let recovery = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let event_id = String::from_str(&env, "event_1");
+
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_organizer(env.clone(), organizer.clone());
+        MockRegistryE2E::set_recovery(env.clone(), recovery.clone(), 1_000);
+    });
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+    buy_ticket(
+        &client, &env, "pay_rec", "event_1", &buyer, &usdc_id, amount,
+    );
+    let eurc_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_token(&eurc_id);
+    fund_buyer(&env, &eurc_id, &buyer, &client.address, amount);
+    buy_ticket(
+        &client,
+        &env,
+        "pay_rec_eurc",
+        "event_1",
+        &buyer,
+        &eurc_id,
+        amount,
+    );
+
+    // Organizer activity pushes the inactivity deadline out
+    env.ledger().set_timestamp(500);
+    assert_eq!(
+        client.try_initiate_escrow_recovery(&event_id),
+        Err(Ok(TicketPaymentError::RecoveryNotReady))
+    );
+    client.record_organizer_activity(&event_id);
+    env.ledger().set_timestamp(1_200);
+    assert_eq!(
+        client.try_initiate_escrow_recovery(&event_id),
+        Err(Ok(TicketPaymentError::RecoveryNotReady))
+    );
+
+    // The admin can veto, and the organizer resurfacing voids a claim
+    env.ledger().set_timestamp(1_500);
+    client.initiate_escrow_recovery(&event_id);
+    assert_eq!(
+        client.try_claim_escrow_recovery(&event_id),
+        Err(Ok(TicketPaymentError::RecoveryNotReady))
+    );
+    client.veto_escrow_recovery(&event_id);
+    assert_eq!(client.get_recovery_claim(&event_id), None);
+
+    client.initiate_escrow_recovery(&event_id);
+    client.record_organizer_activity(&event_id);
+    assert_eq!(client.get_recovery_claim(&event_id), None);
+
+    // Undisturbed, the recovery address receives the organizer escrow in
+    // every token the event sold in
+    env.ledger().set_timestamp(2_500);
+    client.initiate_escrow_recovery(&event_id);
+    env.ledger()
+        .set_timestamp(2_500 + super::contract::RECOVERY_VETO_WINDOW);
+    // Both purchases were the same size, so each token holds half
+    let organizer_amount = client.get_event_escrow_balance(&event_id).organizer_amount / 2;
+    let recovered = client.claim_escrow_recovery(&event_id);
+    assert_eq!(recovered.len(), 2);
+    assert_eq!(recovered.get(usdc_id.clone()), Some(organizer_amount));
+    assert_eq!(recovered.get(eurc_id.clone()), Some(organizer_amount));
+    for token_id in [&usdc_id, &eurc_id] {
+        assert_eq!(
+            token::Client::new(&env, token_id).balance(&recovery),
+            organizer_amount
+        );
+    }
+    assert_eq!(
+        client.get_event_escrow_balance(&event_id).organizer_amount,
+        0
+    );
+    assert_eq!(client.get_recovery_claim(&event_id), None);
+}
+
+// =============================================================================
+// 23. Fee settlement invoices
+// =============================================================================
+
+#[test]
+fn test_e2e_fee_settlements_have_deterministic_invoice_ids() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let fee = (amount * 500) / 10000;
+    let event_1 = String::from_str(&env, "event_1");
+    let event_2 = String::from_str(&env, "event_2");
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 3);
+
+    buy_ticket(&client, &env, "pay_i1", "event_1", &buyer, &usdc_id, amount);
+    assert_eq!(client.settle_platform_fees(&event_1, &usdc_id), fee);
+    buy_ticket(&client, &env, "pay_i2", "event_1", &buyer, &usdc_id, amount);
+    buy_ticket(&client, &env, "pay_i3", "event_2", &buyer, &usdc_id, amount);
+    assert_eq!(
+        client
+            .settle_all_platform_fees(&0, &10)
+            .get(usdc_id.clone()),
+        Some(fee * 2)
+    );
+
+    assert_eq!(client.get_settlement_count(), 3);
+    let history = client.get_settlement_history(&0, &10);
+    let expected = [(&event_1, 0u32), (&event_1, 1), (&event_2, 0)];
+    for (record, (event_id, period)) in history.iter().zip(expected) {
+        assert_eq!(&record.event_id, event_id);
+        assert_eq!(record.period, period);
+        assert_eq!(record.amount, fee);
+        assert_eq!(
+            record.invoice_id,
+            super::contract::fee_invoice_id(&env, event_id.clone(), period, fee, usdc_id.clone())
+        );
+    }
+    // Same amount in a later period still yields a distinct invoice
+    assert_ne!(
+        history.get(0).unwrap().invoice_id,
+        history.get(1).unwrap().invoice_id
+    );
+    assert_eq!(client.get_settlement_history(&2, &10).len(), 1);
+}
+
+// =============================================================================
+// 24. Purchase receipts
+// =============================================================================
+
+#[test]
+fn test_e2e_receipt_issued_on_confirmation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+
+    let pay_id = buy_ticket(
+        &client, &env, "pay_rcpt", "event_1", &buyer, &usdc_id, amount,
+    );
+    assert_eq!(client.get_receipt(&pay_id), None);
+
+    client.confirm_payment(&pay_id, &String::from_str(&env, "tx_rcpt"));
+    let (_, _, data) = env.events().all().last().unwrap();
+    let confirmed: crate::events::PaymentStatusChangedEvent = data.try_into_val(&env).unwrap();
+
+    let receipt = client.get_receipt(&pay_id).unwrap();
+    assert_eq!(receipt.event_id, String::from_str(&env, "event_1"));
+    assert_eq!(receipt.tier_id, String::from_str(&env, "tier_1"));
+    assert_eq!(receipt.buyer, buyer);
+    assert_eq!(receipt.token, usdc_id);
+    assert_eq!(receipt.quantity, 1);
+    assert_eq!(receipt.unit_amount, amount);
+    assert_eq!(receipt.platform_fee + receipt.organizer_amount, amount);
+    assert_eq!(
+        confirmed.receipt_hash,
+        Some(super::contract::receipt_hash_of(&env, &receipt))
+    );
+}
+
+// =============================================================================
+// 25. Availability view
+// =============================================================================
+
+#[test]
+fn test_e2e_availability_accounts_for_box_office_reservations() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let organizer = Address::generate(&env);
+    let custodian = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = String::from_str(&env, "event_1");
+    let tier_id = String::from_str(&env, "tier_1");
+
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_organizer(env.clone(), organizer.clone());
+    });
+
+    let fresh = client.get_availability(&event_id);
+    assert_eq!(fresh.available, 1000);
+    assert_eq!(fresh.sold, 0);
+
+    client.allocate_box_office(&event_id, &tier_id, &3, &custodian);
+    client.assign_from_allotment(
+        &String::from_str(&env, "box_1"),
+        &event_id,
+        &tier_id,
+        &custodian,
+        &buyer,
+    );
+    let amount = 1000_0000000i128;
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+    buy_ticket(&client, &env, "pay_1", "event_1", &buyer, &usdc_id, amount);
+
+    let availability = client.get_availability(&event_id);
+    let tier = availability.tiers.get(tier_id).unwrap();
+    assert_eq!(
+        tier,
+        TierAvailability {
+            limit: 1000,
+            sold: 2,
+            reserved: 2,
+            available: 996,
+        }
+    );
+    assert_eq!(availability.max_supply, 0);
+    assert_eq!(availability.sold, 2);
+    assert_eq!(availability.reserved, 2);
+    assert_eq!(availability.available, 996);
+}
+
+// =============================================================================
+// 26. Failed purchase records
+// =============================================================================
+
+#[test]
+fn test_e2e_failed_purchase_ring_is_bounded() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let event_id = String::from_str(&env, "event_1");
+
+    let amount = 1000_0000000i128;
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+    buy_ticket(&client, &env, "pay_ok", "event_1", &buyer, &usdc_id, amount);
+    assert_eq!(
+        client.try_report_failed_purchase(
+            &String::from_str(&env, "pay_ok"),
+            &event_id,
+            &buyer,
+            &(TicketPaymentError::TransferVerificationFailed as u32),
+        ),
+        Err(Ok(TicketPaymentError::InvalidPaymentStatus))
+    );
+
+    let ids = [
+        "f_00", "f_01", "f_02", "f_03", "f_04", "f_05", "f_06", "f_07", "f_08", "f_09", "f_10",
+        "f_11", "f_12", "f_13", "f_14", "f_15", "f_16", "f_17", "f_18", "f_19", "f_20", "f_21",
+        "f_22", "f_23", "f_24", "f_25", "f_26", "f_27", "f_28", "f_29", "f_30", "f_31", "f_32",
+        "f_33", "f_34", "f_35", "f_36", "f_37", "f_38", "f_39", "f_40", "f_41", "f_42", "f_43",
+        "f_44", "f_45", "f_46", "f_47", "f_48", "f_49", "f_50",
+    ];
+    assert_eq!(ids.len() as u32, MAX_FAILED_PURCHASES + 1);
+    for id in ids {
+        client.report_failed_purchase(
+            &String::from_str(&env, id),
+            &event_id,
+            &buyer,
+            &(TicketPaymentError::TransferVerificationFailed as u32),
+        );
+    }
+
+    let records = client.get_failed_purchases();
+    assert_eq!(records.len(), MAX_FAILED_PURCHASES);
+    // The oldest attempt was evicted
+    assert_eq!(
+        records.first().unwrap().payment_id,
+        String::from_str(&env, "f_01")
+    );
+    let latest = records.last().unwrap();
+    assert_eq!(latest.payment_id, String::from_str(&env, "f_50"));
+    assert_eq!(latest.buyer, buyer);
+    assert_eq!(latest.reason_code, 13);
+}
+
+// =============================================================================
+// 27. Attendance coupon airdrops
+// =============================================================================
+
+#[test]
+fn test_e2e_attendance_coupons_airdropped_and_redeemed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let organizer = Address::generate(&env);
+    let scanner = Address::generate(&env);
+    let attendee = Address::generate(&env);
+    let no_show = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let past = String::from_str(&env, "event_1");
+    let next = String::from_str(&env, "event_2");
+
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_organizer(env.clone(), organizer.clone());
+        MockRegistryE2E::set_scanner(env.clone(), scanner.clone());
+    });
+    fund_buyer(&env, &usdc_id, &attendee, &client.address, amount * 3);
+    fund_buyer(&env, &usdc_id, &no_show, &client.address, amount);
+
+    for (pay, buyer) in [
+        ("pay_a1", &attendee),
+        ("pay_a2", &attendee),
+        ("pay_n1", &no_show),
+    ] {
+        let pay_id = buy_ticket(&client, &env, pay, "event_1", buyer, &usdc_id, amount);
+        client.confirm_payment(&pay_id, &String::from_str(&env, pay));
+    }
+    client.check_in(&String::from_str(&env, "pay_a1"), &scanner);
+    client.check_in(&String::from_str(&env, "pay_a2"), &scanner);
+
+    assert_eq!(
+        client.try_airdrop_attendance_coupons(&past, &next, &0, &10),
+        Err(Ok(TicketPaymentError::InvalidDiscountBps))
+    );
+
+    // Paged crank: one attendee holding two tickets gets a single coupon
+    assert_eq!(
+        client.airdrop_attendance_coupons(&past, &next, &2000, &2),
+        1
+    );
+    assert_eq!(
+        client.airdrop_attendance_coupons(&past, &next, &2000, &2),
+        0
+    );
+    assert_eq!(
+        client.airdrop_attendance_coupons(&past, &next, &2000, &2),
+        0
+    );
+    assert_eq!(client.get_coupon(&next, &attendee), 2000);
+    assert_eq!(client.get_coupon(&next, &no_show), 0);
+
+    // The coupon is applied automatically and consumed by the next purchase
+    let discounted = amount * 8 / 10;
+    assert_eq!(
+        client.try_process_payment(
+            &String::from_str(&env, "pay_full"),
+            &next,
+            &String::from_str(&env, "tier_1"),
+            &attendee,
+            &usdc_id,
+            &amount,
+            &1,
+            &None,
+            &None,
+            &None,
+        ),
+        Err(Ok(TicketPaymentError::InvalidPrice))
+    );
+    buy_ticket(
+        &client, &env, "pay_next", "event_2", &attendee, &usdc_id, discounted,
+    );
+    assert_eq!(
+        client
+            .get_payment_status(&String::from_str(&env, "pay_next"))
+            .unwrap()
+            .amount,
+        discounted
+    );
+    assert_eq!(client.get_coupon(&next, &attendee), 0);
+}
+
+// =============================================================================
+// 28. Per-event platform promo modes
+// =============================================================================
+
+#[test]
+fn test_e2e_promo_mode_opt_out_and_platform_absorbed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let event_id = String::from_str(&env, "event_1");
+    let set_mode = |mode: event_registry::PromoMode| {
+        env.as_contract(&registry_id, || {
+            MockRegistryE2E::set_promo_mode(env.clone(), mode);
+        });
+    };
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_promo(env.clone(), 1000, u64::MAX);
+    });
+
+    let preview = || client.preview_pricing_breakdown(&event_id, &amount, &1, &0, &false);
+    assert_eq!(preview().net_paid, amount * 9 / 10);
+    assert_eq!(preview().organizer_net, amount * 9 / 10 * 95 / 100);
+
+    set_mode(event_registry::PromoMode::OptOut);
+    assert_eq!(preview().promo_reduction, 0);
+    assert_eq!(preview().net_paid, amount);
+
+    // The platform gives up its 5% fee; the organizer nets the full-price share
+    set_mode(event_registry::PromoMode::PlatformAbsorbed);
+    let absorbed = preview();
+    assert_eq!(absorbed.net_paid, amount * 95 / 100);
+    assert_eq!(absorbed.organizer_net, amount * 95 / 100);
+    assert_eq!(absorbed.platform_fee, 0);
+
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+    buy_ticket(
+        &client, &env, "pay_abs", "event_1", &buyer, &usdc_id, amount,
+    );
+    let balance = client.get_event_escrow_balance(&event_id);
+    assert_eq!(balance.organizer_amount, amount * 95 / 100);
+    assert_eq!(balance.platform_fee, 0);
+    assert_eq!(
+        token::Client::new(&env, &usdc_id).balance(&buyer),
+        amount - absorbed.net_paid
+    );
+
+    // The report shows the buyer's discount and the promo cost the platform bore
+    let line = client
+        .get_event_settlement_report(&event_id)
+        .get(0)
+        .unwrap();
+    assert_eq!(line.gross_sales, amount);
+    assert_eq!(line.discounts_granted, amount - absorbed.net_paid);
+    assert_eq!(
+        line.promo_absorbed,
+        absorbed.organizer_net - amount * 9 / 10 * 95 / 100
+    );
+}
+
+// =============================================================================
+// 29. Ticket-holder polls
+// =============================================================================
+
+#[test]
+fn test_e2e_ticket_holder_poll_weighted_by_snapshot() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let organizer = Address::generate(&env);
+    let whale = Address::generate(&env);
+    let fan = Address::generate(&env);
+    let late = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let event_id = String::from_str(&env, "event_1");
+
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_organizer(env.clone(), organizer.clone());
+    });
+    fund_buyer(&env, &usdc_id, &whale, &client.address, amount * 2);
+    fund_buyer(&env, &usdc_id, &fan, &client.address, amount);
+    fund_buyer(&env, &usdc_id, &late, &client.address, amount);
+    for (pay, buyer) in [("pay_w1", &whale), ("pay_w2", &whale), ("pay_f1", &fan)] {
+        let pay_id = buy_ticket(&client, &env, pay, "event_1", buyer, &usdc_id, amount);
+        client.confirm_payment(&pay_id, &String::from_str(&env, pay));
+    }
+
+    env.ledger().set_timestamp(100);
+    let question = BytesN::from_array(&env, &[7u8; 32]);
+    assert_eq!(
+        client.try_open_poll(&event_id, &question, &1, &1000),
+        Err(Ok(TicketPaymentError::InvalidPoll))
+    );
+    let poll_id = client.open_poll(&event_id, &question, &2, &1000);
+
+    // Tickets bought after the poll opened carry no weight
+    env.ledger().set_timestamp(200);
+    let pay_id = buy_ticket(&client, &env, "pay_l1", "event_1", &late, &usdc_id, amount);
+    client.confirm_payment(&pay_id, &String::from_str(&env, "pay_l1"));
+    assert_eq!(
+        client.try_vote(&event_id, &poll_id, &late, &0),
+        Err(Ok(TicketPaymentError::Unauthorized))
+    );
+
+    assert_eq!(client.vote(&event_id, &poll_id, &whale, &1), 2);
+    assert_eq!(client.vote(&event_id, &poll_id, &fan, &0), 1);
+    // A ticket passed on after voting cannot be counted again
+    client.transfer_ticket(&String::from_str(&env, "pay_w1"), &late, &None, &None);
+    assert_eq!(
+        client.try_vote(&event_id, &poll_id, &late, &0),
+        Err(Ok(TicketPaymentError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_vote(&event_id, &poll_id, &fan, &5),
+        Err(Ok(TicketPaymentError::InvalidPoll))
+    );
+
+    let poll = client.get_poll(&event_id, &poll_id).unwrap();
+    assert_eq!(poll.tallies, soroban_sdk::vec![&env, 1u32, 2u32]);
+    assert_eq!(client.get_poll_outcome(&event_id, &poll_id), None);
+
+    env.ledger().set_timestamp(1000);
+    assert_eq!(
+        client.try_vote(&event_id, &poll_id, &fan, &0),
+        Err(Ok(TicketPaymentError::PollClosed))
+    );
+    assert_eq!(client.get_poll_outcome(&event_id, &poll_id), Some(1));
+}
+
+// =============================================================================
+// 30. Per-token fee treasuries
+// =============================================================================
+
+#[test]
+fn test_e2e_treasury_wallet_and_cap_per_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, platform_wallet, _registry_id) = setup_e2e(&env);
+    let custodian = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let fee = amount * 500 / 10000;
+
+    assert!(client.get_treasury_balances().is_empty());
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+    buy_ticket(&client, &env, "pay_t1", "event_1", &buyer, &usdc_id, amount);
+
+    assert_eq!(client.get_treasury_wallet(&usdc_id), platform_wallet);
+    client.set_treasury_wallet(&usdc_id, &Some(custodian.clone()));
+    client.set_withdrawal_cap(&usdc_id, &(fee / 2));
+    client.settle_platform_fees(&String::from_str(&env, "event_1"), &usdc_id);
+
+    client.withdraw_platform_fees(&(fee / 2), &usdc_id);
+    assert_eq!(
+        client.try_withdraw_platform_fees(&1, &usdc_id),
+        Err(Ok(TicketPaymentError::WithdrawalCapExceeded))
+    );
+    let usdc = token::Client::new(&env, &usdc_id);
+    assert_eq!(usdc.balance(&custodian), fee / 2);
+    assert_eq!(usdc.balance(&platform_wallet), 0);
+
+    let balances = client.get_treasury_balances();
+    assert_eq!(balances.len(), 1);
+    let treasury = balances.get(0).unwrap();
+    assert_eq!(treasury.token, usdc_id);
+    assert_eq!(treasury.wallet, custodian);
+    assert_eq!(treasury.balance, fee - fee / 2);
+    assert_eq!(treasury.withdrawal_cap, fee / 2);
+    assert_eq!(treasury.withdrawn_today, fee / 2);
+
+    // Clearing the override routes payouts back to the platform wallet
+    client.set_treasury_wallet(&usdc_id, &None);
+    env.ledger().set_timestamp(86400);
+    client.withdraw_platform_fees(&(fee - fee / 2), &usdc_id);
+    assert_eq!(usdc.balance(&platform_wallet), fee - fee / 2);
+}
+
+// =============================================================================
+// 31. Event operator grants
+// =============================================================================
+
+#[test]
+fn test_e2e_event_operator_runs_scoped_refunds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let organizer = Address::generate(&env);
+    let bot = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let event_1 = String::from_str(&env, "event_1");
+    let event_2 = String::from_str(&env, "event_2");
+
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_organizer(env.clone(), organizer.clone());
+    });
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 3);
+    for (pay, event) in [
+        ("pay_o1", "event_1"),
+        ("pay_o2", "event_1"),
+        ("pay_o3", "event_2"),
+    ] {
+        let pay_id = buy_ticket(&client, &env, pay, event, &buyer, &usdc_id, amount);
+        client.confirm_payment(&pay_id, &String::from_str(&env, pay));
+    }
+
+    assert_eq!(
+        client.try_trigger_bulk_refund_as_operator(&bot, &event_1, &10),
+        Err(Ok(TicketPaymentError::Unauthorized))
+    );
+
+    // Grants are scoped to one event and to the permissions in the mask
+    client.grant_event_operator(&event_1, &bot, &OPERATOR_BULK_REFUND);
+    client.grant_event_operator(&event_2, &bot, &OPERATOR_PARTIAL_REFUND);
+    assert_eq!(
+        client.get_event_operator_permissions(&event_1, &bot),
+        OPERATOR_BULK_REFUND
+    );
+    assert_eq!(
+        client.try_issue_partial_refund_as_operator(&bot, &event_1, &1000, &10),
+        Err(Ok(TicketPaymentError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_trigger_bulk_refund_as_operator(&bot, &event_2, &10),
+        Err(Ok(TicketPaymentError::Unauthorized))
+    );
+    assert_eq!(
+        client.issue_partial_refund_as_operator(&bot, &event_2, &1000, &10),
+        1
+    );
+    assert_eq!(
+        client.trigger_bulk_refund_as_operator(&bot, &event_1, &1),
+        1
+    );
+
+    // Revoking stops the bot mid-way; the organizer can still finish
+    client.grant_event_operator(&event_1, &bot, &0);
+    assert_eq!(
+        client.try_trigger_bulk_refund_as_operator(&bot, &event_1, &1),
+        Err(Ok(TicketPaymentError::Unauthorized))
+    );
+    assert_eq!(client.trigger_bulk_refund(&event_1, &1), 1);
+}
+
+// =============================================================================
+// 32. Ticket NFTs
+// =============================================================================
+
+#[test]
+fn test_e2e_ticket_nft_minted_transferred_and_burned() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, usdc_id, _pw, _reg) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 3);
+
+    // Tickets sold before the collection is registered have no token
+    let early = buy_ticket(&client, &env, "pay_n0", "event_1", &buyer, &usdc_id, amount);
+    assert_eq!(client.get_ticket_token_id(&early), None);
+
+    let nft_id = env.register(ticket_nft::TicketNft, ());
+    let nft = ticket_nft::TicketNftClient::new(&env, &nft_id);
+    nft.initialize(
+        &admin,
+        &client.address,
+        &String::from_str(&env, "Agora Tickets"),
+        &String::from_str(&env, "AGT"),
+    );
+    client.set_ticket_nft_contract(&Some(nft_id.clone()));
+    assert_eq!(client.get_ticket_nft_contract(), Some(nft_id));
+
+    let kept = buy_ticket(&client, &env, "pay_n1", "event_1", &buyer, &usdc_id, amount);
+    let refunded = buy_ticket(&client, &env, "pay_n2", "event_1", &buyer, &usdc_id, amount);
+    let kept_token = client.get_ticket_token_id(&kept).unwrap();
+    let refunded_token = client.get_ticket_token_id(&refunded).unwrap();
+    assert_eq!(nft.owner_of(&kept_token), buyer);
+    assert_eq!(nft.token_info(&kept_token).unwrap().payment_id, kept);
+    assert_eq!(nft.balance(&buyer), 2);
+
+    // The token follows the ticket on transfer
+    client.confirm_payment(&kept, &String::from_str(&env, "tx_n1"));
+    client.transfer_ticket(&kept, &new_owner, &None, &None);
+    assert_eq!(nft.owner_of(&kept_token), new_owner);
+    assert_eq!(nft.balance(&buyer), 1);
+
+    // Refunds burn the token
+    client.request_guest_refund(&refunded);
+    assert_eq!(client.get_ticket_token_id(&refunded), None);
+    assert!(nft.token_info(&refunded_token).is_none());
+    assert_eq!(nft.balance(&buyer), 0);
+    assert_eq!(nft.total_supply(), 1);
+
+    // Refunding a ticket without a token still works
+    client.request_guest_refund(&early);
+    assert_eq!(
+        client.get_payment_status(&early).unwrap().status,
+        PaymentStatus::Refunded
+    );
+}
+
+// =============================================================================
+// 33. Sponsor slots
+// =============================================================================
+
+#[test]
+fn test_e2e_sponsor_slots_sold_through_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _reg) = setup_e2e(&env);
+    let sponsor_a = Address::generate(&env);
+    let sponsor_b = Address::generate(&env);
+    let event_id = String::from_str(&env, "event_1");
+    let gold = String::from_str(&env, "gold");
+    let price = 5000_0000000i128;
+    let cid = String::from_str(
+        &env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+
+    // Invalid definitions are rejected
+    let define = |slot_id: &String, price: i128, quantity: u32| {
+        client.try_define_sponsor_slot(
+            &event_id,
+            slot_id,
+            &String::from_str(&env, "Gold"),
+            &price,
+            &cid,
+            &quantity,
+        )
+    };
+    assert_eq!(
+        define(&gold, 0, 1),
+        Err(Ok(TicketPaymentError::InvalidSponsorSlot))
+    );
+    assert_eq!(
+        define(&gold, price, MAX_EVENT_SPONSORS + 1),
+        Err(Ok(TicketPaymentError::InvalidSponsorSlot))
+    );
+    define(&gold, price, 1).unwrap().unwrap();
+    define(&String::from_str(&env, "silver"), price / 5, 3)
+        .unwrap()
+        .unwrap();
+
+    let slots = client.get_sponsor_slots(&event_id);
+    assert_eq!(slots.len(), 2);
+    assert_eq!(slots.get(0).unwrap().slot_id, gold);
+
+    // A purchase is escrowed with the event's 5% platform fee
+    fund_buyer(&env, &usdc_id, &sponsor_a, &client.address, price);
+    client.purchase_sponsor_slot(&event_id, &gold, &sponsor_a, &usdc_id);
+    let escrow = client.get_event_escrow_balance(&event_id);
+    assert_eq!(escrow.platform_fee, price * 500 / 10000);
+    assert_eq!(escrow.organizer_amount, price - price * 500 / 10000);
+    assert_eq!(token::Client::new(&env, &usdc_id).balance(&sponsor_a), 0);
+
+    let sponsors = client.get_event_sponsors(&event_id);
+    assert_eq!(sponsors.len(), 1);
+    assert_eq!(sponsors.get(0).unwrap().sponsor, sponsor_a);
+    assert_eq!(sponsors.get(0).unwrap().amount, price);
+    assert_eq!(client.get_sponsor_slots(&event_id).get(0).unwrap().sold, 1);
+
+    // Sold-out slots and unknown slots cannot be bought
+    fund_buyer(&env, &usdc_id, &sponsor_b, &client.address, price);
+    assert_eq!(
+        client.try_purchase_sponsor_slot(&event_id, &gold, &sponsor_b, &usdc_id),
+        Err(Ok(TicketPaymentError::SponsorSlotSoldOut))
+    );
+    assert_eq!(
+        client.try_purchase_sponsor_slot(
+            &event_id,
+            &String::from_str(&env, "platinum"),
+            &sponsor_b,
+            &usdc_id
+        ),
+        Err(Ok(TicketPaymentError::InvalidSponsorSlot))
+    );
+
+    // Redefining keeps sales, so quantity cannot drop below what was sold
+    assert_eq!(
+        client.try_define_sponsor_slot(
+            &event_id,
+            &gold,
+            &String::from_str(&env, "Gold"),
+            &price,
+            &cid,
+            &0
+        ),
+        Err(Ok(TicketPaymentError::InvalidSponsorSlot))
+    );
+    client.define_sponsor_slot(
+        &event_id,
+        &gold,
+        &String::from_str(&env, "Gold"),
+        &price,
+        &cid,
+        &2,
+    );
+    client.purchase_sponsor_slot(&event_id, &gold, &sponsor_b, &usdc_id);
+    assert_eq!(client.get_sponsor_slots(&event_id).get(0).unwrap().sold, 2);
+    assert_eq!(client.get_event_sponsors(&event_id).len(), 2);
+}
+
+// =============================================================================
+// 34. Resale marketplace
+// =============================================================================
+
+#[test]
+fn test_e2e_resale_marketplace_escrows_and_pays_royalty() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _reg) = setup_e2e(&env);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let friend = Address::generate(&env);
+    let event_id = String::from_str(&env, "event_1");
+    let amount = 1000_0000000i128;
+    let resale_price = 1200_0000000i128;
+    let usdc = token::Client::new(&env, &usdc_id);
+
+    fund_buyer(&env, &usdc_id, &seller, &client.address, amount);
+    let pay_id = buy_ticket(
+        &client, &env, "pay_m1", "event_1", &seller, &usdc_id, amount,
+    );
+
+    // Only confirmed tickets can be listed
+    assert_eq!(
+        client.try_list_ticket_for_sale(&pay_id, &resale_price, &usdc_id),
+        Err(Ok(TicketPaymentError::InvalidPaymentStatus))
+    );
+    client.confirm_payment(&pay_id, &String::from_str(&env, "tx_m1"));
+
+    assert_eq!(
+        client.try_set_resale_royalty(&event_id, &10001),
+        Err(Ok(TicketPaymentError::InvalidFeeBps))
+    );
+    client.set_resale_royalty(&event_id, &1000);
+    assert_eq!(client.get_resale_royalty(&event_id), 1000);
+
+    client.list_ticket_for_sale(&pay_id, &resale_price, &usdc_id);
+    assert_eq!(client.get_listing(&pay_id).unwrap().seller, seller);
+    assert_eq!(client.get_event_listings(&event_id).len(), 1);
+
+    // A listed ticket is in escrow
+    assert_eq!(
+        client.try_list_ticket_for_sale(&pay_id, &resale_price, &usdc_id),
+        Err(Ok(TicketPaymentError::TicketListed))
+    );
+    assert_eq!(
+        client.try_transfer_ticket(&pay_id, &friend, &None, &None),
+        Err(Ok(TicketPaymentError::TicketListed))
+    );
+    assert_eq!(
+        client.try_buy_listed_ticket(&pay_id, &seller),
+        Err(Ok(TicketPaymentError::InvalidAddress))
+    );
+
+    // Cancelling releases the ticket; relisting puts it back on the market
+    client.cancel_listing(&pay_id);
+    assert!(client.get_listing(&pay_id).is_none());
+    assert_eq!(client.get_event_listings(&event_id).len(), 0);
+    client.list_ticket_for_sale(&pay_id, &resale_price, &usdc_id);
+
+    let escrow_before = client.get_event_escrow_balance(&event_id);
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, resale_price);
+    client.buy_listed_ticket(&pay_id, &buyer);
+
+    let royalty = resale_price * 1000 / 10000;
+    assert_eq!(usdc.balance(&seller), resale_price - royalty);
+    assert_eq!(usdc.balance(&buyer), 0);
+    assert_eq!(
+        client.get_event_escrow_balance(&event_id).organizer_amount,
+        escrow_before.organizer_amount + royalty
+    );
+
+    let payment = client.get_payment_status(&pay_id).unwrap();
+    assert_eq!(payment.buyer_address, buyer);
+    assert_eq!(client.get_buyer_payments(&seller).len(), 0);
+    assert_eq!(client.get_buyer_payments(&buyer).len(), 1);
+    assert!(client.get_listing(&pay_id).is_none());
+    assert_eq!(client.get_event_listings(&event_id).len(), 0);
+    assert_eq!(
+        client.try_buy_listed_ticket(&pay_id, &friend),
+        Err(Ok(TicketPaymentError::InvalidPaymentStatus))
+    );
+}
+
+// =============================================================================
+// 35. Paginated views
+// =============================================================================
+
+#[test]
+fn test_e2e_buyer_payments_paginated_after_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _reg) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let friend = Address::generate(&env);
+    let amount = 1000_0000000i128;
+
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 3);
+    let mut ids = soroban_sdk::Vec::new(&env);
+    for id in ["pay_p1", "pay_p2", "pay_p3"] {
+        ids.push_back(buy_ticket(
+            &client, &env, id, "event_1", &buyer, &usdc_id, amount,
+        ));
+    }
+    client.confirm_payment(&ids.get(1).unwrap(), &String::from_str(&env, "tx_p2"));
+    client.transfer_ticket(&ids.get(1).unwrap(), &friend, &None, &None);
+
+    assert_eq!(client.get_buyer_payment_count(&buyer), 2);
+    let page = client.get_buyer_payments_paginated(&buyer, &1, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap(), ids.get(2).unwrap());
+    assert_eq!(
+        client.get_buyer_payments_paginated(&buyer, &0, &u32::MAX),
+        client.get_buyer_payments(&buyer)
+    );
+    assert_eq!(
+        client.get_buyer_payments_paginated(&friend, &0, &10).len(),
+        1
+    );
+}
+
+#[test]
+fn test_e2e_buyer_payments_detailed_filters_by_event_and_status() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _reg) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 4);
+    for (id, event) in [
+        ("pay_d1", "event_1"),
+        ("pay_d2", "event_2"),
+        ("pay_d3", "event_1"),
+        ("pay_d4", "event_1"),
+    ] {
+        buy_ticket(&client, &env, id, event, &buyer, &usdc_id, amount);
+    }
+    client.confirm_payment(
+        &String::from_str(&env, "pay_d3"),
+        &String::from_str(&env, "tx_d3"),
+    );
+
+    let all = client.get_buyer_payments_detailed(&buyer, &None, &None, &0, &10);
+    assert_eq!(all.len(), 4);
+    assert_eq!(
+        all.get(1).unwrap().event_id,
+        String::from_str(&env, "event_2")
+    );
+    assert_eq!(all.get(1).unwrap().amount, amount);
+
+    // Offsets count matching payments only
+    let event_1 = Some(String::from_str(&env, "event_1"));
+    let page = client.get_buyer_payments_detailed(&buyer, &event_1, &None, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(
+        page.get(0).unwrap().payment_id,
+        String::from_str(&env, "pay_d3")
+    );
+
+    let pending = client.get_buyer_payments_detailed(
+        &buyer,
+        &event_1,
+        &Some(PaymentStatus::Pending),
+        &0,
+        &10,
+    );
+    assert_eq!(pending.len(), 2);
+    assert_eq!(
+        pending.get(1).unwrap().payment_id,
+        String::from_str(&env, "pay_d4")
+    );
+    let confirmed =
+        client.get_buyer_payments_detailed(&buyer, &None, &Some(PaymentStatus::Confirmed), &0, &10);
+    assert_eq!(confirmed.len(), 1);
+    assert_eq!(confirmed.get(0).unwrap().status, PaymentStatus::Confirmed);
+}
+
+#[test]
+fn test_e2e_event_payments_paginated_and_sales_summary() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    env.as_contract(&registry_id, || {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "vip_price"), &1500_0000000i128);
+    });
+    let event_id = String::from_str(&env, "event_1");
+    let tier_1 = String::from_str(&env, "tier_1");
+    let vip = String::from_str(&env, "tier_vip");
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let vip_amount = 1500_0000000i128;
+
+    fund_buyer(
+        &env,
+        &usdc_id,
+        &buyer,
+        &client.address,
+        amount * 3 + vip_amount,
+    );
+    for id in ["pay_s1", "pay_s2", "pay_s3"] {
+        buy_ticket(&client, &env, id, "event_1", &buyer, &usdc_id, amount);
+    }
+    let vip_id = String::from_str(&env, "pay_s4");
+    client.process_payment(
+        &vip_id,
+        &event_id,
+        &vip,
+        &buyer,
+        &usdc_id,
+        &vip_amount,
+        &1,
+        &None,
+        &None,
+        &None,
+    );
+    client.confirm_payment(
+        &String::from_str(&env, "pay_s2"),
+        &String::from_str(&env, "tx_s2"),
+    );
+    client.request_guest_refund(&String::from_str(&env, "pay_s3"));
+
+    let all = client.get_event_payments_paginated(&event_id, &0, &10, &None);
+    assert_eq!(all.len(), 4);
+    assert_eq!(all.get(3).unwrap().ticket_tier_id, vip);
+    let page = client.get_event_payments_paginated(&event_id, &1, &1, &None);
+    assert_eq!(
+        page.get(0).unwrap().payment_id,
+        String::from_str(&env, "pay_s2")
+    );
+    let pending =
+        client.get_event_payments_paginated(&event_id, &0, &10, &Some(PaymentStatus::Pending));
+    assert_eq!(pending.len(), 2);
+    assert_eq!(pending.get(1).unwrap().payment_id, vip_id);
+
+    let summary = client.get_event_sales_summary(&event_id);
+    assert_eq!(summary.status_counts.get(PaymentStatus::Pending), Some(2));
+    assert_eq!(summary.status_counts.get(PaymentStatus::Confirmed), Some(1));
+    assert_eq!(summary.status_counts.get(PaymentStatus::Refunded), Some(1));
+    assert_eq!(summary.status_counts.get(PaymentStatus::CheckedIn), None);
+
+    let general = summary.tiers.get(tier_1).unwrap();
+    assert_eq!(general.sold, 2);
+    assert_eq!(general.refunded, 1);
+    assert_eq!(general.gross_revenue, amount * 2);
+    let premium = summary.tiers.get(vip).unwrap();
+    assert_eq!(premium.sold, 1);
+    assert_eq!(premium.gross_revenue, vip_amount);
+    assert_eq!(summary.gross_revenue, amount * 2 + vip_amount);
+
+    let organizer_share = |id: &str| {
+        client
+            .get_payment_status(&String::from_str(&env, id))
+            .unwrap()
+            .organizer_amount
+    };
+    assert_eq!(
+        general.net_revenue,
+        organizer_share("pay_s1") + organizer_share("pay_s2")
+    );
+    assert_eq!(
+        summary.net_revenue,
+        general.net_revenue + organizer_share("pay_s4")
+    );
+    assert!(summary.net_revenue < summary.gross_revenue);
+}
+
+// =============================================================================
+// 36. Payout thresholds
+// =============================================================================
+
+#[test]
+fn test_e2e_payout_threshold_notifies_once_and_accumulates() {
+    use crate::events::{AgoraEvent, WithdrawableThresholdReachedEvent};
+    use crate::types::PayoutConfig;
+    use soroban_sdk::IntoVal;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = String::from_str(&env, "event_1");
+    let amount = 1000_0000000i128;
+    let net = amount - amount * 500 / 10000;
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_organizer(env.clone(), organizer.clone());
+    });
+
+    // Thresholds notified by the last call
+    let notified = || -> soroban_sdk::Vec<WithdrawableThresholdReachedEvent> {
+        let topic: soroban_sdk::Vec<soroban_sdk::Val> =
+            (AgoraEvent::WithdrawableThresholdReached, event_id.clone()).into_val(&env);
+        let mut found = soroban_sdk::Vec::new(&env);
+        for (_, topics, data) in env.events().all().iter() {
+            if topics == topic {
+                found.push_back(data.try_into_val(&env).unwrap());
+            }
+        }
+        found
+    };
+
+    assert_eq!(
+        client.try_set_payout_config(
+            &organizer,
+            &Some(PayoutConfig {
+                min_withdrawal: 0,
+                auto_accumulate: true
+            })
+        ),
+        Err(Ok(TicketPaymentError::InvalidPrice))
+    );
+    client.set_payout_config(
+        &organizer,
+        &Some(PayoutConfig {
+            min_withdrawal: net + net / 2,
+            auto_accumulate: true,
+        }),
+    );
+
+    // Below the threshold: no notification, and withdrawals are held back
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 4);
+    buy_ticket(&client, &env, "pay_o1", "event_1", &buyer, &usdc_id, amount);
+    assert!(notified().is_empty());
+    assert_eq!(client.withdraw_organizer_funds(&event_id, &usdc_id), 0);
+
+    // Crossing the threshold notifies exactly once
+    buy_ticket(&client, &env, "pay_o2", "event_1", &buyer, &usdc_id, amount);
+    let crossed = notified();
+    assert_eq!(crossed.len(), 1);
+    assert_eq!(crossed.get(0).unwrap().withdrawable, net * 2);
+    assert_eq!(crossed.get(0).unwrap().organizer_address, organizer);
+    buy_ticket(&client, &env, "pay_o3", "event_1", &buyer, &usdc_id, amount);
+    assert!(notified().is_empty());
+
+    // A withdrawal starts a new accumulation cycle
+    assert_eq!(
+        client.withdraw_organizer_funds(&event_id, &usdc_id),
+        net * 3
+    );
+    buy_ticket(&client, &env, "pay_o4", "event_1", &buyer, &usdc_id, amount);
+    assert!(notified().is_empty());
+
+    // Without auto-accumulate the threshold is advisory only
+    client.set_payout_config(
+        &organizer,
+        &Some(PayoutConfig {
+            min_withdrawal: net * 2,
+            auto_accumulate: false,
+        }),
+    );
+    assert_eq!(client.withdraw_organizer_funds(&event_id, &usdc_id), net);
+    client.set_payout_config(&organizer, &None);
+    assert_eq!(client.get_payout_config(&organizer), None);
+}
+
+// =============================================================================
+// 37. Settlement reports
+// =============================================================================
+
+#[test]
+fn test_e2e_settlement_report_separates_transfer_fees_and_royalties() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _reg) = setup_e2e(&env);
+    let seller = Address::generate(&env);
+    let friend = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = String::from_str(&env, "event_1");
+    let amount = 1000_0000000i128;
+    let transfer_fee = 10_0000000i128;
+    let resale_price = 1100_0000000i128;
+
+    fund_buyer(
+        &env,
+        &usdc_id,
+        &seller,
+        &client.address,
+        amount + transfer_fee,
+    );
+    let pay_id = buy_ticket(
+        &client, &env, "pay_r1", "event_1", &seller, &usdc_id, amount,
+    );
+    client.confirm_payment(&pay_id, &String::from_str(&env, "tx_r1"));
+    client.set_transfer_fee(&event_id, &transfer_fee);
+    client.transfer_ticket(&pay_id, &friend, &None, &None);
+
+    client.set_resale_royalty(&event_id, &500);
+    client.list_ticket_for_sale(&pay_id, &resale_price, &usdc_id);
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, resale_price);
+    client.buy_listed_ticket(&pay_id, &buyer);
+
+    let line = client
+        .get_event_settlement_report(&event_id)
+        .get(0)
+        .unwrap();
+    assert_eq!(line.gross_sales, amount);
+    assert_eq!(line.transfer_fees, transfer_fee);
+    assert_eq!(line.royalties, resale_price * 500 / 10000);
+    assert_eq!(line.net_to_organizer, 0);
+}
+
+// =============================================================================
+// 38. Reserved seating
+// =============================================================================
+
+#[test]
+fn test_e2e_seated_tier_reserves_and_releases_seats() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let rival = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let a1 = String::from_str(&env, "A-1");
+    let a2 = String::from_str(&env, "A-2");
+
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_seat_map(env.clone(), soroban_sdk::vec![&env, a1.clone(), a2.clone()]);
+    });
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 2);
+    fund_buyer(&env, &usdc_id, &rival, &client.address, amount * 2);
+
+    let buy_seats = |payment_id: &str, buyer: &Address, seats: soroban_sdk::Vec<String>| {
+        client.try_process_payment(
+            &String::from_str(&env, payment_id),
+            &String::from_str(&env, "event_1"),
+            &String::from_str(&env, "tier_1"),
+            buyer,
+            &usdc_id,
+            &amount,
+            &seats.len(),
+            &None,
+            &None,
+            &Some(PurchaseOptions {
+                identity_hash: None,
+                memo: None,
+                registry: None,
+                seats,
+                max_slippage_bps: None,
+                voucher_code: None,
+                promoter: None,
+            }),
+        )
+    };
+
+    // A seated tier cannot be bought as general admission
+    assert_eq!(
+        client.try_process_payment(
+            &String::from_str(&env, "pay_ga"),
+            &String::from_str(&env, "event_1"),
+            &String::from_str(&env, "tier_1"),
+            &buyer,
+            &usdc_id,
+            &amount,
+            &1,
+            &None,
+            &None,
+            &None,
+        ),
+        Err(Ok(TicketPaymentError::InvalidSeatSelection))
+    );
+
+    buy_seats("pay_s1", &buyer, soroban_sdk::vec![&env, a1.clone()])
+        .unwrap()
+        .unwrap();
+    let payment = client
+        .get_payment_status(&String::from_str(&env, "pay_s1"))
+        .unwrap();
+    assert_eq!(payment.seat_id, Some(a1.clone()));
+
+    // The same seat cannot be sold twice
+    assert!(buy_seats("pay_s2", &rival, soroban_sdk::vec![&env, a1.clone()]).is_err());
+
+    // Refunding frees the seat for someone else
+    client.request_guest_refund(&String::from_str(&env, "pay_s1"));
+    buy_seats("pay_s3", &rival, soroban_sdk::vec![&env, a1.clone()])
+        .unwrap()
+        .unwrap();
+    let payment = client
+        .get_payment_status(&String::from_str(&env, "pay_s3"))
+        .unwrap();
+    assert_eq!(payment.seat_id, Some(a1));
+}
+
+// =============================================================================
+// 39. Dutch-auction tiers
+// =============================================================================
+
+#[test]
+fn test_e2e_dutch_auction_charges_the_current_step() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let start_price = 1000_0000000i128;
+
+    // 1000 USDC falling to 400 USDC in 100 USDC steps between t=100 and t=700
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_pricing(
+            env.clone(),
+            event_registry::TierPricing::DutchAuction(event_registry::DutchAuction {
+                floor_price: 400_0000000,
+                start: 100,
+                end: 700,
+                step_seconds: 100,
+            }),
+        );
+    });
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, start_price * 3);
+
+    env.ledger().set_timestamp(50);
+    buy_ticket(
+        &client,
+        &env,
+        "pay_d1",
+        "event_1",
+        &buyer,
+        &usdc_id,
+        start_price,
+    );
+
+    // Two steps in, the static price no longer matches
+    env.ledger().set_timestamp(320);
+    let stale = client.try_process_payment(
+        &String::from_str(&env, "pay_d2"),
+        &String::from_str(&env, "event_1"),
+        &String::from_str(&env, "tier_1"),
+        &buyer,
+        &usdc_id,
+        &start_price,
+        &1,
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(stale, Err(Ok(TicketPaymentError::InvalidPrice)));
+    buy_ticket(
+        &client,
+        &env,
+        "pay_d2",
+        "event_1",
+        &buyer,
+        &usdc_id,
+        800_0000000,
+    );
+
+    env.ledger().set_timestamp(10_000);
+    buy_ticket(
+        &client,
+        &env,
+        "pay_d3",
+        "event_1",
+        &buyer,
+        &usdc_id,
+        400_0000000,
+    );
+    let payment = client
+        .get_payment_status(&String::from_str(&env, "pay_d3"))
+        .unwrap();
+    assert_eq!(payment.amount, 400_0000000);
+}
+
+// =============================================================================
+// 40. Group discounts
+// =============================================================================
+
+#[test]
+fn test_e2e_group_discount_follows_quantity() {
+    use crate::events::{AgoraEvent, GroupDiscountAppliedEvent};
+    use soroban_sdk::IntoVal;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let price = 1000_0000000i128;
+    let event_id = String::from_str(&env, "event_1");
+
+    // 10% off for 3+ tickets, 20% off for 5+
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_bulk_discounts(
+            env.clone(),
+            soroban_sdk::map![&env, (3u32, 1000u32), (5u32, 2000u32)],
+        );
+    });
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, price * 10);
+
+    let buy = |payment_id: &str, amount: i128, quantity: u32| {
+        client.try_process_payment(
+            &String::from_str(&env, payment_id),
+            &event_id,
+            &String::from_str(&env, "tier_1"),
+            &buyer,
+            &usdc_id,
+            &amount,
+            &quantity,
+            &None,
+            &None,
+            &None,
+        )
+    };
+
+    // Below the first threshold the list price applies
+    buy("pay_g1", price, 2).unwrap().unwrap();
+    // The discount is applied automatically; the list price no longer matches
+    assert_eq!(
+        buy("pay_g2", price, 4),
+        Err(Ok(TicketPaymentError::InvalidPrice))
+    );
+    buy("pay_g2", 900_0000000, 4).unwrap().unwrap();
+    let topic: soroban_sdk::Vec<soroban_sdk::Val> = (
+        AgoraEvent::GroupDiscountApplied,
+        event_id.clone(),
+        String::from_str(&env, "pay_g2"),
+    )
+        .into_val(&env);
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == topic)
+        .unwrap();
+    let saved: GroupDiscountAppliedEvent = data.try_into_val(&env).unwrap();
+    assert_eq!(saved.discount_bps, 1000);
+    assert_eq!(saved.saved_amount, 400_0000000);
+
+    buy("pay_g3", 800_0000000, 5).unwrap().unwrap();
+    let line = client
+        .get_event_settlement_report(&event_id)
+        .get(0)
+        .unwrap();
+    assert_eq!(line.discounts_granted, 400_0000000 + 1000_0000000);
+}
+
+// =============================================================================
+// 41. Installment plans
+// =============================================================================
+
+#[test]
+fn test_e2e_installment_plan_completes_or_forfeits() {
+    use crate::types::INSTALLMENT_INTERVAL;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let price = 1000_0000000i128;
+    let event_id = String::from_str(&env, "event_1");
+    let tier_id = String::from_str(&env, "tier_1");
+    let usdc = token::Client::new(&env, &usdc_id);
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, price * 2);
+
+    // The first installment carries the rounding dust
+    let paid_in_full = String::from_str(&env, "plan_1");
+    let plan = client.start_installment_plan(&paid_in_full, &event_id, &tier_id, &buyer, &3);
+    assert_eq!(plan.paid_amount, 333_3333334);
+    assert_eq!(plan.next_due, INSTALLMENT_INTERVAL);
+    let payment = client.get_payment_status(&paid_in_full).unwrap();
+    assert_eq!(payment.status, PaymentStatus::Reserved);
+    assert_eq!(
+        client.try_request_guest_refund(&paid_in_full),
+        Err(Ok(TicketPaymentError::InvalidPaymentStatus))
+    );
+
+    client.pay_installment(&paid_in_full);
+    assert_eq!(
+        client.get_event_escrow_balance(&event_id).organizer_amount,
+        0
+    );
+    let plan = client.pay_installment(&paid_in_full);
+    assert_eq!(plan.paid_amount, price);
+    assert_eq!(plan.next_due, 0);
+    let payment = client.get_payment_status(&paid_in_full).unwrap();
+    assert_eq!(payment.status, PaymentStatus::Pending);
+    let escrow = client.get_event_escrow_balance(&event_id);
+    assert_eq!(escrow.organizer_amount + escrow.platform_fee, price);
+
+    // A missed deadline forfeits the ticket, refunding less the processing fee
+    client.set_refund_processing_fee(&1000, &0);
+    let lapsed = String::from_str(&env, "plan_2");
+    client.start_installment_plan(&lapsed, &event_id, &tier_id, &buyer, &2);
+    assert_eq!(
+        client.try_forfeit_installment_plan(&lapsed),
+        Err(Ok(TicketPaymentError::InvalidPaymentStatus))
+    );
+    env.ledger()
+        .with_mut(|li| li.timestamp = INSTALLMENT_INTERVAL + 1);
+    assert_eq!(
+        client.try_pay_installment(&lapsed),
+        Err(Ok(TicketPaymentError::InstallmentOverdue))
+    );
+    let balance_before = usdc.balance(&buyer);
+    assert_eq!(client.forfeit_installment_plan(&lapsed), 450_0000000);
+    assert_eq!(usdc.balance(&buyer), balance_before + 450_0000000);
+    let payment = client.get_payment_status(&lapsed).unwrap();
+    assert_eq!(payment.status, PaymentStatus::Refunded);
+    // The completed plan's platform fee plus the forfeiture's processing fee
+    assert_eq!(
+        client.get_total_fees_collected(&usdc_id),
+        50_0000000 + 50_0000000
+    );
+    // Only the completed plan's ticket is still held in escrow
+    assert_eq!(client.get_active_escrow_total_by_token(&usdc_id), price);
+}
+
+// =============================================================================
+// 42. Oracle adapter
+// =============================================================================
+
+/// Reflector-style feed with 14 decimals and prices set per asset.
+#[soroban_sdk::contract]
+pub struct MockReflectorE2E;
+
+#[soroban_sdk::contractimpl]
+impl MockReflectorE2E {
+    pub fn decimals(_env: Env) -> u32 {
+        14
+    }
+
+    pub fn lastprice(
+        env: Env,
+        asset: oracle_adapter::types::Asset,
+    ) -> Option<oracle_adapter::types::PriceData> {
+        env.storage().instance().get(&asset)
+    }
+
+    pub fn set_price(env: Env, asset: Address, price: i128, timestamp: u64) {
+        env.storage().instance().set(
+            &oracle_adapter::types::Asset::Stellar(asset),
+            &oracle_adapter::types::PriceData { price, timestamp },
+        );
+    }
+}
+
+#[test]
+fn test_e2e_checkout_through_oracle_adapter() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let (client, admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let xlm_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_token(&xlm_id);
+
+    let feed_id = env.register(MockReflectorE2E, ());
+    let feed = MockReflectorE2EClient::new(&env, &feed_id);
+    let adapter_id = env.register(oracle_adapter::OracleAdapter, ());
+    oracle_adapter::OracleAdapterClient::new(&env, &adapter_id).initialize(&admin, &feed_id, &300);
+    client.set_oracle(&adapter_id);
+
+    // USDC at $1.00 and XLM at $0.25: 1000 USDC is 4000 XLM
+    feed.set_price(&usdc_id, &100_000_000_000_000, &1_000);
+    feed.set_price(&xlm_id, &25_000_000_000_000, &1_000);
+    let event_id = String::from_str(&env, "event_1");
+    let tier_id = String::from_str(&env, "tier_1");
+    let xlm_price = 4000_0000000i128;
+    assert_eq!(
+        client.quote_ticket_price(&event_id, &tier_id, &xlm_id),
+        xlm_price
+    );
+
+    let buyer = Address::generate(&env);
+    fund_buyer(&env, &xlm_id, &buyer, &client.address, xlm_price * 2);
+    let pay = |payment_id: &str| {
+        client.try_process_payment(
+            &String::from_str(&env, payment_id),
+            &event_id,
+            &tier_id,
+            &buyer,
+            &xlm_id,
+            &xlm_price,
+            &1,
+            &None,
+            &None,
+            &None,
+        )
+    };
+    pay("pay_xlm_1").unwrap().unwrap();
+
+    // Once the feed stops updating, checkout in other tokens halts
+    env.ledger().with_mut(|li| li.timestamp = 1_301);
+    assert_eq!(
+        pay("pay_xlm_2"),
+        Err(Ok(TicketPaymentError::OraclePriceUnavailable))
+    );
+    assert_eq!(
+        client.try_get_asset_price(&xlm_id),
+        Err(Ok(TicketPaymentError::OraclePriceUnavailable))
+    );
+    // The default token does not depend on the oracle
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, 1000_0000000);
+    buy_ticket(
+        &client,
+        &env,
+        "pay_usdc",
+        "event_1",
+        &buyer,
+        &usdc_id,
+        1000_0000000,
+    );
+}
+
+// =============================================================================
+// 43. Refund policies
+// =============================================================================
+
+#[test]
+fn test_e2e_refund_policy_replaces_legacy_fields() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let usdc = token::Client::new(&env, &usdc_id);
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 4);
+    let set_policy = |policy: event_registry::RefundPolicy| {
+        env.as_contract(&registry_id, || {
+            MockRegistryE2E::set_refund_policy(env.clone(), policy);
+        });
+    };
+    let refund = |payment_id: &str| {
+        let pay_id = buy_ticket(
+            &client, &env, payment_id, "event_1", &buyer, &usdc_id, amount,
+        );
+        let before = usdc.balance(&buyer);
+        client
+            .try_request_guest_refund(&pay_id)
+            .map(|_| usdc.balance(&buyer) - before)
+    };
+
+    // Full back until t=1000, then 75% until t=2000, 25% until t=3000, then nothing.
+    // The legacy 50 USDC restocking fee no longer applies.
+    let step = |until: u64, refund_bps: u32| event_registry::RefundStep { until, refund_bps };
+    set_policy(event_registry::RefundPolicy::Schedule(soroban_sdk::vec![
+        &env,
+        step(1000, 10_000),
+        step(2000, 7_500),
+        step(3000, 2_500),
+    ]));
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    assert_eq!(refund("pay_p1"), Ok(amount));
+    env.ledger().with_mut(|li| li.timestamp = 2500);
+    assert_eq!(refund("pay_p2"), Ok(250_0000000));
+    env.ledger().with_mut(|li| li.timestamp = 3001);
+    assert_eq!(
+        refund("pay_p3"),
+        Err(Ok(TicketPaymentError::RefundDeadlinePassed))
+    );
+
+    set_policy(event_registry::RefundPolicy::NoRefund);
+    assert_eq!(
+        refund("pay_p4"),
+        Err(Ok(TicketPaymentError::TicketNotRefundable))
+    );
+    // The organizer keeps what the policy retained
+    let escrow = client.get_event_escrow_balance(&String::from_str(&env, "event_1"));
+    assert_eq!(escrow.organizer_amount, 950_0000000 * 2 + 750_0000000);
+}
+
+// =============================================================================
+// 44. Per-ticket partial refunds
+// =============================================================================
+
+#[test]
+fn test_e2e_partial_refunds_accumulate_until_void() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _reg) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let usdc = token::Client::new(&env, &usdc_id);
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 2);
+    client.set_partial_refund_threshold(&8000);
+
+    let pay_id = buy_ticket(
+        &client, &env, "pay_pr1", "event_1", &buyer, &usdc_id, amount,
+    );
+    assert_eq!(client.request_partial_refund(&pay_id, &2000), 200_0000000);
+    let payment = client.get_payment_status(&pay_id).unwrap();
+    assert_eq!(payment.status, PaymentStatus::Pending);
+    assert_eq!(payment.refunded_amount, 200_0000000);
+
+    // Buyers cannot get back more than a full refund would return (less the
+    // 50 USDC restocking fee); the admin is not bound by the refund rules
+    assert_eq!(
+        client.try_request_partial_refund(&pay_id, &8000),
+        Err(Ok(TicketPaymentError::TicketNotRefundable))
+    );
+    client.admin_partial_refund(&pay_id, &6000);
+    let payment = client.get_payment_status(&pay_id).unwrap();
+    assert_eq!(payment.status, PaymentStatus::Refunded);
+    assert_eq!(usdc.balance(&buyer), amount + 800_0000000);
+    assert_eq!(
+        client.try_request_guest_refund(&pay_id),
+        Err(Ok(TicketPaymentError::InvalidPaymentStatus))
+    );
+
+    // A full refund after a partial one returns only the remainder
+    let pay_id = buy_ticket(
+        &client, &env, "pay_pr2", "event_1", &buyer, &usdc_id, amount,
+    );
+    client.request_partial_refund(&pay_id, &1000);
+    let before = usdc.balance(&buyer);
+    client.request_guest_refund(&pay_id);
+    assert_eq!(
+        usdc.balance(&buyer) - before,
+        amount - 50_0000000 - 100_0000000
+    );
+    let escrow = client.get_event_escrow_balance(&String::from_str(&env, "event_1"));
+    assert_eq!(
+        escrow.organizer_amount,
+        950_0000000 - 800_0000000 + 50_0000000
+    );
+}
+
+// =============================================================================
+// 45. Refund pools
+// =============================================================================
+
+#[test]
+fn test_e2e_cancellation_locks_escrow_into_refund_pool() {
+    use crate::types::REFUND_CLAIM_WINDOW;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let organizer = Address::generate(&env);
+    env.as_contract(&registry_id, || {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "organizer"), &organizer);
+    });
+    let event_id = String::from_str(&env, "event_1");
+    let usdc = token::Client::new(&env, &usdc_id);
+    let amount = 1000_0000000i128;
+    let buyer_a = Address::generate(&env);
+    let buyer_b = Address::generate(&env);
+    fund_buyer(&env, &usdc_id, &buyer_a, &client.address, amount);
+    fund_buyer(&env, &usdc_id, &buyer_b, &client.address, amount);
+    let pay_a = buy_ticket(
+        &client, &env, "pay_rp1", "event_1", &buyer_a, &usdc_id, amount,
+    );
+    let pay_b = buy_ticket(
+        &client, &env, "pay_rp2", "event_1", &buyer_b, &usdc_id, amount,
+    );
+
+    // Only the event's registry can open a pool
+    assert_eq!(
+        client.try_claim_from_refund_pool(&pay_a),
+        Err(Ok(TicketPaymentError::NoFundsAvailable))
+    );
+    MockRegistryE2EClient::new(&env, &registry_id).cancel_event(&client.address, &event_id);
+    let pool = client.get_refund_pool(&event_id).unwrap();
+    assert_eq!(pool.locked.get(usdc_id.clone()), Some(amount * 2));
+    assert!(pool.claimed.is_empty());
+
+    // Anyone may trigger a claim; the refund always goes to the buyer
+    assert_eq!(client.claim_from_refund_pool(&pay_a), amount);
+    assert_eq!(usdc.balance(&buyer_a), amount);
+    assert_eq!(
+        client.get_payment_status(&pay_a).unwrap().status,
+        PaymentStatus::Refunded
+    );
+    assert_eq!(
+        client.try_claim_from_refund_pool(&pay_a),
+        Err(Ok(TicketPaymentError::InvalidPaymentStatus))
+    );
+    assert_eq!(
+        client
+            .get_refund_pool(&event_id)
+            .unwrap()
+            .claimed
+            .get(usdc_id.clone()),
+        Some(amount)
+    );
+
+    // Nothing can be swept while the claim window is open
+    assert_eq!(
+        client.try_sweep_unclaimed(&event_id, &usdc_id),
+        Err(Ok(TicketPaymentError::NoFundsAvailable))
+    );
+    env.ledger()
+        .with_mut(|li| li.timestamp += REFUND_CLAIM_WINDOW + 1);
+    assert_eq!(
+        client.try_claim_from_refund_pool(&pay_b),
+        Err(Ok(TicketPaymentError::RefundDeadlinePassed))
+    );
+
+    // The organizer's share of the unclaimed ticket is released to them
+    assert_eq!(client.sweep_unclaimed(&event_id, &usdc_id), 950_0000000);
+    assert_eq!(usdc.balance(&organizer), 950_0000000);
+    assert!(client
+        .get_refund_pool(&event_id)
+        .unwrap()
+        .swept
+        .contains(&usdc_id));
+    assert_eq!(
+        client.try_sweep_unclaimed(&event_id, &usdc_id),
+        Err(Ok(TicketPaymentError::NoFundsAvailable))
+    );
+    let escrow = client.get_event_escrow_balance(&event_id);
+    assert_eq!(escrow.organizer_amount, 0);
+    assert_eq!(escrow.platform_fee, 50_0000000);
+}
+
+#[test]
+fn test_e2e_refund_pool_is_kept_per_token() {
+    use crate::types::REFUND_CLAIM_WINDOW;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let eurc_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_token(&eurc_id);
+    let organizer = Address::generate(&env);
+    env.as_contract(&registry_id, || {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "organizer"), &organizer);
+    });
+    let event_id = String::from_str(&env, "event_1");
+    let amount = 1000_0000000i128;
+    let buyer_a = Address::generate(&env);
+    let buyer_b = Address::generate(&env);
+    fund_buyer(&env, &usdc_id, &buyer_a, &client.address, amount);
+    fund_buyer(&env, &eurc_id, &buyer_b, &client.address, amount);
+    let pay_a = buy_ticket(
+        &client, &env, "pay_rt1", "event_1", &buyer_a, &usdc_id, amount,
+    );
+    let pay_b = buy_ticket(
+        &client, &env, "pay_rt2", "event_1", &buyer_b, &eurc_id, amount,
+    );
+    client.confirm_payment(&pay_a, &String::from_str(&env, "tx_rt1"));
+
+    MockRegistryE2EClient::new(&env, &registry_id).cancel_event(&client.address, &event_id);
+    let pool = client.get_refund_pool(&event_id).unwrap();
+    assert_eq!(pool.locked.len(), 2);
+    assert_eq!(pool.locked.get(usdc_id.clone()), Some(amount));
+    assert_eq!(pool.locked.get(eurc_id.clone()), Some(amount));
+
+    // The EURC ticket is refunded in EURC, against the EURC pool only
+    assert_eq!(client.claim_from_refund_pool(&pay_b), amount);
+    assert_eq!(token::Client::new(&env, &eurc_id).balance(&buyer_b), amount);
+    let pool = client.get_refund_pool(&event_id).unwrap();
+    assert_eq!(pool.claimed.get(eurc_id.clone()), Some(amount));
+    assert_eq!(pool.claimed.get(usdc_id.clone()), None);
+
+    // Each token's unclaimed organizer share is swept in that token
+    env.ledger()
+        .with_mut(|li| li.timestamp += REFUND_CLAIM_WINDOW + 1);
+    assert_eq!(client.sweep_unclaimed(&event_id, &eurc_id), 0);
+    assert_eq!(client.sweep_unclaimed(&event_id, &usdc_id), 950_0000000);
+    assert_eq!(
+        token::Client::new(&env, &usdc_id).balance(&organizer),
+        950_0000000
+    );
+    assert_eq!(token::Client::new(&env, &eurc_id).balance(&organizer), 0);
+    assert_eq!(
+        client.try_sweep_unclaimed(&event_id, &eurc_id),
+        Err(Ok(TicketPaymentError::NoFundsAvailable))
+    );
+
+    // The swept share cannot be refunded again through another refund path
+    assert_eq!(
+        client.try_claim_automatic_refund(&pay_a),
+        Err(Ok(TicketPaymentError::RefundDeadlinePassed))
+    );
+    assert_eq!(
+        client.try_request_guest_refund(&pay_a),
+        Err(Ok(TicketPaymentError::RefundDeadlinePassed))
+    );
+    assert_eq!(
+        client.try_trigger_bulk_refund(&event_id, &10),
+        Err(Ok(TicketPaymentError::RefundDeadlinePassed))
+    );
+    assert_eq!(
+        client.try_issue_partial_refund(&event_id, &1000, &10),
+        Err(Ok(TicketPaymentError::RefundDeadlinePassed))
+    );
+    assert_eq!(token::Client::new(&env, &usdc_id).balance(&buyer_a), 0);
+}
+
+// =============================================================================
+// 46. Event completion
+// =============================================================================
+
+#[test]
+fn test_e2e_claim_revenue_requires_completion_and_challenge_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let organizer = Address::generate(&env);
+    env.as_contract(&registry_id, || {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "organizer"), &organizer);
+    });
+    let event_id = String::from_str(&env, "event_1");
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+    buy_ticket(
+        &client, &env, "pay_cmp1", "event_1", &buyer, &usdc_id, amount,
+    );
+
+    // Deactivating an event no longer releases its revenue
+    env.as_contract(&registry_id, || {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "active"), &false);
+    });
+    assert_eq!(
+        client.try_claim_revenue(&event_id, &usdc_id),
+        Err(Ok(TicketPaymentError::EventNotCompleted))
+    );
+
+    client.set_revenue_challenge_window(&86_400);
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    MockRegistryE2EClient::new(&env, &registry_id).complete_event(&10_000);
+    assert_eq!(
+        client.try_claim_revenue(&event_id, &usdc_id),
+        Err(Ok(TicketPaymentError::EventNotCompleted))
+    );
+
+    // A dispute raised during the window keeps the revenue locked past it
+    client.set_event_dispute(&event_id, &true);
+    env.ledger().with_mut(|li| li.timestamp = 10_000 + 86_400);
+    assert_eq!(
+        client.try_claim_revenue(&event_id, &usdc_id),
+        Err(Ok(TicketPaymentError::EventDisputed))
+    );
+    client.set_event_dispute(&event_id, &false);
+
+    assert_eq!(client.claim_revenue(&event_id, &usdc_id), 950_0000000);
+    assert_eq!(
+        token::Client::new(&env, &usdc_id).balance(&organizer),
+        950_0000000
+    );
+}
+
+// =============================================================================
+// 47. Payment disputes
+// =============================================================================
+
+#[test]
+fn test_e2e_payment_dispute_freezes_share_until_arbitrated() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let organizer = Address::generate(&env);
+    env.as_contract(&registry_id, || {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "organizer"), &organizer);
+    });
+    let event_id = String::from_str(&env, "event_1");
+    let usdc = token::Client::new(&env, &usdc_id);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let bond = 10_0000000i128;
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 2 + bond);
+    let pay_id = buy_ticket(
+        &client, &env, "pay_dsp1", "event_1", &buyer, &usdc_id, amount,
+    );
+    buy_ticket(
+        &client, &env, "pay_dsp2", "event_1", &buyer, &usdc_id, amount,
+    );
+
+    let arbiter = Address::generate(&env);
+    client.set_dispute_arbiter(&arbiter, &true);
+    client.set_dispute_bond(&bond);
+    let reason = String::from_str(
+        &env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+
+    // Only the buyer or the organizer can raise a dispute, once
+    assert_eq!(
+        client.try_open_dispute(&pay_id, &Address::generate(&env), &reason),
+        Err(Ok(TicketPaymentError::Unauthorized))
+    );
+    client.open_dispute(&pay_id, &buyer, &reason);
+    assert_eq!(usdc.balance(&buyer), 0);
+    assert_eq!(
+        client.try_open_dispute(&pay_id, &organizer, &reason),
+        Err(Ok(TicketPaymentError::EventDisputed))
+    );
+    assert_eq!(
+        client.try_request_guest_refund(&pay_id),
+        Err(Ok(TicketPaymentError::EventDisputed))
+    );
+
+    // Only the disputed ticket's share is held back from the organizer
+    assert_eq!(
+        client.withdraw_organizer_funds(&event_id, &usdc_id),
+        950_0000000
+    );
+    assert_eq!(
+        client.try_resolve_dispute(&Address::generate(&env), &pay_id, &6000),
+        Err(Ok(TicketPaymentError::Unauthorized))
+    );
+
+    // The buyer prevails with 60% of the frozen share and gets the bond back
+    assert_eq!(
+        client.resolve_dispute(&arbiter, &pay_id, &6000),
+        570_0000000
+    );
+    assert_eq!(usdc.balance(&buyer), 570_0000000 + bond);
+    let dispute = client.get_payment_dispute(&pay_id).unwrap();
+    assert!(dispute.resolved);
+    assert_eq!(dispute.buyer_share_bps, 6000);
+    assert_eq!(dispute.bond_token, usdc_id);
+    assert_eq!(
+        client.get_payment_status(&pay_id).unwrap().refunded_amount,
+        570_0000000
+    );
+    assert_eq!(
+        client.try_resolve_dispute(&arbiter, &pay_id, &0),
+        Err(Ok(TicketPaymentError::InvalidPaymentStatus))
+    );
+
+    // The rest of the frozen share is released to the organizer
+    assert_eq!(
+        client.withdraw_organizer_funds(&event_id, &usdc_id),
+        380_0000000
+    );
+    assert_eq!(usdc.balance(&organizer), 950_0000000 + 380_0000000);
+}
+
+// =============================================================================
+// 48. Attendance registry
+// =============================================================================
+
+#[test]
+fn test_e2e_check_ins_build_attendance_and_mint_proofs() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let organizer = Address::generate(&env);
+    let scanner = Address::generate(&env);
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_organizer(env.clone(), organizer.clone());
+        MockRegistryE2E::set_scanner(env.clone(), scanner.clone());
+    });
+    let event_id = String::from_str(&env, "event_1");
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    fund_buyer(&env, &usdc_id, &alice, &client.address, amount * 2);
+    fund_buyer(&env, &usdc_id, &bob, &client.address, amount);
+
+    let minter_id = env.register(MockAttendanceMinter, ());
+    let minter = MockAttendanceMinterClient::new(&env, &minter_id);
+    client.set_attendance_minter(&event_id, &Some(minter_id.clone()));
+    assert_eq!(env.auths()[0].0, organizer);
+    assert_eq!(client.get_attendance_minter(&event_id), Some(minter_id));
+
+    let alice_1 = buy_ticket(
+        &client, &env, "pay_att1", "event_1", &alice, &usdc_id, amount,
+    );
+    let alice_2 = buy_ticket(
+        &client, &env, "pay_att2", "event_1", &alice, &usdc_id, amount,
+    );
+    let bob_1 = buy_ticket(&client, &env, "pay_att3", "event_1", &bob, &usdc_id, amount);
+    assert_eq!(client.get_event_attendance_count(&event_id), 0);
+
+    env.ledger().set_timestamp(5_000);
+    client.check_in(&alice_1, &scanner);
+    client.check_in(&bob_1, &scanner);
+    client.check_in(&alice_2, &scanner);
+
+    assert_eq!(client.get_event_attendance_count(&event_id), 3);
+    let page = client.get_event_attendance(&event_id, &1, &10);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().payment_id, bob_1);
+    assert_eq!(page.get(0).unwrap().attendee, bob);
+    assert_eq!(page.get(0).unwrap().checked_in_at, 5_000);
+    // Holders are listed once per event however many tickets they scanned
+    assert_eq!(
+        client.get_attended_events(&alice),
+        vec![&env, event_id.clone()]
+    );
+    assert_eq!(minter.minted().len(), 3);
+    assert_eq!(minter.minted().get(1).unwrap(), (bob, bob_1));
+
+    // A failing minter does not block entry
+    client.set_attendance_minter(&event_id, &Some(env.register(MockBrokenRewards, ())));
+    fund_buyer(&env, &usdc_id, &alice, &client.address, amount);
+    let alice_3 = buy_ticket(
+        &client, &env, "pay_att4", "event_1", &alice, &usdc_id, amount,
+    );
+    client.check_in(&alice_3, &scanner);
+    assert_eq!(client.get_event_attendance_count(&event_id), 4);
+}
+
+// =============================================================================
+// 49. Re-entry
+// =============================================================================
+
+#[test]
+fn test_e2e_reentry_allows_check_out_and_rescan() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let organizer = Address::generate(&env);
+    let scanner = Address::generate(&env);
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_organizer(env.clone(), organizer.clone());
+        MockRegistryE2E::set_scanner(env.clone(), scanner.clone());
+    });
+    let event_id = String::from_str(&env, "event_1");
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+    let pay_id = buy_ticket(
+        &client, &env, "pay_re1", "event_1", &buyer, &usdc_id, amount,
+    );
+
+    env.ledger().set_timestamp(1_000);
+    client.check_in(&pay_id, &scanner);
+    // Without a re-entry policy the ticket is single-use
+    assert_eq!(
+        client.try_check_out(&pay_id, &scanner),
+        Err(Ok(TicketPaymentError::InvalidPaymentStatus))
+    );
+
+    assert!(!client.is_reentry_allowed(&event_id));
+    client.set_allow_reentry(&event_id, &true);
+    assert_eq!(env.auths()[0].0, organizer);
+    // Still inside, so a second entry scan is rejected
+    assert_eq!(
+        client.try_check_in(&pay_id, &scanner),
+        Err(Ok(TicketPaymentError::TicketAlreadyUsed))
+    );
+
+    env.ledger().set_timestamp(2_000);
+    client.check_out(&pay_id, &scanner);
+    assert_eq!(
+        client.try_check_out(&pay_id, &scanner),
+        Err(Ok(TicketPaymentError::InvalidPaymentStatus))
+    );
+    env.ledger().set_timestamp(3_000);
+    client.check_in(&pay_id, &scanner);
+
+    let history = client.get_scan_history(&pay_id);
+    assert_eq!(history.len(), 3);
+    assert!(history.get(0).unwrap().entry);
+    assert!(!history.get(1).unwrap().entry);
+    assert_eq!(history.get(1).unwrap().timestamp, 2_000);
+    assert!(history.get(2).unwrap().entry);
+    // Re-entry does not count as another attendee
+    assert_eq!(client.get_event_attendance_count(&event_id), 1);
+
+    // Only authorized scanners may readmit holders
+    client.check_out(&pay_id, &scanner);
+    assert_eq!(
+        client.try_check_in(&pay_id, &Address::generate(&env)),
+        Err(Ok(TicketPaymentError::UnauthorizedScanner))
+    );
+}
+
+// =============================================================================
+// 50. Ticket tier changes
+// =============================================================================
+
+#[test]
+fn test_e2e_upgrade_ticket_charges_difference() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    env.as_contract(&registry_id, || {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "vip_price"), &1500_0000000i128);
+    });
+    let event_id = String::from_str(&env, "event_1");
+    let vip = String::from_str(&env, "tier_vip");
+    let usdc = token::Client::new(&env, &usdc_id);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+    let pay_id = buy_ticket(
+        &client, &env, "pay_up1", "event_1", &buyer, &usdc_id, amount,
+    );
+
+    // Unknown tiers are rejected
+    assert_eq!(
+        client.try_upgrade_ticket(&pay_id, &String::from_str(&env, "tier_x")),
+        Err(Ok(TicketPaymentError::TierNotFound))
+    );
+    // The difference needs an allowance
+    assert_eq!(
+        client.try_upgrade_ticket(&pay_id, &vip),
+        Err(Ok(TicketPaymentError::InsufficientAllowance))
+    );
+
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, 500_0000000);
+    let balance_before = client.get_event_escrow_balance(&event_id);
+    assert_eq!(client.upgrade_ticket(&pay_id, &vip), 500_0000000);
+    assert_eq!(env.auths()[0].0, buyer);
+    assert_eq!(usdc.balance(&buyer), 0);
+
+    let payment = client.get_payment_status(&pay_id).unwrap();
+    assert_eq!(payment.ticket_tier_id, vip);
+    assert_eq!(payment.amount, 1500_0000000);
+    // 5% platform fee on the difference
+    assert_eq!(payment.platform_fee, 75_0000000);
+    assert_eq!(payment.organizer_amount, 1425_0000000);
+    let balance = client.get_event_escrow_balance(&event_id);
+    assert_eq!(
+        balance.organizer_amount - balance_before.organizer_amount,
+        475_0000000
+    );
+    assert_eq!(
+        balance.platform_fee - balance_before.platform_fee,
+        25_0000000
+    );
+
+    // Already in the top tier, so moving back down is refused
+    assert_eq!(
+        client.try_upgrade_ticket(&pay_id, &String::from_str(&env, "tier_1")),
+        Err(Ok(TicketPaymentError::InvalidPrice))
+    );
+}
+
+#[test]
+fn test_e2e_downgrade_ticket_refunds_difference_less_change_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let organizer = Address::generate(&env);
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_organizer(env.clone(), organizer.clone());
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "vip_price"), &1500_0000000i128);
+    });
+    let event_id = String::from_str(&env, "event_1");
+    let general = String::from_str(&env, "tier_1");
+    let usdc = token::Client::new(&env, &usdc_id);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+    let pay_id = buy_ticket(
+        &client,
+        &env,
+        "pay_down1",
+        "event_1",
+        &buyer,
+        &usdc_id,
+        amount,
+    );
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, 500_0000000);
+    client.upgrade_ticket(&pay_id, &String::from_str(&env, "tier_vip"));
+
+    client.set_tier_change_fee(&event_id, &20_0000000);
+    assert_eq!(env.auths()[0].0, organizer);
+    assert_eq!(client.get_tier_change_fee(&event_id), 20_0000000);
+
+    let balance_before = client.get_event_escrow_balance(&event_id);
+    assert_eq!(client.downgrade_ticket(&pay_id, &general), 480_0000000);
+    assert_eq!(usdc.balance(&buyer), 480_0000000);
+
+    let payment = client.get_payment_status(&pay_id).unwrap();
+    assert_eq!(payment.ticket_tier_id, general);
+    assert_eq!(payment.amount, amount);
+    assert_eq!(payment.platform_fee, 50_0000000);
+    assert_eq!(payment.organizer_amount, 950_0000000);
+    // The organizer keeps the change fee; the platform returns its cut
+    let balance = client.get_event_escrow_balance(&event_id);
+    assert_eq!(
+        balance_before.organizer_amount - balance.organizer_amount,
+        455_0000000
+    );
+    assert_eq!(
+        balance_before.platform_fee - balance.platform_fee,
+        25_0000000
+    );
+
+    assert_eq!(
+        client.try_downgrade_ticket(&pay_id, &String::from_str(&env, "tier_vip")),
+        Err(Ok(TicketPaymentError::InvalidPrice))
+    );
+}
+
+// =============================================================================
+// 51. Multi-event bundles
+// =============================================================================
+
+#[test]
+fn test_e2e_bundle_payment_creates_linked_payments() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let organizer = Address::generate(&env);
+    let bundle_id = String::from_str(&env, "season");
+    let nights = vec![
+        &env,
+        String::from_str(&env, "night_1"),
+        String::from_str(&env, "night_2"),
+        String::from_str(&env, "night_3"),
+    ];
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_organizer(env.clone(), organizer.clone());
+        env.storage().instance().set(
+            &Symbol::new(&env, "bundle"),
+            &event_registry::Bundle {
+                bundle_id: bundle_id.clone(),
+                organizer: organizer.clone(),
+                event_ids: nights.clone(),
+                tier_id: String::from_str(&env, "tier_1"),
+                price: 2500_0000000,
+                max_supply: 1,
+                sold: 0,
+            },
+        );
+    });
+    let usdc = token::Client::new(&env, &usdc_id);
+    let buyer = Address::generate(&env);
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, 2500_0000000);
+    let payment_ids = vec![
+        &env,
+        String::from_str(&env, "pass_n1"),
+        String::from_str(&env, "pass_n2"),
+        String::from_str(&env, "pass_n3"),
+    ];
+
+    let purchase_id = String::from_str(&env, "pass_1");
+    client.process_bundle_payment(&purchase_id, &bundle_id, &buyer, &payment_ids, &None);
+    assert_eq!(usdc.balance(&buyer), 0);
+
+    let purchase = client.get_bundle_purchase(&purchase_id).unwrap();
+    assert_eq!(purchase.buyer, buyer);
+    assert_eq!(purchase.payment_ids, payment_ids);
+    assert_eq!(purchase.amount, 2500_0000000);
+    // 2500 split three ways; the first event takes the rounding dust
+    let first = client
+        .get_payment_status(&payment_ids.get(0).unwrap())
+        .unwrap();
+    let last = client
+        .get_payment_status(&payment_ids.get(2).unwrap())
+        .unwrap();
+    assert_eq!(first.event_id, nights.get(0).unwrap());
+    assert_eq!(first.amount, 833_3333334);
+    assert_eq!(last.amount, 833_3333333);
+    assert_eq!(last.platform_fee, 41_6666666);
+    let balance = client.get_event_escrow_balance(&nights.get(2).unwrap());
+    assert_eq!(balance.organizer_amount, 833_3333333 - 41_6666666);
+
+    // Bundle supply is enforced by the registry
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, 2500_0000000);
+    assert_eq!(
+        client.try_process_bundle_payment(&purchase_id, &bundle_id, &buyer, &payment_ids, &None),
+        Err(Ok(TicketPaymentError::PaymentAlreadyExists))
+    );
+    assert_eq!(
+        client.try_process_bundle_payment(
+            &String::from_str(&env, "pass_short"),
+            &bundle_id,
+            &buyer,
+            &vec![&env, String::from_str(&env, "pass_s1")],
+            &None,
+        ),
+        Err(Ok(TicketPaymentError::InvalidBundlePaymentIds))
+    );
+    // Linked payment ids must be unused and distinct
+    assert_eq!(
+        client.try_process_bundle_payment(
+            &String::from_str(&env, "pass_dup"),
+            &bundle_id,
+            &buyer,
+            &vec![
+                &env,
+                String::from_str(&env, "pass_d1"),
+                String::from_str(&env, "pass_d2"),
+                String::from_str(&env, "pass_d1"),
+            ],
+            &None,
+        ),
+        Err(Ok(TicketPaymentError::InvalidBundlePaymentIds))
+    );
+    assert_eq!(
+        client.try_process_bundle_payment(
+            &String::from_str(&env, "pass_reused"),
+            &bundle_id,
+            &buyer,
+            &vec![
+                &env,
+                String::from_str(&env, "pass_r1"),
+                String::from_str(&env, "pass_r2"),
+                payment_ids.get(2).unwrap(),
+            ],
+            &None,
+        ),
+        Err(Ok(TicketPaymentError::PaymentAlreadyExists))
+    );
+    assert_eq!(
+        client.try_process_bundle_payment(
+            &String::from_str(&env, "pass_t"),
+            &bundle_id,
+            &buyer,
+            &payment_ids,
+            &Some(Address::generate(&env)),
+        ),
+        Err(Ok(TicketPaymentError::RegistryNotAllowed))
+    );
+    assert!(client
+        .try_process_bundle_payment(
+            &String::from_str(&env, "pass_2"),
+            &bundle_id,
+            &buyer,
+            &vec![
+                &env,
+                String::from_str(&env, "pass_m1"),
+                String::from_str(&env, "pass_m2"),
+                String::from_str(&env, "pass_m3"),
+            ],
+            &None,
+        )
+        .is_err());
+}
+
+// =============================================================================
+// 52. Gift vouchers
+// =============================================================================
+
+#[test]
+fn test_e2e_voucher_credit_balance_and_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let usdc = token::Client::new(&env, &usdc_id);
+    let price = 1000_0000000i128;
+    let gifter = Address::generate(&env);
+    let friend = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let code = Bytes::from_slice(&env, b"HAPPY-BIRTHDAY");
+    let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+
+    fund_buyer(&env, &usdc_id, &gifter, &client.address, 1500_0000000);
+    client.mint_voucher(&gifter, &code_hash, &usdc_id, &1500_0000000, &5_000);
+    assert_eq!(usdc.balance(&gifter), 0);
+
+    let options = PurchaseOptions {
+        identity_hash: None,
+        memo: None,
+        registry: None,
+        seats: Vec::new(&env),
+        max_slippage_bps: None,
+        voucher_code: Some(code.clone()),
+        promoter: None,
+    };
+    let buy = |payment_id: &str, buyer: &Address| {
+        client.try_process_payment(
+            &String::from_str(&env, payment_id),
+            &String::from_str(&env, "event_1"),
+            &String::from_str(&env, "tier_1"),
+            buyer,
+            &usdc_id,
+            &price,
+            &1,
+            &None,
+            &None,
+            &Some(options.clone()),
+        )
+    };
+
+    // The first ticket is covered in full without any allowance
+    buy("gift_1", &friend).unwrap().unwrap();
+    let voucher = client.get_voucher(&code_hash).unwrap();
+    assert_eq!(voucher.balance, 500_0000000);
+    assert_eq!(voucher.holder, Some(friend.clone()));
+
+    // Once redeemed, the remainder belongs to the first redeemer
+    fund_buyer(&env, &usdc_id, &stranger, &client.address, price);
+    assert_eq!(
+        buy("gift_x", &stranger),
+        Err(Ok(TicketPaymentError::Unauthorized))
+    );
+
+    // The second ticket spends the remainder and the buyer tops up the rest
+    fund_buyer(&env, &usdc_id, &friend, &client.address, price);
+    buy("gift_2", &friend).unwrap().unwrap();
+    assert_eq!(usdc.balance(&friend), 500_0000000);
+    assert_eq!(client.get_voucher(&code_hash).unwrap().balance, 0);
+    assert_eq!(
+        buy("gift_3", &friend),
+        Err(Ok(TicketPaymentError::DiscountCodeAlreadyUsed))
+    );
+    let escrow = client.get_event_escrow_balance(&String::from_str(&env, "event_1"));
+    assert_eq!(escrow.organizer_amount + escrow.platform_fee, 2 * price);
+
+    // Unspent value returns to the purchaser only after expiry
+    let other_hash: BytesN<32> = env
+        .crypto()
+        .sha256(&Bytes::from_slice(&env, b"UNUSED"))
+        .into();
+    fund_buyer(&env, &usdc_id, &gifter, &client.address, 300_0000000);
+    client.mint_voucher(&gifter, &other_hash, &usdc_id, &300_0000000, &5_000);
+    assert_eq!(
+        client.try_reclaim_voucher(&other_hash),
+        Err(Ok(TicketPaymentError::RecoveryNotReady))
+    );
+    env.ledger().set_timestamp(5_000);
+    assert_eq!(client.reclaim_voucher(&other_hash), 300_0000000);
+    assert_eq!(usdc.balance(&gifter), 300_0000000);
+    assert_eq!(
+        client.try_reclaim_voucher(&other_hash),
+        Err(Ok(TicketPaymentError::NoFundsAvailable))
+    );
+}
+
+// =============================================================================
+// 53. Referral rewards
+// =============================================================================
+
+#[test]
+fn test_e2e_referral_rewards_accrue_and_are_claimed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let usdc = token::Client::new(&env, &usdc_id);
+    let referrer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let buy = |payment_id: &str, event_id: &str| {
+        let buyer = Address::generate(&env);
+        fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+        client.process_payment(
+            &String::from_str(&env, payment_id),
+            &String::from_str(&env, event_id),
+            &String::from_str(&env, "tier_1"),
+            &buyer,
+            &usdc_id,
+            &amount,
+            &1,
+            &None,
+            &Some(referrer.clone()),
+            &None,
+        );
+    };
+
+    // Default share: 20% of the 5% platform fee, accrued rather than paid inline
+    buy("ref_1", "event_1");
+    assert_eq!(usdc.balance(&referrer), 0);
+    assert_eq!(client.get_referral_rewards(&referrer, &usdc_id), 10_0000000);
+
+    client.set_referral_bps(&5000);
+    buy("ref_2", "event_1");
+    assert_eq!(client.get_referral_rewards(&referrer, &usdc_id), 35_0000000);
+    assert_eq!(
+        client.try_set_referral_bps(&10_001),
+        Err(Ok(TicketPaymentError::InvalidFeeBps))
+    );
+
+    // Opted-out events keep the whole fee for the platform
+    let other = String::from_str(&env, "event_2");
+    client.set_referral_opt_out(&other, &true);
+    assert!(client.is_referral_opted_out(&other));
+    buy("ref_3", "event_2");
+    assert_eq!(client.get_referral_rewards(&referrer, &usdc_id), 35_0000000);
+    assert_eq!(
+        client.get_event_escrow_balance(&other).platform_fee,
+        50_0000000
+    );
+
+    assert_eq!(
+        client.claim_referral_rewards(&referrer, &usdc_id),
+        35_0000000
+    );
+    assert_eq!(usdc.balance(&referrer), 35_0000000);
+    assert_eq!(client.get_referral_rewards(&referrer, &usdc_id), 0);
+    assert_eq!(
+        client.try_claim_referral_rewards(&referrer, &usdc_id),
+        Err(Ok(TicketPaymentError::NoFundsAvailable))
+    );
+}
+
+#[test]
+fn test_e2e_refund_takes_back_referral_reward() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let usdc = token::Client::new(&env, &usdc_id);
+    let referrer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let restocking_fee = 50_0000000i128;
+    let reward = 10_0000000i128;
+    let buy = |payment_id: &str| {
+        let buyer = Address::generate(&env);
+        fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+        let ids = client.process_payment(
+            &String::from_str(&env, payment_id),
+            &String::from_str(&env, "event_1"),
+            &String::from_str(&env, "tier_1"),
+            &buyer,
+            &usdc_id,
+            &amount,
+            &1,
+            &None,
+            &Some(referrer.clone()),
+            &None,
+        );
+        (ids.get(0).unwrap(), buyer)
+    };
+
+    // An unclaimed reward is taken back to fund the refund
+    let (first, first_buyer) = buy("ref_1");
+    assert_eq!(client.get_referral_rewards(&referrer, &usdc_id), reward);
+    client.admin_refund(&first);
+    assert_eq!(usdc.balance(&first_buyer), amount - restocking_fee);
+    assert_eq!(client.get_referral_rewards(&referrer, &usdc_id), 0);
+
+    // A reward already claimed is left out of the refund
+    let (second, second_buyer) = buy("ref_2");
+    assert_eq!(client.claim_referral_rewards(&referrer, &usdc_id), reward);
+    client.admin_refund(&second);
+    assert_eq!(
+        usdc.balance(&second_buyer),
+        amount - restocking_fee - reward
+    );
+    assert_eq!(
+        client.try_claim_referral_rewards(&referrer, &usdc_id),
+        Err(Ok(TicketPaymentError::NoFundsAvailable))
+    );
+    assert_eq!(usdc.balance(&client.address), 2 * restocking_fee);
+}
+
+// =============================================================================
+// 54. Promoter commissions
+// =============================================================================
+
+#[test]
+fn test_e2e_promoter_commission_from_organizer_portion() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let usdc = token::Client::new(&env, &usdc_id);
+    let event_id = String::from_str(&env, "event_1");
+    let promoter = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    client.add_promoter(&event_id, &promoter, &1000);
+    assert_eq!(client.get_promoter_share(&event_id, &promoter), 1000);
+
+    let buy = |payment_id: &str, promoter: &Address| {
+        let buyer = Address::generate(&env);
+        fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+        client.try_process_payment(
+            &String::from_str(&env, payment_id),
+            &event_id,
+            &String::from_str(&env, "tier_1"),
+            &buyer,
+            &usdc_id,
+            &amount,
+            &1,
+            &None,
+            &None,
+            &Some(PurchaseOptions {
+                identity_hash: None,
+                memo: None,
+                registry: None,
+                seats: Vec::new(&env),
+                max_slippage_bps: None,
+                voucher_code: None,
+                promoter: Some(promoter.clone()),
+            }),
+        )
+    };
+
+    // Tags of unregistered promoters are rejected
+    assert_eq!(
+        buy("promo_x", &Address::generate(&env)),
+        Err(Ok(TicketPaymentError::InvalidAddress))
+    );
+
+    // 10% of the organizer's 950 goes to the promoter; the platform fee is untouched
+    buy("promo_1", &promoter).unwrap().unwrap();
+    let balance = client.get_event_escrow_balance(&event_id);
+    assert_eq!(balance.platform_fee, 50_0000000);
+    assert_eq!(balance.organizer_amount, 855_0000000);
+    assert_eq!(
+        client.get_promoter_earnings(&promoter, &usdc_id),
+        95_0000000
+    );
+    let line = client
+        .get_event_settlement_report(&event_id)
+        .get(0)
+        .unwrap();
+    assert_eq!(line.promoter_commissions, 95_0000000);
+
+    assert_eq!(
+        client.claim_promoter_earnings(&promoter, &usdc_id),
+        95_0000000
+    );
+    assert_eq!(usdc.balance(&promoter), 95_0000000);
+    assert_eq!(
+        client.try_claim_promoter_earnings(&promoter, &usdc_id),
+        Err(Ok(TicketPaymentError::NoFundsAvailable))
+    );
+
+    // Removed promoters earn nothing further
+    client.add_promoter(&event_id, &promoter, &0);
+    assert_eq!(
+        buy("promo_2", &promoter),
+        Err(Ok(TicketPaymentError::InvalidAddress))
+    );
+}
+
+#[test]
+fn test_e2e_refund_takes_back_promoter_commission() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let usdc = token::Client::new(&env, &usdc_id);
+    let event_id = String::from_str(&env, "event_1");
+    let promoter = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let restocking_fee = 50_0000000i128;
+    let commission = 95_0000000i128;
+    client.add_promoter(&event_id, &promoter, &1000);
+    let buy = |payment_id: &str| {
+        let buyer = Address::generate(&env);
+        fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+        let ids = client.process_payment(
+            &String::from_str(&env, payment_id),
+            &event_id,
+            &String::from_str(&env, "tier_1"),
+            &buyer,
+            &usdc_id,
+            &amount,
+            &1,
+            &None,
+            &None,
+            &Some(PurchaseOptions {
+                identity_hash: None,
+                memo: None,
+                registry: None,
+                seats: Vec::new(&env),
+                max_slippage_bps: None,
+                voucher_code: None,
+                promoter: Some(promoter.clone()),
+            }),
+        );
+        (ids.get(0).unwrap(), buyer)
+    };
+
+    // An unclaimed commission is taken back to fund the refund
+    let (first, first_buyer) = buy("promo_1");
+    client.admin_refund(&first);
+    assert_eq!(usdc.balance(&first_buyer), amount - restocking_fee);
+    assert_eq!(client.get_promoter_earnings(&promoter, &usdc_id), 0);
+
+    // A commission already claimed is left out of the refund
+    let (second, second_buyer) = buy("promo_2");
+    assert_eq!(
+        client.claim_promoter_earnings(&promoter, &usdc_id),
+        commission
+    );
+    client.admin_refund(&second);
+    assert_eq!(
+        usdc.balance(&second_buyer),
+        amount - restocking_fee - commission
+    );
+    assert_eq!(
+        client.try_claim_promoter_earnings(&promoter, &usdc_id),
+        Err(Ok(TicketPaymentError::NoFundsAvailable))
+    );
+
+    // The organizer's restocking fees are all that is left in the contract
+    assert_eq!(usdc.balance(&client.address), 2 * restocking_fee);
+    assert_eq!(
+        client.get_event_escrow_balance(&event_id).organizer_amount,
+        2 * restocking_fee
+    );
+}
+
+// =============================================================================
+// 55. Loyalty points
+// =============================================================================
+
+#[test]
+fn test_e2e_loyalty_points_accrue_and_redeem_from_platform_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let usdc = token::Client::new(&env, &usdc_id);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let event_id = String::from_str(&env, "event_1");
+    client.set_loyalty_earn_rate(&usdc_id, &1000);
+
+    // Points accrue on confirmation only
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 2);
+    let first = buy_ticket(
+        &client, &env, "loyal_1", "event_1", &buyer, &usdc_id, amount,
+    );
+    assert_eq!(client.get_loyalty_balance(&buyer), 0);
+    client.confirm_payment(&first, &String::from_str(&env, "tx_loyal_1"));
+    assert_eq!(client.get_loyalty_balance(&buyer), 100_0000000);
+    assert_eq!(
+        client.try_confirm_payment(&first, &String::from_str(&env, "tx_loyal_1")),
+        Err(Ok(TicketPaymentError::InvalidPaymentStatus))
+    );
+    assert_eq!(client.get_loyalty_balance(&buyer), 100_0000000);
+    assert_eq!(
+        client.try_redeem_points(&first, &10_0000000),
+        Err(Ok(TicketPaymentError::InvalidPaymentStatus))
+    );
+
+    // A point is worth half a unit; the rebate comes out of the 50 platform fee
+    client.set_loyalty_point_value(&5000);
+    let second = buy_ticket(
+        &client, &env, "loyal_2", "event_1", &buyer, &usdc_id, amount,
+    );
+    assert_eq!(client.redeem_points(&second, &80_0000000), 40_0000000);
+    assert_eq!(usdc.balance(&buyer), 40_0000000);
+    assert_eq!(client.get_loyalty_balance(&buyer), 20_0000000);
+    let payment = client.get_payment_status(&second).unwrap();
+    assert_eq!(payment.amount, 960_0000000);
+    assert_eq!(payment.platform_fee, 10_0000000);
+    let balance = client.get_event_escrow_balance(&event_id);
+    assert_eq!(balance.platform_fee, 60_0000000);
+    assert_eq!(balance.organizer_amount, 1900_0000000);
+    // The treasury can only withdraw the fees it still holds
+    assert_eq!(client.get_total_fees_collected(&usdc_id), 60_0000000);
+    assert_eq!(
+        client.get_tenant_fees_collected(&registry_id, &usdc_id),
+        60_0000000
+    );
+    assert_eq!(
+        client.try_withdraw_platform_fees(&100_0000000, &usdc_id),
+        Err(Ok(TicketPaymentError::InsufficientFees))
+    );
+
+    assert_eq!(
+        client.try_redeem_points(&second, &30_0000000),
+        Err(Ok(TicketPaymentError::NoFundsAvailable))
+    );
+    client.set_loyalty_point_value(&10_000);
+    assert_eq!(
+        client.try_redeem_points(&second, &20_0000000),
+        Err(Ok(TicketPaymentError::InsufficientFees))
+    );
+}
+
+// =============================================================================
+// 56. Yield routing of idle escrow
+// =============================================================================
+
+#[test]
+fn test_e2e_idle_escrow_yield_respects_liquid_reserve() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let usdc = token::Client::new(&env, &usdc_id);
+    let amount = 1000_0000000i128;
+    for payment_id in ["yield_1", "yield_2"] {
+        let buyer = Address::generate(&env);
+        fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+        buy_ticket(
+            &client, &env, payment_id, "event_1", &buyer, &usdc_id, amount,
+        );
+    }
+
+    assert_eq!(
+        client.try_deposit_idle_escrow(&usdc_id, &amount),
+        Err(Ok(TicketPaymentError::InvalidAddress))
+    );
+    let strategy = env.register(MockYieldStrategy, ());
+    client.set_yield_adapter(&Some(strategy.clone()));
+
+    // By default all active escrow must stay liquid
+    assert_eq!(
+        client.try_deposit_idle_escrow(&usdc_id, &1),
+        Err(Ok(TicketPaymentError::NoFundsAvailable))
+    );
+
+    // With a 25% reserve, 1500 of the 2000 escrowed can be put to work
+    client.set_yield_reserve_bps(&2500);
+    client.deposit_idle_escrow(&usdc_id, &1500_0000000);
+    assert_eq!(usdc.balance(&strategy), 1500_0000000);
+    assert_eq!(usdc.balance(&client.address), 500_0000000);
+    assert_eq!(client.get_yield_deployed(&usdc_id), 1500_0000000);
+    assert_eq!(
+        client.try_deposit_idle_escrow(&usdc_id, &1),
+        Err(Ok(TicketPaymentError::NoFundsAvailable))
+    );
+
+    // The adapter cannot be swapped out from under deployed funds
+    assert_eq!(
+        client.try_set_yield_adapter(&Some(env.register(MockYieldStrategy, ()))),
+        Err(Ok(TicketPaymentError::YieldFundsDeployed))
+    );
+    assert_eq!(
+        client.try_set_yield_adapter(&None),
+        Err(Ok(TicketPaymentError::YieldFundsDeployed))
+    );
+
+    assert_eq!(
+        client.try_withdraw_from_yield(&usdc_id, &(1500_0000000 + 1)),
+        Err(Ok(TicketPaymentError::NoFundsAvailable))
+    );
+    client.withdraw_from_yield(&usdc_id, &1500_0000000);
+    assert_eq!(usdc.balance(&client.address), 2 * amount);
+    assert_eq!(client.get_yield_deployed(&usdc_id), 0);
+    client.set_yield_adapter(&None);
+}
+
+#[test]
+fn test_e2e_yield_earnings_are_credited_to_platform_fees() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, platform_wallet, _registry_id) = setup_e2e(&env);
+    let usdc = token::Client::new(&env, &usdc_id);
+    let amount = 1000_0000000i128;
+    let fee = (amount * 500) / 10000;
+    let buyer = Address::generate(&env);
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+    buy_ticket(
+        &client, &env, "yield_1", "event_1", &buyer, &usdc_id, amount,
+    );
+
+    let strategy = env.register(MockYieldStrategy, ());
+    client.set_yield_adapter(&Some(strategy.clone()));
+    client.set_yield_reserve_bps(&0);
+    client.deposit_idle_escrow(&usdc_id, &amount);
+
+    // The strategy earns 10% on the deployed principal
+    let earnings = amount / 10;
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&strategy, &earnings);
+    assert_eq!(
+        client.try_withdraw_from_yield(&usdc_id, &(amount + earnings + 1)),
+        Err(Ok(TicketPaymentError::NoFundsAvailable))
+    );
+
+    // Principal is repaid first, then the rest counts as earnings
+    client.withdraw_from_yield(&usdc_id, &(amount / 2));
+    assert_eq!(client.get_yield_deployed(&usdc_id), amount / 2);
+    assert_eq!(client.get_total_fees_collected(&usdc_id), fee);
+
+    client.withdraw_from_yield(&usdc_id, &(amount / 2 + earnings));
+    assert_eq!(client.get_yield_deployed(&usdc_id), 0);
+    assert_eq!(usdc.balance(&strategy), 0);
+    assert_eq!(usdc.balance(&client.address), amount + earnings);
+    assert_eq!(client.get_total_fees_collected(&usdc_id), fee + earnings);
+    assert_eq!(client.get_rescuable_amount(&usdc_id), 0);
+
+    client.withdraw_platform_fees(&(fee + earnings), &usdc_id);
+    assert_eq!(usdc.balance(&platform_wallet), fee + earnings);
+    client.set_yield_adapter(&None);
+}
+
+// =============================================================================
+// 57. Refunds in the original payment token
+// =============================================================================
+
+#[test]
+fn test_e2e_refunds_return_the_token_paid_with() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let xlm_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_token(&xlm_id);
+    let usdc = token::Client::new(&env, &usdc_id);
+    let xlm = token::Client::new(&env, &xlm_id);
+    let event_id = String::from_str(&env, "event_1");
+    let amount = 1000_0000000i128;
+
+    let usdc_buyer = Address::generate(&env);
+    fund_buyer(&env, &usdc_id, &usdc_buyer, &client.address, amount);
+    let usdc_pay = buy_ticket(
+        &client,
+        &env,
+        "pay_usdc",
+        "event_1",
+        &usdc_buyer,
+        &usdc_id,
+        amount,
+    );
+    let xlm_buyer = Address::generate(&env);
+    fund_buyer(&env, &xlm_id, &xlm_buyer, &client.address, amount * 2);
+    let xlm_guest = buy_ticket(
+        &client,
+        &env,
+        "pay_xlm_1",
+        "event_1",
+        &xlm_buyer,
+        &xlm_id,
+        amount,
+    );
+    let xlm_bulk = buy_ticket(
+        &client,
+        &env,
+        "pay_xlm_2",
+        "event_1",
+        &xlm_buyer,
+        &xlm_id,
+        amount,
+    );
+    assert_eq!(
+        client.get_payment_status(&xlm_guest).unwrap().token_address,
+        xlm_id
+    );
+
+    // A guest refund pays the XLM back, less the restocking fee
+    client.request_guest_refund(&xlm_guest);
+    assert_eq!(xlm.balance(&xlm_buyer), amount - 50_0000000);
+    assert_eq!(usdc.balance(&client.address), amount);
+
+    // The cancellation preview splits the remaining liability by token
+    client.confirm_payment(&usdc_pay, &String::from_str(&env, "tx_usdc"));
+    client.confirm_payment(&xlm_bulk, &String::from_str(&env, "tx_xlm"));
+    let preview = client.preview_cancellation(&event_id);
+    assert_eq!(
+        preview.refundable_by_token.get(usdc_id.clone()),
+        Some(amount)
+    );
+    assert_eq!(
+        preview.refundable_by_token.get(xlm_id.clone()),
+        Some(amount)
+    );
+
+    // A bulk refund pays each buyer in their own token
+    assert_eq!(client.trigger_bulk_refund(&event_id, &10), 2);
+    assert_eq!(usdc.balance(&usdc_buyer), amount);
+    assert_eq!(xlm.balance(&xlm_buyer), 2 * amount - 50_0000000);
+    assert_eq!(usdc.balance(&client.address), 0);
+}
+
+// =============================================================================
+// 58. Ticket reservations
+// =============================================================================
+
+#[test]
+fn test_e2e_reservation_holds_tickets_until_paid_or_expired() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let registry = MockRegistryE2EClient::new(&env, &registry_id);
+    let buyer = Address::generate(&env);
+    let event_id = String::from_str(&env, "event_1");
+    let tier_id = String::from_str(&env, "tier_1");
+    let amount = 1000_0000000i128;
+    let reserve = |reservation_id: &str, quantity: u32| {
+        client.try_reserve_tickets(
+            &String::from_str(&env, reservation_id),
+            &buyer,
+            &event_id,
+            &tier_id,
+            &quantity,
+            &300,
+        )
+    };
+
+    let reservation = reserve("res_1", 2).unwrap().unwrap();
+    assert_eq!(reservation.expires_at, 1_300);
+    assert_eq!(registry.get_held_inventory(), 2);
+    assert_eq!(
+        reserve("res_1", 1),
+        Err(Ok(TicketPaymentError::PaymentAlreadyExists))
+    );
+
+    // Completing the reservation buys the held tickets and lifts the hold
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 2);
+    let ids = client.complete_reserved_payment(
+        &reservation.reservation_id,
+        &usdc_id,
+        &amount,
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(ids.get(1).unwrap(), String::from_str(&env, "res_1-1"));
+    assert_eq!(
+        client
+            .get_payment_status(&ids.get(0).unwrap())
+            .unwrap()
+            .buyer_address,
+        buyer
+    );
+    assert_eq!(registry.get_held_inventory(), 0);
+    assert_eq!(client.get_reservation(&reservation.reservation_id), None);
+
+    // A cancelled reservation returns its tickets straight away
+    reserve("res_2", 1).unwrap().unwrap();
+    client.cancel_reservation(&String::from_str(&env, "res_2"));
+    assert_eq!(registry.get_held_inventory(), 0);
+
+    // An expired reservation can no longer be paid for
+    reserve("res_3", 1).unwrap().unwrap();
+    env.ledger().with_mut(|li| li.timestamp = 1_300);
+    assert_eq!(
+        client.get_reservation(&String::from_str(&env, "res_3")),
+        None
+    );
+    assert_eq!(
+        client.try_complete_reserved_payment(
+            &String::from_str(&env, "res_3"),
+            &usdc_id,
+            &amount,
+            &None,
+            &None,
+            &None,
+        ),
+        Err(Ok(TicketPaymentError::PaymentNotFound))
+    );
+}
+
+// =============================================================================
+// 59. Rescue of stray tokens
+// =============================================================================
+
+#[test]
+fn test_e2e_rescue_tokens_only_withdraws_surplus_after_timelock() {
+    use super::contract::RESCUE_TIMELOCK;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let usdc = token::Client::new(&env, &usdc_id);
+    let amount = 1000_0000000i128;
+    let buyer = Address::generate(&env);
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+    buy_ticket(
+        &client, &env, "rescue_1", "event_1", &buyer, &usdc_id, amount,
+    );
+
+    // Escrowed funds are never rescuable
+    let to = Address::generate(&env);
+    assert_eq!(client.get_rescuable_amount(&usdc_id), 0);
+    assert_eq!(
+        client.try_propose_token_rescue(&usdc_id, &1, &to),
+        Err(Ok(TicketPaymentError::NoFundsAvailable))
+    );
+
+    // Tokens sent straight to the contract are
+    let stray = 250_0000000i128;
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&client.address, &stray);
+    assert_eq!(client.get_rescuable_amount(&usdc_id), stray);
+    assert_eq!(
+        client.try_propose_token_rescue(&usdc_id, &(stray + 1), &to),
+        Err(Ok(TicketPaymentError::NoFundsAvailable))
+    );
+    assert_eq!(
+        client.propose_token_rescue(&usdc_id, &stray, &to),
+        1_000 + RESCUE_TIMELOCK
+    );
+    assert_eq!(client.get_token_rescue(&usdc_id).unwrap().amount, stray);
+
+    // Not before the timelock, and only as proposed
+    assert_eq!(
+        client.try_rescue_tokens(&usdc_id, &stray, &to),
+        Err(Ok(TicketPaymentError::RescueNotReady))
+    );
+    env.ledger()
+        .with_mut(|li| li.timestamp = 1_000 + RESCUE_TIMELOCK);
+    assert_eq!(
+        client.try_rescue_tokens(&usdc_id, &stray, &Address::generate(&env)),
+        Err(Ok(TicketPaymentError::RescueNotReady))
+    );
+    assert_eq!(
+        client.try_rescue_tokens(&usdc_id, &(stray - 1), &to),
+        Err(Ok(TicketPaymentError::RescueNotReady))
+    );
+
+    client.rescue_tokens(&usdc_id, &stray, &to);
+    assert_eq!(usdc.balance(&to), stray);
+    assert_eq!(usdc.balance(&client.address), amount);
+    assert_eq!(client.get_token_rescue(&usdc_id), None);
+    assert_eq!(
+        client.try_rescue_tokens(&usdc_id, &stray, &to),
+        Err(Ok(TicketPaymentError::RescueNotReady))
+    );
+}
+
+#[test]
+fn test_e2e_cancelled_token_rescue_cannot_execute() {
+    use super::contract::RESCUE_TIMELOCK;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&client.address, &100);
+    let to = Address::generate(&env);
+
+    assert_eq!(
+        client.try_cancel_token_rescue(&usdc_id),
+        Err(Ok(TicketPaymentError::RescueNotReady))
+    );
+    client.propose_token_rescue(&usdc_id, &100, &to);
+    client.cancel_token_rescue(&usdc_id);
+    assert_eq!(client.get_token_rescue(&usdc_id), None);
+
+    env.ledger().with_mut(|li| li.timestamp += RESCUE_TIMELOCK);
+    assert_eq!(
+        client.try_rescue_tokens(&usdc_id, &100, &to),
+        Err(Ok(TicketPaymentError::RescueNotReady))
+    );
+    assert_eq!(token::Client::new(&env, &usdc_id).balance(&to), 0);
+}
+
+#[test]
+fn test_e2e_held_vouchers_and_bonds_are_not_rescuable() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let to = Address::generate(&env);
+
+    // An unspent voucher balance belongs to its holder
+    let gifter = Address::generate(&env);
+    let code_hash: BytesN<32> = env
+        .crypto()
+        .sha256(&Bytes::from_slice(&env, b"HELD-VOUCHER"))
+        .into();
+    fund_buyer(&env, &usdc_id, &gifter, &client.address, 500_0000000);
+    client.mint_voucher(&gifter, &code_hash, &usdc_id, &500_0000000, &5_000);
+    assert_eq!(client.get_rescuable_amount(&usdc_id), 0);
+    assert_eq!(
+        client.try_propose_token_rescue(&usdc_id, &1, &to),
+        Err(Ok(TicketPaymentError::NoFundsAvailable))
+    );
+
+    // So does the bond deposited with an open dispute
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let bond = 10_0000000i128;
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount + bond);
+    let pay_id = buy_ticket(&client, &env, "held_1", "event_1", &buyer, &usdc_id, amount);
+    client.set_dispute_bond(&bond);
+    client.open_dispute(&pay_id, &buyer, &String::from_str(&env, "reason"));
+    assert_eq!(client.get_rescuable_amount(&usdc_id), 0);
+    assert_eq!(
+        client.try_propose_token_rescue(&usdc_id, &bond, &to),
+        Err(Ok(TicketPaymentError::NoFundsAvailable))
+    );
+
+    // Once reclaimed, the voucher no longer counts
+    env.ledger().set_timestamp(5_000);
+    client.reclaim_voucher(&code_hash);
+    assert_eq!(client.get_rescuable_amount(&usdc_id), 0);
+    token::StellarAssetClient::new(&env, &usdc_id).mint(&client.address, &7);
+    assert_eq!(client.get_rescuable_amount(&usdc_id), 7);
+}
+
+// =============================================================================
+// 60. Refund spike circuit breaker
+// =============================================================================
+
+#[test]
+fn test_e2e_refund_spike_flags_event_and_blocks_withdrawal() {
+    use crate::events::{AgoraEvent, RefundSpikeDetectedEvent};
+    use soroban_sdk::IntoVal;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let event_id = String::from_str(&env, "event_1");
+    let amount = 1000_0000000i128;
+    let mut payment_ids = Vec::new(&env);
+    for payment_id in [
+        "spike_0", "spike_1", "spike_2", "spike_3", "spike_4", "spike_5", "spike_6", "spike_7",
+        "spike_8", "spike_9",
+    ] {
+        let buyer = Address::generate(&env);
+        fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+        payment_ids.push_back(buy_ticket(
+            &client, &env, payment_id, "event_1", &buyer, &usdc_id, amount,
+        ));
+    }
+
+    // Trip once refunds within an hour exceed 20% of the 10 sales
+    assert_eq!(
+        client.try_set_refund_spike_config(&10_001, &3600),
+        Err(Ok(TicketPaymentError::InvalidFeeBps))
+    );
+    client.set_refund_spike_config(&2000, &3600);
+    client.request_guest_refund(&payment_ids.get(0).unwrap());
+    client.request_guest_refund(&payment_ids.get(1).unwrap());
+
+    // Refunds outside the window start a new count
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+    client.request_guest_refund(&payment_ids.get(2).unwrap());
+    client.request_guest_refund(&payment_ids.get(3).unwrap());
+    assert!(!client.is_event_disputed(&event_id));
+
+    client.request_guest_refund(&payment_ids.get(4).unwrap());
+    let topic: soroban_sdk::Vec<soroban_sdk::Val> =
+        (AgoraEvent::RefundSpikeDetected, event_id.clone()).into_val(&env);
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == topic)
+        .unwrap();
+    let detected: RefundSpikeDetectedEvent = data.try_into_val(&env).unwrap();
+    assert_eq!(detected.refunds, 3);
+    assert_eq!(detected.sales, 10);
+    assert_eq!(detected.window_start, 4_600);
+    assert!(client.is_event_disputed(&event_id));
+
+    assert_eq!(
+        client.try_withdraw_organizer_funds(&event_id, &usdc_id),
+        Err(Ok(TicketPaymentError::EventDisputed))
+    );
+
+    // Once the admin clears the flag, payouts resume
+    client.set_event_dispute(&event_id, &false);
+    assert!(client
+        .try_withdraw_organizer_funds(&event_id, &usdc_id)
+        .is_ok());
+}
+
+// =============================================================================
+// 61. Per-event pause
+// =============================================================================
+
+#[test]
+fn test_e2e_event_pause_freezes_one_event_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let organizer = Address::generate(&env);
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_organizer(env.clone(), organizer.clone());
+    });
+    let event_id = String::from_str(&env, "event_1");
+    let amount = 1000_0000000i128;
+    let buyer = Address::generate(&env);
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, 3 * amount);
+    let pay_id = buy_ticket(
+        &client, &env, "pause_1", "event_1", &buyer, &usdc_id, amount,
+    );
+    client.confirm_payment(&pay_id, &String::from_str(&env, "tx_pause_1"));
+
+    assert_eq!(
+        client.try_set_event_pause(&event_id, &true, &Address::generate(&env)),
+        Err(Ok(TicketPaymentError::Unauthorized))
+    );
+    client.set_event_pause(&event_id, &true, &organizer);
+    assert!(client.is_event_paused(&event_id));
+
+    // Sales and transfers of the paused event are rejected
+    assert_eq!(
+        client.try_process_payment(
+            &String::from_str(&env, "pause_2"),
+            &event_id,
+            &String::from_str(&env, "tier_1"),
+            &buyer,
+            &usdc_id,
+            &amount,
+            &1,
+            &None,
+            &None,
+            &None,
+        ),
+        Err(Ok(TicketPaymentError::EventPaused))
+    );
+    assert_eq!(
+        client.try_transfer_ticket(&pay_id, &Address::generate(&env), &None, &None),
+        Err(Ok(TicketPaymentError::EventPaused))
+    );
+    assert_eq!(
+        client.try_list_ticket_for_sale(&pay_id, &amount, &usdc_id),
+        Err(Ok(TicketPaymentError::EventPaused))
+    );
+
+    // Other events keep selling
+    buy_ticket(
+        &client, &env, "pause_3", "event_2", &buyer, &usdc_id, amount,
+    );
+    assert!(!client.is_event_paused(&String::from_str(&env, "event_2")));
+
+    // The organizer cannot lift a pause set by the admin
+    client.set_event_pause(&event_id, &true, &admin);
+    assert_eq!(
+        client.try_set_event_pause(&event_id, &false, &organizer),
+        Err(Ok(TicketPaymentError::Unauthorized))
+    );
+    client.set_event_pause(&event_id, &false, &admin);
+    assert!(!client.is_event_paused(&event_id));
+    client.transfer_ticket(&pay_id, &Address::generate(&env), &None, &None);
+}
+
+// =============================================================================
+// 62. Per-buyer ticket limit
+// =============================================================================
+
+#[test]
+fn test_e2e_buyer_limit_counts_held_tickets_and_spares_exempt_wallets() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let registry = MockRegistryE2EClient::new(&env, &registry_id);
+    registry.set_buyer_limit(&2);
+    let event_id = String::from_str(&env, "event_1");
+    let amount = 1000_0000000i128;
+    let buyer = Address::generate(&env);
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, 10 * amount);
+    let buy = |payment_id: &str, buyer: &Address, quantity: u32| {
+        client.try_process_payment(
+            &String::from_str(&env, payment_id),
+            &event_id,
+            &String::from_str(&env, "tier_1"),
+            buyer,
+            &usdc_id,
+            &amount,
+            &quantity,
+            &None,
+            &None,
+            &None,
+        )
+    };
+
+    assert_eq!(
+        buy("limit_1", &buyer, 3),
+        Err(Ok(TicketPaymentError::BuyerLimitExceeded))
+    );
+    let first = buy("limit_1", &buyer, 1).unwrap().unwrap().get(0).unwrap();
+    buy("limit_2", &buyer, 1).unwrap().unwrap();
+    assert_eq!(
+        buy("limit_3", &buyer, 1),
+        Err(Ok(TicketPaymentError::BuyerLimitExceeded))
+    );
+
+    // Refunded tickets no longer count
+    client.request_guest_refund(&first);
+    buy("limit_3", &buyer, 1).unwrap().unwrap();
+
+    // Box-office wallets can be exempted by the organizer
+    let box_office = Address::generate(&env);
+    fund_buyer(&env, &usdc_id, &box_office, &client.address, 5 * amount);
+    assert_eq!(
+        buy("office_1", &box_office, 5),
+        Err(Ok(TicketPaymentError::BuyerLimitExceeded))
+    );
+    client.set_buyer_limit_exempt(&event_id, &box_office, &true);
+    assert!(client.is_buyer_limit_exempt(&event_id, &box_office));
+    buy("office_1", &box_office, 5).unwrap().unwrap();
+
+    // Paid transfers count as purchases for the new owner
+    let seller = Address::generate(&env);
+    fund_buyer(&env, &usdc_id, &seller, &client.address, amount);
+    let resale = buy("resale_1", &seller, 1)
+        .unwrap()
+        .unwrap()
+        .get(0)
+        .unwrap();
+    client.confirm_payment(&resale, &String::from_str(&env, "tx_resale_1"));
+    assert_eq!(
+        client.try_transfer_ticket(&resale, &buyer, &Some(amount), &None),
+        Err(Ok(TicketPaymentError::BuyerLimitExceeded))
+    );
+}
+
+// =============================================================================
+// 63. Ticket delegation
+// =============================================================================
+
+#[test]
+fn test_e2e_delegated_ticket_checks_in_attendee_and_keeps_holder() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let attendee = Address::generate(&env);
+    let scanner = Address::generate(&env);
+    let event_id = String::from_str(&env, "event_1");
+    let amount = 1000_0000000i128;
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_scanner(env.clone(), scanner.clone());
+    });
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, 2 * amount);
+
+    // A revoked delegation leaves the holder attending in person
+    let own_id = buy_ticket(
+        &client, &env, "deleg_1", "event_1", &buyer, &usdc_id, amount,
+    );
+    client.delegate_ticket(&own_id, &attendee);
+    client.revoke_delegation(&own_id);
+    assert_eq!(client.get_ticket_delegate(&own_id), None);
+    client.check_in(&own_id, &scanner);
+
+    let lent_id = buy_ticket(
+        &client, &env, "deleg_2", "event_1", &buyer, &usdc_id, amount,
+    );
+    client.confirm_payment(&lent_id, &String::from_str(&env, "tx_deleg_2"));
+    client.delegate_ticket(&lent_id, &attendee);
+    assert_eq!(client.get_ticket_delegate(&lent_id), Some(attendee.clone()));
+    client.check_in(&lent_id, &scanner);
+
+    let attendance = client.get_event_attendance(&event_id, &0, &10);
+    assert_eq!(attendance.get(0).unwrap().attendee, buyer);
+    assert_eq!(attendance.get(1).unwrap().attendee, attendee);
+    assert_eq!(client.get_attended_events(&attendee), vec![&env, event_id]);
+
+    // Ownership never moved
+    let payment = client.get_payment_status(&lent_id).unwrap();
+    assert_eq!(payment.buyer_address, buyer);
+    assert_eq!(payment.status, PaymentStatus::CheckedIn);
+    assert_eq!(
+        client.try_delegate_ticket(&lent_id, &Address::generate(&env)),
+        Err(Ok(TicketPaymentError::InvalidPaymentStatus))
+    );
+}
+
+#[test]
+fn test_e2e_transfer_clears_ticket_delegation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+    let pay_id = buy_ticket(
+        &client, &env, "deleg_3", "event_1", &buyer, &usdc_id, amount,
+    );
+    client.confirm_payment(&pay_id, &String::from_str(&env, "tx_deleg_3"));
+
+    client.delegate_ticket(&pay_id, &Address::generate(&env));
+    client.transfer_ticket(&pay_id, &Address::generate(&env), &None, &None);
+    assert_eq!(client.get_ticket_delegate(&pay_id), None);
+}
+
+// =============================================================================
+// 64. Two-step transfer offers
+// =============================================================================
+
+#[test]
+fn test_e2e_transfer_offer_settles_on_acceptance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = String::from_str(&env, "event_1");
+    let amount = 1000_0000000i128;
+    let price = 800_0000000i128;
+    let usdc = token::Client::new(&env, &usdc_id);
+    fund_buyer(&env, &usdc_id, &seller, &client.address, amount);
+    let pay_id = buy_ticket(
+        &client, &env, "offer_1", "event_1", &seller, &usdc_id, amount,
+    );
+    client.confirm_payment(&pay_id, &String::from_str(&env, "tx_offer_1"));
+    client.set_resale_royalty(&event_id, &500);
+
+    env.ledger().set_timestamp(1_000);
+    let expires_at = client.offer_transfer(&pay_id, &buyer, &price);
+    assert_eq!(expires_at, 1_000 + 7 * 24 * 60 * 60);
+    assert_eq!(client.get_transfer_offer(&pay_id).unwrap().to, buyer);
+
+    // Nothing moves until the recipient accepts
+    assert_eq!(
+        client.get_payment_status(&pay_id).unwrap().buyer_address,
+        seller
+    );
+    assert_eq!(
+        client.try_accept_transfer(&pay_id),
+        Err(Ok(TicketPaymentError::InsufficientAllowance))
+    );
+
+    let escrow_before = client.get_event_escrow_balance(&event_id);
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, price);
+    client.accept_transfer(&pay_id);
+
+    let royalty = price * 500 / 10000;
+    assert_eq!(usdc.balance(&seller), price - royalty);
+    assert_eq!(usdc.balance(&buyer), 0);
+    assert_eq!(
+        client.get_event_escrow_balance(&event_id).organizer_amount,
+        escrow_before.organizer_amount + royalty
+    );
+    assert_eq!(
+        client.get_payment_status(&pay_id).unwrap().buyer_address,
+        buyer
+    );
+    assert!(client.get_transfer_offer(&pay_id).is_none());
+}
+
+#[test]
+fn test_e2e_transfer_offer_expiry_and_cancellation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let holder = Address::generate(&env);
+    let friend = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    fund_buyer(&env, &usdc_id, &holder, &client.address, amount);
+    let pay_id = buy_ticket(
+        &client, &env, "offer_2", "event_1", &holder, &usdc_id, amount,
+    );
+    client.confirm_payment(&pay_id, &String::from_str(&env, "tx_offer_2"));
+
+    assert_eq!(
+        client.try_offer_transfer(&pay_id, &holder, &0),
+        Err(Ok(TicketPaymentError::InvalidAddress))
+    );
+
+    env.ledger().set_timestamp(1_000);
+    let expires_at = client.offer_transfer(&pay_id, &friend, &0);
+    env.ledger().set_timestamp(expires_at);
+    assert_eq!(
+        client.try_accept_transfer(&pay_id),
+        Err(Ok(TicketPaymentError::TransferOfferExpired))
+    );
+
+    // A fresh offer can be withdrawn before the recipient accepts
+    client.offer_transfer(&pay_id, &friend, &0);
+    client.cancel_transfer_offer(&pay_id);
+    assert!(client.get_transfer_offer(&pay_id).is_none());
+    assert_eq!(
+        client.try_accept_transfer(&pay_id),
+        Err(Ok(TicketPaymentError::InvalidPaymentStatus))
+    );
+
+    // A direct transfer invalidates an outstanding offer
+    client.offer_transfer(&pay_id, &friend, &0);
+    client.transfer_ticket(&pay_id, &Address::generate(&env), &None, &None);
+    assert!(client.get_transfer_offer(&pay_id).is_none());
+}
+
+// =============================================================================
+// 65. Escrowed resale settlement in transfer_ticket
+// =============================================================================
+
+#[test]
+fn test_e2e_priced_transfer_settles_resale_on_chain() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = String::from_str(&env, "event_1");
+    let amount = 1000_0000000i128;
+    let price = 900_0000000i128;
+    let usdc = token::Client::new(&env, &usdc_id);
+    fund_buyer(&env, &usdc_id, &seller, &client.address, amount);
+    let pay_id = buy_ticket(
+        &client, &env, "resale_1", "event_1", &seller, &usdc_id, amount,
+    );
+    client.confirm_payment(&pay_id, &String::from_str(&env, "tx_resale_1"));
+
+    assert_eq!(
+        client.try_set_resale_fee_bps(&10001),
+        Err(Ok(TicketPaymentError::InvalidFeeBps))
+    );
+    client.set_resale_fee_bps(&200);
+    client.set_resale_royalty(&event_id, &800);
+
+    // The new owner must fund the sale price up front
+    assert_eq!(
+        client.try_transfer_ticket(&pay_id, &buyer, &Some(price), &None),
+        Err(Ok(TicketPaymentError::InsufficientAllowance))
+    );
+
+    let escrow_before = client.get_event_escrow_balance(&event_id);
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, price);
+    env.ledger().set_timestamp(5_000);
+    client.transfer_ticket(&pay_id, &buyer, &Some(price), &None);
+
+    let platform_fee = price * 200 / 10000;
+    let royalty = price * 800 / 10000;
+    assert_eq!(usdc.balance(&seller), price - platform_fee - royalty);
+    assert_eq!(usdc.balance(&buyer), 0);
+    let escrow = client.get_event_escrow_balance(&event_id);
+    assert_eq!(
+        escrow.organizer_amount,
+        escrow_before.organizer_amount + royalty
+    );
+    assert_eq!(
+        escrow.platform_fee,
+        escrow_before.platform_fee + platform_fee
+    );
+    assert_eq!(
+        client.get_payment_status(&pay_id).unwrap().buyer_address,
+        buyer
+    );
+
+    let history = client.get_resale_history(&pay_id);
+    assert_eq!(history.len(), 1);
+    let record = history.get(0).unwrap();
+    assert_eq!(record.seller, seller);
+    assert_eq!(record.buyer, buyer);
+    assert_eq!(record.price, price);
+    assert_eq!(record.platform_fee, platform_fee);
+    assert_eq!(record.royalty, royalty);
+    assert_eq!(record.resold_at, 5_000);
+}
+
+// =============================================================================
+// 66. Registered resale royalty
+// =============================================================================
+
+#[test]
+fn test_e2e_registered_resale_royalty_routes_to_event_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let registry = MockRegistryE2EClient::new(&env, &registry_id);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = String::from_str(&env, "event_1");
+    let amount = 1000_0000000i128;
+    let price = 1000_0000000i128;
+    let usdc = token::Client::new(&env, &usdc_id);
+    fund_buyer(&env, &usdc_id, &seller, &client.address, amount);
+    let pay_id = buy_ticket(
+        &client,
+        &env,
+        "royalty_1",
+        "event_1",
+        &seller,
+        &usdc_id,
+        amount,
+    );
+    client.confirm_payment(&pay_id, &String::from_str(&env, "tx_royalty_1"));
+
+    // The royalty registered with the event applies until the organizer overrides it
+    registry.set_resale_royalty(&300);
+    assert_eq!(client.get_resale_royalty(&event_id), 300);
+
+    let escrow_before = client.get_event_escrow_balance(&event_id);
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, price);
+    client.transfer_ticket(&pay_id, &buyer, &Some(price), &None);
+
+    let royalty = price * 300 / 10000;
+    assert_eq!(usdc.balance(&seller), price - royalty);
+    assert_eq!(
+        client.get_event_escrow_balance(&event_id).organizer_amount,
+        escrow_before.organizer_amount + royalty
+    );
+
+    client.set_resale_royalty(&event_id, &0);
+    assert_eq!(client.get_resale_royalty(&event_id), 0);
+}
+
+// =============================================================================
+// 67. Transfer lock before event start
+// =============================================================================
+
+#[test]
+fn test_e2e_transfer_lock_blocks_transfers_unless_approved() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let registry = MockRegistryE2EClient::new(&env, &registry_id);
+    let holder = Address::generate(&env);
+    let friend = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    fund_buyer(&env, &usdc_id, &holder, &client.address, amount);
+    let pay_id = buy_ticket(
+        &client, &env, "lock_1", "event_1", &holder, &usdc_id, amount,
+    );
+    client.confirm_payment(&pay_id, &String::from_str(&env, "tx_lock_1"));
+
+    // Starts at 100_000 and locks transfers for the final day
+    registry.set_transfer_lock(&100_000, &86_400);
+    env.ledger().set_timestamp(100_000 - 86_400 - 1);
+    client.offer_transfer(&pay_id, &friend, &0);
+
+    env.ledger().set_timestamp(100_000 - 86_400);
+    assert_eq!(
+        client.try_transfer_ticket(&pay_id, &friend, &None, &None),
+        Err(Ok(TicketPaymentError::TransferLocked))
+    );
+    assert_eq!(
+        client.try_accept_transfer(&pay_id),
+        Err(Ok(TicketPaymentError::TransferLocked))
+    );
+
+    // The organizer can let a single ticket through; the approval is spent on use
+    client.approve_locked_transfer(&pay_id, &true);
+    client.transfer_ticket(&pay_id, &friend, &None, &None);
+    assert!(!client.is_locked_transfer_approved(&pay_id));
+    assert_eq!(
+        client.try_transfer_ticket(&pay_id, &holder, &None, &None),
+        Err(Ok(TicketPaymentError::TransferLocked))
+    );
+}
+
+// =============================================================================
+// 68. Check-in statistics
+// =============================================================================
+
+#[test]
+fn test_e2e_checkin_stats_count_entries_per_tier_and_scanner() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let scanner = Address::generate(&env);
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_scanner(env.clone(), scanner.clone());
+    });
+    let event_id = String::from_str(&env, "event_1");
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, 2 * amount);
+    let first = buy_ticket(
+        &client, &env, "stats_1", "event_1", &buyer, &usdc_id, amount,
+    );
+    let second = buy_ticket(
+        &client, &env, "stats_2", "event_1", &buyer, &usdc_id, amount,
+    );
+
+    let stats = client.get_checkin_stats(&event_id);
+    assert_eq!(stats.checked_in, 0);
+    assert_eq!(stats.by_scanner.len(), 0);
+
+    client.set_allow_reentry(&event_id, &true);
+    client.check_in(&first, &scanner);
+    client.check_in(&second, &scanner);
+    client.check_out(&first, &scanner);
+    client.check_in(&first, &scanner);
+
+    // Re-entries count towards the scanner's throughput but not the headcount
+    let stats = client.get_checkin_stats(&event_id);
+    assert_eq!(stats.checked_in, 2);
+    assert_eq!(stats.reentries, 1);
+    assert_eq!(stats.by_tier.get(String::from_str(&env, "tier_1")), Some(2));
+    assert_eq!(stats.by_scanner.get(scanner), Some(3));
+    assert_eq!(
+        client.get_event_attendance_count(&event_id),
+        stats.checked_in
+    );
+}
+
+// =============================================================================
+// 69. Check-in proofs
+// =============================================================================
+
+#[test]
+fn test_e2e_check_in_proof_walks_hash_chain_and_rejects_replay() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let scanner = Address::generate(&env);
+    env.as_contract(&registry_id, || {
+        MockRegistryE2E::set_scanner(env.clone(), scanner.clone());
+    });
+    let event_id = String::from_str(&env, "event_1");
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+    let pay_id = buy_ticket(
+        &client, &env, "proof_1", "event_1", &buyer, &usdc_id, amount,
+    );
+
+    // Chain seed -> link_1 -> link_2; the holder commits to the last link
+    let seed = BytesN::from_array(&env, &[7u8; 32]);
+    let link_1: BytesN<32> = env.crypto().sha256(&seed.clone().into()).into();
+    let link_2: BytesN<32> = env.crypto().sha256(&link_1.clone().into()).into();
+    client.set_check_in_commitment(&pay_id, &Some(link_2));
+
+    assert_eq!(
+        client.try_check_in(&pay_id, &scanner),
+        Err(Ok(TicketPaymentError::InvalidCheckInProof))
+    );
+    assert_eq!(
+        client.try_check_in_with_proof(&pay_id, &scanner, &seed),
+        Err(Ok(TicketPaymentError::InvalidCheckInProof))
+    );
+
+    client.set_allow_reentry(&event_id, &true);
+    client.check_in_with_proof(&pay_id, &scanner, &link_1);
+    assert_eq!(
+        client.get_check_in_commitment(&pay_id),
+        Some(link_1.clone())
+    );
+    client.check_out(&pay_id, &scanner);
+
+    // A screenshot of the first code no longer admits anyone
+    assert_eq!(
+        client.try_check_in_with_proof(&pay_id, &scanner, &link_1),
+        Err(Ok(TicketPaymentError::InvalidCheckInProof))
+    );
+    client.check_in_with_proof(&pay_id, &scanner, &seed);
+    assert_eq!(client.get_checkin_stats(&event_id).reentries, 1);
+}
+
+// =============================================================================
+// 70. Postponement grace refunds
+// =============================================================================
+
+#[test]
+fn test_e2e_postponement_grace_refunds_in_full() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, registry_id) = setup_e2e(&env);
+    let registry = MockRegistryE2EClient::new(&env, &registry_id);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let usdc = token::Client::new(&env, &usdc_id);
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, 2 * amount);
+    let in_grace = buy_ticket(
+        &client, &env, "grace_1", "event_1", &buyer, &usdc_id, amount,
+    );
+    let after_grace = buy_ticket(
+        &client, &env, "grace_2", "event_1", &buyer, &usdc_id, amount,
+    );
+    client.set_refund_processing_fee(&100, &0);
+
+    // Neither the restocking fee nor the processing fee applies in the window
+    env.ledger().set_timestamp(1_000);
+    registry.set_postponed(&2_000);
+    client.request_guest_refund(&in_grace);
+    assert_eq!(usdc.balance(&buyer), amount);
+
+    env.ledger().set_timestamp(2_001);
+    client.request_guest_refund(&after_grace);
+    let restocking_fee = 50_0000000i128;
+    let processing_fee = amount / 100;
+    assert_eq!(
+        usdc.balance(&buyer),
+        2 * amount - restocking_fee - processing_fee
+    );
+}
+
+// =============================================================================
+// 71. Organizer event statistics
+// =============================================================================
+
+#[test]
+fn test_e2e_event_stats_summarize_sales_fees_and_refunds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let event_id = String::from_str(&env, "event_1");
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount * 2);
+
+    buy_ticket(
+        &client, &env, "stats_1", "event_1", &buyer, &usdc_id, amount,
+    );
+    let refunded = buy_ticket(
+        &client, &env, "stats_2", "event_1", &buyer, &usdc_id, amount,
+    );
+    client.request_guest_refund(&refunded);
+
+    let restocking_fee = 50_0000000i128;
+    let fee = amount * 500 / 10_000;
+    let stats = client.get_event_stats(&event_id);
+    assert_eq!(stats.gross_revenue, amount * 2);
+    assert_eq!(stats.net_to_organizer, amount - fee + restocking_fee);
+    assert_eq!(stats.fees_accrued, fee);
+    assert_eq!(stats.refund_count, 1);
+    assert_eq!(stats.refund_amount, amount - restocking_fee);
+    // One of the tier's 1000 tickets remains sold
+    assert_eq!(
+        stats
+            .sold_bps_by_tier
+            .get(String::from_str(&env, "tier_1"))
+            .unwrap(),
+        10
+    );
+
+    // Withdrawals and fee settlement move funds without changing the totals
+    client.withdraw_organizer_funds(&event_id, &usdc_id);
+    client.settle_platform_fees(&event_id, &usdc_id);
+    assert_eq!(client.get_event_stats(&event_id), stats);
+}
+
+// =============================================================================
+// 72. Platform treasury report
+// =============================================================================
+
+#[test]
+fn test_e2e_treasury_report_per_whitelisted_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let other_token = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let fee = amount * 500 / 10000;
+
+    // Whitelisted tokens are reported before they collect any fees
+    client.add_token(&other_token);
+    let report = client.get_treasury_report();
+    assert_eq!(report.len(), 2);
+    assert_eq!(report.get(1).unwrap().token, other_token);
+    assert_eq!(report.get(1).unwrap().lifetime_fees, 0);
+    client.remove_token(&other_token);
+
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+    buy_ticket(&client, &env, "pay_r1", "event_1", &buyer, &usdc_id, amount);
+    client.set_withdrawal_cap(&usdc_id, &(fee * 2));
+    client.settle_platform_fees(&String::from_str(&env, "event_1"), &usdc_id);
+    client.withdraw_platform_fees(&(fee / 2), &usdc_id);
+
+    let report = client.get_treasury_report();
+    assert_eq!(report.len(), 1);
+    let line = report.get(0).unwrap();
+    assert_eq!(line.token, usdc_id);
+    assert_eq!(line.lifetime_fees, fee);
+    assert_eq!(line.available_fees, fee - fee / 2);
+    assert_eq!(line.escrow_at_risk, amount - fee);
+    assert_eq!(line.withdrawal_cap, fee * 2);
+    assert_eq!(line.withdrawn_today, fee / 2);
+    assert_eq!(line.cap_used_bps, 2500);
+}
+
+// =============================================================================
+// 73. Event and payment ids in event topics
+// =============================================================================
+
+#[test]
+fn test_e2e_event_topics_carry_event_and_payment_ids() {
+    use crate::events::AgoraEvent;
+    use soroban_sdk::IntoVal;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc_id, _pw, _registry_id) = setup_e2e(&env);
+    let buyer = Address::generate(&env);
+    let amount = 1000_0000000i128;
+    let event_id = String::from_str(&env, "event_1");
+    fund_buyer(&env, &usdc_id, &buyer, &client.address, amount);
+
+    let payment_id = buy_ticket(
+        &client, &env, "topics_1", "event_1", &buyer, &usdc_id, amount,
+    );
+    let topic: soroban_sdk::Vec<soroban_sdk::Val> = (
+        AgoraEvent::PaymentProcessed,
+        event_id.clone(),
+        payment_id.clone(),
+    )
+        .into_val(&env);
+    assert!(env
+        .events()
+        .all()
+        .iter()
+        .any(|(_, topics, _)| topics == topic));
+
+    client.request_guest_refund(&payment_id);
+    let topic: soroban_sdk::Vec<soroban_sdk::Val> =
+        (AgoraEvent::PaymentStatusChanged, event_id, payment_id).into_val(&env);
+    assert!(env
+        .events()
+        .all()
+        .iter()
+        .any(|(_, topics, _)| topics == topic));
+}
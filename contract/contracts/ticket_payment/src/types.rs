@@ -1,4 +1,56 @@
-use soroban_sdk::{contracttype, Address, BytesN, String};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Map, String, Vec};
+
+/// Maximum size in bytes of a payment memo.
+pub const MAX_MEMO_LEN: u32 = 256;
+
+/// Maximum length of a payment id that batch ticket ids are derived from.
+pub const MAX_PAYMENT_ID_LEN: u32 = 64;
+
+/// Maximum length of a registry tenant's event id namespace.
+pub const MAX_TENANT_NAMESPACE_LEN: u32 = 16;
+
+/// Maximum length of an event id a registry tenant can bind; tenant event ids
+/// are `<namespace>:<id>`.
+pub const MAX_TENANT_EVENT_ID_LEN: u32 = 128;
+
+/// Number of recent failed purchase attempts retained for support triage.
+pub const MAX_FAILED_PURCHASES: u32 = 50;
+
+/// Maximum number of options in a ticket-holder poll.
+pub const MAX_POLL_OPTIONS: u32 = 10;
+
+/// Maximum number of sponsor slots an event can define.
+pub const MAX_SPONSOR_SLOTS: u32 = 10;
+/// Maximum number of sponsorships an event can sell across all its slots.
+pub const MAX_EVENT_SPONSORS: u32 = 50;
+
+/// Maximum number of installments a ticket's price can be split into.
+pub const MAX_INSTALLMENTS: u32 = 12;
+/// Seconds allowed between consecutive installment deadlines.
+pub const INSTALLMENT_INTERVAL: u64 = 30 * 24 * 60 * 60;
+
+/// Longest a `reserve_tickets` hold may last, in seconds.
+pub const MAX_RESERVATION_TTL: u64 = 15 * 60;
+
+/// Seconds a pending `offer_transfer` stays acceptable by its recipient.
+pub const TRANSFER_OFFER_TTL: u64 = 7 * 24 * 60 * 60;
+
+/// Seconds buyers have to claim from a cancelled event's refund pool.
+pub const REFUND_CLAIM_WINDOW: u64 = 90 * 24 * 60 * 60;
+
+/// Decimals of the price oracle's rates and of tier `usd_price`s.
+pub const ORACLE_DECIMALS: u32 = 7;
+/// One whole unit at `ORACLE_DECIMALS`, i.e. a rate of 1:1.
+pub const ORACLE_PRICE_SCALE: i128 = 1_0000000;
+
+/// Event operator permission: may run `issue_partial_refund` batches.
+pub const OPERATOR_PARTIAL_REFUND: u32 = 1 << 0;
+/// Event operator permission: may run `trigger_bulk_refund` batches.
+pub const OPERATOR_BULK_REFUND: u32 = 1 << 1;
+
+/// Layout version of the records this build writes. Deployments that predate
+/// versioning are at version 1; `migrate` brings them up to this one.
+pub const STORAGE_VERSION: u32 = 2;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -8,6 +60,7 @@ pub enum PaymentStatus {
     Refunded,
     Failed,
     CheckedIn,
+    Reserved, // held under an installment plan until fully paid
 }
 
 #[contracttype]
@@ -17,7 +70,30 @@ pub struct Payment {
     pub event_id: String,
     pub buyer_address: Address,
     pub ticket_tier_id: String,
-    pub amount: i128, // USDC amount in stroops
+    pub amount: i128,           // amount in stroops of token_address
+    pub token_address: Address, // token the buyer paid in, used for refunds
+    pub platform_fee: i128,
+    pub organizer_amount: i128,
+    pub status: PaymentStatus,
+    pub transaction_hash: String,
+    pub created_at: u64,
+    pub confirmed_at: Option<u64>,
+    pub refunded_amount: i128,
+    pub identity_hash: Option<BytesN<32>>, // hash of an external identity claim, if bound
+    pub memo: Option<Bytes>, // encrypted gift/support memo, at most MAX_MEMO_LEN bytes
+    pub seat_id: Option<String>, // assigned seat, for tiers sold from a seat map
+}
+
+/// Payment as stored by version 1 deployments, which only accepted USDC and
+/// had no identity binding, memo or seat.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentV1 {
+    pub payment_id: String,
+    pub event_id: String,
+    pub buyer_address: Address,
+    pub ticket_tier_id: String,
+    pub amount: i128,
     pub platform_fee: i128,
     pub organizer_amount: i128,
     pub status: PaymentStatus,
@@ -27,6 +103,73 @@ pub struct Payment {
     pub refunded_amount: i128,
 }
 
+impl PaymentV1 {
+    /// Converts to the current layout, recording `usdc_token` as the token paid in.
+    pub fn into_current(self, usdc_token: Address) -> Payment {
+        Payment {
+            payment_id: self.payment_id,
+            event_id: self.event_id,
+            buyer_address: self.buyer_address,
+            ticket_tier_id: self.ticket_tier_id,
+            amount: self.amount,
+            token_address: usdc_token,
+            platform_fee: self.platform_fee,
+            organizer_amount: self.organizer_amount,
+            status: self.status,
+            transaction_hash: self.transaction_hash,
+            created_at: self.created_at,
+            confirmed_at: self.confirmed_at,
+            refunded_amount: self.refunded_amount,
+            identity_hash: None,
+            memo: None,
+            seat_id: None,
+        }
+    }
+}
+
+/// Terms of a hashed discount code, as uploaded by an event organizer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiscountSpec {
+    pub hash: BytesN<32>, // sha256 of the code buyers present
+    pub bps: u32,
+    pub max_uses: u32,           // 0 for unlimited
+    pub max_uses_per_buyer: u32, // 0 for unlimited
+    pub expiry: u64,             // 0 for no expiry
+    pub tier_restriction: Option<String>,
+}
+
+/// A registered discount code and how often it has been redeemed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiscountCode {
+    pub event_id: String,
+    pub spec: DiscountSpec,
+    pub uses_so_far: u32,
+}
+
+/// Optional purchase-time extras for `process_payment`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PurchaseOptions {
+    /// Binds the ticket to a hashed identity claim (e.g. a KYC or ID-document
+    /// attestation) so venues can verify the holder even after wallet key rotation.
+    pub identity_hash: Option<BytesN<32>>,
+    /// Small encrypted memo (e.g. a gift message) stored with the payment.
+    pub memo: Option<Bytes>,
+    /// Registry tenant the event belongs to; defaults to the configured registry.
+    pub registry: Option<Address>,
+    /// Seats to buy, one per ticket, for tiers with a seat map; empty otherwise.
+    pub seats: Vec<String>,
+    /// Tightest price deviation, in bps, the buyer accepts on oracle-quoted
+    /// prices. Never widens the platform's tolerance.
+    pub max_slippage_bps: Option<u32>,
+    /// Code of a prepaid voucher to apply as credit toward the ticket price.
+    pub voucher_code: Option<Bytes>,
+    /// Promoter of the event whose link the buyer came through.
+    pub promoter: Option<Address>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EventBalance {
@@ -35,6 +178,763 @@ pub struct EventBalance {
     pub platform_fee: i128,
 }
 
+/// Read-only estimate of what cancelling an event would cost, mirroring
+/// `trigger_bulk_refund` (every confirmed payment is refunded what earlier
+/// partial refunds left of it).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CancellationPreview {
+    pub confirmed_count: u32,
+    pub refundable_by_token: Map<Address, i128>,
+    pub total_refundable: i128,
+    pub platform_fees_reversed: i128,
+    pub escrow_by_token: Map<Address, i128>, // organizer_amount + platform_fee currently held
+    pub shortfall_by_token: Map<Address, i128>, // refundable not covered by the token's escrow
+}
+
+/// Platform-configured fee charged on buyer-initiated refunds.
+/// Either component may be zero; when both are set they are added together.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundProcessingFee {
+    pub bps: u32,
+    pub fixed_amount: i128,
+}
+
+/// Identifies a promotional campaign for effectiveness tracking.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PromoScope {
+    /// Platform-wide promo, identified by its expiry timestamp
+    Global(u64),
+    /// Discount codes redeemed for a specific event
+    Event(String),
+}
+
+/// Aggregated effectiveness metrics for a promo campaign.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PromoStats {
+    pub payment_count: u32,
+    pub gross_discount: i128,
+    pub net_revenue: i128,
+}
+
+/// Every intermediate amount of a purchase, as computed by `compute_pricing_breakdown`.
+/// `net_paid == platform_fee + referral_cut + organizer_net`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PricingBreakdown {
+    pub gross: i128,
+    pub promo_reduction: i128,
+    pub code_discount: i128,
+    pub net_paid: i128,
+    pub platform_fee: i128,
+    pub referral_cut: i128,
+    pub organizer_net: i128,
+}
+
+/// Inventory of a single tier. `reserved` counts box-office allotments not yet
+/// assigned to a buyer; `available == limit - sold - reserved`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierAvailability {
+    pub limit: i128,
+    pub sold: i128,
+    pub reserved: i128,
+    pub available: i128,
+}
+
+/// Inventory of an event across all tiers. `max_supply` of 0 means unlimited;
+/// otherwise `available` is additionally capped by the remaining event supply.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventAvailability {
+    pub tiers: Map<String, TierAvailability>,
+    pub max_supply: i128,
+    pub sold: i128,
+    pub reserved: i128,
+    pub available: i128,
+}
+
+/// Sales of a single tier. `sold` counts tickets still held (pending, confirmed,
+/// checked in or reserved); revenue figures cover those tickets only, net of
+/// partial refunds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierSalesSummary {
+    pub sold: u32,
+    pub refunded: u32,
+    pub gross_revenue: i128,
+    pub net_revenue: i128, // organizer share after platform fees
+}
+
+/// Sales of an event across all tiers, for organizer dashboards.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventSalesSummary {
+    pub tiers: Map<String, TierSalesSummary>,
+    pub status_counts: Map<PaymentStatus, u32>,
+    pub gross_revenue: i128,
+    pub net_revenue: i128,
+}
+
+/// Headline figures of an event for organizer analytics, summed across tokens.
+/// Money figures come from the event's escrow ledger and balance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventStats {
+    /// Ticket sales before discounts
+    pub gross_revenue: i128,
+    /// Organizer share withdrawn or still held in escrow
+    pub net_to_organizer: i128,
+    /// Platform fees settled or still held in escrow
+    pub fees_accrued: i128,
+    /// Refund transfers made to buyers, partial refunds included
+    pub refund_count: u32,
+    pub refund_amount: i128,
+    /// Share of each tier's limit sold, in basis points (0 for unlimited tiers)
+    pub sold_bps_by_tier: Map<String, u32>,
+}
+
+/// A purchase attempt that failed, kept for support triage. `reason_code` is the
+/// `TicketPaymentError` code returned by the failed call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FailedPurchase {
+    pub payment_id: String,
+    pub event_id: String,
+    pub buyer: Address,
+    pub reason_code: u32,
+    pub timestamp: u64,
+}
+
+/// Keys of attendance records and the coupon airdrops built on them.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CouponKey {
+    Grant(String, Address),            // (target_event_id, holder) -> discount bps
+    Cursor(String, String),            // (source_event_id, target_event_id) -> next payment index
+    AttendanceShard(String, u32),      // (event_id, shard_id) -> Vec<AttendanceRecord>
+    AttendanceCount(String),           // event_id -> number of check-ins
+    AttendedEvents(Address),           // holder -> Vec<event_id> checked into, oldest first
+    ReentryAllowed(String), // event_id -> bool, whether checked-in holders may leave and return
+    CheckedOut(String),     // payment_id -> true while a checked-in holder is outside
+    Delegate(String),       // payment_id -> attendee checking in on the holder's behalf
+    ScanHistory(String),    // payment_id -> Vec<ScanRecord>, oldest first
+    CheckInStats(String),   // event_id -> CheckInStats
+    Commitment(String),     // payment_id -> hash the next check-in proof must preimage
+    Voucher(BytesN<32>),    // sha256 of the voucher code -> Voucher
+    DiscountUses(BytesN<32>, Address), // (code hash, buyer) -> purchases made with the code
+    LoyaltyEarnBps(Address), // token -> points earned per unit spent, in bps
+    LoyaltyPointValue,      // u32 — token units one point is worth at redemption, in bps
+    LoyaltyPoints(Address), // buyer -> unspent loyalty points
+}
+
+/// A prepaid gift voucher. Its value is held by the contract until redeemed as
+/// payment credit in `process_payment` or reclaimed by the purchaser after expiry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Voucher {
+    pub code_hash: BytesN<32>,
+    pub purchaser: Address,
+    pub token: Address,
+    pub value: i128,
+    pub balance: i128,
+    /// Buyer who first redeemed the voucher; only they may spend the rest of it,
+    /// since the code becomes public with the first redemption.
+    pub holder: Option<Address>,
+    pub created_at: u64,
+    pub expires_at: u64,
+}
+
+/// A single gate scan of a ticket.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScanRecord {
+    pub scanner: Address,
+    pub timestamp: u64,
+    pub entry: bool, // true for check-in, false for check-out
+}
+
+/// Door throughput of an event. `checked_in` and `by_tier` count tickets on
+/// their first entry; `by_scanner` counts every admitted scan, re-entries
+/// included.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CheckInStats {
+    pub checked_in: u32,
+    pub reentries: u32,
+    pub by_tier: Map<String, u32>,
+    pub by_scanner: Map<Address, u32>,
+}
+
+/// A check-in recorded in an event's attendance index.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttendanceRecord {
+    pub payment_id: String,
+    pub attendee: Address,
+    pub checked_in_at: u64,
+}
+
+/// A ticket-holder poll. Each option's tally counts eligible tickets, i.e. tickets
+/// bought no later than `created_at` and still confirmed or checked in.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Poll {
+    pub question_hash: BytesN<32>, // hash of the off-chain question and option labels
+    pub tallies: Vec<u32>,
+    pub created_at: u64,
+    pub deadline: u64,
+}
+
+/// Keys of ticket-holder polls.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PollKey {
+    Poll(String, u32),            // (event_id, poll_id) -> Poll
+    Count(String),                // event_id -> number of polls opened
+    Counted(String, u32, String), // (event_id, poll_id, payment_id) -> ticket already voted
+}
+
+/// Keys of external contracts ticket_payment calls into.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IntegrationKey {
+    OracleContract,           // Address of the price oracle
+    OracleSlippageBps,        // u32 — slippage tolerance in bps (default 200 = 2%)
+    RewardsContract,          // Address of the loyalty rewards hook, if any
+    TicketNftContract,        // Address of the ticket NFT collection, if any
+    TicketNftToken(String),   // payment_id -> token_id minted for that ticket
+    AttendanceMinter(String), // event_id -> proof-of-attendance minter, if any
+    YieldAdapter,             // Address of the strategy idle escrow may be deposited in
+    YieldReserveBps,          // u32 — share of active escrow that must stay liquid
+    YieldDeployed(Address),   // token -> amount currently deposited in the strategy
+    YieldDeployedTokens,      // u32 — tokens with a non-zero amount in the strategy
+}
+
+/// A sponsorship package an organizer sells for an event.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SponsorSlot {
+    pub slot_id: String,
+    pub name: String,
+    pub price: i128,
+    pub benefits_cid: String, // IPFS CID describing the sponsor benefits
+    pub quantity: u32,
+    pub sold: u32,
+}
+
+/// A sponsor slot purchase, escrowed like a ticket sale.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sponsorship {
+    pub slot_id: String,
+    pub sponsor: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub platform_fee: i128,
+    pub purchased_at: u64,
+}
+
+/// A ticket offered on the resale marketplace. While listed the ticket is held
+/// in escrow: it cannot be transferred, refunded or checked in.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Listing {
+    pub payment_id: String,
+    pub event_id: String,
+    pub seller: Address,
+    pub token: Address,
+    pub price: i128,
+    pub listed_at: u64,
+}
+
+/// One settled on-chain resale of a ticket. The buyer paid `price`; the
+/// seller received it less `platform_fee` and the organizer's `royalty`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResaleRecord {
+    pub seller: Address,
+    pub buyer: Address,
+    pub token: Address,
+    pub price: i128,
+    pub platform_fee: i128,
+    pub royalty: i128,
+    pub resold_at: u64,
+}
+
+/// A holder's pending transfer of a ticket to one recipient. The ticket only
+/// moves once the recipient accepts, paying `price` through the contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferOffer {
+    pub payment_id: String,
+    pub event_id: String,
+    pub from: Address,
+    pub to: Address,
+    pub token: Address,
+    pub price: i128,
+    pub expires_at: u64,
+}
+
+/// Pay-over-time schedule for a reserved ticket. The ticket's inventory is held
+/// and it stays `Reserved` until every installment is paid; missing `next_due`
+/// lets anyone forfeit the plan, refunding what was paid minus the refund fee.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InstallmentPlan {
+    pub payment_id: String,
+    pub token: Address,
+    pub total_amount: i128,
+    pub paid_amount: i128,
+    pub num_installments: u32,
+    pub installments_paid: u32,
+    pub next_due: u64, // 0 once fully paid
+}
+
+/// Tickets held for a buyer between quote and payment. The registry keeps the
+/// inventory on hold until `expires_at`; `complete_reserved_payment` buys them
+/// under `reservation_id` as the payment id.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reservation {
+    pub reservation_id: String,
+    pub buyer: Address,
+    pub event_id: String,
+    pub tier_id: String,
+    pub quantity: u32,
+    pub expires_at: u64,
+}
+
+/// A buyer's or organizer's challenge of a single payment. While open, the
+/// payment's organizer share (`frozen_amount`) is held out of payouts and the
+/// ticket cannot be refunded, transferred or listed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentDispute {
+    pub payment_id: String,
+    pub event_id: String,
+    pub opened_by: Address,
+    pub reason_cid: String,
+    pub bond: i128,
+    /// Token the bond was paid in, and is paid back out in
+    pub bond_token: Address,
+    pub frozen_amount: i128,
+    pub opened_at: u64,
+    pub resolved: bool,
+    /// Share of `frozen_amount` awarded to the buyer, set on resolution
+    pub buyer_share_bps: u32,
+}
+
+/// Escrow of a cancelled event locked for its buyers. Created when the event's
+/// registry reports the cancellation; anyone can then pay a ticket's refund out
+/// of it until `claim_deadline`, after which the organizer sweeps what is left.
+/// Each token the event sold in is pooled separately.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundPool {
+    pub event_id: String,
+    pub locked: Map<Address, i128>, // token -> escrow locked at cancellation
+    pub claimed: Map<Address, i128>, // token -> refunds paid out so far
+    pub claim_deadline: u64,
+    pub swept: Vec<Address>, // tokens whose remainder went to the organizer
+}
+
+/// An organizer's payout preferences. `min_withdrawal` is the withdrawable
+/// amount at which `WithdrawableThresholdReached` fires; with `auto_accumulate`
+/// set, withdrawals are also held back until that amount is available.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutConfig {
+    pub min_withdrawal: i128,
+    pub auto_accumulate: bool,
+}
+
+/// Keys of withdrawal limits and organizer payout settings.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum WithdrawalKey {
+    Cap(Address),              // token_address -> max amount per day
+    Daily(Address, u64),       // (token_address, day_timestamp) -> amount withdrawn
+    PayoutConfig(Address),     // organizer -> PayoutConfig
+    ThresholdNotified(String), // event_id -> threshold event already emitted
+}
+
+/// Totals of one token's entries in an event's escrow ledger, for accounting
+/// exports. `gross_sales` is list value before discounts; `net_to_organizer`
+/// is what has actually been paid out to the organizer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementReportLine {
+    pub token: Address,
+    pub gross_sales: i128,
+    pub discounts_granted: i128,
+    pub promo_absorbed: i128,
+    pub refunds_paid: i128,
+    pub restocking_retained: i128,
+    pub fees_settled: i128,
+    pub royalties: i128,
+    pub transfer_fees: i128,
+    pub promoter_commissions: i128,
+    pub net_to_organizer: i128,
+}
+
+/// Keys of the resale marketplace.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MarketKey {
+    Listing(String),       // payment_id -> Listing
+    EventListings(String), // event_id -> Vec<payment_id> currently listed
+    RoyaltyBps(String),    // event_id -> organizer royalty override on resales, in bps
+    Offer(String),         // payment_id -> TransferOffer awaiting the recipient
+    Resales(String),       // payment_id -> Vec<ResaleRecord>, oldest first
+    PlatformFeeBps,        // u32 platform fee on every on-chain resale, in bps
+    LockApproved(String), // payment_id -> true if the organizer lets it move during the transfer lock
+}
+
+/// Keys of dispute state.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeKey {
+    Event(String),        // event_id -> bool, the event-wide dispute flag
+    Payment(String),      // payment_id -> PaymentDispute
+    Frozen(String),       // event_id -> organizer share held back by open payment disputes
+    Arbiter(Address),     // arbiter -> true while allowed to resolve disputes
+    Bond,                 // amount an opener deposits with a dispute
+    SpikeConfig,          // RefundSpikeConfig tripping the event-wide flag automatically
+    RefundWindow(String), // event_id -> RefundWindow of recent buyer-initiated refunds
+    Paused(String),       // event_id -> address that froze the event's sales and transfers
+}
+
+/// Share of an event's sales, in bps, that may be refunded by buyers within
+/// `window` seconds before the event is flagged as disputed. 0 disables.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundSpikeConfig {
+    pub threshold_bps: u32,
+    pub window: u64,
+}
+
+/// Buyer-initiated refunds of an event counted since `started_at`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundWindow {
+    pub started_at: u64,
+    pub refunds: u32,
+}
+
+/// Keys of the referral program.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReferralKey {
+    ShareBps,                           // u32 — referrer share of the platform fee
+    OptedOut(String),                   // event_id -> true if the event pays no referral rewards
+    Accrued(Address, Address),          // (referrer, token) -> unclaimed rewards
+    Promoter(String, Address), // (event_id, promoter) -> share of the organizer portion, in bps
+    PromoterEarnings(Address, Address), // (promoter, token) -> unclaimed commissions
+    TicketCommissions(String), // payment_id -> TicketCommissions
+}
+
+/// Commissions accrued on the sale of a single ticket, taken back if the
+/// ticket is refunded in full.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketCommissions {
+    pub promoter: Option<Address>,
+    pub promoter_cut: i128,
+    pub referrer: Option<Address>,
+    pub referral_cut: i128,
+}
+
+/// Keys of cancellation refund state.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RefundKey {
+    Pool(String),           // event_id -> RefundPool
+    ProcessingFee,          // RefundProcessingFee charged on buyer-initiated refunds
+    FeesCollected(Address), // cumulative refund processing fees collected by token
+    ChangeFee(String),      // event_id -> fee withheld from ticket downgrade refunds
+    BulkDeferred(String),   // event_id -> Vec<u32> payment indices the bulk refund must revisit
+    BulkRefunded(String), // event_id -> (u32, Map<Address, i128>) payments and amounts refunded in bulk
+}
+
+/// Progress of an event's bulk refund. `processed` of the event's `total`
+/// payments have been scanned; `deferred` of those could not be refunded yet
+/// (reserved or disputed) and are revisited by later batches.
+/// `refunded_by_token` totals the refunds per token they were paid in.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BulkRefundProgress {
+    pub processed: u32,
+    pub total: u32,
+    pub deferred: u32,
+    pub refunded_count: u32,
+    pub refunded_by_token: Map<Address, i128>,
+}
+
+/// Keys of installment plans and ticket reservations.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InstallmentKey {
+    Plan(String),        // payment_id -> InstallmentPlan
+    Reservation(String), // reservation_id -> Reservation
+}
+
+/// Keys of per-event sponsor slots.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SponsorKey {
+    Slot(String, String), // (event_id, slot_id) -> SponsorSlot
+    SlotIds(String),      // event_id -> Vec<slot_id>, in definition order
+    Sponsors(String),     // event_id -> Vec<Sponsorship>, in purchase order
+}
+
+/// Keys of per-event operator grants and wallets exempt from buyer limits.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OperatorKey {
+    Grant(String, Address), // (event_id, operator) -> OPERATOR_* permission bitmask
+    LimitExempt(String, Address), // (event_id, wallet) -> true if not bound by max_tickets_per_buyer
+}
+
+/// Keys of the per-token platform fee treasuries.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TreasuryKey {
+    Wallet(Address),       // token -> payout wallet overriding the platform wallet
+    Tokens,                // Vec<Address> of tokens that have ever collected fees
+    PendingPlatformWallet, // platform wallet proposed by the admin, awaiting acceptance
+    Whitelisted,           // Vec<Address> of whitelisted tokens, in whitelisting order
+    LifetimeFees(Address), // token -> platform fees ever collected, never decremented
+    HeldFunds(Address),    // token -> deposits, voucher balances and dispute bonds owed to users
+}
+
+/// Platform fee treasury of a single token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryBalance {
+    pub token: Address,
+    pub wallet: Address,
+    pub balance: i128,
+    pub withdrawal_cap: i128, // daily cap, 0 for none
+    pub withdrawn_today: i128,
+}
+
+/// Line of the platform treasury report for a single token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryReportLine {
+    pub token: Address,
+    pub lifetime_fees: i128,
+    pub available_fees: i128, // withdrawable via `withdraw_platform_fees`
+    pub escrow_at_risk: i128, // escrow held for events, refundable to buyers
+    pub withdrawal_cap: i128, // daily cap, 0 for none
+    pub withdrawn_today: i128,
+    pub cap_used_bps: u32, // share of today's cap withdrawn, 0 without a cap
+}
+
+/// Machine-readable description of a deployed contract and how it is wired.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractMetadata {
+    pub name: String,
+    pub version: String,
+    /// Revision of the public interface, bumped on breaking entrypoint changes
+    pub interface_revision: u32,
+    /// Address of the counterpart Agora contract, if configured
+    pub counterpart: Option<Address>,
+}
+
+/// Tracks which lifecycle events have been emitted for a tier's flash sale.
+/// `start` identifies the sale so a newly scheduled one is announced afresh.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FlashSaleState {
+    pub start: u64,
+    pub ended: bool,
+}
+
+/// Keys of the append-only index of events that have ever accrued platform fees,
+/// used to settle fees across many events in bounded batches.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FeeEventIndexKey {
+    Shard(u32),     // shard_id -> Vec<event_id>
+    Count,          // total number of indexed events
+    Member(String), // event_id -> bool
+}
+
+/// Kind of escrow mutation recorded in an event's ledger.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LedgerEntryType {
+    Sale,
+    Refund,
+    Withdrawal,
+    FeeSettlement,
+    TransferRoyalty,    // organizer's fee on a holder-to-holder transfer
+    Discount,           // price reduction granted to the buyer of a sale (no funds move)
+    PromoAbsorbed,      // promo cost borne by the platform on the organizer's behalf
+    RestockingFee,      // part of a refunded payment retained by the organizer
+    ResaleRoyalty,      // organizer's royalty on a marketplace resale
+    PromoterCommission, // promoter's share of a sale, taken from the organizer portion
+    LoyaltyRebate,      // price returned to a buyer redeeming loyalty points
+}
+
+/// A single bank-statement-style line in an event's escrow ledger.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LedgerEntry {
+    pub entry_type: LedgerEntryType,
+    pub amount: i128,
+    pub token: Address,
+    pub counterparty: Address,
+    pub timestamp: u64,
+}
+
+/// Keys of the sharded per-event escrow ledger.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EventLedgerKey {
+    Shard(String, u32), // (event_id, shard_id) -> Vec<LedgerEntry>
+    Count(String),      // event_id -> number of entries
+    // event_id -> Map<token, EventBalance> for tokens other than the default
+    // one; the default token's slice is the aggregate balance minus these.
+    TokenBalances(String),
+}
+
+/// Keys scoping events, fees and allow-listing per registry tenant.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TenantKey {
+    Allowed(Address),                // registry -> bool
+    EventRegistry(String),           // event_id -> registry the event is bound to
+    FeesCollected(Address, Address), // (registry, token) -> cumulative platform fees
+    Namespace(String),               // namespace -> registry it is reserved for
+    NamespaceOf(Address),            // registry -> its reserved event id namespace
+}
+
+/// Refundable damage deposit escrowed alongside a ticket.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketDeposit {
+    pub amount: i128,
+    pub token: Address,
+    pub deduction: i128,
+    pub reason: Option<String>,
+    pub released: bool,
+}
+
+/// Keys of the damage-deposit escrow.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DepositKey {
+    Ticket(String),      // payment_id -> TicketDeposit
+    WindowStart(String), // event_id -> timestamp the deduction window opened
+}
+
+/// Terms captured at purchase time for building the receipt on confirmation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PurchaseTerms {
+    pub token: Address,
+    pub quantity: u32,
+    pub terms_hash: BytesN<32>,
+}
+
+/// Fixed-layout purchase receipt issued when a payment is confirmed. Amounts are
+/// per ticket; `quantity` is the size of the order the ticket was bought in.
+/// Its hash (SHA-256 of the XDR encoding) is published in the confirmation event.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Receipt {
+    pub payment_id: String,
+    pub event_id: String,
+    pub tier_id: String,
+    pub buyer: Address,
+    pub token: Address,
+    pub quantity: u32,
+    pub unit_amount: i128,
+    pub platform_fee: i128,
+    pub organizer_amount: i128,
+    pub terms_hash: BytesN<32>,
+    pub confirmed_at: u64,
+}
+
+/// Keys of purchase receipts.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReceiptKey {
+    Terms(String),  // payment_id -> PurchaseTerms
+    Issued(String), // payment_id -> Receipt
+    Bundle(String), // purchase_id -> BundlePurchase
+}
+
+/// A bundle bought through `process_bundle_payment`, linking the payment
+/// created for each included event, in the bundle's event order.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BundlePurchase {
+    pub purchase_id: String,
+    pub bundle_id: String,
+    pub buyer: Address,
+    pub payment_ids: Vec<String>,
+    pub amount: i128,
+    pub created_at: u64,
+}
+
+/// A platform fee settlement for one event, identified by a deterministic invoice ID.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementRecord {
+    pub invoice_id: BytesN<32>,
+    pub event_id: String,
+    pub period: u32, // per-event settlement sequence number, starting at 0
+    pub amount: i128,
+    pub token: Address,
+    pub timestamp: u64,
+}
+
+/// Keys of the sharded platform fee settlement history.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SettlementKey {
+    Shard(u32),           // shard_id -> Vec<SettlementRecord>
+    Count,                // total number of settlement records
+    EventPeriods(String), // event_id -> number of settlements so far
+    ChallengeWindow,      // seconds revenue stays locked after completion
+}
+
+/// Keys of partial refund state.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PartialRefundKey {
+    Index(String),      // event_id -> last processed payment index
+    Percentage(String), // event_id -> active refund percentage in bps
+    VoidThreshold,      // u32 — share of a ticket's price, in bps, refunded at which it is voided
+}
+
+/// Keys of the organizer-escrow dead-man's switch and of admin token rescues.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RecoveryKey {
+    LastActivity(String), // event_id -> last organizer activity timestamp
+    Claim(String),        // event_id -> timestamp the pending recovery was initiated
+    Rescue(Address),      // token -> pending TokenRescue
+}
+
+/// Admin withdrawal of stray tokens, executable once its timelock has elapsed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenRescue {
+    pub token: Address,
+    pub amount: i128,
+    pub to: Address,
+    pub proposed_at: u64,
+}
+
 #[contracttype]
 pub enum DataKey {
     Payment(String), // payment_id -> Payment
@@ -50,28 +950,47 @@ pub enum DataKey {
     BuyerPaymentShard(Address, u32),
     /// Total number of payments for a buyer (Persistent)
     BuyerPaymentCount(Address),
-    Admin,                               // Contract administrator address
-    UsdcToken,                           // USDC token address
-    PlatformWallet,                      // Platform wallet address
-    EventRegistry,                       // Event Registry contract address
-    Initialized,                         // Initialization flag
-    TokenWhitelist(Address),             // token_address -> bool
-    Balances(String),                    // event_id -> EventBalance (escrow tracking)
-    TransferFee(String),                 // event_id -> transfer_fee amount
-    BulkRefundIndex(String),             // event_id -> last processed payment index
-    PriceSwitched(String, String),       // (event_id, tier_id) -> bool
-    TotalVolumeProcessed,                // protocol-wide gross volume from all ticket sales
-    TotalFeesCollected(Address),         // cumulative platform fees collected by token
-    ActiveEscrowTotal,                   // protocol-wide active escrow across all tokens
-    ActiveEscrowByToken(Address),        // active escrow amount per token
-    DiscountCodeHash(BytesN<32>),        // sha256_hash -> bool (registered)
-    DiscountCodeUsed(BytesN<32>),        // sha256_hash -> bool (spent)
-    WithdrawalCap(Address),              // token_address -> max amount per day
-    DailyWithdrawalAmount(Address, u64), // (token_address, day_timestamp) -> amount withdrawn
-    IsPaused,                            // bool – global circuit breaker flag
-    DisputeStatus(String),               // event_id -> bool
-    PartialRefundIndex(String),          // event_id -> last processed payment index
-    PartialRefundPercentage(String),     // event_id -> active refund percentage in bps
-    OracleAddress,                       // Address of oracle contract
-    SlippageBps,                         // u32 — slippage tolerance in bps (default 200 = 2%)
+    Admin,                              // Contract administrator address
+    PendingAdmin,                       // Admin nominated by `transfer_admin`, awaiting acceptance
+    UsdcToken,                          // USDC token address
+    PlatformWallet,                     // Platform wallet address
+    EventRegistry,                      // Event Registry contract address
+    Initialized,                        // Initialization flag
+    TokenWhitelist(Address),            // token_address -> bool
+    Balances(String),                   // event_id -> EventBalance (escrow tracking)
+    TransferFee(String),                // event_id -> transfer_fee amount
+    BulkRefundIndex(String),            // event_id -> last processed payment index
+    PriceSwitched(String, String),      // (event_id, tier_id) -> bool
+    TotalVolumeProcessed,               // protocol-wide gross volume from all ticket sales
+    TotalFeesCollected(Address),        // cumulative platform fees collected by token
+    ActiveEscrowTotal,                  // protocol-wide active escrow across all tokens
+    ActiveEscrowByToken(Address),       // active escrow amount per token
+    DiscountCodeHash(BytesN<32>),       // sha256_hash -> DiscountCode
+    Withdrawal(WithdrawalKey),          // withdrawal caps and organizer payout settings
+    IsPaused,                           // bool – global circuit breaker flag
+    Dispute(DisputeKey),                // event-wide flags and per-payment arbitration
+    PartialRefund(PartialRefundKey),    // event-wide batches and per-ticket void threshold
+    PromoStats(PromoScope),             // PromoScope -> PromoStats
+    FeeEventIndex(FeeEventIndexKey),    // index of events that have accrued platform fees
+    EventLedger(EventLedgerKey),        // per-event escrow ledger entries
+    FlashSaleState(String, String),     // (event_id, tier_id) -> FlashSaleState
+    Tenant(TenantKey),                  // multi-registry tenant scoping
+    BoxOffice(String, String, Address), // (event_id, tier_id, custodian) -> unassigned tickets
+    BoxOfficeReserved(String, String),  // (event_id, tier_id) -> unassigned tickets, summed
+    Deposit(DepositKey),                // damage deposits held per ticket
+    Integration(IntegrationKey),        // oracle, rewards hook and ticket NFT wiring
+    Recovery(RecoveryKey),              // organizer-escrow recovery state
+    Settlement(SettlementKey),          // platform fee settlement history
+    Receipt(ReceiptKey),                // purchase terms and issued receipts
+    FailedPurchases,                    // Vec<FailedPurchase>, oldest first, bounded ring
+    Coupon(CouponKey),                  // attendance coupons, vouchers and loyalty points
+    Poll(PollKey),                      // ticket-holder polls
+    Treasury(TreasuryKey),              // per-token platform fee treasuries
+    Operator(OperatorKey),              // per-event operator grants
+    Sponsor(SponsorKey),                // sponsor slots and sold sponsorships
+    Market(MarketKey),                  // resale listings and royalties
+    Installment(InstallmentKey),        // pay-over-time plans for reserved tickets
+    Refund(RefundKey),                  // refund pools of cancelled events
+    Referral(ReferralKey),              // referral share, opt-outs and accrued rewards
+    StorageVersion,                     // u32 layout version, absent before versioning
 }